@@ -1,8 +1,11 @@
-use parol::{build::Builder, parol_runtime::Result};
+use parol::{
+    build::Builder,
+    parol_runtime::{Result, lexer::ColumnUnit},
+};
 
 fn main() -> Result<()> {
     // CLI equivalent is:
-    // parol -f ./parol_ls.par -e ./parol_ls-exp.par -p ./src/parol_ls_parser.rs -a ./src/parol_ls_grammar_trait.rs -t ParolLsGrammar -m parol_ls_grammar -b -x --max-parsing-depth 1500
+    // parol -f ./parol_ls.par -e ./parol_ls-exp.par -p ./src/parol_ls_parser.rs -a ./src/parol_ls_grammar_trait.rs -t ParolLsGrammar -m parol_ls_grammar -b -x --max-parsing-depth 1500 --column-unit utf16
     Builder::with_explicit_output_dir("src")
         .grammar_file("parol_ls.par")
         .expanded_grammar_output_file("../parol_ls-exp.par")
@@ -13,6 +16,8 @@ fn main() -> Result<()> {
         .user_trait_module_name("parol_ls_grammar")
         .trim_parse_tree()
         .max_parsing_depth(1500)
+        // The Language Server Protocol counts columns in UTF-16 code units by default
+        .column_unit(ColumnUnit::Utf16)
         .generate_parser()?;
     Ok(())
 }
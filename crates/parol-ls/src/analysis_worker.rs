@@ -0,0 +1,298 @@
+//! Background grammar analysis.
+//!
+//! The expensive parts of the grammar analysis, i.e. the calculation of the lookahead DFAs of
+//! LL(k) grammars and the calculation of the LALR(1) parse table, are executed on a dedicated
+//! worker thread. This way the message loop of the server stays responsive while the user edits
+//! large grammars.
+//!
+//! The input preview of the custom request `parol-ls/tryInput` needs the same calculations. It is
+//! executed on the worker as well, without debouncing, and the worker sends the response.
+//!
+//! Analysis requests are debounced: the worker waits until no new request arrived for a certain amount of
+//! time and then only analyzes the newest version of each document. Analyses of outdated document
+//! versions are skipped, and results of analyses that were superseded while running are discarded.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::Duration,
+};
+
+use lsp_server::{Message, RequestId, Response};
+use lsp_types::{DiagnosticRelatedInformation, Uri};
+
+use crate::{
+    document_state::DocumentState,
+    server::Server,
+    try_input::{TryInputResult, try_input},
+};
+
+/// Default time the worker waits for further changes before it starts an analysis.
+pub(crate) const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+///
+/// A request to analyze a certain version of a document.
+///
+#[derive(Debug)]
+pub(crate) struct AnalysisRequest {
+    pub(crate) uri: Uri,
+    pub(crate) version: i32,
+    pub(crate) file_name: PathBuf,
+    pub(crate) max_k: usize,
+    /// Snapshot of the document state at the time of the request.
+    /// It is needed to locate errors in the grammar text.
+    pub(crate) document_state: DocumentState,
+    /// Information about the build scripts that process the grammar, added to each diagnostic
+    pub(crate) related_information: Vec<DiagnosticRelatedInformation>,
+}
+
+///
+/// A request to parse a sample text with a snapshot of a grammar description.
+///
+#[derive(Debug)]
+pub(crate) struct TryInputRequest {
+    /// The id of the `parol-ls/tryInput` request to respond to
+    pub(crate) id: RequestId,
+    pub(crate) file_name: PathBuf,
+    pub(crate) max_k: usize,
+    /// The text of the grammar description
+    pub(crate) grammar: String,
+    /// The sample text to parse
+    pub(crate) input: String,
+}
+
+#[derive(Debug)]
+enum Task {
+    Analyze(Box<AnalysisRequest>),
+    TryInput(TryInputRequest),
+}
+
+///
+/// The newest known version of each open document.
+/// Shared between the server and the worker thread to detect stale analysis runs.
+///
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DocumentVersions(Arc<Mutex<HashMap<Uri, i32>>>);
+
+impl DocumentVersions {
+    pub(crate) fn set(&self, uri: &Uri, version: i32) {
+        self.0.lock().unwrap().insert(uri.clone(), version);
+    }
+
+    pub(crate) fn remove(&self, uri: &Uri) {
+        self.0.lock().unwrap().remove(uri);
+    }
+
+    /// Returns true if the given version is still the newest version of the document.
+    /// Closed documents are never current.
+    pub(crate) fn is_current(&self, uri: &Uri, version: i32) -> bool {
+        self.0.lock().unwrap().get(uri) == Some(&version)
+    }
+}
+
+///
+/// Handle to the analysis worker thread.
+/// The thread terminates when this handle is dropped.
+///
+#[derive(Debug)]
+pub(crate) struct AnalysisWorker {
+    sender: Sender<Task>,
+    versions: DocumentVersions,
+}
+
+impl AnalysisWorker {
+    pub(crate) fn new(connection: Arc<lsp_server::Connection>, debounce: Duration) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let versions = DocumentVersions::default();
+        let worker_versions = versions.clone();
+        thread::spawn(move || Self::run(receiver, connection, worker_versions, debounce));
+        Self { sender, versions }
+    }
+
+    ///
+    /// Marks the given version as the newest one of the document.
+    /// Pending or running analyses of older versions are discarded.
+    ///
+    pub(crate) fn supersede(&self, uri: &Uri, version: i32) {
+        self.versions.set(uri, version);
+    }
+
+    ///
+    /// Cancels all pending and running analyses of the given document, e.g. after it was closed.
+    ///
+    pub(crate) fn cancel(&self, uri: &Uri) {
+        self.versions.remove(uri);
+    }
+
+    ///
+    /// Schedules the analysis of a document.
+    ///
+    pub(crate) fn schedule(&self, request: AnalysisRequest) {
+        self.versions.set(&request.uri, request.version);
+        if self.sender.send(Task::Analyze(Box::new(request))).is_err() {
+            eprintln!("analysis worker: worker thread has terminated");
+        }
+    }
+
+    ///
+    /// Schedules the parsing of a sample text. The worker responds to the request when done.
+    ///
+    pub(crate) fn schedule_try_input(&self, request: TryInputRequest) {
+        if self.sender.send(Task::TryInput(request)).is_err() {
+            eprintln!("analysis worker: worker thread has terminated");
+        }
+    }
+
+    // The URIs are never mutated while they are used as keys.
+    #[allow(clippy::mutable_key_type)]
+    fn run(
+        receiver: Receiver<Task>,
+        connection: Arc<lsp_server::Connection>,
+        versions: DocumentVersions,
+        debounce: Duration,
+    ) {
+        while let Ok(task) = receiver.recv() {
+            let request = match task {
+                Task::Analyze(request) => *request,
+                Task::TryInput(request) => {
+                    Self::try_input(request, &connection);
+                    continue;
+                }
+            };
+            let mut pending = HashMap::new();
+            pending.insert(request.uri.clone(), request);
+            // Collect further requests until the editor settles down.
+            // Newer requests replace older ones of the same document.
+            // The user waits for the input preview, so it isn't delayed.
+            loop {
+                match receiver.recv_timeout(debounce) {
+                    Ok(Task::Analyze(request)) => {
+                        pending.insert(request.uri.clone(), *request);
+                    }
+                    Ok(Task::TryInput(request)) => Self::try_input(request, &connection),
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            for request in pending.into_values() {
+                Self::analyze(request, connection.clone(), &versions);
+            }
+        }
+    }
+
+    fn analyze(
+        request: AnalysisRequest,
+        connection: Arc<lsp_server::Connection>,
+        versions: &DocumentVersions,
+    ) {
+        let AnalysisRequest {
+            uri,
+            version,
+            file_name,
+            max_k,
+            document_state,
+            related_information,
+        } = request;
+        if !versions.is_current(&uri, version) {
+            eprintln!("analysis worker: skipping stale version {version} of {uri:?}");
+            return;
+        }
+        eprintln!("analysis worker: analyzing version {version} of {uri:?}");
+        let result = Server::check_grammar(&document_state.input, &file_name, max_k);
+        if !versions.is_current(&uri, version) {
+            eprintln!("analysis worker: discarding result of stale version {version} of {uri:?}");
+            return;
+        }
+        let published = match result {
+            Ok(check_result) if check_result.is_empty() => {
+                Server::notify_analysis_ok(connection, uri, version)
+            }
+            Ok(check_result) => Server::notify_grammar_check_findings(
+                check_result,
+                connection,
+                &uri,
+                version,
+                &document_state,
+                &related_information,
+            ),
+            Err(err) => Server::notify_analysis_error(
+                err,
+                connection,
+                &uri,
+                version,
+                document_state,
+                &related_information,
+            ),
+        };
+        if let Err(err) = published {
+            eprintln!("analysis worker: failed to publish diagnostics: {err}");
+        }
+    }
+
+    fn try_input(request: TryInputRequest, connection: &lsp_server::Connection) {
+        let TryInputRequest {
+            id,
+            file_name,
+            max_k,
+            grammar,
+            input,
+        } = request;
+        let result = Server::obtain_transformed_grammar_config(&grammar, &file_name)
+            .and_then(|grammar_config| try_input(grammar_config, max_k, &input))
+            .unwrap_or_else(|err| TryInputResult::from_grammar_error(&err));
+        let response = Response::new_ok(id, serde_json::to_value(result).unwrap());
+        if connection.sender.send(Message::Response(response)).is_err() {
+            eprintln!("analysis worker: failed to send the tryInput response");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnalysisWorker, DocumentVersions, TryInputRequest};
+    use lsp_server::{Connection, Message, RequestId};
+    use std::{str::FromStr, sync::Arc, time::Duration};
+
+    #[test]
+    fn document_versions_detect_superseded_versions() {
+        let uri = lsp_types::Uri::from_str("file:///test.par").expect("valid URI");
+        let versions = DocumentVersions::default();
+        assert!(!versions.is_current(&uri, 1));
+
+        versions.set(&uri, 1);
+        assert!(versions.is_current(&uri, 1));
+
+        versions.set(&uri, 2);
+        assert!(!versions.is_current(&uri, 1));
+        assert!(versions.is_current(&uri, 2));
+
+        versions.remove(&uri);
+        assert!(!versions.is_current(&uri, 2));
+    }
+
+    #[test]
+    fn worker_responds_to_try_input() {
+        let (server, client) = Connection::memory();
+        let worker = AnalysisWorker::new(Arc::new(server), Duration::from_millis(1));
+        worker.schedule_try_input(TryInputRequest {
+            id: RequestId::from(7),
+            file_name: "test.par".into(),
+            max_k: 3,
+            grammar: "%start S\n%%\nS: \"a\";".to_string(),
+            input: "a".to_string(),
+        });
+        let Ok(Message::Response(response)) = client.receiver.recv_timeout(Duration::from_secs(10))
+        else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.id, RequestId::from(7));
+        let result = response.response_result.expect("tryInput succeeds");
+        assert_eq!(result["roots"], serde_json::json!([0]));
+        assert_eq!(result["diagnostics"], serde_json::json!([]));
+    }
+}
@@ -1,10 +1,10 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 use lsp_types::{DynamicRegistrationClientCapabilities, InitializeParams};
 use serde::Deserialize;
 use serde_json::Value;
 
-use crate::arguments::Arguments;
+use crate::{arguments::Arguments, utils::uri_to_path};
 
 #[derive(Debug, Default, Deserialize)]
 pub(crate) struct ConfigProperties(pub(crate) HashMap<String, Value>);
@@ -48,6 +48,31 @@ impl Config {
         &self.config_properties
     }
 
+    /// The folders of the workspace.
+    /// Falls back to the root URI for clients that don't support workspace folders.
+    pub(crate) fn workspace_folders(&self) -> Vec<PathBuf> {
+        if let Some(folders) = self.initialization_params.workspace_folders.as_ref() {
+            folders.iter().map(|f| uri_to_path(&f.uri)).collect()
+        } else {
+            #[allow(deprecated)]
+            self.initialization_params
+                .root_uri
+                .iter()
+                .map(uri_to_path)
+                .collect()
+        }
+    }
+
+    pub(crate) fn supports_dynamic_registration_for_watched_files(&self) -> bool {
+        self.initialization_params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+            .and_then(|capabilities| capabilities.dynamic_registration)
+            .unwrap_or_default()
+    }
+
     pub(crate) fn supports_dynamic_registration_for_change_config(&self) -> bool {
         if let Some(workspace) = self.initialization_params.capabilities.workspace.as_ref() {
             matches!(
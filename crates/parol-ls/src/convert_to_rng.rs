@@ -22,7 +22,12 @@ impl From<&ASTControl> for Rng {
 
 impl From<&Alternation> for Rng {
     fn from(val: &Alternation) -> Self {
-        Self::from_slice(&val.alternation_list)
+        val.alternation_opt
+            .as_ref()
+            .map_or(Rng::default(), |alternation_opt| {
+                Self::from(&alternation_opt.predicate)
+            })
+            .extend(Self::from_slice(&val.alternation_list))
     }
 }
 
@@ -75,6 +80,9 @@ impl From<&Declaration> for Rng {
             Declaration::PercentNtUnderscoreTypeNtNameEquNtType(nt_type) => {
                 Self::from(&nt_type.percent_nt_underscore_type).extend(Self::from(&nt_type.nt_type))
             }
+            Declaration::PercentEntryIdentifierList(entry) => {
+                Self::from(&entry.percent_entry).extend(Self::from(&entry.identifier_list))
+            }
         }
     }
 }
@@ -184,6 +192,12 @@ impl From<&ParolLs> for Rng {
     }
 }
 
+impl From<&Predicate> for Rng {
+    fn from(val: &Predicate) -> Self {
+        Self::from(&val.amp_l_brace).extend(Self::from(&val.r_brace))
+    }
+}
+
 impl From<&Production> for Rng {
     fn from(val: &Production) -> Self {
         Self::from(&val.production_l_h_s).extend(Self::from(&val.semicolon))
@@ -233,6 +247,10 @@ impl From<&ScannerDirectives> for Rng {
                 Self::from(&bc.percent_block_underscore_comment)
                     .extend(Self::from(&bc.token_literal0))
             }
+            ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                bc,
+            ) => Self::from(&bc.percent_nested_underscore_block_underscore_comment)
+                .extend(Self::from(&bc.token_literal0)),
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(auto_nl) => {
                 Self::from(&auto_nl.percent_auto_underscore_newline_underscore_off)
             }
@@ -242,6 +260,12 @@ impl From<&ScannerDirectives> for Rng {
             ScannerDirectives::PercentSkipIdentifierList(skip) => {
                 Self::from(&skip.percent_skip).extend(Self::from(&skip.identifier_list))
             }
+            ScannerDirectives::PercentOffsideIndentDedentNewline(offside) => {
+                Self::from(&offside.percent_offside).extend(Self::from(&offside.newline))
+            }
+            ScannerDirectives::PercentExternalIdentifierList(external) => {
+                Self::from(&external.percent_external).extend(Self::from(&external.identifier_list))
+            }
             ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(trans) => {
                 Self::from(&trans.percent_on).extend(Self::from(&trans.scanner_state_directives))
             }
@@ -367,6 +391,10 @@ impl From<&TokenLiteral> for Rng {
         match val {
             TokenLiteral::String(s) => Self::from(&s.string.string),
             TokenLiteral::LiteralString(l) => Self::from(&l.literal_string.literal_string),
+            TokenLiteral::CaseInsensitiveLiteralString(l) => Self::from(
+                &l.case_insensitive_literal_string
+                    .case_insensitive_literal_string,
+            ),
             TokenLiteral::Regex(r) => Self::from(&r.regex.regex),
         }
     }
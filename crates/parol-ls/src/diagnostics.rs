@@ -2,21 +2,40 @@ use lsp_types::{
     Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Range, Uri,
 };
 use parol::{
-    GrammarAnalysisError, ParolParserError, analysis::lalr1_parse_table::LRResolvedConflict,
+    GrammarAnalysisError, ParolParserError,
+    analysis::{TerminalOverlap, TerminalOverlapKind, lalr1_parse_table::LRResolvedConflict},
 };
-use parol_runtime::{ParolError, ParserError, SyntaxError};
+use parol_runtime::{ParolError, ParserError, SyntaxError, parser::RecoveryEdit};
 use std::error::Error;
 
 use crate::{
     document_state::{DocumentState, LocatedDocumentState},
     server::Server,
-    utils::{location_to_location, location_to_range},
+    utils::{offsets_to_location, offsets_to_range},
 };
 
 #[derive(Debug)]
 pub struct Diagnostics {}
 
 impl Diagnostics {
+    ///
+    /// Adds the given related information to each diagnostic.
+    ///
+    pub(crate) fn add_related_information(
+        diagnostics: &mut [Diagnostic],
+        related_information: &[DiagnosticRelatedInformation],
+    ) {
+        if related_information.is_empty() {
+            return;
+        }
+        for diagnostic in diagnostics {
+            diagnostic
+                .related_information
+                .get_or_insert_with(Vec::new)
+                .extend_from_slice(related_information);
+        }
+    }
+
     pub(crate) fn to_diagnostics(
         uri: &Uri,
         document_state: &DocumentState,
@@ -34,7 +53,12 @@ impl Diagnostics {
             match e {
                 ParolError::ParserError(err) => {
                     if let ParserError::SyntaxErrors { entries } = err {
-                        extract_syntax_errors(entries, &mut diagnostics, uri);
+                        extract_syntax_errors(
+                            entries,
+                            &document_state.input,
+                            &mut diagnostics,
+                            uri,
+                        );
                         return diagnostics;
                     }
                 }
@@ -107,44 +131,106 @@ impl Diagnostics {
             ..Default::default()
         }
     }
+
+    ///
+    /// Converts the findings of the terminal overlap analysis into diagnostics.
+    /// The diagnostics are attached to the first occurrence of the affected terminal.
+    /// Overlaps are usually intended and are therefore only reported as hints.
+    ///
+    pub(crate) fn to_terminal_overlap_diagnostics(
+        uri: &Uri,
+        document_state: &DocumentState,
+        overlaps: &[TerminalOverlap],
+    ) -> Vec<Diagnostic> {
+        let find_terminal = |pattern: &str| {
+            document_state
+                .parsed_data
+                .find_terminal_definition(pattern)
+                .unwrap_or_default()
+        };
+        overlaps
+            .iter()
+            .map(|overlap| {
+                let (code, others) = match &overlap.kind {
+                    TerminalOverlapKind::MatchesEmpty => ("terminal_matches_empty", vec![]),
+                    TerminalOverlapKind::Shadowed { by } => {
+                        ("shadowed_terminal", by.iter().collect())
+                    }
+                    TerminalOverlapKind::Overlaps { with, .. } => {
+                        ("overlapping_terminal", vec![with])
+                    }
+                };
+                let related_information = others
+                    .iter()
+                    .map(|other| DiagnosticRelatedInformation {
+                        location: Location::new(uri.clone(), find_terminal(&other.pattern)),
+                        message: format!("Terminal {other} is defined first"),
+                    })
+                    .collect::<Vec<_>>();
+                Diagnostic {
+                    range: find_terminal(&overlap.terminal.pattern),
+                    severity: Some(if overlap.is_warning() {
+                        DiagnosticSeverity::WARNING
+                    } else {
+                        DiagnosticSeverity::HINT
+                    }),
+                    code: Some(lsp_types::NumberOrString::String(format!(
+                        "parol::analysis::{code}"
+                    ))),
+                    source: Some("parol-ls".to_string()),
+                    message: overlap.to_string(),
+                    related_information: if related_information.is_empty() {
+                        None
+                    } else {
+                        Some(related_information)
+                    },
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
 }
 
-fn extract_syntax_errors(entries: &[SyntaxError], diagnostics: &mut Vec<Diagnostic>, uri: &Uri) {
+fn extract_syntax_errors(
+    entries: &[SyntaxError],
+    input: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+    uri: &Uri,
+) {
     for e in entries {
         let range = if e.unexpected_tokens.is_empty() {
-            location_to_range(&e.error_location)
+            offsets_to_range(input, &e.error_location)
         } else {
-            e.unexpected_tokens
-                .iter()
-                .fold(Range::default(), |mut acc, un| {
-                    if acc.start == lsp_types::Position::default() {
-                        acc.start = lsp_types::Position {
-                            line: un.token.start_line,
-                            character: un.token.start_column,
-                        };
-                        acc.end = lsp_types::Position {
-                            line: un.token.end_line,
-                            character: un.token.end_column,
-                        };
-                    }
-                    acc
-                })
+            offsets_to_range(input, &e.unexpected_tokens[0].token)
         };
         let mut related_information: Vec<DiagnosticRelatedInformation> = vec![];
         for u in &e.unexpected_tokens {
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(&u.token, uri),
+                location: offsets_to_location(input, &u.token, uri),
                 message: format!("Unexpected token {u}"),
             })
         }
         related_information.push(DiagnosticRelatedInformation {
-            location: location_to_location(&e.unexpected_tokens[0].token, uri),
+            location: offsets_to_location(input, &e.unexpected_tokens[0].token, uri),
             message: if !e.expected_tokens.is_empty() {
                 format!("Expected one of: {}", e.expected_tokens)
             } else {
                 "No expected tokens available".to_string()
             },
         });
+        for edit in &e.recovery_edits {
+            related_information.push(DiagnosticRelatedInformation {
+                location: offsets_to_location(input, edit.location(), uri),
+                message: format!("Recovered with: {edit}"),
+            });
+        }
+        // The tokens the parser skipped are offered as quick-fixes
+        let deletions = e
+            .recovery_edits
+            .iter()
+            .filter(|edit| matches!(edit, RecoveryEdit::Delete { .. }))
+            .map(|edit| offsets_to_range(input, edit.location()))
+            .collect::<Vec<_>>();
         diagnostics.push(Diagnostic {
             range,
             severity: Some(DiagnosticSeverity::ERROR),
@@ -156,7 +242,11 @@ fn extract_syntax_errors(entries: &[SyntaxError], diagnostics: &mut Vec<Diagnost
             message: e.cause.clone(),
             related_information: Some(related_information),
             tags: None,
-            data: None,
+            data: if deletions.is_empty() {
+                None
+            } else {
+                Some(serde_json::json!({ "deletions": deletions }))
+            },
         });
     }
 }
@@ -285,62 +375,66 @@ fn extract_parser_error(
     code: &mut Option<lsp_types::NumberOrString>,
     related_information: &mut Vec<DiagnosticRelatedInformation>,
 ) {
+    let text = &located_document_state.document_state.input;
     match error {
         ParolParserError::UnknownScanner { name, token, .. } => {
-            *range = location_to_range(token);
+            *range = offsets_to_range(text, token);
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(token, located_document_state.uri),
+                location: offsets_to_location(text, token, located_document_state.uri),
                 message: name.to_string(),
             });
         }
         ParolParserError::EmptyGroup { start, end, .. } => {
-            *range = location_to_range(&parol_runtime::Location {
-                start_line: start.start_line,
-                start_column: start.start_column,
-                end_line: end.end_line,
-                end_column: end.end_column,
-                ..Default::default()
-            });
+            *range = offsets_to_range(
+                text,
+                &parol_runtime::Location {
+                    start: start.start,
+                    end: end.end,
+                    ..Default::default()
+                },
+            );
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(start, located_document_state.uri),
+                location: offsets_to_location(text, start, located_document_state.uri),
                 message: "Start".to_string(),
             });
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(end, located_document_state.uri),
+                location: offsets_to_location(text, end, located_document_state.uri),
                 message: "End".to_string(),
             });
         }
         ParolParserError::EmptyOptional { start, end, .. } => {
-            *range = location_to_range(&parol_runtime::Location {
-                start_line: start.start_line,
-                start_column: start.start_column,
-                end_line: end.end_line,
-                end_column: end.end_column,
-                ..Default::default()
-            });
+            *range = offsets_to_range(
+                text,
+                &parol_runtime::Location {
+                    start: start.start,
+                    end: end.end,
+                    ..Default::default()
+                },
+            );
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(start, located_document_state.uri),
+                location: offsets_to_location(text, start, located_document_state.uri),
                 message: "Start".to_string(),
             });
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(end, located_document_state.uri),
+                location: offsets_to_location(text, end, located_document_state.uri),
                 message: "End".to_string(),
             });
         }
         ParolParserError::EmptyRepetition { start, end, .. } => {
-            *range = location_to_range(&parol_runtime::Location {
-                start_line: start.start_line,
-                start_column: start.start_column,
-                end_line: end.end_line,
-                end_column: end.end_column,
-                ..Default::default()
-            });
+            *range = offsets_to_range(
+                text,
+                &parol_runtime::Location {
+                    start: start.start,
+                    end: end.end,
+                    ..Default::default()
+                },
+            );
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(start, located_document_state.uri),
+                location: offsets_to_location(text, start, located_document_state.uri),
                 message: "Start".to_string(),
             });
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(end, located_document_state.uri),
+                location: offsets_to_location(text, end, located_document_state.uri),
                 message: "End".to_string(),
             });
         }
@@ -352,11 +446,11 @@ fn extract_parser_error(
             ..
         } => {
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(first, located_document_state.uri),
+                location: offsets_to_location(text, first, located_document_state.uri),
                 message: first_alias.to_string(),
             });
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(second, located_document_state.uri),
+                location: offsets_to_location(text, second, located_document_state.uri),
                 message: second_alias.to_string(),
             });
         }
@@ -368,9 +462,9 @@ fn extract_parser_error(
             token,
             ..
         } => {
-            *range = location_to_range(token);
+            *range = offsets_to_range(text, token);
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(token, located_document_state.uri),
+                location: offsets_to_location(text, token, located_document_state.uri),
                 message: grammar_type.to_string(),
             });
         }
@@ -380,9 +474,9 @@ fn extract_parser_error(
             hint,
             ..
         } => {
-            *range = location_to_range(token);
+            *range = offsets_to_range(text, token);
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(token, located_document_state.uri),
+                location: offsets_to_location(text, token, located_document_state.uri),
                 message: format!("{feature} - Feature is not yet supported\n{hint}"),
             });
         }
@@ -397,9 +491,9 @@ fn extract_parser_error(
             *code = Some(lsp_types::NumberOrString::String(
                 "parol::parser::invalid_token_in_transition".to_owned(),
             ));
-            *range = location_to_range(location);
+            *range = offsets_to_range(text, location);
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(location, located_document_state.uri),
+                location: offsets_to_location(text, location, located_document_state.uri),
                 message: format!(
                     "Context: {}, Token: {}, Input: {}",
                     context,
@@ -408,7 +502,7 @@ fn extract_parser_error(
                 ),
             });
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(location, located_document_state.uri),
+                location: offsets_to_location(text, location, located_document_state.uri),
                 message: "Quick fix: use a terminal that exists in this context, or remove/adjust the %on transition directive.".to_owned(),
             });
         }
@@ -425,9 +519,9 @@ fn extract_parser_error(
             *code = Some(lsp_types::NumberOrString::String(
                 "parol::parser::token_not_in_scanner".to_owned(),
             ));
-            *range = location_to_range(location);
+            *range = offsets_to_range(text, location);
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(location, located_document_state.uri),
+                location: offsets_to_location(text, location, located_document_state.uri),
                 message: format!(
                     "Context: {}, Scanner: {}, Token: {}, Input: {}",
                     context,
@@ -437,12 +531,25 @@ fn extract_parser_error(
                 ),
             });
             related_information.push(DiagnosticRelatedInformation {
-                location: location_to_location(location, located_document_state.uri),
+                location: offsets_to_location(text, location, located_document_state.uri),
                 message: format!(
                     "Quick fix: assign token '{token}' to scanner '{scanner}' in its token definition, or change the directive to use a token that already belongs to '{scanner}'."
                 ),
             });
         }
+        ParolParserError::UnknownEntryPoint {
+            entry, location, ..
+        } => {
+            *message = format!("Entry point '{entry}' is not a non-terminal of the grammar.");
+            *code = Some(lsp_types::NumberOrString::String(
+                "parol::parser::unknown_entry_point".to_owned(),
+            ));
+            *range = offsets_to_range(text, location);
+            related_information.push(DiagnosticRelatedInformation {
+                location: offsets_to_location(text, location, located_document_state.uri),
+                message: "Quick fix: add productions for the non-terminal or remove it from the %entry directive.".to_owned(),
+            });
+        }
         _ => {
             unreachable!("Scanner switching directives have been removed from the grammar syntax.");
         }
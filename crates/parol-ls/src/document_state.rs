@@ -1,7 +1,8 @@
 use derive_new::new;
 use lsp_types::{
-    DocumentFormattingParams, DocumentSymbolParams, DocumentSymbolResponse, Hover, HoverParams,
-    Position, PrepareRenameResponse, RenameParams, TextDocumentPositionParams, Uri, WorkspaceEdit,
+    DocumentFormattingParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+    DocumentSymbolParams, DocumentSymbolResponse, Hover, HoverParams, Position,
+    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, Uri, WorkspaceEdit,
 };
 
 use crate::parol_ls_grammar::ParolLsGrammar;
@@ -46,6 +47,20 @@ impl DocumentState {
     ) -> Option<Vec<lsp_types::TextEdit>> {
         self.parsed_data.format(params)
     }
+
+    pub(crate) fn format_range(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Option<Vec<lsp_types::TextEdit>> {
+        self.parsed_data.format_range(params)
+    }
+
+    pub(crate) fn format_on_type(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Option<Vec<lsp_types::TextEdit>> {
+        self.parsed_data.format_on_type(params)
+    }
 }
 
 #[derive(Debug, new)]
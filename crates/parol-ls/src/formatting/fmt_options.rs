@@ -47,6 +47,15 @@ pub(crate) struct FmtOptions {
 
     /// Maximum number of characters per line
     pub(crate) max_line_length: usize,
+
+    /// Place each alternative of a production on its own line
+    pub(crate) align_alternations: bool,
+
+    /// Align the trailing line comments of consecutive lines
+    pub(crate) align_comments: bool,
+
+    /// Sort the prolog declarations by their kind and separate the groups by empty lines
+    pub(crate) sort_prolog_declarations: bool,
 }
 
 #[allow(unused)]
@@ -57,6 +66,9 @@ impl FmtOptions {
             empty_line_after_prod: defaults.empty_line_after_prod,
             prod_semicolon_on_nl: defaults.prod_semicolon_on_nl,
             max_line_length: defaults.max_line_length,
+            align_alternations: defaults.align_alternations,
+            align_comments: defaults.align_comments,
+            sort_prolog_declarations: defaults.sort_prolog_declarations,
             ..Default::default()
         }
     }
@@ -128,6 +140,9 @@ impl From<&FormattingOptions> for FmtOptions {
             empty_line_after_prod: settings.empty_line_after_prod,
             prod_semicolon_on_nl: settings.prod_semicolon_on_nl,
             max_line_length: settings.max_line_length,
+            align_alternations: settings.align_alternations,
+            align_comments: settings.align_comments,
+            sort_prolog_declarations: settings.sort_prolog_declarations,
             ..Self::default()
         }
     }
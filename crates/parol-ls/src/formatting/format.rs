@@ -7,12 +7,14 @@ mod helpers;
 mod last_token;
 mod production_fmt;
 mod prolog_fmt;
+mod range_fmt;
 mod scalar_fmt;
 mod scanner_fmt;
 mod scanner_state_fmt;
 mod token_expr_fmt;
 mod traits;
 
+pub(crate) use range_fmt::format_range;
 pub(crate) use traits::Format;
 #[cfg(test)]
 mod test;
@@ -103,6 +103,50 @@ pub(super) fn format_declaration_with_context(
                 comments,
             )
         }
+        Declaration::PercentEntryIdentifierList(entry) => {
+            let (comments_before_token, comments) = format_comments_before_token(
+                comments,
+                &entry.percent_entry,
+                &comment_opts_left(context.policy()),
+            );
+            let context = context_for_declaration(context, &comments_before_token);
+            let delim = declaration_delimiter(&context);
+            let (following_comment, comments) = format_trailing_comment(
+                comments,
+                entry.get_last_token(),
+                &comment_opts_left_force_remove(context.policy()),
+            );
+            let ident_list = entry
+                .identifier_list
+                .identifier_list_list
+                .iter()
+                .fold(
+                    vec![
+                        entry
+                            .identifier_list
+                            .identifier
+                            .identifier
+                            .text()
+                            .to_string(),
+                    ],
+                    |mut acc, i| {
+                        acc.push(i.identifier.identifier.text().to_string());
+                        acc
+                    },
+                )
+                .join(", ");
+            (
+                format!(
+                    "{}{}{} {}{}",
+                    comments_before_token,
+                    delim,
+                    entry.percent_entry,
+                    ident_list,
+                    following_comment,
+                ),
+                comments,
+            )
+        }
         Declaration::PercentNtUnderscoreTypeNtNameEquNtType(nt_type) => {
             let (comments_before_token, comments) = format_comments_before_token(
                 comments,
@@ -2,7 +2,7 @@ use crate::{
     parol_ls_grammar_trait::{
         ASTControl, Alternation, AlternationList, Alternations, AlternationsList, CutOperator,
         Factor, GrammarDefinition, GrammarDefinitionList, Group, LookAhead, NonTerminal,
-        NonTerminalOpt, Optional, ParolLs, Production, ProductionLHS, Repeat, Symbol,
+        NonTerminalOpt, Optional, ParolLs, Predicate, Production, ProductionLHS, Repeat, Symbol,
     },
     utils::RX_NEW_LINE,
 };
@@ -11,7 +11,7 @@ use super::super::comments::Comments;
 use super::super::fmt_options::FmtOptions;
 use super::super::{context::FormatterContext, line::Line};
 use super::dispatch::handle_symbol;
-use super::helpers::{comment_opts_right, format_comments_before_token};
+use super::helpers::{align_trailing_comments, comment_opts_right, format_comments_before_token};
 use super::production_fmt::{format_production_lhs_with_context, format_production_with_context};
 use super::traits::Fmt;
 
@@ -57,8 +57,13 @@ impl Fmt for Alternation {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let split_top_level_alternatives = options.nesting_depth <= 1;
         let next_option = options.clone().next_depth();
+        let (predicate_str, comments) = if let Some(alternation_opt) = &self.alternation_opt {
+            alternation_opt.predicate.txt(options, comments)
+        } else {
+            (String::default(), comments)
+        };
         let (mut alternation_str, comments) = self.alternation_list.iter().fold(
-            (String::new(), comments),
+            (predicate_str, comments),
             |(mut acc, comments), e| {
                 let (mut next_part, comments) = e.txt(&next_option, comments);
                 if split_top_level_alternatives && !acc.is_empty() {
@@ -116,7 +121,11 @@ impl Fmt for Alternations {
                 (acc, comments)
             },
         );
+        let keep_alternatives_apart = options.align_alternations
+            && options.nesting_depth == 0
+            && !self.alternations_list.is_empty();
         if options.nesting_depth <= 1
+            && !keep_alternatives_apart
             && !all_alternations_str.contains("//")
             && !all_alternations_str.contains("/*")
         {
@@ -306,7 +315,12 @@ impl Fmt for ParolLs {
             "\n"
         };
         let (grammar_definition, comments) = self.grammar_definition.txt(options, comments);
-        (format!("{prolog}{nl_opt}{grammar_definition}"), comments)
+        let text = format!("{prolog}{nl_opt}{grammar_definition}");
+        if options.align_comments {
+            (align_trailing_comments(&text, options), comments)
+        } else {
+            (text, comments)
+        }
     }
 }
 
@@ -324,6 +338,16 @@ impl Fmt for ProductionLHS {
     }
 }
 
+impl Fmt for Predicate {
+    fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        let (identifier_str, comments) = self.identifier.txt(options, comments);
+        (
+            format!("{}{}{}", self.amp_l_brace, identifier_str, self.r_brace),
+            comments,
+        )
+    }
+}
+
 impl Fmt for Repeat {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (alternations_str, comments) = self.alternations.txt(options, comments);
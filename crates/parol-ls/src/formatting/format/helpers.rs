@@ -2,6 +2,7 @@ use crate::parol_ls_grammar::OwnedToken;
 
 use super::super::comments::Comments;
 use super::super::fmt_options::{FmtOptions, LineEnd, Padding};
+use super::super::line::Line;
 
 pub(super) fn format_comments_before_token(
     comments: Comments,
@@ -34,3 +35,46 @@ pub(super) fn comment_opts_force_single_newline(options: &FmtOptions) -> FmtOpti
 pub(super) fn comment_opts_left_force_remove(options: &FmtOptions) -> FmtOptions {
     comment_opts_left(options).with_line_end(LineEnd::ForceRemove)
 }
+
+/// Aligns the line comments that trail the code of consecutive lines to a common column.
+/// A block of lines is left unchanged if the alignment would exceed the maximum line length.
+pub(super) fn align_trailing_comments(text: &str, options: &FmtOptions) -> String {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    let split = lines
+        .iter()
+        .map(|line| {
+            Line::trailing_comment_start(line)
+                .map(|pos| (line[..pos].trim_end(), line[pos..].trim_end()))
+        })
+        .collect::<Vec<_>>();
+    let mut aligned = Vec::with_capacity(lines.len());
+    let mut i = 0;
+    while i < lines.len() {
+        let block_len = split[i..].iter().take_while(|s| s.is_some()).count();
+        if block_len == 0 {
+            aligned.push(lines[i].to_string());
+            i += 1;
+            continue;
+        }
+        let block = split[i..i + block_len].iter().flatten().collect::<Vec<_>>();
+        let column = block
+            .iter()
+            .map(|(code, _)| code.chars().count() + 1)
+            .max()
+            .unwrap_or_default();
+        let fits = block
+            .iter()
+            .all(|(_, comment)| column + comment.chars().count() <= options.max_line_length);
+        if block_len > 1 && fits {
+            aligned.extend(
+                block
+                    .iter()
+                    .map(|(code, comment)| format!("{code:column$}{comment}")),
+            );
+        } else {
+            aligned.extend(lines[i..i + block_len].iter().map(|l| l.to_string()));
+        }
+        i += block_len;
+    }
+    aligned.join("\n")
+}
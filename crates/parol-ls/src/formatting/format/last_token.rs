@@ -1,7 +1,7 @@
 use crate::{
     parol_ls_grammar::OwnedToken,
     parol_ls_grammar_trait::{
-        ScannerDirectivesPercentSkipIdentifierList,
+        DeclarationPercentEntryIdentifierList, ScannerDirectivesPercentExternalIdentifierList, ScannerDirectivesPercentSkipIdentifierList,
         ScannerDirectivesPercentOnIdentifierListScannerStateDirectives, ScannerStateDirectives,
         TokenLiteral, UserTypeName,
     },
@@ -31,6 +31,10 @@ impl LastToken for TokenLiteral {
         match self {
             TokenLiteral::String(s) => &s.string.string,
             TokenLiteral::LiteralString(l) => &l.literal_string.literal_string,
+            TokenLiteral::CaseInsensitiveLiteralString(l) => {
+                &l.case_insensitive_literal_string
+                    .case_insensitive_literal_string
+            }
             TokenLiteral::Regex(r) => &r.regex.regex,
         }
     }
@@ -75,3 +79,35 @@ impl LastToken for ScannerDirectivesPercentSkipIdentifierList {
         }
     }
 }
+
+impl LastToken for DeclarationPercentEntryIdentifierList {
+    fn get_last_token(&self) -> &OwnedToken {
+        if self.identifier_list.identifier_list_list.is_empty() {
+            &self.identifier_list.identifier.identifier
+        } else {
+            &self
+                .identifier_list
+                .identifier_list_list
+                .last()
+                .unwrap()
+                .identifier
+                .identifier
+        }
+    }
+}
+
+impl LastToken for ScannerDirectivesPercentExternalIdentifierList {
+    fn get_last_token(&self) -> &OwnedToken {
+        if self.identifier_list.identifier_list_list.is_empty() {
+            &self.identifier_list.identifier.identifier
+        } else {
+            &self
+                .identifier_list
+                .identifier_list_list
+                .last()
+                .unwrap()
+                .identifier
+                .identifier
+        }
+    }
+}
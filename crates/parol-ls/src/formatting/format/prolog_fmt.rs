@@ -1,4 +1,4 @@
-use crate::parol_ls_grammar_trait::{Prolog, PrologList, PrologList0};
+use crate::parol_ls_grammar_trait::{Declaration, Prolog, PrologList, PrologList0};
 
 use super::super::comments::Comments;
 use super::super::fmt_options::FmtOptions;
@@ -7,14 +7,17 @@ use super::traits::Fmt;
 impl Fmt for Prolog {
     fn txt(&self, options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let (start_declaration, comments) = self.start_declaration.txt(options, comments);
-        let (prolog_list, comments) =
+        let (prolog_list, comments) = if options.sort_prolog_declarations {
+            sorted_prolog_list(&self.prolog_list, options, comments)
+        } else {
             self.prolog_list
                 .iter()
                 .fold((String::new(), comments), |(mut acc, comments), p| {
                     let (pro_str, comments) = p.txt(options, comments);
                     acc.push_str(&pro_str);
                     (acc, comments)
-                });
+                })
+        };
         let (prolog_list0, comments) =
             self.prolog_list0
                 .iter()
@@ -41,3 +44,57 @@ impl Fmt for PrologList0 {
         self.scanner_state.txt(options, comments)
     }
 }
+
+/// Groups of prolog declarations in the order they are emitted when sorting is enabled
+fn declaration_group(declaration: &Declaration) -> u8 {
+    match declaration {
+        Declaration::PercentTitleString(_)
+        | Declaration::PercentCommentString(_)
+        | Declaration::PercentGrammarUnderscoreTypeLiteralString(_)
+        | Declaration::PercentEntryIdentifierList(_) => 0,
+        Declaration::ScannerDirectives(_) => 1,
+        Declaration::PercentUserUnderscoreTypeIdentifierEquUserTypeName(_)
+        | Declaration::PercentNtUnderscoreTypeNtNameEquNtType(_)
+        | Declaration::PercentTUnderscoreTypeTType(_) => 2,
+    }
+}
+
+/// Formats the prolog declarations sorted by their group and separates the groups by an empty
+/// line. The order within a group is retained. Comments on the lines before a declaration move
+/// with it, a comment at the end of the line stays with the declaration it trails.
+fn sorted_prolog_list(
+    prolog_list: &[PrologList],
+    options: &FmtOptions,
+    comments: Comments,
+) -> (String, Comments) {
+    let mut leading = String::new();
+    let mut declarations: Vec<(u8, String)> = Vec::with_capacity(prolog_list.len());
+    let comments = prolog_list.iter().fold(comments, |comments, p| {
+        let (pro_str, comments) = p.txt(options, comments);
+        // Text before the first newline is a trailing comment of the previous line
+        let (trailing, own) = if pro_str.starts_with('\n') {
+            ("", pro_str.as_str())
+        } else {
+            pro_str
+                .find('\n')
+                .map_or(("", pro_str.as_str()), |pos| pro_str.split_at(pos))
+        };
+        match declarations.last_mut() {
+            Some((_, last)) => last.push_str(trailing),
+            None => leading.push_str(trailing),
+        }
+        declarations.push((declaration_group(&p.declaration), own.to_string()));
+        comments
+    });
+    declarations.sort_by_key(|(group, _)| *group);
+    let mut prolog_list = leading;
+    let mut previous_group = None;
+    for (group, declaration) in declarations {
+        if previous_group.is_some_and(|previous| previous != group) {
+            prolog_list.push('\n');
+        }
+        prolog_list.push_str(&declaration);
+        previous_group = Some(group);
+    }
+    (prolog_list, comments)
+}
@@ -0,0 +1,120 @@
+use std::path::PathBuf;
+
+use lsp_types::{FormattingOptions, Range, TextEdit};
+
+use crate::{
+    parol_ls_grammar::ParolLsGrammar, parol_ls_grammar_trait::ParolLs, parol_ls_parser::parse,
+    rng::Rng, utils::extract_text_range,
+};
+
+use super::traits::Format;
+
+/// Formats the items of the grammar description that overlap with the given range.
+///
+/// Items are the prolog as a whole and the single productions. The whole document is formatted
+/// and the formatted text of each affected item replaces the original one. Items whose comments
+/// would be moved out of them by the formatting are left untouched.
+pub(crate) fn format_range(
+    grammar: &ParolLsGrammar,
+    range: Range,
+    options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let parol_ls = grammar.grammar.as_ref()?;
+    let formatted_text = parol_ls
+        .format(options, grammar.comments.clone())
+        .pop()?
+        .new_text;
+    let mut formatted_grammar = ParolLsGrammar::new();
+    parse(
+        &formatted_text,
+        PathBuf::from("formatted.par"),
+        &mut formatted_grammar,
+    )
+    .ok()?;
+    let formatted_items = items(formatted_grammar.grammar.as_ref()?);
+    let original_items = items(parol_ls);
+    if formatted_items.len() != original_items.len() {
+        return None;
+    }
+    Some(
+        original_items
+            .into_iter()
+            .zip(formatted_items)
+            .filter(|(original, _)| original.0.start <= range.end && range.start <= original.0.end)
+            .filter(|(original, formatted)| {
+                comment_count(grammar, original) == comment_count(&formatted_grammar, formatted)
+            })
+            .map(|(original, formatted)| TextEdit {
+                range: original.0,
+                new_text: extract_text_range(&formatted_text, formatted).to_string(),
+            })
+            .collect(),
+    )
+}
+
+/// The ranges of the prolog and of all productions in document order
+fn items(parol_ls: &ParolLs) -> Vec<Rng> {
+    let definition = &parol_ls.grammar_definition;
+    std::iter::once(Rng::from(&parol_ls.prolog))
+        .chain(std::iter::once(Rng::from(&definition.production)))
+        .chain(
+            definition
+                .grammar_definition_list
+                .iter()
+                .map(|p| Rng::from(&p.production)),
+        )
+        .collect()
+}
+
+fn comment_count(grammar: &ParolLsGrammar, item: &Rng) -> usize {
+    grammar
+        .comments
+        .comments
+        .iter()
+        .filter(|c| {
+            let comment = Rng::from(*c).0;
+            item.0.start <= comment.start && comment.end <= item.0.end
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lsp_types::{FormattingOptions, Position, Range};
+
+    use super::format_range;
+    use crate::{parol_ls_grammar::ParolLsGrammar, parol_ls_parser::parse};
+
+    const INPUT: &str = "%start A\n%%\nA: B   C;\nB:\"b\" ;\nC: \"c\";\n";
+
+    fn format_at(range: Range) -> Vec<(u32, String)> {
+        let mut grammar = ParolLsGrammar::new();
+        parse(INPUT, PathBuf::from("test.par"), &mut grammar).unwrap();
+        format_range(&grammar, range, &FormattingOptions::default())
+            .unwrap()
+            .into_iter()
+            .map(|edit| (edit.range.start.line, edit.new_text))
+            .collect()
+    }
+
+    #[test]
+    fn format_only_overlapping_productions() {
+        let position = Position::new(3, 3);
+        let edits = format_at(Range::new(position, position));
+        assert_eq!(vec![(3, "B   : \"b\"\n    ;".to_string())], edits);
+    }
+
+    #[test]
+    fn format_all_productions_in_range() {
+        let edits = format_at(Range::new(Position::new(2, 0), Position::new(3, 1)));
+        assert_eq!(
+            vec![
+                (2, "A   : B C\n    ;".to_string()),
+                (3, "B   : \"b\"\n    ;".to_string())
+            ],
+            edits
+        );
+    }
+}
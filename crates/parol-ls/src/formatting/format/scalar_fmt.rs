@@ -1,5 +1,6 @@
 use crate::parol_ls_grammar_trait::{
-    DoubleColon, Identifier, LiteralString, LookAheadGroup, Regex, TokenLiteral, UserTypeNameList,
+    CaseInsensitiveLiteralString, DoubleColon, Identifier, LiteralString, LookAheadGroup, Regex,
+    TokenLiteral, UserTypeNameList,
 };
 
 use super::super::comments::Comments;
@@ -24,6 +25,15 @@ impl Fmt for LiteralString {
     }
 }
 
+impl Fmt for CaseInsensitiveLiteralString {
+    fn txt(&self, _options: &FmtOptions, comments: Comments) -> (String, Comments) {
+        (
+            self.case_insensitive_literal_string.text().to_string(),
+            comments,
+        )
+    }
+}
+
 impl Fmt for LookAheadGroup {
     fn txt(&self, _options: &FmtOptions, comments: Comments) -> (String, Comments) {
         let lookahead_group_str = match self {
@@ -55,6 +65,9 @@ impl Fmt for TokenLiteral {
         match self {
             TokenLiteral::String(s) => s.string.txt(options, comments),
             TokenLiteral::LiteralString(l) => l.literal_string.txt(options, comments),
+            TokenLiteral::CaseInsensitiveLiteralString(l) => {
+                l.case_insensitive_literal_string.txt(options, comments)
+            }
             TokenLiteral::Regex(r) => r.regex.txt(options, comments),
         }
     }
@@ -96,6 +96,34 @@ pub(super) fn format_scanner_directives_with_context(
                 comments,
             )
         }
+        ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(b) => {
+            let (comments_before_token, comments) = format_comments_before_token(
+                comments,
+                &b.percent_nested_underscore_block_underscore_comment,
+                &comment_opts_left(context.policy()),
+            );
+            let (str1, comments) = b.token_literal.txt(context.policy(), comments);
+            let (str2, comments) = b.token_literal0.txt(context.policy(), comments);
+            let context = context_for_scanner_directive(context, &comments_before_token);
+            let indent = scanner_directive_indent(&base_indent, &context);
+            let (following_comment, comments) = format_trailing_comment(
+                comments,
+                b.token_literal0.get_last_token(),
+                &comment_opts_left_force_remove(context.policy()),
+            );
+            (
+                format!(
+                    "{}{}{} {} {}{}",
+                    comments_before_token,
+                    indent,
+                    b.percent_nested_underscore_block_underscore_comment,
+                    str1,
+                    str2,
+                    following_comment,
+                ),
+                comments,
+            )
+        }
 
         ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(n) => {
             let (comments_before_token, comments) = format_comments_before_token(
@@ -185,6 +213,72 @@ pub(super) fn format_scanner_directives_with_context(
                 comments,
             )
         }
+        ScannerDirectives::PercentExternalIdentifierList(external) => {
+            let (comments_before_token, comments) = format_comments_before_token(
+                comments,
+                &external.percent_external,
+                &comment_opts_left(context.policy()),
+            );
+            let context = context_for_scanner_directive(context, &comments_before_token);
+            let indent = scanner_directive_indent(&base_indent, &context);
+            let (following_comment, comments) = format_trailing_comment(
+                comments,
+                external.get_last_token(),
+                &comment_opts_left_force_remove(context.policy()),
+            );
+            let ident_list = external
+                .identifier_list
+                .identifier_list_list
+                .iter()
+                .fold(
+                    vec![
+                        external.identifier_list
+                            .identifier
+                            .identifier
+                            .text()
+                            .to_string(),
+                    ],
+                    |mut acc, i| {
+                        acc.push(i.identifier.identifier.text().to_string());
+                        acc
+                    },
+                )
+                .join(", ");
+            (
+                format!(
+                    "{}{}{} {}{}",
+                    comments_before_token, indent, external.percent_external, ident_list, following_comment,
+                ),
+                comments,
+            )
+        }
+        ScannerDirectives::PercentOffsideIndentDedentNewline(offside) => {
+            let (comments_before_token, comments) = format_comments_before_token(
+                comments,
+                &offside.percent_offside,
+                &comment_opts_left(context.policy()),
+            );
+            let context = context_for_scanner_directive(context, &comments_before_token);
+            let indent = scanner_directive_indent(&base_indent, &context);
+            let (following_comment, comments) = format_trailing_comment(
+                comments,
+                &offside.newline.identifier,
+                &comment_opts_left_force_remove(context.policy()),
+            );
+            (
+                format!(
+                    "{}{}{} {} {} {}{}",
+                    comments_before_token,
+                    indent,
+                    offside.percent_offside,
+                    offside.indent.identifier,
+                    offside.dedent.identifier,
+                    offside.newline.identifier,
+                    following_comment,
+                ),
+                comments,
+            )
+        }
         ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(trans) => {
             let (comments_before_token, comments) = format_comments_before_token(
                 comments,
@@ -31,6 +31,9 @@ const TEST_DATA: &[(FmtOptions, &str)] = &[
             empty_line_after_prod: true,
             prod_semicolon_on_nl: true,
             max_line_length: 100,
+            align_alternations: false,
+            align_comments: false,
+            sort_prolog_declarations: false,
             padding: Padding::None,
             line_end: LineEnd::Unchanged,
             trimming: Trimming::Unchanged,
@@ -43,6 +46,9 @@ const TEST_DATA: &[(FmtOptions, &str)] = &[
             empty_line_after_prod: true,
             prod_semicolon_on_nl: false,
             max_line_length: 100,
+            align_alternations: false,
+            align_comments: false,
+            sort_prolog_declarations: false,
             padding: Padding::None,
             line_end: LineEnd::Unchanged,
             trimming: Trimming::Unchanged,
@@ -58,6 +64,9 @@ const TEST_DATA: &[(FmtOptions, &str)] = &[
             empty_line_after_prod: false,
             prod_semicolon_on_nl: true,
             max_line_length: 100,
+            align_alternations: false,
+            align_comments: false,
+            sort_prolog_declarations: false,
             padding: Padding::None,
             line_end: LineEnd::Unchanged,
             trimming: Trimming::Unchanged,
@@ -68,6 +77,57 @@ const TEST_DATA: &[(FmtOptions, &str)] = &[
             "/data/expected/empty_line_after_prod_false"
         ),
     ),
+    (
+        FmtOptions {
+            empty_line_after_prod: true,
+            prod_semicolon_on_nl: true,
+            max_line_length: 100,
+            align_alternations: true,
+            align_comments: false,
+            sort_prolog_declarations: false,
+            padding: Padding::None,
+            line_end: LineEnd::Unchanged,
+            trimming: Trimming::Unchanged,
+            nesting_depth: 0,
+        },
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/expected/align_alternations"
+        ),
+    ),
+    (
+        FmtOptions {
+            empty_line_after_prod: true,
+            prod_semicolon_on_nl: true,
+            max_line_length: 100,
+            align_alternations: false,
+            align_comments: true,
+            sort_prolog_declarations: false,
+            padding: Padding::None,
+            line_end: LineEnd::Unchanged,
+            trimming: Trimming::Unchanged,
+            nesting_depth: 0,
+        },
+        concat!(env!("CARGO_MANIFEST_DIR"), "/data/expected/align_comments"),
+    ),
+    (
+        FmtOptions {
+            empty_line_after_prod: true,
+            prod_semicolon_on_nl: true,
+            max_line_length: 100,
+            align_alternations: false,
+            align_comments: false,
+            sort_prolog_declarations: true,
+            padding: Padding::None,
+            line_end: LineEnd::Unchanged,
+            trimming: Trimming::Unchanged,
+            nesting_depth: 0,
+        },
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/expected/sort_prolog_declarations"
+        ),
+    ),
 ];
 
 #[test]
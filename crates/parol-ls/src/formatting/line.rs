@@ -14,6 +14,41 @@ impl Line {
         line.ends_with(['\n', '\r'])
     }
 
+    /// Returns the byte position of a line comment that follows code on the given line.
+    /// Comment markers inside of string, raw string and regex literals are ignored.
+    pub(crate) fn trailing_comment_start(line: &str) -> Option<usize> {
+        let mut chars = line.char_indices().peekable();
+        let mut has_code = false;
+        while let Some((pos, c)) = chars.next() {
+            match c {
+                '/' if chars.peek().is_some_and(|(_, n)| *n == '/') => {
+                    return has_code.then_some(pos);
+                }
+                '/' if chars.peek().is_some_and(|(_, n)| *n == '*') => {
+                    chars.next();
+                    while let Some((_, c)) = chars.next() {
+                        if c == '*' && chars.peek().is_some_and(|(_, n)| *n == '/') {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                '"' | '\'' | '/' => {
+                    while let Some((_, n)) = chars.next() {
+                        if n == '\\' && c != '\'' {
+                            chars.next();
+                        } else if n == c {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+            has_code |= !c.is_whitespace();
+        }
+        None
+    }
+
     // fn ends_with_nls_after_line_comment(line: &str) -> bool {
     //     RX_NEW_LINES_AFTER_LINE_COMMENT.is_match(line)
     // }
@@ -1,5 +1,5 @@
 mod format;
-pub(crate) use format::Format;
+pub(crate) use format::{Format, format_range};
 
 mod indent;
 
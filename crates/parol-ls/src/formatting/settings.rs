@@ -1,16 +1,23 @@
 use lsp_types::{FormattingOptions, FormattingProperty};
+use parol::conversions::par::FormatConfig;
 
 use crate::config::ConfigProperties;
 
 pub(crate) const EMPTY_LINE_AFTER_PROD_KEY: &str = "formatting.empty_line_after_prod";
 pub(crate) const PROD_SEMICOLON_ON_NL_KEY: &str = "formatting.prod_semicolon_on_nl";
 pub(crate) const MAX_LINE_LENGTH_KEY: &str = "formatting.max_line_length";
+pub(crate) const ALIGN_ALTERNATIONS_KEY: &str = "formatting.align_alternations";
+pub(crate) const ALIGN_COMMENTS_KEY: &str = "formatting.align_comments";
+pub(crate) const SORT_PROLOG_DECLARATIONS_KEY: &str = "formatting.sort_prolog_declarations";
 
 #[derive(Debug, Clone)]
 pub(crate) struct FormattingSettings {
     pub(crate) empty_line_after_prod: bool,
     pub(crate) prod_semicolon_on_nl: bool,
     pub(crate) max_line_length: usize,
+    pub(crate) align_alternations: bool,
+    pub(crate) align_comments: bool,
+    pub(crate) sort_prolog_declarations: bool,
 }
 
 impl Default for FormattingSettings {
@@ -19,6 +26,9 @@ impl Default for FormattingSettings {
             empty_line_after_prod: true,
             prod_semicolon_on_nl: true,
             max_line_length: 100,
+            align_alternations: false,
+            align_comments: false,
+            sort_prolog_declarations: false,
         }
     }
 }
@@ -33,6 +43,14 @@ impl FormattingSettings {
         self.prod_semicolon_on_nl =
             read_config_bool(props, PROD_SEMICOLON_ON_NL_KEY, self.prod_semicolon_on_nl)?;
         self.max_line_length = read_config_usize(props, MAX_LINE_LENGTH_KEY, self.max_line_length)?;
+        self.align_alternations =
+            read_config_bool(props, ALIGN_ALTERNATIONS_KEY, self.align_alternations)?;
+        self.align_comments = read_config_bool(props, ALIGN_COMMENTS_KEY, self.align_comments)?;
+        self.sort_prolog_declarations = read_config_bool(
+            props,
+            SORT_PROLOG_DECLARATIONS_KEY,
+            self.sort_prolog_declarations,
+        )?;
 
         eprintln!(
             "{EMPTY_LINE_AFTER_PROD_KEY}: {}",
@@ -40,6 +58,12 @@ impl FormattingSettings {
         );
         eprintln!("{PROD_SEMICOLON_ON_NL_KEY}: {}", self.prod_semicolon_on_nl);
         eprintln!("{MAX_LINE_LENGTH_KEY}: {}", self.max_line_length);
+        eprintln!("{ALIGN_ALTERNATIONS_KEY}: {}", self.align_alternations);
+        eprintln!("{ALIGN_COMMENTS_KEY}: {}", self.align_comments);
+        eprintln!(
+            "{SORT_PROLOG_DECLARATIONS_KEY}: {}",
+            self.sort_prolog_declarations
+        );
 
         Ok(())
     }
@@ -57,6 +81,36 @@ impl FormattingSettings {
             MAX_LINE_LENGTH_KEY.to_owned(),
             FormattingProperty::Number(self.max_line_length as i32),
         );
+        options.properties.insert(
+            ALIGN_ALTERNATIONS_KEY.to_owned(),
+            FormattingProperty::Bool(self.align_alternations),
+        );
+        options.properties.insert(
+            ALIGN_COMMENTS_KEY.to_owned(),
+            FormattingProperty::Bool(self.align_comments),
+        );
+        options.properties.insert(
+            SORT_PROLOG_DECLARATIONS_KEY.to_owned(),
+            FormattingProperty::Bool(self.sort_prolog_declarations),
+        );
+    }
+
+    /// Returns the settings overridden by the options of a project's `.parolfmt.toml` file.
+    pub(crate) fn with_format_config(&self, config: &FormatConfig) -> Self {
+        Self {
+            empty_line_after_prod: config
+                .empty_line_after_prod
+                .unwrap_or(self.empty_line_after_prod),
+            prod_semicolon_on_nl: config
+                .prod_semicolon_on_nl
+                .unwrap_or(self.prod_semicolon_on_nl),
+            max_line_length: config.max_line_length.unwrap_or(self.max_line_length),
+            align_alternations: config.align_alternations.unwrap_or(self.align_alternations),
+            align_comments: config.align_comments.unwrap_or(self.align_comments),
+            sort_prolog_declarations: config
+                .sort_prolog_declarations
+                .unwrap_or(self.sort_prolog_declarations),
+        }
     }
 
     pub(crate) fn from_options(options: &FormattingOptions) -> Self {
@@ -77,6 +131,17 @@ impl FormattingSettings {
                 MAX_LINE_LENGTH_KEY,
                 defaults.max_line_length,
             ),
+            align_alternations: read_option_bool(
+                options,
+                ALIGN_ALTERNATIONS_KEY,
+                defaults.align_alternations,
+            ),
+            align_comments: read_option_bool(options, ALIGN_COMMENTS_KEY, defaults.align_comments),
+            sort_prolog_declarations: read_option_bool(
+                options,
+                SORT_PROLOG_DECLARATIONS_KEY,
+                defaults.sort_prolog_declarations,
+            ),
         }
     }
 }
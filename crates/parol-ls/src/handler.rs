@@ -1,7 +1,8 @@
 use lsp_server::{RequestId, Response};
 use lsp_types::request::{
     CodeActionRequest, DocumentSymbolRequest, Formatting, GotoDefinition, HoverRequest,
-    PrepareRenameRequest, Rename, Request,
+    OnTypeFormatting, PrepareRenameRequest, RangeFormatting, Rename, Request,
+    WorkspaceSymbolRequest,
 };
 
 use crate::server::Server;
@@ -64,6 +65,24 @@ impl RequestHandler for Formatting {
     }
 }
 
+impl RequestHandler for RangeFormatting {
+    fn handle(server: &mut Server, id: RequestId, params: Self::Params) -> Response {
+        eprintln!("got rangeFormatting request #{id}: {params:?}");
+        let result = server.handle_range_formatting(params);
+        let result = serde_json::to_value(result).unwrap();
+        Response::new_ok(id, result)
+    }
+}
+
+impl RequestHandler for OnTypeFormatting {
+    fn handle(server: &mut Server, id: RequestId, params: Self::Params) -> Response {
+        eprintln!("got onTypeFormatting request #{id}: {params:?}");
+        let result = server.handle_on_type_formatting(params);
+        let result = serde_json::to_value(result).unwrap();
+        Response::new_ok(id, result)
+    }
+}
+
 impl RequestHandler for CodeActionRequest {
     fn handle(server: &mut Server, id: RequestId, params: Self::Params) -> Response {
         eprintln!("got codeAction request #{id}: {params:?}");
@@ -72,3 +91,12 @@ impl RequestHandler for CodeActionRequest {
         Response::new_ok(id, result)
     }
 }
+
+impl RequestHandler for WorkspaceSymbolRequest {
+    fn handle(server: &mut Server, id: RequestId, params: Self::Params) -> Response {
+        eprintln!("got workspaceSymbol request #{id}: {params:?}");
+        let result = server.handle_workspace_symbol(params);
+        let result = serde_json::to_value(result).unwrap();
+        Response::new_ok(id, result)
+    }
+}
@@ -5,6 +5,7 @@ use std::{cell::RefCell, error::Error, result::Result};
 use crate::handler::RequestHandler;
 use crate::{arguments::Arguments, config::Config};
 
+mod analysis_worker;
 pub mod arguments;
 mod config;
 mod convert_to_rng;
@@ -17,10 +18,13 @@ mod handler;
 pub mod parol_ls_grammar;
 mod parol_ls_grammar_trait;
 mod parol_ls_parser;
+mod refactoring;
 mod rng;
 mod server;
 mod symbol_def;
+mod try_input;
 mod utils;
+mod workspace;
 
 extern crate clap;
 extern crate parol_runtime;
@@ -28,24 +32,28 @@ extern crate parol_runtime;
 use clap::Parser;
 
 use errors::ServerError;
-use lsp_server::{Connection, ExtractError, Message, Request, RequestId};
+use lsp_server::{Connection, ExtractError, Message, Request, RequestId, Response};
 use lsp_types::notification::DidChangeConfiguration;
 use lsp_types::request::RegisterCapability;
 use lsp_types::{
-    CodeActionProviderCapability, HoverProviderCapability, InitializeParams, OneOf, RenameOptions,
-    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, WorkDoneProgressOptions,
+    CodeActionProviderCapability, DidChangeWatchedFilesRegistrationOptions,
+    DocumentOnTypeFormattingOptions, FileSystemWatcher, GlobPattern, HoverProviderCapability,
+    InitializeParams, OneOf, PositionEncodingKind, RenameOptions, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, WorkDoneProgressOptions,
     notification::{
-        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification,
+        DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+        Notification,
     },
     request::{
         CodeActionRequest, DocumentSymbolRequest, Formatting, GotoDefinition, HoverRequest,
-        PrepareRenameRequest, Rename,
+        OnTypeFormatting, PrepareRenameRequest, RangeFormatting, Rename, WorkspaceSymbolRequest,
     },
 };
 use lsp_types::{Registration, RegistrationParams};
 use parol_runtime::log::debug;
 use serde::Serialize;
 use server::Server;
+use try_input::TryInput;
 
 static GLOBAL_REQUEST_ID: RequestCounter = RequestCounter::new();
 
@@ -108,7 +116,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Run the server and wait for the two threads to end (typically by trigger LSP Exit event).
     let server_capabilities = serde_json::to_value(ServerCapabilities {
-        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        // Positions are counted in UTF-16 code units, the encoding every client supports
+        position_encoding: Some(PositionEncodingKind::UTF16),
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        )),
         definition_provider: Some(OneOf::Left(true)),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
         document_symbol_provider: Some(OneOf::Left(true)),
@@ -117,7 +129,13 @@ fn main() -> Result<(), Box<dyn Error>> {
             work_done_progress_options: WorkDoneProgressOptions::default(),
         })),
         document_formatting_provider: Some(OneOf::Left(true)),
+        document_range_formatting_provider: Some(OneOf::Left(true)),
+        document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+            first_trigger_character: ";".to_string(),
+            more_trigger_character: Some(vec!["|".to_string()]),
+        }),
         code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
         ..Default::default()
     })
     .unwrap();
@@ -143,12 +161,39 @@ fn main_loop(connection: Arc<Connection>, config: Config) -> Result<(), Box<dyn
         config.initialization_options()
     );
     // First initialize the server with the lookahead from the server invocation
-    let server = RefCell::new(server::Server::new(config.lookahead()));
+    let server = RefCell::new(server::Server::new(config.lookahead(), connection.clone()));
     // Then update properties from client configuration (i.e. settings).
     server
         .borrow_mut()
         .update_configuration(config.config_properties())?;
 
+    server
+        .borrow_mut()
+        .index_workspace(&config.workspace_folders());
+
+    if config.supports_dynamic_registration_for_watched_files() {
+        let register_options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: ["**/*.par", "**/build.rs"]
+                .into_iter()
+                .map(|pattern| FileSystemWatcher {
+                    glob_pattern: GlobPattern::String(pattern.to_string()),
+                    kind: None,
+                })
+                .collect(),
+        };
+        send_request::<RegisterCapability>(
+            connection.clone(),
+            RegistrationParams {
+                registrations: vec![Registration {
+                    id: "workspace/didChangeWatchedFiles".to_string(),
+                    method: "workspace/didChangeWatchedFiles".to_string(),
+                    register_options: Some(serde_json::to_value(register_options)?),
+                }],
+            },
+            &server,
+        )?;
+    }
+
     if config.supports_dynamic_registration_for_change_config() {
         send_request::<RegisterCapability>(
             connection.clone(),
@@ -189,9 +234,39 @@ fn main_loop(connection: Arc<Connection>, config: Config) -> Result<(), Box<dyn
                     <Formatting as lsp_types::request::Request>::METHOD => {
                         request_match!(Formatting, server, connection, req);
                     }
+                    <RangeFormatting as lsp_types::request::Request>::METHOD => {
+                        request_match!(RangeFormatting, server, connection, req);
+                    }
+                    <OnTypeFormatting as lsp_types::request::Request>::METHOD => {
+                        request_match!(OnTypeFormatting, server, connection, req);
+                    }
                     <CodeActionRequest as lsp_types::request::Request>::METHOD => {
                         request_match!(CodeActionRequest, server, connection, req);
                     }
+                    <WorkspaceSymbolRequest as lsp_types::request::Request>::METHOD => {
+                        request_match!(WorkspaceSymbolRequest, server, connection, req);
+                    }
+                    <TryInput as lsp_types::request::Request>::METHOD => {
+                        let (id, params) = match cast::<TryInput>(req) {
+                            Ok(request) => request,
+                            Err(err @ ExtractError::JsonError { .. }) => panic!("{:?}", err),
+                            Err(ExtractError::MethodMismatch(req)) => {
+                                eprintln!("Unhandled request {}", req.method);
+                                continue;
+                            }
+                        };
+                        eprintln!(
+                            "got tryInput request #{id} for {:?}",
+                            params.text_document.uri
+                        );
+                        // Usually the analysis worker sends the response
+                        if let Some(result) = server.borrow().handle_try_input(id.clone(), params) {
+                            let result = serde_json::to_value(result)?;
+                            connection
+                                .sender
+                                .send(Message::Response(Response::new_ok(id, result)))?;
+                        }
+                    }
                     _ => {
                         eprintln!("Unhandled request {}", req.method);
                     }
@@ -221,6 +296,7 @@ fn process_notification(
             .handle_change_document(connection, not)?,
         DidCloseTextDocument::METHOD => server.borrow_mut().handle_close_document(not)?,
         DidChangeConfiguration::METHOD => server.borrow_mut().handle_changed_configuration(not)?,
+        DidChangeWatchedFiles::METHOD => server.borrow_mut().handle_changed_watched_files(not)?,
         _ => {}
     }
     Ok(())
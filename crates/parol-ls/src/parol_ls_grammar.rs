@@ -3,19 +3,19 @@ use crate::{
     parol_ls_grammar_trait::{
         self, ASTControl, Declaration, NonTerminal, ParolLs, ParolLsGrammarTrait, Production,
         ProductionLHS, Prolog, ScannerDirectives, ScannerState, ScannerStateDirectives,
-        SimpleToken, StartDeclaration, TokenLiteral, TokenWithStates, UserTypeDeclaration,
-        UserTypeName,
+        SimpleToken, StartDeclaration, TokenExpression, TokenLiteral, TokenWithStates,
+        UserTypeDeclaration, UserTypeName,
     },
     rng::Rng,
     symbol_def::SymbolDefs,
     utils::{extract_text_range, location_to_range, to_markdown},
 };
 use lsp_types::{
-    DocumentChanges, DocumentFormattingParams, DocumentSymbol, DocumentSymbolParams,
-    DocumentSymbolResponse, Hover, HoverContents::Markup, HoverParams, MarkupContent, MarkupKind,
-    OneOf, OptionalVersionedTextDocumentIdentifier, Position, PrepareRenameResponse, Range,
-    RenameParams, SymbolKind, TextDocumentEdit, TextDocumentPositionParams, TextEdit,
-    WorkspaceEdit,
+    DocumentChanges, DocumentFormattingParams, DocumentOnTypeFormattingParams,
+    DocumentRangeFormattingParams, DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse,
+    Hover, HoverContents::Markup, HoverParams, MarkupContent, MarkupKind, OneOf,
+    OptionalVersionedTextDocumentIdentifier, Position, PrepareRenameResponse, Range, RenameParams,
+    SymbolKind, TextDocumentEdit, TextDocumentPositionParams, TextEdit, WorkspaceEdit,
 };
 use parol::TerminalKind;
 #[allow(unused_imports)]
@@ -52,6 +52,9 @@ pub struct ParolLsGrammar {
     // Terminal type definition and references
     pub(crate) terminal_type: SymbolDefs,
 
+    // Ranges of the terminals in the productions, keyed by their expanded regular expression
+    pub(crate) terminal_definitions: HashMap<String, Vec<Range>>,
+
     // A hash that maps non-terminals to their productions
     pub productions: HashMap<String, Vec<Production>>,
 
@@ -95,6 +98,17 @@ impl ParolLsGrammar {
         self.non_terminal_definitions.find_definitions(non_terminal)
     }
 
+    ///
+    /// Returns the range of the first occurrence of the terminal with the given expanded regular
+    /// expression. The first occurrence determines the precedence of the terminal in the scanner.
+    ///
+    pub(crate) fn find_terminal_definition(&self, pattern: &str) -> Option<Range> {
+        self.terminal_definitions
+            .get(pattern)
+            .and_then(|ranges| ranges.first())
+            .copied()
+    }
+
     fn find_non_terminal_range(&self, non_terminal: &str, position: Position) -> Option<&Range> {
         self.non_terminal_definitions
             .find_reference_range(non_terminal, position)
@@ -240,6 +254,48 @@ impl ParolLsGrammar {
                     ]),
                 });
             }
+            ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                block_comment,
+            ) => {
+                #[allow(deprecated)]
+                symbols.push(DocumentSymbol {
+                    name: block_comment
+                        .percent_nested_underscore_block_underscore_comment
+                        .text()
+                        .to_string(),
+                    detail: Some("Nested block comment for the scanner state".to_string()),
+                    kind: SymbolKind::PROPERTY,
+                    tags: None,
+                    deprecated: None,
+                    range: Into::<Rng>::into(arg).0,
+                    selection_range: Into::<Rng>::into(
+                        &block_comment.percent_nested_underscore_block_underscore_comment,
+                    )
+                    .0,
+                    children: Some(vec![
+                        DocumentSymbol {
+                            name: Self::expanded_token_literal(&block_comment.token_literal),
+                            detail: Some("Text".to_string()),
+                            kind: SymbolKind::STRING,
+                            tags: None,
+                            deprecated: None,
+                            range: Into::<Rng>::into(arg).0,
+                            selection_range: Into::<Rng>::into(&block_comment.token_literal).0,
+                            children: None,
+                        },
+                        DocumentSymbol {
+                            name: Self::expanded_token_literal(&block_comment.token_literal0),
+                            detail: Some("Text".to_string()),
+                            kind: SymbolKind::STRING,
+                            tags: None,
+                            deprecated: None,
+                            range: Into::<Rng>::into(arg).0,
+                            selection_range: Into::<Rng>::into(&block_comment.token_literal0).0,
+                            children: None,
+                        },
+                    ]),
+                });
+            }
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(auto_newline) => {
                 #[allow(deprecated)]
                 symbols.push(DocumentSymbol {
@@ -310,6 +366,64 @@ impl ParolLsGrammar {
 
                 symbols.push(skip_directive);
             }
+            ScannerDirectives::PercentExternalIdentifierList(external) => {
+                // Add the reference to the non-terminal for hover and rename support
+                // This is the first non-terminal in the struct `identifier_list`
+                self.add_non_terminal_ref(&external.identifier_list.identifier.identifier);
+
+                let mut first_id: DocumentSymbol = (&external.identifier_list.identifier.identifier).into();
+                first_id.detail = Some("External terminal".to_string());
+
+                let children: Vec<DocumentSymbol> = external
+                    .identifier_list
+                    .identifier_list_list
+                    .iter()
+                    .fold(vec![first_id], |mut acc, id| {
+                        let mut id_sym: DocumentSymbol = (&id.identifier.identifier).into();
+                        id_sym.detail = Some("External terminal".to_string());
+
+                        // Add the reference to the non-terminal for hover and rename support
+                        self.add_non_terminal_ref(&id.identifier.identifier);
+
+                        acc.push(id_sym);
+                        acc
+                    });
+
+                let mut external_directive: DocumentSymbol = (&external.percent_external).into();
+                external_directive.detail = Some("Terminals scanned by user code".to_string());
+                external_directive.range = Into::<Rng>::into(arg).0;
+                external_directive.selection_range = Into::<Rng>::into(&external.percent_external).0;
+                external_directive.kind = SymbolKind::PROPERTY;
+                external_directive.children = Some(children);
+
+                symbols.push(external_directive);
+            }
+            ScannerDirectives::PercentOffsideIndentDedentNewline(offside) => {
+                let children = [
+                    (&offside.indent, "Indentation terminal"),
+                    (&offside.dedent, "Dedentation terminal"),
+                    (&offside.newline, "End of line terminal"),
+                ]
+                .into_iter()
+                .map(|(id, detail)| {
+                    // Add the reference to the non-terminal for hover and rename support
+                    self.add_non_terminal_ref(&id.identifier);
+
+                    let mut id_sym: DocumentSymbol = (&id.identifier).into();
+                    id_sym.detail = Some(detail.to_string());
+                    id_sym
+                })
+                .collect::<Vec<DocumentSymbol>>();
+
+                let mut offside_directive: DocumentSymbol = (&offside.percent_offside).into();
+                offside_directive.detail = Some("Offside rule for the scanner state".to_string());
+                offside_directive.range = Into::<Rng>::into(arg).0;
+                offside_directive.selection_range = Into::<Rng>::into(&offside.percent_offside).0;
+                offside_directive.kind = SymbolKind::PROPERTY;
+                offside_directive.children = Some(children);
+
+                symbols.push(offside_directive);
+            }
             ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(trans) => {
                 // Add the reference to the non-terminal for hover and rename support
                 // This is the first non-terminal in the struct `identifier_list`
@@ -580,6 +694,21 @@ impl ParolLsGrammar {
         }
     }
 
+    pub(crate) fn format_range(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Option<Vec<TextEdit>> {
+        crate::formatting::format_range(self, params.range, &params.options)
+    }
+
+    pub(crate) fn format_on_type(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Option<Vec<TextEdit>> {
+        let position = params.text_document_position.position;
+        crate::formatting::format_range(self, Range::new(position, position), &params.options)
+    }
+
     fn trim_quotes(string: &str) -> String {
         let delimiters: &[_] = &['"', '\'', '/'];
         string
@@ -597,6 +726,16 @@ impl ParolLsGrammar {
             }
             TokenLiteral::LiteralString(l) => TerminalKind::Raw
                 .expand(Self::trim_quotes(l.literal_string.literal_string.text()).as_str()),
+            TokenLiteral::CaseInsensitiveLiteralString(l) => {
+                let text = l
+                    .case_insensitive_literal_string
+                    .case_insensitive_literal_string
+                    .text();
+                let text = text
+                    .strip_suffix(TerminalKind::RawCaseInsensitive.suffix())
+                    .unwrap_or(text);
+                TerminalKind::RawCaseInsensitive.expand(&Self::trim_quotes(text))
+            }
             TokenLiteral::Regex(r) => {
                 TerminalKind::Regex.expand(Self::trim_quotes(r.regex.regex.text()).as_str())
             }
@@ -770,6 +909,39 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
                     }]),
                 });
             }
+            Declaration::PercentEntryIdentifierList(entry) => {
+                // Add the reference to the non-terminal for hover and rename support
+                // This is the first non-terminal in the struct `identifier_list`
+                self.add_non_terminal_ref(&entry.identifier_list.identifier.identifier);
+
+                let mut first_id: DocumentSymbol =
+                    (&entry.identifier_list.identifier.identifier).into();
+                first_id.detail = Some("Entry point".to_string());
+
+                let children: Vec<DocumentSymbol> = entry
+                    .identifier_list
+                    .identifier_list_list
+                    .iter()
+                    .fold(vec![first_id], |mut acc, id| {
+                        let mut id_sym: DocumentSymbol = (&id.identifier.identifier).into();
+                        id_sym.detail = Some("Entry point".to_string());
+
+                        // Add the reference to the non-terminal for hover and rename support
+                        self.add_non_terminal_ref(&id.identifier.identifier);
+
+                        acc.push(id_sym);
+                        acc
+                    });
+
+                let mut entry_directive: DocumentSymbol = (&entry.percent_entry).into();
+                entry_directive.detail = Some("Additional start symbols".to_string());
+                entry_directive.range = Into::<Rng>::into(arg).0;
+                entry_directive.selection_range = Into::<Rng>::into(&entry.percent_entry).0;
+                entry_directive.kind = SymbolKind::PROPERTY;
+                entry_directive.children = Some(children);
+
+                self.symbols.push(entry_directive);
+            }
             Declaration::PercentNtUnderscoreTypeNtNameEquNtType(nt_type) => {
                 // Allow the non-terminal to be renamed
                 self.add_non_terminal_ref(&nt_type.nt_name.identifier);
@@ -904,6 +1076,15 @@ impl ParolLsGrammarTrait for ParolLsGrammar {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'TokenExpression'
+    fn token_expression(&mut self, arg: &TokenExpression) -> Result<()> {
+        self.terminal_definitions
+            .entry(Self::expanded_token_literal(&arg.token_literal))
+            .or_default()
+            .push(Into::<Rng>::into(&arg.token_literal).0);
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Production'
     fn production(&mut self, arg: &Production) -> Result<()> {
         let nt = arg
@@ -79,6 +79,11 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Predicate'
+    fn predicate(&mut self, _arg: &Predicate) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Factor'
     fn factor(&mut self, _arg: &Factor) -> Result<()> {
         Ok(())
@@ -144,6 +149,14 @@ pub trait ParolLsGrammarTrait {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CaseInsensitiveLiteralString'
+    fn case_insensitive_literal_string(
+        &mut self,
+        _arg: &CaseInsensitiveLiteralString,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'ScannerState'
     fn scanner_state(&mut self, _arg: &ScannerState) -> Result<()> {
         Ok(())
@@ -288,6 +301,18 @@ pub struct DeclarationPercentGrammarUnderscoreTypeLiteralString {
 ///
 /// Type derived for production 13
 ///
+/// `Declaration: '%entry' IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeclarationPercentEntryIdentifierList {
+    pub percent_entry: crate::parol_ls_grammar::OwnedToken, /* %entry */
+    pub identifier_list: IdentifierList,
+}
+
+///
+/// Type derived for production 14
+///
 /// `Declaration: ScannerDirectives;`
 ///
 #[allow(dead_code)]
@@ -297,7 +322,7 @@ pub struct DeclarationScannerDirectives {
 }
 
 ///
-/// Type derived for production 14
+/// Type derived for production 15
 ///
 /// `ScannerDirectives: "%line_comment" TokenLiteral;`
 ///
@@ -309,7 +334,7 @@ pub struct ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral {
 }
 
 ///
-/// Type derived for production 15
+/// Type derived for production 16
 ///
 /// `ScannerDirectives: "%block_comment" TokenLiteral TokenLiteral;`
 ///
@@ -322,7 +347,20 @@ pub struct ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLitera
 }
 
 ///
-/// Type derived for production 16
+/// Type derived for production 17
+///
+/// `ScannerDirectives: "%nested_block_comment" TokenLiteral TokenLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral {
+    pub percent_nested_underscore_block_underscore_comment: crate::parol_ls_grammar::OwnedToken, /* %nested_block_comment */
+    pub token_literal: TokenLiteral,
+    pub token_literal0: TokenLiteral,
+}
+
+///
+/// Type derived for production 18
 ///
 /// `ScannerDirectives: "%auto_newline_off";`
 ///
@@ -333,7 +371,7 @@ pub struct ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {
 }
 
 ///
-/// Type derived for production 17
+/// Type derived for production 19
 ///
 /// `ScannerDirectives: "%auto_ws_off";`
 ///
@@ -344,7 +382,7 @@ pub struct ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {
 }
 
 ///
-/// Type derived for production 18
+/// Type derived for production 20
 ///
 /// `ScannerDirectives: '%skip' IdentifierList;`
 ///
@@ -356,7 +394,33 @@ pub struct ScannerDirectivesPercentSkipIdentifierList {
 }
 
 ///
-/// Type derived for production 19
+/// Type derived for production 21
+///
+/// `ScannerDirectives: '%offside' Identifier@indent Identifier@dedent Identifier@newline;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ScannerDirectivesPercentOffsideIndentDedentNewline {
+    pub percent_offside: crate::parol_ls_grammar::OwnedToken, /* %offside */
+    pub indent: Identifier,
+    pub dedent: Identifier,
+    pub newline: Identifier,
+}
+
+///
+/// Type derived for production 22
+///
+/// `ScannerDirectives: '%external' IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ScannerDirectivesPercentExternalIdentifierList {
+    pub percent_external: crate::parol_ls_grammar::OwnedToken, /* %external */
+    pub identifier_list: IdentifierList,
+}
+
+///
+/// Type derived for production 23
 ///
 /// `ScannerDirectives: '%on' IdentifierList ScannerStateDirectives;`
 ///
@@ -369,7 +433,7 @@ pub struct ScannerDirectivesPercentOnIdentifierListScannerStateDirectives {
 }
 
 ///
-/// Type derived for production 20
+/// Type derived for production 24
 ///
 /// `ScannerDirectives: "%allow_unmatched";`
 ///
@@ -380,7 +444,7 @@ pub struct ScannerDirectivesPercentAllowUnderscoreUnmatched {
 }
 
 ///
-/// Type derived for production 21
+/// Type derived for production 25
 ///
 /// `ScannerStateDirectives: '%enter' Identifier;`
 ///
@@ -392,7 +456,7 @@ pub struct ScannerStateDirectivesPercentEnterIdentifier {
 }
 
 ///
-/// Type derived for production 22
+/// Type derived for production 26
 ///
 /// `ScannerStateDirectives: '%push' Identifier;`
 ///
@@ -404,7 +468,7 @@ pub struct ScannerStateDirectivesPercentPushIdentifier {
 }
 
 ///
-/// Type derived for production 23
+/// Type derived for production 27
 ///
 /// `ScannerStateDirectives: '%pop';`
 ///
@@ -415,7 +479,7 @@ pub struct ScannerStateDirectivesPercentPop {
 }
 
 ///
-/// Type derived for production 36
+/// Type derived for production 43
 ///
 /// `Factor: Group;`
 ///
@@ -426,7 +490,7 @@ pub struct FactorGroup {
 }
 
 ///
-/// Type derived for production 37
+/// Type derived for production 44
 ///
 /// `Factor: Repeat;`
 ///
@@ -437,7 +501,7 @@ pub struct FactorRepeat {
 }
 
 ///
-/// Type derived for production 38
+/// Type derived for production 45
 ///
 /// `Factor: Optional;`
 ///
@@ -448,7 +512,7 @@ pub struct FactorOptional {
 }
 
 ///
-/// Type derived for production 39
+/// Type derived for production 46
 ///
 /// `Factor: Symbol;`
 ///
@@ -459,7 +523,7 @@ pub struct FactorSymbol {
 }
 
 ///
-/// Type derived for production 40
+/// Type derived for production 47
 ///
 /// `Symbol: NonTerminal;`
 ///
@@ -470,7 +534,7 @@ pub struct SymbolNonTerminal {
 }
 
 ///
-/// Type derived for production 41
+/// Type derived for production 48
 ///
 /// `Symbol: SimpleToken;`
 ///
@@ -481,7 +545,7 @@ pub struct SymbolSimpleToken {
 }
 
 ///
-/// Type derived for production 42
+/// Type derived for production 49
 ///
 /// `Symbol: TokenWithStates;`
 ///
@@ -492,7 +556,7 @@ pub struct SymbolTokenWithStates {
 }
 
 ///
-/// Type derived for production 43
+/// Type derived for production 50
 ///
 /// `TokenLiteral: String;`
 ///
@@ -503,7 +567,7 @@ pub struct TokenLiteralString {
 }
 
 ///
-/// Type derived for production 44
+/// Type derived for production 51
 ///
 /// `TokenLiteral: LiteralString;`
 ///
@@ -514,7 +578,18 @@ pub struct TokenLiteralLiteralString {
 }
 
 ///
-/// Type derived for production 45
+/// Type derived for production 52
+///
+/// `TokenLiteral: CaseInsensitiveLiteralString;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TokenLiteralCaseInsensitiveLiteralString {
+    pub case_insensitive_literal_string: CaseInsensitiveLiteralString,
+}
+
+///
+/// Type derived for production 53
 ///
 /// `TokenLiteral: Regex;`
 ///
@@ -525,7 +600,7 @@ pub struct TokenLiteralRegex {
 }
 
 ///
-/// Type derived for production 70
+/// Type derived for production 79
 ///
 /// `ASTControl: CutOperator;`
 ///
@@ -536,7 +611,7 @@ pub struct ASTControlCutOperator {
 }
 
 ///
-/// Type derived for production 71
+/// Type derived for production 80
 ///
 /// `ASTControl: MemberName ASTControlOpt /* Option */;`
 ///
@@ -548,7 +623,7 @@ pub struct ASTControlMemberNameASTControlOpt {
 }
 
 ///
-/// Type derived for production 72
+/// Type derived for production 81
 ///
 /// `ASTControl: UserTypeDeclaration;`
 ///
@@ -559,7 +634,7 @@ pub struct ASTControlUserTypeDeclaration {
 }
 
 ///
-/// Type derived for production 83
+/// Type derived for production 92
 ///
 /// `LookAheadGroup: PositiveLookahead;`
 ///
@@ -570,7 +645,7 @@ pub struct LookAheadGroupPositiveLookahead {
 }
 
 ///
-/// Type derived for production 84
+/// Type derived for production 93
 ///
 /// `LookAheadGroup: NegativeLookahead;`
 ///
@@ -611,6 +686,7 @@ pub struct ASTControlOpt {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Alternation {
+    pub alternation_opt: Option<AlternationOpt>,
     pub alternation_list: Vec<AlternationList>,
 }
 
@@ -623,6 +699,15 @@ pub struct AlternationList {
     pub factor: Factor,
 }
 
+///
+/// Type derived for non-terminal AlternationOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct AlternationOpt {
+    pub predicate: Predicate,
+}
+
 ///
 /// Type derived for non-terminal Alternations
 ///
@@ -643,6 +728,15 @@ pub struct AlternationsList {
     pub alternation: Alternation,
 }
 
+///
+/// Type derived for non-terminal CaseInsensitiveLiteralString
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct CaseInsensitiveLiteralString {
+    pub case_insensitive_literal_string: crate::parol_ls_grammar::OwnedToken, /* '(\\.|[^'])*'i */
+}
+
 ///
 /// Type derived for non-terminal CutOperator
 ///
@@ -666,6 +760,7 @@ pub enum Declaration {
     PercentNtUnderscoreTypeNtNameEquNtType(DeclarationPercentNtUnderscoreTypeNtNameEquNtType),
     PercentTUnderscoreTypeTType(DeclarationPercentTUnderscoreTypeTType),
     PercentGrammarUnderscoreTypeLiteralString(DeclarationPercentGrammarUnderscoreTypeLiteralString),
+    PercentEntryIdentifierList(DeclarationPercentEntryIdentifierList),
     ScannerDirectives(DeclarationScannerDirectives),
 }
 
@@ -846,6 +941,17 @@ pub struct PositiveLookahead {
     pub positive_lookahead: crate::parol_ls_grammar::OwnedToken, /* ?= */
 }
 
+///
+/// Type derived for non-terminal Predicate
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Predicate {
+    pub amp_l_brace: crate::parol_ls_grammar::OwnedToken, /* &{ */
+    pub identifier: Identifier,
+    pub r_brace: crate::parol_ls_grammar::OwnedToken, /* } */
+}
+
 ///
 /// Type derived for non-terminal Production
 ///
@@ -928,11 +1034,16 @@ pub enum ScannerDirectives {
     PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
         ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral,
     ),
+    PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+        ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral,
+    ),
     PercentAutoUnderscoreNewlineUnderscoreOff(
         ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff,
     ),
     PercentAutoUnderscoreWsUnderscoreOff(ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff),
     PercentSkipIdentifierList(ScannerDirectivesPercentSkipIdentifierList),
+    PercentOffsideIndentDedentNewline(ScannerDirectivesPercentOffsideIndentDedentNewline),
+    PercentExternalIdentifierList(ScannerDirectivesPercentExternalIdentifierList),
     PercentOnIdentifierListScannerStateDirectives(
         ScannerDirectivesPercentOnIdentifierListScannerStateDirectives,
     ),
@@ -1048,6 +1159,7 @@ pub struct TokenExpressionOpt {
 pub enum TokenLiteral {
     String(TokenLiteralString),
     LiteralString(TokenLiteralLiteralString),
+    CaseInsensitiveLiteralString(TokenLiteralCaseInsensitiveLiteralString),
     Regex(TokenLiteralRegex),
 }
 
@@ -1115,8 +1227,10 @@ pub enum ASTType {
     ASTControlOpt(Option<ASTControlOpt>),
     Alternation(Alternation),
     AlternationList(Vec<AlternationList>),
+    AlternationOpt(Option<AlternationOpt>),
     Alternations(Alternations),
     AlternationsList(Vec<AlternationsList>),
+    CaseInsensitiveLiteralString(CaseInsensitiveLiteralString),
     CutOperator(CutOperator),
     Declaration(Declaration),
     DoubleColon(DoubleColon),
@@ -1137,6 +1251,7 @@ pub enum ASTType {
     Optional(Optional),
     ParolLs(ParolLs),
     PositiveLookahead(PositiveLookahead),
+    Predicate(Predicate),
     Production(Production),
     ProductionLHS(ProductionLHS),
     Prolog(Prolog),
@@ -1565,15 +1680,26 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 13:
     ///
-    /// `Declaration: ScannerDirectives;`
+    /// `Declaration: '%entry' IdentifierList;`
     ///
     #[parol_runtime::function_name::named]
-    fn declaration_6(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+    fn declaration_6(
+        &mut self,
+        percent_entry: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
-        let declaration_6_built = DeclarationScannerDirectives { scanner_directives };
-        let declaration_6_built = Declaration::ScannerDirectives(declaration_6_built);
+        let percent_entry = percent_entry
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let declaration_6_built = DeclarationPercentEntryIdentifierList {
+            percent_entry,
+            identifier_list,
+        };
+        let declaration_6_built = Declaration::PercentEntryIdentifierList(declaration_6_built);
         // Calling user action here
         self.user_grammar.declaration(&declaration_6_built)?;
         self.push(ASTType::Declaration(declaration_6_built), context);
@@ -1582,6 +1708,23 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 14:
     ///
+    /// `Declaration: ScannerDirectives;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_7(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
+        let declaration_7_built = DeclarationScannerDirectives { scanner_directives };
+        let declaration_7_built = Declaration::ScannerDirectives(declaration_7_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_7_built)?;
+        self.push(ASTType::Declaration(declaration_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
     /// `ScannerDirectives: "%line_comment" TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
@@ -1614,7 +1757,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 16:
     ///
     /// `ScannerDirectives: "%block_comment" TokenLiteral TokenLiteral;`
     ///
@@ -1653,12 +1796,48 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 17:
     ///
-    /// `ScannerDirectives: "%auto_newline_off";`
+    /// `ScannerDirectives: "%nested_block_comment" TokenLiteral TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
     fn scanner_directives_2(
+        &mut self,
+        percent_nested_underscore_block_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+        _token_literal0: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_nested_underscore_block_underscore_comment =
+            percent_nested_underscore_block_underscore_comment
+                .token()?
+                .try_into()
+                .map_err(parol_runtime::ParolError::UserError)?;
+        let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_2_built = ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral {
+            percent_nested_underscore_block_underscore_comment,
+            token_literal,
+            token_literal0,
+        };
+        let scanner_directives_2_built = ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(scanner_directives_2_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_2_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_2_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 18:
+    ///
+    /// `ScannerDirectives: "%auto_newline_off";`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_3(
         &mut self,
         percent_auto_underscore_newline_underscore_off: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -1669,30 +1848,30 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
                 .token()?
                 .try_into()
                 .map_err(parol_runtime::ParolError::UserError)?;
-        let scanner_directives_2_built =
+        let scanner_directives_3_built =
             ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {
                 percent_auto_underscore_newline_underscore_off,
             };
-        let scanner_directives_2_built =
+        let scanner_directives_3_built =
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(
-                scanner_directives_2_built,
+                scanner_directives_3_built,
             );
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_2_built)?;
+            .scanner_directives(&scanner_directives_3_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_2_built),
+            ASTType::ScannerDirectives(scanner_directives_3_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 19:
     ///
     /// `ScannerDirectives: "%auto_ws_off";`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_3(
+    fn scanner_directives_4(
         &mut self,
         percent_auto_underscore_ws_underscore_off: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -1702,27 +1881,27 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let scanner_directives_3_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {
+        let scanner_directives_4_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {
             percent_auto_underscore_ws_underscore_off,
         };
-        let scanner_directives_3_built =
-            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_3_built);
+        let scanner_directives_4_built =
+            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_4_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_3_built)?;
+            .scanner_directives(&scanner_directives_4_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_3_built),
+            ASTType::ScannerDirectives(scanner_directives_4_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 20:
     ///
     /// `ScannerDirectives: '%skip' IdentifierList;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_4(
+    fn scanner_directives_5(
         &mut self,
         percent_skip: &ParseTreeType<'t>,
         _identifier_list: &ParseTreeType<'t>,
@@ -1734,28 +1913,100 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
         let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let scanner_directives_4_built = ScannerDirectivesPercentSkipIdentifierList {
+        let scanner_directives_5_built = ScannerDirectivesPercentSkipIdentifierList {
             percent_skip,
             identifier_list,
         };
-        let scanner_directives_4_built =
-            ScannerDirectives::PercentSkipIdentifierList(scanner_directives_4_built);
+        let scanner_directives_5_built =
+            ScannerDirectives::PercentSkipIdentifierList(scanner_directives_5_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_4_built)?;
+            .scanner_directives(&scanner_directives_5_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_4_built),
+            ASTType::ScannerDirectives(scanner_directives_5_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 21:
+    ///
+    /// `ScannerDirectives: '%offside' Identifier@indent Identifier@dedent Identifier@newline;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_6(
+        &mut self,
+        percent_offside: &ParseTreeType<'t>,
+        _indent: &ParseTreeType<'t>,
+        _dedent: &ParseTreeType<'t>,
+        _newline: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_offside = percent_offside
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let newline = pop_item!(self, newline, Identifier, context);
+        let dedent = pop_item!(self, dedent, Identifier, context);
+        let indent = pop_item!(self, indent, Identifier, context);
+        let scanner_directives_6_built = ScannerDirectivesPercentOffsideIndentDedentNewline {
+            percent_offside,
+            indent,
+            dedent,
+            newline,
+        };
+        let scanner_directives_6_built =
+            ScannerDirectives::PercentOffsideIndentDedentNewline(scanner_directives_6_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_6_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_6_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 22:
+    ///
+    /// `ScannerDirectives: '%external' IdentifierList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_7(
+        &mut self,
+        percent_external: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let percent_external = percent_external
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let scanner_directives_7_built = ScannerDirectivesPercentExternalIdentifierList {
+            percent_external,
+            identifier_list,
+        };
+        let scanner_directives_7_built =
+            ScannerDirectives::PercentExternalIdentifierList(scanner_directives_7_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_7_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_7_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 23:
     ///
     /// `ScannerDirectives: '%on' IdentifierList ScannerStateDirectives;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_5(
+    fn scanner_directives_8(
         &mut self,
         percent_on: &ParseTreeType<'t>,
         _identifier_list: &ParseTreeType<'t>,
@@ -1774,32 +2025,32 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
             context
         );
         let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let scanner_directives_5_built =
+        let scanner_directives_8_built =
             ScannerDirectivesPercentOnIdentifierListScannerStateDirectives {
                 percent_on,
                 identifier_list,
                 scanner_state_directives,
             };
-        let scanner_directives_5_built =
+        let scanner_directives_8_built =
             ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(
-                scanner_directives_5_built,
+                scanner_directives_8_built,
             );
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_5_built)?;
+            .scanner_directives(&scanner_directives_8_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_5_built),
+            ASTType::ScannerDirectives(scanner_directives_8_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 24:
     ///
     /// `ScannerDirectives: "%allow_unmatched";`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_6(
+    fn scanner_directives_9(
         &mut self,
         percent_allow_underscore_unmatched: &ParseTreeType<'t>,
     ) -> Result<()> {
@@ -1809,22 +2060,22 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
             .token()?
             .try_into()
             .map_err(parol_runtime::ParolError::UserError)?;
-        let scanner_directives_6_built = ScannerDirectivesPercentAllowUnderscoreUnmatched {
+        let scanner_directives_9_built = ScannerDirectivesPercentAllowUnderscoreUnmatched {
             percent_allow_underscore_unmatched,
         };
-        let scanner_directives_6_built =
-            ScannerDirectives::PercentAllowUnderscoreUnmatched(scanner_directives_6_built);
+        let scanner_directives_9_built =
+            ScannerDirectives::PercentAllowUnderscoreUnmatched(scanner_directives_9_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_6_built)?;
+            .scanner_directives(&scanner_directives_9_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_6_built),
+            ASTType::ScannerDirectives(scanner_directives_9_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 25:
     ///
     /// `ScannerStateDirectives: '%enter' Identifier;`
     ///
@@ -1857,7 +2108,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 26:
     ///
     /// `ScannerStateDirectives: '%push' Identifier;`
     ///
@@ -1890,7 +2141,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 27:
     ///
     /// `ScannerStateDirectives: '%pop';`
     ///
@@ -1915,7 +2166,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 28:
     ///
     /// `GrammarDefinition: "%%" Production GrammarDefinitionList /* Vec */;`
     ///
@@ -1954,7 +2205,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 29:
     ///
     /// `GrammarDefinitionList /* Vec<T>::Push */: Production GrammarDefinitionList;`
     ///
@@ -1983,7 +2234,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 30:
     ///
     /// `GrammarDefinitionList /* Vec<T>::New */: ;`
     ///
@@ -1999,7 +2250,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 27:
+    /// Semantic action for production 31:
     ///
     /// `DoubleColon: "::";`
     ///
@@ -2018,7 +2269,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 28:
+    /// Semantic action for production 32:
     ///
     /// `ProductionLHS: Identifier ":";`
     ///
@@ -2043,7 +2294,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 29:
+    /// Semantic action for production 33:
     ///
     /// `Production: ProductionLHS Alternations ";";`
     ///
@@ -2073,7 +2324,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 30:
+    /// Semantic action for production 34:
     ///
     /// `Alternations: Alternation AlternationsList /* Vec */;`
     ///
@@ -2098,7 +2349,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 31:
+    /// Semantic action for production 35:
     ///
     /// `AlternationsList /* Vec<T>::Push */: '|' Alternation AlternationsList;`
     ///
@@ -2124,7 +2375,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 32:
+    /// Semantic action for production 36:
     ///
     /// `AlternationsList /* Vec<T>::New */: ;`
     ///
@@ -2140,24 +2391,32 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 37:
     ///
-    /// `Alternation: AlternationList /* Vec */;`
+    /// `Alternation: AlternationOpt /* Option */ AlternationList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternation(&mut self, _alternation_list: &ParseTreeType<'t>) -> Result<()> {
+    fn alternation(
+        &mut self,
+        _alternation_opt: &ParseTreeType<'t>,
+        _alternation_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let alternation_list =
             pop_and_reverse_item!(self, alternation_list, AlternationList, context);
-        let alternation_built = Alternation { alternation_list };
+        let alternation_opt = pop_item!(self, alternation_opt, AlternationOpt, context);
+        let alternation_built = Alternation {
+            alternation_opt,
+            alternation_list,
+        };
         // Calling user action here
         self.user_grammar.alternation(&alternation_built)?;
         self.push(ASTType::Alternation(alternation_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 38:
     ///
     /// `AlternationList /* Vec<T>::Push */: Factor AlternationList;`
     ///
@@ -2178,7 +2437,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 39:
     ///
     /// `AlternationList /* Vec<T>::New */: ;`
     ///
@@ -2191,7 +2450,69 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 40:
+    ///
+    /// `AlternationOpt /* Option<T>::Some */: Predicate;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_0(&mut self, _predicate: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let predicate = pop_item!(self, predicate, Predicate, context);
+        let alternation_opt_0_built = AlternationOpt { predicate };
+        self.push(
+            ASTType::AlternationOpt(Some(alternation_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// `AlternationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlternationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
+    ///
+    /// `Predicate: '&{' Identifier '}';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn predicate(
+        &mut self,
+        amp_l_brace: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let amp_l_brace = amp_l_brace
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let r_brace = r_brace
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let predicate_built = Predicate {
+            amp_l_brace,
+            identifier,
+            r_brace,
+        };
+        // Calling user action here
+        self.user_grammar.predicate(&predicate_built)?;
+        self.push(ASTType::Predicate(predicate_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 43:
     ///
     /// `Factor: Group;`
     ///
@@ -2208,7 +2529,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 44:
     ///
     /// `Factor: Repeat;`
     ///
@@ -2225,7 +2546,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 45:
     ///
     /// `Factor: Optional;`
     ///
@@ -2242,7 +2563,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 46:
     ///
     /// `Factor: Symbol;`
     ///
@@ -2259,7 +2580,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 47:
     ///
     /// `Symbol: NonTerminal;`
     ///
@@ -2276,7 +2597,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 48:
     ///
     /// `Symbol: SimpleToken;`
     ///
@@ -2293,7 +2614,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 49:
     ///
     /// `Symbol: TokenWithStates;`
     ///
@@ -2310,7 +2631,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 50:
     ///
     /// `TokenLiteral: String;`
     ///
@@ -2327,7 +2648,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 51:
     ///
     /// `TokenLiteral: LiteralString;`
     ///
@@ -2344,24 +2665,52 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 52:
     ///
-    /// `TokenLiteral: Regex;`
+    /// `TokenLiteral: CaseInsensitiveLiteralString;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_literal_2(&mut self, _regex: &ParseTreeType<'t>) -> Result<()> {
+    fn token_literal_2(
+        &mut self,
+        _case_insensitive_literal_string: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let regex = pop_item!(self, regex, Regex, context);
-        let token_literal_2_built = TokenLiteralRegex { regex };
-        let token_literal_2_built = TokenLiteral::Regex(token_literal_2_built);
+        let case_insensitive_literal_string = pop_item!(
+            self,
+            case_insensitive_literal_string,
+            CaseInsensitiveLiteralString,
+            context
+        );
+        let token_literal_2_built = TokenLiteralCaseInsensitiveLiteralString {
+            case_insensitive_literal_string,
+        };
+        let token_literal_2_built =
+            TokenLiteral::CaseInsensitiveLiteralString(token_literal_2_built);
         // Calling user action here
         self.user_grammar.token_literal(&token_literal_2_built)?;
         self.push(ASTType::TokenLiteral(token_literal_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 53:
+    ///
+    /// `TokenLiteral: Regex;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn token_literal_3(&mut self, _regex: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let regex = pop_item!(self, regex, Regex, context);
+        let token_literal_3_built = TokenLiteralRegex { regex };
+        let token_literal_3_built = TokenLiteral::Regex(token_literal_3_built);
+        // Calling user action here
+        self.user_grammar.token_literal(&token_literal_3_built)?;
+        self.push(ASTType::TokenLiteral(token_literal_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 54:
     ///
     /// `TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;`
     ///
@@ -2387,7 +2736,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 55:
     ///
     /// `TokenExpressionOpt /* Option<T>::Some */: LookAhead;`
     ///
@@ -2404,7 +2753,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 56:
     ///
     /// `TokenExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -2416,7 +2765,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 57:
     ///
     /// `SimpleToken: TokenExpression SimpleTokenOpt /* Option */;`
     ///
@@ -2440,7 +2789,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 58:
     ///
     /// `SimpleTokenOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2457,7 +2806,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 59:
     ///
     /// `SimpleTokenOpt /* Option<T>::None */: ;`
     ///
@@ -2469,7 +2818,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 60:
     ///
     /// `TokenWithStates: "<" IdentifierList ">" TokenExpression TokenWithStatesOpt /* Option */;`
     ///
@@ -2510,7 +2859,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 61:
     ///
     /// `TokenWithStatesOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2527,7 +2876,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 62:
     ///
     /// `TokenWithStatesOpt /* Option<T>::None */: ;`
     ///
@@ -2539,7 +2888,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 63:
     ///
     /// `Group: '(' Alternations ')';`
     ///
@@ -2572,7 +2921,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 64:
     ///
     /// `Optional: '[' Alternations ']';`
     ///
@@ -2605,7 +2954,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 65:
     ///
     /// `Repeat: '{' Alternations '}';`
     ///
@@ -2638,7 +2987,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 66:
     ///
     /// `NonTerminal: Identifier NonTerminalOpt /* Option */;`
     ///
@@ -2662,7 +3011,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 67:
     ///
     /// `NonTerminalOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2679,7 +3028,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 68:
     ///
     /// `NonTerminalOpt /* Option<T>::None */: ;`
     ///
@@ -2691,7 +3040,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 69:
     ///
     /// `Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;`
     ///
@@ -2710,7 +3059,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 70:
     ///
     /// `String: /"(\\.|[^"])*"/;`
     ///
@@ -2729,7 +3078,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 71:
     ///
     /// `LiteralString: /'(\\.|[^'])*'/;`
     ///
@@ -2748,7 +3097,35 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 72:
+    ///
+    /// `CaseInsensitiveLiteralString: /'(\\.|[^'])*'i/ ?! /[a-zA-Z0-9_]/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn case_insensitive_literal_string(
+        &mut self,
+        case_insensitive_literal_string: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let case_insensitive_literal_string = case_insensitive_literal_string
+            .token()?
+            .try_into()
+            .map_err(parol_runtime::ParolError::UserError)?;
+        let case_insensitive_literal_string_built = CaseInsensitiveLiteralString {
+            case_insensitive_literal_string,
+        };
+        // Calling user action here
+        self.user_grammar
+            .case_insensitive_literal_string(&case_insensitive_literal_string_built)?;
+        self.push(
+            ASTType::CaseInsensitiveLiteralString(case_insensitive_literal_string_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 73:
     ///
     /// `ScannerState: "%scanner" Identifier '{' ScannerStateList /* Vec */ '}';`
     ///
@@ -2791,7 +3168,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 74:
     ///
     /// `ScannerStateList /* Vec<T>::Push */: ScannerDirectives ScannerStateList;`
     ///
@@ -2812,7 +3189,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 75:
     ///
     /// `ScannerStateList /* Vec<T>::New */: ;`
     ///
@@ -2828,7 +3205,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 76:
     ///
     /// `IdentifierList: Identifier IdentifierListList /* Vec */;`
     ///
@@ -2853,7 +3230,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 77:
     ///
     /// `IdentifierListList /* Vec<T>::Push */: "," Identifier IdentifierListList;`
     ///
@@ -2880,7 +3257,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 78:
     ///
     /// `IdentifierListList /* Vec<T>::New */: ;`
     ///
@@ -2896,7 +3273,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 79:
     ///
     /// `ASTControl: CutOperator;`
     ///
@@ -2913,7 +3290,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 80:
     ///
     /// `ASTControl: MemberName ASTControlOpt /* Option */;`
     ///
@@ -2938,7 +3315,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 81:
     ///
     /// `ASTControl: UserTypeDeclaration;`
     ///
@@ -2958,7 +3335,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 82:
     ///
     /// `ASTControlOpt /* Option<T>::Some */: UserTypeDeclaration;`
     ///
@@ -2978,7 +3355,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 83:
     ///
     /// `ASTControlOpt /* Option<T>::None */: ;`
     ///
@@ -2990,7 +3367,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 84:
     ///
     /// `MemberName: '@'^ /* Clipped */ Identifier;`
     ///
@@ -3010,7 +3387,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 85:
     ///
     /// `CutOperator: '^';`
     ///
@@ -3029,7 +3406,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 86:
     ///
     /// `UserTypeDeclaration: ":" UserTypeName;`
     ///
@@ -3060,7 +3437,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 87:
     ///
     /// `UserTypeName: Identifier UserTypeNameList /* Vec */;`
     ///
@@ -3085,7 +3462,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 88:
     ///
     /// `UserTypeNameList /* Vec<T>::Push */: DoubleColon Identifier UserTypeNameList;`
     ///
@@ -3112,7 +3489,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 89:
     ///
     /// `UserTypeNameList /* Vec<T>::New */: ;`
     ///
@@ -3128,7 +3505,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 90:
     ///
     /// `Regex: "/(\\.|[^\/])*/";`
     ///
@@ -3147,7 +3524,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 91:
     ///
     /// `LookAhead: LookAheadGroup TokenLiteral;`
     ///
@@ -3171,7 +3548,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 92:
     ///
     /// `LookAheadGroup: PositiveLookahead;`
     ///
@@ -3186,7 +3563,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 93:
     ///
     /// `LookAheadGroup: NegativeLookahead;`
     ///
@@ -3201,7 +3578,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 94:
     ///
     /// `PositiveLookahead: '?=';`
     ///
@@ -3224,7 +3601,7 @@ impl<'t, 'u> ParolLsGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 86:
+    /// Semantic action for production 95:
     ///
     /// `NegativeLookahead: '?!';`
     ///
@@ -3271,92 +3648,101 @@ impl<'t> UserActionsTrait<'t> for ParolLsGrammarAuto<'t, '_> {
             10 => self.declaration_3(&children[0], &children[1], &children[2], &children[3]),
             11 => self.declaration_4(&children[0], &children[1]),
             12 => self.declaration_5(&children[0], &children[1]),
-            13 => self.declaration_6(&children[0]),
-            14 => self.scanner_directives_0(&children[0], &children[1]),
-            15 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
-            16 => self.scanner_directives_2(&children[0]),
-            17 => self.scanner_directives_3(&children[0]),
-            18 => self.scanner_directives_4(&children[0], &children[1]),
-            19 => self.scanner_directives_5(&children[0], &children[1], &children[2]),
-            20 => self.scanner_directives_6(&children[0]),
-            21 => self.scanner_state_directives_0(&children[0], &children[1]),
-            22 => self.scanner_state_directives_1(&children[0], &children[1]),
-            23 => self.scanner_state_directives_2(&children[0]),
-            24 => self.grammar_definition(&children[0], &children[1], &children[2]),
-            25 => self.grammar_definition_list_0(&children[0], &children[1]),
-            26 => self.grammar_definition_list_1(),
-            27 => self.double_colon(&children[0]),
-            28 => self.production_l_h_s(&children[0], &children[1]),
-            29 => self.production(&children[0], &children[1], &children[2]),
-            30 => self.alternations(&children[0], &children[1]),
-            31 => self.alternations_list_0(&children[0], &children[1], &children[2]),
-            32 => self.alternations_list_1(),
-            33 => self.alternation(&children[0]),
-            34 => self.alternation_list_0(&children[0], &children[1]),
-            35 => self.alternation_list_1(),
-            36 => self.factor_0(&children[0]),
-            37 => self.factor_1(&children[0]),
-            38 => self.factor_2(&children[0]),
-            39 => self.factor_3(&children[0]),
-            40 => self.symbol_0(&children[0]),
-            41 => self.symbol_1(&children[0]),
-            42 => self.symbol_2(&children[0]),
-            43 => self.token_literal_0(&children[0]),
-            44 => self.token_literal_1(&children[0]),
-            45 => self.token_literal_2(&children[0]),
-            46 => self.token_expression(&children[0], &children[1]),
-            47 => self.token_expression_opt_0(&children[0]),
-            48 => self.token_expression_opt_1(),
-            49 => self.simple_token(&children[0], &children[1]),
-            50 => self.simple_token_opt_0(&children[0]),
-            51 => self.simple_token_opt_1(),
-            52 => self.token_with_states(
+            13 => self.declaration_6(&children[0], &children[1]),
+            14 => self.declaration_7(&children[0]),
+            15 => self.scanner_directives_0(&children[0], &children[1]),
+            16 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
+            17 => self.scanner_directives_2(&children[0], &children[1], &children[2]),
+            18 => self.scanner_directives_3(&children[0]),
+            19 => self.scanner_directives_4(&children[0]),
+            20 => self.scanner_directives_5(&children[0], &children[1]),
+            21 => self.scanner_directives_6(&children[0], &children[1], &children[2], &children[3]),
+            22 => self.scanner_directives_7(&children[0], &children[1]),
+            23 => self.scanner_directives_8(&children[0], &children[1], &children[2]),
+            24 => self.scanner_directives_9(&children[0]),
+            25 => self.scanner_state_directives_0(&children[0], &children[1]),
+            26 => self.scanner_state_directives_1(&children[0], &children[1]),
+            27 => self.scanner_state_directives_2(&children[0]),
+            28 => self.grammar_definition(&children[0], &children[1], &children[2]),
+            29 => self.grammar_definition_list_0(&children[0], &children[1]),
+            30 => self.grammar_definition_list_1(),
+            31 => self.double_colon(&children[0]),
+            32 => self.production_l_h_s(&children[0], &children[1]),
+            33 => self.production(&children[0], &children[1], &children[2]),
+            34 => self.alternations(&children[0], &children[1]),
+            35 => self.alternations_list_0(&children[0], &children[1], &children[2]),
+            36 => self.alternations_list_1(),
+            37 => self.alternation(&children[0], &children[1]),
+            38 => self.alternation_list_0(&children[0], &children[1]),
+            39 => self.alternation_list_1(),
+            40 => self.alternation_opt_0(&children[0]),
+            41 => self.alternation_opt_1(),
+            42 => self.predicate(&children[0], &children[1], &children[2]),
+            43 => self.factor_0(&children[0]),
+            44 => self.factor_1(&children[0]),
+            45 => self.factor_2(&children[0]),
+            46 => self.factor_3(&children[0]),
+            47 => self.symbol_0(&children[0]),
+            48 => self.symbol_1(&children[0]),
+            49 => self.symbol_2(&children[0]),
+            50 => self.token_literal_0(&children[0]),
+            51 => self.token_literal_1(&children[0]),
+            52 => self.token_literal_2(&children[0]),
+            53 => self.token_literal_3(&children[0]),
+            54 => self.token_expression(&children[0], &children[1]),
+            55 => self.token_expression_opt_0(&children[0]),
+            56 => self.token_expression_opt_1(),
+            57 => self.simple_token(&children[0], &children[1]),
+            58 => self.simple_token_opt_0(&children[0]),
+            59 => self.simple_token_opt_1(),
+            60 => self.token_with_states(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            53 => self.token_with_states_opt_0(&children[0]),
-            54 => self.token_with_states_opt_1(),
-            55 => self.group(&children[0], &children[1], &children[2]),
-            56 => self.optional(&children[0], &children[1], &children[2]),
-            57 => self.repeat(&children[0], &children[1], &children[2]),
-            58 => self.non_terminal(&children[0], &children[1]),
-            59 => self.non_terminal_opt_0(&children[0]),
-            60 => self.non_terminal_opt_1(),
-            61 => self.identifier(&children[0]),
-            62 => self.string(&children[0]),
-            63 => self.literal_string(&children[0]),
-            64 => self.scanner_state(
+            61 => self.token_with_states_opt_0(&children[0]),
+            62 => self.token_with_states_opt_1(),
+            63 => self.group(&children[0], &children[1], &children[2]),
+            64 => self.optional(&children[0], &children[1], &children[2]),
+            65 => self.repeat(&children[0], &children[1], &children[2]),
+            66 => self.non_terminal(&children[0], &children[1]),
+            67 => self.non_terminal_opt_0(&children[0]),
+            68 => self.non_terminal_opt_1(),
+            69 => self.identifier(&children[0]),
+            70 => self.string(&children[0]),
+            71 => self.literal_string(&children[0]),
+            72 => self.case_insensitive_literal_string(&children[0]),
+            73 => self.scanner_state(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            65 => self.scanner_state_list_0(&children[0], &children[1]),
-            66 => self.scanner_state_list_1(),
-            67 => self.identifier_list(&children[0], &children[1]),
-            68 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
-            69 => self.identifier_list_list_1(),
-            70 => self.a_s_t_control_0(&children[0]),
-            71 => self.a_s_t_control_1(&children[0], &children[1]),
-            72 => self.a_s_t_control_2(&children[0]),
-            73 => self.a_s_t_control_opt_0(&children[0]),
-            74 => self.a_s_t_control_opt_1(),
-            75 => self.member_name(&children[0], &children[1]),
-            76 => self.cut_operator(&children[0]),
-            77 => self.user_type_declaration(&children[0], &children[1]),
-            78 => self.user_type_name(&children[0], &children[1]),
-            79 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
-            80 => self.user_type_name_list_1(),
-            81 => self.regex(&children[0]),
-            82 => self.look_ahead(&children[0], &children[1]),
-            83 => self.look_ahead_group_0(&children[0]),
-            84 => self.look_ahead_group_1(&children[0]),
-            85 => self.positive_lookahead(&children[0]),
-            86 => self.negative_lookahead(&children[0]),
+            74 => self.scanner_state_list_0(&children[0], &children[1]),
+            75 => self.scanner_state_list_1(),
+            76 => self.identifier_list(&children[0], &children[1]),
+            77 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
+            78 => self.identifier_list_list_1(),
+            79 => self.a_s_t_control_0(&children[0]),
+            80 => self.a_s_t_control_1(&children[0], &children[1]),
+            81 => self.a_s_t_control_2(&children[0]),
+            82 => self.a_s_t_control_opt_0(&children[0]),
+            83 => self.a_s_t_control_opt_1(),
+            84 => self.member_name(&children[0], &children[1]),
+            85 => self.cut_operator(&children[0]),
+            86 => self.user_type_declaration(&children[0], &children[1]),
+            87 => self.user_type_name(&children[0], &children[1]),
+            88 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
+            89 => self.user_type_name_list_1(),
+            90 => self.regex(&children[0]),
+            91 => self.look_ahead(&children[0], &children[1]),
+            92 => self.look_ahead_group_0(&children[0]),
+            93 => self.look_ahead_group_1(&children[0]),
+            94 => self.positive_lookahead(&children[0]),
+            95 => self.negative_lookahead(&children[0]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {prod_num}"
             ))
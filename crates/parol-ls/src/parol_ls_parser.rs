@@ -16,7 +16,7 @@ use std::path::Path;
 use crate::parol_ls_grammar::ParolLsGrammar;
 use crate::parol_ls_grammar_trait::ParolLsGrammarAuto;
 
-pub const TERMINAL_NAMES: &[&str; 47] = &[
+pub const TERMINAL_NAMES: &[&str; 53] = &[
     /*  0 */ "EndOfInput",
     /*  1 */ "Newline",
     /*  2 */ "Whitespace",
@@ -30,40 +30,46 @@ pub const TERMINAL_NAMES: &[&str; 47] = &[
     /* 10 */ "PercentNtUnderscoreType",
     /* 11 */ "PercentTUnderscoreType",
     /* 12 */ "PercentGrammarUnderscoreType",
-    /* 13 */ "PercentLineUnderscoreComment",
-    /* 14 */ "PercentBlockUnderscoreComment",
-    /* 15 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
-    /* 16 */ "PercentAutoUnderscoreWsUnderscoreOff",
-    /* 17 */ "PercentSkip",
-    /* 18 */ "PercentOn",
-    /* 19 */ "PercentAllowUnderscoreUnmatched",
-    /* 20 */ "PercentEnter",
-    /* 21 */ "PercentPush",
-    /* 22 */ "PercentPop",
-    /* 23 */ "PercentPercent",
-    /* 24 */ "DoubleColon",
-    /* 25 */ "Colon",
-    /* 26 */ "Semicolon",
-    /* 27 */ "Or",
-    /* 28 */ "LT",
-    /* 29 */ "GT",
-    /* 30 */ "LParen",
-    /* 31 */ "RParen",
-    /* 32 */ "LBracket",
-    /* 33 */ "RBracket",
-    /* 34 */ "LBrace",
-    /* 35 */ "RBrace",
-    /* 36 */ "Identifier",
-    /* 37 */ "String",
-    /* 38 */ "LiteralString",
-    /* 39 */ "PercentScanner",
-    /* 40 */ "Comma",
-    /* 41 */ "At",
-    /* 42 */ "CutOperator",
-    /* 43 */ "Regex",
-    /* 44 */ "PositiveLookahead",
-    /* 45 */ "NegativeLookahead",
-    /* 46 */ "Error",
+    /* 13 */ "PercentEntry",
+    /* 14 */ "PercentLineUnderscoreComment",
+    /* 15 */ "PercentBlockUnderscoreComment",
+    /* 16 */ "PercentNestedUnderscoreBlockUnderscoreComment",
+    /* 17 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
+    /* 18 */ "PercentAutoUnderscoreWsUnderscoreOff",
+    /* 19 */ "PercentSkip",
+    /* 20 */ "PercentOffside",
+    /* 21 */ "PercentExternal",
+    /* 22 */ "PercentOn",
+    /* 23 */ "PercentAllowUnderscoreUnmatched",
+    /* 24 */ "PercentEnter",
+    /* 25 */ "PercentPush",
+    /* 26 */ "PercentPop",
+    /* 27 */ "PercentPercent",
+    /* 28 */ "DoubleColon",
+    /* 29 */ "Colon",
+    /* 30 */ "Semicolon",
+    /* 31 */ "Or",
+    /* 32 */ "AmpLBrace",
+    /* 33 */ "RBrace",
+    /* 34 */ "LT",
+    /* 35 */ "GT",
+    /* 36 */ "LParen",
+    /* 37 */ "RParen",
+    /* 38 */ "LBracket",
+    /* 39 */ "RBracket",
+    /* 40 */ "LBrace",
+    /* 41 */ "Identifier",
+    /* 42 */ "String",
+    /* 43 */ "LiteralString",
+    /* 44 */ "CaseInsensitiveLiteralString",
+    /* 45 */ "PercentScanner",
+    /* 46 */ "Comma",
+    /* 47 */ "At",
+    /* 48 */ "CutOperator",
+    /* 49 */ "Regex",
+    /* 50 */ "PositiveLookahead",
+    /* 51 */ "NegativeLookahead",
+    /* 52 */ "Error",
 ];
 
 scanner! {
@@ -81,40 +87,46 @@ scanner! {
             token r"%nt_type" => 10; // "PercentNtUnderscoreType"
             token r"%t_type" => 11; // "PercentTUnderscoreType"
             token r"%grammar_type" => 12; // "PercentGrammarUnderscoreType"
-            token r"%line_comment" => 13; // "PercentLineUnderscoreComment"
-            token r"%block_comment" => 14; // "PercentBlockUnderscoreComment"
-            token r"%auto_newline_off" => 15; // "PercentAutoUnderscoreNewlineUnderscoreOff"
-            token r"%auto_ws_off" => 16; // "PercentAutoUnderscoreWsUnderscoreOff"
-            token r"%skip" => 17; // "PercentSkip"
-            token r"%on" => 18; // "PercentOn"
-            token r"%allow_unmatched" => 19; // "PercentAllowUnderscoreUnmatched"
-            token r"%enter" => 20; // "PercentEnter"
-            token r"%push" => 21; // "PercentPush"
-            token r"%pop" => 22; // "PercentPop"
-            token r"%%" => 23; // "PercentPercent"
-            token r"::" => 24; // "DoubleColon"
-            token r":" => 25; // "Colon"
-            token r";" => 26; // "Semicolon"
-            token r"\|" => 27; // "Or"
-            token r"<" => 28; // "LT"
-            token r">" => 29; // "GT"
-            token r"\(" => 30; // "LParen"
-            token r"\)" => 31; // "RParen"
-            token r"\[" => 32; // "LBracket"
-            token r"\]" => 33; // "RBracket"
-            token r"\{" => 34; // "LBrace"
-            token r"\}" => 35; // "RBrace"
-            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 36; // "Identifier"
-            token r#""(\\.|[^"])*""# => 37; // "String"
-            token r"'(\\.|[^'])*'" => 38; // "LiteralString"
-            token r"%scanner" => 39; // "PercentScanner"
-            token r"," => 40; // "Comma"
-            token r"@" => 41; // "At"
-            token r"\^" => 42; // "CutOperator"
-            token r"/(\\.|[^\/])*/" => 43; // "Regex"
-            token r"\?=" => 44; // "PositiveLookahead"
-            token r"\?!" => 45; // "NegativeLookahead"
-            token r"." => 46; // "Error"
+            token r"%entry" => 13; // "PercentEntry"
+            token r"%line_comment" => 14; // "PercentLineUnderscoreComment"
+            token r"%block_comment" => 15; // "PercentBlockUnderscoreComment"
+            token r"%nested_block_comment" => 16; // "PercentNestedUnderscoreBlockUnderscoreComment"
+            token r"%auto_newline_off" => 17; // "PercentAutoUnderscoreNewlineUnderscoreOff"
+            token r"%auto_ws_off" => 18; // "PercentAutoUnderscoreWsUnderscoreOff"
+            token r"%skip" => 19; // "PercentSkip"
+            token r"%offside" => 20; // "PercentOffside"
+            token r"%external" => 21; // "PercentExternal"
+            token r"%on" => 22; // "PercentOn"
+            token r"%allow_unmatched" => 23; // "PercentAllowUnderscoreUnmatched"
+            token r"%enter" => 24; // "PercentEnter"
+            token r"%push" => 25; // "PercentPush"
+            token r"%pop" => 26; // "PercentPop"
+            token r"%%" => 27; // "PercentPercent"
+            token r"::" => 28; // "DoubleColon"
+            token r":" => 29; // "Colon"
+            token r";" => 30; // "Semicolon"
+            token r"\|" => 31; // "Or"
+            token r"\&\{" => 32; // "AmpLBrace"
+            token r"\}" => 33; // "RBrace"
+            token r"<" => 34; // "LT"
+            token r">" => 35; // "GT"
+            token r"\(" => 36; // "LParen"
+            token r"\)" => 37; // "RParen"
+            token r"\[" => 38; // "LBracket"
+            token r"\]" => 39; // "RBracket"
+            token r"\{" => 40; // "LBrace"
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 41; // "Identifier"
+            token r#""(\\.|[^"])*""# => 42; // "String"
+            token r"'(\\.|[^'])*'" => 43; // "LiteralString"
+            token r"'(\\.|[^'])*'i"  not followed by r"[a-zA-Z0-9_]"=> 44; // "CaseInsensitiveLiteralString"
+            token r"%scanner" => 45; // "PercentScanner"
+            token r"," => 46; // "Comma"
+            token r"@" => 47; // "At"
+            token r"\^" => 48; // "CutOperator"
+            token r"/(\\.|[^\/])*/" => 49; // "Regex"
+            token r"\?=" => 50; // "PositiveLookahead"
+            token r"\?!" => 51; // "NegativeLookahead"
+            token r"." => 52; // "Error"
         }
     }
 }
@@ -122,67 +134,70 @@ scanner! {
 const MAX_K: usize = 1;
 pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
 
-pub const NON_TERMINALS: &[&str; 50] = &[
+pub const NON_TERMINALS: &[&str; 53] = &[
     /*  0 */ "ASTControl",
     /*  1 */ "ASTControlOpt",
     /*  2 */ "Alternation",
     /*  3 */ "AlternationList",
-    /*  4 */ "Alternations",
-    /*  5 */ "AlternationsList",
-    /*  6 */ "CutOperator",
-    /*  7 */ "Declaration",
-    /*  8 */ "DoubleColon",
-    /*  9 */ "Factor",
-    /* 10 */ "GrammarDefinition",
-    /* 11 */ "GrammarDefinitionList",
-    /* 12 */ "Group",
-    /* 13 */ "Identifier",
-    /* 14 */ "IdentifierList",
-    /* 15 */ "IdentifierListList",
-    /* 16 */ "LiteralString",
-    /* 17 */ "LookAhead",
-    /* 18 */ "LookAheadGroup",
-    /* 19 */ "MemberName",
-    /* 20 */ "NegativeLookahead",
-    /* 21 */ "NonTerminal",
-    /* 22 */ "NonTerminalOpt",
-    /* 23 */ "Optional",
-    /* 24 */ "ParolLs",
-    /* 25 */ "PositiveLookahead",
-    /* 26 */ "Production",
-    /* 27 */ "ProductionLHS",
-    /* 28 */ "Prolog",
-    /* 29 */ "PrologList",
-    /* 30 */ "PrologList0",
-    /* 31 */ "Regex",
-    /* 32 */ "Repeat",
-    /* 33 */ "ScannerDirectives",
-    /* 34 */ "ScannerState",
-    /* 35 */ "ScannerStateDirectives",
-    /* 36 */ "ScannerStateList",
-    /* 37 */ "SimpleToken",
-    /* 38 */ "SimpleTokenOpt",
-    /* 39 */ "StartDeclaration",
-    /* 40 */ "String",
-    /* 41 */ "Symbol",
-    /* 42 */ "TokenExpression",
-    /* 43 */ "TokenExpressionOpt",
-    /* 44 */ "TokenLiteral",
-    /* 45 */ "TokenWithStates",
-    /* 46 */ "TokenWithStatesOpt",
-    /* 47 */ "UserTypeDeclaration",
-    /* 48 */ "UserTypeName",
-    /* 49 */ "UserTypeNameList",
+    /*  4 */ "AlternationOpt",
+    /*  5 */ "Alternations",
+    /*  6 */ "AlternationsList",
+    /*  7 */ "CaseInsensitiveLiteralString",
+    /*  8 */ "CutOperator",
+    /*  9 */ "Declaration",
+    /* 10 */ "DoubleColon",
+    /* 11 */ "Factor",
+    /* 12 */ "GrammarDefinition",
+    /* 13 */ "GrammarDefinitionList",
+    /* 14 */ "Group",
+    /* 15 */ "Identifier",
+    /* 16 */ "IdentifierList",
+    /* 17 */ "IdentifierListList",
+    /* 18 */ "LiteralString",
+    /* 19 */ "LookAhead",
+    /* 20 */ "LookAheadGroup",
+    /* 21 */ "MemberName",
+    /* 22 */ "NegativeLookahead",
+    /* 23 */ "NonTerminal",
+    /* 24 */ "NonTerminalOpt",
+    /* 25 */ "Optional",
+    /* 26 */ "ParolLs",
+    /* 27 */ "PositiveLookahead",
+    /* 28 */ "Predicate",
+    /* 29 */ "Production",
+    /* 30 */ "ProductionLHS",
+    /* 31 */ "Prolog",
+    /* 32 */ "PrologList",
+    /* 33 */ "PrologList0",
+    /* 34 */ "Regex",
+    /* 35 */ "Repeat",
+    /* 36 */ "ScannerDirectives",
+    /* 37 */ "ScannerState",
+    /* 38 */ "ScannerStateDirectives",
+    /* 39 */ "ScannerStateList",
+    /* 40 */ "SimpleToken",
+    /* 41 */ "SimpleTokenOpt",
+    /* 42 */ "StartDeclaration",
+    /* 43 */ "String",
+    /* 44 */ "Symbol",
+    /* 45 */ "TokenExpression",
+    /* 46 */ "TokenExpressionOpt",
+    /* 47 */ "TokenLiteral",
+    /* 48 */ "TokenWithStates",
+    /* 49 */ "TokenWithStatesOpt",
+    /* 50 */ "UserTypeDeclaration",
+    /* 51 */ "UserTypeName",
+    /* 52 */ "UserTypeNameList",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 50] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 53] = &[
     /* 0 - "ASTControl" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 3, 72),
-            Trans(0, 41, 2, 71),
-            Trans(0, 42, 1, 70),
+            Trans(0, 29, 3, 81),
+            Trans(0, 47, 2, 80),
+            Trans(0, 48, 1, 79),
         ],
         k: 1,
     },
@@ -190,26 +205,27 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 50] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 73),
-            Trans(0, 26, 2, 74),
-            Trans(0, 27, 2, 74),
-            Trans(0, 28, 2, 74),
-            Trans(0, 30, 2, 74),
-            Trans(0, 31, 2, 74),
-            Trans(0, 32, 2, 74),
-            Trans(0, 33, 2, 74),
-            Trans(0, 34, 2, 74),
-            Trans(0, 35, 2, 74),
-            Trans(0, 36, 2, 74),
-            Trans(0, 37, 2, 74),
-            Trans(0, 38, 2, 74),
-            Trans(0, 43, 2, 74),
+            Trans(0, 29, 1, 82),
+            Trans(0, 30, 2, 83),
+            Trans(0, 31, 2, 83),
+            Trans(0, 33, 2, 83),
+            Trans(0, 34, 2, 83),
+            Trans(0, 36, 2, 83),
+            Trans(0, 37, 2, 83),
+            Trans(0, 38, 2, 83),
+            Trans(0, 39, 2, 83),
+            Trans(0, 40, 2, 83),
+            Trans(0, 41, 2, 83),
+            Trans(0, 42, 2, 83),
+            Trans(0, 43, 2, 83),
+            Trans(0, 44, 2, 83),
+            Trans(0, 49, 2, 83),
         ],
         k: 1,
     },
     /* 2 - "Alternation" */
     LookaheadDFA {
-        prod0: 33,
+        prod0: 37,
         transitions: &[],
         k: 0,
     },
@@ -217,47 +233,76 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 50] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 26, 2, 35),
-            Trans(0, 27, 2, 35),
-            Trans(0, 28, 1, 34),
-            Trans(0, 30, 1, 34),
-            Trans(0, 31, 2, 35),
-            Trans(0, 32, 1, 34),
-            Trans(0, 33, 2, 35),
-            Trans(0, 34, 1, 34),
-            Trans(0, 35, 2, 35),
-            Trans(0, 36, 1, 34),
-            Trans(0, 37, 1, 34),
-            Trans(0, 38, 1, 34),
-            Trans(0, 43, 1, 34),
+            Trans(0, 30, 2, 39),
+            Trans(0, 31, 2, 39),
+            Trans(0, 33, 2, 39),
+            Trans(0, 34, 1, 38),
+            Trans(0, 36, 1, 38),
+            Trans(0, 37, 2, 39),
+            Trans(0, 38, 1, 38),
+            Trans(0, 39, 2, 39),
+            Trans(0, 40, 1, 38),
+            Trans(0, 41, 1, 38),
+            Trans(0, 42, 1, 38),
+            Trans(0, 43, 1, 38),
+            Trans(0, 44, 1, 38),
+            Trans(0, 49, 1, 38),
+        ],
+        k: 1,
+    },
+    /* 4 - "AlternationOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 30, 2, 41),
+            Trans(0, 31, 2, 41),
+            Trans(0, 32, 1, 40),
+            Trans(0, 33, 2, 41),
+            Trans(0, 34, 2, 41),
+            Trans(0, 36, 2, 41),
+            Trans(0, 37, 2, 41),
+            Trans(0, 38, 2, 41),
+            Trans(0, 39, 2, 41),
+            Trans(0, 40, 2, 41),
+            Trans(0, 41, 2, 41),
+            Trans(0, 42, 2, 41),
+            Trans(0, 43, 2, 41),
+            Trans(0, 44, 2, 41),
+            Trans(0, 49, 2, 41),
         ],
         k: 1,
     },
-    /* 4 - "Alternations" */
+    /* 5 - "Alternations" */
     LookaheadDFA {
-        prod0: 30,
+        prod0: 34,
         transitions: &[],
         k: 0,
     },
-    /* 5 - "AlternationsList" */
+    /* 6 - "AlternationsList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 26, 2, 32),
-            Trans(0, 27, 1, 31),
-            Trans(0, 31, 2, 32),
-            Trans(0, 33, 2, 32),
-            Trans(0, 35, 2, 32),
+            Trans(0, 30, 2, 36),
+            Trans(0, 31, 1, 35),
+            Trans(0, 33, 2, 36),
+            Trans(0, 37, 2, 36),
+            Trans(0, 39, 2, 36),
         ],
         k: 1,
     },
-    /* 6 - "CutOperator" */
+    /* 7 - "CaseInsensitiveLiteralString" */
     LookaheadDFA {
-        prod0: 76,
+        prod0: 72,
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "CutOperator" */
+    LookaheadDFA {
+        prod0: 85,
         transitions: &[],
         k: 0,
     },
-    /* 7 - "Declaration" */
+    /* 9 - "Declaration" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -268,190 +313,206 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 50] = &[
             Trans(0, 11, 5, 11),
             Trans(0, 12, 6, 12),
             Trans(0, 13, 7, 13),
-            Trans(0, 14, 7, 13),
-            Trans(0, 15, 7, 13),
-            Trans(0, 16, 7, 13),
-            Trans(0, 17, 7, 13),
-            Trans(0, 18, 7, 13),
-            Trans(0, 19, 7, 13),
+            Trans(0, 14, 8, 14),
+            Trans(0, 15, 8, 14),
+            Trans(0, 16, 8, 14),
+            Trans(0, 17, 8, 14),
+            Trans(0, 18, 8, 14),
+            Trans(0, 19, 8, 14),
+            Trans(0, 20, 8, 14),
+            Trans(0, 21, 8, 14),
+            Trans(0, 22, 8, 14),
+            Trans(0, 23, 8, 14),
         ],
         k: 1,
     },
-    /* 8 - "DoubleColon" */
+    /* 10 - "DoubleColon" */
     LookaheadDFA {
-        prod0: 27,
+        prod0: 31,
         transitions: &[],
         k: 0,
     },
-    /* 9 - "Factor" */
+    /* 11 - "Factor" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 28, 4, 39),
-            Trans(0, 30, 1, 36),
-            Trans(0, 32, 3, 38),
-            Trans(0, 34, 2, 37),
-            Trans(0, 36, 4, 39),
-            Trans(0, 37, 4, 39),
-            Trans(0, 38, 4, 39),
-            Trans(0, 43, 4, 39),
+            Trans(0, 34, 4, 46),
+            Trans(0, 36, 1, 43),
+            Trans(0, 38, 3, 45),
+            Trans(0, 40, 2, 44),
+            Trans(0, 41, 4, 46),
+            Trans(0, 42, 4, 46),
+            Trans(0, 43, 4, 46),
+            Trans(0, 44, 4, 46),
+            Trans(0, 49, 4, 46),
         ],
         k: 1,
     },
-    /* 10 - "GrammarDefinition" */
+    /* 12 - "GrammarDefinition" */
     LookaheadDFA {
-        prod0: 24,
+        prod0: 28,
         transitions: &[],
         k: 0,
     },
-    /* 11 - "GrammarDefinitionList" */
+    /* 13 - "GrammarDefinitionList" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 0, 2, 26), Trans(0, 36, 1, 25)],
+        transitions: &[Trans(0, 0, 2, 30), Trans(0, 41, 1, 29)],
         k: 1,
     },
-    /* 12 - "Group" */
+    /* 14 - "Group" */
     LookaheadDFA {
-        prod0: 55,
+        prod0: 63,
         transitions: &[],
         k: 0,
     },
-    /* 13 - "Identifier" */
+    /* 15 - "Identifier" */
     LookaheadDFA {
-        prod0: 61,
+        prod0: 69,
         transitions: &[],
         k: 0,
     },
-    /* 14 - "IdentifierList" */
+    /* 16 - "IdentifierList" */
     LookaheadDFA {
-        prod0: 67,
+        prod0: 76,
         transitions: &[],
         k: 0,
     },
-    /* 15 - "IdentifierListList" */
+    /* 17 - "IdentifierListList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 2, 69),
-            Trans(0, 7, 2, 69),
-            Trans(0, 8, 2, 69),
-            Trans(0, 10, 2, 69),
-            Trans(0, 11, 2, 69),
-            Trans(0, 12, 2, 69),
-            Trans(0, 13, 2, 69),
-            Trans(0, 14, 2, 69),
-            Trans(0, 15, 2, 69),
-            Trans(0, 16, 2, 69),
-            Trans(0, 17, 2, 69),
-            Trans(0, 18, 2, 69),
-            Trans(0, 19, 2, 69),
-            Trans(0, 20, 2, 69),
-            Trans(0, 21, 2, 69),
-            Trans(0, 22, 2, 69),
-            Trans(0, 23, 2, 69),
-            Trans(0, 29, 2, 69),
-            Trans(0, 35, 2, 69),
-            Trans(0, 39, 2, 69),
-            Trans(0, 40, 1, 68),
+            Trans(0, 6, 2, 78),
+            Trans(0, 7, 2, 78),
+            Trans(0, 8, 2, 78),
+            Trans(0, 10, 2, 78),
+            Trans(0, 11, 2, 78),
+            Trans(0, 12, 2, 78),
+            Trans(0, 13, 2, 78),
+            Trans(0, 14, 2, 78),
+            Trans(0, 15, 2, 78),
+            Trans(0, 16, 2, 78),
+            Trans(0, 17, 2, 78),
+            Trans(0, 18, 2, 78),
+            Trans(0, 19, 2, 78),
+            Trans(0, 20, 2, 78),
+            Trans(0, 21, 2, 78),
+            Trans(0, 22, 2, 78),
+            Trans(0, 23, 2, 78),
+            Trans(0, 24, 2, 78),
+            Trans(0, 25, 2, 78),
+            Trans(0, 26, 2, 78),
+            Trans(0, 27, 2, 78),
+            Trans(0, 33, 2, 78),
+            Trans(0, 35, 2, 78),
+            Trans(0, 45, 2, 78),
+            Trans(0, 46, 1, 77),
         ],
         k: 1,
     },
-    /* 16 - "LiteralString" */
+    /* 18 - "LiteralString" */
     LookaheadDFA {
-        prod0: 63,
+        prod0: 71,
         transitions: &[],
         k: 0,
     },
-    /* 17 - "LookAhead" */
+    /* 19 - "LookAhead" */
     LookaheadDFA {
-        prod0: 82,
+        prod0: 91,
         transitions: &[],
         k: 0,
     },
-    /* 18 - "LookAheadGroup" */
+    /* 20 - "LookAheadGroup" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 44, 1, 83), Trans(0, 45, 2, 84)],
+        transitions: &[Trans(0, 50, 1, 92), Trans(0, 51, 2, 93)],
         k: 1,
     },
-    /* 19 - "MemberName" */
+    /* 21 - "MemberName" */
     LookaheadDFA {
-        prod0: 75,
+        prod0: 84,
         transitions: &[],
         k: 0,
     },
-    /* 20 - "NegativeLookahead" */
+    /* 22 - "NegativeLookahead" */
     LookaheadDFA {
-        prod0: 86,
+        prod0: 95,
         transitions: &[],
         k: 0,
     },
-    /* 21 - "NonTerminal" */
+    /* 23 - "NonTerminal" */
     LookaheadDFA {
-        prod0: 58,
+        prod0: 66,
         transitions: &[],
         k: 0,
     },
-    /* 22 - "NonTerminalOpt" */
+    /* 24 - "NonTerminalOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 59),
-            Trans(0, 26, 2, 60),
-            Trans(0, 27, 2, 60),
-            Trans(0, 28, 2, 60),
-            Trans(0, 30, 2, 60),
-            Trans(0, 31, 2, 60),
-            Trans(0, 32, 2, 60),
-            Trans(0, 33, 2, 60),
-            Trans(0, 34, 2, 60),
-            Trans(0, 35, 2, 60),
-            Trans(0, 36, 2, 60),
-            Trans(0, 37, 2, 60),
-            Trans(0, 38, 2, 60),
-            Trans(0, 41, 1, 59),
-            Trans(0, 42, 1, 59),
-            Trans(0, 43, 2, 60),
+            Trans(0, 29, 1, 67),
+            Trans(0, 30, 2, 68),
+            Trans(0, 31, 2, 68),
+            Trans(0, 33, 2, 68),
+            Trans(0, 34, 2, 68),
+            Trans(0, 36, 2, 68),
+            Trans(0, 37, 2, 68),
+            Trans(0, 38, 2, 68),
+            Trans(0, 39, 2, 68),
+            Trans(0, 40, 2, 68),
+            Trans(0, 41, 2, 68),
+            Trans(0, 42, 2, 68),
+            Trans(0, 43, 2, 68),
+            Trans(0, 44, 2, 68),
+            Trans(0, 47, 1, 67),
+            Trans(0, 48, 1, 67),
+            Trans(0, 49, 2, 68),
         ],
         k: 1,
     },
-    /* 23 - "Optional" */
+    /* 25 - "Optional" */
     LookaheadDFA {
-        prod0: 56,
+        prod0: 64,
         transitions: &[],
         k: 0,
     },
-    /* 24 - "ParolLs" */
+    /* 26 - "ParolLs" */
     LookaheadDFA {
         prod0: 0,
         transitions: &[],
         k: 0,
     },
-    /* 25 - "PositiveLookahead" */
+    /* 27 - "PositiveLookahead" */
     LookaheadDFA {
-        prod0: 85,
+        prod0: 94,
         transitions: &[],
         k: 0,
     },
-    /* 26 - "Production" */
+    /* 28 - "Predicate" */
     LookaheadDFA {
-        prod0: 29,
+        prod0: 42,
         transitions: &[],
         k: 0,
     },
-    /* 27 - "ProductionLHS" */
+    /* 29 - "Production" */
     LookaheadDFA {
-        prod0: 28,
+        prod0: 33,
         transitions: &[],
         k: 0,
     },
-    /* 28 - "Prolog" */
+    /* 30 - "ProductionLHS" */
+    LookaheadDFA {
+        prod0: 32,
+        transitions: &[],
+        k: 0,
+    },
+    /* 31 - "Prolog" */
     LookaheadDFA {
         prod0: 1,
         transitions: &[],
         k: 0,
     },
-    /* 29 - "PrologList" */
+    /* 32 - "PrologList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -468,790 +529,869 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 50] = &[
             Trans(0, 17, 1, 4),
             Trans(0, 18, 1, 4),
             Trans(0, 19, 1, 4),
-            Trans(0, 23, 2, 5),
-            Trans(0, 39, 2, 5),
+            Trans(0, 20, 1, 4),
+            Trans(0, 21, 1, 4),
+            Trans(0, 22, 1, 4),
+            Trans(0, 23, 1, 4),
+            Trans(0, 27, 2, 5),
+            Trans(0, 45, 2, 5),
         ],
         k: 1,
     },
-    /* 30 - "PrologList0" */
+    /* 33 - "PrologList0" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 23, 2, 3), Trans(0, 39, 1, 2)],
+        transitions: &[Trans(0, 27, 2, 3), Trans(0, 45, 1, 2)],
         k: 1,
     },
-    /* 31 - "Regex" */
+    /* 34 - "Regex" */
     LookaheadDFA {
-        prod0: 81,
+        prod0: 90,
         transitions: &[],
         k: 0,
     },
-    /* 32 - "Repeat" */
+    /* 35 - "Repeat" */
     LookaheadDFA {
-        prod0: 57,
+        prod0: 65,
         transitions: &[],
         k: 0,
     },
-    /* 33 - "ScannerDirectives" */
+    /* 36 - "ScannerDirectives" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 13, 1, 14),
-            Trans(0, 14, 2, 15),
-            Trans(0, 15, 3, 16),
-            Trans(0, 16, 4, 17),
-            Trans(0, 17, 5, 18),
-            Trans(0, 18, 6, 19),
-            Trans(0, 19, 7, 20),
+            Trans(0, 14, 1, 15),
+            Trans(0, 15, 2, 16),
+            Trans(0, 16, 3, 17),
+            Trans(0, 17, 4, 18),
+            Trans(0, 18, 5, 19),
+            Trans(0, 19, 6, 20),
+            Trans(0, 20, 7, 21),
+            Trans(0, 21, 8, 22),
+            Trans(0, 22, 9, 23),
+            Trans(0, 23, 10, 24),
         ],
         k: 1,
     },
-    /* 34 - "ScannerState" */
+    /* 37 - "ScannerState" */
     LookaheadDFA {
-        prod0: 64,
+        prod0: 73,
         transitions: &[],
         k: 0,
     },
-    /* 35 - "ScannerStateDirectives" */
+    /* 38 - "ScannerStateDirectives" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 20, 1, 21),
-            Trans(0, 21, 2, 22),
-            Trans(0, 22, 3, 23),
+            Trans(0, 24, 1, 25),
+            Trans(0, 25, 2, 26),
+            Trans(0, 26, 3, 27),
         ],
         k: 1,
     },
-    /* 36 - "ScannerStateList" */
+    /* 39 - "ScannerStateList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 13, 1, 65),
-            Trans(0, 14, 1, 65),
-            Trans(0, 15, 1, 65),
-            Trans(0, 16, 1, 65),
-            Trans(0, 17, 1, 65),
-            Trans(0, 18, 1, 65),
-            Trans(0, 19, 1, 65),
-            Trans(0, 35, 2, 66),
+            Trans(0, 14, 1, 74),
+            Trans(0, 15, 1, 74),
+            Trans(0, 16, 1, 74),
+            Trans(0, 17, 1, 74),
+            Trans(0, 18, 1, 74),
+            Trans(0, 19, 1, 74),
+            Trans(0, 20, 1, 74),
+            Trans(0, 21, 1, 74),
+            Trans(0, 22, 1, 74),
+            Trans(0, 23, 1, 74),
+            Trans(0, 33, 2, 75),
         ],
         k: 1,
     },
-    /* 37 - "SimpleToken" */
+    /* 40 - "SimpleToken" */
     LookaheadDFA {
-        prod0: 49,
+        prod0: 57,
         transitions: &[],
         k: 0,
     },
-    /* 38 - "SimpleTokenOpt" */
+    /* 41 - "SimpleTokenOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 50),
-            Trans(0, 26, 2, 51),
-            Trans(0, 27, 2, 51),
-            Trans(0, 28, 2, 51),
-            Trans(0, 30, 2, 51),
-            Trans(0, 31, 2, 51),
-            Trans(0, 32, 2, 51),
-            Trans(0, 33, 2, 51),
-            Trans(0, 34, 2, 51),
-            Trans(0, 35, 2, 51),
-            Trans(0, 36, 2, 51),
-            Trans(0, 37, 2, 51),
-            Trans(0, 38, 2, 51),
-            Trans(0, 41, 1, 50),
-            Trans(0, 42, 1, 50),
-            Trans(0, 43, 2, 51),
+            Trans(0, 29, 1, 58),
+            Trans(0, 30, 2, 59),
+            Trans(0, 31, 2, 59),
+            Trans(0, 33, 2, 59),
+            Trans(0, 34, 2, 59),
+            Trans(0, 36, 2, 59),
+            Trans(0, 37, 2, 59),
+            Trans(0, 38, 2, 59),
+            Trans(0, 39, 2, 59),
+            Trans(0, 40, 2, 59),
+            Trans(0, 41, 2, 59),
+            Trans(0, 42, 2, 59),
+            Trans(0, 43, 2, 59),
+            Trans(0, 44, 2, 59),
+            Trans(0, 47, 1, 58),
+            Trans(0, 48, 1, 58),
+            Trans(0, 49, 2, 59),
         ],
         k: 1,
     },
-    /* 39 - "StartDeclaration" */
+    /* 42 - "StartDeclaration" */
     LookaheadDFA {
         prod0: 6,
         transitions: &[],
         k: 0,
     },
-    /* 40 - "String" */
+    /* 43 - "String" */
     LookaheadDFA {
-        prod0: 62,
+        prod0: 70,
         transitions: &[],
         k: 0,
     },
-    /* 41 - "Symbol" */
+    /* 44 - "Symbol" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 28, 3, 42),
-            Trans(0, 36, 1, 40),
-            Trans(0, 37, 2, 41),
-            Trans(0, 38, 2, 41),
-            Trans(0, 43, 2, 41),
+            Trans(0, 34, 3, 49),
+            Trans(0, 41, 1, 47),
+            Trans(0, 42, 2, 48),
+            Trans(0, 43, 2, 48),
+            Trans(0, 44, 2, 48),
+            Trans(0, 49, 2, 48),
         ],
         k: 1,
     },
-    /* 42 - "TokenExpression" */
+    /* 45 - "TokenExpression" */
     LookaheadDFA {
-        prod0: 46,
+        prod0: 54,
         transitions: &[],
         k: 0,
     },
-    /* 43 - "TokenExpressionOpt" */
+    /* 46 - "TokenExpressionOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 2, 48),
-            Trans(0, 26, 2, 48),
-            Trans(0, 27, 2, 48),
-            Trans(0, 28, 2, 48),
-            Trans(0, 30, 2, 48),
-            Trans(0, 31, 2, 48),
-            Trans(0, 32, 2, 48),
-            Trans(0, 33, 2, 48),
-            Trans(0, 34, 2, 48),
-            Trans(0, 35, 2, 48),
-            Trans(0, 36, 2, 48),
-            Trans(0, 37, 2, 48),
-            Trans(0, 38, 2, 48),
-            Trans(0, 41, 2, 48),
-            Trans(0, 42, 2, 48),
-            Trans(0, 43, 2, 48),
-            Trans(0, 44, 1, 47),
-            Trans(0, 45, 1, 47),
+            Trans(0, 29, 2, 56),
+            Trans(0, 30, 2, 56),
+            Trans(0, 31, 2, 56),
+            Trans(0, 33, 2, 56),
+            Trans(0, 34, 2, 56),
+            Trans(0, 36, 2, 56),
+            Trans(0, 37, 2, 56),
+            Trans(0, 38, 2, 56),
+            Trans(0, 39, 2, 56),
+            Trans(0, 40, 2, 56),
+            Trans(0, 41, 2, 56),
+            Trans(0, 42, 2, 56),
+            Trans(0, 43, 2, 56),
+            Trans(0, 44, 2, 56),
+            Trans(0, 47, 2, 56),
+            Trans(0, 48, 2, 56),
+            Trans(0, 49, 2, 56),
+            Trans(0, 50, 1, 55),
+            Trans(0, 51, 1, 55),
         ],
         k: 1,
     },
-    /* 44 - "TokenLiteral" */
+    /* 47 - "TokenLiteral" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 37, 1, 43),
-            Trans(0, 38, 2, 44),
-            Trans(0, 43, 3, 45),
+            Trans(0, 42, 1, 50),
+            Trans(0, 43, 2, 51),
+            Trans(0, 44, 3, 52),
+            Trans(0, 49, 4, 53),
         ],
         k: 1,
     },
-    /* 45 - "TokenWithStates" */
+    /* 48 - "TokenWithStates" */
     LookaheadDFA {
-        prod0: 52,
+        prod0: 60,
         transitions: &[],
         k: 0,
     },
-    /* 46 - "TokenWithStatesOpt" */
+    /* 49 - "TokenWithStatesOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 53),
-            Trans(0, 26, 2, 54),
-            Trans(0, 27, 2, 54),
-            Trans(0, 28, 2, 54),
-            Trans(0, 30, 2, 54),
-            Trans(0, 31, 2, 54),
-            Trans(0, 32, 2, 54),
-            Trans(0, 33, 2, 54),
-            Trans(0, 34, 2, 54),
-            Trans(0, 35, 2, 54),
-            Trans(0, 36, 2, 54),
-            Trans(0, 37, 2, 54),
-            Trans(0, 38, 2, 54),
-            Trans(0, 41, 1, 53),
-            Trans(0, 42, 1, 53),
-            Trans(0, 43, 2, 54),
+            Trans(0, 29, 1, 61),
+            Trans(0, 30, 2, 62),
+            Trans(0, 31, 2, 62),
+            Trans(0, 33, 2, 62),
+            Trans(0, 34, 2, 62),
+            Trans(0, 36, 2, 62),
+            Trans(0, 37, 2, 62),
+            Trans(0, 38, 2, 62),
+            Trans(0, 39, 2, 62),
+            Trans(0, 40, 2, 62),
+            Trans(0, 41, 2, 62),
+            Trans(0, 42, 2, 62),
+            Trans(0, 43, 2, 62),
+            Trans(0, 44, 2, 62),
+            Trans(0, 47, 1, 61),
+            Trans(0, 48, 1, 61),
+            Trans(0, 49, 2, 62),
         ],
         k: 1,
     },
-    /* 47 - "UserTypeDeclaration" */
+    /* 50 - "UserTypeDeclaration" */
     LookaheadDFA {
-        prod0: 77,
+        prod0: 86,
         transitions: &[],
         k: 0,
     },
-    /* 48 - "UserTypeName" */
+    /* 51 - "UserTypeName" */
     LookaheadDFA {
-        prod0: 78,
+        prod0: 87,
         transitions: &[],
         k: 0,
     },
-    /* 49 - "UserTypeNameList" */
+    /* 52 - "UserTypeNameList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 2, 80),
-            Trans(0, 7, 2, 80),
-            Trans(0, 8, 2, 80),
-            Trans(0, 10, 2, 80),
-            Trans(0, 11, 2, 80),
-            Trans(0, 12, 2, 80),
-            Trans(0, 13, 2, 80),
-            Trans(0, 14, 2, 80),
-            Trans(0, 15, 2, 80),
-            Trans(0, 16, 2, 80),
-            Trans(0, 17, 2, 80),
-            Trans(0, 18, 2, 80),
-            Trans(0, 19, 2, 80),
-            Trans(0, 23, 2, 80),
-            Trans(0, 24, 1, 79),
-            Trans(0, 26, 2, 80),
-            Trans(0, 27, 2, 80),
-            Trans(0, 28, 2, 80),
-            Trans(0, 30, 2, 80),
-            Trans(0, 31, 2, 80),
-            Trans(0, 32, 2, 80),
-            Trans(0, 33, 2, 80),
-            Trans(0, 34, 2, 80),
-            Trans(0, 35, 2, 80),
-            Trans(0, 36, 2, 80),
-            Trans(0, 37, 2, 80),
-            Trans(0, 38, 2, 80),
-            Trans(0, 39, 2, 80),
-            Trans(0, 43, 2, 80),
+            Trans(0, 6, 2, 89),
+            Trans(0, 7, 2, 89),
+            Trans(0, 8, 2, 89),
+            Trans(0, 10, 2, 89),
+            Trans(0, 11, 2, 89),
+            Trans(0, 12, 2, 89),
+            Trans(0, 13, 2, 89),
+            Trans(0, 14, 2, 89),
+            Trans(0, 15, 2, 89),
+            Trans(0, 16, 2, 89),
+            Trans(0, 17, 2, 89),
+            Trans(0, 18, 2, 89),
+            Trans(0, 19, 2, 89),
+            Trans(0, 20, 2, 89),
+            Trans(0, 21, 2, 89),
+            Trans(0, 22, 2, 89),
+            Trans(0, 23, 2, 89),
+            Trans(0, 27, 2, 89),
+            Trans(0, 28, 1, 88),
+            Trans(0, 30, 2, 89),
+            Trans(0, 31, 2, 89),
+            Trans(0, 33, 2, 89),
+            Trans(0, 34, 2, 89),
+            Trans(0, 36, 2, 89),
+            Trans(0, 37, 2, 89),
+            Trans(0, 38, 2, 89),
+            Trans(0, 39, 2, 89),
+            Trans(0, 40, 2, 89),
+            Trans(0, 41, 2, 89),
+            Trans(0, 42, 2, 89),
+            Trans(0, 43, 2, 89),
+            Trans(0, 44, 2, 89),
+            Trans(0, 45, 2, 89),
+            Trans(0, 49, 2, 89),
         ],
         k: 1,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 87] = &[
+pub const PRODUCTIONS: &[Production; 96] = &[
     // 0 - ParolLs: Prolog GrammarDefinition;
     Production {
-        lhs: 24,
-        production: &[ParseType::N(10), ParseType::N(28)],
+        lhs: 26,
+        production: &[ParseType::N(12), ParseType::N(31)],
         is_push_production: false,
     },
     // 1 - Prolog: StartDeclaration PrologList /* Vec */ PrologList0 /* Vec */;
     Production {
-        lhs: 28,
-        production: &[ParseType::N(30), ParseType::N(29), ParseType::N(39)],
+        lhs: 31,
+        production: &[ParseType::N(33), ParseType::N(32), ParseType::N(42)],
         is_push_production: false,
     },
     // 2 - PrologList0: ScannerState PrologList0;
     Production {
-        lhs: 30,
-        production: &[ParseType::N(30), ParseType::N(34)],
+        lhs: 33,
+        production: &[ParseType::N(33), ParseType::N(37)],
         is_push_production: true,
     },
     // 3 - PrologList0: ;
     Production {
-        lhs: 30,
+        lhs: 33,
         production: &[],
         is_push_production: false,
     },
     // 4 - PrologList: Declaration PrologList;
     Production {
-        lhs: 29,
-        production: &[ParseType::N(29), ParseType::N(7)],
+        lhs: 32,
+        production: &[ParseType::N(32), ParseType::N(9)],
         is_push_production: true,
     },
     // 5 - PrologList: ;
     Production {
-        lhs: 29,
+        lhs: 32,
         production: &[],
         is_push_production: false,
     },
     // 6 - StartDeclaration: "%start" Identifier;
     Production {
-        lhs: 39,
-        production: &[ParseType::N(13), ParseType::T(5)],
+        lhs: 42,
+        production: &[ParseType::N(15), ParseType::T(5)],
         is_push_production: false,
     },
     // 7 - Declaration: "%title" String;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(40), ParseType::T(6)],
+        lhs: 9,
+        production: &[ParseType::N(43), ParseType::T(6)],
         is_push_production: false,
     },
     // 8 - Declaration: "%comment" String;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(40), ParseType::T(7)],
+        lhs: 9,
+        production: &[ParseType::N(43), ParseType::T(7)],
         is_push_production: false,
     },
     // 9 - Declaration: "%user_type" Identifier "=" UserTypeName;
     Production {
-        lhs: 7,
+        lhs: 9,
         production: &[
-            ParseType::N(48),
+            ParseType::N(51),
             ParseType::T(9),
-            ParseType::N(13),
+            ParseType::N(15),
             ParseType::T(8),
         ],
         is_push_production: false,
     },
     // 10 - Declaration: "%nt_type" Identifier@nt_name "=" UserTypeName@nt_type;
     Production {
-        lhs: 7,
+        lhs: 9,
         production: &[
-            ParseType::N(48),
+            ParseType::N(51),
             ParseType::T(9),
-            ParseType::N(13),
+            ParseType::N(15),
             ParseType::T(10),
         ],
         is_push_production: false,
     },
     // 11 - Declaration: "%t_type" UserTypeName@t_type;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(48), ParseType::T(11)],
+        lhs: 9,
+        production: &[ParseType::N(51), ParseType::T(11)],
         is_push_production: false,
     },
     // 12 - Declaration: '%grammar_type' LiteralString;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(16), ParseType::T(12)],
+        lhs: 9,
+        production: &[ParseType::N(18), ParseType::T(12)],
         is_push_production: false,
     },
-    // 13 - Declaration: ScannerDirectives;
+    // 13 - Declaration: '%entry' IdentifierList;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(33)],
+        lhs: 9,
+        production: &[ParseType::N(16), ParseType::T(13)],
         is_push_production: false,
     },
-    // 14 - ScannerDirectives: "%line_comment" TokenLiteral;
+    // 14 - Declaration: ScannerDirectives;
     Production {
-        lhs: 33,
-        production: &[ParseType::N(44), ParseType::T(13)],
+        lhs: 9,
+        production: &[ParseType::N(36)],
         is_push_production: false,
     },
-    // 15 - ScannerDirectives: "%block_comment" TokenLiteral TokenLiteral;
+    // 15 - ScannerDirectives: "%line_comment" TokenLiteral;
     Production {
-        lhs: 33,
-        production: &[ParseType::N(44), ParseType::N(44), ParseType::T(14)],
+        lhs: 36,
+        production: &[ParseType::N(47), ParseType::T(14)],
         is_push_production: false,
     },
-    // 16 - ScannerDirectives: "%auto_newline_off";
+    // 16 - ScannerDirectives: "%block_comment" TokenLiteral TokenLiteral;
     Production {
-        lhs: 33,
-        production: &[ParseType::T(15)],
+        lhs: 36,
+        production: &[ParseType::N(47), ParseType::N(47), ParseType::T(15)],
         is_push_production: false,
     },
-    // 17 - ScannerDirectives: "%auto_ws_off";
+    // 17 - ScannerDirectives: "%nested_block_comment" TokenLiteral TokenLiteral;
     Production {
-        lhs: 33,
-        production: &[ParseType::T(16)],
+        lhs: 36,
+        production: &[ParseType::N(47), ParseType::N(47), ParseType::T(16)],
         is_push_production: false,
     },
-    // 18 - ScannerDirectives: '%skip' IdentifierList;
+    // 18 - ScannerDirectives: "%auto_newline_off";
     Production {
-        lhs: 33,
-        production: &[ParseType::N(14), ParseType::T(17)],
+        lhs: 36,
+        production: &[ParseType::T(17)],
         is_push_production: false,
     },
-    // 19 - ScannerDirectives: '%on' IdentifierList ScannerStateDirectives;
+    // 19 - ScannerDirectives: "%auto_ws_off";
     Production {
-        lhs: 33,
-        production: &[ParseType::N(35), ParseType::N(14), ParseType::T(18)],
+        lhs: 36,
+        production: &[ParseType::T(18)],
         is_push_production: false,
     },
-    // 20 - ScannerDirectives: "%allow_unmatched";
+    // 20 - ScannerDirectives: '%skip' IdentifierList;
     Production {
-        lhs: 33,
-        production: &[ParseType::T(19)],
+        lhs: 36,
+        production: &[ParseType::N(16), ParseType::T(19)],
         is_push_production: false,
     },
-    // 21 - ScannerStateDirectives: '%enter' Identifier;
+    // 21 - ScannerDirectives: '%offside' Identifier@indent Identifier@dedent Identifier@newline;
     Production {
-        lhs: 35,
-        production: &[ParseType::N(13), ParseType::T(20)],
+        lhs: 36,
+        production: &[
+            ParseType::N(15),
+            ParseType::N(15),
+            ParseType::N(15),
+            ParseType::T(20),
+        ],
         is_push_production: false,
     },
-    // 22 - ScannerStateDirectives: '%push' Identifier;
+    // 22 - ScannerDirectives: '%external' IdentifierList;
     Production {
-        lhs: 35,
-        production: &[ParseType::N(13), ParseType::T(21)],
+        lhs: 36,
+        production: &[ParseType::N(16), ParseType::T(21)],
         is_push_production: false,
     },
-    // 23 - ScannerStateDirectives: '%pop';
+    // 23 - ScannerDirectives: '%on' IdentifierList ScannerStateDirectives;
     Production {
-        lhs: 35,
-        production: &[ParseType::T(22)],
+        lhs: 36,
+        production: &[ParseType::N(38), ParseType::N(16), ParseType::T(22)],
         is_push_production: false,
     },
-    // 24 - GrammarDefinition: "%%" Production GrammarDefinitionList /* Vec */;
+    // 24 - ScannerDirectives: "%allow_unmatched";
     Production {
-        lhs: 10,
-        production: &[ParseType::N(11), ParseType::N(26), ParseType::T(23)],
+        lhs: 36,
+        production: &[ParseType::T(23)],
         is_push_production: false,
     },
-    // 25 - GrammarDefinitionList: Production GrammarDefinitionList;
+    // 25 - ScannerStateDirectives: '%enter' Identifier;
     Production {
-        lhs: 11,
-        production: &[ParseType::N(11), ParseType::N(26)],
+        lhs: 38,
+        production: &[ParseType::N(15), ParseType::T(24)],
+        is_push_production: false,
+    },
+    // 26 - ScannerStateDirectives: '%push' Identifier;
+    Production {
+        lhs: 38,
+        production: &[ParseType::N(15), ParseType::T(25)],
+        is_push_production: false,
+    },
+    // 27 - ScannerStateDirectives: '%pop';
+    Production {
+        lhs: 38,
+        production: &[ParseType::T(26)],
+        is_push_production: false,
+    },
+    // 28 - GrammarDefinition: "%%" Production GrammarDefinitionList /* Vec */;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(13), ParseType::N(29), ParseType::T(27)],
+        is_push_production: false,
+    },
+    // 29 - GrammarDefinitionList: Production GrammarDefinitionList;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(13), ParseType::N(29)],
         is_push_production: true,
     },
-    // 26 - GrammarDefinitionList: ;
+    // 30 - GrammarDefinitionList: ;
     Production {
-        lhs: 11,
+        lhs: 13,
         production: &[],
         is_push_production: false,
     },
-    // 27 - DoubleColon: "::";
+    // 31 - DoubleColon: "::";
     Production {
-        lhs: 8,
-        production: &[ParseType::T(24)],
+        lhs: 10,
+        production: &[ParseType::T(28)],
         is_push_production: false,
     },
-    // 28 - ProductionLHS: Identifier ":";
+    // 32 - ProductionLHS: Identifier ":";
     Production {
-        lhs: 27,
-        production: &[ParseType::T(25), ParseType::N(13)],
+        lhs: 30,
+        production: &[ParseType::T(29), ParseType::N(15)],
         is_push_production: false,
     },
-    // 29 - Production: ProductionLHS Alternations ";";
+    // 33 - Production: ProductionLHS Alternations ";";
     Production {
-        lhs: 26,
-        production: &[ParseType::T(26), ParseType::N(4), ParseType::N(27)],
+        lhs: 29,
+        production: &[ParseType::T(30), ParseType::N(5), ParseType::N(30)],
         is_push_production: false,
     },
-    // 30 - Alternations: Alternation AlternationsList /* Vec */;
+    // 34 - Alternations: Alternation AlternationsList /* Vec */;
     Production {
-        lhs: 4,
-        production: &[ParseType::N(5), ParseType::N(2)],
+        lhs: 5,
+        production: &[ParseType::N(6), ParseType::N(2)],
         is_push_production: false,
     },
-    // 31 - AlternationsList: '|' Alternation AlternationsList;
+    // 35 - AlternationsList: '|' Alternation AlternationsList;
     Production {
-        lhs: 5,
-        production: &[ParseType::N(5), ParseType::N(2), ParseType::T(27)],
+        lhs: 6,
+        production: &[ParseType::N(6), ParseType::N(2), ParseType::T(31)],
         is_push_production: true,
     },
-    // 32 - AlternationsList: ;
+    // 36 - AlternationsList: ;
     Production {
-        lhs: 5,
+        lhs: 6,
         production: &[],
         is_push_production: false,
     },
-    // 33 - Alternation: AlternationList /* Vec */;
+    // 37 - Alternation: AlternationOpt /* Option */ AlternationList /* Vec */;
     Production {
         lhs: 2,
-        production: &[ParseType::N(3)],
+        production: &[ParseType::N(3), ParseType::N(4)],
         is_push_production: false,
     },
-    // 34 - AlternationList: Factor AlternationList;
+    // 38 - AlternationList: Factor AlternationList;
     Production {
         lhs: 3,
-        production: &[ParseType::N(3), ParseType::N(9)],
+        production: &[ParseType::N(3), ParseType::N(11)],
         is_push_production: true,
     },
-    // 35 - AlternationList: ;
+    // 39 - AlternationList: ;
     Production {
         lhs: 3,
         production: &[],
         is_push_production: false,
     },
-    // 36 - Factor: Group;
+    // 40 - AlternationOpt: Predicate;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(12)],
+        lhs: 4,
+        production: &[ParseType::N(28)],
         is_push_production: false,
     },
-    // 37 - Factor: Repeat;
+    // 41 - AlternationOpt: ;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(32)],
+        lhs: 4,
+        production: &[],
         is_push_production: false,
     },
-    // 38 - Factor: Optional;
+    // 42 - Predicate: '&{' Identifier '}';
     Production {
-        lhs: 9,
-        production: &[ParseType::N(23)],
+        lhs: 28,
+        production: &[ParseType::T(33), ParseType::N(15), ParseType::T(32)],
         is_push_production: false,
     },
-    // 39 - Factor: Symbol;
+    // 43 - Factor: Group;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(41)],
+        lhs: 11,
+        production: &[ParseType::N(14)],
         is_push_production: false,
     },
-    // 40 - Symbol: NonTerminal;
+    // 44 - Factor: Repeat;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(21)],
+        lhs: 11,
+        production: &[ParseType::N(35)],
         is_push_production: false,
     },
-    // 41 - Symbol: SimpleToken;
+    // 45 - Factor: Optional;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(37)],
+        lhs: 11,
+        production: &[ParseType::N(25)],
         is_push_production: false,
     },
-    // 42 - Symbol: TokenWithStates;
+    // 46 - Factor: Symbol;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(45)],
+        lhs: 11,
+        production: &[ParseType::N(44)],
         is_push_production: false,
     },
-    // 43 - TokenLiteral: String;
+    // 47 - Symbol: NonTerminal;
     Production {
         lhs: 44,
-        production: &[ParseType::N(40)],
+        production: &[ParseType::N(23)],
         is_push_production: false,
     },
-    // 44 - TokenLiteral: LiteralString;
+    // 48 - Symbol: SimpleToken;
     Production {
         lhs: 44,
-        production: &[ParseType::N(16)],
+        production: &[ParseType::N(40)],
         is_push_production: false,
     },
-    // 45 - TokenLiteral: Regex;
+    // 49 - Symbol: TokenWithStates;
     Production {
         lhs: 44,
-        production: &[ParseType::N(31)],
+        production: &[ParseType::N(48)],
         is_push_production: false,
     },
-    // 46 - TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;
+    // 50 - TokenLiteral: String;
     Production {
-        lhs: 42,
-        production: &[ParseType::N(43), ParseType::N(44)],
+        lhs: 47,
+        production: &[ParseType::N(43)],
         is_push_production: false,
     },
-    // 47 - TokenExpressionOpt: LookAhead;
+    // 51 - TokenLiteral: LiteralString;
     Production {
-        lhs: 43,
-        production: &[ParseType::N(17)],
+        lhs: 47,
+        production: &[ParseType::N(18)],
         is_push_production: false,
     },
-    // 48 - TokenExpressionOpt: ;
+    // 52 - TokenLiteral: CaseInsensitiveLiteralString;
     Production {
-        lhs: 43,
+        lhs: 47,
+        production: &[ParseType::N(7)],
+        is_push_production: false,
+    },
+    // 53 - TokenLiteral: Regex;
+    Production {
+        lhs: 47,
+        production: &[ParseType::N(34)],
+        is_push_production: false,
+    },
+    // 54 - TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;
+    Production {
+        lhs: 45,
+        production: &[ParseType::N(46), ParseType::N(47)],
+        is_push_production: false,
+    },
+    // 55 - TokenExpressionOpt: LookAhead;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(19)],
+        is_push_production: false,
+    },
+    // 56 - TokenExpressionOpt: ;
+    Production {
+        lhs: 46,
         production: &[],
         is_push_production: false,
     },
-    // 49 - SimpleToken: TokenExpression SimpleTokenOpt /* Option */;
+    // 57 - SimpleToken: TokenExpression SimpleTokenOpt /* Option */;
     Production {
-        lhs: 37,
-        production: &[ParseType::N(38), ParseType::N(42)],
+        lhs: 40,
+        production: &[ParseType::N(41), ParseType::N(45)],
         is_push_production: false,
     },
-    // 50 - SimpleTokenOpt: ASTControl;
+    // 58 - SimpleTokenOpt: ASTControl;
     Production {
-        lhs: 38,
+        lhs: 41,
         production: &[ParseType::N(0)],
         is_push_production: false,
     },
-    // 51 - SimpleTokenOpt: ;
+    // 59 - SimpleTokenOpt: ;
     Production {
-        lhs: 38,
+        lhs: 41,
         production: &[],
         is_push_production: false,
     },
-    // 52 - TokenWithStates: "<" IdentifierList ">" TokenExpression TokenWithStatesOpt /* Option */;
+    // 60 - TokenWithStates: "<" IdentifierList ">" TokenExpression TokenWithStatesOpt /* Option */;
     Production {
-        lhs: 45,
+        lhs: 48,
         production: &[
-            ParseType::N(46),
-            ParseType::N(42),
-            ParseType::T(29),
-            ParseType::N(14),
-            ParseType::T(28),
+            ParseType::N(49),
+            ParseType::N(45),
+            ParseType::T(35),
+            ParseType::N(16),
+            ParseType::T(34),
         ],
         is_push_production: false,
     },
-    // 53 - TokenWithStatesOpt: ASTControl;
+    // 61 - TokenWithStatesOpt: ASTControl;
     Production {
-        lhs: 46,
+        lhs: 49,
         production: &[ParseType::N(0)],
         is_push_production: false,
     },
-    // 54 - TokenWithStatesOpt: ;
+    // 62 - TokenWithStatesOpt: ;
     Production {
-        lhs: 46,
+        lhs: 49,
         production: &[],
         is_push_production: false,
     },
-    // 55 - Group: '(' Alternations ')';
+    // 63 - Group: '(' Alternations ')';
     Production {
-        lhs: 12,
-        production: &[ParseType::T(31), ParseType::N(4), ParseType::T(30)],
+        lhs: 14,
+        production: &[ParseType::T(37), ParseType::N(5), ParseType::T(36)],
         is_push_production: false,
     },
-    // 56 - Optional: '[' Alternations ']';
+    // 64 - Optional: '[' Alternations ']';
     Production {
-        lhs: 23,
-        production: &[ParseType::T(33), ParseType::N(4), ParseType::T(32)],
+        lhs: 25,
+        production: &[ParseType::T(39), ParseType::N(5), ParseType::T(38)],
         is_push_production: false,
     },
-    // 57 - Repeat: '{' Alternations '}';
+    // 65 - Repeat: '{' Alternations '}';
     Production {
-        lhs: 32,
-        production: &[ParseType::T(35), ParseType::N(4), ParseType::T(34)],
+        lhs: 35,
+        production: &[ParseType::T(33), ParseType::N(5), ParseType::T(40)],
         is_push_production: false,
     },
-    // 58 - NonTerminal: Identifier NonTerminalOpt /* Option */;
+    // 66 - NonTerminal: Identifier NonTerminalOpt /* Option */;
     Production {
-        lhs: 21,
-        production: &[ParseType::N(22), ParseType::N(13)],
+        lhs: 23,
+        production: &[ParseType::N(24), ParseType::N(15)],
         is_push_production: false,
     },
-    // 59 - NonTerminalOpt: ASTControl;
+    // 67 - NonTerminalOpt: ASTControl;
     Production {
-        lhs: 22,
+        lhs: 24,
         production: &[ParseType::N(0)],
         is_push_production: false,
     },
-    // 60 - NonTerminalOpt: ;
+    // 68 - NonTerminalOpt: ;
     Production {
-        lhs: 22,
+        lhs: 24,
         production: &[],
         is_push_production: false,
     },
-    // 61 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    // 69 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
     Production {
-        lhs: 13,
-        production: &[ParseType::T(36)],
+        lhs: 15,
+        production: &[ParseType::T(41)],
         is_push_production: false,
     },
-    // 62 - String: /"(\\.|[^"])*"/;
+    // 70 - String: /"(\\.|[^"])*"/;
     Production {
-        lhs: 40,
-        production: &[ParseType::T(37)],
+        lhs: 43,
+        production: &[ParseType::T(42)],
         is_push_production: false,
     },
-    // 63 - LiteralString: /'(\\.|[^'])*'/;
+    // 71 - LiteralString: /'(\\.|[^'])*'/;
     Production {
-        lhs: 16,
-        production: &[ParseType::T(38)],
+        lhs: 18,
+        production: &[ParseType::T(43)],
         is_push_production: false,
     },
-    // 64 - ScannerState: "%scanner" Identifier '{' ScannerStateList /* Vec */ '}';
+    // 72 - CaseInsensitiveLiteralString: /'(\\.|[^'])*'i/;
     Production {
-        lhs: 34,
+        lhs: 7,
+        production: &[ParseType::T(44)],
+        is_push_production: false,
+    },
+    // 73 - ScannerState: "%scanner" Identifier '{' ScannerStateList /* Vec */ '}';
+    Production {
+        lhs: 37,
         production: &[
-            ParseType::T(35),
-            ParseType::N(36),
-            ParseType::T(34),
-            ParseType::N(13),
-            ParseType::T(39),
+            ParseType::T(33),
+            ParseType::N(39),
+            ParseType::T(40),
+            ParseType::N(15),
+            ParseType::T(45),
         ],
         is_push_production: false,
     },
-    // 65 - ScannerStateList: ScannerDirectives ScannerStateList;
+    // 74 - ScannerStateList: ScannerDirectives ScannerStateList;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(36), ParseType::N(33)],
+        lhs: 39,
+        production: &[ParseType::N(39), ParseType::N(36)],
         is_push_production: true,
     },
-    // 66 - ScannerStateList: ;
+    // 75 - ScannerStateList: ;
     Production {
-        lhs: 36,
+        lhs: 39,
         production: &[],
         is_push_production: false,
     },
-    // 67 - IdentifierList: Identifier IdentifierListList /* Vec */;
+    // 76 - IdentifierList: Identifier IdentifierListList /* Vec */;
     Production {
-        lhs: 14,
-        production: &[ParseType::N(15), ParseType::N(13)],
+        lhs: 16,
+        production: &[ParseType::N(17), ParseType::N(15)],
         is_push_production: false,
     },
-    // 68 - IdentifierListList: "," Identifier IdentifierListList;
+    // 77 - IdentifierListList: "," Identifier IdentifierListList;
     Production {
-        lhs: 15,
-        production: &[ParseType::N(15), ParseType::N(13), ParseType::T(40)],
+        lhs: 17,
+        production: &[ParseType::N(17), ParseType::N(15), ParseType::T(46)],
         is_push_production: true,
     },
-    // 69 - IdentifierListList: ;
+    // 78 - IdentifierListList: ;
     Production {
-        lhs: 15,
+        lhs: 17,
         production: &[],
         is_push_production: false,
     },
-    // 70 - ASTControl: CutOperator;
+    // 79 - ASTControl: CutOperator;
     Production {
         lhs: 0,
-        production: &[ParseType::N(6)],
+        production: &[ParseType::N(8)],
         is_push_production: false,
     },
-    // 71 - ASTControl: MemberName ASTControlOpt /* Option */;
+    // 80 - ASTControl: MemberName ASTControlOpt /* Option */;
     Production {
         lhs: 0,
-        production: &[ParseType::N(1), ParseType::N(19)],
+        production: &[ParseType::N(1), ParseType::N(21)],
         is_push_production: false,
     },
-    // 72 - ASTControl: UserTypeDeclaration;
+    // 81 - ASTControl: UserTypeDeclaration;
     Production {
         lhs: 0,
-        production: &[ParseType::N(47)],
+        production: &[ParseType::N(50)],
         is_push_production: false,
     },
-    // 73 - ASTControlOpt: UserTypeDeclaration;
+    // 82 - ASTControlOpt: UserTypeDeclaration;
     Production {
         lhs: 1,
-        production: &[ParseType::N(47)],
+        production: &[ParseType::N(50)],
         is_push_production: false,
     },
-    // 74 - ASTControlOpt: ;
+    // 83 - ASTControlOpt: ;
     Production {
         lhs: 1,
         production: &[],
         is_push_production: false,
     },
-    // 75 - MemberName: '@'^ /* Clipped */ Identifier;
+    // 84 - MemberName: '@'^ /* Clipped */ Identifier;
     Production {
-        lhs: 19,
-        production: &[ParseType::N(13), ParseType::T(41)],
+        lhs: 21,
+        production: &[ParseType::N(15), ParseType::T(47)],
         is_push_production: false,
     },
-    // 76 - CutOperator: '^';
+    // 85 - CutOperator: '^';
     Production {
-        lhs: 6,
-        production: &[ParseType::T(42)],
+        lhs: 8,
+        production: &[ParseType::T(48)],
         is_push_production: false,
     },
-    // 77 - UserTypeDeclaration: ":" UserTypeName;
+    // 86 - UserTypeDeclaration: ":" UserTypeName;
     Production {
-        lhs: 47,
-        production: &[ParseType::N(48), ParseType::T(25)],
+        lhs: 50,
+        production: &[ParseType::N(51), ParseType::T(29)],
         is_push_production: false,
     },
-    // 78 - UserTypeName: Identifier UserTypeNameList /* Vec */;
+    // 87 - UserTypeName: Identifier UserTypeNameList /* Vec */;
     Production {
-        lhs: 48,
-        production: &[ParseType::N(49), ParseType::N(13)],
+        lhs: 51,
+        production: &[ParseType::N(52), ParseType::N(15)],
         is_push_production: false,
     },
-    // 79 - UserTypeNameList: DoubleColon Identifier UserTypeNameList;
+    // 88 - UserTypeNameList: DoubleColon Identifier UserTypeNameList;
     Production {
-        lhs: 49,
-        production: &[ParseType::N(49), ParseType::N(13), ParseType::N(8)],
+        lhs: 52,
+        production: &[ParseType::N(52), ParseType::N(15), ParseType::N(10)],
         is_push_production: true,
     },
-    // 80 - UserTypeNameList: ;
+    // 89 - UserTypeNameList: ;
     Production {
-        lhs: 49,
+        lhs: 52,
         production: &[],
         is_push_production: false,
     },
-    // 81 - Regex: "/(\\.|[^\/])*/";
+    // 90 - Regex: "/(\\.|[^\/])*/";
     Production {
-        lhs: 31,
-        production: &[ParseType::T(43)],
+        lhs: 34,
+        production: &[ParseType::T(49)],
         is_push_production: false,
     },
-    // 82 - LookAhead: LookAheadGroup TokenLiteral;
+    // 91 - LookAhead: LookAheadGroup TokenLiteral;
     Production {
-        lhs: 17,
-        production: &[ParseType::N(44), ParseType::N(18)],
+        lhs: 19,
+        production: &[ParseType::N(47), ParseType::N(20)],
         is_push_production: false,
     },
-    // 83 - LookAheadGroup: PositiveLookahead;
+    // 92 - LookAheadGroup: PositiveLookahead;
     Production {
-        lhs: 18,
-        production: &[ParseType::N(25)],
+        lhs: 20,
+        production: &[ParseType::N(27)],
         is_push_production: false,
     },
-    // 84 - LookAheadGroup: NegativeLookahead;
+    // 93 - LookAheadGroup: NegativeLookahead;
     Production {
-        lhs: 18,
-        production: &[ParseType::N(20)],
+        lhs: 20,
+        production: &[ParseType::N(22)],
         is_push_production: false,
     },
-    // 85 - PositiveLookahead: '?=';
+    // 94 - PositiveLookahead: '?=';
     Production {
-        lhs: 25,
-        production: &[ParseType::T(44)],
+        lhs: 27,
+        production: &[ParseType::T(50)],
         is_push_production: false,
     },
-    // 86 - NegativeLookahead: '?!';
+    // 95 - NegativeLookahead: '?!';
     Production {
-        lhs: 20,
-        production: &[ParseType::T(45)],
+        lhs: 22,
+        production: &[ParseType::T(51)],
         is_push_production: false,
     },
 ];
@@ -1284,7 +1424,7 @@ where
 {
     use parol_ls_grammar_scanner::ParolLsGrammarScanner;
     let mut llk_parser = LLKParser::new(
-        24,
+        26,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
@@ -1297,15 +1437,22 @@ where
     let mut user_actions = ParolLsGrammarAuto::new(user_actions);
     llk_parser.parse_into(
         tree_builder,
-        TokenStream::new_with_skip_tokens(
+        TokenStream::new_with_options(
             input,
             file_name,
             scanner.scanner_impl.clone(),
             &ParolLsGrammarScanner::match_function,
             MAX_K,
-            SKIP_TOKENS_BY_SCANNER_STATE,
-        )
-        .unwrap(),
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: &[],
+                contextual_lexing: false,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Utf16,
+            },
+        )?,
         &mut user_actions,
     )
 }
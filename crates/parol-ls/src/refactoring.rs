@@ -0,0 +1,616 @@
+//! Refactoring code actions for grammar description files.
+//!
+//! All refactorings work on the syntax tree of the document and are returned as text edits on the
+//! original source. They don't run the grammar analysis, thus they are cheap enough to be
+//! calculated on every code action request.
+
+use std::collections::{BTreeSet, HashMap};
+
+use lsp_types::{CodeAction, CodeActionKind, Position, Range, TextEdit, Uri, WorkspaceEdit};
+use parol::{Cfg, Pr, Symbol as CfgSymbol, generators::generate_terminal_name, left_factor};
+
+use crate::{
+    document_state::DocumentState,
+    parol_ls_grammar::ParolLsGrammar,
+    parol_ls_grammar_trait::{
+        Alternation, Alternations, Factor, ParolLs, Production, SimpleToken, Symbol,
+    },
+    rng::Rng,
+    utils::{extract_text_range, offset_to_pos, pos_to_offset},
+};
+
+/// Prefix of the names of the opaque symbols that stand for factors during left-factoring
+const FACTOR_PLACEHOLDER_PREFIX: &str = "__Factor";
+
+///
+/// Returns all refactorings that are applicable at the given range of the document.
+///
+pub(crate) fn code_actions(
+    uri: &Uri,
+    document_state: &DocumentState,
+    range: Range,
+) -> Vec<CodeAction> {
+    let Some(grammar) = document_state.parsed_data.grammar.as_ref() else {
+        return Vec::new();
+    };
+    let refactoring = Refactoring::new(uri, document_state, grammar);
+    [
+        refactoring.extract_non_terminal(range),
+        refactoring.inline_non_terminal(range.start),
+        refactoring.introduce_primary_non_terminal(range.start),
+        refactoring.left_factor_non_terminal(range.start),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+struct Refactoring<'a> {
+    uri: &'a Uri,
+    input: &'a str,
+    parsed_data: &'a ParolLsGrammar,
+    /// All productions in the order of their appearance
+    productions: Vec<&'a Production>,
+}
+
+impl<'a> Refactoring<'a> {
+    fn new(uri: &'a Uri, document_state: &'a DocumentState, grammar: &'a ParolLs) -> Self {
+        let definition = &grammar.grammar_definition;
+        let productions = std::iter::once(&definition.production)
+            .chain(
+                definition
+                    .grammar_definition_list
+                    .iter()
+                    .map(|p| &p.production),
+            )
+            .collect();
+        Self {
+            uri,
+            input: &document_state.input,
+            parsed_data: &document_state.parsed_data,
+            productions,
+        }
+    }
+
+    ///
+    /// Extracts the factors of an alternation that overlap with the selection into a new
+    /// non-terminal.
+    ///
+    fn extract_non_terminal(&self, selection: Range) -> Option<CodeAction> {
+        if selection.start == selection.end {
+            return None;
+        }
+        let production = self
+            .productions
+            .iter()
+            .find(|p| contains_range(&Rng::from(**p).0, &selection))?;
+        let alternation = innermost_alternation(&production.alternations, &selection)?;
+        let selected = alternation
+            .alternation_list
+            .iter()
+            .map(|a| factor_range(&a.factor).0)
+            .filter(|r| r.start < selection.end && selection.start < r.end)
+            .collect::<Vec<Range>>();
+        let extracted = Range {
+            start: selected.first()?.start,
+            end: selected.last()?.end,
+        };
+        let lhs = production.production_l_h_s.identifier.identifier.text();
+        let name = self.unique_name(&format!("{lhs}Part"));
+        let text = extract_text_range(self.input, Rng(extracted));
+        let edits = vec![
+            TextEdit {
+                range: extracted,
+                new_text: name.clone(),
+            },
+            self.insert_after(production, format!("{name}: {text};")),
+        ];
+        Some(self.code_action(
+            format!("Extract selection into new non-terminal '{name}'"),
+            CodeActionKind::REFACTOR_EXTRACT,
+            edits,
+        ))
+    }
+
+    ///
+    /// Inlines a non-terminal that is defined by exactly one production and used exactly once.
+    ///
+    fn inline_non_terminal(&self, position: Position) -> Option<CodeAction> {
+        let name = self.non_terminal_at(position)?;
+        if name == self.parsed_data.start_symbol {
+            return None;
+        }
+        let [definition] = self.productions_of(name)[..] else {
+            return None;
+        };
+        let mut references = Vec::new();
+        for production in &self.productions {
+            for_each_factor(&production.alternations, &mut |factor| {
+                if let Factor::Symbol(symbol) = factor
+                    && let Symbol::NonTerminal(nt) = &symbol.symbol
+                    && nt.non_terminal.identifier.identifier.text() == name
+                {
+                    references.push((*production, &nt.non_terminal));
+                }
+            });
+        }
+        let [(using_production, reference)] = references[..] else {
+            return None;
+        };
+        if std::ptr::eq(using_production, definition) || reference.non_terminal_opt.is_some() {
+            // Recursive non-terminals can't be inlined and AST control on the reference would get
+            // lost.
+            return None;
+        }
+        let body = extract_text_range(self.input, Rng::from(&definition.alternations));
+        let new_text = if definition.alternations.alternations_list.is_empty() {
+            body.to_string()
+        } else {
+            format!("({body})")
+        };
+        let edits = vec![
+            TextEdit {
+                range: Rng::from(reference).0,
+                new_text,
+            },
+            self.delete(definition),
+        ];
+        Some(self.code_action(
+            format!("Inline non-terminal '{name}'"),
+            CodeActionKind::REFACTOR_INLINE,
+            edits,
+        ))
+    }
+
+    ///
+    /// Replaces all occurrences of a terminal literal by a primary non-terminal, i.e. a
+    /// non-terminal with a single production that only contains the terminal.
+    /// An already existing primary non-terminal for the terminal is reused.
+    ///
+    fn introduce_primary_non_terminal(&self, position: Position) -> Option<CodeAction> {
+        let mut tokens: Vec<(&Production, &SimpleToken)> = Vec::new();
+        for production in &self.productions {
+            for_each_factor(&production.alternations, &mut |factor| {
+                if let Factor::Symbol(symbol) = factor
+                    && let Symbol::SimpleToken(token) = &symbol.symbol
+                {
+                    tokens.push((*production, &token.simple_token));
+                }
+            });
+        }
+        let (_, selected) = tokens
+            .iter()
+            .find(|(_, t)| contains_position(&Rng::from(&t.token_expression).0, position))?;
+        let terminal = self.text(Rng::from(&selected.token_expression));
+
+        let (primaries, occurrences): (Vec<_>, Vec<_>) = tokens
+            .iter()
+            .filter(|(_, t)| self.text(Rng::from(&t.token_expression)) == terminal)
+            .partition(|(p, _)| self.is_primary_production(p));
+        let existing = primaries
+            .first()
+            .map(|(p, _)| p.production_l_h_s.identifier.identifier.text().to_string());
+        if occurrences.is_empty() || (existing.is_none() && occurrences.len() < 2) {
+            return None;
+        }
+
+        let name = existing
+            .clone()
+            .unwrap_or_else(|| self.primary_name(selected));
+        let mut edits = occurrences
+            .iter()
+            .map(|(_, t)| TextEdit {
+                range: Rng::from(&t.token_expression).0,
+                new_text: name.clone(),
+            })
+            .collect::<Vec<TextEdit>>();
+        let title = if existing.is_some() {
+            format!("Replace terminal {terminal} with non-terminal '{name}'")
+        } else {
+            let last_production = self.productions.last()?;
+            edits.push(self.insert_after(last_production, format!("{name}: {terminal};")));
+            format!("Introduce non-terminal '{name}' for terminal {terminal}")
+        };
+        Some(self.code_action(title, CodeActionKind::REFACTOR_REWRITE, edits))
+    }
+
+    ///
+    /// Applies left-factoring to the productions of the non-terminal at the given position.
+    ///
+    /// The factors of the alternatives are treated as opaque symbols. This way the transformation
+    /// `parol::left_factor` can be applied to productions that contain groups, optional
+    /// expressions, repetitions and AST control directives, and the result can be written back
+    /// using the original source text of the factors.
+    ///
+    fn left_factor_non_terminal(&self, position: Position) -> Option<CodeAction> {
+        let production = self
+            .productions
+            .iter()
+            .find(|p| contains_position(&Rng::from(**p).0, position))?;
+        let name = production.production_l_h_s.identifier.identifier.text();
+        let productions = self.productions_of(name);
+        // Left factoring would drop the semantic predicates
+        if productions
+            .iter()
+            .any(|p| alternations_of(&p.alternations).any(|a| a.alternation_opt.is_some()))
+        {
+            return None;
+        }
+
+        let mut placeholders: HashMap<String, String> = HashMap::new();
+        let mut factor_texts: HashMap<String, String> = HashMap::new();
+        let mut cfg = Cfg::with_start_symbol(name);
+        for production in &productions {
+            for alternation in alternations_of(&production.alternations) {
+                let rhs = alternation
+                    .alternation_list
+                    .iter()
+                    .map(|a| {
+                        let text = self.text(factor_range(&a.factor)).to_string();
+                        let next_index = placeholders.len();
+                        let placeholder = placeholders
+                            .entry(text.clone())
+                            .or_insert_with(|| format!("{FACTOR_PLACEHOLDER_PREFIX}{next_index}"))
+                            .clone();
+                        factor_texts.insert(placeholder.clone(), text);
+                        CfgSymbol::n(&placeholder)
+                    })
+                    .collect();
+                cfg = cfg.add_pr(Pr::new(name, rhs));
+            }
+        }
+        // Add the other non-terminals as empty productions to avoid name clashes with newly
+        // created non-terminals.
+        let existing = self.non_terminal_names();
+        for other in existing.iter().filter(|n| n.as_str() != name) {
+            cfg = cfg.add_pr(Pr::new(other, Vec::new()));
+        }
+
+        let factored = left_factor(&cfg);
+        let mut rendered: Vec<(String, Vec<String>)> = Vec::new();
+        for pr in factored
+            .pr
+            .iter()
+            .filter(|p| p.get_n_str() == name || !existing.contains(p.get_n_str()))
+        {
+            let alternative = pr
+                .get_r()
+                .iter()
+                .map(|s| match s {
+                    CfgSymbol::N(n, ..) => factor_texts.get(n).unwrap_or(n).clone(),
+                    _ => s.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            if let Some((_, alternatives)) = rendered.iter_mut().find(|(n, _)| n == pr.get_n_str())
+            {
+                alternatives.push(alternative);
+            } else {
+                rendered.push((pr.get_n(), vec![alternative]));
+            }
+        }
+        if rendered.len() < 2 {
+            // No common prefix found
+            return None;
+        }
+
+        let new_text = rendered
+            .iter()
+            .map(|(n, alternatives)| format!("{n}: {};", alternatives.join(" | ")))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let mut edits = vec![TextEdit {
+            range: Rng::from(productions[0]).0,
+            new_text,
+        }];
+        edits.extend(productions.iter().skip(1).map(|p| self.delete(p)));
+        Some(self.code_action(
+            format!("Left-factor productions of '{name}'"),
+            CodeActionKind::REFACTOR_REWRITE,
+            edits,
+        ))
+    }
+
+    fn text(&self, rng: Rng) -> &'a str {
+        extract_text_range(self.input, rng)
+    }
+
+    fn non_terminal_names(&self) -> BTreeSet<String> {
+        self.parsed_data.productions.keys().cloned().collect()
+    }
+
+    fn non_terminal_at(&self, position: Position) -> Option<&'a str> {
+        self.parsed_data
+            .non_terminal_definitions
+            .find_reference(position)
+    }
+
+    fn productions_of(&self, name: &str) -> Vec<&'a Production> {
+        self.productions
+            .iter()
+            .filter(|p| p.production_l_h_s.identifier.identifier.text() == name)
+            .copied()
+            .collect()
+    }
+
+    /// A primary production is the only production of its non-terminal and consists of a single
+    /// terminal.
+    fn is_primary_production(&self, production: &Production) -> bool {
+        let lhs = production.production_l_h_s.identifier.identifier.text();
+        let alternation = &production.alternations.alternation;
+        production.alternations.alternations_list.is_empty()
+            && alternation.alternation_list.len() == 1
+            && matches!(
+                &alternation.alternation_list[0].factor,
+                Factor::Symbol(symbol) if matches!(symbol.symbol, Symbol::SimpleToken(_))
+            )
+            && self.productions_of(lhs).len() == 1
+    }
+
+    fn primary_name(&self, token: &SimpleToken) -> String {
+        let expanded =
+            ParolLsGrammar::expanded_token_literal(&token.token_expression.token_literal);
+        self.unique_name(&generate_terminal_name(
+            &expanded,
+            None,
+            None,
+            &Cfg::default(),
+        ))
+    }
+
+    fn unique_name(&self, preferred: &str) -> String {
+        let existing = self.non_terminal_names();
+        if !existing.contains(preferred) {
+            return preferred.to_string();
+        }
+        (1..)
+            .map(|i| format!("{preferred}{i}"))
+            .find(|n| !existing.contains(n))
+            .unwrap()
+    }
+
+    /// Inserts a new production on a new line after the given production.
+    fn insert_after(&self, production: &Production, new_production: String) -> TextEdit {
+        let end = Rng::from(production).0.end;
+        TextEdit {
+            range: Range { start: end, end },
+            new_text: format!("\n{new_production}"),
+        }
+    }
+
+    /// Deletes the given production.
+    /// The lines are removed completely if they don't contain anything else.
+    fn delete(&self, production: &Production) -> TextEdit {
+        let range = Rng::from(production).0;
+        let mut start = pos_to_offset(self.input, range.start);
+        let mut end = pos_to_offset(self.input, range.end);
+        let before = &self.input[..start];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let after = &self.input[end..];
+        let line_end = after.find('\n').map_or(after.len(), |i| i + 1);
+        if before[line_start..].trim().is_empty() && after[..line_end].trim().is_empty() {
+            start = line_start;
+            end += line_end;
+        }
+        TextEdit {
+            range: Range {
+                start: offset_to_pos(self.input, start),
+                end: offset_to_pos(self.input, end),
+            },
+            new_text: String::new(),
+        }
+    }
+
+    fn code_action(&self, title: String, kind: CodeActionKind, edits: Vec<TextEdit>) -> CodeAction {
+        CodeAction {
+            title,
+            kind: Some(kind),
+            edit: Some(WorkspaceEdit {
+                changes: Some(HashMap::from([(self.uri.clone(), edits)])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+fn contains_range(outer: &Range, inner: &Range) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+fn contains_position(range: &Range, position: Position) -> bool {
+    range.start <= position && position <= range.end
+}
+
+/// The range of a factor including its AST control directives
+fn factor_range(factor: &Factor) -> Rng {
+    match factor {
+        Factor::Symbol(symbol) => match &symbol.symbol {
+            Symbol::NonTerminal(nt) => Rng::from(&nt.non_terminal),
+            _ => Rng::from(factor),
+        },
+        _ => Rng::from(factor),
+    }
+}
+
+fn alternations_of(alternations: &Alternations) -> impl Iterator<Item = &Alternation> {
+    std::iter::once(&alternations.alternation).chain(
+        alternations
+            .alternations_list
+            .iter()
+            .map(|a| &a.alternation),
+    )
+}
+
+fn inner_alternations(factor: &Factor) -> Option<&Alternations> {
+    match factor {
+        Factor::Group(group) => Some(&group.group.alternations),
+        Factor::Repeat(repeat) => Some(&repeat.repeat.alternations),
+        Factor::Optional(optional) => Some(&optional.optional.alternations),
+        Factor::Symbol(_) => None,
+    }
+}
+
+/// Visits all factors of the given alternations including those in nested groups.
+fn for_each_factor<'a>(alternations: &'a Alternations, f: &mut impl FnMut(&'a Factor)) {
+    for alternation in alternations_of(alternations) {
+        for item in &alternation.alternation_list {
+            f(&item.factor);
+            if let Some(inner) = inner_alternations(&item.factor) {
+                for_each_factor(inner, f);
+            }
+        }
+    }
+}
+
+/// Finds the most deeply nested alternation that contains the given range.
+fn innermost_alternation<'a>(
+    alternations: &'a Alternations,
+    range: &Range,
+) -> Option<&'a Alternation> {
+    for alternation in alternations_of(alternations) {
+        for item in &alternation.alternation_list {
+            if let Some(inner) = inner_alternations(&item.factor)
+                && contains_range(&Rng::from(inner).0, range)
+                && let Some(found) = innermost_alternation(inner, range)
+            {
+                return Some(found);
+            }
+        }
+        let rng = Rng::from(alternation);
+        if !rng.is_empty() && contains_range(&rng.0, range) {
+            return Some(alternation);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parol_ls_parser::parse;
+    use std::str::FromStr;
+
+    const GRAMMAR: &str = r#"%start S
+%%
+S: A "x" "y" | B;
+A: "a" ",";
+B: "b" "," | "b" "c";
+"#;
+
+    fn document_state(input: &str) -> DocumentState {
+        let mut parsed_data = ParolLsGrammar::new();
+        parse(input, "test.par", &mut parsed_data).expect("test grammar must be valid");
+        DocumentState {
+            input: input.to_string(),
+            parsed_data,
+        }
+    }
+
+    fn position_of(input: &str, needle: &str) -> Position {
+        offset_to_pos(
+            input,
+            input.find(needle).expect("needle must exist in test input"),
+        )
+    }
+
+    fn apply(input: &str, action: &CodeAction) -> String {
+        let uri = Uri::from_str("file:///test.par").unwrap();
+        let mut edits = action.edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri].clone();
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.range.start));
+        let mut output = input.to_string();
+        for edit in edits {
+            let start = pos_to_offset(&output, edit.range.start);
+            let end = pos_to_offset(&output, edit.range.end);
+            output.replace_range(start..end, &edit.new_text);
+        }
+        output
+    }
+
+    fn actions(input: &str, range: Range) -> Vec<CodeAction> {
+        let uri = Uri::from_str("file:///test.par").unwrap();
+        code_actions(&uri, &document_state(input), range)
+    }
+
+    fn find<'a>(actions: &'a [CodeAction], prefix: &str) -> &'a CodeAction {
+        actions
+            .iter()
+            .find(|a| a.title.starts_with(prefix))
+            .unwrap_or_else(|| panic!("No action '{prefix}' in {actions:#?}"))
+    }
+
+    #[test]
+    fn extract_selected_factors_into_new_non_terminal() {
+        let start = position_of(GRAMMAR, r#""x""#);
+        let mut end = position_of(GRAMMAR, r#""y""#);
+        end.character += 3;
+        let actions = actions(GRAMMAR, Range { start, end });
+        let action = find(&actions, "Extract selection");
+        assert_eq!(
+            apply(GRAMMAR, action),
+            r#"%start S
+%%
+S: A SPart | B;
+SPart: "x" "y";
+A: "a" ",";
+B: "b" "," | "b" "c";
+"#
+        );
+    }
+
+    #[test]
+    fn inline_single_use_non_terminal() {
+        let position = position_of(GRAMMAR, "A \"x\"");
+        let actions = actions(GRAMMAR, Range::new(position, position));
+        let action = find(&actions, "Inline non-terminal 'A'");
+        assert_eq!(
+            apply(GRAMMAR, action),
+            r#"%start S
+%%
+S: "a" "," "x" "y" | B;
+B: "b" "," | "b" "c";
+"#
+        );
+    }
+
+    #[test]
+    fn introduce_primary_non_terminal_for_repeated_terminal() {
+        let position = position_of(GRAMMAR, r#"",""#);
+        let actions = actions(GRAMMAR, Range::new(position, position));
+        let action = find(&actions, "Introduce non-terminal 'Comma'");
+        assert_eq!(
+            apply(GRAMMAR, action),
+            r#"%start S
+%%
+S: A "x" "y" | B;
+A: "a" Comma;
+B: "b" Comma | "b" "c";
+Comma: ",";
+"#
+        );
+    }
+
+    #[test]
+    fn left_factor_productions_of_non_terminal() {
+        let position = position_of(GRAMMAR, "B: ");
+        let actions = actions(GRAMMAR, Range::new(position, position));
+        let action = find(&actions, "Left-factor productions of 'B'");
+        assert_eq!(
+            apply(GRAMMAR, action),
+            r#"%start S
+%%
+S: A "x" "y" | B;
+A: "a" ",";
+B: "b" BSuffix;
+BSuffix: "," | "c";
+"#
+        );
+    }
+
+    #[test]
+    fn no_left_factoring_without_common_prefix() {
+        let position = position_of(GRAMMAR, "S: ");
+        let actions = actions(GRAMMAR, Range::new(position, position));
+        assert!(!actions.iter().any(|a| a.title.starts_with("Left-factor")));
+    }
+}
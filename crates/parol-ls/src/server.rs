@@ -2,32 +2,48 @@ use std::{
     collections::{HashMap, HashSet},
     error::Error,
     path::{Path, PathBuf},
-    sync::Arc,
-    thread,
+    sync::{Arc, Mutex},
 };
 
-use lsp_server::Message;
+use lsp_server::{Message, RequestId};
 use lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
-    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DocumentFormattingParams, DocumentSymbolParams,
-    DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams,
-    Location, Position, PrepareRenameResponse, PublishDiagnosticsParams, Range, RenameParams,
+    DiagnosticRelatedInformation, DidChangeConfigurationParams, DidChangeTextDocumentParams,
+    DidChangeWatchedFilesParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentFormattingParams, DocumentOnTypeFormattingParams, DocumentRangeFormattingParams,
+    DocumentSymbolParams, DocumentSymbolResponse, FileChangeType, FormattingOptions,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, Location, Position,
+    PrepareRenameResponse, PublishDiagnosticsParams, Range, RenameParams,
     TextDocumentContentChangeEvent, TextDocumentPositionParams, TextEdit, Uri, WorkspaceEdit,
+    WorkspaceSymbolParams, WorkspaceSymbolResponse,
     notification::{
-        DidChangeConfiguration, DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
-        Notification, PublishDiagnostics,
+        DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument,
+        DidOpenTextDocument, Notification, PublishDiagnostics,
     },
 };
+use parol::conversions::par::FormatConfig;
 use parol::generators::grammar_trans::check_and_transform_grammar_with_ignored;
 use parol::{
-    GrammarConfig, ParolGrammar, analysis::lalr1_parse_table::calculate_lalr1_parse_table,
-    calculate_lookahead_dfas, parser::parol_grammar::GrammarType,
+    GrammarConfig, ParolGrammar,
+    analysis::{
+        TerminalOverlap, detect_terminal_overlaps,
+        lalr1_parse_table::{LRResolvedConflict, calculate_lalr1_parse_table},
+    },
+    calculate_lookahead_dfas,
+    parser::parol_grammar::GrammarType,
 };
 
 use crate::{
-    config::ConfigProperties, diagnostics::Diagnostics, document_state::DocumentState,
-    formatting::FormattingSettings, parol_ls_parser::parse, utils::pos_to_offset,
+    analysis_worker::{AnalysisRequest, AnalysisWorker, DEFAULT_DEBOUNCE, TryInputRequest},
+    config::ConfigProperties,
+    diagnostics::Diagnostics,
+    document_state::DocumentState,
+    formatting::FormattingSettings,
+    parol_ls_parser::parse,
+    refactoring,
+    try_input::{TryInputParams, TryInputResult},
+    utils::{apply_content_change, pos_to_offset, uri_to_path},
+    workspace::WorkspaceIndex,
 };
 
 use regex::Regex;
@@ -52,27 +68,43 @@ macro_rules! update_number_option {
     };
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(crate) struct Server {
     /// Any documents the server has handled, indexed by their URL
     documents: HashMap<Uri, DocumentState>,
 
+    /// Worker thread that runs the expensive grammar analysis in the background
+    analysis_worker: AnalysisWorker,
+
     /// Limit for lookahead calculation.
     /// Be careful with high values. The server can get stuck for some grammars.
     max_k: usize,
 
     /// Aggregated formatting settings
     formatting_settings: FormattingSettings,
+
+    /// Index of the grammars and build scripts in the workspace folders
+    workspace_index: Arc<Mutex<WorkspaceIndex>>,
 }
 
 impl Server {
-    pub(crate) fn new(max_k: usize) -> Self {
+    pub(crate) fn new(max_k: usize, connection: Arc<lsp_server::Connection>) -> Self {
         Self {
+            documents: HashMap::new(),
+            analysis_worker: AnalysisWorker::new(connection, DEFAULT_DEBOUNCE),
             max_k,
             formatting_settings: FormattingSettings::default(),
-            ..Default::default()
+            workspace_index: Arc::default(),
         }
     }
+
+    ///
+    /// Starts indexing the workspace folders in the background.
+    /// Workspace symbol queries answer with the grammars indexed so far.
+    ///
+    pub(crate) fn index_workspace(&mut self, folders: &[PathBuf]) {
+        WorkspaceIndex::index_folders(self.workspace_index.clone(), folders.to_vec());
+    }
     pub(crate) fn update_configuration(
         &mut self,
         props: &ConfigProperties,
@@ -83,13 +115,13 @@ impl Server {
         Ok(())
     }
 
-    pub(crate) fn analyze(
-        &mut self,
-        uri: Uri,
-        version: i32,
-        connection: Arc<lsp_server::Connection>,
-    ) -> anyhow::Result<()> {
+    ///
+    /// Parses the document synchronously and schedules the grammar analysis on the worker thread.
+    /// The worker publishes the diagnostics of the analysis.
+    ///
+    pub(crate) fn analyze(&mut self, uri: Uri, version: i32) -> anyhow::Result<()> {
         let file_path: PathBuf = PathBuf::from(uri.path().to_string());
+        let related_information = self.build_information(&uri);
         let document_state = self.documents.get_mut(&uri).unwrap();
         eprintln!("analyze: step 1 - parse");
         document_state.clear();
@@ -98,21 +130,37 @@ impl Server {
             &file_path,
             &mut document_state.parsed_data,
         )?;
-        eprintln!("analyze: step 2 - check_grammar");
-        let document_state = self.documents.get(&uri).unwrap();
-        Self::check_grammar(
-            &document_state.input,
-            &file_path,
-            self.max_k,
-            connection,
+        self.workspace_index.lock().unwrap().update_grammar(
+            uri.clone(),
+            &uri_to_path(&uri),
+            &document_state.parsed_data,
+        );
+        eprintln!("analyze: step 2 - schedule check_grammar");
+        self.analysis_worker.schedule(AnalysisRequest {
             uri,
             version,
-            document_state.clone(),
-        )?;
-        eprintln!("analyze: finished");
+            file_name: file_path,
+            max_k: self.max_k,
+            document_state: document_state.clone(),
+            related_information,
+        });
         Ok(())
     }
 
+    ///
+    /// Related information about the build scripts that process the grammar and the modules they
+    /// generate from it.
+    ///
+    fn build_information(&self, uri: &Uri) -> Vec<DiagnosticRelatedInformation> {
+        self.workspace_index
+            .lock()
+            .unwrap()
+            .grammar_builds(&uri_to_path(uri))
+            .iter()
+            .filter_map(|build| build.related_information())
+            .collect()
+    }
+
     pub(crate) fn obtain_grammar_config_from_string(
         input: &str,
         file_name: &Path,
@@ -122,15 +170,14 @@ impl Server {
         GrammarConfig::try_from(parol_grammar)
     }
 
-    pub(crate) fn check_grammar(
+    ///
+    /// Obtains the grammar configuration and applies the same checks and transformations as the
+    /// parser generator.
+    ///
+    pub(crate) fn obtain_transformed_grammar_config(
         input: &str,
         file_name: &Path,
-        max_k: usize,
-        connection: Arc<lsp_server::Connection>,
-        uri: Uri,
-        version: i32,
-        document_state: DocumentState,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<GrammarConfig> {
         let mut grammar_config = Self::obtain_grammar_config_from_string(input, file_name)?;
         let ignored_unreachable_non_terminals = grammar_config
             .unreachable_non_terminals_to_ignore
@@ -143,40 +190,38 @@ impl Server {
             &ignored_unreachable_non_terminals,
         )?;
         grammar_config.update_cfg(cfg);
-        let grammar_config = grammar_config.clone();
-        thread::spawn(move || match grammar_config.grammar_type {
+        Ok(grammar_config)
+    }
+
+    ///
+    /// Checks the grammar and calculates the lookahead DFAs or the LALR(1) parse table
+    /// respectively.
+    /// Returns the automatically resolved conflicts of LALR(1) grammars and the shadowed or
+    /// overlapping terminals.
+    ///
+    /// This can take a long time for some grammars and is therefore called from the analysis
+    /// worker thread.
+    ///
+    pub(crate) fn check_grammar(
+        input: &str,
+        file_name: &Path,
+        max_k: usize,
+    ) -> anyhow::Result<GrammarCheckResult> {
+        let grammar_config = Self::obtain_transformed_grammar_config(input, file_name)?;
+        let resolved_conflicts = match grammar_config.grammar_type {
             GrammarType::LLK => {
-                if let Err(err) = calculate_lookahead_dfas(&grammar_config, max_k) {
-                    eprintln!("check_grammar: errors from calculate_lookahead_dfas");
-                    let _ =
-                        Self::notify_analysis_error(err, connection, &uri, version, document_state);
-                }
+                calculate_lookahead_dfas(&grammar_config, max_k)?;
+                Vec::new()
             }
             GrammarType::LALR1 => {
-                let result = calculate_lalr1_parse_table(&grammar_config);
-                match result {
-                    Ok((_, resolved_conflicts)) => {
-                        let _ = Self::notify_resolved_conflicts(
-                            resolved_conflicts,
-                            connection,
-                            &uri,
-                            version,
-                        );
-                    }
-                    Err(err) => {
-                        eprintln!("check_grammar: errors from calculate_lookahead_dfas");
-                        let _ = Self::notify_analysis_error(
-                            err,
-                            connection,
-                            &uri,
-                            version,
-                            document_state,
-                        );
-                    }
-                }
+                let (_, resolved_conflicts) = calculate_lalr1_parse_table(&grammar_config)?;
+                resolved_conflicts
             }
-        });
-        Ok(())
+        };
+        Ok(GrammarCheckResult {
+            resolved_conflicts,
+            terminal_overlaps: detect_terminal_overlaps(&grammar_config)?,
+        })
     }
 
     pub(crate) fn handle_open_document(
@@ -192,36 +237,11 @@ impl Server {
                 ..Default::default()
             },
         );
-        match self.analyze(
-            params.text_document.uri.clone(),
+        self.analyze_and_notify(
+            connection,
+            params.text_document.uri,
             params.text_document.version,
-            connection.clone(),
-        ) {
-            Ok(()) => {
-                eprintln!("handle_open_document: ok");
-                Self::notify_analysis_ok(
-                    connection,
-                    params.text_document.uri,
-                    params.text_document.version,
-                )?;
-            }
-            Err(err) => {
-                eprintln!("handle_open_document: error");
-                let document_state = self
-                    .documents
-                    .get(&params.text_document.uri)
-                    .unwrap()
-                    .clone();
-                Self::notify_analysis_error(
-                    err,
-                    connection,
-                    &params.text_document.uri,
-                    params.text_document.version,
-                    document_state,
-                )?;
-            }
-        }
-        Ok(())
+        )
     }
 
     pub(crate) fn handle_change_document(
@@ -231,32 +251,36 @@ impl Server {
     ) -> Result<(), Box<dyn Error>> {
         let params: DidChangeTextDocumentParams = n.extract(DidChangeTextDocument::METHOD)?;
         self.apply_changes(&params.text_document.uri, &params.content_changes);
-        match self.analyze(
-            params.text_document.uri.clone(),
+        self.analyze_and_notify(
+            connection,
+            params.text_document.uri,
             params.text_document.version,
-            connection.clone(),
-        ) {
+        )
+    }
+
+    fn analyze_and_notify(
+        &mut self,
+        connection: Arc<lsp_server::Connection>,
+        uri: Uri,
+        version: i32,
+    ) -> Result<(), Box<dyn Error>> {
+        // Any analysis of an older version still running in the background is outdated now
+        self.analysis_worker.supersede(&uri, version);
+        match self.analyze(uri.clone(), version) {
             Ok(()) => {
-                eprintln!("handle_change_document: ok");
-                Self::notify_analysis_ok(
-                    connection,
-                    params.text_document.uri,
-                    params.text_document.version,
-                )?;
+                eprintln!("analyze_and_notify: ok");
             }
             Err(err) => {
-                eprintln!("handle_change_document: error");
-                let document_state = self
-                    .documents
-                    .get(&params.text_document.uri)
-                    .unwrap()
-                    .clone();
+                eprintln!("analyze_and_notify: error");
+                let document_state = self.documents.get(&uri).unwrap().clone();
+                let related_information = self.build_information(&uri);
                 Self::notify_analysis_error(
                     err,
                     connection,
-                    &params.text_document.uri,
-                    params.text_document.version,
+                    &uri,
+                    version,
                     document_state,
+                    &related_information,
                 )?;
             }
         }
@@ -269,9 +293,43 @@ impl Server {
     ) -> Result<(), Box<dyn Error>> {
         let params: DidCloseTextDocumentParams = n.extract(DidCloseTextDocument::METHOD)?;
         self.cleanup(&params.text_document.uri);
+        // From now on the content on disk is authoritative
+        self.workspace_index
+            .lock()
+            .unwrap()
+            .index_file(&uri_to_path(&params.text_document.uri));
+        Ok(())
+    }
+
+    pub(crate) fn handle_changed_watched_files(
+        &mut self,
+        n: lsp_server::Notification,
+    ) -> Result<(), Box<dyn Error>> {
+        let params: DidChangeWatchedFilesParams = n.extract(DidChangeWatchedFiles::METHOD)?;
+        for change in params.changes {
+            // Open documents are indexed from their current content
+            if self.documents.contains_key(&change.uri) {
+                continue;
+            }
+            let path = uri_to_path(&change.uri);
+            if change.typ == FileChangeType::DELETED {
+                self.workspace_index.lock().unwrap().remove_file(&path);
+            } else {
+                self.workspace_index.lock().unwrap().index_file(&path);
+            }
+        }
         Ok(())
     }
 
+    pub(crate) fn handle_workspace_symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> Option<WorkspaceSymbolResponse> {
+        Some(WorkspaceSymbolResponse::Nested(
+            self.workspace_index.lock().unwrap().symbols(&params.query),
+        ))
+    }
+
     pub(crate) fn handle_goto_definition(
         &mut self,
         params: GotoDefinitionParams,
@@ -356,13 +414,79 @@ impl Server {
         mut params: DocumentFormattingParams,
     ) -> Option<Vec<TextEdit>> {
         if let Some(document_state) = self.documents.get(&params.text_document.uri) {
-            self.formatting_settings.add_to_options(&mut params.options);
+            self.add_formatting_options(&params.text_document.uri, &mut params.options);
             document_state.format(params)
         } else {
             None
         }
     }
 
+    pub(crate) fn handle_range_formatting(
+        &self,
+        mut params: DocumentRangeFormattingParams,
+    ) -> Option<Vec<TextEdit>> {
+        if let Some(document_state) = self.documents.get(&params.text_document.uri) {
+            self.add_formatting_options(&params.text_document.uri, &mut params.options);
+            document_state.format_range(params)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn handle_on_type_formatting(
+        &self,
+        mut params: DocumentOnTypeFormattingParams,
+    ) -> Option<Vec<TextEdit>> {
+        let uri = &params.text_document_position.text_document.uri;
+        if let Some(document_state) = self.documents.get(uri) {
+            self.add_formatting_options(uri, &mut params.options);
+            document_state.format_on_type(params)
+        } else {
+            None
+        }
+    }
+
+    /// Adds the formatting settings to the options of a formatting request.
+    /// The options of a `.parolfmt.toml` file found for the document take precedence over the
+    /// client's settings.
+    fn add_formatting_options(&self, uri: &Uri, options: &mut FormattingOptions) {
+        let file_path = PathBuf::from(uri.path().to_string());
+        let format_config = FormatConfig::for_grammar_file(&file_path).unwrap_or_else(|err| {
+            eprintln!("Ignoring formatting configuration: {err}");
+            FormatConfig::default()
+        });
+        self.formatting_settings
+            .with_format_config(&format_config)
+            .add_to_options(options);
+    }
+
+    ///
+    /// Schedules the parsing of the sample text on the analysis worker, because it needs the
+    /// lookahead DFAs or the parse table of the grammar.
+    /// Returns an error result if the grammar is not opened, otherwise the worker responds.
+    ///
+    pub(crate) fn handle_try_input(
+        &self,
+        id: RequestId,
+        params: TryInputParams,
+    ) -> Option<TryInputResult> {
+        let uri = &params.text_document.uri;
+        let Some(document_state) = self.documents.get(uri) else {
+            return Some(TryInputResult::from_grammar_error(&anyhow::anyhow!(
+                "Grammar {} is not opened",
+                uri.path()
+            )));
+        };
+        self.analysis_worker.schedule_try_input(TryInputRequest {
+            id,
+            file_name: PathBuf::from(uri.path().to_string()),
+            max_k: self.max_k,
+            grammar: document_state.input.clone(),
+            input: params.input,
+        });
+        None
+    }
+
     pub(crate) fn handle_code_action(
         &self,
         params: CodeActionParams,
@@ -404,6 +528,14 @@ impl Server {
                 }
             }
 
+            if code == "parol_runtime::parser::syntax_error" {
+                actions.extend(
+                    Self::make_remove_unexpected_token_actions(uri, document_state, &diagnostic)
+                        .into_iter()
+                        .map(CodeActionOrCommand::CodeAction),
+                );
+            }
+
             if code == "parol::parser::token_not_in_scanner" {
                 let candidates = Self::replacement_candidates(
                     &document_state.input,
@@ -431,6 +563,12 @@ impl Server {
             }
         }
 
+        actions.extend(
+            refactoring::code_actions(uri, document_state, params.range)
+                .into_iter()
+                .map(CodeActionOrCommand::CodeAction),
+        );
+
         Some(actions)
     }
 
@@ -446,23 +584,20 @@ impl Server {
     }
 
     fn cleanup(&mut self, uri: &Uri) {
+        self.analysis_worker.cancel(uri);
         self.documents.remove(uri);
     }
 
+    /// The changes are applied in the order they were sent by the client.
+    /// Each change refers to the document state after the previous change has been applied.
     fn apply_changes(&mut self, uri: &Uri, content_changes: &[TextDocumentContentChangeEvent]) {
-        if let Some(change) = content_changes.last() {
-            self.apply_change(uri, change);
+        if let Some(document_state) = self.documents.get_mut(uri) {
+            for change in content_changes {
+                apply_content_change(&mut document_state.input, change);
+            }
         }
     }
 
-    fn apply_change(&mut self, uri: &Uri, change: &TextDocumentContentChangeEvent) {
-        self.documents
-            .get_mut(uri)
-            .unwrap()
-            .input
-            .clone_from(&change.text);
-    }
-
     fn find_user_type_definitions(
         document_state: &DocumentState,
         text_at_position: String,
@@ -688,6 +823,43 @@ impl Server {
         current.or_else(|| Some("INITIAL".to_owned()))
     }
 
+    /// Offers the tokens the parser deleted during error recovery for removal
+    fn make_remove_unexpected_token_actions(
+        uri: &Uri,
+        document_state: &DocumentState,
+        diagnostic: &lsp_types::Diagnostic,
+    ) -> Vec<CodeAction> {
+        let Some(deletions) = diagnostic
+            .data
+            .as_ref()
+            .and_then(|data| data.get("deletions"))
+            .and_then(|deletions| serde_json::from_value::<Vec<Range>>(deletions.clone()).ok())
+        else {
+            return Vec::new();
+        };
+        deletions
+            .into_iter()
+            .filter_map(|range| {
+                let text = Self::text_from_range(&document_state.input, range)?;
+                let edit = TextEdit {
+                    range,
+                    new_text: String::new(),
+                };
+                Some(CodeAction {
+                    title: format!("Remove unexpected token '{text}'"),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+                        ..Default::default()
+                    }),
+                    is_preferred: Some(false),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
     fn make_delete_line_action(
         uri: &Uri,
         document_state: &DocumentState,
@@ -783,7 +955,7 @@ impl Server {
     }
 
     fn line_char_len(input: &str, line: u32) -> Option<u32> {
-        Self::line_text(input, line).map(|line_text| line_text.chars().count() as u32)
+        Self::line_text(input, line).map(|line_text| line_text.encode_utf16().count() as u32)
     }
 
     fn line_content_range(input: &str, line: u32) -> Option<Range> {
@@ -828,7 +1000,7 @@ impl Server {
         Some(input.get(start..end)?.trim().to_owned())
     }
 
-    fn notify_analysis_ok(
+    pub(crate) fn notify_analysis_ok(
         connection: Arc<lsp_server::Connection>,
         uri: Uri,
         version: i32,
@@ -845,18 +1017,17 @@ impl Server {
         Ok(())
     }
 
-    fn notify_analysis_error(
+    pub(crate) fn notify_analysis_error(
         err: anyhow::Error,
         connection: Arc<lsp_server::Connection>,
         uri: &Uri,
         version: i32,
         document_state: DocumentState,
+        related_information: &[DiagnosticRelatedInformation],
     ) -> Result<(), Box<dyn Error>> {
-        let result = PublishDiagnosticsParams::new(
-            uri.clone(),
-            Diagnostics::to_diagnostics(uri, &document_state, err),
-            Some(version),
-        );
+        let mut diagnostics = Diagnostics::to_diagnostics(uri, &document_state, err);
+        Diagnostics::add_related_information(&mut diagnostics, related_information);
+        let result = PublishDiagnosticsParams::new(uri.clone(), diagnostics, Some(version));
         let params = serde_json::to_value(result).unwrap();
         let method = <PublishDiagnostics as Notification>::METHOD.to_string();
         connection
@@ -868,23 +1039,32 @@ impl Server {
         Ok(())
     }
 
-    fn notify_resolved_conflicts(
-        resolved_conflicts: Vec<parol::analysis::lalr1_parse_table::LRResolvedConflict>,
+    pub(crate) fn notify_grammar_check_findings(
+        check_result: GrammarCheckResult,
         connection: Arc<lsp_server::Connection>,
         uri: &Uri,
         version: i32,
+        document_state: &DocumentState,
+        related_information: &[DiagnosticRelatedInformation],
     ) -> Result<(), Box<dyn Error>> {
-        if resolved_conflicts.is_empty() {
-            return Ok(());
-        }
-        let result = PublishDiagnosticsParams::new(
-            uri.clone(),
-            vec![Diagnostics::to_resolved_conflict_warning(
+        let GrammarCheckResult {
+            resolved_conflicts,
+            terminal_overlaps,
+        } = check_result;
+        let mut diagnostics = Vec::new();
+        if !resolved_conflicts.is_empty() {
+            diagnostics.push(Diagnostics::to_resolved_conflict_warning(
                 uri,
                 resolved_conflicts,
-            )],
-            Some(version),
-        );
+            ));
+        }
+        diagnostics.extend(Diagnostics::to_terminal_overlap_diagnostics(
+            uri,
+            document_state,
+            &terminal_overlaps,
+        ));
+        Diagnostics::add_related_information(&mut diagnostics, related_information);
+        let result = PublishDiagnosticsParams::new(uri.clone(), diagnostics, Some(version));
         let params = serde_json::to_value(result).unwrap();
         let method = <PublishDiagnostics as Notification>::METHOD.to_string();
         connection
@@ -897,6 +1077,21 @@ impl Server {
     }
 }
 
+///
+/// The findings of a successful grammar check
+///
+#[derive(Debug, Default)]
+pub(crate) struct GrammarCheckResult {
+    pub(crate) resolved_conflicts: Vec<LRResolvedConflict>,
+    pub(crate) terminal_overlaps: Vec<TerminalOverlap>,
+}
+
+impl GrammarCheckResult {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.resolved_conflicts.is_empty() && self.terminal_overlaps.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{DocumentState, Server};
@@ -924,7 +1119,7 @@ WS: /[ \t\r\n]+/;
         let col = before
             .lines()
             .last()
-            .map(|l| l.chars().count() as u32)
+            .map(|l| l.encode_utf16().count() as u32)
             .unwrap_or(0);
         Range {
             start: Position {
@@ -933,7 +1128,7 @@ WS: /[ \t\r\n]+/;
             },
             end: Position {
                 line,
-                character: col + needle.chars().count() as u32,
+                character: col + needle.encode_utf16().count() as u32,
             },
         }
     }
@@ -1032,6 +1227,35 @@ GOOD: "good";
         assert_eq!(edit.new_text, "    %skip GOOD // keep scanner hygiene");
     }
 
+    #[test]
+    fn make_remove_unexpected_token_actions_delete_skipped_tokens() {
+        let input = "%start S\n%%\nS: \"x\" ) ;\n";
+        let uri = lsp_types::Uri::from_str("file:///test.par").expect("valid URI");
+        let document_state = DocumentState {
+            input: input.to_string(),
+            ..Default::default()
+        };
+        let range = range_of(input, ")");
+        let diagnostic = Diagnostic {
+            data: Some(serde_json::json!({ "deletions": [range] })),
+            ..Default::default()
+        };
+
+        let actions =
+            Server::make_remove_unexpected_token_actions(&uri, &document_state, &diagnostic);
+        assert_eq!(1, actions.len());
+        assert_eq!(actions[0].title, "Remove unexpected token ')'");
+        let edit = actions[0]
+            .edit
+            .as_ref()
+            .and_then(|e| e.changes.as_ref())
+            .and_then(|c| c.get(&uri))
+            .expect("changes for URI must exist")[0]
+            .clone();
+        assert_eq!(edit.range, range);
+        assert!(edit.new_text.is_empty());
+    }
+
     #[test]
     fn make_remove_from_skip_action_is_none_for_single_token_skip_list() {
         let input = r#"%start S
@@ -1058,4 +1282,45 @@ BAD: "bad";
         let action = Server::make_remove_from_skip_action(&uri, &document_state, &diagnostic);
         assert!(action.is_none());
     }
+
+    #[test]
+    fn check_grammar_reports_shadowed_terminals_at_their_first_occurrence() {
+        let input = r#"%start S
+%%
+S: Identifier | Begin;
+Identifier: /[a-z]+/;
+Begin: "begin";
+"#;
+        let uri = lsp_types::Uri::from_str("file:///test.par").expect("valid URI");
+        let mut document_state = DocumentState {
+            input: input.to_string(),
+            ..Default::default()
+        };
+        crate::parol_ls_parser::parse(input, "test.par", &mut document_state.parsed_data)
+            .expect("grammar must parse");
+
+        let check_result = Server::check_grammar(input, std::path::Path::new("test.par"), 5)
+            .expect("grammar check must succeed");
+        assert!(check_result.resolved_conflicts.is_empty());
+        let diagnostics = crate::diagnostics::Diagnostics::to_terminal_overlap_diagnostics(
+            &uri,
+            &document_state,
+            &check_result.terminal_overlaps,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range, range_of(input, "\"begin\""));
+        assert_eq!(
+            diagnostics[0].severity,
+            Some(lsp_types::DiagnosticSeverity::WARNING)
+        );
+        let related_information = diagnostics[0]
+            .related_information
+            .as_ref()
+            .expect("shadowing terminal must be referenced");
+        assert_eq!(
+            related_information[0].location.range,
+            range_of(input, "/[a-z]+/")
+        );
+    }
 }
@@ -0,0 +1,400 @@
+//! Live preview of a grammar.
+//!
+//! The custom request `parol-ls/tryInput` parses a sample text with the current version of a
+//! grammar description and returns the resulting parse tree together with the syntax errors.
+//!
+//! No code is generated for this. Instead the sample text is parsed by the parsers of
+//! `parol_runtime` with the parse tables calculated by `parol`, see `parol::runtime_parser`.
+//! The scanner is emulated by `parol::scanner_emulation`.
+
+use anyhow::Result;
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, Position, Range, TextDocumentIdentifier, request::Request,
+};
+use parol::{
+    GrammarConfig, generators::generate_terminal_names, runtime_parser::RuntimeParser,
+    scanner_emulation::Scanner,
+};
+use parol_runtime::parser::parse_tree_type::TreeConstruct;
+use parol_runtime::{Location, ParolError, ParserError, Token, lexer::EOI};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+///
+/// The custom request to parse a sample text with a grammar.
+///
+#[derive(Debug)]
+pub(crate) enum TryInput {}
+
+impl Request for TryInput {
+    type Params = TryInputParams;
+    type Result = TryInputResult;
+    const METHOD: &'static str = "parol-ls/tryInput";
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TryInputParams {
+    /// The grammar description to use. The server uses its current content, including unsaved
+    /// changes.
+    pub(crate) text_document: TextDocumentIdentifier,
+    /// The sample text to parse
+    pub(crate) input: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TryInputResult {
+    /// Set if the grammar can't be used to parse the sample, e.g. because it has errors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) grammar_error: Option<String>,
+    /// All nodes of the parse tree. The nodes refer to their children by index.
+    /// After a syntax error LL(k) grammars provide the partial tree, LALR(1) grammars no tree.
+    pub(crate) nodes: Vec<ParseTreeNode>,
+    /// The indices of the nodes without a parent.
+    /// After a successful parse this is exactly the start symbol's node.
+    pub(crate) roots: Vec<usize>,
+    /// Syntax errors found in the sample text
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+impl TryInputResult {
+    pub(crate) fn from_grammar_error(err: &anyhow::Error) -> Self {
+        Self {
+            grammar_error: Some(format!("{err:#}")),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ParseTreeNode {
+    pub(crate) label: String,
+    pub(crate) is_terminal: bool,
+    /// The range in the sample text, missing for non-terminals that derived the empty word
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) range: Option<Range>,
+    pub(crate) children: Vec<usize>,
+}
+
+///
+/// Parses the input with the given, already transformed grammar configuration.
+/// LL(k) grammars are analyzed with a lookahead of at most `max_k`.
+///
+pub(crate) fn try_input(
+    mut grammar_config: GrammarConfig,
+    max_k: usize,
+    input: &str,
+) -> Result<TryInputResult> {
+    grammar_config.update_lookahead_size(max_k);
+    let terminal_names = generate_terminal_names(&grammar_config);
+    let scanner = Scanner::try_new(&grammar_config, &terminal_names)?;
+    let parser = RuntimeParser::try_new(&grammar_config, &terminal_names)?;
+    let positions = Positions::new(input);
+    let scanned = scanner.tokenize(input, Arc::default());
+    let mut diagnostics = scanned
+        .unmatched
+        .iter()
+        .map(|token| {
+            diagnostic(
+                positions.range(&token.location),
+                format!("No terminal matches the input {:?}", token.text()),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut tree_builder = PreviewTreeBuilder {
+        terminal_names: &terminal_names,
+        positions: &positions,
+        result: TryInputResult::default(),
+        open_nodes: Vec::new(),
+    };
+    if let Err(err) = parser.parse_into(&mut tree_builder, scanned.tokens, None) {
+        diagnostics.extend(syntax_errors(err, &positions));
+    }
+    let mut result = tree_builder.result;
+    // Children are always added after their parents
+    for i in (0..result.nodes.len()).rev() {
+        if !result.nodes[i].is_terminal {
+            result.nodes[i].range = children_range(&result.nodes, &result.nodes[i].children);
+        }
+    }
+    result.diagnostics = diagnostics;
+    Ok(result)
+}
+
+fn diagnostic(range: Range, message: String) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("parol-ls".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+/// Converts the errors of the parser into diagnostics
+fn syntax_errors(err: ParolError, positions: &Positions<'_>) -> Vec<Diagnostic> {
+    let end_of_input = Range {
+        start: positions.end(),
+        end: positions.end(),
+    };
+    let ParolError::ParserError(ParserError::SyntaxErrors { entries }) = err else {
+        return vec![diagnostic(end_of_input, err.to_string())];
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            let (found, range) = match entry.unexpected_tokens.first() {
+                Some(unexpected) if unexpected.token_type != "EndOfInput" => (
+                    format!(
+                        "{} {:?}",
+                        unexpected.token_type,
+                        positions.text(&unexpected.token)
+                    ),
+                    positions.range(&unexpected.token),
+                ),
+                _ => ("end of input".to_string(), end_of_input),
+            };
+            diagnostic(
+                range,
+                format!(
+                    "Unexpected token {found}, expected one of: {}",
+                    entry.expected_tokens
+                ),
+            )
+        })
+        .collect()
+}
+
+fn children_range(nodes: &[ParseTreeNode], children: &[usize]) -> Option<Range> {
+    let mut ranges = children.iter().filter_map(|c| nodes[*c].range);
+    let first = ranges.next()?;
+    let last = ranges.next_back().unwrap_or(first);
+    Some(Range {
+        start: first.start,
+        end: last.end,
+    })
+}
+
+/// Converts byte offsets of the sample text into LSP positions
+#[derive(Debug)]
+struct Positions<'t> {
+    input: &'t str,
+    line_starts: Vec<usize>,
+}
+
+impl<'t> Positions<'t> {
+    fn new(input: &'t str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(input.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { input, line_starts }
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.input.len());
+        let line = self.line_starts.partition_point(|start| *start <= offset) - 1;
+        Position {
+            line: line as u32,
+            character: self.input[self.line_starts[line]..offset]
+                .encode_utf16()
+                .count() as u32,
+        }
+    }
+
+    fn end(&self) -> Position {
+        self.position(self.input.len())
+    }
+
+    fn range(&self, location: &Location) -> Range {
+        Range {
+            start: self.position(location.start as usize),
+            end: self.position(location.end as usize),
+        }
+    }
+
+    fn text(&self, location: &Location) -> &'t str {
+        self.input
+            .get(location.start as usize..location.end as usize)
+            .unwrap_or_default()
+    }
+}
+
+///
+/// Builds the nodes of the preview from the parse tree the parser produces.
+/// The parser's root node that has no name isn't part of the preview, its children are the roots.
+///
+struct PreviewTreeBuilder<'a, 't> {
+    terminal_names: &'a [String],
+    positions: &'a Positions<'t>,
+    result: TryInputResult,
+    /// The open non-terminals, None for the parser's root node
+    open_nodes: Vec<Option<usize>>,
+}
+
+impl PreviewTreeBuilder<'_, '_> {
+    fn add_node(&mut self, label: String, is_terminal: bool, range: Option<Range>) -> usize {
+        let index = self.result.nodes.len();
+        self.result.nodes.push(ParseTreeNode {
+            label,
+            is_terminal,
+            range,
+            children: Vec::new(),
+        });
+        match self.open_nodes.last() {
+            Some(Some(parent)) => self.result.nodes[*parent].children.push(index),
+            _ => self.result.roots.push(index),
+        }
+        index
+    }
+}
+
+impl<'t> TreeConstruct<'t> for PreviewTreeBuilder<'_, '_> {
+    type Error = ParolError;
+    type Tree = ();
+
+    fn open_non_terminal(
+        &mut self,
+        name: &'static str,
+        _size_hint: Option<usize>,
+    ) -> Result<(), Self::Error> {
+        let node = (!name.is_empty()).then(|| self.add_node(name.to_string(), false, None));
+        self.open_nodes.push(node);
+        Ok(())
+    }
+
+    fn close_non_terminal(&mut self) -> Result<(), Self::Error> {
+        self.open_nodes.pop();
+        Ok(())
+    }
+
+    fn add_token(&mut self, token: &Token<'t>) -> Result<(), Self::Error> {
+        if token.token_type != EOI {
+            let label = format!(
+                "{} {:?}",
+                self.terminal_names[token.token_type as usize],
+                token.text()
+            );
+            self.add_node(label, true, Some(self.positions.range(&token.location)));
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Result<Self::Tree, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TryInputResult, try_input};
+    use crate::server::Server;
+    use std::path::Path;
+
+    fn parse(grammar: &str, input: &str) -> TryInputResult {
+        let grammar_config =
+            Server::obtain_transformed_grammar_config(grammar, Path::new("test.par")).unwrap();
+        try_input(grammar_config, 3, input).unwrap()
+    }
+
+    fn render(result: &TryInputResult) -> String {
+        fn render_node(result: &TryInputResult, node: usize, indent: usize, out: &mut String) {
+            let node = &result.nodes[node];
+            out.push_str(&format!("{}{}\n", "  ".repeat(indent), node.label));
+            for child in &node.children {
+                render_node(result, *child, indent + 1, out);
+            }
+        }
+        let mut out = String::new();
+        for root in &result.roots {
+            render_node(result, *root, 0, &mut out);
+        }
+        out
+    }
+
+    const LLK_GRAMMAR: &str = r#"%start List
+%line_comment "//"
+%%
+List: Item { "," Item };
+Item: "[0-9]+" | "[a-z]+";
+"#;
+
+    #[test]
+    fn parse_tree_of_llk_grammar() {
+        let result = parse(LLK_GRAMMAR, "1, ab // comment\n, 2");
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(result.roots, vec![0]);
+        let root = &result.nodes[0];
+        assert_eq!(root.label, "List");
+        let range = root.range.unwrap();
+        assert_eq!((range.start.line, range.start.character), (0, 0));
+        assert_eq!((range.end.line, range.end.character), (1, 3));
+        let tree = render(&result);
+        assert!(tree.contains("Comma \",\""), "{tree}");
+        assert!(tree.contains("\"ab\""), "{tree}");
+        assert!(!tree.contains("comment"), "{tree}");
+    }
+
+    #[test]
+    fn unmatched_input_is_a_lexer_error() {
+        let result = parse(LLK_GRAMMAR, "1, ab ?!\n, 2");
+        assert_eq!(result.diagnostics.len(), 1, "{:?}", result.diagnostics);
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(diagnostic.range.start.character, 6);
+        assert_eq!(diagnostic.range.end.character, 8);
+        assert!(
+            diagnostic.message.contains("\"?!\""),
+            "{}",
+            diagnostic.message
+        );
+    }
+
+    #[test]
+    fn syntax_error_of_llk_grammar() {
+        let result = parse(LLK_GRAMMAR, "1,\n,");
+        assert_eq!(result.diagnostics.len(), 1);
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(diagnostic.range.start.line, 1);
+        assert_eq!(diagnostic.range.start.character, 0);
+        assert!(diagnostic.message.starts_with("Unexpected token Comma"));
+        // The partial tree is still available
+        assert_eq!(result.nodes[0].label, "List");
+    }
+
+    #[test]
+    fn terminals_take_the_longest_match() {
+        let result = parse(
+            "%start L\n%%\nL: { Num };\nNum: /\\d+|\\d+\\.\\d+/;\n",
+            "1.5 2",
+        );
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert!(render(&result).contains("\"1.5\""));
+    }
+
+    const LALR_GRAMMAR: &str = r#"%start E
+%grammar_type 'LALR(1)'
+%%
+E: E "\+" T | T;
+T: "[0-9]+";
+"#;
+
+    #[test]
+    fn parse_tree_of_lalr1_grammar() {
+        let result = parse(LALR_GRAMMAR, "1 + 2 + 3");
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(result.roots.len(), 1);
+        // The transformed grammar starts with E0, because the start symbol E is recursive
+        let root = &result.nodes[result.roots[0]];
+        assert_eq!(root.label, "E0");
+        assert_eq!(root.range.unwrap().end.character, 9);
+        assert_eq!(render(&result).matches("E\n").count(), 3);
+    }
+
+    #[test]
+    fn syntax_error_of_lalr1_grammar() {
+        let result = parse(LALR_GRAMMAR, "1 + + 3");
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].range.start.character, 4);
+    }
+}
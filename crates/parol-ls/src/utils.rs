@@ -1,4 +1,7 @@
-use lsp_types::{Position, Range};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use lsp_types::{Position, Range, TextDocumentContentChangeEvent, Uri};
 use parol_runtime::lexer::Location;
 use parol_runtime::once_cell::sync::Lazy;
 use regex::Regex;
@@ -10,6 +13,8 @@ pub(crate) static RX_NEW_LINE: Lazy<Regex> =
 
 ///
 /// Converts `parol_runtime::lexer::Location` to a `lsp_types::Range`.
+/// The columns of the location must be counted in UTF-16 code units, like the ones of the
+/// parol-ls parser. Use `offsets_to_range` for locations of other parsers.
 ///
 pub(crate) fn location_to_range(location: &Location) -> Range {
     Range {
@@ -25,19 +30,45 @@ pub(crate) fn location_to_range(location: &Location) -> Range {
 }
 
 ///
-/// Converts `parol_runtime::lexer::Location` to a `lsp_types::Location`.
-/// Uri is usually taken from the `LocatedDocumentState`.
+/// Converts the byte offsets of a `parol_runtime::lexer::Location` in the given input to a
+/// `lsp_types::Range`. This works regardless of the unit in which the location counts columns.
+///
+pub(crate) fn offsets_to_range(input: &str, location: &Location) -> Range {
+    let start = (location.start as usize).min(input.len());
+    let end = (location.end as usize).clamp(start, input.len());
+    Range {
+        start: offset_to_pos(input, floor_char_boundary(input, start)),
+        end: offset_to_pos(input, floor_char_boundary(input, end)),
+    }
+}
+
+///
+/// Converts the byte offsets of a `parol_runtime::lexer::Location` in the given input to a
+/// `lsp_types::Location`.
 ///
-pub(crate) fn location_to_location(
+pub(crate) fn offsets_to_location(
+    input: &str,
     location: &Location,
     uri: &lsp_types::Uri,
 ) -> lsp_types::Location {
     lsp_types::Location {
         uri: uri.to_owned(),
-        range: location_to_range(location),
+        range: offsets_to_range(input, location),
     }
 }
 
+fn floor_char_boundary(input: &str, mut offset: usize) -> usize {
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+///
+/// Converts a position to a byte offset into the input.
+/// The character of the position is counted in UTF-16 code units, the default position encoding
+/// of the Language Server Protocol.
+///
 pub(crate) fn pos_to_offset(input: &str, pos: Position) -> usize {
     let mut offset = 0;
     for line in input.lines().take(pos.line as usize) {
@@ -56,13 +87,92 @@ pub(crate) fn pos_to_offset(input: &str, pos: Position) -> usize {
     if let Some(last_line) = input.lines().nth(pos.line as usize)
         && !last_line.is_empty()
     {
-        if let Some((p, _)) = last_line.char_indices().nth(pos.character as usize) {
-            offset += p
+        let mut units = 0;
+        let p = last_line
+            .char_indices()
+            .find(|(_, c)| {
+                units += c.len_utf16();
+                units > pos.character as usize
+            })
+            .map_or(last_line.len(), |(p, _)| p);
+        offset += p
+    }
+    offset
+}
+
+///
+/// Converts a byte offset into the input to a position.
+/// The character of the position is counted in UTF-16 code units like in `pos_to_offset`.
+///
+pub(crate) fn offset_to_pos(input: &str, offset: usize) -> Position {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+///
+/// Converts a file URI to a file system path.
+/// Percent-encoded characters are decoded.
+///
+pub(crate) fn uri_to_path(uri: &Uri) -> PathBuf {
+    let path = uri.path().to_string();
+    let mut bytes = Vec::with_capacity(path.len());
+    let mut rest = path.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        let decoded = (b == b'%')
+            .then(|| tail.get(..2))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match decoded {
+            Some(decoded) => {
+                bytes.push(decoded);
+                rest = &tail[2..];
+            }
+            None => {
+                bytes.push(b);
+                rest = tail;
+            }
+        }
+    }
+    PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+///
+/// Converts a file system path to a file URI.
+/// Characters that are not allowed in URI paths are percent-encoded.
+///
+pub(crate) fn path_to_uri(path: &Path) -> Option<Uri> {
+    let path = path.to_string_lossy().replace('\\', "/");
+    let mut uri = String::from("file://");
+    if !path.starts_with('/') {
+        uri.push('/');
+    }
+    for b in path.bytes() {
+        if b.is_ascii_alphanumeric() || b"-._~/:".contains(&b) {
+            uri.push(b as char);
         } else {
-            offset += last_line.char_indices().last().unwrap().0 + 1
+            uri.push_str(&format!("%{b:02X}"));
         }
     }
-    offset
+    Uri::from_str(&uri).ok()
+}
+
+///
+/// Applies a single content change sent by the client to the given document text.
+/// Changes without a range replace the whole document.
+///
+pub(crate) fn apply_content_change(input: &mut String, change: &TextDocumentContentChangeEvent) {
+    match change.range {
+        Some(range) => {
+            let start = pos_to_offset(input, range.start).min(input.len());
+            let end = pos_to_offset(input, range.end).clamp(start, input.len());
+            input.replace_range(start..end, &change.text);
+        }
+        None => input.clone_from(&change.text),
+    }
 }
 
 pub(crate) fn extract_text_range(input: &str, rng: Rng) -> &str {
@@ -103,6 +213,73 @@ TrailingComma: [","^];"#;
 EXPECTED
 "#;
 
+    fn change(range: Option<Range>, text: &str) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range,
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range {
+            start: Position {
+                line: start.0,
+                character: start.1,
+            },
+            end: Position {
+                line: end.0,
+                character: end.1,
+            },
+        }
+    }
+
+    #[test]
+    fn test_offset_to_pos() {
+        for offset in [0, 2, 6, 7, 10, CONTENT2.len()] {
+            let pos = offset_to_pos(CONTENT2, offset);
+            assert_eq!(offset, pos_to_offset(CONTENT2, pos), "{pos:?}");
+        }
+    }
+
+    #[test]
+    fn test_astral_characters_count_two_utf16_units() {
+        let mut input = "a\u{1F600}b\nc".to_string();
+        let pos = offset_to_pos(&input, 5);
+        assert_eq!((0, 3), (pos.line, pos.character));
+        assert_eq!(5, pos_to_offset(&input, pos));
+        apply_content_change(&mut input, &change(Some(range((0, 3), (0, 4))), "X"));
+        assert_eq!("a\u{1F600}X\nc", input);
+        apply_content_change(&mut input, &change(Some(range((1, 0), (1, 1))), "d"));
+        assert_eq!("a\u{1F600}X\nd", input);
+        let location = Location {
+            start: 5,
+            end: 6,
+            ..Default::default()
+        };
+        assert_eq!(range((0, 3), (0, 4)), offsets_to_range(&input, &location));
+    }
+
+    #[test]
+    fn test_apply_content_change() {
+        let mut input = CONTENT2.to_string();
+        // Replace within a line with multi-byte characters
+        apply_content_change(&mut input, &change(Some(range((0, 1), (0, 2))), "X"));
+        assert_eq!("ÜXÖ\nEXPECTED\n", input);
+        // Insert at a position
+        apply_content_change(&mut input, &change(Some(range((1, 0), (1, 0))), "UN"));
+        assert_eq!("ÜXÖ\nUNEXPECTED\n", input);
+        // Delete across lines
+        apply_content_change(&mut input, &change(Some(range((0, 3), (1, 2))), ""));
+        assert_eq!("ÜXÖEXPECTED\n", input);
+        // Append at the end of the document
+        apply_content_change(&mut input, &change(Some(range((1, 0), (1, 0))), "END"));
+        assert_eq!("ÜXÖEXPECTED\nEND", input);
+        // Full text replacement
+        apply_content_change(&mut input, &change(None, "new"));
+        assert_eq!("new", input);
+    }
+
     #[test]
     fn test_pos_to_offset() {
         {
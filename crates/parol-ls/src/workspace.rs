@@ -0,0 +1,552 @@
+//! Index of the grammar descriptions in the workspace folders.
+//!
+//! The index holds the symbols of all `.par` files of the workspace, whether they are opened in
+//! the editor or not, to answer workspace symbol queries. Open documents are indexed from their
+//! current content, all other files from their content on disk.
+//!
+//! Additionally the build scripts (`build.rs`) of the workspace are scanned for calls of
+//! `Builder::grammar_file`. This way the server knows which grammars are processed by a build
+//! script and which modules are generated from them.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use lsp_types::{
+    DiagnosticRelatedInformation, Location, OneOf, Range, SymbolKind, Uri, WorkspaceSymbol,
+};
+use parol_runtime::once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::{
+    parol_ls_grammar::ParolLsGrammar,
+    parol_ls_parser::parse,
+    symbol_def::SymbolDefs,
+    utils::{offset_to_pos, path_to_uri},
+};
+
+/// Name of the build scripts that are scanned for grammar files
+const BUILD_SCRIPT_NAME: &str = "build.rs";
+
+/// Directories that are not searched for grammar files
+const EXCLUDED_DIRECTORIES: &[&str] = &["target", "node_modules"];
+
+/// Default output files of `Builder::with_cargo_script_output`
+const DEFAULT_PARSER_OUTPUT_FILE: &str = "parser.rs";
+const DEFAULT_ACTIONS_OUTPUT_FILE: &str = "grammar_trait.rs";
+
+static RX_BUILDER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"Builder\s*::\s*(?:with_explicit_output_dir\s*\(\s*"(?P<dir>[^"]*)"\s*\)|with_cargo_script_output\s*\(\s*\))"#,
+    )
+    .expect("error parsing regex: RX_BUILDER")
+});
+
+static RX_BUILDER_CALL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"\.\s*(?P<method>grammar_file|parser_output_file|actions_output_file)\s*\(\s*"(?P<arg>[^"]*)"\s*\)"#,
+    )
+    .expect("error parsing regex: RX_BUILDER_CALL")
+});
+
+///
+/// A symbol defined in a grammar description
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct IndexedSymbol {
+    pub(crate) name: String,
+    pub(crate) kind: SymbolKind,
+    pub(crate) range: Range,
+}
+
+///
+/// The indexed symbols of a single grammar description
+///
+#[derive(Debug, Clone)]
+struct IndexedGrammar {
+    uri: Uri,
+    symbols: Vec<IndexedSymbol>,
+}
+
+///
+/// A call of `Builder::grammar_file` in a build script
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GrammarBuild {
+    /// The path of the build script
+    pub(crate) build_script: PathBuf,
+    /// The range of the grammar file argument in the build script
+    pub(crate) range: Range,
+    /// The grammar file as given in the build script
+    pub(crate) grammar_file: String,
+    /// The generated modules, i.e. the parser and the grammar trait, relative to the output
+    /// directory
+    pub(crate) generated_modules: Vec<String>,
+}
+
+impl GrammarBuild {
+    ///
+    /// Describes the generated modules, e.g. for diagnostics
+    ///
+    pub(crate) fn description(&self) -> String {
+        if self.generated_modules.is_empty() {
+            "The grammar is processed by this build script".to_string()
+        } else {
+            format!(
+                "The grammar feeds the generated module(s) {}",
+                self.generated_modules
+                    .iter()
+                    .map(|m| format!("'{m}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+    }
+
+    pub(crate) fn related_information(&self) -> Option<DiagnosticRelatedInformation> {
+        Some(DiagnosticRelatedInformation {
+            location: Location {
+                uri: path_to_uri(&self.build_script)?,
+                range: self.range,
+            },
+            message: self.description(),
+        })
+    }
+}
+
+///
+/// The index of the grammar descriptions and build scripts in the workspace folders
+///
+#[derive(Debug, Default)]
+pub(crate) struct WorkspaceIndex {
+    /// The indexed grammars by their normalized path
+    grammars: HashMap<PathBuf, IndexedGrammar>,
+    /// The grammar file calls found in build scripts by the normalized path of the grammar
+    grammar_builds: HashMap<PathBuf, Vec<GrammarBuild>>,
+}
+
+impl WorkspaceIndex {
+    ///
+    /// Indexes all grammar descriptions and build scripts in the given folders on a background
+    /// thread.
+    ///
+    /// The files are parsed without holding the lock of the index. Each file is added to the index
+    /// as soon as it is parsed, unless the index already contains information about it. Such
+    /// information was provided by the server in the meantime, e.g. from an opened document, and
+    /// is newer than the content read from disk.
+    ///
+    pub(crate) fn index_folders(
+        index: Arc<Mutex<WorkspaceIndex>>,
+        folders: Vec<PathBuf>,
+    ) -> JoinHandle<()> {
+        thread::spawn(move || {
+            for folder in &folders {
+                eprintln!("indexing workspace folder {}", folder.display());
+                index_folder(&index, folder);
+            }
+            let index = index.lock().unwrap();
+            eprintln!(
+                "indexed {} grammar(s) and {} build reference(s)",
+                index.grammars.len(),
+                index.grammar_builds.values().map(Vec::len).sum::<usize>()
+            );
+        })
+    }
+
+    ///
+    /// Indexes a single file from its content on disk.
+    /// Files that are neither grammar descriptions nor build scripts are ignored.
+    /// The symbols of a grammar description that can't be read or parsed are removed.
+    ///
+    pub(crate) fn index_file(&mut self, path: &Path) {
+        if is_grammar_file(path) {
+            let mut grammar = ParolLsGrammar::new();
+            match fs::read_to_string(path) {
+                Ok(input) if parse(&input, path, &mut grammar).is_ok() => {
+                    if let Some(uri) = path_to_uri(path) {
+                        self.update_grammar(uri, path, &grammar);
+                    }
+                }
+                _ => {
+                    self.grammars.remove(&normalize(path));
+                }
+            }
+        } else if is_build_script(path) {
+            let Ok(input) = fs::read_to_string(path) else {
+                return;
+            };
+            self.update_build_script(path, &input);
+        }
+    }
+
+    ///
+    /// Adds the information of the other index about files this index knows nothing about
+    ///
+    fn merge_new_files(&mut self, other: WorkspaceIndex) {
+        for (path, grammar) in other.grammars {
+            self.grammars.entry(path).or_insert(grammar);
+        }
+        let known_build_scripts = self
+            .grammar_builds
+            .values()
+            .flatten()
+            .map(|b| b.build_script.clone())
+            .collect::<HashSet<_>>();
+        for (grammar, builds) in other.grammar_builds {
+            for build in builds {
+                if !known_build_scripts.contains(&build.build_script) {
+                    self.grammar_builds
+                        .entry(grammar.clone())
+                        .or_default()
+                        .push(build);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Removes all information that stems from the given file
+    ///
+    pub(crate) fn remove_file(&mut self, path: &Path) {
+        if is_grammar_file(path) {
+            self.grammars.remove(&normalize(path));
+        } else if is_build_script(path) {
+            self.remove_build_script(path);
+        }
+    }
+
+    ///
+    /// Updates the symbols of a grammar description from its parsed content
+    ///
+    pub(crate) fn update_grammar(&mut self, uri: Uri, path: &Path, grammar: &ParolLsGrammar) {
+        let mut symbols = Vec::new();
+        add_symbols(
+            &mut symbols,
+            &grammar.non_terminal_definitions,
+            SymbolKind::FUNCTION,
+        );
+        add_symbols(
+            &mut symbols,
+            &grammar.scanner_state_definitions,
+            SymbolKind::STRUCT,
+        );
+        add_symbols(
+            &mut symbols,
+            &grammar.user_type_definitions,
+            SymbolKind::CONSTANT,
+        );
+        // The implicit INITIAL scanner state exists in every grammar
+        symbols.retain(|s| s.kind != SymbolKind::STRUCT || s.name != "INITIAL");
+        symbols.sort_by(|a, b| (a.range.start, &a.name).cmp(&(b.range.start, &b.name)));
+        self.grammars
+            .insert(normalize(path), IndexedGrammar { uri, symbols });
+    }
+
+    ///
+    /// Updates the grammar file calls of a build script from its content
+    ///
+    pub(crate) fn update_build_script(&mut self, path: &Path, input: &str) {
+        self.remove_build_script(path);
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        for build in find_grammar_builds(path, input) {
+            let grammar = normalize(&base_dir.join(&build.grammar_file));
+            self.grammar_builds.entry(grammar).or_default().push(build);
+        }
+    }
+
+    fn remove_build_script(&mut self, path: &Path) {
+        self.grammar_builds.retain(|_, builds| {
+            builds.retain(|b| b.build_script != path);
+            !builds.is_empty()
+        });
+    }
+
+    ///
+    /// Returns the build script calls that process the given grammar
+    ///
+    pub(crate) fn grammar_builds(&self, grammar: &Path) -> &[GrammarBuild] {
+        self.grammar_builds
+            .get(&normalize(grammar))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    ///
+    /// Returns the symbols of all indexed grammars that match the given query.
+    /// A symbol matches if the characters of the query appear in its name in the same order,
+    /// ignoring case.
+    ///
+    pub(crate) fn symbols(&self, query: &str) -> Vec<WorkspaceSymbol> {
+        let mut grammars = self.grammars.iter().collect::<Vec<_>>();
+        grammars.sort_by(|a, b| a.0.cmp(b.0));
+        grammars
+            .into_iter()
+            .flat_map(|(path, grammar)| {
+                let container_name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string());
+                grammar
+                    .symbols
+                    .iter()
+                    .filter(|symbol| matches_query(&symbol.name, query))
+                    .map(move |symbol| WorkspaceSymbol {
+                        name: symbol.name.clone(),
+                        kind: symbol.kind,
+                        tags: None,
+                        container_name: container_name.clone(),
+                        location: OneOf::Left(Location {
+                            uri: grammar.uri.clone(),
+                            range: symbol.range,
+                        }),
+                        data: None,
+                    })
+            })
+            .collect()
+    }
+}
+
+fn index_folder(index: &Mutex<WorkspaceIndex>, folder: &Path) {
+    let Ok(entries) = fs::read_dir(folder) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if !name.starts_with('.') && !EXCLUDED_DIRECTORIES.contains(&name.as_ref()) {
+                index_folder(index, &path);
+            }
+        } else if file_type.is_file() {
+            let mut file_index = WorkspaceIndex::default();
+            file_index.index_file(&path);
+            index.lock().unwrap().merge_new_files(file_index);
+        }
+    }
+}
+
+fn add_symbols(symbols: &mut Vec<IndexedSymbol>, defs: &SymbolDefs, kind: SymbolKind) {
+    for name in defs.symbols.keys() {
+        if let Some(range) = defs
+            .find_definitions(name)
+            .and_then(|ranges| ranges.into_iter().min_by_key(|r| r.start))
+        {
+            symbols.push(IndexedSymbol {
+                name: name.clone(),
+                kind,
+                range,
+            });
+        }
+    }
+}
+
+fn matches_query(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| name.any(|c| c == q))
+}
+
+fn is_grammar_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "par")
+}
+
+fn is_build_script(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name == BUILD_SCRIPT_NAME)
+}
+
+/// Resolves symbolic links and relative components if the path exists
+fn normalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+///
+/// Finds the calls of `Builder::grammar_file` with a string literal argument in a build script.
+/// Each builder chain starts at `Builder::with_explicit_output_dir` or
+/// `Builder::with_cargo_script_output`.
+///
+fn find_grammar_builds(build_script: &Path, input: &str) -> Vec<GrammarBuild> {
+    let starts = RX_BUILDER.captures_iter(input).collect::<Vec<_>>();
+    let mut builds = Vec::new();
+    for (i, start) in starts.iter().enumerate() {
+        let chain_start = start.get(0).unwrap().end();
+        let chain_end = starts
+            .get(i + 1)
+            .map_or(input.len(), |next| next.get(0).unwrap().start());
+        let chain = &input[chain_start..chain_end];
+        let output_dir = start.name("dir").map(|dir| dir.as_str());
+        let (mut parser, mut actions) = if output_dir.is_some() {
+            (None, None)
+        } else {
+            (
+                Some(DEFAULT_PARSER_OUTPUT_FILE.to_string()),
+                Some(DEFAULT_ACTIONS_OUTPUT_FILE.to_string()),
+            )
+        };
+        let mut grammar = None;
+        for call in RX_BUILDER_CALL.captures_iter(chain) {
+            let arg = call.name("arg").unwrap();
+            match &call["method"] {
+                "grammar_file" => grammar = Some(arg),
+                "parser_output_file" => parser = Some(arg.as_str().to_string()),
+                _ => actions = Some(arg.as_str().to_string()),
+            }
+        }
+        let Some(grammar) = grammar else {
+            continue;
+        };
+        let output_dir = Path::new(output_dir.unwrap_or("$OUT_DIR"));
+        let generated_modules = [parser, actions]
+            .into_iter()
+            .flatten()
+            .map(|module| output_dir.join(module).to_string_lossy().replace('\\', "/"))
+            .collect();
+        let start = chain_start + grammar.start();
+        let end = chain_start + grammar.end();
+        builds.push(GrammarBuild {
+            build_script: build_script.to_path_buf(),
+            range: Range::new(offset_to_pos(input, start), offset_to_pos(input, end)),
+            grammar_file: grammar.as_str().to_string(),
+            generated_modules,
+        });
+    }
+    builds
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+
+    use lsp_types::{Position, Range, Uri};
+
+    use super::{WorkspaceIndex, find_grammar_builds, matches_query};
+    use crate::{parol_ls_grammar::ParolLsGrammar, parol_ls_parser::parse, utils::path_to_uri};
+
+    const BUILD_SCRIPT: &str = r#"use parol::build::Builder;
+
+fn main() {
+    Builder::with_explicit_output_dir("src")
+        .grammar_file("calc.par")
+        .parser_output_file("calc_parser.rs")
+        .actions_output_file("calc_grammar_trait.rs")
+        .generate_parser()
+        .unwrap();
+    Builder::with_cargo_script_output()
+        .grammar_file("list.par")
+        .generate_parser()
+        .unwrap();
+}
+"#;
+
+    #[test]
+    fn find_grammar_file_calls_in_build_script() {
+        let builds = find_grammar_builds(Path::new("/ws/build.rs"), BUILD_SCRIPT);
+        assert_eq!(2, builds.len());
+        assert_eq!("calc.par", builds[0].grammar_file);
+        assert_eq!(
+            Range::new(Position::new(4, 23), Position::new(4, 31)),
+            builds[0].range
+        );
+        assert_eq!(
+            vec!["src/calc_parser.rs", "src/calc_grammar_trait.rs"],
+            builds[0].generated_modules
+        );
+        assert_eq!("list.par", builds[1].grammar_file);
+        assert_eq!(
+            vec!["$OUT_DIR/parser.rs", "$OUT_DIR/grammar_trait.rs"],
+            builds[1].generated_modules
+        );
+    }
+
+    #[test]
+    fn associate_build_scripts_with_grammars() {
+        let mut index = WorkspaceIndex::default();
+        index.update_build_script(Path::new("/ws/build.rs"), BUILD_SCRIPT);
+        assert_eq!(1, index.grammar_builds(Path::new("/ws/calc.par")).len());
+        assert!(index.grammar_builds(Path::new("/ws/other.par")).is_empty());
+
+        index.update_build_script(Path::new("/ws/build.rs"), "fn main() {}");
+        assert!(index.grammar_builds(Path::new("/ws/calc.par")).is_empty());
+    }
+
+    #[test]
+    fn query_workspace_symbols() {
+        let input = "%start List\n%%\nList: { Item };\nItem: \"[0-9]+\";\n";
+        let mut grammar = ParolLsGrammar::new();
+        parse(input, "list.par", &mut grammar).unwrap();
+        let mut index = WorkspaceIndex::default();
+        let uri = Uri::from_str("file:///ws/list.par").unwrap();
+        index.update_grammar(uri, Path::new("/ws/list.par"), &grammar);
+
+        let names = |query| {
+            index
+                .symbols(query)
+                .into_iter()
+                .map(|s| s.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["List", "Item"], names(""));
+        assert_eq!(vec!["Item"], names("itm"));
+        assert!(names("xyz").is_empty());
+    }
+
+    #[test]
+    fn index_folders_in_background() {
+        let dir = std::env::temp_dir().join(format!("parol-ls-workspace-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("list.par");
+        fs::write(
+            &file,
+            "%start List\n%%\nList: { Item };\nItem: \"[0-9]+\";\n",
+        )
+        .unwrap();
+
+        let index = Arc::new(Mutex::new(WorkspaceIndex::default()));
+        WorkspaceIndex::index_folders(index.clone(), vec![dir.clone()])
+            .join()
+            .unwrap();
+        let names = |index: &WorkspaceIndex| {
+            index
+                .symbols("")
+                .into_iter()
+                .map(|s| s.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["List", "Item"], names(&index.lock().unwrap()));
+
+        // Symbols of a grammar that doesn't parse anymore are removed
+        fs::write(&file, "%start List\n%%\nList: { Item ;\n").unwrap();
+        index.lock().unwrap().index_file(&file);
+        assert!(names(&index.lock().unwrap()).is_empty());
+
+        // Information provided in the meantime wins over the content on disk
+        let mut grammar = ParolLsGrammar::new();
+        parse("%start Other\n%%\nOther: \"o\";\n", &file, &mut grammar).unwrap();
+        let uri = path_to_uri(&file).unwrap();
+        index.lock().unwrap().update_grammar(uri, &file, &grammar);
+        WorkspaceIndex::index_folders(index.clone(), vec![dir.clone()])
+            .join()
+            .unwrap();
+        assert_eq!(vec!["Other"], names(&index.lock().unwrap()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn match_query_as_subsequence() {
+        assert!(matches_query("ScannerState", "scst"));
+        assert!(matches_query("ScannerState", ""));
+        assert!(!matches_query("ScannerState", "stsc"));
+    }
+}
@@ -13,4 +13,4 @@ import type { Symbol } from "./Symbol";
  * as simple as possible.
  *
  */
-export type SymbolTable = { symbols: Array<Symbol>, scopes: Array<Scope>, };
+export type SymbolTable = { symbols: Array<Symbol>, scopes: Array<Scope>, arena_allocation: boolean, };
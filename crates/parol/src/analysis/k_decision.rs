@@ -1,9 +1,9 @@
 use crate::analysis::LookaheadDFA;
-use crate::analysis::lookahead_dfa::ProductionIndex;
+use crate::analysis::lookahead_dfa::{DFAState, INVALID_PROD, ProductionIndex};
 use crate::analysis::{FirstSet, FollowSet, first_k, follow_k};
 use crate::grammar::cfg::NonTerminalIndexFn;
+use crate::{Cfg, GrammarConfig, KTuples};
 use crate::{GrammarAnalysisError, MAX_K};
-use crate::{GrammarConfig, KTuples};
 use anyhow::{Result, anyhow, bail};
 use parol_runtime::log::trace;
 use std::cell::RefCell;
@@ -156,9 +156,9 @@ pub fn decidable(
                     .collect::<Vec<(ProductionIndex, KTuples)>>();
 
                 if concatenated_k_tuples.iter().all(|(i, t1)| {
-                    concatenated_k_tuples
-                        .iter()
-                        .all(|(j, t2)| i == j || t1.is_disjoint(t2))
+                    concatenated_k_tuples.iter().all(|(j, t2)| {
+                        i == j || resolved_by_predicate(cfg, *i, *j) || t1.is_disjoint(t2)
+                    })
                 }) {
                     return Ok(current_k);
                 }
@@ -171,6 +171,14 @@ pub fn decidable(
     }
 }
 
+///
+/// Two productions of a non-terminal may share k-tuples if exactly one of them is guarded by a
+/// semantic predicate. The predicate decides between them at parse time.
+///
+fn resolved_by_predicate(cfg: &Cfg, p1: ProductionIndex, p2: ProductionIndex) -> bool {
+    cfg[p1].get_predicate().is_some() != cfg[p2].get_predicate().is_some()
+}
+
 ///
 /// Calculates maximum lookahead size where max_k is the limit.
 ///
@@ -275,7 +283,16 @@ pub fn calculate_lookahead_dfas(
             let nt = cfg[*i].get_n();
             let dfa = LookaheadDFA::from_k_tuples(t, *i);
             if let Some(found_dfa) = acc.remove(&nt) {
-                let united_dfa = found_dfa.unite(&dfa)?;
+                // Conflicts with predicated productions are resolved in their favor
+                let united_dfa = found_dfa.unite_with(&dfa, |p1, p2| {
+                    if !resolved_by_predicate(cfg, p1 as ProductionIndex, p2 as ProductionIndex) {
+                        None
+                    } else if cfg[p1 as ProductionIndex].get_predicate().is_some() {
+                        Some(p1)
+                    } else {
+                        Some(p2)
+                    }
+                })?;
                 acc.insert(nt, united_dfa);
             } else {
                 acc.insert(nt, dfa);
@@ -285,6 +302,88 @@ pub fn calculate_lookahead_dfas(
     )
 }
 
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// Calculates lookahead DFAs for all non-terminals for parsing an entry point of the grammar, where
+/// max_k is the limit.
+///
+/// The entry point is treated as start symbol, thus the end of input follows it. The lookahead DFAs
+/// of the start symbol, see [calculate_lookahead_dfas], are not affected by the entry points.
+///
+pub fn calculate_entry_lookahead_dfas(
+    grammar_config: &GrammarConfig,
+    entry_point: &str,
+    max_k: usize,
+) -> Result<BTreeMap<String, LookaheadDFA>> {
+    let mut entry_grammar_config = grammar_config.clone();
+    entry_grammar_config.cfg.st = entry_point.to_string();
+    calculate_lookahead_dfas(&entry_grammar_config, max_k)
+}
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// Calculates the lookahead DFAs that are used when the semantic predicate of a predicted
+/// production fails, where max_k is the limit.
+/// They exist only for non-terminals with predicated productions and predict the productions of
+/// the non-terminal that are not guarded by a predicate.
+///
+pub fn calculate_fallback_lookahead_dfas(
+    grammar_config: &GrammarConfig,
+    max_k: usize,
+) -> Result<BTreeMap<String, LookaheadDFA>> {
+    let cfg = &grammar_config.cfg;
+
+    let first_cache = FirstCache::new();
+    let follow_cache = FollowCache::new();
+    let nti = Rc::new(cfg.get_non_terminal_index_function());
+
+    cfg.get_non_terminal_set()
+        .iter()
+        .filter(|nt| {
+            cfg.matching_productions(nt)
+                .iter()
+                .any(|(_, p)| p.get_predicate().is_some())
+        })
+        .try_fold(BTreeMap::new(), |mut acc, nt| {
+            let k = decidable(grammar_config, nt, max_k, &first_cache, &follow_cache)?;
+            let k_tuples = calculate_tuples_for_non_terminal(
+                nt.clone(),
+                k,
+                grammar_config,
+                &first_cache,
+                &follow_cache,
+                nti.clone(),
+                BTreeMap::new(),
+            )?;
+            let fallback_dfa = k_tuples
+                .iter()
+                .filter(|(i, _)| cfg[**i].get_predicate().is_none())
+                .try_fold(None, |dfa: Option<LookaheadDFA>, (i, t)| {
+                    let other = LookaheadDFA::from_k_tuples(t, *i);
+                    match dfa {
+                        Some(dfa) => dfa.unite(&other).map(Some),
+                        None => Ok(Some(other)),
+                    }
+                })?
+                // Without unguarded productions the fallback never predicts a production
+                .unwrap_or_else(|| LookaheadDFA {
+                    states: vec![DFAState {
+                        id: 0,
+                        prod_num: INVALID_PROD,
+                    }],
+                    ..Default::default()
+                });
+            acc.insert(nt.clone(), fallback_dfa);
+            Ok(acc)
+        })
+}
+
 ///
 /// Returns conflicts for a given non-terminal at given lookahead size.
 ///
@@ -330,6 +429,7 @@ pub fn explain_conflicts(
             for (i, ki) in &concatenated_k_tuples {
                 for (j, kj) in &concatenated_k_tuples {
                     if i != j
+                        && !resolved_by_predicate(cfg, *i, *j)
                         && !ki.is_disjoint(kj)
                         && !conflicting_k_tuples
                             .iter()
@@ -347,7 +447,11 @@ pub fn explain_conflicts(
 
 #[cfg(test)]
 mod test {
-    use super::{FirstCache, FollowCache, calculate_k, decidable};
+    use super::{
+        FirstCache, FollowCache, calculate_fallback_lookahead_dfas, calculate_k,
+        calculate_lookahead_dfas, decidable,
+    };
+    use crate::analysis::lookahead_dfa::INVALID_PROD;
     use crate::grammar::SymbolAttribute;
     use crate::{Cfg, GrammarConfig, Pr, Symbol, Terminal, TerminalKind};
 
@@ -431,4 +535,59 @@ mod test {
         let result = calculate_k(&grammar_config, 5, &first_cache, &follow_cache).unwrap();
         assert_eq!(1, result);
     }
+
+    fn predicated_grammar() -> GrammarConfig {
+        let cfg = Cfg::with_start_symbol("S")
+            .add_pr(Pr::new("S", vec![terminal!("a"), terminal!("b")]).with_predicate("p"))
+            .add_pr(Pr::new("S", vec![terminal!("a"), terminal!("b")]))
+            .add_pr(Pr::new("S", vec![terminal!("c")]));
+        GrammarConfig::new(cfg, 5)
+    }
+
+    #[test]
+    fn check_predicate_resolves_conflict() {
+        let grammar_config = predicated_grammar();
+        let first_cache = FirstCache::new();
+        let follow_cache = FollowCache::new();
+        let result = decidable(&grammar_config, "S", 5, &first_cache, &follow_cache).unwrap();
+        assert_eq!(1, result);
+
+        let dfa = &calculate_lookahead_dfas(&grammar_config, 5).unwrap()["S"];
+        let mut prod_nums = dfa
+            .states
+            .iter()
+            .filter(|s| s.is_accepting())
+            .map(|s| s.prod_num)
+            .collect::<Vec<_>>();
+        prod_nums.sort();
+        assert_eq!(vec![0, 2], prod_nums);
+    }
+
+    #[test]
+    fn check_fallback_lookahead_dfas() {
+        let grammar_config = predicated_grammar();
+        let fallback_dfas = calculate_fallback_lookahead_dfas(&grammar_config, 5).unwrap();
+        assert_eq!(vec!["S"], fallback_dfas.keys().collect::<Vec<_>>());
+        let mut prod_nums = fallback_dfas["S"]
+            .states
+            .iter()
+            .filter(|s| s.is_accepting())
+            .map(|s| s.prod_num)
+            .collect::<Vec<_>>();
+        prod_nums.sort();
+        assert_eq!(vec![1, 2], prod_nums);
+    }
+
+    #[test]
+    fn check_fallback_without_unguarded_productions() {
+        let cfg = Cfg::with_start_symbol("S")
+            .add_pr(Pr::new("S", vec![terminal!("a")]).with_predicate("p"))
+            .add_pr(Pr::new("S", vec![terminal!("b")]).with_predicate("q"));
+        let grammar_config = GrammarConfig::new(cfg, 5);
+        let fallback_dfas = calculate_fallback_lookahead_dfas(&grammar_config, 5).unwrap();
+        let dfa = &fallback_dfas["S"];
+        assert_eq!(1, dfa.states.len());
+        assert_eq!(INVALID_PROD, dfa.states[0].prod_num);
+        assert!(dfa.transitions.is_empty());
+    }
 }
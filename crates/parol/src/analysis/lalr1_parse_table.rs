@@ -14,7 +14,7 @@ use std::{
 };
 
 use crate::{
-    Cfg, GrammarAnalysisError, GrammarConfig, Pr, Terminal,
+    Cfg, GrammarAnalysisError, GrammarConfig, Pr, Symbol, SymbolAttribute, Terminal,
     grammar::cfg::{NonTerminalIndexFn, TerminalIndexFn},
     render_par_string,
 };
@@ -50,7 +50,7 @@ impl From<&Cfg> for GrammarLalr {
             start: nti.non_terminal_index(&cfg.st),
         };
 
-        for (i, Pr(s, rhs, _)) in cfg.pr.iter().enumerate() {
+        for (i, Pr(s, rhs, ..)) in cfg.pr.iter().enumerate() {
             let lhs = nti.non_terminal_index(s.get_n_ref().unwrap());
             let rhs = RhsLalr {
                 syms: rhs
@@ -335,7 +335,7 @@ impl Display for LRConflictError {
                 if let Some(cfg) = &self.cfg {
                     writeln!(f, "Shift-reduce conflict in state")?;
                     state.items.iter().for_each(|item| {
-                        let Pr(lhs, rhs, _) = &cfg.pr[item.prod];
+                        let Pr(lhs, rhs, ..) = &cfg.pr[item.prod];
                         let mut r = rhs
                             .iter()
                             .enumerate()
@@ -483,3 +483,51 @@ pub fn calculate_lalr1_parse_table(
     trace!("Converted LALR(1) parse table: {parse_table:#?}");
     Ok((parse_table, config.calls.into_inner()))
 }
+
+/// Calculate the LALR(1) parse table for an entry point of the given grammar configuration.
+///
+/// The table is constructed for an additional start symbol that derives only the entry point. This
+/// start symbol and its production are numbered after the non-terminals and productions of the
+/// grammar, so the table can be used together with the generated productions of the grammar.
+/// The parser accepts the input as soon as the entry point is followed by the end of input.
+pub fn calculate_lalr1_entry_parse_table(
+    grammar_config: &GrammarConfig,
+    entry_point: &str,
+) -> Result<(LRParseTable, Vec<LRResolvedConflict>)> {
+    let cfg = &grammar_config.cfg;
+    let nti = cfg.get_non_terminal_index_function();
+    let mut grammar = GrammarLalr::from(cfg);
+    let entry_start = cfg.get_non_terminal_set().len();
+    grammar.rules.insert(
+        entry_start,
+        vec![RhsLalr {
+            syms: vec![lalry::Symbol::Nonterminal(
+                nti.non_terminal_index(entry_point),
+            )],
+            act: cfg.pr.len(),
+        }],
+    );
+    grammar.start = entry_start;
+    trace!("LALR(1) grammar of entry point {entry_point}: {grammar:#?}");
+    let config = LALRConfig::new();
+    let parse_table = grammar.lalr1(&config).map_err(|e| {
+        let conflict: LRConflict = e.into();
+        let mut conflict: LRConflictError = conflict.into();
+        // The conflict can refer to the production of the additional start symbol
+        let mut entry_cfg = cfg.clone();
+        entry_cfg.pr.push(Pr::new(
+            &format!("{entry_point}'"),
+            vec![Symbol::N(
+                entry_point.to_string(),
+                SymbolAttribute::None,
+                None,
+                None,
+            )],
+        ));
+        conflict.set_cfg(entry_cfg);
+        anyhow!(GrammarAnalysisError::LALR1ParseTableConstructionFailed { conflict })
+    })?;
+    let parse_table = LRParseTable::from(parse_table);
+    trace!("Converted LALR(1) parse table of entry point {entry_point}: {parse_table:#?}");
+    Ok((parse_table, config.calls.into_inner()))
+}
@@ -168,6 +168,23 @@ impl LookaheadDFA {
     /// an error is returned.
     ///
     pub fn unite(self, other: &Self) -> Result<Self> {
+        self.unite_with(other, |_, _| None)
+    }
+
+    ///
+    /// Returns the union of self and other while consuming self.
+    /// A conflict in the accepting state's production numbers is resolved by the given function
+    /// which returns the production number to use instead. If it returns None an error is
+    /// returned.
+    ///
+    pub(crate) fn unite_with(
+        self,
+        other: &Self,
+        resolve: impl Fn(
+            CompiledProductionIndex,
+            CompiledProductionIndex,
+        ) -> Option<CompiledProductionIndex>,
+    ) -> Result<Self> {
         // Helper map for other's states: state in other -> state in union
         let state_mapping: RefCell<BTreeMap<StateIndex, StateIndex>> =
             RefCell::new(BTreeMap::new());
@@ -198,20 +215,25 @@ impl LookaheadDFA {
                             let result_state_prod_num =
                                 result_union.borrow().states[result_state].prod_num;
 
+                            let mut prod_num = other_to_state_prod_num;
                             if other_state_accepted
                                 && result_state_accepted
                                 && (other_to_state_prod_num != result_state_prod_num)
                             {
-                                let message = format!(
-                                    r#"Conflict in union operation detected:
+                                if let Some(resolved) =
+                                    resolve(result_state_prod_num, other_to_state_prod_num)
+                                {
+                                    prod_num = resolved;
+                                } else {
+                                    let message = format!(
+                                        r#"Conflict in union operation detected:
 Ambiguous production number prediction
 {result_state_prod_num} <--> {other_to_state_prod_num}"#
-                                );
-                                bail!(message);
+                                    );
+                                    bail!(message);
+                                }
                             }
-                            result_union
-                                .borrow_mut()
-                                .coin_state(result_state, other_to_state_prod_num);
+                            result_union.borrow_mut().coin_state(result_state, prod_num);
                         }
                     }
                 }
@@ -35,14 +35,15 @@ pub use follow::{FollowSet, follow_k};
 /// Module with conflict calculations
 pub mod k_decision;
 pub use k_decision::{
-    FirstCache, FollowCache, calculate_k, calculate_k_tuples, calculate_lookahead_dfas, decidable,
-    explain_conflicts,
+    FirstCache, FollowCache, calculate_entry_lookahead_dfas, calculate_fallback_lookahead_dfas,
+    calculate_k, calculate_k_tuples, calculate_lookahead_dfas, decidable, explain_conflicts,
 };
 
 /// Module with calculations for the LALR(1) parse table
 pub mod lalr1_parse_table;
 pub use lalr1_parse_table::{
-    LR1State, LRAction, LRConflict, LRConflictError, LRParseTable, calculate_lalr1_parse_table,
+    LR1State, LRAction, LRConflict, LRConflictError, LRParseTable,
+    calculate_lalr1_entry_parse_table, calculate_lalr1_parse_table,
 };
 
 /// Module with types for production selection
@@ -61,4 +62,10 @@ pub use reachability::{
     unreachable_non_terminals,
 };
 
+/// Module with the detection of shadowed and overlapping terminals
+pub mod terminal_overlaps;
+pub use terminal_overlaps::{
+    ScannerTerminal, TerminalOverlap, TerminalOverlapKind, detect_terminal_overlaps,
+};
+
 // mod terminals_trie;
@@ -0,0 +1,379 @@
+//! Scanner analysis
+//! Detection of terminals that shadow or overlap each other within a scanner state
+
+use crate::GrammarConfig;
+use crate::generators::generate_terminal_names;
+use anyhow::Result;
+use parol_runtime::TerminalIndex;
+use parol_runtime::lexer::FIRST_USER_TOKEN;
+use parol_runtime::log::trace;
+use regex_automata::dfa::{Automaton, StartKind, dense};
+use regex_automata::util::primitives::StateID;
+use regex_automata::util::start;
+use regex_automata::{Anchored, MatchKind};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::{Display, Error, Formatter};
+
+/// The size limit applied to the DFA that is built for the terminals of a scanner state.
+/// Scanner states whose DFA exceeds this limit are not analyzed.
+const DFA_SIZE_LIMIT: usize = 16 * (1 << 20);
+
+///
+/// A terminal as it is seen by the scanner of a certain scanner state
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannerTerminal {
+    /// The terminal index
+    pub index: TerminalIndex,
+    /// The generated name of the terminal
+    pub name: String,
+    /// The expanded regular expression of the terminal
+    pub pattern: String,
+}
+
+impl Display for ScannerTerminal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "{} /{}/", self.name, self.pattern)
+    }
+}
+
+///
+/// The kind of a finding of the terminal overlap analysis
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TerminalOverlapKind {
+    /// The terminal matches the empty string
+    MatchesEmpty,
+    /// Every input the terminal matches is also matched by terminals defined before it.
+    /// The scanner never produces this terminal.
+    Shadowed {
+        /// The terminals that take precedence
+        by: Vec<ScannerTerminal>,
+    },
+    /// Some inputs are matched by both terminals.
+    /// The scanner produces the terminal that is defined first for them.
+    Overlaps {
+        /// The terminal defined before the affected terminal
+        with: ScannerTerminal,
+        /// An input matched by both terminals
+        example: String,
+    },
+}
+
+///
+/// A finding of the terminal overlap analysis
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalOverlap {
+    /// The name of the scanner state
+    pub scanner_state: String,
+    /// The affected terminal
+    pub terminal: ScannerTerminal,
+    /// The kind of the finding
+    pub kind: TerminalOverlapKind,
+}
+
+impl TerminalOverlap {
+    ///
+    /// Returns true if the finding most likely indicates an error in the grammar.
+    /// Overlaps are commonly intended, e.g. keywords defined before identifiers.
+    ///
+    pub fn is_warning(&self) -> bool {
+        !matches!(self.kind, TerminalOverlapKind::Overlaps { .. })
+    }
+}
+
+impl Display for TerminalOverlap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match &self.kind {
+            TerminalOverlapKind::MatchesEmpty => write!(
+                f,
+                "Terminal {} matches the empty string in scanner state {}",
+                self.terminal, self.scanner_state
+            ),
+            TerminalOverlapKind::Shadowed { by } => write!(
+                f,
+                "Terminal {} is shadowed by {} in scanner state {} and is never scanned",
+                self.terminal,
+                by.iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                self.scanner_state
+            ),
+            TerminalOverlapKind::Overlaps { with, example } => write!(
+                f,
+                "Terminal {} overlaps with {} in scanner state {}, e.g. {:?} is scanned as {}",
+                self.terminal, with, self.scanner_state, example, with.name
+            ),
+        }
+    }
+}
+
+///
+/// Compares the regular languages of all terminals of each scanner state.
+///
+/// The scanner prefers the longest match and resolves ties by the order of the terminals.
+/// A terminal is reported if it matches the empty string, if all inputs it matches are also
+/// matched by terminals defined before it, or if it shares inputs with an earlier terminal.
+/// Terminals with a lookahead expression never shadow other terminals because they only match
+/// in certain contexts.
+///
+/// Scanner states whose terminals can't be converted into a DFA are skipped.
+///
+pub fn detect_terminal_overlaps(grammar_config: &GrammarConfig) -> Result<Vec<TerminalOverlap>> {
+    let terminal_names = generate_terminal_names(grammar_config);
+    let error_token_index = (terminal_names.len() - 1) as TerminalIndex;
+    let mut overlaps = Vec::new();
+    for scanner_config in &grammar_config.scanner_configurations {
+        let (terminal_mappings, _) =
+            scanner_config.generate_build_information(grammar_config, &terminal_names)?;
+        let (terminals, lookaheads): (Vec<ScannerTerminal>, Vec<bool>) = terminal_mappings
+            .into_iter()
+            .filter(|(pattern, index, _, _)| {
+                *index != error_token_index && regex_syntax::parse(pattern).is_ok()
+            })
+            .map(|(pattern, index, lookahead, name)| {
+                (
+                    ScannerTerminal {
+                        index,
+                        name,
+                        pattern,
+                    },
+                    lookahead.is_some(),
+                )
+            })
+            .unzip();
+        overlaps.extend(detect_in_scanner_state(
+            &scanner_config.scanner_name,
+            &terminals,
+            &lookaheads,
+        ));
+    }
+    Ok(overlaps)
+}
+
+fn detect_in_scanner_state(
+    scanner_state: &str,
+    terminals: &[ScannerTerminal],
+    lookaheads: &[bool],
+) -> Vec<TerminalOverlap> {
+    let patterns = terminals
+        .iter()
+        .map(|t| t.pattern.as_str())
+        .collect::<Vec<&str>>();
+    // With MatchKind::All the DFA reports every pattern that matches an input, regardless of
+    // the order of the patterns.
+    let dfa = match dense::Builder::new()
+        .configure(
+            dense::Config::new()
+                .match_kind(MatchKind::All)
+                .start_kind(StartKind::Anchored)
+                .unicode_word_boundary(true)
+                .dfa_size_limit(Some(DFA_SIZE_LIMIT))
+                .determinize_size_limit(Some(DFA_SIZE_LIMIT)),
+        )
+        .build_many(&patterns)
+    {
+        Ok(dfa) => dfa,
+        Err(err) => {
+            trace!("Skipping terminal overlap analysis of scanner state {scanner_state}: {err}");
+            return Vec::new();
+        }
+    };
+    let Ok(start) = dfa.start_state(&start::Config::new().anchored(Anchored::Yes)) else {
+        return Vec::new();
+    };
+
+    let count = terminals.len();
+    let mut matches_empty = vec![false; count];
+    let mut matches_any = vec![false; count];
+    let mut matches_alone = vec![false; count];
+    // (earlier terminal, later terminal) -> shortest common input
+    let mut examples: BTreeMap<(usize, usize), String> = BTreeMap::new();
+
+    // Breadth-first traversal of all states reachable from the start state.
+    // Each state represents the inputs that lead to it, the parents are used to reconstruct the
+    // shortest of them.
+    let mut parents: HashMap<StateID, (StateID, u8)> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let eoi = dfa.next_eoi_state(state);
+        if dfa.is_match_state(eoi) {
+            let mut matching = (0..dfa.match_len(eoi))
+                .map(|i| dfa.match_pattern(eoi, i).as_usize())
+                .collect::<Vec<usize>>();
+            matching.sort_unstable();
+            for &later in &matching {
+                matches_any[later] = true;
+                if state == start {
+                    matches_empty[later] = true;
+                }
+                if !matching
+                    .iter()
+                    .any(|&earlier| earlier < later && !lookaheads[earlier])
+                {
+                    matches_alone[later] = true;
+                }
+                for &earlier in matching.iter().take_while(|&&earlier| earlier < later) {
+                    examples
+                        .entry((earlier, later))
+                        .or_insert_with(|| example_input(&parents, start, state));
+                }
+            }
+        }
+        for byte in 0..=u8::MAX {
+            let next = dfa.next_state(state, byte);
+            if dfa.is_dead_state(next)
+                || dfa.is_quit_state(next)
+                || next == start
+                || parents.contains_key(&next)
+            {
+                continue;
+            }
+            parents.insert(next, (state, byte));
+            queue.push_back(next);
+        }
+    }
+
+    let mut overlaps = Vec::new();
+    for later in 0..count {
+        if terminals[later].index < FIRST_USER_TOKEN {
+            continue;
+        }
+        let mut finding = |kind| {
+            overlaps.push(TerminalOverlap {
+                scanner_state: scanner_state.to_string(),
+                terminal: terminals[later].clone(),
+                kind,
+            })
+        };
+        if matches_empty[later] {
+            finding(TerminalOverlapKind::MatchesEmpty);
+        }
+        let earlier_terminals = examples
+            .iter()
+            .filter(|((_, l), _)| *l == later)
+            .map(|((earlier, _), example)| (*earlier, example));
+        if matches_any[later] && !matches_alone[later] {
+            finding(TerminalOverlapKind::Shadowed {
+                by: earlier_terminals
+                    .filter(|(earlier, _)| !lookaheads[*earlier])
+                    .map(|(earlier, _)| terminals[earlier].clone())
+                    .collect(),
+            });
+        } else {
+            for (earlier, example) in earlier_terminals {
+                finding(TerminalOverlapKind::Overlaps {
+                    with: terminals[earlier].clone(),
+                    example: example.clone(),
+                });
+            }
+        }
+    }
+    overlaps
+}
+
+/// Reconstructs the shortest input that leads from the start state to the given state
+fn example_input(
+    parents: &HashMap<StateID, (StateID, u8)>,
+    start: StateID,
+    state: StateID,
+) -> String {
+    let mut bytes = Vec::new();
+    let mut current = state;
+    while current != start {
+        let (parent, byte) = parents[&current];
+        bytes.push(byte);
+        current = parent;
+    }
+    bytes.reverse();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TerminalOverlapKind, detect_terminal_overlaps};
+    use crate::obtain_grammar_config_from_string;
+
+    fn overlaps(grammar: &str) -> Vec<(String, TerminalOverlapKind)> {
+        let grammar_config = obtain_grammar_config_from_string(grammar, false).unwrap();
+        detect_terminal_overlaps(&grammar_config)
+            .unwrap()
+            .into_iter()
+            .map(|o| (o.terminal.pattern, o.kind))
+            .collect()
+    }
+
+    #[test]
+    fn keyword_after_identifier_is_shadowed() {
+        let overlaps = overlaps(
+            r#"%start S
+%%
+S: Identifier | Begin;
+Identifier: /[a-z]+/;
+Begin: "begin";
+"#,
+        );
+        assert_eq!(1, overlaps.len());
+        assert_eq!("begin", overlaps[0].0);
+        let TerminalOverlapKind::Shadowed { by } = &overlaps[0].1 else {
+            panic!("Expected a shadowed terminal, got {:?}", overlaps[0].1);
+        };
+        assert_eq!(
+            vec!["[a-z]+"],
+            by.iter().map(|t| t.pattern.as_str()).collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn keyword_before_identifier_overlaps() {
+        let overlaps = overlaps(
+            r#"%start S
+%%
+S: Begin | Identifier;
+Begin: "begin";
+Identifier: /[a-z]+/;
+"#,
+        );
+        assert_eq!(1, overlaps.len());
+        assert_eq!("[a-z]+", overlaps[0].0);
+        let TerminalOverlapKind::Overlaps { with, example } = &overlaps[0].1 else {
+            panic!("Expected overlapping terminals, got {:?}", overlaps[0].1);
+        };
+        assert_eq!("begin", with.pattern);
+        assert_eq!("begin", example);
+    }
+
+    #[test]
+    fn terminal_matching_empty_string() {
+        let overlaps = overlaps(
+            r#"%start S
+%%
+S: A B;
+A: /a*/;
+B: "b";
+"#,
+        );
+        assert_eq!(
+            vec![("a*".to_string(), TerminalOverlapKind::MatchesEmpty)],
+            overlaps
+        );
+    }
+
+    #[test]
+    fn terminals_of_other_scanner_states_do_not_overlap() {
+        let overlaps = overlaps(
+            r#"%start S
+%scanner Other {
+    %auto_newline_off
+}
+%%
+S: Identifier Begin;
+Identifier: /[a-z]+/;
+Begin: <Other>"begin";
+"#,
+        );
+        assert!(overlaps.is_empty(), "{overlaps:?}");
+    }
+}
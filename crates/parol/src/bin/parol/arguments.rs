@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use parol::InnerAttributes;
 
 // LL(k) Parser Generator written in Rust
@@ -75,6 +75,11 @@ pub(crate) struct CliArgs {
     #[arg(short = 'b', long = "minbox")]
     pub minimize_boxed_types: bool,
 
+    /// Allocates the nodes of the generated AST in an arena the parse functions take. Requires the
+    /// feature `arena` of parol_runtime
+    #[arg(long = "arena")]
+    pub arena_allocation: bool,
+
     /// Inserts the given inner attributes at the top of the generated trait source.
     #[arg(long, value_enum)]
     pub inner_attributes: Vec<InnerAttributes>,
@@ -88,11 +93,60 @@ pub(crate) struct CliArgs {
     #[arg(long)]
     pub disable_recovery: bool,
 
+    /// Sets the maximum number of syntax errors the generated parser collects before it stops
+    /// parsing. Only supported for LL(k) grammars.
+    #[arg(long)]
+    pub max_errors: Option<usize>,
+
+    /// Generates the additional function `parse_with_recovery` that takes the strategy to recover
+    /// from syntax errors, e.g. panic mode. Only supported for LL(k) grammars.
+    #[arg(long)]
+    pub custom_recovery: bool,
+
     /// Sets the maximum parsing depth. Can be used to prevent stack overflows for
     /// grammars that allow deep recursion.
     #[arg(long)]
     pub max_parsing_depth: Option<usize>,
 
+    /// Sets the maximum number of parse steps of the generated parser. Can be used to limit the
+    /// time spent on pathological input.
+    #[arg(long)]
+    pub step_budget: Option<usize>,
+
+    /// Generates the additional function `parse_cancellable` that takes a cancellation token
+    #[arg(long)]
+    pub cancellable: bool,
+
+    /// Scans each token only when the parser needs it and only matches the terminals the parser
+    /// accepts at this position. Keywords can then be used as identifiers where they aren't
+    /// expected.
+    #[arg(long)]
+    pub contextual_lexing: bool,
+
+    /// Generates the additional function `parse_bytes` that scans arbitrary bytes. Each byte is
+    /// scanned as the character with the same code point, e.g. `\xFF` matches the byte 0xFF.
+    #[arg(long)]
+    pub byte_input: bool,
+
+    /// Generates the additional function `parse_tokens` that parses tokens supplied by the user,
+    /// e.g. the output of a preprocessor, instead of scanning an input text.
+    #[arg(long)]
+    pub token_input: bool,
+
+    /// Generates an additional parse function for each non-terminal of the grammar, as if all of
+    /// them were declared with `%entry`.
+    #[arg(long)]
+    pub all_entry_points: bool,
+
+    /// The unit in which the generated parser counts the columns of token locations
+    #[arg(long, value_enum, default_value_t = ColumnUnit::Chars)]
+    pub column_unit: ColumnUnit,
+
+    /// Generates only the scanner and a `Tokenizer` type that iterates over the tokens of an
+    /// input. The output is written to the parser output file, no actions file is generated.
+    #[arg(long)]
+    pub lexer_only: bool,
+
     /// The language to generate code for
     #[arg(short, long, value_enum, default_value_t = parol::Language::Rust)]
     pub language: parol::Language,
@@ -100,3 +154,24 @@ pub(crate) struct CliArgs {
     #[command(subcommand)]
     pub subcommand: Option<super::tools::ToolsSubcommands>,
 }
+
+/// The unit in which columns are counted, see `parol_runtime::lexer::ColumnUnit`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum ColumnUnit {
+    /// Unicode scalar values
+    Chars,
+    /// Bytes of the UTF-8 encoding
+    Utf8Bytes,
+    /// Code units of the UTF-16 encoding
+    Utf16,
+}
+
+impl From<ColumnUnit> for parol_runtime::lexer::ColumnUnit {
+    fn from(column_unit: ColumnUnit) -> Self {
+        match column_unit {
+            ColumnUnit::Chars => Self::Chars,
+            ColumnUnit::Utf8Bytes => Self::Utf8Bytes,
+            ColumnUnit::Utf16 => Self::Utf16,
+        }
+    }
+}
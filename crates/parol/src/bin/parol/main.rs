@@ -17,6 +17,7 @@ use parol_runtime::{Report, Result, log::trace};
 
 use parol::{
     GrammarConfig, ParolErrorReporter, ParolGrammar,
+    analysis::detect_terminal_overlaps,
     build::{BuildListener, IntermediateGrammar},
     render_par_string,
 };
@@ -76,6 +77,9 @@ fn run(args: &CliArgs) -> Result<u128> {
     if args.minimize_boxed_types {
         builder.minimize_boxed_types();
     }
+    if args.arena_allocation {
+        builder.arena_allocation();
+    }
     if args.range {
         builder.range();
     }
@@ -85,9 +89,37 @@ fn run(args: &CliArgs) -> Result<u128> {
     builder.inner_attributes(args.inner_attributes.clone());
     builder.add_derives(args.add_derives.clone());
     builder.language(args.language);
+    if let Some(max_errors) = args.max_errors {
+        builder.max_errors(max_errors);
+    }
+    if args.custom_recovery {
+        builder.custom_recovery();
+    }
     if let Some(max_parsing_depth) = args.max_parsing_depth {
         builder.max_parsing_depth(max_parsing_depth);
     }
+    if let Some(step_budget) = args.step_budget {
+        builder.step_budget(step_budget);
+    }
+    if args.cancellable {
+        builder.cancellable();
+    }
+    if args.contextual_lexing {
+        builder.contextual_lexing();
+    }
+    if args.byte_input {
+        builder.byte_input();
+    }
+    builder.column_unit(args.column_unit.into());
+    if args.token_input {
+        builder.token_input();
+    }
+    if args.all_entry_points {
+        builder.all_entry_points();
+    }
+    if args.lexer_only {
+        builder.lexer_only();
+    }
     if let Some(expanded_grammar_file) = &args.expanded {
         if expanded_grammar_file == OsStr::new("--") {
             // We special case this in our listener (see below)
@@ -163,6 +195,14 @@ impl BuildListener for CLIListener<'_> {
             }
             // final pass
             IntermediateGrammar::LAST => {
+                if !self.config.quiet {
+                    // Shadowed terminals are never scanned which is most likely unintended
+                    detect_terminal_overlaps(grammar_config)
+                        .map_err(|e| parol!(e))?
+                        .iter()
+                        .filter(|o| o.is_warning())
+                        .for_each(|o| eprintln!("{}: {o}", "warning".bright_yellow()));
+                }
                 if let Some(file_name) = self.config.expanded.as_ref() {
                     // NOTE: We still need special handling for writing to stdout
                     let lf_source =
@@ -224,16 +264,19 @@ fn extract_file_name(args: &CliArgs) -> Option<PathBuf> {
         match subcommand {
             tools::ToolsSubcommands::calculate_k(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::calculate_k_tuples(args) => Some(args.grammar_file.clone()),
+            tools::ToolsSubcommands::coverage(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::decidable(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::deduce_types(args) => args.grammar_file.clone(),
             tools::ToolsSubcommands::export(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::first(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::follow(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::format(args) => Some(args.grammar_file.clone()),
+            tools::ToolsSubcommands::fuzz(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::generate(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::left_factor(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::left_recursions(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::productivity(args) => Some(args.grammar_file.clone()),
+            tools::ToolsSubcommands::terminal_overlaps(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::acceptor_test(args) => Some(args.grammar_file.clone()),
             tools::ToolsSubcommands::new(_) => None,
         }
@@ -35,16 +35,19 @@ macro_rules! declare_tools {
 declare_tools!(
     calculate_k,
     calculate_k_tuples,
+    coverage,
     decidable,
     deduce_types,
     export,
     first,
     follow,
     format,
+    fuzz,
     generate,
     left_factor,
     left_recursions,
     new,
     productivity,
+    terminal_overlaps,
     acceptor_test
 );
@@ -0,0 +1,119 @@
+use anyhow::{Context, Result, bail};
+use owo_colors::OwoColorize;
+use parol::coverage::{CoverageItemKind, GrammarCoverage};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Parses a corpus of inputs with the given grammar and reports the alternatives, optional
+/// expressions, repetitions and terminals of the grammar the inputs never use.
+#[derive(clap::Parser)]
+#[clap(name = "coverage")]
+pub struct Args {
+    /// The grammar file to use
+    #[clap(short = 'f', long = "grammar-file")]
+    pub(crate) grammar_file: PathBuf,
+
+    /// The maximum number of lookahead tokens to be used
+    #[clap(short = 'k', long = "lookahead", default_value = "5")]
+    lookahead: usize,
+
+    /// Writes the coverage in the LCOV format to the given file
+    #[clap(long)]
+    lcov: Option<PathBuf>,
+
+    /// The input files of the corpus. Directories are searched recursively.
+    #[clap(required = true)]
+    corpus: Vec<PathBuf>,
+}
+
+pub fn main(args: &Args) -> Result<()> {
+    let grammar_source = fs::read_to_string(&args.grammar_file)
+        .with_context(|| format!("Can't read file {}", args.grammar_file.display()))?;
+    let mut coverage = GrammarCoverage::try_new(&grammar_source, &args.grammar_file, args.lookahead)?;
+
+    let mut inputs = Vec::new();
+    for path in &args.corpus {
+        collect_inputs(path, &mut inputs)?;
+    }
+    if inputs.is_empty() {
+        bail!("The corpus contains no input files");
+    }
+    let mut failed = 0;
+    for input in &inputs {
+        let text = fs::read_to_string(input)
+            .with_context(|| format!("Can't read file {}", input.display()))?;
+        if let Err(e) = coverage.add_input(&text, input) {
+            failed += 1;
+            eprintln!("{}", format!("{e}").yellow());
+        }
+    }
+    println!(
+        "Parsed {} input files, {} with syntax errors",
+        inputs.len(),
+        failed
+    );
+
+    let report = coverage.report();
+    let covered = |kind: CoverageItemKind| {
+        let items = report.items.iter().filter(|i| i.kind == kind);
+        (
+            items.clone().filter(|i| i.count > 0).count(),
+            items.count(),
+        )
+    };
+    let (alternatives, alternative_count) = covered(CoverageItemKind::Alternative);
+    let (repetitions, repetition_count) = covered(CoverageItemKind::Repetition);
+    let (terminals, terminal_count) = (
+        report.terminals.iter().filter(|t| t.count > 0).count(),
+        report.terminals.len(),
+    );
+    println!("Alternatives: {alternatives} of {alternative_count} covered");
+    println!("Repetitions: {repetitions} of {repetition_count} repeated");
+    println!("Terminals: {terminals} of {terminal_count} matched");
+
+    let grammar_file = args.grammar_file.display();
+    for item in report.uncovered_items() {
+        let message = match item.kind {
+            CoverageItemKind::Alternative if item.text.is_empty() => {
+                format!("empty alternative of {} is never used", item.non_terminal)
+            }
+            CoverageItemKind::Alternative => format!(
+                "alternative `{}` of {} is never used",
+                item.text, item.non_terminal
+            ),
+            CoverageItemKind::OptionalPresent => format!("`{}` is never present", item.text),
+            CoverageItemKind::OptionalAbsent => format!("`{}` is never absent", item.text),
+            CoverageItemKind::Repetition => format!("`{}` is never repeated", item.text),
+        };
+        println!("{grammar_file}:{}:{}: {message}", item.line, item.column);
+    }
+    for terminal in report.uncovered_terminals() {
+        println!(
+            "{grammar_file}: terminal {} is never matched",
+            terminal.pattern
+        );
+    }
+
+    if let Some(lcov) = &args.lcov {
+        fs::write(lcov, report.to_lcov(&args.grammar_file))
+            .with_context(|| format!("Can't write file {}", lcov.display()))?;
+    }
+    Ok(())
+}
+
+/// Collects the files below the given path in a stable order
+fn collect_inputs(path: &Path, inputs: &mut Vec<PathBuf>) -> Result<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)
+            .with_context(|| format!("Can't read directory {}", path.display()))?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<std::io::Result<Vec<PathBuf>>>()?;
+        entries.sort();
+        for entry in entries {
+            collect_inputs(&entry, inputs)?;
+        }
+    } else {
+        inputs.push(path.to_path_buf());
+    }
+    Ok(())
+}
@@ -1,22 +1,34 @@
 use anyhow::Result;
 use std::path::PathBuf;
 
-use parol::conversions::par::render_par_string;
+use parol::conversions::par::{FormatConfig, render_par_string_with_config};
 use parol::obtain_grammar_config;
 
 /// Formats the given grammar with the standard format and prints the result to stdout.
+/// Formatting options are read from a `.parolfmt.toml` file in the directory of the grammar file
+/// or in one of its ancestors.
 #[derive(clap::Parser)]
 #[clap(name = "format")]
 pub struct Args {
     /// The grammar file to use
     #[clap(short = 'f', long = "grammar-file")]
     pub(crate) grammar_file: PathBuf,
+    /// The formatting configuration file to use instead of the searched `.parolfmt.toml`
+    #[clap(short = 'c', long = "config")]
+    pub(crate) config: Option<PathBuf>,
 }
 
 pub fn main(args: &Args) -> Result<()> {
     let file_name = &args.grammar_file;
 
+    let format_config = match &args.config {
+        Some(config) => FormatConfig::load(config)?,
+        None => FormatConfig::for_grammar_file(file_name)?,
+    };
     let grammar_config = obtain_grammar_config(file_name, false)?;
-    println!("{}", render_par_string(&grammar_config, true)?);
+    println!(
+        "{}",
+        render_par_string_with_config(&grammar_config, true, &format_config)?
+    );
     Ok(())
 }
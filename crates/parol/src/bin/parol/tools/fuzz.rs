@@ -0,0 +1,154 @@
+mod build_rs;
+mod cargo_toml;
+mod fuzz_target_rs;
+
+use build_rs::BuildRsDataBuilder;
+use cargo_toml::CargoTomlDataBuilder;
+use fuzz_target_rs::FuzzTargetRsDataBuilder;
+
+use anyhow::{Context, Result, anyhow, bail};
+use owo_colors::OwoColorize;
+use parol::generators::NamingHelper as NmHlp;
+use parol::generators::grammar_type_generator::GrammarTypeInfo;
+use parol::{left_factor, obtain_grammar_config};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Creates a cargo-fuzz crate that checks the parser of the given grammar.
+/// The fuzz inputs are production choices from which sentences of the grammar are derived.
+#[derive(clap::Parser)]
+#[clap(name = "fuzz")]
+pub struct Args {
+    /// The grammar file to use
+    #[clap(short = 'f', long = "grammar-file")]
+    pub(crate) grammar_file: PathBuf,
+
+    /// The directory of the fuzz crate, defaults to the directory `fuzz` next to the grammar file
+    #[clap(short, long)]
+    path: Option<PathBuf>,
+
+    /// The maximum length of the sentences derived from the fuzz input
+    #[clap(short = 'l', long = "max-length", default_value_t = 4096)]
+    max_len: usize,
+}
+
+pub fn main(args: &Args) -> Result<()> {
+    let mut grammar_config = obtain_grammar_config(&args.grammar_file, false)?;
+    if !matches!(grammar_config.grammar_type, parol::parser::GrammarType::LLK) {
+        bail!("Only LL grammars are supported for fuzzing");
+    }
+    if grammar_config
+        .scanner_configurations
+        .iter()
+        .any(|sc| !sc.external_tokens.is_empty())
+    {
+        bail!("Grammars with external terminals are not supported for fuzzing");
+    }
+
+    let crate_name = NmHlp::to_lower_snake_case(&NmHlp::purge_name(
+        args.grammar_file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| anyhow!("Trouble to handle path"))?,
+    ));
+    let grammar_name = NmHlp::to_upper_camel_case(&crate_name);
+
+    // The user type of the fuzz target needs a lifetime if the grammar trait has one
+    let cfg = left_factor(&grammar_config.cfg);
+    grammar_config.update_cfg(cfg);
+    let mut type_info = GrammarTypeInfo::try_new(&format!("{grammar_name}Grammar"))?;
+    type_info.set_grammar_type(grammar_config.grammar_type);
+    type_info.build(&grammar_config)?;
+    let has_lifetime = type_info.ast_type_has_lifetime();
+
+    let fuzz_dir = if let Some(path) = &args.path {
+        path.clone()
+    } else {
+        args.grammar_file
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join("fuzz")
+    };
+    if fuzz_dir.join("Cargo.toml").exists() {
+        bail!("The fuzz crate {} already exists", fuzz_dir.display());
+    }
+    fs::create_dir_all(fuzz_dir.join("fuzz_targets")).context("Error creating fuzz crate!")?;
+    let grammar_path = relative_path(&args.grammar_file, &fuzz_dir)?;
+
+    print!(
+        "Generating fuzz target {} for grammar {} in {}...",
+        crate_name.green(),
+        grammar_name.green(),
+        fuzz_dir.display().yellow()
+    );
+
+    let cargo_toml = CargoTomlDataBuilder::default()
+        .crate_name(&crate_name)
+        .build()?;
+    fs::write(fuzz_dir.join("Cargo.toml"), format!("{cargo_toml}"))
+        .context("Error writing Cargo.toml!")?;
+
+    let build_rs = BuildRsDataBuilder::default()
+        .crate_name(&crate_name)
+        .grammar_name(grammar_name.clone())
+        .grammar_path(grammar_path.clone())
+        .build()?;
+    fs::write(fuzz_dir.join("build.rs"), format!("{build_rs}"))
+        .context("Error writing build.rs!")?;
+
+    let fuzz_target_rs = FuzzTargetRsDataBuilder::default()
+        .crate_name(&crate_name)
+        .grammar_name(grammar_name)
+        .grammar_path(grammar_path)
+        .has_lifetime(has_lifetime)
+        .max_len(args.max_len)
+        .build()?;
+    fs::write(
+        fuzz_dir
+            .join("fuzz_targets")
+            .join(format!("{crate_name}.rs")),
+        format!("{fuzz_target_rs}"),
+    )
+    .context("Error writing fuzz target!")?;
+
+    fs::write(
+        fuzz_dir.join(".gitignore"),
+        format!(
+            "\
+            target\n\
+            corpus\n\
+            artifacts\n\
+            coverage\n\
+            # Generated by parol\n\
+            fuzz_targets/{crate_name}_parser.rs\n\
+            fuzz_targets/{crate_name}_grammar_trait.rs\n"
+        ),
+    )
+    .context("Error writing .gitignore file!")?;
+
+    println!("\nRun it with `cargo fuzz run {crate_name}` in the fuzz crate's parent directory.");
+    Ok(())
+}
+
+/// Returns the path of the grammar file relative to the fuzz crate with forward slashes
+fn relative_path(grammar_file: &Path, fuzz_dir: &Path) -> Result<String> {
+    let grammar_file = fs::canonicalize(grammar_file)?;
+    let fuzz_dir = fs::canonicalize(fuzz_dir)?;
+    let common = grammar_file
+        .components()
+        .zip(fuzz_dir.components())
+        .take_while(|(g, f)| g == f)
+        .count();
+    let relative = fuzz_dir
+        .components()
+        .skip(common)
+        .map(|_| "..".to_string())
+        .chain(
+            grammar_file
+                .components()
+                .skip(common)
+                .map(|c| c.as_os_str().to_string_lossy().to_string()),
+        )
+        .collect::<Vec<_>>();
+    Ok(relative.join("/"))
+}
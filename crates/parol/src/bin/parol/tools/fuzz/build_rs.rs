@@ -0,0 +1,41 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, Default)]
+pub(crate) struct BuildRsData<'a> {
+    crate_name: &'a str,
+    grammar_name: String,
+    grammar_path: String,
+}
+
+impl std::fmt::Display for BuildRsData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let BuildRsData {
+            crate_name,
+            grammar_name,
+            grammar_path,
+        } = self;
+
+        write!(
+            f,
+            r#"use std::process;
+
+use parol::{{build::Builder, parol_runtime::Report, ParolErrorReporter}};
+
+fn main() {{
+    // The generated parser is placed next to the fuzz target
+    if let Err(err) = Builder::with_explicit_output_dir("fuzz_targets")
+        .grammar_file("{grammar_path}")
+        .parser_output_file("{crate_name}_parser.rs")
+        .actions_output_file("{crate_name}_grammar_trait.rs")
+        .user_type_name("{grammar_name}Grammar")
+        .user_trait_module_name("{crate_name}_grammar")
+        .generate_parser()
+    {{
+        ParolErrorReporter::report_error(&err, "{grammar_path}").unwrap_or_default();
+        process::exit(1);
+    }}
+}}
+"#
+        )
+    }
+}
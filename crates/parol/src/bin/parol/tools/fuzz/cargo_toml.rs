@@ -0,0 +1,48 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, Default)]
+pub(crate) struct CargoTomlData<'a> {
+    crate_name: &'a str,
+}
+
+impl std::fmt::Display for CargoTomlData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let CargoTomlData { crate_name } = self;
+        let parol_version = env!("CARGO_PKG_VERSION");
+        // parol and parol_runtime are released in lockstep
+        let parol_runtime_version = env!("CARGO_PKG_VERSION");
+        let scnr2_version = crate::tools::new::SCNR2_VERSION;
+
+        write!(
+            f,
+            r#"[package]
+name = "{crate_name}_fuzz"
+version = "0.0.0"
+publish = false
+edition = "2024"
+
+[package.metadata]
+cargo-fuzz = true
+
+[dependencies]
+libfuzzer-sys = "0.4"
+parol = "{parol_version}"
+parol_runtime = "{parol_runtime_version}"
+scnr2 = "{scnr2_version}"
+
+[build-dependencies]
+parol = "{parol_version}"
+
+[[bin]]
+name = "{crate_name}"
+path = "fuzz_targets/{crate_name}.rs"
+test = false
+doc = false
+bench = false
+
+# Keep the fuzz crate out of an enclosing workspace
+[workspace]
+"#
+        )
+    }
+}
@@ -0,0 +1,127 @@
+use derive_builder::Builder;
+
+#[derive(Builder, Debug, Default)]
+pub(crate) struct FuzzTargetRsData<'a> {
+    crate_name: &'a str,
+    grammar_name: String,
+    grammar_path: String,
+    has_lifetime: bool,
+    max_len: usize,
+}
+
+impl std::fmt::Display for FuzzTargetRsData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let FuzzTargetRsData {
+            crate_name,
+            grammar_name,
+            grammar_path,
+            has_lifetime,
+            max_len,
+        } = self;
+
+        let user_type = if *has_lifetime {
+            format!(
+                "    pub struct {grammar_name}Grammar<'t>(std::marker::PhantomData<&'t str>);
+
+    impl {grammar_name}Grammar<'_> {{
+        pub fn new() -> Self {{
+            Self(std::marker::PhantomData)
+        }}
+    }}
+
+    impl<'t> {grammar_name}GrammarTrait<'t> for {grammar_name}Grammar<'t> {{}}"
+            )
+        } else {
+            format!(
+                "    pub struct {grammar_name}Grammar;
+
+    impl {grammar_name}Grammar {{
+        pub fn new() -> Self {{
+            Self
+        }}
+    }}
+
+    impl {grammar_name}GrammarTrait for {grammar_name}Grammar {{}}"
+            )
+        };
+
+        write!(
+            f,
+            r#"//! Fuzz target for the parser of the grammar `{grammar_path}`.
+//!
+//! The fuzz input is interpreted as a sequence of production choices from which a sentence of
+//! the grammar is derived. This way most inputs get past the scanner and reach deep into the
+//! parser. The raw input is parsed too if it is valid UTF-8.
+//! Parse errors are expected, panics and stack overflows are reported as crashes.
+#![no_main]
+
+mod {crate_name}_grammar_trait;
+mod {crate_name}_parser;
+
+mod {crate_name}_grammar {{
+    //! A user type that accepts every input
+    use crate::{crate_name}_grammar_trait::{grammar_name}GrammarTrait;
+
+{user_type}
+}}
+
+use libfuzzer_sys::{{fuzz_mutator, fuzz_target, fuzzer_mutate}};
+use parol::{{LanguageGenerator, obtain_grammar_config_from_string}};
+
+use crate::{crate_name}_grammar::{grammar_name}Grammar;
+use crate::{crate_name}_parser::parse;
+
+/// The maximum length of the sentences derived from the fuzz input
+const MAX_SENTENCE_LENGTH: usize = {max_len};
+
+thread_local! {{
+    static GRAMMAR_CONFIG: parol::GrammarConfig =
+        obtain_grammar_config_from_string(include_str!("../{grammar_path}"), false)
+            .expect("The grammar is valid");
+}}
+
+fn parse_input(input: &str) {{
+    let mut grammar = {grammar_name}Grammar::new();
+    let _ = parse(input, "fuzz", &mut grammar);
+}}
+
+fuzz_target!(|data: &[u8]| {{
+    let sentence = GRAMMAR_CONFIG.with(|grammar_config| {{
+        LanguageGenerator::new(&grammar_config.cfg)
+            .generate_from_choices(data, Some(MAX_SENTENCE_LENGTH))
+            .ok()
+    }});
+    if let Some(sentence) = sentence {{
+        parse_input(&sentence);
+    }}
+    if let Ok(input) = std::str::from_utf8(data) {{
+        parse_input(input);
+    }}
+}});
+
+// Mutates the production choices as a whole in addition to the byte-level mutations
+fuzz_mutator!(|data: &mut [u8], size: usize, max_size: usize, seed: u32| {{
+    let mut state = seed | 1;
+    let mut next = |bound: usize| {{
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as usize % bound.max(1)
+    }};
+    match next(4) {{
+        // Repeat a range of choices to nest recursive productions deeper
+        0 if size > 0 && size < max_size => {{
+            let start = next(size);
+            let len = (1 + next(size - start)).min(max_size - size);
+            data.copy_within(start..size, start + len);
+            size + len
+        }}
+        // Drop trailing choices to derive shorter sentences
+        1 if size > 1 => 1 + next(size - 1),
+        _ => fuzzer_mutate(data, size, max_size),
+    }}
+}});
+"#
+        )
+    }
+}
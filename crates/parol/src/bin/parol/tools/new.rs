@@ -116,6 +116,9 @@ pub fn main(args: &Args) -> Result<()> {
     Ok(())
 }
 
+/// The version of scnr2 that generated crates depend on
+pub(crate) const SCNR2_VERSION: &str = "0.5.2";
+
 const DEPENDENCIES: &[&[&str]] = &[
     &["add", "env_logger@0.11"],
     &["add", "parol_runtime@5.0"],
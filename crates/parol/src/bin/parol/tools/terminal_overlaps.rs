@@ -0,0 +1,35 @@
+use anyhow::Result;
+use std::path::PathBuf;
+
+use parol::{analysis::detect_terminal_overlaps, obtain_grammar_config};
+
+/// Checks the terminals of each scanner state for shadowing and overlaps.
+#[derive(clap::Parser)]
+pub struct Args {
+    /// The grammar file to use
+    #[clap(short = 'f', long = "grammar-file")]
+    pub(crate) grammar_file: PathBuf,
+
+    /// Only reports shadowed terminals and terminals that match the empty string
+    #[clap(short = 'w', long = "warnings-only")]
+    pub(crate) warnings_only: bool,
+}
+
+pub fn main(args: &Args) -> Result<()> {
+    let file_name = &args.grammar_file;
+
+    let grammar_config = obtain_grammar_config(file_name, false)?;
+    let overlaps = detect_terminal_overlaps(&grammar_config)?
+        .into_iter()
+        .filter(|o| !args.warnings_only || o.is_warning())
+        .collect::<Vec<_>>();
+    if overlaps.is_empty() {
+        println!("No shadowed or overlapping terminals found!");
+    } else {
+        println!("Found {} terminal overlaps:\n", overlaps.len());
+        overlaps.iter().for_each(|o| {
+            println!("{o}");
+        });
+    }
+    Ok(())
+}
@@ -133,6 +133,7 @@ use crate::generators::parser_backend::{
     CSharpParserBackend, RustParserBackend, generate_parser_source_for_language,
 };
 use crate::generators::parser_ir::{ParserAlgorithmIR, ParserGenerationIR};
+use crate::generators::tokenizer_generator::generate_tokenizer_source;
 use crate::generators::user_trait_backend::{
     CSharpUserTraitBackend, RustUserTraitBackend, generate_user_trait_source_for_language,
 };
@@ -206,6 +207,8 @@ pub struct Builder {
     output_sanity_checks: bool,
     /// Activate the minimization of boxed types in the generated parser
     pub(crate) minimize_boxed_types: bool,
+    /// Allocate the nodes of the generated AST in an arena
+    pub(crate) arena_allocation: bool,
     /// Internal debugging for CLI.
     debug_verbose: bool,
     /// Generate range information for AST types
@@ -221,8 +224,28 @@ pub struct Builder {
     pub(crate) trim_parse_tree: bool,
     /// Disbales the error recovery mechanism in the generated parser
     pub(crate) disable_recovery: bool,
+    /// The maximum number of syntax errors the generated parser collects
+    pub(crate) max_errors: Option<usize>,
+    /// Generate a function that takes the strategy to recover from syntax errors
+    pub(crate) custom_recovery: bool,
     /// The maximum parsing depth.
     pub(crate) max_parsing_depth: Option<usize>,
+    /// The maximum number of parse steps of the generated parser
+    pub(crate) step_budget: Option<usize>,
+    /// Generate a function that takes a cancellation token
+    pub(crate) cancellable: bool,
+    /// Restrict the scanning of each token to the terminals the parser accepts
+    pub(crate) contextual_lexing: bool,
+    /// Scan bytes instead of text
+    pub(crate) byte_input: bool,
+    /// The unit in which the columns of token locations are counted
+    pub(crate) column_unit: parol_runtime::lexer::ColumnUnit,
+    /// Generate only the scanner and a tokenizer instead of a parser
+    pub(crate) lexer_only: bool,
+    /// Generate a function that parses tokens supplied by the user
+    pub(crate) token_input: bool,
+    /// Use all non-terminals of the grammar as entry points
+    pub(crate) all_entry_points: bool,
     /// The language to generate code for
     pub(crate) language: crate::config::Language,
 }
@@ -299,13 +322,24 @@ impl Builder {
             node_kind_enum_output_file: None,
             expanded_grammar_output_file: None,
             minimize_boxed_types: false,
+            arena_allocation: false,
             inner_attributes: Vec::new(),
             additional_derive_attributes: Vec::new(),
             // By default, we require that output files != /dev/null
             output_sanity_checks: true,
             trim_parse_tree: false,
             disable_recovery: false,
+            max_errors: None,
+            custom_recovery: false,
             max_parsing_depth: None,
+            step_budget: None,
+            cancellable: false,
+            contextual_lexing: false,
+            byte_input: false,
+            column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            lexer_only: false,
+            token_input: false,
+            all_entry_points: false,
             language: crate::config::Language::Rust,
         }
     }
@@ -397,12 +431,46 @@ impl Builder {
         self.max_lookahead = k;
         Ok(self)
     }
+    /// Sets the maximum number of syntax errors the generated parser collects before it stops
+    /// parsing. Only supported for LL(k) grammars.
+    pub fn max_errors(&mut self, max_errors: usize) -> &mut Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    /// Enables the choice of the recovery strategy in the generated parser.
+    ///
+    /// The generated parser provides the additional function `parse_with_recovery` that takes a
+    /// `parol_runtime::parser::recovery::RecoveryStrategy`, e.g. `PanicMode` with the terminals to
+    /// synchronize with. Only supported for LL(k) grammars.
+    pub fn custom_recovery(&mut self) -> &mut Self {
+        self.custom_recovery = true;
+        self
+    }
+
     /// Sets the maximum parsing depth.
     pub fn max_parsing_depth(&mut self, max_parsing_depth: usize) -> &mut Self {
         self.max_parsing_depth = Some(max_parsing_depth);
         self
     }
 
+    /// Sets the maximum number of parse steps of the generated parser. Can be used to limit the
+    /// time spent on pathological input.
+    pub fn step_budget(&mut self, step_budget: usize) -> &mut Self {
+        self.step_budget = Some(step_budget);
+        self
+    }
+
+    /// Enables the cancellation of the generated parser.
+    ///
+    /// The generated parser provides the additional function `parse_cancellable` that takes an
+    /// `Arc<AtomicBool>`. Setting it, e.g. from another thread, stops the parser with
+    /// `ParserError::Cancelled`.
+    pub fn cancellable(&mut self) -> &mut Self {
+        self.cancellable = true;
+        self
+    }
+
     /// Debug verbose information to the standard output
     ///
     /// This is an internal method, and is only intended for the CLI.
@@ -443,6 +511,16 @@ impl Builder {
         self.minimize_boxed_types = true;
         self
     }
+    /// Allocate the nodes of the generated AST in an arena.
+    ///
+    /// The generated types use references `&'t T` instead of `Box` and slices `&'t [T]` instead
+    /// of `Vec`. The generated parse functions take an additional `&'t parol_runtime::arena::Bump`
+    /// the adapter allocates the nodes in.
+    /// The generated parser requires the feature `arena` of `parol_runtime`.
+    pub fn arena_allocation(&mut self) -> &mut Self {
+        self.arena_allocation = true;
+        self
+    }
     /// Enables trimming of the parse tree during parsing.
     /// Generates the call to trim_parse_tree on the parser object before the call of parse.
     ///
@@ -457,6 +535,63 @@ impl Builder {
         self
     }
 
+    /// Enables contextual lexing in the generated parser.
+    ///
+    /// The parser scans each token only when it needs it and only matches the terminals it
+    /// accepts at this position. This way keywords that are only reserved in certain contexts can
+    /// be used as identifiers elsewhere.
+    pub fn contextual_lexing(&mut self) -> &mut Self {
+        self.contextual_lexing = true;
+        self
+    }
+
+    /// Enables byte input in the generated parser.
+    ///
+    /// The generated parser provides the additional function `parse_bytes` that scans a
+    /// `parol_runtime::lexer::ByteInput`. Each byte is scanned as the character with the same code
+    /// point, so terminals like `\xFF` match single bytes, and locations report byte offsets.
+    pub fn byte_input(&mut self) -> &mut Self {
+        self.byte_input = true;
+        self
+    }
+
+    /// Enables token input in the generated parser.
+    ///
+    /// The generated parser provides the additional function `parse_tokens` that parses tokens
+    /// supplied by the user, e.g. the output of a preprocessor, instead of scanning an input text.
+    pub fn token_input(&mut self) -> &mut Self {
+        self.token_input = true;
+        self
+    }
+
+    /// Uses all non-terminals of the grammar as entry points.
+    ///
+    /// The generated parser provides an additional parse function for each non-terminal besides
+    /// the start symbol, e.g. `parse_expression`, as if they were declared with `%entry`.
+    pub fn all_entry_points(&mut self) -> &mut Self {
+        self.all_entry_points = true;
+        self
+    }
+
+    /// Sets the unit in which the generated parser counts the columns of token locations.
+    ///
+    /// By default columns are counted in characters. Language servers usually need UTF-16 code
+    /// units, other tools UTF-8 bytes.
+    pub fn column_unit(&mut self, column_unit: parol_runtime::lexer::ColumnUnit) -> &mut Self {
+        self.column_unit = column_unit;
+        self
+    }
+
+    /// Generates only the scanner instead of a parser.
+    ///
+    /// The parser output file then contains the scanner, the enum `TerminalKind` and the type
+    /// `Tokenizer` that iterates over the tokens of an input. No actions file is generated, and
+    /// the grammar needn't be LL(k) or LALR(1).
+    pub fn lexer_only(&mut self) -> &mut Self {
+        self.lexer_only = true;
+        self
+    }
+
     /// Set the language to generate code for
     pub fn language(&mut self, language: crate::config::Language) -> &mut Self {
         self.language = language;
@@ -487,7 +622,7 @@ impl Builder {
             // Check that we have outputs
             if self.parser_output_file.is_none() {
                 return Err(BuilderError::MissingParserOutputFile);
-            } else if self.actions_output_file.is_none() {
+            } else if self.actions_output_file.is_none() && !self.lexer_only {
                 return Err(BuilderError::MissingActionOutputFile);
             }
             // Missing expanded grammar file is fine. They might not want that.
@@ -503,20 +638,63 @@ impl Builder {
             type_info: None,
         })
     }
+    /// Checks that the selected language supports the configured features.
+    fn check_language_support(&self) -> Result<()> {
+        if self.language == crate::Language::Rust {
+            return Ok(());
+        }
+        if self.max_parsing_depth.is_some() {
+            return Err(parol!(
+                "Maximum parsing depth is only supported for Rust parsers"
+            ));
+        }
+        if self.max_errors.is_some() || self.custom_recovery {
+            return Err(parol!(
+                "Recovery strategies and error limits are only supported for Rust parsers"
+            ));
+        }
+        if self.step_budget.is_some() || self.cancellable {
+            return Err(parol!(
+                "Step budgets and cancellation are only supported for Rust parsers"
+            ));
+        }
+        if self.contextual_lexing {
+            return Err(parol!(
+                "Contextual lexing is only supported for Rust parsers"
+            ));
+        }
+        if self.byte_input {
+            return Err(parol!("Byte input is only supported for Rust parsers"));
+        }
+        if self.arena_allocation {
+            return Err(parol!(
+                "Arena allocation is only supported for Rust parsers"
+            ));
+        }
+        if self.column_unit != parol_runtime::lexer::ColumnUnit::Chars {
+            return Err(parol!("Column units are only supported for Rust parsers"));
+        }
+        if self.token_input {
+            return Err(parol!("Token input is only supported for Rust parsers"));
+        }
+        if self.all_entry_points {
+            return Err(parol!("Entry points are only supported for Rust parsers"));
+        }
+        if self.lexer_only {
+            return Err(parol!("Lexer-only generation is only supported for Rust"));
+        }
+        Ok(())
+    }
     /// Generate the parser, writing it to the pre-configured output files.
     pub fn generate_parser(&mut self) -> Result<()> {
-        if self.max_parsing_depth.is_some() && self.language != crate::Language::Rust {
-            parol!("Maximum parsing depth is only supported for Rust parsers");
-        }
+        self.check_language_support()?;
         self.begin_generation_with(None)
             .map_err(|e| parol!("Misconfigured parol generation: {}", e))?
             .generate_parser()
     }
     /// Generate the parser, writing it to the pre-configured output files. And export the node info.
     pub fn generate_parser_and_export_node_infos(&mut self) -> Result<NodeTypesInfo> {
-        if self.max_parsing_depth.is_some() && self.language != crate::Language::Rust {
-            parol!("Maximum parsing depth is only supported for Rust parsers");
-        }
+        self.check_language_support()?;
         self.begin_generation_with(None)
             .map_err(|e| parol!("Misconfigured parol generation: {}", e))?
             .generate_parser_and_export_node_infos()
@@ -547,6 +725,22 @@ impl CommonGeneratorConfig for Builder {
     fn language(&self) -> crate::config::Language {
         self.language
     }
+
+    fn contextual_lexing(&self) -> bool {
+        self.contextual_lexing
+    }
+
+    fn byte_input(&self) -> bool {
+        self.byte_input
+    }
+
+    fn column_unit(&self) -> parol_runtime::lexer::ColumnUnit {
+        self.column_unit
+    }
+
+    fn arena_allocation(&self) -> bool {
+        self.arena_allocation
+    }
 }
 
 impl ParserGeneratorConfig for Builder {
@@ -561,6 +755,26 @@ impl ParserGeneratorConfig for Builder {
     fn max_parsing_depth(&self) -> Option<usize> {
         self.max_parsing_depth
     }
+
+    fn max_errors(&self) -> Option<usize> {
+        self.max_errors
+    }
+
+    fn custom_recovery(&self) -> bool {
+        self.custom_recovery
+    }
+
+    fn step_budget(&self) -> Option<usize> {
+        self.step_budget
+    }
+
+    fn cancellable(&self) -> bool {
+        self.cancellable
+    }
+
+    fn token_input(&self) -> bool {
+        self.token_input
+    }
 }
 
 impl UserTraitGeneratorConfig for Builder {
@@ -633,9 +847,16 @@ impl GrammarGenerator<'_> {
         let syntax_tree = crate::parser::parse(&input, &self.grammar_file, &mut parol_grammar)?;
         self.listener
             .on_initial_grammar_parse(&syntax_tree, &input, &parol_grammar)?;
-        self.grammar_config = Some(GrammarConfig::try_from(parol_grammar)?);
-
-        let _grammar_config = self.grammar_config.as_ref().unwrap();
+        let mut grammar_config = GrammarConfig::try_from(parol_grammar)?;
+        if self.builder.all_entry_points {
+            grammar_config.entry_points = grammar_config
+                .non_terminals
+                .iter()
+                .filter(|n| **n != grammar_config.cfg.st)
+                .cloned()
+                .collect();
+        }
+        self.grammar_config = Some(grammar_config);
 
         self.state = Some(State::Parsed);
         Ok(())
@@ -688,6 +909,11 @@ impl GrammarGenerator<'_> {
     pub fn post_process(&mut self) -> Result<()> {
         assert_eq!(self.state, Some(State::Expanded));
         let grammar_config = self.grammar_config.as_mut().unwrap();
+        if self.builder.lexer_only {
+            // The tokenizer doesn't need the lookahead automata or the parse table
+            self.state = Some(State::PostProcessed);
+            return Ok(());
+        }
         match grammar_config.grammar_type {
             GrammarType::LLK => {
                 self.lookahead_dfa_s = Some(
@@ -731,6 +957,9 @@ impl GrammarGenerator<'_> {
     #[doc(hidden)]
     pub fn write_output(&mut self) -> Result<()> {
         assert_eq!(self.state, Some(State::PostProcessed));
+        if self.builder.lexer_only {
+            return self.write_tokenizer_output();
+        }
         let grammar_config = self.grammar_config.as_mut().unwrap();
 
         let language = self.builder.language();
@@ -851,7 +1080,25 @@ impl GrammarGenerator<'_> {
         Ok(())
     }
 
+    fn write_tokenizer_output(&mut self) -> Result<()> {
+        let grammar_config = self.grammar_config.as_ref().unwrap();
+        let tokenizer_source = generate_tokenizer_source(grammar_config, &self.builder)
+            .map_err(|e| parol!("Failed to generate tokenizer source!: {}", e))?;
+        if let Some(ref parser_file_out) = self.builder.parser_output_file {
+            fs::write(parser_file_out, tokenizer_source)
+                .map_err(|e| parol!("Error writing generated tokenizer source!: {}", e))?;
+            crate::try_format(parser_file_out)?;
+        } else if self.builder.debug_verbose {
+            println!("\nTokenizer source:\n{tokenizer_source}");
+        }
+        self.state = Some(State::Finished);
+        Ok(())
+    }
+
     fn export_node_infos(&self) -> Result<NodeTypesInfo> {
+        if self.builder.lexer_only {
+            return Err(parol!("Node infos are not available in lexer-only mode"));
+        }
         let node_types_exporter = NodeTypesExporter::new(
             self.grammar_config.as_ref().unwrap(),
             self.type_info.as_ref().unwrap(),
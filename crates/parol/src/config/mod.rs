@@ -1,4 +1,5 @@
 use crate::InnerAttributes;
+use parol_runtime::lexer::ColumnUnit;
 
 /// The language to generate code for.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
@@ -39,6 +40,25 @@ pub trait CommonGeneratorConfig {
     fn language(&self) -> Language {
         Language::Rust
     }
+    /// Generate a lexer and a parser that only match the terminals the parser accepts at the
+    /// current position, so that keywords can be used as identifiers where they aren't expected
+    fn contextual_lexing(&self) -> bool {
+        false
+    }
+    /// Generate a parser for byte input, where each byte is scanned as the character with the
+    /// same code point
+    fn byte_input(&self) -> bool {
+        false
+    }
+    /// The unit in which the generated parser counts the columns of token locations
+    fn column_unit(&self) -> ColumnUnit {
+        ColumnUnit::Chars
+    }
+    /// Allocate the nodes of the generated AST in an arena provided by the user and link them
+    /// with references instead of boxes
+    fn arena_allocation(&self) -> bool {
+        false
+    }
 }
 
 // ---------------------------------------------------
@@ -54,10 +74,37 @@ pub trait ParserGeneratorConfig {
     /// If true error recovery in the generated parser should be disabled.
     fn recovery_disabled(&self) -> bool;
 
+    /// If set, the generated parser stops parsing after the given number of syntax errors.
+    fn max_errors(&self) -> Option<usize> {
+        None
+    }
+
+    /// Generate the additional function `parse_with_recovery` that takes the strategy to recover
+    /// from syntax errors
+    fn custom_recovery(&self) -> bool {
+        false
+    }
+
     /// If set, the generated parser will limit the maximum parsing depth to the given value.
     fn max_parsing_depth(&self) -> Option<usize> {
         None
     }
+
+    /// If set, the generated parser stops after the given number of parse steps.
+    fn step_budget(&self) -> Option<usize> {
+        None
+    }
+
+    /// Generate the additional function `parse_cancellable` that takes a cancellation token
+    fn cancellable(&self) -> bool {
+        false
+    }
+
+    /// Generate the additional function `parse_tokens` that parses tokens supplied by the user
+    /// instead of scanning an input text
+    fn token_input(&self) -> bool {
+        false
+    }
 }
 
 // ---------------------------------------------------
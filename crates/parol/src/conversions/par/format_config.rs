@@ -0,0 +1,120 @@
+//!
+//! The module contains the project specific formatting configuration for grammar descriptions.
+//!
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+/// The name of the formatting configuration file.
+/// It is searched in the directory of the grammar description and all of its ancestors.
+pub const FORMAT_CONFIG_FILE_NAME: &str = ".parolfmt.toml";
+
+///
+/// Formatting options read from a `.parolfmt.toml` file.
+///
+/// Each option is optional. Options that are not set leave the formatting tool's default, or any
+/// setting provided otherwise, e.g. by an editor, untouched.
+///
+/// The file is a TOML document with the options as top-level keys. Unknown options are rejected.
+///
+/// ```toml
+/// # Formatting options for parol's grammar descriptions
+/// max_line_length = 80
+/// align_alternations = true
+/// ```
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FormatConfig {
+    /// Add an empty line after each production
+    pub empty_line_after_prod: Option<bool>,
+    /// Place the semicolon after each production on a new line
+    pub prod_semicolon_on_nl: Option<bool>,
+    /// Maximum number of characters per line
+    pub max_line_length: Option<usize>,
+    /// Place each alternative of a production on its own line, aligned below the colon
+    pub align_alternations: Option<bool>,
+    /// Align the trailing line comments of consecutive lines
+    pub align_comments: Option<bool>,
+    /// Sort the declarations of the prolog by their kind and separate the groups by empty lines
+    pub sort_prolog_declarations: Option<bool>,
+}
+
+impl FormatConfig {
+    ///
+    /// Parses the content of a formatting configuration file.
+    ///
+    pub fn parse(input: &str) -> Result<Self> {
+        toml::from_str(input).map_err(|e| anyhow!("{FORMAT_CONFIG_FILE_NAME}: {e}"))
+    }
+
+    ///
+    /// Reads the given formatting configuration file.
+    ///
+    pub fn load(path: &Path) -> Result<Self> {
+        let content =
+            fs::read_to_string(path).map_err(|e| anyhow!("Can't read {}: {e}", path.display()))?;
+        Self::parse(&content)
+    }
+
+    ///
+    /// Searches the formatting configuration file that applies to the given grammar file.
+    /// The search starts in the directory of the grammar file and continues in its ancestors.
+    ///
+    pub fn find(grammar_file: &Path) -> Option<PathBuf> {
+        grammar_file
+            .ancestors()
+            .skip(1)
+            .map(|dir| dir.join(FORMAT_CONFIG_FILE_NAME))
+            .find(|path| path.is_file())
+    }
+
+    ///
+    /// Reads the formatting configuration that applies to the given grammar file.
+    /// Returns the default configuration if no configuration file exists.
+    ///
+    pub fn for_grammar_file(grammar_file: &Path) -> Result<Self> {
+        Self::find(grammar_file).map_or_else(|| Ok(Self::default()), |path| Self::load(&path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormatConfig;
+
+    #[test]
+    fn parse_format_config() {
+        let config = FormatConfig::parse(
+            r#"
+# Project wide formatting
+max_line_length = 80 # narrow
+align_alternations = true
+
+sort_prolog_declarations = false
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            FormatConfig {
+                max_line_length: Some(80),
+                align_alternations: Some(true),
+                sort_prolog_declarations: Some(false),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn reject_invalid_format_config() {
+        assert!(FormatConfig::parse("align_comments = yes").is_err());
+        assert!(FormatConfig::parse("max_line_length = -1").is_err());
+        assert!(FormatConfig::parse("unknown = true").is_err());
+        assert!(FormatConfig::parse("[format]").is_err());
+    }
+}
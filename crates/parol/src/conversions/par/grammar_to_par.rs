@@ -1,11 +1,12 @@
 //!
 //! The module contains the conversion to a the PAR format.
 //!
+use super::FormatConfig;
 use crate::{
     GrammarConfig, ScannerConfig, StrVec, grammar::cfg::FnPrimaryNonTerminalFinder, group_by,
     parser::parol_grammar::GrammarType,
 };
-use anyhow::Result;
+use anyhow::{Result, bail};
 
 // ---------------------------------------------------
 // Part of the Public API
@@ -18,6 +19,29 @@ pub fn render_par_string(
     grammar_config: &GrammarConfig,
     add_index_comment: bool,
 ) -> Result<String> {
+    render_par_string_with_config(grammar_config, add_index_comment, &FormatConfig::default())
+}
+
+///
+/// Formats the given GrammarConfig in the PAR format and applies the given formatting options.
+///
+/// The options `empty_line_after_prod`, `prod_semicolon_on_nl`, `align_alternations` and
+/// `max_line_length` affect the rendering of the productions, `sort_prolog_declarations` separates
+/// the groups of prolog declarations by empty lines. Options that are not set keep the standard
+/// format, i.e. one line per production.
+///
+/// The rendered grammar contains no line comments, therefore enabling `align_comments` is rejected
+/// with an error.
+///
+pub fn render_par_string_with_config(
+    grammar_config: &GrammarConfig,
+    add_index_comment: bool,
+    format_config: &FormatConfig,
+) -> Result<String> {
+    if format_config.align_comments == Some(true) {
+        bail!("The option `align_comments` is not supported when rendering a grammar");
+    }
+
     let title = grammar_config
         .title
         .as_ref()
@@ -35,6 +59,12 @@ pub fn render_par_string(
         GrammarType::LALR1 => "%grammar_type 'lalr(1)'\n".to_owned(),
     };
 
+    let entry_points = if grammar_config.entry_points.is_empty() {
+        "".to_owned()
+    } else {
+        format!("%entry {}\n", grammar_config.entry_points.join(" "))
+    };
+
     let scanner_state_resolver = grammar_config.get_scanner_state_resolver();
     let primary_non_terminal_finder = grammar_config.cfg.get_primary_non_terminal_finder();
 
@@ -65,27 +95,18 @@ pub fn render_par_string(
 
     let user_type_resolver = grammar_config.get_user_type_resolver();
 
-    let mut productions =
-        grammar_config
-            .cfg
-            .pr
-            .iter()
-            .try_fold(Vec::new(), |mut acc: Vec<String>, p| {
-                p.format(&scanner_state_resolver, &user_type_resolver)
-                    .map(|s| {
-                        acc.push(s);
-                        acc
-                    })
-            })?;
-
-    if add_index_comment {
-        let width = (productions.len() as f32).log10() as usize + 1;
-        productions = productions
-            .drain(..)
-            .enumerate()
-            .map(|(i, p)| format!("/* {i:width$} */ {p}"))
-            .collect();
-    }
+    let productions = grammar_config.cfg.pr.iter().try_fold(
+        Vec::new(),
+        |mut acc: Vec<(String, Vec<String>)>, p| {
+            p.format_parts(&scanner_state_resolver, &user_type_resolver)
+                .map(|s| {
+                    acc.push(s);
+                    acc
+                })
+        },
+    )?;
+
+    let mut productions = render_productions(productions, add_index_comment, format_config);
 
     let mut scanner_states = grammar_config
         .scanner_configurations
@@ -111,16 +132,103 @@ pub fn render_par_string(
         acc
     });
 
+    let prolog = if format_config.sort_prolog_declarations.unwrap_or_default() {
+        [
+            format!("{title}{comment}{grammar_type}{entry_points}"),
+            initial_scanner_state,
+            user_types,
+        ]
+        .into_iter()
+        .filter(|group| !group.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+    } else {
+        format!("{title}{comment}{grammar_type}{entry_points}{initial_scanner_state}{user_types}")
+    };
+
     let start_symbol = grammar_config.cfg.st.clone();
     Ok(format!(
         "%start {start_symbol}
-{title}{comment}{grammar_type}{initial_scanner_state}{user_types}
+{prolog}
 {scanner_states}%%
 
 {productions}"
     ))
 }
 
+/// Arranges the formatted productions according to the formatting options.
+/// Each production is expected as its left-hand side and the items of its right-hand side.
+fn render_productions(
+    productions: Vec<(String, Vec<String>)>,
+    add_index_comment: bool,
+    format_config: &FormatConfig,
+) -> Vec<String> {
+    let width = (productions.len() as f32).log10() as usize + 1;
+    let align_alternations = format_config.align_alternations.unwrap_or_default();
+    let prod_semicolon_on_nl = format_config.prod_semicolon_on_nl.unwrap_or_default();
+    let empty_line_after_prod = format_config.empty_line_after_prod.unwrap_or_default();
+
+    let mut lines = Vec::with_capacity(productions.len());
+    for (i, (lhs, rhs)) in productions.iter().enumerate() {
+        let prefix = if add_index_comment {
+            format!("/* {i:width$} */ ")
+        } else {
+            String::new()
+        };
+        let is_alternative = align_alternations && i > 0 && productions[i - 1].0 == *lhs;
+        let has_alternative =
+            align_alternations && productions.get(i + 1).is_some_and(|n| n.0 == *lhs);
+        let mut line = if is_alternative {
+            format!("{prefix}{:indent$}|", "", indent = lhs.len())
+        } else {
+            format!("{prefix}{lhs}:")
+        };
+        push_rhs(
+            &mut line,
+            rhs,
+            prefix.len() + lhs.len() + 2,
+            format_config.max_line_length,
+        );
+        if !has_alternative {
+            if prod_semicolon_on_nl {
+                line.push_str(&format!(
+                    "\n{:indent$};",
+                    "",
+                    indent = prefix.len() + lhs.len()
+                ));
+            } else {
+                line.push(';');
+            }
+            if empty_line_after_prod {
+                line.push('\n');
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Appends the items of a right-hand side to the line.
+/// Items that would exceed the maximum line length are continued on a new line with the given
+/// indentation. Each line takes at least one item, longer items are not broken up.
+fn push_rhs(line: &mut String, rhs: &[String], indent: usize, max_line_length: Option<usize>) {
+    if rhs.is_empty() {
+        line.push(' ');
+    }
+    let mut line_start = 0;
+    for (i, item) in rhs.iter().enumerate() {
+        let exceeds = max_line_length
+            .is_some_and(|max| line[line_start..].chars().count() + 1 + item.chars().count() > max);
+        if i > 0 && exceeds {
+            line_start = line.len() + 1;
+            line.push_str(&format!("\n{:indent$}", ""));
+        } else {
+            line.push(' ');
+        }
+        line.push_str(item);
+    }
+}
+
 fn render_scanner_config_string(
     index: usize,
     scanner_config: &ScannerConfig,
@@ -143,6 +251,10 @@ fn render_scanner_config_string(
         scanner_directives.push_str(&format!("{indent}%block_comment \"{s}\" \"{e}\"\n"));
     }
 
+    for (s, e) in &scanner_config.nested_block_comments {
+        scanner_directives.push_str(&format!("{indent}%nested_block_comment \"{s}\" \"{e}\"\n"));
+    }
+
     if !scanner_config.auto_newline {
         scanner_directives.push_str(&format!("{indent}%auto_newline_off\n"));
     }
@@ -165,6 +277,36 @@ fn render_scanner_config_string(
         scanner_directives.push_str(&format!("{indent}%skip {}\n", skip_tokens.join(", ")));
     }
 
+    if let Some(offside_tokens) = &scanner_config.offside_tokens {
+        let [indent_token, dedent_token, newline_token] = [
+            offside_tokens.indent,
+            offside_tokens.dedent,
+            offside_tokens.newline,
+        ]
+        .map(|terminal_index| {
+            primary_non_terminal_finder(terminal_index)
+                .unwrap_or_else(|| terminal_index.to_string())
+        });
+        scanner_directives.push_str(&format!(
+            "{indent}%offside {indent_token} {dedent_token} {newline_token}\n"
+        ));
+    }
+
+    let external_tokens = scanner_config
+        .external_tokens
+        .iter()
+        .map(|terminal_index| {
+            primary_non_terminal_finder(*terminal_index)
+                .unwrap_or_else(|| terminal_index.to_string())
+        })
+        .collect::<Vec<_>>();
+    if !external_tokens.is_empty() {
+        scanner_directives.push_str(&format!(
+            "{indent}%external {}\n",
+            external_tokens.join(", ")
+        ));
+    }
+
     let mut transitions = Vec::new();
 
     for (scanner_switch, primary_nts) in group_by(&scanner_config.transitions, |(_, v)| v.clone()) {
@@ -199,7 +341,7 @@ fn render_scanner_config_string(
 
 #[cfg(test)]
 mod test {
-    use crate::conversions::par::render_par_string;
+    use crate::conversions::par::{FormatConfig, render_par_string, render_par_string_with_config};
     use crate::{
         Cfg, GrammarConfig, Pr, ScannerConfig, Symbol, SymbolAttribute, Terminal, TerminalKind,
     };
@@ -284,4 +426,83 @@ mod test {
             "Expected rendered PAR string to contain %skip directive, got:\n{par_str}"
         );
     }
+
+    #[test]
+    fn check_par_format_with_config() {
+        let g = Cfg::with_start_symbol("S")
+            .add_pr(Pr::new("S", vec![terminal!("a"), Symbol::n("X")]))
+            .add_pr(Pr::new("X", vec![terminal!("b"), Symbol::n("S")]))
+            .add_pr(Pr::new("X", vec![terminal!("a")]));
+
+        let grammar_config = GrammarConfig::new(g, 1).add_scanner(ScannerConfig::default());
+        let format_config = FormatConfig {
+            align_alternations: Some(true),
+            prod_semicolon_on_nl: Some(true),
+            empty_line_after_prod: Some(true),
+            ..Default::default()
+        };
+
+        let par_str = render_par_string_with_config(&grammar_config, false, &format_config)
+            .unwrap()
+            .replace("\r\n", "\n");
+        let expected = r#"%start S
+
+%%
+
+S: "a" X
+ ;
+
+X: "b" S
+ | "a"
+ ;
+
+"#;
+        assert_eq!(expected, par_str);
+    }
+
+    #[test]
+    fn check_par_format_with_line_length_and_sorted_prolog() {
+        let g = Cfg::with_start_symbol("S").add_pr(Pr::new(
+            "S",
+            vec![
+                terminal!("a"),
+                Symbol::n("S"),
+                terminal!("b"),
+                Symbol::n("S"),
+                terminal!("a"),
+                Symbol::n("S"),
+            ],
+        ));
+
+        let grammar_config = GrammarConfig::new(g, 1)
+            .with_title(Some("Test grammar".to_owned()))
+            .add_user_type_def("Num".to_owned(), "crate::Number".to_owned())
+            .add_scanner(ScannerConfig::default());
+        let format_config = FormatConfig {
+            max_line_length: Some(16),
+            sort_prolog_declarations: Some(true),
+            ..Default::default()
+        };
+
+        let par_str = render_par_string_with_config(&grammar_config, false, &format_config)
+            .unwrap()
+            .replace("\r\n", "\n");
+        let expected = r#"%start S
+%title "Test grammar"
+
+%user_type Num = crate::Number
+
+%%
+
+S: "a" S "b" S
+   "a" S;
+"#;
+        assert_eq!(expected, par_str);
+
+        let format_config = FormatConfig {
+            align_comments: Some(true),
+            ..Default::default()
+        };
+        assert!(render_par_string_with_config(&grammar_config, false, &format_config).is_err());
+    }
 }
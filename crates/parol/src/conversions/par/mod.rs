@@ -1,3 +1,5 @@
+pub mod format_config;
 pub mod grammar_to_par;
 
-pub use grammar_to_par::render_par_string;
+pub use format_config::{FORMAT_CONFIG_FILE_NAME, FormatConfig};
+pub use grammar_to_par::{render_par_string, render_par_string_with_config};
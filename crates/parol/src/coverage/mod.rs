@@ -0,0 +1,414 @@
+//! Measurement of the grammar coverage achieved by a corpus of inputs.
+//!
+//! The parser and the scanner for the grammar are built at runtime and parse the inputs with
+//! coverage counting enabled. The counts of the productions are then related to the alternatives,
+//! optional expressions and repetitions of the grammar description.
+
+use crate::analysis::lookahead_dfa::ProductionIndex;
+use crate::generators::generate_terminal_names;
+use crate::runtime_parser::RuntimeParser;
+use crate::scanner_emulation::Scanner;
+use crate::{check_and_transform_grammar, obtain_grammar_config_from_string};
+use anyhow::{Result, anyhow, bail};
+use parol_runtime::Coverage;
+use parol_runtime::lexer::FIRST_USER_TOKEN;
+use parol_runtime::parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor};
+use parol_runtime::syntree::Builder;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+mod source_map;
+use source_map::{SourceItem, canonical_productions, map_source};
+
+/// The kind of a construct of the grammar description whose usage is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageItemKind {
+    /// An alternative of a production or of a group
+    Alternative,
+    /// An optional expression whose content is present in the input
+    OptionalPresent,
+    /// An optional expression whose content is absent in the input
+    OptionalAbsent,
+    /// A repetition with at least one repeated element
+    Repetition,
+}
+
+/// The usage of a construct of the grammar description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverageItem {
+    /// The kind of the construct
+    pub kind: CoverageItemKind,
+    /// The non-terminal in whose production the construct is located
+    pub non_terminal: String,
+    /// The source text of the construct with normalized whitespace
+    pub text: String,
+    /// The line of the construct in the grammar description, starting at 1
+    pub line: usize,
+    /// The column of the construct in the grammar description, starting at 1
+    pub column: usize,
+    /// The number of the decision the construct belongs to, e.g. all alternatives of a group
+    pub block: usize,
+    /// How often the construct has been used
+    pub count: usize,
+}
+
+/// The usage of a terminal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalCoverage {
+    /// The generated name of the terminal
+    pub name: String,
+    /// The terminal as written in the grammar description
+    pub pattern: String,
+    /// How often the terminal has been matched
+    pub count: usize,
+}
+
+/// The coverage of a grammar description by the inputs added to a [GrammarCoverage]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoverageReport {
+    /// The measured constructs in the order of their appearance in the grammar description
+    pub items: Vec<CoverageItem>,
+    /// The terminals in the order of their definition
+    pub terminals: Vec<TerminalCoverage>,
+}
+
+impl CoverageReport {
+    /// Returns the constructs that have never been used
+    pub fn uncovered_items(&self) -> impl Iterator<Item = &CoverageItem> {
+        self.items.iter().filter(|i| i.count == 0)
+    }
+
+    /// Returns the terminals that have never been matched
+    pub fn uncovered_terminals(&self) -> impl Iterator<Item = &TerminalCoverage> {
+        self.terminals.iter().filter(|t| t.count == 0)
+    }
+
+    ///
+    /// Renders the report in the LCOV tracefile format.
+    ///
+    /// Each construct is a branch of its decision. A line counts as executed only if all
+    /// constructs starting on it have been used, so uncovered alternatives are visible as line
+    /// decorations in editors.
+    ///
+    pub fn to_lcov(&self, grammar_file: &Path) -> String {
+        let mut lcov = format!("TN:\nSF:{}\n", grammar_file.display());
+        let mut lines = BTreeMap::<usize, usize>::new();
+        let mut branches = BTreeMap::<usize, usize>::new();
+        for item in &self.items {
+            let branch = branches.entry(item.block).or_default();
+            let _ = writeln!(
+                lcov,
+                "BRDA:{},{},{},{}",
+                item.line, item.block, branch, item.count
+            );
+            *branch += 1;
+            lines
+                .entry(item.line)
+                .and_modify(|count| *count = (*count).min(item.count))
+                .or_insert(item.count);
+        }
+        let _ = writeln!(lcov, "BRF:{}", self.items.len());
+        let _ = writeln!(
+            lcov,
+            "BRH:{}",
+            self.items.iter().filter(|i| i.count > 0).count()
+        );
+        for (line, count) in &lines {
+            let _ = writeln!(lcov, "DA:{line},{count}");
+        }
+        let _ = writeln!(lcov, "LF:{}", lines.len());
+        let _ = writeln!(lcov, "LH:{}", lines.values().filter(|c| **c > 0).count());
+        lcov.push_str("end_of_record\n");
+        lcov
+    }
+}
+
+///
+/// Measures which constructs and terminals of a grammar description are used when parsing a
+/// corpus of inputs.
+///
+#[derive(Debug)]
+pub struct GrammarCoverage {
+    grammar_source: String,
+    scanner: Scanner,
+    parser: RuntimeParser,
+    /// The terminals with their patterns in the order of their terminal index
+    terminals: Vec<(String, String)>,
+    items: Vec<SourceItem>,
+    /// The canonical production for each production of the transformed grammar
+    canonical_productions: Vec<Option<ProductionIndex>>,
+    canonical_production_count: usize,
+    coverage: Coverage,
+}
+
+impl GrammarCoverage {
+    ///
+    /// Creates the parser and the scanner for the grammar description in `grammar_source`.
+    /// The `grammar_file` is only used in messages. LL(k) grammars are analyzed with a lookahead
+    /// of at most `max_lookahead`.
+    ///
+    pub fn try_new(
+        grammar_source: &str,
+        grammar_file: &Path,
+        max_lookahead: usize,
+    ) -> Result<Self> {
+        let mut grammar_config = obtain_grammar_config_from_string(grammar_source, false)?;
+        grammar_config.update_lookahead_size(max_lookahead);
+        let canonical = grammar_config.cfg.clone();
+        let items = map_source(grammar_source, grammar_file, &canonical)?;
+        let cfg = check_and_transform_grammar(&canonical, grammar_config.grammar_type)?;
+        grammar_config.update_cfg(cfg);
+
+        let terminal_names = generate_terminal_names(&grammar_config);
+        let scanner = Scanner::try_new(&grammar_config, &terminal_names)?;
+        let parser = RuntimeParser::try_new(&grammar_config, &terminal_names)?;
+        let terminals = grammar_config
+            .cfg
+            .get_ordered_terminals()
+            .iter()
+            .enumerate()
+            .map(|(i, (t, k, ..))| {
+                let delimiter = k.delimiter();
+                (
+                    terminal_names[i + FIRST_USER_TOKEN as usize].clone(),
+                    format!("{delimiter}{t}{delimiter}{}", k.suffix()),
+                )
+            })
+            .collect();
+        let coverage = Coverage::new(grammar_config.cfg.pr.len(), terminal_names.len());
+
+        Ok(Self {
+            grammar_source: grammar_source.to_string(),
+            scanner,
+            parser,
+            terminals,
+            items,
+            canonical_productions: canonical_productions(&canonical, &grammar_config.cfg),
+            canonical_production_count: canonical.pr.len(),
+            coverage,
+        })
+    }
+
+    ///
+    /// Parses the given input and adds the usage of the grammar to the measured coverage.
+    /// The usage up to a syntax error is measured as well before the error is returned.
+    /// Input that no terminal matches is an error, too.
+    ///
+    pub fn add_input(&mut self, input: &str, file_name: &Path) -> Result<()> {
+        let scanned = self
+            .scanner
+            .tokenize(input, Arc::new(PathBuf::from(file_name)));
+        if let Some(unmatched) = scanned.unmatched.first() {
+            bail!(
+                "Failed parsing {}: No terminal matches the input {:?} at {}:{}",
+                file_name.display(),
+                unmatched.text(),
+                unmatched.location.start_line,
+                unmatched.location.start_column
+            );
+        }
+        let mut tree_builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+        self.parser
+            .parse_into(&mut tree_builder, scanned.tokens, Some(&mut self.coverage))
+            .map_err(|e| anyhow!("Failed parsing {}: {e}", file_name.display()))
+    }
+
+    /// Returns the coverage of the grammar by all inputs added so far
+    pub fn report(&self) -> CoverageReport {
+        let production_counts = self.coverage.production_counts();
+        let mut counts = vec![0; self.canonical_production_count];
+        for (production, canonical) in self.canonical_productions.iter().enumerate() {
+            if let Some(canonical) = canonical {
+                counts[*canonical] += production_counts[production];
+            }
+        }
+        let items = self
+            .items
+            .iter()
+            .map(|item| {
+                let (line, column) = position(&self.grammar_source, item.span.start);
+                CoverageItem {
+                    kind: item.kind,
+                    non_terminal: item.non_terminal.clone(),
+                    text: self.grammar_source[item.span.clone()]
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    line,
+                    column,
+                    block: item.block,
+                    count: counts[item.production],
+                }
+            })
+            .collect();
+        let terminal_counts = self.coverage.terminal_counts();
+        let terminals = self
+            .terminals
+            .iter()
+            .enumerate()
+            .map(|(i, (name, pattern))| TerminalCoverage {
+                name: name.clone(),
+                pattern: pattern.clone(),
+                count: terminal_counts[i + FIRST_USER_TOKEN as usize],
+            })
+            .collect();
+        CoverageReport { items, terminals }
+    }
+}
+
+/// Returns the line and the column of the given byte offset, both starting at 1
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRAMMAR: &str = r#"%start List
+%%
+List: { Item };
+Item: Num [ Unit ]
+    | Num '%'
+    | Str;
+Num: /[0-9]+/;
+Unit: 'px' | 'em';
+Str: /"[^"]*"/;
+"#;
+
+    fn uncovered(grammar: &str, inputs: &[&str]) -> (Vec<String>, Vec<String>) {
+        let mut coverage = GrammarCoverage::try_new(grammar, Path::new("test.par"), 5).unwrap();
+        for input in inputs {
+            coverage.add_input(input, Path::new("input.txt")).unwrap();
+        }
+        let report = coverage.report();
+        (
+            report
+                .uncovered_items()
+                .map(|i| format!("{}:{}:{:?}:{}", i.line, i.column, i.kind, i.text))
+                .collect(),
+            report
+                .uncovered_terminals()
+                .map(|t| t.pattern.clone())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn uncovered_alternatives_of_ll_grammar() {
+        let (items, terminals) = uncovered(GRAMMAR, &["1px 2"]);
+        assert_eq!(
+            vec![
+                "5:7:Alternative:Num '%'",
+                "6:7:Alternative:Str",
+                "8:14:Alternative:'em'",
+                "9:6:Alternative:/\"[^\"]*\"/",
+            ],
+            items
+        );
+        assert_eq!(vec!["'%'", "'em'", r#"/"[^"]*"/"#], terminals);
+
+        let (items, terminals) = uncovered(GRAMMAR, &["1px 2", "3% \"s\" 4em"]);
+        assert!(items.is_empty(), "{items:?}");
+        assert!(terminals.is_empty(), "{terminals:?}");
+    }
+
+    #[test]
+    fn uncovered_alternatives_of_lr_grammar() {
+        let grammar = GRAMMAR.replace("%%", "%grammar_type 'LALR(1)'\n%%");
+        let (items, _) = uncovered(&grammar, &["1px 2"]);
+        assert_eq!(
+            vec![
+                "6:7:Alternative:Num '%'",
+                "7:7:Alternative:Str",
+                "9:14:Alternative:'em'",
+                "10:6:Alternative:/\"[^\"]*\"/",
+            ],
+            items
+        );
+    }
+
+    #[test]
+    fn empty_corpus_covers_nothing() {
+        let (items, _) = uncovered(GRAMMAR, &[]);
+        assert_eq!(
+            vec![
+                "3:7:Alternative:{ Item }",
+                "3:7:Repetition:{ Item }",
+                "4:7:Alternative:Num [ Unit ]",
+                "4:11:OptionalPresent:[ Unit ]",
+                "4:11:OptionalAbsent:[ Unit ]",
+                "5:7:Alternative:Num '%'",
+                "6:7:Alternative:Str",
+                "7:6:Alternative:/[0-9]+/",
+                "8:7:Alternative:'px'",
+                "8:14:Alternative:'em'",
+                "9:6:Alternative:/\"[^\"]*\"/",
+            ],
+            items
+        );
+    }
+
+    #[test]
+    fn syntax_errors_are_reported_after_counting() {
+        let mut coverage = GrammarCoverage::try_new(GRAMMAR, Path::new("test.par"), 5).unwrap();
+        assert!(
+            coverage
+                .add_input("1 px %", Path::new("input.txt"))
+                .is_err()
+        );
+        let report = coverage.report();
+        assert!(
+            report
+                .items
+                .iter()
+                .any(|i| i.text == "'px'" && i.count == 1)
+        );
+    }
+
+    #[test]
+    fn unmatched_input_is_an_error() {
+        let mut coverage = GrammarCoverage::try_new(GRAMMAR, Path::new("test.par"), 5).unwrap();
+        let error = coverage
+            .add_input("1px\n 2 ?", Path::new("input.txt"))
+            .unwrap_err();
+        assert_eq!(
+            "Failed parsing input.txt: No terminal matches the input \"?\" at 2:4",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn terminals_are_rendered_with_their_suffix() {
+        let grammar = GRAMMAR.replace("'em'", "'em'i");
+        let (_, terminals) = uncovered(&grammar, &["1px 2"]);
+        assert_eq!(vec!["'%'", "'em'i", r#"/"[^"]*"/"#], terminals);
+    }
+
+    #[test]
+    fn terminals_take_the_longest_match() {
+        let grammar = "%start List\n%%\nList: { Num };\nNum: /\\d+|\\d+\\.\\d+/;\n";
+        let mut coverage = GrammarCoverage::try_new(grammar, Path::new("test.par"), 5).unwrap();
+        coverage.add_input("1.5 2", Path::new("input.txt")).unwrap();
+        let report = coverage.report();
+        assert_eq!(2, report.terminals[0].count);
+    }
+
+    #[test]
+    fn lcov_marks_lines_with_uncovered_constructs() {
+        let mut coverage = GrammarCoverage::try_new(GRAMMAR, Path::new("test.par"), 5).unwrap();
+        coverage.add_input("1px 2", Path::new("input.txt")).unwrap();
+        let lcov = coverage.report().to_lcov(Path::new("test.par"));
+        assert!(lcov.starts_with("TN:\nSF:test.par\n"));
+        assert!(lcov.contains("DA:3,1\n"));
+        assert!(lcov.contains("DA:5,0\n"));
+        assert!(lcov.contains("BRDA:8,5,1,0\n"));
+        assert!(lcov.contains("LF:7\nLH:3\n"));
+        assert!(lcov.ends_with("end_of_record\n"));
+    }
+}
@@ -0,0 +1,412 @@
+//! Relates the productions of the transformed grammar to the constructs of the grammar
+//! description.
+//!
+//! The canonicalization keeps the structure of the grammar description: each alternative of a
+//! non-terminal becomes one production of this non-terminal in the same order, and each optional
+//! expression, repetition and group with more than one alternative is replaced by a new
+//! non-terminal at its position. Groups with only one alternative are inlined.
+//! Left-factoring and the augmentation of LR grammars are undone by matching the right-hand
+//! sides of the productions.
+
+use super::CoverageItemKind;
+use crate::analysis::lookahead_dfa::ProductionIndex;
+use crate::grammar::ProductionAttribute;
+use crate::parser::{ParolGrammar, parse};
+use crate::{Cfg, Symbol};
+use anyhow::{Result, anyhow};
+use parol_runtime::ParseTree;
+use parol_runtime::lexer::FIRST_USER_TOKEN;
+use parol_runtime::parser::parse_tree_type::SynTree;
+use parol_runtime::parser::parser_types::SynTreeFlavor;
+use parol_runtime::syntree::Node;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Range;
+use std::path::Path;
+
+type TreeNode<'a> = Node<'a, SynTree, SynTreeFlavor>;
+
+/// An alternative of the grammar description
+#[derive(Debug, Clone)]
+struct SrcAlt {
+    span: Range<usize>,
+    factors: Vec<SrcFactor>,
+}
+
+/// A factor of an alternative of the grammar description
+#[derive(Debug, Clone)]
+enum SrcFactor {
+    Group(Vec<SrcAlt>),
+    Repeat(Range<usize>, Vec<SrcAlt>),
+    Optional(Range<usize>, Vec<SrcAlt>),
+    Symbol,
+}
+
+/// A construct of the grammar description together with the production of the canonical
+/// grammar that is used when the construct is used
+#[derive(Debug, Clone)]
+pub(crate) struct SourceItem {
+    pub(crate) kind: CoverageItemKind,
+    pub(crate) non_terminal: String,
+    pub(crate) span: Range<usize>,
+    /// Constructs of the same decision share the block number
+    pub(crate) block: usize,
+    pub(crate) production: ProductionIndex,
+}
+
+///
+/// Returns the constructs of the grammar description `input` related to the productions of the
+/// canonical grammar `cfg` that has been created from it.
+///
+pub(crate) fn map_source(input: &str, file_name: &Path, cfg: &Cfg) -> Result<Vec<SourceItem>> {
+    let mut parol_grammar = ParolGrammar::new();
+    let syntax_tree = parse(input, file_name, &mut parol_grammar)
+        .map_err(|e| anyhow!("Failed parsing grammar {}: {e}", file_name.display()))?;
+    let productions = source_productions(&syntax_tree, input)?;
+
+    let source_non_terminals = productions
+        .iter()
+        .map(|(lhs, _)| lhs.clone())
+        .collect::<BTreeSet<String>>();
+    let mut mapper = Mapper {
+        cfg,
+        source_non_terminals,
+        owned: BTreeSet::new(),
+        items: Vec::new(),
+        blocks: 0,
+    };
+    // The alternatives of a non-terminal become its productions in the same order
+    let mut next_production = BTreeMap::<&str, usize>::new();
+    let mut blocks = BTreeMap::<&str, usize>::new();
+    for (lhs, alternatives) in &productions {
+        let candidates = cfg.matching_productions(lhs);
+        let block = *blocks.entry(lhs).or_insert_with(|| mapper.new_block());
+        for alt in alternatives {
+            let next = next_production.entry(lhs).or_default();
+            let Some((production, _)) = candidates.get(*next) else {
+                break;
+            };
+            *next += 1;
+            mapper.map_alt(
+                lhs,
+                alt.span.clone(),
+                &alt.factors,
+                *production,
+                Some(block),
+            );
+        }
+    }
+    let mut items = mapper.items;
+    items.sort_by_key(|item| item.span.start);
+    Ok(items)
+}
+
+///
+/// Returns for each production of the `transformed` grammar the production of the `canonical`
+/// grammar that is completed by it.
+/// The productions that only contain a prefix of a canonical production after left-factoring
+/// and the production of the augmented start symbol are mapped to None.
+///
+pub(crate) fn canonical_productions(
+    canonical: &Cfg,
+    transformed: &Cfg,
+) -> Vec<Option<ProductionIndex>> {
+    struct Expander<'a> {
+        canonical: &'a Cfg,
+        transformed: &'a Cfg,
+        canonical_non_terminals: BTreeSet<String>,
+        assigned: Vec<bool>,
+        result: Vec<Option<ProductionIndex>>,
+    }
+
+    impl Expander<'_> {
+        fn expand(&mut self, lhs: &str, prefix: &[Symbol], index: ProductionIndex) {
+            let rhs = prefix
+                .iter()
+                .chain(self.transformed.pr[index].get_r())
+                .cloned()
+                .collect::<Vec<Symbol>>();
+            match rhs.last() {
+                // A suffix introduced by left-factoring
+                Some(Symbol::N(n, ..)) if !self.canonical_non_terminals.contains(n) => {
+                    let suffixes = self
+                        .transformed
+                        .pr
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, pr)| pr.get_n_str() == n)
+                        .map(|(i, _)| i)
+                        .collect::<Vec<_>>();
+                    for suffix in suffixes {
+                        self.expand(lhs, &rhs[..rhs.len() - 1], suffix);
+                    }
+                }
+                _ => {
+                    if let Some(production) =
+                        self.canonical.pr.iter().enumerate().position(|(i, pr)| {
+                            !self.assigned[i] && pr.get_n_str() == lhs && *pr.get_r() == rhs
+                        })
+                    {
+                        self.assigned[production] = true;
+                        self.result[index] = Some(production);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut expander = Expander {
+        canonical,
+        transformed,
+        canonical_non_terminals: canonical.get_non_terminal_set(),
+        assigned: vec![false; canonical.pr.len()],
+        result: vec![None; transformed.pr.len()],
+    };
+    for (index, pr) in transformed.pr.iter().enumerate() {
+        let lhs = pr.get_n_str();
+        if expander.canonical_non_terminals.contains(lhs) {
+            expander.expand(lhs, &[], index);
+        }
+    }
+    expander.result
+}
+
+struct Mapper<'a> {
+    cfg: &'a Cfg,
+    source_non_terminals: BTreeSet<String>,
+    /// The generated non-terminals that have already been related to a construct
+    owned: BTreeSet<String>,
+    items: Vec<SourceItem>,
+    blocks: usize,
+}
+
+impl Mapper<'_> {
+    fn new_block(&mut self) -> usize {
+        self.blocks += 1;
+        self.blocks - 1
+    }
+
+    /// Records the alternative if a block is given and maps the constructs inside of it
+    fn map_alt(
+        &mut self,
+        non_terminal: &str,
+        span: Range<usize>,
+        factors: &[SrcFactor],
+        production: ProductionIndex,
+        block: Option<usize>,
+    ) {
+        if let Some(block) = block {
+            self.add_item(
+                CoverageItemKind::Alternative,
+                non_terminal,
+                span,
+                block,
+                production,
+            );
+        }
+        let mut constructs = Vec::new();
+        flatten(factors, &mut constructs);
+        let generated = self.cfg.pr[production]
+            .get_r()
+            .iter()
+            .filter_map(|s| match s {
+                Symbol::N(n, ..)
+                    if !self.source_non_terminals.contains(n) && !self.owned.contains(n) =>
+                {
+                    Some(n.clone())
+                }
+                _ => None,
+            })
+            .collect::<Vec<String>>();
+        if constructs.len() != generated.len() {
+            // The inner constructs can't be related to the productions
+            return;
+        }
+        for (construct, generated) in constructs.into_iter().zip(generated) {
+            self.map_construct(non_terminal, construct, generated);
+        }
+    }
+
+    fn map_construct(&mut self, non_terminal: &str, construct: &SrcFactor, generated: String) {
+        let productions = self
+            .cfg
+            .matching_productions(&generated)
+            .iter()
+            .map(|(i, pr)| (*i, pr.2))
+            .collect::<Vec<_>>();
+        self.owned.insert(generated);
+        let with_attribute = |attribute: ProductionAttribute| {
+            productions
+                .iter()
+                .find(|(_, a)| *a == attribute)
+                .map(|(i, _)| *i)
+        };
+        match construct {
+            SrcFactor::Optional(span, alts) => {
+                let (Some(some), Some(none)) = (
+                    with_attribute(ProductionAttribute::OptionalSome),
+                    with_attribute(ProductionAttribute::OptionalNone),
+                ) else {
+                    return;
+                };
+                let block = self.new_block();
+                self.add_item(
+                    CoverageItemKind::OptionalPresent,
+                    non_terminal,
+                    span.clone(),
+                    block,
+                    some,
+                );
+                self.add_item(
+                    CoverageItemKind::OptionalAbsent,
+                    non_terminal,
+                    span.clone(),
+                    block,
+                    none,
+                );
+                let content = [SrcFactor::Group(alts.clone())];
+                self.map_alt(non_terminal, span.clone(), &content, some, None);
+            }
+            SrcFactor::Repeat(span, alts) => {
+                let Some(add) = with_attribute(ProductionAttribute::AddToCollection) else {
+                    return;
+                };
+                let block = self.new_block();
+                self.add_item(
+                    CoverageItemKind::Repetition,
+                    non_terminal,
+                    span.clone(),
+                    block,
+                    add,
+                );
+                let content = [SrcFactor::Group(alts.clone())];
+                self.map_alt(non_terminal, span.clone(), &content, add, None);
+            }
+            SrcFactor::Group(alts) if alts.len() == productions.len() => {
+                let block = self.new_block();
+                for (alt, (production, _)) in alts.iter().zip(productions) {
+                    self.map_alt(
+                        non_terminal,
+                        alt.span.clone(),
+                        &alt.factors,
+                        production,
+                        Some(block),
+                    );
+                }
+            }
+            SrcFactor::Group(..) | SrcFactor::Symbol => (),
+        }
+    }
+
+    fn add_item(
+        &mut self,
+        kind: CoverageItemKind,
+        non_terminal: &str,
+        span: Range<usize>,
+        block: usize,
+        production: ProductionIndex,
+    ) {
+        self.items.push(SourceItem {
+            kind,
+            non_terminal: non_terminal.to_string(),
+            span,
+            block,
+            production,
+        });
+    }
+}
+
+/// Collects the constructs that are replaced by new non-terminals in the order of their
+/// appearance. Groups with only one alternative are inlined by the canonicalization.
+fn flatten<'a>(factors: &'a [SrcFactor], constructs: &mut Vec<&'a SrcFactor>) {
+    for factor in factors {
+        match factor {
+            SrcFactor::Group(alts) if alts.len() == 1 => flatten(&alts[0].factors, constructs),
+            SrcFactor::Symbol => (),
+            _ => constructs.push(factor),
+        }
+    }
+}
+
+/// Returns the productions of the grammar description with their alternatives
+fn source_productions(syntax_tree: &ParseTree, input: &str) -> Result<Vec<(String, Vec<SrcAlt>)>> {
+    let mut productions = Vec::new();
+    for root in syntax_tree.children() {
+        for production in find_all(root, "Production") {
+            let lhs = production
+                .children()
+                .find(|n| is_non_terminal(n, "Identifier"))
+                .and_then(|n| span_of(n))
+                .map(|span| input[span].to_string())
+                .ok_or_else(|| anyhow!("Production without left-hand side"))?;
+            let fallback = span_of(production).unwrap_or_default();
+            productions.push((lhs, alternatives(production, &fallback)));
+        }
+    }
+    Ok(productions)
+}
+
+fn alternatives(node: TreeNode<'_>, fallback: &Range<usize>) -> Vec<SrcAlt> {
+    find_all(node, "Alternation")
+        .into_iter()
+        .map(|alt| SrcAlt {
+            // Empty alternatives are located at their enclosing construct
+            span: span_of(alt).unwrap_or(fallback.start..fallback.start),
+            factors: find_all(alt, "Factor")
+                .into_iter()
+                .map(|factor| {
+                    let Some(inner) = factor
+                        .children()
+                        .find(|n| matches!(n.value(), SynTree::NonTerminal(_)))
+                    else {
+                        return SrcFactor::Symbol;
+                    };
+                    let span = span_of(inner).unwrap_or_else(|| fallback.clone());
+                    let alts = || alternatives(inner, &span);
+                    match inner.value() {
+                        SynTree::NonTerminal("Group") => SrcFactor::Group(alts()),
+                        SynTree::NonTerminal("Repeat") => SrcFactor::Repeat(span.clone(), alts()),
+                        SynTree::NonTerminal("Optional") => {
+                            SrcFactor::Optional(span.clone(), alts())
+                        }
+                        _ => SrcFactor::Symbol,
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn is_non_terminal(node: &TreeNode<'_>, name: &str) -> bool {
+    matches!(node.value(), SynTree::NonTerminal(n) if n == name)
+}
+
+/// Returns the nodes with the given name below the given node without descending into them
+fn find_all<'a>(node: TreeNode<'a>, name: &str) -> Vec<TreeNode<'a>> {
+    let mut found = Vec::new();
+    for child in node.children() {
+        if is_non_terminal(&child, name) {
+            found.push(child);
+        } else {
+            found.append(&mut find_all(child, name));
+        }
+    }
+    found
+}
+
+/// Returns the range of the input covered by the tokens below the given node
+fn span_of(node: TreeNode<'_>) -> Option<Range<usize>> {
+    node.children().fold(None, |span, child| {
+        let child_span = match child.value() {
+            // Whitespace and comments are part of the parse tree
+            SynTree::Terminal(token) if token.token_type >= FIRST_USER_TOKEN => {
+                Some(token.start..token.end)
+            }
+            SynTree::NonTerminal(_) => span_of(child),
+            _ => None,
+        };
+        match (span, child_span) {
+            (Some(s), Some(c)) => Some(s.start.min(c.start)..s.end.max(c.end)),
+            (s, c) => s.or(c),
+        }
+    })
+}
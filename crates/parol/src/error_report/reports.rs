@@ -282,6 +282,34 @@ impl Report for ParolErrorReporter {
                             ]),
                     )?)
                 }
+                ParolParserError::UnknownEntryPoint {
+                    entry,
+                    input,
+                    location,
+                } => {
+                    let mut files = SimpleFiles::new();
+                    let content = fs::read_to_string(input).unwrap_or_default();
+                    let file_id = files.add(input.display().to_string(), content);
+
+                    Ok(term::emit_to_write_style(
+                        &mut writer,
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "Entry point '{entry}' is not a non-terminal of the grammar"
+                            ))
+                            .with_code("parol::parser::unknown_entry_point")
+                            .with_labels(vec![Label::primary(
+                                file_id,
+                                Into::<Range<usize>>::into(location),
+                            )])
+                            .with_notes(vec![
+                                "Only non-terminals defined by productions can be entry points."
+                                    .to_string(),
+                            ]),
+                    )?)
+                }
                 _ => {
                     unreachable!(
                         "Scanner switching directives have been removed from the grammar syntax."
@@ -61,6 +61,37 @@ pub(crate) fn generate_scanner_data_with_terminal_names<C: CommonGeneratorConfig
     config: &C,
     terminal_names: &[String],
 ) -> Result<String> {
+    if let Some(sc) = grammar_config
+        .scanner_configurations
+        .iter()
+        .find(|sc| !sc.nested_block_comments.is_empty())
+    {
+        anyhow::bail!(
+            "Nested block comments in scanner state '{}' are not supported by the C# code \
+            generator.",
+            sc.scanner_name
+        );
+    }
+    if let Some(sc) = grammar_config
+        .scanner_configurations
+        .iter()
+        .find(|sc| sc.offside_tokens.is_some())
+    {
+        anyhow::bail!(
+            "The offside rule in scanner state '{}' is not supported by the C# code generator.",
+            sc.scanner_name
+        );
+    }
+    if let Some(sc) = grammar_config
+        .scanner_configurations
+        .iter()
+        .find(|sc| !sc.external_tokens.is_empty())
+    {
+        anyhow::bail!(
+            "External terminals in scanner state '{}' are not supported by the C# code generator.",
+            sc.scanner_name
+        );
+    }
     let mode_data = build_scanner_mode_data(grammar_config, terminal_names)
         .map_err(|e| anyhow::anyhow!(e.to_string()))?;
     let mode_indices = mode_data
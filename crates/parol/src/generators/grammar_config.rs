@@ -82,6 +82,13 @@ pub struct GrammarConfig {
     /// Non-terminals that should be ignored during unreachable analysis.
     ///
     pub unreachable_non_terminals_to_ignore: Vec<String>,
+
+    ///
+    /// Non-terminals the parser can start with besides the start symbol, see `%entry`.
+    /// The input ends after the entry point. Thus each entry point is analyzed separately and the
+    /// analysis of the start symbol isn't affected.
+    ///
+    pub entry_points: Vec<String>,
 }
 
 /// The type of a scanner state resolver function.
@@ -131,6 +138,12 @@ impl GrammarConfig {
         self
     }
 
+    /// Sets the entry points
+    pub fn with_entry_points(mut self, entry_points: Vec<String>) -> Self {
+        self.entry_points = entry_points;
+        self
+    }
+
     /// Adds a scanner configuration
     pub fn add_scanner(mut self, scanner_config: ScannerConfig) -> Self {
         self.scanner_configurations.push(scanner_config);
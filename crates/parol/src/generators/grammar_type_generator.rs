@@ -156,12 +156,24 @@ impl GrammarTypeInfo {
         self.minimize_boxed_types = true;
     }
 
+    /// Sets the arena allocation flag
+    pub fn arena_allocation(&mut self) {
+        self.symbol_table.arena_allocation = true;
+    }
+
     /// Set the grammar type
     pub fn set_grammar_type(&mut self, grammar_type: GrammarType) {
         trace!("Setting grammar type to {grammar_type:?}");
         self.grammar_type = grammar_type;
     }
 
+    /// Returns true if the AST type, and thus the grammar trait and the user type, have the
+    /// lifetime of the input. The type information must have been built before.
+    pub fn ast_type_has_lifetime(&mut self) -> bool {
+        self.symbol_table.propagate_lifetimes();
+        self.symbol_table.has_lifetime(self.ast_enum_type)
+    }
+
     /// Add user actions
     pub fn add_user_actions(&mut self, grammar_config: &GrammarConfig) -> Result<()> {
         grammar_config
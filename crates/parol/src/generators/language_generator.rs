@@ -1,7 +1,8 @@
 use crate::{Cfg, Pr, Symbol, Terminal};
 use anyhow::{Result, anyhow};
 use parol_runtime::log::trace;
-use rand::RngExt;
+use rand::rngs::StdRng;
+use rand::{Rng, RngExt, SeedableRng};
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -41,17 +42,61 @@ impl<'a> LanguageGenerator<'a> {
 
     /// Generates a sentence
     pub fn generate(&mut self, max_result_length: Option<usize>) -> Result<String> {
+        self.generate_with(max_result_length, &mut rand::rng(), |rng, count| {
+            Some(rng.random_range(0..count))
+        })
+    }
+
+    /// Generates a sentence from the given production choices.
+    ///
+    /// Each expansion of a non-terminal consumes one choice that selects the production modulo the
+    /// number of productions of the non-terminal. When the choices are exhausted the generator
+    /// chooses the productions that end the sentence quickly.
+    /// The texts of the terminals are derived from the choices, too. Thus the same choices always
+    /// result in the same sentence, which makes this function suitable for structure-aware fuzzing.
+    pub fn generate_from_choices(
+        &mut self,
+        choices: &[u8],
+        max_result_length: Option<usize>,
+    ) -> Result<String> {
+        let seed = choices.iter().fold(14695981039346656037u64, |hash, b| {
+            hash.wrapping_mul(1099511628211).wrapping_add(u64::from(*b))
+        });
+        let mut choices = choices.iter();
+        self.generate_with(
+            max_result_length,
+            &mut StdRng::seed_from_u64(seed),
+            |_, count| choices.next().map(|c| *c as usize % count),
+        )
+    }
+
+    /// The function `choose` returns the index of the production to expand out of the given number
+    /// of productions, or None if the generation should terminate.
+    fn generate_with<R: Rng>(
+        &mut self,
+        max_result_length: Option<usize>,
+        rng: &mut R,
+        mut choose: impl FnMut(&mut R, usize) -> Option<usize>,
+    ) -> Result<String> {
         let mut result = String::new();
         let termination_threshold = max_result_length.unwrap_or(MAX_RESULT_SIZE) / 2;
         trace!("Try to terminate at result length {termination_threshold}");
-        self.process_non_terminal(self.cfg.get_start_symbol(), false)?;
+        self.generator_stack.clear();
+        let start_symbol = self.cfg.get_start_symbol();
+        let chosen_index = choose(rng, self.cfg.matching_productions(start_symbol).len());
+        self.process_non_terminal(start_symbol, chosen_index)?;
         while let Some(symbol) = self.generator_stack.pop() {
             match symbol {
                 Symbol::N(n, ..) => {
-                    self.process_non_terminal(&n, result.len() > termination_threshold)
+                    let chosen_index = if result.len() > termination_threshold {
+                        None
+                    } else {
+                        choose(rng, self.cfg.matching_productions(&n).len())
+                    };
+                    self.process_non_terminal(&n, chosen_index)
                 }
                 Symbol::T(Terminal::Trm(t, k, ..)) => {
-                    self.process_terminal(k.expand(&t), &mut result, max_result_length)
+                    self.process_terminal(k.expand(&t), &mut result, max_result_length, rng)
                 }
                 _ => Ok(()),
             }?
@@ -59,13 +104,15 @@ impl<'a> LanguageGenerator<'a> {
         Ok(result)
     }
 
-    fn process_non_terminal(&mut self, non_terminal: &str, terminate: bool) -> Result<()> {
+    fn process_non_terminal(
+        &mut self,
+        non_terminal: &str,
+        chosen_index: Option<usize>,
+    ) -> Result<()> {
         let productions_of_nt = self.cfg.matching_productions(non_terminal);
-        let chosen_index = if terminate {
-            Self::chose_minimal_expanding_production(&productions_of_nt)
-        } else {
-            rand::rng().random_range(0..productions_of_nt.len())
-        };
+        let terminate = chosen_index.is_none();
+        let chosen_index = chosen_index
+            .unwrap_or_else(|| Self::chose_minimal_expanding_production(&productions_of_nt));
         trace!(
             "/* {} */ {} {}/{} {}",
             productions_of_nt[chosen_index].0,
@@ -83,13 +130,13 @@ impl<'a> LanguageGenerator<'a> {
         Ok(())
     }
 
-    fn process_terminal(
+    fn process_terminal<R: Rng>(
         &mut self,
         terminal: String,
         result: &mut String,
         max_result_length: Option<usize>,
+        rng: &mut R,
     ) -> Result<()> {
-        let mut rng = rand::rng();
         let utf8_gen = self.get_regex(terminal)?;
         let generated: String = rng.sample(utf8_gen);
         trace!("gen: {generated}");
@@ -159,3 +206,43 @@ impl<'a> LanguageGenerator<'a> {
             .unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::LanguageGenerator;
+    use crate::obtain_grammar_config_from_string;
+
+    const GRAMMAR: &str = r#"%start List
+%%
+List: Item { "," Item };
+Item: "\(" List "\)" | /[0-9]+/;
+"#;
+
+    #[test]
+    fn same_choices_generate_same_sentence() {
+        let grammar_config = obtain_grammar_config_from_string(GRAMMAR, false).unwrap();
+        let choices = [1, 0, 3, 0, 7, 1, 1, 0, 2];
+        let sentence = LanguageGenerator::new(&grammar_config.cfg)
+            .generate_from_choices(&choices, None)
+            .unwrap();
+        let mut generator = LanguageGenerator::new(&grammar_config.cfg);
+        for _ in 0..3 {
+            assert_eq!(
+                sentence,
+                generator.generate_from_choices(&choices, None).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn exhausted_choices_terminate_the_sentence() {
+        let grammar_config = obtain_grammar_config_from_string(GRAMMAR, false).unwrap();
+        let mut generator = LanguageGenerator::new(&grammar_config.cfg);
+        let sentence = generator.generate_from_choices(&[], None).unwrap();
+        assert!(!sentence.contains(['(', ',']), "{sentence}");
+        // Deeply nested sentences are either terminated in time or rejected
+        if let Ok(sentence) = generator.generate_from_choices(&[0; 4096], Some(200)) {
+            assert!(sentence.len() <= 200, "{sentence}");
+        }
+    }
+}
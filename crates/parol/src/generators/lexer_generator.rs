@@ -1,10 +1,12 @@
 use crate::generators::{GrammarConfig, NamingHelper, generate_terminal_name};
 use crate::generators::lexer_ir::{build_scanner_mode_data, ScannerModeBuildData};
 use crate::{CommonGeneratorConfig, generate_name};
-use anyhow::Result;
+use anyhow::{Result, bail};
 use parol_runtime::TerminalIndex;
+use parol_runtime::lexer::{FIRST_USER_TOKEN, TERMINAL_MODE_PREFIX};
 
 use crate::StrVec;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 
 #[derive(Debug, Default)]
@@ -64,7 +66,13 @@ pub(crate) fn generate_lexer_source_with_terminal_names<C: CommonGeneratorConfig
     let terminal_count = terminal_names.len();
     let width = (terminal_count as f32).log10() as usize + 1;
 
-    let mode_data = build_scanner_mode_data(grammar_config, terminal_names)?;
+    let mut mode_data = build_scanner_mode_data(grammar_config, terminal_names)?;
+    if config.byte_input() {
+        check_byte_input_terminals(&mode_data)?;
+    }
+    if config.contextual_lexing() {
+        mode_data.extend(build_terminal_mode_data(&mode_data));
+    }
 
     let macro_start =
         StrVec::from_iter(vec![format!("\n    {} {{", get_scanner_type_name(config))]);
@@ -95,6 +103,44 @@ pub(crate) fn generate_lexer_source_with_terminal_names<C: CommonGeneratorConfig
     Ok(format!("{lexer_data}"))
 }
 
+///
+/// Checks that the terminals can match byte input, where each byte is scanned as the character
+/// with the same code point. Characters beyond `\u{FF}` can't occur in such input.
+///
+fn check_byte_input_terminals(mode_data: &[ScannerModeBuildData]) -> Result<()> {
+    if let Some((_, _, _, tn)) = mode_data
+        .iter()
+        .flat_map(|m| m.terminal_mappings.iter())
+        .find(|(rx, _, _, _)| rx.chars().any(|c| c > '\u{FF}'))
+    {
+        bail!("Terminal {tn} contains characters beyond \\u{{FF}} that can't occur in byte input");
+    }
+    Ok(())
+}
+
+///
+/// Builds one scanner mode for each user terminal that only matches this terminal.
+/// With contextual lexing the scanner uses these modes to match only the terminals the parser
+/// accepts, regardless of the terminals that would shadow them in the current scanner state.
+///
+fn build_terminal_mode_data(mode_data: &[ScannerModeBuildData]) -> Vec<ScannerModeBuildData> {
+    mode_data
+        .iter()
+        .flat_map(|m| m.terminal_mappings.iter())
+        .filter(|(_, i, _, _)| *i >= FIRST_USER_TOKEN)
+        .fold(BTreeMap::new(), |mut acc, mapping| {
+            acc.entry(mapping.1).or_insert_with(|| mapping.clone());
+            acc
+        })
+        .into_iter()
+        .map(|(i, mapping)| ScannerModeBuildData {
+            scanner_name: format!("{TERMINAL_MODE_PREFIX}{i}"),
+            terminal_mappings: vec![mapping],
+            transitions: Vec::new(),
+        })
+        .collect()
+}
+
 /// Generates all terminal names of a given grammar
 pub fn generate_terminal_names(grammar_config: &GrammarConfig) -> Vec<String> {
     grammar_config
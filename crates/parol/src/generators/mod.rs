@@ -78,6 +78,10 @@ pub use terminal_name_generator::generate_terminal_name;
 /// Module with the node kind enum generator
 pub mod node_kind_enum_generator;
 
+/// Module with the tokenizer generator of the lexer-only mode
+pub mod tokenizer_generator;
+pub use tokenizer_generator::generate_tokenizer_source;
+
 /// Module with the node types exporter
 pub mod export_node_types;
 
@@ -37,20 +37,11 @@ impl NodeKindTypesGenerator<'_> {
         non_terminal.push(String::default());
         non_terminal.push("All possible non-terminal kinds".to_string());
         non_terminal.push(String::default());
-        let mut terminal = StrVec::new(0);
-        terminal.push(String::default());
-        terminal.push("All possible terminal kinds".to_string());
-        terminal.push(String::default());
         let non_terminal_enum = grammar_type_info
             .generate_non_terminal_enum_type()
             .into_iter()
             .map(|NonTerminalEnumType { name, .. }| format!("{}", ume::ume!(#name,)))
             .collect::<StrVec>();
-        let terminal_enum = self
-            .terminals
-            .iter()
-            .map(|(_, name)| format!("{}", ume::ume!(#name,)))
-            .into_str_iter();
         f.write_fmt(ume::ume! {
             #[allow(dead_code)]
             #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -59,15 +50,7 @@ impl NodeKindTypesGenerator<'_> {
             }
         })?;
 
-        f.write_fmt(ume::ume! {
-            #[allow(dead_code)]
-            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-            pub enum TerminalKind {
-                #terminal_enum
-            }
-        })?;
-
-        Ok(())
+        generate_terminal_kind_enum(&self.terminals, f)
     }
 
     /// Generate the implementation of the AST enum.
@@ -76,15 +59,6 @@ impl NodeKindTypesGenerator<'_> {
             grammar_type_info, ..
         } = self;
 
-        let num_to_terminal_match_arms = self
-            .terminals
-            .iter()
-            .map(|(i, t)| NumToTerminalVariant {
-                variant: t.to_string(),
-                prod_num: *i,
-            })
-            .into_str_iter();
-
         let non_terminal_match_arms = grammar_type_info
             .generate_non_terminal_enum_type()
             .into_iter()
@@ -100,37 +74,7 @@ impl NodeKindTypesGenerator<'_> {
             )
             .into_str_iter();
 
-        f.write_fmt(ume::ume! {
-            #[allow(dead_code)]
-            impl TerminalKind {
-                pub fn from_terminal_index(index: u16) -> Self {
-                    match index {
-                        #num_to_terminal_match_arms
-                        _ => panic!("Invalid terminal index: {}", index),
-                    }
-                }
-
-                pub fn is_builtin_terminal(&self) -> bool {
-                    matches!(self, TerminalKind::NewLine | TerminalKind::Whitespace | TerminalKind::LineComment | TerminalKind::BlockComment)
-                }
-
-                pub fn is_builtin_new_line(&self) -> bool {
-                    matches!(self, TerminalKind::NewLine)
-                }
-
-                pub fn is_builtin_whitespace(&self) -> bool {
-                    matches!(self, TerminalKind::Whitespace)
-                }
-
-                pub fn is_builtin_line_comment(&self) -> bool {
-                    matches!(self, TerminalKind::LineComment)
-                }
-
-                pub fn is_builtin_block_comment(&self) -> bool {
-                    matches!(self, TerminalKind::BlockComment)
-                }
-            }
-        })?;
+        generate_terminal_kind_impl(&self.terminals, f)?;
 
         write!(f, "\n\n")?;
 
@@ -154,15 +98,6 @@ impl NodeKindTypesGenerator<'_> {
             grammar_type_info, ..
         } = self;
 
-        let terminal_arms = self
-            .terminals
-            .iter()
-            .map(|(_i, t)| DisplayArm {
-                variant: t,
-                value: t,
-            })
-            .into_str_iter();
-
         let non_terminals = grammar_type_info.generate_non_terminal_enum_type();
         let non_terminal_arms = non_terminals
             .iter()
@@ -172,15 +107,7 @@ impl NodeKindTypesGenerator<'_> {
             })
             .into_str_iter();
 
-        f.write_fmt(ume::ume! {
-            impl std::fmt::Display for TerminalKind {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    match self {
-                        #terminal_arms
-                    }
-                }
-            }
-        })?;
+        generate_terminal_kind_display(&self.terminals, f)?;
 
         write!(f, "\n\n")?;
 
@@ -204,3 +131,94 @@ impl NodeKindTypesGenerator<'_> {
         Ok(())
     }
 }
+
+/// Generates the enum of all terminal kinds.
+pub(crate) fn generate_terminal_kind_enum(
+    terminals: &[(usize, String)],
+    f: &mut impl Write,
+) -> anyhow::Result<()> {
+    let terminal_enum = terminals
+        .iter()
+        .map(|(_, name)| format!("{}", ume::ume!(#name,)))
+        .into_str_iter();
+    f.write_fmt(ume::ume! {
+        #[allow(dead_code)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum TerminalKind {
+            #terminal_enum
+        }
+    })?;
+    Ok(())
+}
+
+/// Generates the conversion from terminal indices and the predicates of the terminal kinds.
+pub(crate) fn generate_terminal_kind_impl(
+    terminals: &[(usize, String)],
+    f: &mut impl Write,
+) -> anyhow::Result<()> {
+    let num_to_terminal_match_arms = terminals
+        .iter()
+        .map(|(i, t)| NumToTerminalVariant {
+            variant: t.to_string(),
+            prod_num: *i,
+        })
+        .into_str_iter();
+
+    f.write_fmt(ume::ume! {
+        #[allow(dead_code)]
+        impl TerminalKind {
+            pub fn from_terminal_index(index: u16) -> Self {
+                match index {
+                    #num_to_terminal_match_arms
+                    _ => panic!("Invalid terminal index: {}", index),
+                }
+            }
+
+            pub fn is_builtin_terminal(&self) -> bool {
+                matches!(self, TerminalKind::NewLine | TerminalKind::Whitespace | TerminalKind::LineComment | TerminalKind::BlockComment)
+            }
+
+            pub fn is_builtin_new_line(&self) -> bool {
+                matches!(self, TerminalKind::NewLine)
+            }
+
+            pub fn is_builtin_whitespace(&self) -> bool {
+                matches!(self, TerminalKind::Whitespace)
+            }
+
+            pub fn is_builtin_line_comment(&self) -> bool {
+                matches!(self, TerminalKind::LineComment)
+            }
+
+            pub fn is_builtin_block_comment(&self) -> bool {
+                matches!(self, TerminalKind::BlockComment)
+            }
+        }
+    })?;
+    Ok(())
+}
+
+/// Generates the Display implementation of the terminal kinds.
+pub(crate) fn generate_terminal_kind_display(
+    terminals: &[(usize, String)],
+    f: &mut impl Write,
+) -> anyhow::Result<()> {
+    let terminal_arms = terminals
+        .iter()
+        .map(|(_i, t)| DisplayArm {
+            variant: t,
+            value: t,
+        })
+        .into_str_iter();
+
+    f.write_fmt(ume::ume! {
+        impl std::fmt::Display for TerminalKind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #terminal_arms
+                }
+            }
+        }
+    })?;
+    Ok(())
+}
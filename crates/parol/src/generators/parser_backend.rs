@@ -47,6 +47,34 @@ where
     C: CommonGeneratorConfig + ParserGeneratorConfig,
 {
     fn generate_parser_source(&self, parser_ir: &ParserGenerationIR<'_, C>) -> Result<String> {
+        if !parser_ir.grammar_config.entry_points.is_empty() {
+            return Err(anyhow!("Entry points are only supported for Rust parsers"));
+        }
+        if !parser_ir
+            .grammar_config
+            .cfg
+            .get_predicate_names()
+            .is_empty()
+        {
+            return Err(anyhow!(
+                "Semantic predicates are only supported for Rust parsers"
+            ));
+        }
+        if parser_ir.config.max_errors().is_some() || parser_ir.config.custom_recovery() {
+            return Err(anyhow!(
+                "Recovery strategies and error limits are only supported for Rust parsers"
+            ));
+        }
+        if parser_ir.config.step_budget().is_some() || parser_ir.config.cancellable() {
+            return Err(anyhow!(
+                "Step budgets and cancellation are only supported for Rust parsers"
+            ));
+        }
+        if parser_ir.config.arena_allocation() {
+            return Err(anyhow!(
+                "Arena allocation is only supported for Rust parsers"
+            ));
+        }
         let ast_type_has_lifetime = parser_ir.common.ast_type_has_lifetime;
         match parser_ir.algorithm {
             ParserAlgorithmIR::Llk(lookahead_dfas) => {
@@ -95,12 +123,13 @@ mod tests {
     use super::*;
     use crate::config::{CommonGeneratorConfig, ParserGeneratorConfig, UserTraitGeneratorConfig};
     use crate::generators::lexer_generator;
-    use crate::generators::{GrammarTypeInfo, UserTraitGenerator};
-    use crate::utils::obtain_grammar_config;
+    use crate::generators::{GrammarConfig, GrammarTypeInfo, UserTraitGenerator};
+    use crate::utils::{obtain_grammar_config, obtain_grammar_config_from_string};
     use crate::{InnerAttributes, calculate_lookahead_dfas};
+    use parol_runtime::lexer::ColumnUnit;
     use std::path::PathBuf;
 
-    const RUST_PARSER_OUTPUT_CHECKSUM: u64 = 2615522609648179731;
+    const RUST_PARSER_OUTPUT_CHECKSUM: u64 = 14173315477280396579;
     const CSHARP_PARSER_OUTPUT_CHECKSUM: u64 = 330885139893830924;
 
     #[derive(Debug)]
@@ -187,6 +216,93 @@ mod tests {
         }
     }
 
+    #[derive(Debug, Default)]
+    struct FeatureTestConfig {
+        contextual_lexing: bool,
+        byte_input: bool,
+        column_unit: ColumnUnit,
+        token_input: bool,
+        max_errors: Option<usize>,
+        custom_recovery: bool,
+        step_budget: Option<usize>,
+        cancellable: bool,
+        arena_allocation: bool,
+    }
+
+    impl CommonGeneratorConfig for FeatureTestConfig {
+        fn user_type_name(&self) -> &str {
+            "BackendTest"
+        }
+
+        fn module_name(&self) -> &str {
+            "backend_test"
+        }
+
+        fn minimize_boxed_types(&self) -> bool {
+            false
+        }
+
+        fn range(&self) -> bool {
+            false
+        }
+
+        fn node_kind_enums(&self) -> bool {
+            false
+        }
+
+        fn contextual_lexing(&self) -> bool {
+            self.contextual_lexing
+        }
+
+        fn byte_input(&self) -> bool {
+            self.byte_input
+        }
+
+        fn column_unit(&self) -> ColumnUnit {
+            self.column_unit
+        }
+
+        fn arena_allocation(&self) -> bool {
+            self.arena_allocation
+        }
+    }
+
+    impl ParserGeneratorConfig for FeatureTestConfig {
+        fn trim_parse_tree(&self) -> bool {
+            false
+        }
+
+        fn recovery_disabled(&self) -> bool {
+            false
+        }
+
+        fn token_input(&self) -> bool {
+            self.token_input
+        }
+
+        fn max_errors(&self) -> Option<usize> {
+            self.max_errors
+        }
+
+        fn custom_recovery(&self) -> bool {
+            self.custom_recovery
+        }
+
+        fn step_budget(&self) -> Option<usize> {
+            self.step_budget
+        }
+
+        fn cancellable(&self) -> bool {
+            self.cancellable
+        }
+    }
+
+    impl UserTraitGeneratorConfig for FeatureTestConfig {
+        fn inner_attributes(&self) -> &[InnerAttributes] {
+            &[]
+        }
+    }
+
     impl UserTraitGeneratorConfig for TestConfig {
         fn inner_attributes(&self) -> &[InnerAttributes] {
             &[]
@@ -389,4 +505,303 @@ mod tests {
         let source = generate_parser_source_for_language(&RustParserBackend, &parser_ir).unwrap();
         assert!(source.contains("lr_parser.set_max_parsing_depth(42);"));
     }
+
+    /// Generates the LL(k) and the LALR(1) parser sources without whitespace
+    fn generate_feature_sources(config: &FeatureTestConfig) -> Vec<String> {
+        let grammar_config = obtain_grammar_config(test_grammar_path(), false).unwrap();
+        generate_feature_sources_for(grammar_config, config)
+    }
+
+    /// Generates the LL(k) and the LALR(1) parser sources of the given grammar without whitespace
+    fn generate_feature_sources_for(
+        grammar_config: GrammarConfig,
+        config: &FeatureTestConfig,
+    ) -> Vec<String> {
+        let lexer_source = lexer_generator::generate_lexer_source(&grammar_config, config).unwrap();
+        let lookahead_dfas = calculate_lookahead_dfas(&grammar_config, 5).unwrap();
+        let parse_table = crate::calculate_lalr1_parse_table(&grammar_config)
+            .unwrap()
+            .0;
+
+        let mut type_info = GrammarTypeInfo::try_new(config.user_type_name()).unwrap();
+        UserTraitGenerator::new(&grammar_config)
+            .generate_user_trait_source(config, grammar_config.grammar_type, &mut type_info)
+            .unwrap();
+
+        [
+            ParserAlgorithmIR::Llk(&lookahead_dfas),
+            ParserAlgorithmIR::Lalr1(&parse_table),
+        ]
+        .into_iter()
+        .map(|algorithm| {
+            let parser_ir = ParserGenerationIR::new(
+                &grammar_config,
+                &lexer_source,
+                config,
+                type_info.symbol_table.has_lifetime(type_info.ast_enum_type),
+                algorithm,
+            )
+            .unwrap();
+            generate_parser_source_for_language(&RustParserBackend, &parser_ir)
+                .unwrap()
+                .split_whitespace()
+                .collect()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_contextual_lexing_option() {
+        let config = FeatureTestConfig {
+            contextual_lexing: true,
+            ..Default::default()
+        };
+        for source in generate_feature_sources(&config) {
+            assert!(source.contains("TokenStream::new_with_options("));
+            assert!(source.contains("contextual_lexing:true"));
+            assert!(source.contains("mode__TERMINAL_"));
+        }
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_parse_bytes() {
+        let config = FeatureTestConfig {
+            byte_input: true,
+            ..Default::default()
+        };
+        for source in generate_feature_sources(&config) {
+            assert!(source.contains("byte_input:true"));
+            assert!(
+                source.contains(
+                    "pubfnparse_bytes<'t,T>(input:&'tparol_runtime::lexer::ByteInput<'_>,"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_column_unit() {
+        let config = FeatureTestConfig {
+            column_unit: ColumnUnit::Utf16,
+            ..Default::default()
+        };
+        for source in generate_feature_sources(&config) {
+            assert!(source.contains("TokenStream::new_with_options("));
+            assert!(source.contains("column_unit:parol_runtime::lexer::ColumnUnit::Utf16"));
+        }
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_parse_tokens() {
+        let config = FeatureTestConfig {
+            token_input: true,
+            ..Default::default()
+        };
+        for source in generate_feature_sources(&config) {
+            assert!(source.contains("pubfnparse_tokens<'t,I>(tokens:I,"));
+            assert!(source.contains("_parser.parse_tokens(tokens,&mutuser_actions)"));
+        }
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_recovery_options() {
+        let config = FeatureTestConfig {
+            max_errors: Some(5),
+            custom_recovery: true,
+            ..Default::default()
+        };
+        let grammar_config = obtain_grammar_config(test_grammar_path(), false).unwrap();
+        let lexer_source =
+            lexer_generator::generate_lexer_source(&grammar_config, &config).unwrap();
+        let lookahead_dfas = calculate_lookahead_dfas(&grammar_config, 5).unwrap();
+        let parse_table = crate::calculate_lalr1_parse_table(&grammar_config)
+            .unwrap()
+            .0;
+        let mut type_info = GrammarTypeInfo::try_new(config.user_type_name()).unwrap();
+        UserTraitGenerator::new(&grammar_config)
+            .generate_user_trait_source(&config, grammar_config.grammar_type, &mut type_info)
+            .unwrap();
+        let has_lifetime = type_info.symbol_table.has_lifetime(type_info.ast_enum_type);
+
+        let parser_ir = ParserGenerationIR::new(
+            &grammar_config,
+            &lexer_source,
+            &config,
+            has_lifetime,
+            ParserAlgorithmIR::Llk(&lookahead_dfas),
+        )
+        .unwrap();
+        let source: String = generate_parser_source_for_language(&RustParserBackend, &parser_ir)
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        assert!(source.contains("llk_parser.set_max_errors(5);"));
+        assert!(source.contains("pubfnparse_with_recovery<'t,T>(input:&'tstr,"));
+        assert!(source.contains("llk_parser.set_recovery_strategy(recovery_strategy);"));
+
+        // LALR(1) parsers don't recover from syntax errors
+        let parser_ir = ParserGenerationIR::new(
+            &grammar_config,
+            &lexer_source,
+            &config,
+            has_lifetime,
+            ParserAlgorithmIR::Lalr1(&parse_table),
+        )
+        .unwrap();
+        assert!(generate_parser_source_for_language(&RustParserBackend, &parser_ir).is_err());
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_step_budget_and_cancellation() {
+        let config = FeatureTestConfig {
+            step_budget: Some(7),
+            cancellable: true,
+            ..Default::default()
+        };
+        let grammar_config = obtain_grammar_config(test_grammar_path(), false).unwrap();
+        let lexer_source =
+            lexer_generator::generate_lexer_source(&grammar_config, &config).unwrap();
+        let lookahead_dfas = calculate_lookahead_dfas(&grammar_config, 5).unwrap();
+        let parse_table = crate::calculate_lalr1_parse_table(&grammar_config)
+            .unwrap()
+            .0;
+        let mut type_info = GrammarTypeInfo::try_new(config.user_type_name()).unwrap();
+        UserTraitGenerator::new(&grammar_config)
+            .generate_user_trait_source(&config, grammar_config.grammar_type, &mut type_info)
+            .unwrap();
+        let has_lifetime = type_info.symbol_table.has_lifetime(type_info.ast_enum_type);
+
+        for (algorithm, parser_name) in [
+            (ParserAlgorithmIR::Llk(&lookahead_dfas), "llk_parser"),
+            (ParserAlgorithmIR::Lalr1(&parse_table), "lr_parser"),
+        ] {
+            let parser_ir = ParserGenerationIR::new(
+                &grammar_config,
+                &lexer_source,
+                &config,
+                has_lifetime,
+                algorithm,
+            )
+            .unwrap();
+            let source: String =
+                generate_parser_source_for_language(&RustParserBackend, &parser_ir)
+                    .unwrap()
+                    .split_whitespace()
+                    .collect();
+            assert!(source.contains(&format!("{parser_name}.set_step_budget(7);")));
+            assert!(source.contains("pubfnparse_cancellable<'t,T>(input:&'tstr,"));
+            assert!(source.contains(&format!(
+                "{parser_name}.set_cancellation_token(cancellation_token);"
+            )));
+        }
+    }
+
+    #[test]
+    fn rust_generation_emits_arena_allocation() {
+        let config = FeatureTestConfig {
+            arena_allocation: true,
+            ..Default::default()
+        };
+        let grammar_config = obtain_grammar_config(test_grammar_path(), false).unwrap();
+        let mut type_info = GrammarTypeInfo::try_new(config.user_type_name()).unwrap();
+        let trait_source: String = UserTraitGenerator::new(&grammar_config)
+            .generate_user_trait_source(&config, grammar_config.grammar_type, &mut type_info)
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        assert!(trait_source.contains("pubstart_list:&'t[StartList<'t>],"));
+        assert!(
+            trait_source.contains("start_list:&*self.arena.alloc_slice_fill_iter(start_list),")
+        );
+        assert!(trait_source.contains("arena:&'tparol_runtime::arena::Bump,"));
+
+        for source in generate_feature_sources(&config) {
+            assert!(
+                source.contains(
+                    "user_actions:&mutBackendTest<'t>,arena:&'tparol_runtime::arena::Bump,"
+                )
+            );
+            assert!(source.contains("parse_into(input,&mutbuilder,file_name,user_actions,arena)"));
+            assert!(source.contains("BackendTestAuto::new(user_actions,arena);"));
+        }
+    }
+
+    #[test]
+    fn rust_parser_generation_emits_entry_points() {
+        let grammar_config = obtain_grammar_config(test_grammar_path(), false)
+            .unwrap()
+            .with_entry_points(vec!["StartList".to_string()]);
+        let sources = generate_feature_sources_for(grammar_config, &FeatureTestConfig::default());
+        assert!(sources[0].contains("pubfnparse_start_list<'t,T>(input:&'tstr,"));
+        assert!(sources[0].contains("pubconstLOOKAHEAD_AUTOMATA_START_LIST:&[LookaheadDFA;2]"));
+        assert!(sources[0].contains("LLKParser::new(1,LOOKAHEAD_AUTOMATA_START_LIST,"));
+        assert!(sources[1].contains("pubfnparse_start_list<'t,T>(input:&'tstr,"));
+        assert!(sources[1].contains("staticPARSE_TABLE_START_LIST:LRParseTable"));
+        assert!(sources[1].contains("LRParser::new_for_entry_point(1,&PARSE_TABLE_START_LIST,"));
+    }
+
+    #[test]
+    fn csharp_parser_generation_rejects_entry_points() {
+        let grammar_config = obtain_grammar_config(test_grammar_path(), false)
+            .unwrap()
+            .with_entry_points(vec!["StartList".to_string()]);
+        let config = FeatureTestConfig::default();
+        let lexer_source =
+            lexer_generator::generate_lexer_source(&grammar_config, &config).unwrap();
+        let lookahead_dfas = calculate_lookahead_dfas(&grammar_config, 5).unwrap();
+        let parser_ir = ParserGenerationIR::new(
+            &grammar_config,
+            &lexer_source,
+            &config,
+            false,
+            ParserAlgorithmIR::Llk(&lookahead_dfas),
+        )
+        .unwrap();
+        assert!(generate_parser_source_for_language(&CSharpParserBackend, &parser_ir).is_err());
+    }
+
+    const PREDICATED_GRAMMAR: &str = r#"%start Stmt
+%%
+Stmt: &{is_type_name} Name '*' Name ';' | Name '*' Name ';';
+Name: /[a-z]+/;
+"#;
+
+    #[test]
+    fn rust_parser_generation_emits_semantic_predicates() {
+        let grammar_config = obtain_grammar_config_from_string(PREDICATED_GRAMMAR, false).unwrap();
+        let config = FeatureTestConfig::default();
+        let mut type_info = GrammarTypeInfo::try_new(config.user_type_name()).unwrap();
+        let trait_source: String = UserTraitGenerator::new(&grammar_config)
+            .generate_user_trait_source(&config, grammar_config.grammar_type, &mut type_info)
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        assert!(
+            trait_source.contains("fnis_type_name(&mutself,_lookahead:&Token<'t>)->Result<bool>")
+        );
+        assert!(trait_source.contains("0=>self.user_grammar.is_type_name(lookahead),"));
+
+        let lexer_source =
+            lexer_generator::generate_lexer_source(&grammar_config, &config).unwrap();
+        let lookahead_dfas = calculate_lookahead_dfas(&grammar_config, 5).unwrap();
+        let parser_ir = ParserGenerationIR::new(
+            &grammar_config,
+            &lexer_source,
+            &config,
+            type_info.symbol_table.has_lifetime(type_info.ast_enum_type),
+            ParserAlgorithmIR::Llk(&lookahead_dfas),
+        )
+        .unwrap();
+        let source: String = generate_parser_source_for_language(&RustParserBackend, &parser_ir)
+            .unwrap()
+            .split_whitespace()
+            .collect();
+        assert!(source.contains("pubconstPREDICATED_DECISIONS:&[PredicatedDecision;1]"));
+        assert!(source.contains(
+            "predicates:&[(0,0),],fallback:LookaheadDFA{prod0:-1,transitions:&[Trans(0,7,1,1),],k:1,}"
+        ));
+        assert!(source.contains("llk_parser.set_predicated_decisions(PREDICATED_DECISIONS);"));
+
+        assert!(generate_parser_source_for_language(&CSharpParserBackend, &parser_ir).is_err());
+    }
 }
@@ -3,6 +3,7 @@ use crate::analysis::LookaheadDFA;
 use crate::analysis::compiled_la_dfa::CompiledDFA;
 use crate::analysis::lookahead_dfa::CompiledProductionIndex;
 use crate::config::{CommonGeneratorConfig, ParserGeneratorConfig};
+use crate::generators::lexer_generator::generate_terminal_names;
 use crate::generators::parser_model::{
     LookaheadAutomatonModel as LookaheadAutomatonIR, ProductionModel as ProductionIR,
     ProductionSymbolModel as ProductionSymbolIR, build_export_model_for_lalr,
@@ -16,8 +17,10 @@ use crate::generators::parser_render_ir::{
 use crate::generators::{GrammarConfig, NamingHelper};
 use crate::parser::GrammarType;
 use crate::parser::parol_grammar::LookaheadExpression;
-use anyhow::Result;
-use std::collections::BTreeMap;
+use anyhow::{Result, bail};
+use parol_runtime::TerminalIndex;
+use parol_runtime::lexer::ColumnUnit;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::StrVec;
 use std::fmt::Debug;
@@ -31,7 +34,7 @@ pub(crate) struct Dfa {
     nt_name: String,
 }
 
-fn generate_skip_tokens_by_state_source(grammar_config: &GrammarConfig) -> String {
+pub(crate) fn generate_skip_tokens_by_state_source(grammar_config: &GrammarConfig) -> String {
     let scanner_state_count = grammar_config.scanner_configurations.len();
     let rows = grammar_config
         .scanner_configurations
@@ -52,6 +55,518 @@ fn generate_skip_tokens_by_state_source(grammar_config: &GrammarConfig) -> Strin
         "pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; {scanner_state_count}] = &[\n{rows}\n];"
     )
 }
+
+/// Generates the delimiters of nested block comments per scanner state.
+/// Returns None if no scanner state has nested block comments.
+pub(crate) fn generate_nested_block_comments_by_state_source(
+    grammar_config: &GrammarConfig,
+) -> Result<Option<String>> {
+    if grammar_config
+        .scanner_configurations
+        .iter()
+        .all(|sc| sc.nested_block_comments.is_empty())
+    {
+        return Ok(None);
+    }
+    let scanner_state_count = grammar_config.scanner_configurations.len();
+    let rows = grammar_config
+        .scanner_configurations
+        .iter()
+        .map(|sc| {
+            let delimiters = sc
+                .nested_block_comment_delimiters()?
+                .iter()
+                .map(|(s, e)| format!("({s:?}, {e:?})"))
+                .collect::<Vec<String>>()
+                .join(", ");
+            Ok(format!("    &[{delimiters}],"))
+        })
+        .collect::<Result<Vec<String>>>()?
+        .join("\n");
+
+    Ok(Some(format!(
+        "pub const NESTED_BLOCK_COMMENTS_BY_SCANNER_STATE: &[&[(&str, &str)]; {scanner_state_count}] = &[\n{rows}\n];"
+    )))
+}
+
+/// Generates the terminals synthesized by the offside rule per scanner state.
+/// Returns None if no scanner state uses the offside rule.
+pub(crate) fn generate_offside_tokens_by_state_source(
+    grammar_config: &GrammarConfig,
+) -> Option<String> {
+    if grammar_config
+        .scanner_configurations
+        .iter()
+        .all(|sc| sc.offside_tokens.is_none())
+    {
+        return None;
+    }
+    let scanner_state_count = grammar_config.scanner_configurations.len();
+    let rows = grammar_config
+        .scanner_configurations
+        .iter()
+        .map(|sc| match sc.offside_tokens {
+            Some(o) => format!(
+                "    Some(parol_runtime::lexer::OffsideTokens {{ indent: {}, dedent: {}, newline: {} }}),",
+                o.indent, o.dedent, o.newline
+            ),
+            None => "    None,".to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Some(format!(
+        "pub const OFFSIDE_TOKENS_BY_SCANNER_STATE: &[Option<parol_runtime::lexer::OffsideTokens>; {scanner_state_count}] = &[\n{rows}\n];"
+    ))
+}
+
+/// Generates the terminals that are scanned by the user's external scanner per scanner state,
+/// the trait the user implements and the adapter to the runtime's external scanner.
+/// Returns None if no scanner state declares external terminals.
+fn generate_external_scanner_source(
+    grammar_config: &GrammarConfig,
+    user_type_name: &str,
+) -> Option<(String, String)> {
+    if grammar_config
+        .scanner_configurations
+        .iter()
+        .all(|sc| sc.external_tokens.is_empty())
+    {
+        return None;
+    }
+    let scanner_state_count = grammar_config.scanner_configurations.len();
+    let rows = grammar_config
+        .scanner_configurations
+        .iter()
+        .map(|sc| {
+            format!(
+                "    &[{}], /* {} */",
+                sc.external_tokens
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                sc.scanner_name
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let terminal_names = generate_terminal_names(grammar_config);
+    let external_tokens = grammar_config
+        .scanner_configurations
+        .iter()
+        .flat_map(|sc| sc.external_tokens.iter().copied())
+        .collect::<BTreeSet<TerminalIndex>>();
+    let scanner_methods = external_tokens
+        .iter()
+        .map(|t| {
+            let terminal_name = &terminal_names[*t as usize];
+            (
+                *t,
+                terminal_name,
+                NamingHelper::escape_rust_keyword(NamingHelper::to_lower_snake_case(terminal_name)),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let trait_name = format!("{user_type_name}ExternalScanner");
+    let trait_methods = scanner_methods
+        .iter()
+        .map(|(_, terminal_name, method_name)| {
+            format!(
+                "    /// Returns the length in bytes of the terminal {terminal_name} at the start of the\n    /// input or None if it doesn't start there.\n    fn {method_name}(&mut self, input: &str, scanner_state: usize) -> Option<usize>;"
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let match_arms = scanner_methods
+        .iter()
+        .map(|(t, _, method_name)| {
+            format!("            {t} => self.0.{method_name}(input, scanner_state),")
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let source = format!(
+        r#"pub const EXTERNAL_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; {scanner_state_count}] = &[
+{rows}
+];
+
+///
+/// The scanner of the terminals that are declared with `%external` in the grammar.
+/// It is asked for its terminals at every token start in the scanner states that declare them,
+/// before the generated scanner.
+///
+pub trait {trait_name} {{
+{trait_methods}
+}}
+
+struct ExternalScannerAdapter<S>(S);
+
+impl<S: {trait_name}> parol_runtime::lexer::ExternalScanner for ExternalScannerAdapter<S> {{
+    fn scan(
+        &mut self,
+        terminal: parol_runtime::TerminalIndex,
+        input: &str,
+        scanner_state: usize,
+    ) -> Option<usize> {{
+        match terminal {{
+{match_arms}
+            _ => None,
+        }}
+    }}
+}}"#
+    );
+    Some((source, trait_name))
+}
+
+/// Generates the function that parses byte input by scanning its Latin-1 decoded text
+fn generate_parse_bytes_source(user_actions_params: &str, user_actions_args: &str) -> String {
+    format!(
+        r#"
+
+///
+/// Parses the given bytes. Each byte is scanned as the character with the same code point and
+/// the locations of the tokens report byte offsets of the input.
+/// Use `Token::bytes` to obtain the bytes of a token.
+///
+#[allow(dead_code)]
+pub fn parse_bytes<'t, T>(
+    input: &'t parol_runtime::lexer::ByteInput<'_>,
+    file_name: T,
+    user_actions: {user_actions_params},
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{{
+    parse(input.as_str(), file_name, {user_actions_args})
+}}
+"#
+    )
+}
+
+/// Generates the function that parses tokens supplied by the user instead of scanning an input
+fn generate_parse_tokens_source(
+    user_actions: &str,
+    auto_wrapper: &str,
+    parser_setup: &str,
+    parser_name: &str,
+) -> String {
+    format!(
+        r#"
+
+///
+/// Parses the given tokens instead of scanning an input text, e.g. the output of a preprocessor.
+/// The token types are the indices into `TERMINAL_NAMES`. The locations of the tokens are kept,
+/// so they may point into different files.
+///
+#[allow(dead_code)]
+pub fn parse_tokens<'t, I>(
+    tokens: I,
+    user_actions: {user_actions},
+) -> Result<ParseTree, ParolError>
+where
+    I: IntoIterator<Item = parol_runtime::Token<'t>>,
+    I::IntoIter: 't,
+{{
+{parser_setup}{auto_wrapper}
+    {parser_name}.parse_tokens(tokens, &mut user_actions)
+}}
+"#
+    )
+}
+
+/// An additional parse function of the generated parser that starts with an entry point instead of
+/// the start symbol, see `%entry`
+#[derive(Debug, Default)]
+struct EntryPointData {
+    /// The name of the entry point
+    non_terminal: String,
+    /// The non-terminal index of the entry point
+    index: usize,
+    /// The name of the parse function, e.g. `parse_expression`
+    function_name: String,
+    /// The name of the entry point's lookahead automata, only used for LL(k) parsers. The source is
+    /// only given if they differ from the lookahead automata of the start symbol.
+    lookahead_automata: Option<(String, Option<String>)>,
+    /// The name and the source of the entry point's parse table, only used for LALR(1) parsers
+    parse_table: Option<(String, String)>,
+}
+
+/// Collects the entry points of the grammar and names their parse functions
+fn build_entry_points(
+    grammar_config: &GrammarConfig,
+    non_terminal_names: &[String],
+) -> Result<Vec<EntryPointData>> {
+    let mut entry_points: Vec<EntryPointData> = Vec::new();
+    for non_terminal in &grammar_config.entry_points {
+        let Some(index) = non_terminal_names.iter().position(|n| n == non_terminal) else {
+            bail!("Entry point {non_terminal} is not a non-terminal of the grammar");
+        };
+        let function_name = format!("parse_{}", NamingHelper::to_lower_snake_case(non_terminal));
+        if [
+            "parse_into",
+            "parse_tokens",
+            "parse_bytes",
+            "parse_with_recovery",
+            "parse_cancellable",
+        ]
+        .contains(&function_name.as_str())
+            || entry_points
+                .iter()
+                .any(|e| e.function_name == function_name)
+        {
+            bail!(
+                "The parse function {function_name} of the entry point {non_terminal} clashes \
+                with another function of the generated parser"
+            );
+        }
+        entry_points.push(EntryPointData {
+            non_terminal: non_terminal.clone(),
+            index,
+            function_name,
+            lookahead_automata: None,
+            parse_table: None,
+        });
+    }
+    Ok(entry_points)
+}
+
+/// Generates the function that parses the input starting with the given entry point
+fn generate_parse_entry_point_source(
+    entry_point: &EntryPointData,
+    user_actions_params: &str,
+    parser_setup: &str,
+    parser_name: &str,
+    token_stream: &str,
+) -> String {
+    let EntryPointData {
+        non_terminal,
+        function_name,
+        ..
+    } = entry_point;
+    format!(
+        r#"
+
+///
+/// Parses the input as {non_terminal} instead of the start symbol.
+/// The input must end after the {non_terminal}.
+///
+#[allow(dead_code)]
+pub fn {function_name}<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: {user_actions_params},
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{{
+    use parol_runtime::{{
+        parser::{{parse_tree_type::SynTree, parser_types::SynTreeFlavor}},
+        syntree::Builder,
+    }};
+{parser_setup}
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    {parser_name}.parse_into(&mut builder, {token_stream}, &mut user_actions)?;
+    Ok(builder.build()?)
+}}
+"#
+    )
+}
+
+/// Generates the function that parses the input with a recovery strategy chosen by the user
+fn generate_parse_with_recovery_source(
+    user_actions_params: &str,
+    parser_setup: &str,
+    token_stream: &str,
+) -> String {
+    format!(
+        r#"
+
+///
+/// Parses the input like `parse` but recovers from syntax errors with the given strategy, e.g.
+/// `PanicMode`. The edits applied during recovery are reported with the syntax errors.
+///
+#[allow(dead_code)]
+pub fn parse_with_recovery<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: {user_actions_params},
+    recovery_strategy: Box<dyn parol_runtime::parser::recovery::RecoveryStrategy>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{{
+    use parol_runtime::{{
+        parser::{{parse_tree_type::SynTree, parser_types::SynTreeFlavor}},
+        syntree::Builder,
+    }};
+{parser_setup}
+    llk_parser.set_recovery_strategy(recovery_strategy);
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    llk_parser.parse_into(&mut builder, {token_stream}, &mut user_actions)?;
+    Ok(builder.build()?)
+}}
+"#
+    )
+}
+
+/// Generates the function that parses the input until the given cancellation token is set
+fn generate_parse_cancellable_source(
+    user_actions_params: &str,
+    parser_setup: &str,
+    parser_name: &str,
+    token_stream: &str,
+) -> String {
+    format!(
+        r#"
+
+///
+/// Parses the input like `parse` but stops with `ParserError::Cancelled` as soon as the
+/// cancellation token is set, e.g. by another thread that starts parsing a newer input.
+///
+#[allow(dead_code)]
+pub fn parse_cancellable<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: {user_actions_params},
+    cancellation_token: std::sync::Arc<std::sync::atomic::AtomicBool>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{{
+    use parol_runtime::{{
+        parser::{{parse_tree_type::SynTree, parser_types::SynTreeFlavor}},
+        syntree::Builder,
+    }};
+{parser_setup}
+    {parser_name}.set_cancellation_token(cancellation_token);
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    {parser_name}.parse_into(&mut builder, {token_stream}, &mut user_actions)?;
+    Ok(builder.build()?)
+}}
+"#
+    )
+}
+
+/// The optional scanner features of the token stream that is passed to the parser
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TokenStreamFeatures {
+    pub(crate) nested_block_comments: bool,
+    pub(crate) offside_tokens: bool,
+    pub(crate) external_tokens: bool,
+    pub(crate) contextual_lexing: bool,
+    pub(crate) byte_input: bool,
+    pub(crate) column_unit: ColumnUnit,
+}
+
+/// Generates the [parol_runtime::lexer::TokenStreamOptions] of the given features
+pub(crate) fn generate_token_stream_options_source(features: TokenStreamFeatures) -> String {
+    let TokenStreamFeatures {
+        nested_block_comments,
+        offside_tokens,
+        external_tokens,
+        contextual_lexing,
+        byte_input,
+        column_unit,
+    } = features;
+    let nested_block_comments = if nested_block_comments {
+        "NESTED_BLOCK_COMMENTS_BY_SCANNER_STATE"
+    } else {
+        "&[]"
+    };
+    let offside_tokens = if offside_tokens {
+        "OFFSIDE_TOKENS_BY_SCANNER_STATE"
+    } else {
+        "&[]"
+    };
+    let external_tokens_by_state = if external_tokens {
+        "EXTERNAL_TOKENS_BY_SCANNER_STATE"
+    } else {
+        "&[]"
+    };
+    let column_unit = format!("parol_runtime::lexer::ColumnUnit::{column_unit:?}");
+    ume::ume! {
+        parol_runtime::lexer::TokenStreamOptions {
+            skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+            nested_block_comments_by_state: #nested_block_comments,
+            offside_tokens_by_state: #offside_tokens,
+            external_tokens_by_state: #external_tokens_by_state,
+            contextual_lexing: #contextual_lexing,
+            byte_input: #byte_input,
+            column_unit: #column_unit,
+        }
+    }
+    .to_string()
+}
+
+/// Generates the construction of the token stream that is passed to the parser
+fn generate_token_stream_source(
+    scanner_type_name: &str,
+    max_k: &str,
+    features: TokenStreamFeatures,
+) -> String {
+    let TokenStreamFeatures {
+        nested_block_comments,
+        offside_tokens,
+        external_tokens,
+        contextual_lexing,
+        byte_input,
+        column_unit,
+    } = features;
+    if !(nested_block_comments
+        || offside_tokens
+        || external_tokens
+        || contextual_lexing
+        || byte_input
+        || column_unit != ColumnUnit::Chars)
+    {
+        return ume::ume! {
+            TokenStream::new_with_skip_tokens(
+                input,
+                file_name,
+                scanner.scanner_impl.clone(),
+                &#scanner_type_name::match_function,
+                #max_k,
+                SKIP_TOKENS_BY_SCANNER_STATE,
+            )
+            .unwrap()
+        }
+        .to_string();
+    }
+    let options = generate_token_stream_options_source(features);
+    if external_tokens {
+        // Invalid matches of the external scanner are reported by the token stream
+        ume::ume! {
+            TokenStream::new_with_external_scanner(
+                input,
+                file_name,
+                scanner.scanner_impl.clone(),
+                &#scanner_type_name::match_function,
+                #max_k,
+                #options,
+                Box::new(ExternalScannerAdapter(external_scanner)),
+            )?
+        }
+        .to_string()
+    } else {
+        // Unterminated nested block comments and inconsistent indentation are reported by the
+        // token stream
+        ume::ume! {
+            TokenStream::new_with_options(
+                input,
+                file_name,
+                scanner.scanner_impl.clone(),
+                &#scanner_type_name::match_function,
+                #max_k,
+                #options,
+            )?
+        }
+        .to_string()
+    }
+}
+
 impl Dfa {
     #[allow(dead_code)]
     pub(crate) fn from_compiled_dfa(
@@ -126,24 +641,60 @@ impl std::fmt::Display for Dfa {
 }
 
 #[derive(Debug, Default)]
-struct Dfas {
+struct Dfas<'a> {
+    name: &'a str,
     dfa_count: usize,
     lookahead_dfa_s: String,
 }
 
-impl std::fmt::Display for Dfas {
+impl std::fmt::Display for Dfas<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let Dfas {
+            name,
             dfa_count,
             lookahead_dfa_s,
         } = self;
         f.write_fmt(ume::ume! {
-            pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; #dfa_count] = &[
+            pub const #name: &[LookaheadDFA; #dfa_count] = &[
             #lookahead_dfa_s];
         })
     }
 }
 
+#[derive(Debug, Default)]
+struct PredicatedDecision {
+    predicates: StrVec,
+    fallback: Dfa,
+}
+
+impl std::fmt::Display for PredicatedDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let PredicatedDecision {
+            predicates,
+            fallback,
+        } = self;
+        let Dfa {
+            prod0,
+            transitions,
+            k,
+            nt_index,
+            nt_name,
+        } = fallback;
+        writeln!(f, r#"/* {nt_index} - "{nt_name}" */"#)?;
+        f.write_fmt(ume::ume! {
+            PredicatedDecision {
+                non_terminal: #nt_index,
+                predicates: &[#predicates],
+                fallback: LookaheadDFA {
+                    prod0: #prod0,
+                    transitions: &[#transitions],
+                    k: #k,
+                },
+            },
+        })
+    }
+}
+
 #[derive(Debug, Default)]
 struct Production {
     lhs: usize,
@@ -290,9 +841,13 @@ struct ParserData<'a> {
     non_terminals: StrVec,
     non_terminal_count: usize,
     dfa_source: String,
+    predicated_decisions_source: Option<String>,
     productions: String,
     max_k: usize,
     skip_tokens_by_state_source: String,
+    nested_block_comments_by_state_source: Option<String>,
+    offside_tokens_by_state_source: Option<String>,
+    external_scanner_source: Option<(String, String)>,
     user_type_name: &'a str,
     user_type_life_time: &'static str,
     scanner_type_name: String,
@@ -300,7 +855,17 @@ struct ParserData<'a> {
     module_name: &'a str,
     trim_parse_tree: bool,
     disable_recovery: bool,
+    max_errors: Option<usize>,
+    custom_recovery: bool,
     max_parsing_depth: Option<usize>,
+    step_budget: Option<usize>,
+    cancellable: bool,
+    contextual_lexing: bool,
+    byte_input: bool,
+    column_unit: ColumnUnit,
+    token_input: bool,
+    arena_allocation: bool,
+    entry_points: Vec<EntryPointData>,
 }
 
 impl std::fmt::Display for ParserData<'_> {
@@ -311,9 +876,13 @@ impl std::fmt::Display for ParserData<'_> {
             non_terminals,
             non_terminal_count,
             dfa_source,
+            predicated_decisions_source,
             productions,
             max_k,
             skip_tokens_by_state_source,
+            nested_block_comments_by_state_source,
+            offside_tokens_by_state_source,
+            external_scanner_source,
             user_type_name,
             scanner_type_name,
             scanner_module_name,
@@ -321,7 +890,17 @@ impl std::fmt::Display for ParserData<'_> {
             module_name,
             trim_parse_tree,
             disable_recovery,
+            max_errors,
+            custom_recovery,
             max_parsing_depth,
+            step_budget,
+            cancellable,
+            contextual_lexing,
+            byte_input,
+            column_unit,
+            token_input,
+            arena_allocation,
+            entry_points,
         } = self;
 
         writeln!(
@@ -345,6 +924,11 @@ impl std::fmt::Display for ParserData<'_> {
             use scnr2::scanner;
             use std::path::Path;
         })?;
+        if predicated_decisions_source.is_some() {
+            f.write_fmt(ume::ume! {
+                use parol_runtime::parser::PredicatedDecision;
+            })?;
+        }
 
         writeln!(f, "\n")?;
         let auto_name = format!("{user_type_name}Auto");
@@ -361,27 +945,65 @@ impl std::fmt::Display for ParserData<'_> {
             const MAX_K: usize = #max_k;
         })?;
         writeln!(f, "{skip_tokens_by_state_source}")?;
+        if let Some(nested_block_comments_by_state_source) = nested_block_comments_by_state_source {
+            writeln!(f, "{nested_block_comments_by_state_source}")?;
+        }
+        if let Some(offside_tokens_by_state_source) = offside_tokens_by_state_source {
+            writeln!(f, "{offside_tokens_by_state_source}")?;
+        }
+        if let Some((external_scanner_source, _)) = external_scanner_source {
+            writeln!(f, "{external_scanner_source}")?;
+        }
         writeln!(f, "\n\n")?;
         f.write_fmt(ume::ume! {
             pub const NON_TERMINALS: &[&str; #non_terminal_count] = &[#non_terminals];
         })?;
 
         writeln!(f, "\n\n{dfa_source}")?;
+        for lookahead_automata_source in entry_points
+            .iter()
+            .filter_map(|e| e.lookahead_automata.as_ref().and_then(|(_, s)| s.as_ref()))
+        {
+            writeln!(f, "\n{lookahead_automata_source}")?;
+        }
+        if let Some(predicated_decisions_source) = predicated_decisions_source {
+            writeln!(f, "\n{predicated_decisions_source}")?;
+        }
         writeln!(f, "\n{productions}\n")?;
 
         writeln!(f, "\n")?;
 
         let user_actions = ume::ume!(&mut #user_type_name #user_type_life_time).to_string();
-        let lifetime_on_parse = if *user_type_life_time == "<'t>" {
-            "'t,"
-        } else {
-            ""
-        };
-        let lifetime_on_input = if *user_type_life_time == "<'t>" {
-            "'t"
+        // The external scanner and the arena live as long as the input
+        let input_lifetime_needed = *user_type_life_time == "<'t>"
+            || external_scanner_source.is_some()
+            || *arena_allocation;
+        let lifetime_on_parse = if input_lifetime_needed { "'t," } else { "" };
+        let lifetime_on_input = if input_lifetime_needed { "'t" } else { "" };
+        // The external scanner is appended to the user actions to keep the signatures unchanged
+        // for grammars without external terminals
+        let (mut user_actions_params, mut user_actions_args) =
+            if let Some((_, trait_name)) = external_scanner_source {
+                (
+                    format!("{user_actions}, external_scanner: impl {trait_name} + 't"),
+                    "user_actions, external_scanner".to_string(),
+                )
+            } else {
+                (user_actions.clone(), "user_actions".to_string())
+            };
+        // The arena is appended likewise and handed over to the adapter
+        let (arena_param, auto_args) = if *arena_allocation {
+            (
+                ", arena: &'t parol_runtime::arena::Bump",
+                "user_actions, arena",
+            )
         } else {
-            ""
+            ("", "user_actions")
         };
+        if *arena_allocation {
+            user_actions_params += arena_param;
+            user_actions_args += ", arena";
+        }
         let use_scanner_type = ume::ume! {
             use #scanner_module_name::#scanner_type_name;
         }
@@ -393,10 +1015,22 @@ impl std::fmt::Display for ParserData<'_> {
         let auto_wrapper = format!(
             "\n// Initialize wrapper\n{}",
             ume::ume! {
-                let mut user_actions = #auto_name::new(user_actions);
+                let mut user_actions = #auto_name::new(#auto_args);
             }
         );
         let mut_ref_user_actions = ume::ume!(&mut user_actions);
+        let token_stream = generate_token_stream_source(
+            scanner_type_name,
+            "MAX_K",
+            TokenStreamFeatures {
+                nested_block_comments: nested_block_comments_by_state_source.is_some(),
+                offside_tokens: offside_tokens_by_state_source.is_some(),
+                external_tokens: external_scanner_source.is_some(),
+                contextual_lexing: *contextual_lexing,
+                byte_input: *byte_input,
+                column_unit: *column_unit,
+            },
+        );
         let enable_trimming = if *trim_parse_tree {
             "llk_parser.trim_parse_tree();\n"
         } else {
@@ -407,23 +1041,39 @@ impl std::fmt::Display for ParserData<'_> {
         } else {
             ""
         };
+        let error_limit = if let Some(max_errors) = *max_errors {
+            format!("llk_parser.set_max_errors({max_errors});\n")
+        } else {
+            String::new()
+        };
         let depth_limit = if let Some(max_parsing_depth) = *max_parsing_depth {
             format!("llk_parser.set_max_parsing_depth({max_parsing_depth});\n")
         } else {
             String::new()
         };
+        let step_limit = if let Some(step_budget) = *step_budget {
+            format!("llk_parser.set_step_budget({step_budget});\n")
+        } else {
+            String::new()
+        };
+        let predicates = if predicated_decisions_source.is_some() {
+            "llk_parser.set_predicated_decisions(PREDICATED_DECISIONS);\n"
+        } else {
+            ""
+        };
+        let limits = format!("{error_limit}{depth_limit}{step_limit}{predicates}");
         f.write_fmt(ume::ume! {
             pub fn parse<#lifetime_on_parse T>(
                 input: &#lifetime_on_input str,
                 file_name: T,
-                user_actions: #user_actions,
+                user_actions: #user_actions_params,
             ) -> Result<ParseTree, ParolError> where T: AsRef<Path> {
                 use parol_runtime::{
                     parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
                     syntree::Builder,
                 };
                 let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
-                parse_into(input, &mut builder, file_name, user_actions)?;
+                parse_into(input, &mut builder, file_name, #user_actions_args)?;
                 Ok(builder.build()?)
             }
         })?;
@@ -433,7 +1083,7 @@ impl std::fmt::Display for ParserData<'_> {
                 input: &'t str,
                 tree_builder: &mut T,
                 file_name: impl AsRef<Path>,
-                user_actions: #user_actions,
+                user_actions: #user_actions_params,
             ) -> Result<(), ParolError> where ParolError: From<T::Error> {
                 #use_scanner_type
                 let mut llk_parser = LLKParser::new(
@@ -445,25 +1095,79 @@ impl std::fmt::Display for ParserData<'_> {
                 );
                 #enable_trimming
                 #recovery
-                #depth_limit
+                #limits
                 #scanner_instance
                 #auto_wrapper
 
                 llk_parser.parse_into(
                     tree_builder,
-                    TokenStream::new_with_skip_tokens(
-                        input,
-                        file_name,
-                        scanner.scanner_impl.clone(),
-                        &#scanner_type_name::match_function,
-                        MAX_K,
-                        SKIP_TOKENS_BY_SCANNER_STATE,
-                    )
-                    .unwrap(),
+                    #token_stream,
                     #mut_ref_user_actions
                 )
             }
-        })
+        })?;
+        if *byte_input {
+            f.write_str(&generate_parse_bytes_source(
+                &user_actions_params,
+                &user_actions_args,
+            ))?;
+        }
+        if *token_input {
+            let parser_setup = format!(
+                "let mut llk_parser = LLKParser::new({start_symbol_index}, LOOKAHEAD_AUTOMATA, \
+                PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);\n{enable_trimming}{recovery}{limits}"
+            );
+            f.write_str(&generate_parse_tokens_source(
+                &format!("{user_actions}{arena_param}"),
+                &auto_wrapper,
+                &parser_setup,
+                "llk_parser",
+            ))?;
+        }
+        if *custom_recovery {
+            let parser_setup = format!(
+                "{use_scanner_type}\nlet mut llk_parser = LLKParser::new({start_symbol_index}, \
+                LOOKAHEAD_AUTOMATA, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);\n\
+                {enable_trimming}{limits}{scanner_instance}{auto_wrapper}"
+            );
+            f.write_str(&generate_parse_with_recovery_source(
+                &user_actions_params,
+                &parser_setup,
+                &token_stream,
+            ))?;
+        }
+        if *cancellable {
+            let parser_setup = format!(
+                "{use_scanner_type}\nlet mut llk_parser = LLKParser::new({start_symbol_index}, \
+                LOOKAHEAD_AUTOMATA, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);\n\
+                {enable_trimming}{recovery}{limits}{scanner_instance}{auto_wrapper}"
+            );
+            f.write_str(&generate_parse_cancellable_source(
+                &user_actions_params,
+                &parser_setup,
+                "llk_parser",
+                &token_stream,
+            ))?;
+        }
+        for entry_point in entry_points {
+            let Some((lookahead_automata_name, _)) = &entry_point.lookahead_automata else {
+                continue;
+            };
+            let parser_setup = format!(
+                "{use_scanner_type}\nlet mut llk_parser = LLKParser::new({}, \
+                {lookahead_automata_name}, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);\n\
+                {enable_trimming}{recovery}{limits}{scanner_instance}{auto_wrapper}",
+                entry_point.index
+            );
+            f.write_str(&generate_parse_entry_point_source(
+                entry_point,
+                &user_actions_params,
+                &parser_setup,
+                "llk_parser",
+                &token_stream,
+            ))?;
+        }
+        Ok(())
     }
 }
 
@@ -475,6 +1179,9 @@ struct LRParserData<'a> {
     non_terminal_count: usize,
     productions: String,
     skip_tokens_by_state_source: String,
+    nested_block_comments_by_state_source: Option<String>,
+    offside_tokens_by_state_source: Option<String>,
+    external_scanner_source: Option<(String, String)>,
     user_type_name: &'a str,
     user_type_life_time: &'static str,
     scanner_type_name: String,
@@ -482,7 +1189,15 @@ struct LRParserData<'a> {
     module_name: &'a str,
     trim_parse_tree: bool,
     max_parsing_depth: Option<usize>,
+    step_budget: Option<usize>,
+    cancellable: bool,
+    contextual_lexing: bool,
+    byte_input: bool,
+    column_unit: ColumnUnit,
+    token_input: bool,
+    arena_allocation: bool,
     parse_table_source: String,
+    entry_points: Vec<EntryPointData>,
 }
 
 impl std::fmt::Display for LRParserData<'_> {
@@ -494,6 +1209,9 @@ impl std::fmt::Display for LRParserData<'_> {
             non_terminal_count,
             productions,
             skip_tokens_by_state_source,
+            nested_block_comments_by_state_source,
+            offside_tokens_by_state_source,
+            external_scanner_source,
             user_type_name,
             user_type_life_time,
             scanner_type_name,
@@ -501,7 +1219,15 @@ impl std::fmt::Display for LRParserData<'_> {
             module_name,
             trim_parse_tree,
             max_parsing_depth,
+            step_budget,
+            cancellable,
+            contextual_lexing,
+            byte_input,
+            column_unit,
+            token_input,
+            arena_allocation,
             parse_table_source,
+            entry_points,
         } = self;
 
         writeln!(
@@ -537,6 +1263,15 @@ impl std::fmt::Display for LRParserData<'_> {
         writeln!(f, "{lexer_source}\n")?;
 
         writeln!(f, "{skip_tokens_by_state_source}")?;
+        if let Some(nested_block_comments_by_state_source) = nested_block_comments_by_state_source {
+            writeln!(f, "{nested_block_comments_by_state_source}")?;
+        }
+        if let Some(offside_tokens_by_state_source) = offside_tokens_by_state_source {
+            writeln!(f, "{offside_tokens_by_state_source}")?;
+        }
+        if let Some((external_scanner_source, _)) = external_scanner_source {
+            writeln!(f, "{external_scanner_source}")?;
+        }
         writeln!(f, "\n\n")?;
         f.write_fmt(ume::ume! {
             pub const NON_TERMINALS: &[&str; #non_terminal_count] = &[#non_terminals];
@@ -546,28 +1281,68 @@ impl std::fmt::Display for LRParserData<'_> {
             f,
             "\n\nstatic PARSE_TABLE: LRParseTable  = {parse_table_source};\n"
         )?;
+        for (parse_table_name, parse_table_source) in
+            entry_points.iter().filter_map(|e| e.parse_table.as_ref())
+        {
+            writeln!(
+                f,
+                "\nstatic {parse_table_name}: LRParseTable  = {parse_table_source};\n"
+            )?;
+        }
         writeln!(f, "\n{productions}\n")?;
 
         writeln!(f, "\n")?;
 
         let user_actions = ume::ume!(&mut #user_type_name #user_type_life_time).to_string();
-        let lifetime_on_parse = if *user_type_life_time == "<'t>" {
-            "'t,"
-        } else {
-            ""
-        };
-        let lifetime_on_input = if *user_type_life_time == "<'t>" {
-            "'t"
+        // The external scanner and the arena live as long as the input
+        let input_lifetime_needed = *user_type_life_time == "<'t>"
+            || external_scanner_source.is_some()
+            || *arena_allocation;
+        let lifetime_on_parse = if input_lifetime_needed { "'t," } else { "" };
+        let lifetime_on_input = if input_lifetime_needed { "'t" } else { "" };
+        // The external scanner is appended to the user actions to keep the signatures unchanged
+        // for grammars without external terminals
+        let (mut user_actions_params, mut user_actions_args) =
+            if let Some((_, trait_name)) = external_scanner_source {
+                (
+                    format!("{user_actions}, external_scanner: impl {trait_name} + 't"),
+                    "user_actions, external_scanner".to_string(),
+                )
+            } else {
+                (user_actions.clone(), "user_actions".to_string())
+            };
+        // The arena is appended likewise and handed over to the adapter
+        let (arena_param, auto_args) = if *arena_allocation {
+            (
+                ", arena: &'t parol_runtime::arena::Bump",
+                "user_actions, arena",
+            )
         } else {
-            ""
+            ("", "user_actions")
         };
+        if *arena_allocation {
+            user_actions_params += arena_param;
+            user_actions_args += ", arena";
+        }
         let auto_wrapper = format!(
             "\n// Initialize wrapper\n{}",
             ume::ume! {
-                let mut user_actions = #auto_name::new(user_actions);
+                let mut user_actions = #auto_name::new(#auto_args);
             }
         );
         let mut_ref_user_actions = ume::ume!(&mut user_actions);
+        let token_stream = generate_token_stream_source(
+            scanner_type_name,
+            "1",
+            TokenStreamFeatures {
+                nested_block_comments: nested_block_comments_by_state_source.is_some(),
+                offside_tokens: offside_tokens_by_state_source.is_some(),
+                external_tokens: external_scanner_source.is_some(),
+                contextual_lexing: *contextual_lexing,
+                byte_input: *byte_input,
+                column_unit: *column_unit,
+            },
+        );
         let enable_trimming = if *trim_parse_tree {
             "lr_parser.trim_parse_tree();\n"
         } else {
@@ -578,6 +1353,12 @@ impl std::fmt::Display for LRParserData<'_> {
         } else {
             String::new()
         };
+        let step_limit = if let Some(step_budget) = *step_budget {
+            format!("lr_parser.set_step_budget({step_budget});\n")
+        } else {
+            String::new()
+        };
+        let limits = format!("{depth_limit}{step_limit}");
         let use_scanner_type = ume::ume! {
             use #scanner_module_name::#scanner_type_name;
         }
@@ -591,14 +1372,14 @@ impl std::fmt::Display for LRParserData<'_> {
             pub fn parse<#lifetime_on_parse T>(
                 input: &#lifetime_on_input str,
                 file_name: T,
-                user_actions: #user_actions,
+                user_actions: #user_actions_params,
             ) -> Result<ParseTree, ParolError> where T: AsRef<Path> {
                 use parol_runtime::{
                     parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
                     syntree::Builder,
                 };
                 let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
-                parse_into(input, &mut builder, file_name, user_actions)?;
+                parse_into(input, &mut builder, file_name, #user_actions_args)?;
                 Ok(builder.build()?)
             }
         })?;
@@ -608,7 +1389,7 @@ impl std::fmt::Display for LRParserData<'_> {
                 input: &'t str,
                 tree_builder: &mut T,
                 file_name: impl AsRef<Path>,
-                user_actions: #user_actions,
+                user_actions: #user_actions_params,
             ) -> Result<(), ParolError> where ParolError: From<T::Error> {
                 #use_scanner_type
                 let mut lr_parser = LRParser::new(
@@ -619,24 +1400,66 @@ impl std::fmt::Display for LRParserData<'_> {
                     NON_TERMINALS,
                 );
                 #enable_trimming
-                #depth_limit
+                #limits
                 #auto_wrapper
                 #scanner_instance
                 lr_parser.parse_into(
                     tree_builder,
-                    TokenStream::new_with_skip_tokens(
-                        input,
-                        file_name,
-                        scanner.scanner_impl.clone(),
-                        &#scanner_type_name::match_function,
-                        1,
-                        SKIP_TOKENS_BY_SCANNER_STATE,
-                    )
-                    .unwrap(),
+                    #token_stream,
                     #mut_ref_user_actions
                 )
             }
-        })
+        })?;
+        if *byte_input {
+            f.write_str(&generate_parse_bytes_source(
+                &user_actions_params,
+                &user_actions_args,
+            ))?;
+        }
+        if *token_input {
+            let parser_setup = format!(
+                "let mut lr_parser = LRParser::new({start_symbol_index}, &PARSE_TABLE, PRODUCTIONS, \
+                TERMINAL_NAMES, NON_TERMINALS);\n{enable_trimming}{limits}"
+            );
+            f.write_str(&generate_parse_tokens_source(
+                &format!("{user_actions}{arena_param}"),
+                &auto_wrapper,
+                &parser_setup,
+                "lr_parser",
+            ))?;
+        }
+        if *cancellable {
+            let parser_setup = format!(
+                "{use_scanner_type}\nlet mut lr_parser = LRParser::new({start_symbol_index}, \
+                &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);\n\
+                {enable_trimming}{limits}{auto_wrapper}{scanner_instance}"
+            );
+            f.write_str(&generate_parse_cancellable_source(
+                &user_actions_params,
+                &parser_setup,
+                "lr_parser",
+                &token_stream,
+            ))?;
+        }
+        for entry_point in entry_points {
+            let Some((parse_table_name, _)) = &entry_point.parse_table else {
+                continue;
+            };
+            let parser_setup = format!(
+                "{use_scanner_type}\nlet mut lr_parser = LRParser::new_for_entry_point({}, \
+                &{parse_table_name}, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);\n\
+                {enable_trimming}{limits}{auto_wrapper}{scanner_instance}",
+                entry_point.index
+            );
+            f.write_str(&generate_parse_entry_point_source(
+                entry_point,
+                &user_actions_params,
+                &parser_setup,
+                "lr_parser",
+                &token_stream,
+            ))?;
+        }
+        Ok(())
     }
 }
 
@@ -700,13 +1523,37 @@ fn generate_parser_source_internal<C: CommonGeneratorConfig + ParserGeneratorCon
             });
 
     let lookahead_automata_ir = build_lookahead_automata_model(la_dfa, &non_terminal_names);
-    let dfa_source = generate_dfa_source(&lookahead_automata_ir);
+    let dfa_source = generate_dfa_source("LOOKAHEAD_AUTOMATA", &lookahead_automata_ir);
+    let predicated_decisions_source =
+        generate_predicated_decisions_source(grammar_config, &non_terminal_names)?;
 
     let production_ir = build_production_model(grammar_config, &non_terminal_names)?;
     let productions = generate_productions(&production_ir);
 
     let max_k = grammar_config.lookahead_size;
 
+    let mut entry_points = build_entry_points(grammar_config, &non_terminal_names)?;
+    for entry_point in &mut entry_points {
+        let entry_la_dfa = crate::calculate_entry_lookahead_dfas(
+            grammar_config,
+            &entry_point.non_terminal,
+            max_k,
+        )?;
+        // Most entry points can share the lookahead automata of the start symbol
+        entry_point.lookahead_automata = Some(if entry_la_dfa == *la_dfa {
+            ("LOOKAHEAD_AUTOMATA".to_string(), None)
+        } else {
+            let name = format!(
+                "LOOKAHEAD_AUTOMATA_{}",
+                NamingHelper::to_lower_snake_case(&entry_point.non_terminal).to_uppercase()
+            );
+            let lookahead_automata_ir =
+                build_lookahead_automata_model(&entry_la_dfa, &non_terminal_names);
+            let source = generate_dfa_source(&name, &lookahead_automata_ir);
+            (name, Some(source))
+        });
+    }
+
     let user_type_life_time = if ast_type_has_lifetime { "<'t>" } else { "" };
 
     let parser_data = ParserData {
@@ -715,9 +1562,18 @@ fn generate_parser_source_internal<C: CommonGeneratorConfig + ParserGeneratorCon
         non_terminals,
         non_terminal_count,
         dfa_source,
+        predicated_decisions_source,
         productions,
         max_k,
         skip_tokens_by_state_source: generate_skip_tokens_by_state_source(grammar_config),
+        nested_block_comments_by_state_source: generate_nested_block_comments_by_state_source(
+            grammar_config,
+        )?,
+        offside_tokens_by_state_source: generate_offside_tokens_by_state_source(grammar_config),
+        external_scanner_source: generate_external_scanner_source(
+            grammar_config,
+            config.user_type_name(),
+        ),
         user_type_name: config.user_type_name(),
         user_type_life_time,
         scanner_type_name: get_scanner_type_name(config),
@@ -725,7 +1581,17 @@ fn generate_parser_source_internal<C: CommonGeneratorConfig + ParserGeneratorCon
         module_name: config.module_name(),
         trim_parse_tree: config.trim_parse_tree(),
         disable_recovery: config.recovery_disabled(),
+        max_errors: config.max_errors(),
+        custom_recovery: config.custom_recovery(),
         max_parsing_depth: config.max_parsing_depth(),
+        step_budget: config.step_budget(),
+        cancellable: config.cancellable(),
+        contextual_lexing: config.contextual_lexing(),
+        byte_input: config.byte_input(),
+        column_unit: config.column_unit(),
+        token_input: config.token_input(),
+        arena_allocation: config.arena_allocation(),
+        entry_points,
     };
 
     Ok(format!("{parser_data}"))
@@ -740,12 +1606,12 @@ fn get_terminals(grammar_config: &GrammarConfig) -> Vec<(&str, Option<LookaheadE
         .collect::<Vec<(&str, Option<LookaheadExpression>)>>()
 }
 
-fn get_scanner_module_name<C: CommonGeneratorConfig>(config: &C) -> String {
+pub(crate) fn get_scanner_module_name<C: CommonGeneratorConfig>(config: &C) -> String {
     let scanner_module_name = NamingHelper::to_lower_snake_case(config.user_type_name());
     scanner_module_name + "_scanner"
 }
 
-fn get_scanner_type_name<C: CommonGeneratorConfig>(config: &C) -> String {
+pub(crate) fn get_scanner_type_name<C: CommonGeneratorConfig>(config: &C) -> String {
     let scanner_type_name = NamingHelper::to_upper_camel_case(config.user_type_name());
     scanner_type_name + "Scanner"
 }
@@ -835,6 +1701,12 @@ fn generate_lalr1_parser_source_internal<C: CommonGeneratorConfig + ParserGenera
     parse_table: &LRParseTable,
     ast_type_has_lifetime: bool,
 ) -> Result<String> {
+    if config.max_errors().is_some() || config.custom_recovery() {
+        bail!(
+            "Recovery strategies and error limits are only supported for LL(k) grammars, \
+            LALR(1) parsers stop at the first syntax error"
+        );
+    }
     let terminals = get_terminals(grammar_config);
     let non_terminal_metadata = build_non_terminal_metadata_ir(grammar_config);
     let non_terminal_names = non_terminal_metadata.names;
@@ -859,6 +1731,19 @@ fn generate_lalr1_parser_source_internal<C: CommonGeneratorConfig + ParserGenera
 
     let parse_table_source = generate_parse_table_source(parse_table, &terminals, &non_terminals);
 
+    let mut entry_points = build_entry_points(grammar_config, &non_terminal_names)?;
+    for entry_point in &mut entry_points {
+        let entry_parse_table =
+            crate::calculate_lalr1_entry_parse_table(grammar_config, &entry_point.non_terminal)?.0;
+        entry_point.parse_table = Some((
+            format!(
+                "PARSE_TABLE_{}",
+                NamingHelper::to_lower_snake_case(&entry_point.non_terminal).to_uppercase()
+            ),
+            generate_parse_table_source(&entry_parse_table, &terminals, &non_terminals),
+        ));
+    }
+
     let parser_data = LRParserData {
         start_symbol_index,
         lexer_source,
@@ -866,6 +1751,14 @@ fn generate_lalr1_parser_source_internal<C: CommonGeneratorConfig + ParserGenera
         non_terminal_count,
         productions,
         skip_tokens_by_state_source: generate_skip_tokens_by_state_source(grammar_config),
+        nested_block_comments_by_state_source: generate_nested_block_comments_by_state_source(
+            grammar_config,
+        )?,
+        offside_tokens_by_state_source: generate_offside_tokens_by_state_source(grammar_config),
+        external_scanner_source: generate_external_scanner_source(
+            grammar_config,
+            config.user_type_name(),
+        ),
         user_type_name: config.user_type_name(),
         user_type_life_time,
         scanner_type_name: get_scanner_type_name(config),
@@ -873,7 +1766,15 @@ fn generate_lalr1_parser_source_internal<C: CommonGeneratorConfig + ParserGenera
         module_name: config.module_name(),
         trim_parse_tree: config.trim_parse_tree(),
         max_parsing_depth: config.max_parsing_depth(),
+        step_budget: config.step_budget(),
+        cancellable: config.cancellable(),
+        contextual_lexing: config.contextual_lexing(),
+        byte_input: config.byte_input(),
+        column_unit: config.column_unit(),
+        token_input: config.token_input(),
+        arena_allocation: config.arena_allocation(),
         parse_table_source,
+        entry_points,
     };
 
     Ok(format!("{parser_data}"))
@@ -966,7 +1867,7 @@ fn generate_parse_table_source(
     format!("LRParseTable {{ actions: &[{actions}], states: &[{states}] }}",)
 }
 
-fn generate_dfa_source(lookahead_automata_ir: &[LookaheadAutomatonIR]) -> String {
+fn generate_dfa_source(name: &str, lookahead_automata_ir: &[LookaheadAutomatonIR]) -> String {
     let lookahead_dfa_s =
         lookahead_automata_ir
             .iter()
@@ -978,6 +1879,7 @@ fn generate_dfa_source(lookahead_automata_ir: &[LookaheadAutomatonIR]) -> String
     let dfa_count = lookahead_automata_ir.len();
 
     let dfas = Dfas {
+        name,
         dfa_count,
         lookahead_dfa_s: format!("{lookahead_dfa_s}"),
     };
@@ -985,6 +1887,52 @@ fn generate_dfa_source(lookahead_automata_ir: &[LookaheadAutomatonIR]) -> String
     format!("{dfas}")
 }
 
+/// Generates the decisions of the non-terminals with productions guarded by semantic predicates.
+/// Returns None if the grammar has no predicates.
+fn generate_predicated_decisions_source(
+    grammar_config: &GrammarConfig,
+    non_terminal_names: &[String],
+) -> Result<Option<String>> {
+    let cfg = &grammar_config.cfg;
+    let predicate_names = cfg.get_predicate_names();
+    if predicate_names.is_empty() {
+        return Ok(None);
+    }
+    let fallback_dfas =
+        crate::calculate_fallback_lookahead_dfas(grammar_config, grammar_config.lookahead_size)?;
+    let decisions = build_lookahead_automata_model(&fallback_dfas, non_terminal_names)
+        .iter()
+        .fold(StrVec::new(0), |mut acc, automaton_ir| {
+            let predicates = cfg
+                .matching_productions(&automaton_ir.non_terminal_name)
+                .iter()
+                .filter_map(|(i, p)| {
+                    p.get_predicate().map(|name| {
+                        let index = predicate_names.iter().position(|n| *n == name).unwrap();
+                        format!("({i}, {index}),")
+                    })
+                })
+                .fold(StrVec::new(0), |mut acc, p| {
+                    acc.push(p);
+                    acc
+                });
+            let decision = PredicatedDecision {
+                predicates,
+                fallback: Dfa::from_ir(automaton_ir),
+            };
+            acc.push(format!("{decision}"));
+            acc
+        });
+    let decision_count = fallback_dfas.len();
+    Ok(Some(format!(
+        "{}",
+        ume::ume! {
+            pub const PREDICATED_DECISIONS: &[PredicatedDecision; #decision_count] = &[
+            #decisions];
+        }
+    )))
+}
+
 fn generate_productions(production_ir: &[ProductionIR]) -> String {
     let production_count = production_ir.len();
     let productions = production_ir.iter().fold(String::new(), |mut acc, p| {
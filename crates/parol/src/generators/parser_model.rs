@@ -88,6 +88,7 @@ pub enum TerminalKindExportModel {
     Legacy,
     Regex,
     Raw,
+    RawCaseInsensitive,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -124,16 +125,29 @@ pub struct ScannerTransitionExportModel {
     pub target_scanner_name: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffsideTokensExportModel {
+    pub indent: TerminalIndex,
+    pub dedent: TerminalIndex,
+    pub newline: TerminalIndex,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ScannerStateExportModel {
     pub scanner_state: usize,
     pub scanner_name: String,
     pub line_comments: Vec<String>,
     pub block_comments: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nested_block_comments: Vec<(String, String)>,
     pub auto_newline: bool,
     pub auto_ws: bool,
     pub allow_unmatched: bool,
     pub skip_tokens: Vec<TerminalIndex>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offside_tokens: Option<OffsideTokensExportModel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub external_tokens: Vec<TerminalIndex>,
     pub transitions: Vec<ScannerTransitionExportModel>,
 }
 
@@ -428,6 +442,7 @@ fn to_terminal_kind_export_model(kind: TerminalKind) -> TerminalKindExportModel
         TerminalKind::Legacy => TerminalKindExportModel::Legacy,
         TerminalKind::Regex => TerminalKindExportModel::Regex,
         TerminalKind::Raw => TerminalKindExportModel::Raw,
+        TerminalKind::RawCaseInsensitive => TerminalKindExportModel::RawCaseInsensitive,
     }
 }
 
@@ -613,10 +628,17 @@ fn build_scanner_export_model(grammar_config: &GrammarConfig) -> ScannerExportMo
                 scanner_name: scanner.scanner_name.clone(),
                 line_comments: scanner.line_comments.clone(),
                 block_comments: scanner.block_comments.clone(),
+                nested_block_comments: scanner.nested_block_comments.clone(),
                 auto_newline: scanner.auto_newline,
                 auto_ws: scanner.auto_ws,
                 allow_unmatched: scanner.allow_unmatched,
                 skip_tokens: scanner.skip_tokens.clone(),
+                offside_tokens: scanner.offside_tokens.map(|o| OffsideTokensExportModel {
+                    indent: o.indent,
+                    dedent: o.dedent,
+                    newline: o.newline,
+                }),
+                external_tokens: scanner.external_tokens.clone(),
                 transitions,
             }
         })
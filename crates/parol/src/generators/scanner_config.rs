@@ -4,7 +4,7 @@ use parol_runtime::{
     TerminalIndex,
     lexer::{
         BLOCK_COMMENT, ERROR_TOKEN, FIRST_USER_TOKEN, LINE_COMMENT, NEW_LINE, NEW_LINE_TOKEN,
-        WHITESPACE, WHITESPACE_TOKEN,
+        OffsideTokens, WHITESPACE, WHITESPACE_TOKEN,
     },
 };
 use std::fmt::{Debug, Display, Error, Formatter};
@@ -47,6 +47,14 @@ pub struct ScannerConfig {
     ///
     pub block_comments: Vec<(String, String)>,
 
+    ///
+    /// (String, String) tuples with the characters that start and end
+    /// a block comments that can be nested, respectively.
+    /// The scanner only matches the start of such a comment. The rest of the comment is consumed
+    /// by the runtime's token iterator that keeps track of the nesting depth.
+    ///
+    pub nested_block_comments: Vec<(String, String)>,
+
     ///
     /// If true the lexer handles (and skips) newlines.
     /// If false the user has to handle newlines on its own.
@@ -65,6 +73,20 @@ pub struct ScannerConfig {
     /// Additional terminal indices skipped in this scanner state.
     pub skip_tokens: Vec<TerminalIndex>,
 
+    ///
+    /// The terminals synthesized by the offside rule in this scanner state, if any.
+    /// They are never matched by the scanner but inserted by the runtime's token stream
+    /// depending on the indentation of the lines.
+    ///
+    pub offside_tokens: Option<OffsideTokens>,
+
+    ///
+    /// The terminals scanned by user code in this scanner state, in the order in which they are
+    /// tried. They are never matched by the scanner but by an external scanner that the runtime's
+    /// token iterator calls at the start of each token.
+    ///
+    pub external_tokens: Vec<TerminalIndex>,
+
     /// Scanner state transitions
     /// Maps from token to scanner state, where the token is identified by its TerminalIndex
     /// The scanner state is identified by its index.
@@ -79,10 +101,13 @@ impl ScannerConfig {
             scanner_state,
             line_comments: Vec::new(),
             block_comments: Vec::new(),
+            nested_block_comments: Vec::new(),
             auto_newline: true,
             auto_ws: true,
             allow_unmatched: false,
             skip_tokens: Vec::new(),
+            offside_tokens: None,
+            external_tokens: Vec::new(),
             transitions: Vec::new(),
         }
     }
@@ -99,6 +124,15 @@ impl ScannerConfig {
         self
     }
 
+    /// Adds nested block comments to self
+    pub fn with_nested_block_comments(
+        mut self,
+        nested_block_comments: Vec<(String, String)>,
+    ) -> Self {
+        self.nested_block_comments = nested_block_comments;
+        self
+    }
+
     /// Sets auto newline behavior
     pub fn with_auto_newline(mut self, auto_newline: bool) -> Self {
         self.auto_newline = auto_newline;
@@ -123,6 +157,18 @@ impl ScannerConfig {
         self
     }
 
+    /// Sets the terminal indices scanned by user code in the scanner state.
+    pub fn with_external_tokens(mut self, external_tokens: Vec<TerminalIndex>) -> Self {
+        self.external_tokens = external_tokens;
+        self
+    }
+
+    /// Sets the terminal indices synthesized by the offside rule in the scanner state.
+    pub fn with_offside_tokens(mut self, offside_tokens: Option<OffsideTokens>) -> Self {
+        self.offside_tokens = offside_tokens;
+        self
+    }
+
     /// Generates the data needed by the lexer generator.
     /// The tuple contains the mapping of terminal strings to their indices plus an optional
     /// lookahead pattern and the transitions, i.e. a mapping of terminal indices to scanner names.
@@ -133,6 +179,12 @@ impl ScannerConfig {
         terminal_names: &[String],
     ) -> Result<BuildInformation> {
         let cfg = &grammar_config.cfg;
+        if self.offside_tokens.is_some() && !(self.auto_newline && self.auto_ws) {
+            bail!(
+                "Scanner state {} uses the offside rule which requires automatic handling of newlines and whitespace.",
+                self.scanner_name
+            );
+        }
         let mut terminal_mappings = Vec::new();
         if self.auto_newline {
             terminal_mappings.push((
@@ -164,11 +216,18 @@ impl ScannerConfig {
                 terminal_names[LINE_COMMENT as usize].clone(),
             ));
         }
-        if !self.block_comments.is_empty() {
+        if !self.block_comments.is_empty() || !self.nested_block_comments.is_empty() {
+            // Only the start of a nested block comment is matched by the scanner.
+            self.nested_block_comment_delimiters()?;
             let block_comments_rx = self
                 .block_comments
                 .iter()
                 .map(|(s, e)| Self::format_block_comment(s, e))
+                .chain(
+                    self.nested_block_comments
+                        .iter()
+                        .map(|(s, _)| Ok(s.clone())),
+                )
                 .collect::<Result<Vec<String>>>()?
                 .join("|");
             terminal_mappings.push((
@@ -179,13 +238,30 @@ impl ScannerConfig {
             ));
         }
 
+        // The terminals of the offside rule are synthesized by the runtime and external terminals
+        // are scanned by user code in any scanner state
+        let unscanned_terminals = grammar_config
+            .scanner_configurations
+            .iter()
+            .filter_map(|sc| sc.offside_tokens)
+            .flat_map(|o| [o.indent, o.dedent, o.newline])
+            .chain(
+                grammar_config
+                    .scanner_configurations
+                    .iter()
+                    .flat_map(|sc| sc.external_tokens.iter().copied()),
+            )
+            .collect::<Vec<TerminalIndex>>();
+
         let mut terminal_mappings = cfg.get_ordered_terminals().iter().enumerate().fold(
             terminal_mappings,
             |mut acc, (i, (t, k, l, s))| {
-                if s.contains(&self.scanner_state) {
+                let terminal_index = i as TerminalIndex + FIRST_USER_TOKEN;
+                if s.contains(&self.scanner_state) && !unscanned_terminals.contains(&terminal_index)
+                {
                     acc.push((
                         k.expand(t),
-                        i as TerminalIndex + FIRST_USER_TOKEN,
+                        terminal_index,
                         l.as_ref()
                             .map(|l| (l.is_positive, l.kind.expand(&l.pattern))),
                         terminal_names[i + FIRST_USER_TOKEN as usize].clone(),
@@ -208,6 +284,46 @@ impl ScannerConfig {
         Ok((terminal_mappings, self.transitions.clone()))
     }
 
+    ///
+    /// Returns the literal start and end delimiters of the nested block comments.
+    /// The runtime compares them verbatim with the input, hence they must not contain any regex
+    /// meta characters besides escaped ones.
+    ///
+    pub fn nested_block_comment_delimiters(&self) -> Result<Vec<(String, String)>> {
+        self.nested_block_comments
+            .iter()
+            .map(|(s, e)| Ok((Self::literal_text(s)?, Self::literal_text(e)?)))
+            .collect()
+    }
+
+    /// Converts an escaped regex pattern without any meta characters into its literal text
+    fn literal_text(pattern: &str) -> Result<String> {
+        let mut text = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some(c) if !c.is_alphanumeric() => text.push(c),
+                    _ => bail!(
+                        "Nested block comment delimiter '{}' must be a literal text.",
+                        pattern
+                    ),
+                },
+                '.' | '^' | '$' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' => {
+                    bail!(
+                        "Nested block comment delimiter '{}' must be a literal text.",
+                        pattern
+                    )
+                }
+                _ => text.push(c),
+            }
+        }
+        if text.is_empty() {
+            bail!("Nested block comment delimiter is empty.");
+        }
+        Ok(text)
+    }
+
     /// Formats a block comment
     /// The block comment is formatted as a regular expression.
     /// We need to specify the repeated expression for the comment content in such a way that
@@ -309,10 +425,13 @@ impl Default for ScannerConfig {
             scanner_state: 0,
             line_comments: Vec::new(),
             block_comments: Vec::new(),
+            nested_block_comments: Vec::new(),
             auto_newline: true,
             auto_ws: true,
             allow_unmatched: false,
             skip_tokens: Vec::new(),
+            offside_tokens: None,
+            external_tokens: Vec::new(),
             transitions: Vec::new(),
         }
     }
@@ -324,9 +443,18 @@ impl Display for ScannerConfig {
         writeln!(f, "scanner_state: {}", self.scanner_state)?;
         writeln!(f, "line_comments: {:?}", self.line_comments)?;
         writeln!(f, "block_comments: {:?}", self.block_comments)?;
+        if !self.nested_block_comments.is_empty() {
+            writeln!(f, "nested_block_comments: {:?}", self.nested_block_comments)?;
+        }
         writeln!(f, "auto_newline: {:?}", self.auto_newline)?;
         writeln!(f, "auto_ws: {:?}", self.auto_ws)?;
         writeln!(f, "skip_tokens: {:?}", self.skip_tokens)?;
+        if let Some(offside_tokens) = &self.offside_tokens {
+            writeln!(f, "offside_tokens: {offside_tokens:?}")?;
+        }
+        if !self.external_tokens.is_empty() {
+            writeln!(f, "external_tokens: {:?}", self.external_tokens)?;
+        }
         self.transitions
             .iter()
             .try_for_each(|(k, v)| write!(f, "on {k} enter {v};"))
@@ -575,6 +703,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_nested_block_comment_delimiters() {
+        let config = ScannerConfig::default()
+            .with_nested_block_comments(vec![(r"\(\*".to_owned(), r"\*\)".to_owned())]);
+        assert_eq!(
+            vec![("(*".to_owned(), "*)".to_owned())],
+            config.nested_block_comment_delimiters().unwrap()
+        );
+
+        let config = ScannerConfig::default()
+            .with_nested_block_comments(vec![(r"/\*+".to_owned(), r"\*/".to_owned())]);
+        assert!(config.nested_block_comment_delimiters().is_err());
+    }
+
+    #[test]
+    fn test_nested_block_comment_build_information() {
+        let grammar_config = crate::obtain_grammar_config_from_string(
+            r#"%start S
+%block_comment "/\*" "\*/"
+%nested_block_comment "\(\*" "\*\)"
+%%
+S: "s";
+"#,
+            false,
+        )
+        .unwrap();
+        let terminal_names = crate::generators::generate_terminal_names(&grammar_config);
+        let (terminal_mappings, _) = grammar_config.scanner_configurations[0]
+            .generate_build_information(&grammar_config, &terminal_names)
+            .unwrap();
+        let block_comment = terminal_mappings
+            .iter()
+            .find(|(_, index, _, _)| *index == BLOCK_COMMENT)
+            .unwrap();
+        assert_eq!(r"/\*/?([^/]|[^*]/)*\*/|\(\*", block_comment.0);
+    }
+
+    #[test]
+    fn test_offside_tokens_build_information() {
+        let grammar_config = crate::obtain_grammar_config_from_string(
+            r#"%start Block
+%offside Indent Dedent Eol
+%%
+Block: { Line };
+Line: Identifier [ Colon Eol Indent Block Dedent ] Eol;
+Identifier: /[a-z]+/;
+Colon: ':';
+Indent: '<indent>';
+Dedent: '<dedent>';
+Eol: '<eol>';
+"#,
+            false,
+        )
+        .unwrap();
+        let scanner_config = &grammar_config.scanner_configurations[0];
+        let offside_tokens = scanner_config.offside_tokens.unwrap();
+        let terminal_names = crate::generators::generate_terminal_names(&grammar_config);
+        assert_eq!(
+            ["Indent", "Dedent", "Eol"],
+            [
+                offside_tokens.indent,
+                offside_tokens.dedent,
+                offside_tokens.newline
+            ]
+            .map(|i| terminal_names[i as usize].as_str())
+        );
+        let (terminal_mappings, _) = scanner_config
+            .generate_build_information(&grammar_config, &terminal_names)
+            .unwrap();
+        assert!(
+            terminal_mappings
+                .iter()
+                .all(|(_, _, _, name)| !["Indent", "Dedent", "Eol"].contains(&name.as_str()))
+        );
+    }
+
+    #[test]
+    fn test_offside_requires_automatic_whitespace() {
+        let result = crate::obtain_grammar_config_from_string(
+            r#"%start S
+%auto_ws_off
+%offside Indent Dedent Newline
+%%
+S: Indent Dedent Newline;
+Indent: '<indent>';
+Dedent: '<dedent>';
+Newline: '<newline>';
+"#,
+            false,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_external_tokens_build_information() {
+        let grammar_config = crate::obtain_grammar_config_from_string(
+            r#"%start Items
+%on Hash %enter Raw
+%scanner Raw {
+    %external RawString
+    %on RawString %enter INITIAL
+}
+%%
+Items: { Item };
+Item: Name | Hash RawString;
+Name: /[a-z]+/;
+Hash: '#';
+RawString: <Raw>'<raw string>';
+"#,
+            false,
+        )
+        .unwrap();
+        let terminal_names = crate::generators::generate_terminal_names(&grammar_config);
+        assert!(
+            grammar_config.scanner_configurations[0]
+                .external_tokens
+                .is_empty()
+        );
+        let external_tokens = &grammar_config.scanner_configurations[1].external_tokens;
+        assert_eq!(
+            vec!["RawString"],
+            external_tokens
+                .iter()
+                .map(|i| terminal_names[*i as usize].as_str())
+                .collect::<Vec<_>>()
+        );
+        grammar_config
+            .scanner_configurations
+            .iter()
+            .for_each(|scanner_config| {
+                let (terminal_mappings, _) = scanner_config
+                    .generate_build_information(&grammar_config, &terminal_names)
+                    .unwrap();
+                assert!(
+                    terminal_mappings
+                        .iter()
+                        .all(|(_, _, _, name)| name != "RawString")
+                );
+            });
+    }
+
     #[test]
     fn test_block_comment_mixed_delimiters_sequence() {
         use scanner11::Scanner11 as S;
@@ -229,6 +229,9 @@ impl Type {
         match &self.entrails {
             TypeEntrails::None => "*TypeError*".to_string(),
             TypeEntrails::Token => format!("Token{lifetime}"),
+            TypeEntrails::Box(r) if symbol_table.arena_allocation => {
+                format!("&'t {}", symbol_table.symbol(*r).to_rust())
+            }
             TypeEntrails::Box(r) => {
                 format!("Box<{}>", symbol_table.symbol(*r).to_rust())
             }
@@ -239,17 +242,21 @@ impl Type {
             TypeEntrails::Struct => format!("{my_type_name}{lifetime}"),
             TypeEntrails::Enum => format!("{my_type_name}{lifetime}"),
             TypeEntrails::EnumVariant(t) => {
-                format!(
-                    "{}({}),",
-                    my_type_name,
-                    symbol_table.symbol_as_type(*t).to_rust(),
-                )
+                let inner_type = symbol_table.symbol_as_type(*t);
+                // The lists of the ASTType are still collected in vectors
+                let inner_type = if let TypeEntrails::Vec(r) = inner_type.entrails() {
+                    Self::vec_to_rust(symbol_table, *r)
+                } else {
+                    inner_type.to_rust()
+                };
+                format!("{my_type_name}({inner_type}),")
             }
-            TypeEntrails::Vec(r) => format!(
-                "Vec<{}{}>",
+            TypeEntrails::Vec(r) if symbol_table.arena_allocation => format!(
+                "&'t [{}{}]",
                 symbol_table.symbol(*r).name(),
                 symbol_table.lifetime(*r)
             ),
+            TypeEntrails::Vec(r) => Self::vec_to_rust(symbol_table, *r),
             TypeEntrails::Trait => format!("trait {my_type_name}{lifetime}"),
             TypeEntrails::Function(f) => f.to_rust(my_type_name),
             TypeEntrails::Option(o) => format!("Option<{}>", symbol_table.symbol(*o).to_rust()),
@@ -263,6 +270,14 @@ impl Type {
         }
     }
 
+    fn vec_to_rust(symbol_table: &SymbolTable, r: SymbolId) -> String {
+        format!(
+            "Vec<{}{}>",
+            symbol_table.symbol(r).name(),
+            symbol_table.lifetime(r)
+        )
+    }
+
     /// Returns the name of the wrapped type
     pub(crate) fn inner_name(&self, symbol_table: &SymbolTable, my_symbol: &Symbol) -> String {
         let is_user_defined_type = matches!(self.entrails, TypeEntrails::UserDefinedType(..));
@@ -623,6 +638,9 @@ pub struct SymbolTable {
     // All scopes
     // The one and only global scope has always index 0
     pub(crate) scopes: Vec<Scope>,
+
+    // If true, boxed types and vectors are generated as boxes and vectors in an arena
+    pub(crate) arena_allocation: bool,
 }
 
 impl SymbolTable {
@@ -637,6 +655,7 @@ impl SymbolTable {
         Self {
             symbols: Vec::new(),
             scopes: vec![Scope::new(None, Self::GLOBAL_SCOPE)],
+            arena_allocation: false,
         }
     }
 
@@ -861,6 +880,19 @@ impl SymbolTable {
 
     /// Propagates lifetimes from the bottom up
     pub(crate) fn propagate_lifetimes(&mut self) {
+        if self.arena_allocation {
+            // References into the arena have the lifetime 't
+            for symbol in &mut self.symbols {
+                symbol.has_lifetime |= symbol.sem() != SymbolAttribute::Clipped
+                    && matches!(
+                        symbol.kind,
+                        SymbolKind::Type(Type {
+                            entrails: TypeEntrails::Box(_) | TypeEntrails::Vec(_),
+                            ..
+                        })
+                    );
+            }
+        }
         let mut symbols_with_lifetime = self.symbols_with_lifetime();
         let mut count = symbols_with_lifetime.len();
         let mut old_count = 0;
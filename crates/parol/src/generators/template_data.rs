@@ -77,6 +77,33 @@ impl std::fmt::Display for UserTraitFunctionData {
     }
 }
 
+#[derive(Builder, Debug, Default)]
+pub(crate) struct UserTraitPredicateData {
+    pub fn_name: String,
+    pub predicate_name: String,
+    pub token_lifetime: &'static str,
+}
+
+impl std::fmt::Display for UserTraitPredicateData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let UserTraitPredicateData {
+            fn_name,
+            predicate_name,
+            token_lifetime,
+        } = self;
+        writeln!(
+            f,
+            "/// Semantic predicate '&{{{predicate_name}}}'
+            /// Returns false if the guarded alternative must not be chosen for the lookahead token."
+        )?;
+        f.write_fmt(ume::ume! {
+            fn #fn_name(&mut self, _lookahead: &Token #token_lifetime) -> Result<bool> {
+                Ok(true)
+            }
+        })
+    }
+}
+
 #[derive(Builder, Debug, Default)]
 pub(crate) struct UserTraitFunctionStackPopData {
     pub arg_name: String,
@@ -114,9 +141,12 @@ pub(crate) struct UserTraitData<'a> {
     pub non_terminal_types: StrVec,
     pub ast_type_decl: String,
     pub ast_type_has_lifetime: bool,
+    pub arena_allocation: bool,
     pub trait_functions: StrVec,
     pub trait_caller: StrVec,
     pub user_trait_functions: StrVec,
+    #[builder(default)]
+    pub predicate_caller: StrVec,
 }
 
 impl std::fmt::Display for UserTraitData<'_> {
@@ -129,9 +159,11 @@ impl std::fmt::Display for UserTraitData<'_> {
             non_terminal_types,
             ast_type_decl,
             ast_type_has_lifetime,
+            arena_allocation,
             trait_functions,
             trait_caller,
             user_trait_functions,
+            predicate_caller,
         } = self;
 
         write!(
@@ -239,7 +271,7 @@ impl std::fmt::Display for UserTraitData<'_> {
                 // -------------------------------------------------------------------------------------------------
                 ")?;
 
-        let phantom_data_field = if *ast_type_has_lifetime {
+        let mut phantom_data_field = if *ast_type_has_lifetime {
             "".into()
         } else {
             let comment = "\n// Just to hold the lifetime generated by parol\n";
@@ -249,6 +281,16 @@ impl std::fmt::Display for UserTraitData<'_> {
             }
             .to_string()
         };
+        let mut new_params = ume::ume!(user_grammar: &'u mut dyn #trait_name #lifetime).to_string();
+        if *arena_allocation {
+            let comment = "\n// Arena the nodes of the AST are allocated in\n";
+            phantom_data_field += &ume::ume! {
+                #comment
+                arena: &'t parol_runtime::arena::Bump,
+            }
+            .to_string();
+            new_params += ", arena: &'t parol_runtime::arena::Bump";
+        }
         writeln!(
             f,
             "
@@ -272,7 +314,7 @@ impl std::fmt::Display for UserTraitData<'_> {
             }
         })?;
 
-        let phantom_data_field_default = if *ast_type_has_lifetime {
+        let mut phantom_data_field_default = if *ast_type_has_lifetime {
             "".into()
         } else {
             ume::ume! {
@@ -280,6 +322,9 @@ impl std::fmt::Display for UserTraitData<'_> {
             }
             .to_string()
         };
+        if *arena_allocation {
+            phantom_data_field_default += "arena,";
+        }
         writeln!(
             f,
             "
@@ -295,7 +340,7 @@ impl std::fmt::Display for UserTraitData<'_> {
             "#;
         f.write_fmt(ume::ume! {
             impl<'t, 'u> #auto_name<'t, 'u> {
-                pub fn new(user_grammar: &'u mut dyn #trait_name #lifetime) -> Self {
+                pub fn new(#new_params) -> Self {
                     Self {
                         user_grammar,
                         item_stack: Vec::new(),
@@ -338,7 +383,8 @@ impl std::fmt::Display for UserTraitData<'_> {
         })?;
 
         writeln!(f, "\n")?;
-        f.write_fmt(ume::ume! {
+        if predicate_caller.is_empty() {
+            f.write_fmt(ume::ume! {
                 #blank_line
                 impl<'t> UserActionsTrait<'t> for #auto_name<'t, '_> {
                     #call_semantic_action_for_production_number_doc
@@ -357,7 +403,35 @@ impl std::fmt::Display for UserTraitData<'_> {
                     }
                 }
             })?;
-
+        } else {
+            // The semantic predicates are evaluated by the user grammar
+            f.write_fmt(ume::ume! {
+                #blank_line
+                impl<'t> UserActionsTrait<'t> for #auto_name<'t, '_> {
+                    #call_semantic_action_for_production_number_doc
+                    fn call_semantic_action_for_production_number(
+                        &mut self,
+                        prod_num: usize,
+                        children: &[ParseTreeType<'t>]) -> Result<()> {
+                        match prod_num {
+                            #trait_caller
+                            _ => Err(ParserError::InternalError(format!("Unhandled production number: {prod_num}")).into()),
+                        }
+                    }
+                    #blank_line
+                    fn on_comment(&mut self, token: Token<'t>) {
+                        self.user_grammar.on_comment(token)
+                    }
+                    #blank_line
+                    fn evaluate_predicate(&mut self, predicate: usize, lookahead: &Token<'t>) -> Result<bool> {
+                        match predicate {
+                            #predicate_caller
+                            _ => Err(ParserError::InternalError(format!("Unhandled predicate index: {predicate}")).into()),
+                        }
+                    }
+                }
+            })?;
+        }
         Ok(())
     }
 }
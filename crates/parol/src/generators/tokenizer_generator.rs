@@ -0,0 +1,240 @@
+use crate::config::CommonGeneratorConfig;
+use crate::generators::GrammarConfig;
+use crate::generators::lexer_generator::generate_lexer_source;
+use crate::generators::node_kind_enum_generator::{
+    generate_terminal_kind_display, generate_terminal_kind_enum, generate_terminal_kind_impl,
+};
+use crate::generators::parser_generator::{
+    TokenStreamFeatures, generate_nested_block_comments_by_state_source,
+    generate_offside_tokens_by_state_source, generate_skip_tokens_by_state_source,
+    generate_token_stream_options_source, get_scanner_module_name, get_scanner_type_name,
+};
+use anyhow::{Result, bail};
+
+// ---------------------------------------------------
+// Part of the Public API
+// *Changes will affect crate's version according to semver*
+// ---------------------------------------------------
+///
+/// Generates the output file of the lexer-only mode.
+///
+/// It contains the scanner, the enum `TerminalKind` and the type `Tokenizer` that iterates over
+/// the tokens of an input without a parser.
+///
+pub fn generate_tokenizer_source<C: CommonGeneratorConfig>(
+    grammar_config: &GrammarConfig,
+    config: &C,
+) -> Result<String> {
+    if grammar_config
+        .scanner_configurations
+        .iter()
+        .any(|sc| !sc.external_tokens.is_empty())
+    {
+        bail!("External terminals are not supported in lexer-only mode");
+    }
+    if config.contextual_lexing() {
+        bail!("Contextual lexing requires a parser and is not supported in lexer-only mode");
+    }
+    // The lexer source starts with the terminal names, which only a parser uses. They are marked
+    // as allowed dead code in the output.
+    let lexer_source = generate_lexer_source(grammar_config, config)?;
+    let lexer_source = lexer_source.trim_start();
+    let skip_tokens_by_state_source = generate_skip_tokens_by_state_source(grammar_config);
+    let nested_block_comments_by_state_source =
+        generate_nested_block_comments_by_state_source(grammar_config)?;
+    let offside_tokens_by_state_source = generate_offside_tokens_by_state_source(grammar_config);
+    let options = generate_token_stream_options_source(TokenStreamFeatures {
+        nested_block_comments: nested_block_comments_by_state_source.is_some(),
+        offside_tokens: offside_tokens_by_state_source.is_some(),
+        external_tokens: false,
+        contextual_lexing: false,
+        byte_input: config.byte_input(),
+        column_unit: config.column_unit(),
+    });
+
+    let terminals = grammar_config.generate_terminal_names();
+    let mut terminal_kind = Vec::new();
+    generate_terminal_kind_enum(&terminals, &mut terminal_kind)?;
+    terminal_kind.extend_from_slice(b"\n\n");
+    generate_terminal_kind_impl(&terminals, &mut terminal_kind)?;
+    terminal_kind.extend_from_slice(b"\n\n");
+    generate_terminal_kind_display(&terminals, &mut terminal_kind)?;
+    let terminal_kind = String::from_utf8(terminal_kind)?;
+    let token_to_terminal_match_arms = terminals
+        .iter()
+        .map(|(i, t)| format!("            {i} => Some(Self::{t}),"))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let scanner_states_source = [
+        Some(skip_tokens_by_state_source),
+        nested_block_comments_by_state_source,
+        offside_tokens_by_state_source,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>()
+    .join("\n");
+    let scanner_module_name = get_scanner_module_name(config);
+    let scanner_type_name = get_scanner_type_name(config);
+
+    Ok(format!(
+        r#"// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{{LexerError, Token}};
+use scnr2::scanner;
+use std::path::Path;
+
+#[allow(dead_code)]
+{lexer_source}
+
+{scanner_states_source}
+
+{terminal_kind}
+
+#[allow(dead_code)]
+impl TerminalKind {{
+    /// Returns the kind of the given token or None for unmatched text and the error terminal
+    pub fn from_token(token: &Token<'_>) -> Option<Self> {{
+        match token.token_type {{
+{token_to_terminal_match_arms}
+            _ => None,
+        }}
+    }}
+}}
+
+///
+/// Iterator over the tokens of an input, use [TerminalKind::from_token] to obtain their kinds.
+///
+/// By default it yields the tokens a parser would receive. With `with_skip_tokens(true)` also
+/// newlines, whitespace, comments and unmatched text are yielded, so that the tokens cover the
+/// whole input.
+///
+pub struct Tokenizer<'t> {{
+    tokenizer: parol_runtime::lexer::Tokenizer<'t, fn(char) -> Option<usize>>,
+}}
+
+#[allow(dead_code)]
+impl<'t> Tokenizer<'t> {{
+    /// Creates a tokenizer for the given input
+    pub fn new<T>(input: &'t str, file_name: T) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {{
+        use {scanner_module_name}::{scanner_type_name};
+        let scanner = {scanner_type_name}::new();
+        let tokenizer = parol_runtime::lexer::Tokenizer::new(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &({scanner_type_name}::match_function as fn(char) -> Option<usize>),
+            {options},
+        )?;
+        Ok(Self {{ tokenizer }})
+    }}
+
+    /// Sets whether newlines, whitespace, comments and unmatched text are yielded, too
+    pub fn with_skip_tokens(self, skip_tokens: bool) -> Self {{
+        Self {{
+            tokenizer: self.tokenizer.with_skip_tokens(skip_tokens),
+        }}
+    }}
+
+    /// Returns the index of the scanner state in which the scanning continues
+    pub fn scanner_state(&self) -> usize {{
+        self.tokenizer.scanner_state()
+    }}
+
+    /// Returns the name of the scanner state in which the scanning continues
+    pub fn scanner_state_name(&self) -> &str {{
+        self.tokenizer.scanner_state_name()
+    }}
+}}
+
+impl<'t> Iterator for Tokenizer<'t> {{
+    type Item = Result<Token<'t>, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {{
+        self.tokenizer.next()
+    }}
+}}
+"#
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct TokenizerTestConfig {
+        contextual_lexing: bool,
+    }
+
+    impl CommonGeneratorConfig for TokenizerTestConfig {
+        fn user_type_name(&self) -> &str {
+            "Highlighter"
+        }
+
+        fn module_name(&self) -> &str {
+            "highlighter"
+        }
+
+        fn minimize_boxed_types(&self) -> bool {
+            false
+        }
+
+        fn range(&self) -> bool {
+            false
+        }
+
+        fn node_kind_enums(&self) -> bool {
+            false
+        }
+
+        fn contextual_lexing(&self) -> bool {
+            self.contextual_lexing
+        }
+    }
+
+    const GRAMMAR: &str = r#"%start Items
+%line_comment "//"
+%offside Indent Dedent Eol
+%%
+Items: { Item };
+Item: Name [ Indent Items Dedent ] Eol;
+Name: /[a-z]+/;
+Indent: '<indent>';
+Dedent: '<dedent>';
+Eol: '<eol>';
+"#;
+
+    #[test]
+    fn tokenizer_source_contains_scanner_and_tokenizer() {
+        let grammar_config = crate::obtain_grammar_config_from_string(GRAMMAR, false).unwrap();
+        let source = generate_tokenizer_source(&grammar_config, &TokenizerTestConfig::default())
+            .unwrap()
+            .split_whitespace()
+            .collect::<String>();
+        assert!(source.contains("scanner!{HighlighterScanner{"));
+        assert!(source.contains("pubenumTerminalKind{"));
+        assert!(source.contains("5=>Some(Self::Name),"));
+        assert!(source.contains("pubstructTokenizer<'t>{"));
+        assert!(source.contains("offside_tokens_by_state:OFFSIDE_TOKENS_BY_SCANNER_STATE,"));
+        // No parser is generated
+        assert!(!source.contains("LOOKAHEAD_AUTOMATA"));
+    }
+
+    #[test]
+    fn tokenizer_source_rejects_contextual_lexing() {
+        let grammar_config = crate::obtain_grammar_config_from_string(GRAMMAR, false).unwrap();
+        let config = TokenizerTestConfig {
+            contextual_lexing: true,
+        };
+        assert!(generate_tokenizer_source(&grammar_config, &config).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use super::symbol_table_facade::{InstanceFacade, SymbolFacade, TypeFacade};
 use super::template_data::{
     NonTerminalTypeEnum, NonTerminalTypeStruct, RangeCalculationBuilder,
     UserTraitCallerFunctionDataBuilder, UserTraitDataBuilder, UserTraitFunctionDataBuilder,
-    UserTraitFunctionStackPopDataBuilder,
+    UserTraitFunctionStackPopDataBuilder, UserTraitPredicateData,
 };
 use crate::config::{CommonGeneratorConfig, UserTraitGeneratorConfig};
 use crate::generators::GrammarConfig;
@@ -193,7 +193,7 @@ impl<'a> UserTraitGenerator<'a> {
                 // If the production is AddToCollection then instance semantic must not be RepetitionAnchor
                 (sem != ProductionAttribute::AddToCollection || arg_inst.sem() != SymbolAttribute::RepetitionAnchor)
             {
-                let mut result = format!("Box::new({arg_name})");
+                let mut result = arg_name.to_string();
                 if let TypeEntrails::Box(inner_type) = arg_type.entrails() {
                     let inner_type_symbol = symbol_table.symbol_as_type(*inner_type);
                     if matches!(
@@ -202,11 +202,11 @@ impl<'a> UserTraitGenerator<'a> {
                     ) {
                         // The inner type is a user-defined type, so we need to convert it
                         result = format!(
-                            r#"Box::new((&{arg_name}).try_into().map_err(parol_runtime::ParolError::UserError)?)"#
+                            r#"(&{arg_name}).try_into().map_err(parol_runtime::ParolError::UserError)?"#
                         );
                     }
                 }
-                result
+                Self::format_box_call(symbol_table, &result)
             } else if matches!(
                 *arg_type.entrails(),
                 TypeEntrails::UserDefinedType(MetaSymbolKind::NonTerminal(_), _)
@@ -217,10 +217,21 @@ impl<'a> UserTraitGenerator<'a> {
             } else if let TypeEntrails::Option(t) = arg_type.entrails() {
                 let inner_type = symbol_table.symbol_as_type(*t);
                 if let TypeEntrails::Box(_) = inner_type.entrails() {
-                    format!("{arg_name}.map(Box::new)")
+                    if symbol_table.arena_allocation {
+                        format!(
+                            "{arg_name}.map(|{arg_name}| {})",
+                            Self::format_box_call(symbol_table, arg_name)
+                        )
+                    } else {
+                        format!("{arg_name}.map(Box::new)")
+                    }
                 } else {
                     arg_name.to_string()
                 }
+            } else if symbol_table.arena_allocation
+                && matches!(*arg_type.entrails(), TypeEntrails::Vec(_))
+            {
+                format!("&*self.arena.alloc_slice_fill_iter({arg_name})")
             } else {
                 arg_name.to_string()
             };
@@ -234,6 +245,15 @@ impl<'a> UserTraitGenerator<'a> {
         Ok(())
     }
 
+    /// Formats the expression that moves the given value into a box or into the arena
+    fn format_box_call(symbol_table: &SymbolTable, value: &str) -> String {
+        if symbol_table.arena_allocation {
+            format!("&*self.arena.alloc({value})")
+        } else {
+            format!("Box::new({value})")
+        }
+    }
+
     fn generate_result_builder(
         &self,
         code: &mut StrVec,
@@ -346,11 +366,11 @@ impl<'a> UserTraitGenerator<'a> {
                     .ok_or_else(|| anyhow!("Enum variant not found {}", fn_name))?;
                 if is_box {
                     code.push(format!(
-                        "let {}_built = {}::{}(Box::new({}_built));",
+                        "let {}_built = {}::{}({});",
                         fn_name,
                         nt_type.name(),
                         enum_variant_name,
-                        fn_name
+                        Self::format_box_call(symbol_table, &format!("{fn_name}_built"))
                     ));
                 } else {
                     code.push(format!(
@@ -452,9 +472,9 @@ impl<'a> UserTraitGenerator<'a> {
                     let inner_type_symbol = symbol_table.symbol_as_type(*inner_type);
                     let inner_type_name = symbol_table.name(inner_type_symbol.my_id());
                     code.push(format!(
-                        "self.push(ASTType::{}(Some(Box::new({}_built))), context);",
+                        "self.push(ASTType::{}(Some({})), context);",
                         NmHlp::to_upper_camel_case(&function.non_terminal),
-                        inner_type_name,
+                        Self::format_box_call(symbol_table, &format!("{inner_type_name}_built")),
                     ));
                 }
                 _ => {
@@ -589,6 +609,9 @@ impl<'a> UserTraitGenerator<'a> {
         if config.minimize_boxed_types() {
             type_info.minimize_boxed_types();
         }
+        if config.arena_allocation() {
+            type_info.arena_allocation();
+        }
         type_info.set_grammar_type(grammar_type);
         type_info.build(self.grammar_config)?;
 
@@ -659,7 +682,7 @@ impl<'a> UserTraitGenerator<'a> {
                 self.generate_single_adapter_function(a, type_info, grammar_type, acc)
             })?;
 
-        let user_trait_functions = type_info
+        let mut user_trait_functions = type_info
             .get_user_actions()
             .iter()
             .try_fold(StrVec::new(0).first_line_no_indent(), |acc, fn_id| {
@@ -682,6 +705,8 @@ impl<'a> UserTraitGenerator<'a> {
         trace!("{type_info}");
 
         let ast_type_has_lifetime = type_info.symbol_table.has_lifetime(type_info.ast_enum_type);
+        let predicate_caller =
+            self.generate_predicate_functions(ast_type_has_lifetime, &mut user_trait_functions)?;
         let user_trait_data = UserTraitDataBuilder::default()
             .user_type_name(config.user_type_name())
             .range(config.range())
@@ -696,15 +721,63 @@ impl<'a> UserTraitGenerator<'a> {
             .non_terminal_types(non_terminal_types)
             .ast_type_decl(ast_type_decl)
             .ast_type_has_lifetime(ast_type_has_lifetime)
+            .arena_allocation(config.arena_allocation())
             .trait_functions(trait_functions)
             .trait_caller(trait_caller)
             .user_trait_functions(user_trait_functions)
+            .predicate_caller(predicate_caller)
             .build()
             .unwrap();
 
         Ok(format!("{user_trait_data}"))
     }
 
+    ///
+    /// Adds a trait function for each semantic predicate of the grammar and returns the match
+    /// arms that dispatch the predicate indices to these functions.
+    ///
+    fn generate_predicate_functions(
+        &self,
+        ast_type_has_lifetime: bool,
+        user_trait_functions: &mut StrVec,
+    ) -> Result<StrVec> {
+        let action_names = self
+            .grammar_config
+            .cfg
+            .get_non_terminal_set()
+            .iter()
+            .map(|n| NmHlp::to_lower_snake_case(n))
+            .collect::<Vec<_>>();
+        let token_lifetime = if ast_type_has_lifetime {
+            "<'t>"
+        } else {
+            "<'_>"
+        };
+        self.grammar_config
+            .cfg
+            .get_predicate_names()
+            .iter()
+            .enumerate()
+            .try_fold(StrVec::new(12), |mut acc, (index, predicate_name)| {
+                let fn_name = NmHlp::to_lower_snake_case(predicate_name);
+                if fn_name == "on_comment" || action_names.contains(&fn_name) {
+                    bail!(
+                        "The semantic predicate '{predicate_name}' clashes with the user action '{fn_name}'"
+                    );
+                }
+                acc.push(format!("{index} => self.user_grammar.{fn_name}(lookahead),"));
+                user_trait_functions.push(format!(
+                    "{}",
+                    UserTraitPredicateData {
+                        fn_name,
+                        predicate_name: predicate_name.to_string(),
+                        token_lifetime,
+                    }
+                ));
+                Ok(acc)
+            })
+    }
+
     fn generate_single_adapter_function(
         &self,
         a: (&usize, &SymbolId),
@@ -281,6 +281,19 @@ impl Cfg {
             })
     }
 
+    ///
+    /// Returns the names of the semantic predicates used in the grammar in alphabetical order.
+    /// The position of a name in this list is the index of the predicate in generated parsers.
+    ///
+    pub fn get_predicate_names(&self) -> Vec<&str> {
+        self.pr
+            .iter()
+            .filter_map(|p| p.get_predicate())
+            .collect::<BTreeSet<&str>>()
+            .into_iter()
+            .collect()
+    }
+
     ///
     /// Returns a vector of production references with the LHS matching the given non-terminal n
     ///
@@ -20,9 +20,15 @@ pub type Rhs = Vec<Symbol>;
 // ---------------------------------------------------
 ///
 /// Production type
+/// The optional last member is the name of the semantic predicate that guards the production.
 ///
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
-pub struct Pr(pub Symbol, pub Rhs, pub ProductionAttribute);
+pub struct Pr(
+    pub Symbol,
+    pub Rhs,
+    pub ProductionAttribute,
+    pub Option<String>,
+);
 
 impl Display for Pr {
     ///
@@ -72,6 +78,7 @@ impl Default for Pr {
             Symbol::n(""),
             Rhs::default(),
             ProductionAttribute::default(),
+            None,
         )
     }
 }
@@ -82,7 +89,7 @@ impl Pr {
         if !r.iter().all(Self::is_allowed_symbol) {
             panic!("Unexpected symbol kind!");
         }
-        Self(Symbol::n(n), r, ProductionAttribute::default())
+        Self(Symbol::n(n), r, ProductionAttribute::default(), None)
     }
 
     pub(crate) fn with_attribute(mut self, attribute: ProductionAttribute) -> Self {
@@ -90,6 +97,17 @@ impl Pr {
         self
     }
 
+    /// Sets the name of the semantic predicate that guards the production
+    pub fn with_predicate(mut self, predicate: &str) -> Self {
+        self.3 = Some(predicate.to_owned());
+        self
+    }
+
+    /// Returns the name of the semantic predicate that guards the production if any
+    pub fn get_predicate(&self) -> Option<&str> {
+        self.3.as_deref()
+    }
+
     /// Returns a clone of the non-terminal
     pub fn get_n(&self) -> String {
         self.0.get_n().unwrap()
@@ -147,6 +165,21 @@ impl Pr {
 
     /// Formats self with the help of a scanner state resolver
     pub fn format<R, S>(&self, scanner_state_resolver: &R, user_type_resolver: &S) -> Result<String>
+    where
+        R: Fn(&[usize]) -> String,
+        S: Fn(&str) -> Option<String>,
+    {
+        let (lhs, rhs) = self.format_parts(scanner_state_resolver, user_type_resolver)?;
+        Ok(format!("{}: {};", lhs, rhs.join(" ")))
+    }
+
+    /// Formats the left-hand side and each item of the right-hand side of self separately.
+    /// A semantic predicate is the first item of the right-hand side.
+    pub fn format_parts<R, S>(
+        &self,
+        scanner_state_resolver: &R,
+        user_type_resolver: &S,
+    ) -> Result<(String, Vec<String>)>
     where
         R: Fn(&[usize]) -> String,
         S: Fn(&str) -> Option<String>,
@@ -155,19 +188,17 @@ impl Pr {
         self.2
             .decorate(&mut s, &self.0)
             .map_err(|e| anyhow!("Decorate error!: {}", e))?;
-        Ok(format!(
-            "{}: {};",
-            s,
-            self.1
-                .iter()
-                .try_fold(Vec::new(), |mut acc: Vec<String>, s| {
-                    s.format(scanner_state_resolver, user_type_resolver)
-                        .map(|s| {
-                            acc.push(s);
-                            acc
-                        })
-                })
-                .map(|v| v.join(" "))?
-        ))
+        let predicate = self.get_predicate().map(|p| format!("&{{{p}}}"));
+        let rhs = self.1.iter().try_fold(
+            predicate.into_iter().collect::<Vec<String>>(),
+            |mut acc, s| {
+                s.format(scanner_state_resolver, user_type_resolver)
+                    .map(|s| {
+                        acc.push(s);
+                        acc
+                    })
+            },
+        )?;
+        Ok((s, rhs))
     }
 }
@@ -22,6 +22,9 @@ pub enum TerminalKind {
     Regex,
     /// Meta characters will be escaped when regex for scanner is created
     Raw,
+    /// Like [TerminalKind::Raw] but letters match regardless of their case.
+    /// Written with the suffix `i`, e.g. `'begin'i`
+    RawCaseInsensitive,
 }
 
 impl TerminalKind {
@@ -70,7 +73,15 @@ impl TerminalKind {
         match self {
             TerminalKind::Legacy => '"',
             TerminalKind::Regex => '/',
-            TerminalKind::Raw => '\'',
+            TerminalKind::Raw | TerminalKind::RawCaseInsensitive => '\'',
+        }
+    }
+
+    /// Retrieves the syntactic suffix that follows the closing delimiter
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            TerminalKind::RawCaseInsensitive => "i",
+            _ => "",
         }
     }
 
@@ -87,18 +98,17 @@ impl TerminalKind {
     /// assert!(TerminalKind::Raw.behaves_like(TerminalKind::Raw));
     /// assert!(!TerminalKind::Raw.behaves_like(TerminalKind::Regex));
     /// assert!(!TerminalKind::Raw.behaves_like(TerminalKind::Legacy));
+    /// assert!(!TerminalKind::Raw.behaves_like(TerminalKind::RawCaseInsensitive));
     /// ```
     ///
     pub fn behaves_like(&self, other: TerminalKind) -> bool {
         match self {
             TerminalKind::Legacy | TerminalKind::Regex => match other {
                 TerminalKind::Legacy | TerminalKind::Regex => true,
-                TerminalKind::Raw => false,
-            },
-            TerminalKind::Raw => match other {
-                TerminalKind::Legacy | TerminalKind::Regex => false,
-                TerminalKind::Raw => true,
+                TerminalKind::Raw | TerminalKind::RawCaseInsensitive => false,
             },
+            TerminalKind::Raw => other == TerminalKind::Raw,
+            TerminalKind::RawCaseInsensitive => other == TerminalKind::RawCaseInsensitive,
         }
     }
 
@@ -132,10 +142,14 @@ impl TerminalKind {
     /// The actual preparation for scanner regex generation
     /// * Raw strings and legacy strings are not specially treaded
     /// * Regex strings are escaped using regex::escape
+    /// * Case-insensitive raw strings are escaped and prefixed with the flag `(?i)`
     pub fn expand(&self, term: &str) -> String {
         match self {
             crate::TerminalKind::Legacy | crate::TerminalKind::Regex => term.to_string(),
             crate::TerminalKind::Raw => Self::escape_raw_terminal(term),
+            crate::TerminalKind::RawCaseInsensitive => {
+                format!("(?i){}", Self::escape_raw_terminal(term))
+            }
         }
     }
 }
@@ -154,6 +168,15 @@ mod tests {
         assert_eq!(TerminalKind::Raw.expand("{"), r"\{");
         assert_eq!(TerminalKind::Raw.expand(r"a+b"), r"a\+b");
     }
+
+    #[test]
+    fn case_insensitive_raw_term_expansion_adds_flag() {
+        assert_eq!(
+            TerminalKind::RawCaseInsensitive.expand("begin"),
+            "(?i)begin"
+        );
+        assert_eq!(TerminalKind::RawCaseInsensitive.expand("a+b"), r"(?i)a\+b");
+    }
 }
 
 // ---------------------------------------------------
@@ -254,7 +277,8 @@ impl Terminal {
             Self::Trm(t, k, s, a, u, m, l) => {
                 let mut d = String::new();
                 let delimiter = k.delimiter();
-                a.decorate(&mut d, &format!("{delimiter}{t}{delimiter}"))
+                let suffix = k.suffix();
+                a.decorate(&mut d, &format!("{delimiter}{t}{delimiter}{suffix}"))
                     .map_err(|e| anyhow!("Decorate error!: {}", e))?;
                 if let Some(la) = l {
                     write!(d, " {}", la.to_par()).map_err(|e| anyhow!(e))?;
@@ -296,7 +320,8 @@ impl Display for Terminal {
         match self {
             Self::Trm(t, k, ..) => {
                 let delimiter = k.delimiter();
-                write!(f, "{delimiter}{t}{delimiter}")
+                let suffix = k.suffix();
+                write!(f, "{delimiter}{t}{delimiter}{suffix}")
             }
             Self::Eps => write!(f, "\u{03B5}"), // Lower creek letter Epsilon (ε)
             Self::End => write!(f, "$"),
@@ -39,7 +39,9 @@ pub mod analysis;
 pub use analysis::{
     CompiledTerminal, GrammarAnalysisError, KTuple, KTupleBuilder, KTuples, KTuplesBuilder,
     LR1State, LRAction, LRParseTable, LookaheadDFA, RecursiveNonTerminal, RelatedHint,
-    calculate_lalr1_parse_table, calculate_lookahead_dfas, detect_left_recursive_non_terminals,
+    calculate_entry_lookahead_dfas, calculate_fallback_lookahead_dfas,
+    calculate_lalr1_entry_parse_table, calculate_lalr1_parse_table, calculate_lookahead_dfas,
+    detect_left_recursive_non_terminals,
 };
 
 ///
@@ -50,6 +52,12 @@ pub mod conversions;
 
 pub use conversions::{render_dfa_dot_string, render_nt_dot_string, render_par_string};
 
+///
+/// Module with functionalities for measuring the grammar coverage of a test corpus
+///
+#[forbid(missing_docs)]
+pub mod coverage;
+
 ///
 /// Module with functionalities for error reporting
 ///
@@ -66,7 +74,7 @@ pub use generators::{
     GrammarConfig, GrammarTypeInfo, LanguageGenerator, ScannerConfig, UserTraitGenerator,
     check_and_transform_grammar, generate_lalr1_parser_export_model, generate_lalr1_parser_source,
     generate_lexer_source, generate_parser_export_model, generate_parser_export_model_from_grammar,
-    generate_parser_source, try_format,
+    generate_parser_source, generate_tokenizer_source, try_format,
 };
 
 ///
@@ -75,6 +83,18 @@ pub use generators::{
 pub mod parser;
 pub use parser::{ParolGrammar, ParolParserError, parse};
 
+///
+/// Module with parsers for grammar descriptions that are built at runtime
+///
+#[forbid(missing_docs)]
+pub mod runtime_parser;
+
+///
+/// Module with the emulation of the generated scanners
+///
+#[forbid(missing_docs)]
+pub mod scanner_emulation;
+
 ///
 /// Module with test template(s)
 ///
@@ -142,6 +142,17 @@ pub enum ParolParserError {
         location: Location,
     },
 
+    /// A non-terminal declared with `%entry` has no productions in the grammar.
+    #[error("Entry point '{entry}' is not a non-terminal of the grammar")]
+    UnknownEntryPoint {
+        /// The name of the entry point
+        entry: String,
+        /// Source file
+        input: PathBuf,
+        /// Location of the entry point's name
+        location: Location,
+    },
+
     /// Mixed scanner switching is not allowed - use either parser-based or scanner-based switching.
     /// Parser-based switching is done via the %sc, %push and %pop directives productions.
     /// Scanner-based switching is done via the %on directive in the header of the grammar file.
@@ -118,11 +118,12 @@ impl LookaheadExpression {
     pub fn to_par(&self) -> String {
         let delimiter = self.kind.delimiter();
         format!(
-            "{} {}{}{}",
+            "{} {}{}{}{}",
             if self.is_positive { "?=" } else { "?!" },
             delimiter,
             self.pattern,
-            delimiter
+            delimiter,
+            self.kind.suffix()
         )
     }
 }
@@ -131,11 +132,12 @@ impl Display for LookaheadExpression {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
         write!(
             f,
-            "{} {}{}{}",
+            "{} {}{}{}{}",
             if self.is_positive { "?=" } else { "?!" },
             self.kind.delimiter(),
             self.pattern,
-            self.kind.delimiter()
+            self.kind.delimiter(),
+            self.kind.suffix()
         )
     }
 }
@@ -246,7 +248,7 @@ impl Factor {
                 }
                 let delimiter = k.delimiter();
                 format!(
-                    "<{}>{}{}{}{}",
+                    "<{}>{}{}{}{}{}",
                     s.iter()
                         .map(|s| format!("{s}"))
                         .collect::<Vec<String>>()
@@ -254,6 +256,7 @@ impl Factor {
                     delimiter,
                     d,
                     delimiter,
+                    k.suffix(),
                     if let Some(lookahead) = l {
                         format!(" {}", lookahead.to_par())
                     } else {
@@ -297,7 +300,8 @@ impl Display for Factor {
             Self::Terminal(t, k, s, a, u, m, l) => {
                 let mut d = String::new();
                 let delimiter = k.delimiter();
-                a.decorate(&mut d, &format!("T({delimiter}{t}{delimiter})"))?;
+                let suffix = k.suffix();
+                a.decorate(&mut d, &format!("T({delimiter}{t}{delimiter}{suffix})"))?;
                 if let Some(member_name) = m {
                     write!(d, "@{member_name}")?;
                 }
@@ -341,13 +345,14 @@ impl Display for Factor {
 ///
 /// An Alternation is a sequence of factors.
 /// Valid operation on Alternation is "|".
+/// The optional third member is the name of the semantic predicate that guards the alternation.
 ///
 #[derive(Debug, Clone, Eq, PartialEq)]
-pub struct Alternation(pub Vec<Factor>, pub ProductionAttribute);
+pub struct Alternation(pub Vec<Factor>, pub ProductionAttribute, pub Option<String>);
 
 impl Alternation {
     pub(crate) fn new() -> Self {
-        Self(Vec::new(), ProductionAttribute::default())
+        Self(Vec::new(), ProductionAttribute::default(), None)
     }
 
     pub(crate) fn with_factors(mut self, factors: Vec<Factor>) -> Self {
@@ -360,6 +365,11 @@ impl Alternation {
         self
     }
 
+    pub(crate) fn with_predicate(mut self, predicate: Option<String>) -> Self {
+        self.2 = predicate;
+        self
+    }
+
     pub(crate) fn insert(&mut self, index: usize, fac: Factor) {
         self.0.insert(index, fac)
     }
@@ -370,11 +380,17 @@ impl Alternation {
 
     /// Generate parol's syntax
     pub fn to_par(&self) -> String {
-        self.0
+        let factors = self
+            .0
             .iter()
             .map(|f| f.to_par())
             .collect::<Vec<String>>()
-            .join(" ")
+            .join(" ");
+        if let Some(predicate) = &self.2 {
+            format!("&{{{predicate}}} {factors}")
+        } else {
+            factors
+        }
     }
 
     fn is_used_scanner(&self, scanner_index: usize) -> bool {
@@ -399,9 +415,13 @@ impl Alternation {
 
 impl Display for Alternation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        write!(f, "Alt(")?;
+        if let Some(predicate) = &self.2 {
+            write!(f, "&{{{predicate}}} ")?;
+        }
         write!(
             f,
-            "Alt({}",
+            "{}",
             self.0
                 .iter()
                 .map(|f| format!("{f}"))
@@ -597,6 +617,8 @@ pub struct ScannerConfig {
     pub line_comments: Vec<String>,
     /// Optional block comments
     pub block_comments: Vec<(String, String)>,
+    /// Optional block comments that can be nested
+    pub nested_block_comments: Vec<(String, String)>,
     /// Defines whether to handle newlines automatically in scanner
     pub auto_newline_off: bool,
     /// Defines whether to handle whitespace automatically in scanner
@@ -605,6 +627,12 @@ pub struct ScannerConfig {
     pub allow_unmatched: bool,
     /// Primary non-terminals whose derived terminals are skipped in this scanner state
     pub skip: Vec<Token<'static>>,
+    /// Primary non-terminals whose derived terminals are synthesized by the offside rule in this
+    /// scanner state, in the order indent, dedent and newline
+    pub offside: Option<[Token<'static>; 3]>,
+    /// Primary non-terminals whose derived terminals are scanned by user code in this scanner
+    /// state
+    pub external: Vec<Token<'static>>,
     /// Scanner state transitions
     /// Maps from (token, terminal kind) to scanner state, where the token is identified by its
     /// primary non-terminal name. The scanner state is identified by its name.
@@ -621,6 +649,29 @@ impl ScannerConfig {
             .for_each(|i| self.skip.push(i.identifier.identifier.to_owned()));
     }
 
+    pub(crate) fn add_externals(
+        &mut self,
+        identifier_list: &parol_grammar_trait::IdentifierList<'_>,
+    ) {
+        self.external
+            .push(identifier_list.identifier.identifier.to_owned());
+        identifier_list
+            .identifier_list_list
+            .iter()
+            .for_each(|i| self.external.push(i.identifier.identifier.to_owned()));
+    }
+
+    pub(crate) fn set_offside(
+        &mut self,
+        offside: &parol_grammar_trait::ScannerDirectivesPercentOffsideIndentDedentNewline<'_>,
+    ) {
+        self.offside = Some([
+            offside.indent.identifier.to_owned(),
+            offside.dedent.identifier.to_owned(),
+            offside.newline.identifier.to_owned(),
+        ]);
+    }
+
     pub(crate) fn add_transitions(
         &mut self,
         transitions: &ScannerDirectivesPercentOnIdentifierListScannerStateDirectives<'_>,
@@ -649,9 +700,22 @@ impl Display for ScannerConfig {
         write!(f, "name: {};", self.name)?;
         write!(f, "line_comments: {:?};", self.line_comments)?;
         write!(f, "block_comments: {:?};", self.block_comments)?;
+        if !self.nested_block_comments.is_empty() {
+            write!(
+                f,
+                "nested_block_comments: {:?};",
+                self.nested_block_comments
+            )?;
+        }
         write!(f, "auto_newline_off: {};", self.auto_newline_off)?;
         write!(f, "auto_ws_off: {};", self.auto_ws_off)?;
         write!(f, "skip: {:?};", self.skip)?;
+        if let Some(offside) = &self.offside {
+            write!(f, "offside: {offside:?};")?;
+        }
+        if !self.external.is_empty() {
+            write!(f, "external: {:?};", self.external)?;
+        }
         self.transitions
             .iter()
             .try_for_each(|(k, v)| write!(f, "on {k} {v};"))
@@ -664,10 +728,13 @@ impl Default for ScannerConfig {
             name: "INITIAL".to_owned(),
             line_comments: Vec::default(),
             block_comments: Vec::default(),
+            nested_block_comments: Vec::default(),
             auto_newline_off: false,
             auto_ws_off: false,
             allow_unmatched: false,
             skip: Vec::default(),
+            offside: None,
+            external: Vec::default(),
             transitions: BTreeMap::default(),
         }
     }
@@ -697,6 +764,12 @@ impl TryFrom<&parol_grammar_trait::ScannerState<'_>> for ScannerConfig {
                     ParolGrammar::expanded_token_literal(&block_comment.token_literal),
                     ParolGrammar::expanded_token_literal(&block_comment.token_literal0),
                 )),
+                ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                    block_comment,
+                ) => me.nested_block_comments.push((
+                    ParolGrammar::expanded_token_literal(&block_comment.token_literal),
+                    ParolGrammar::expanded_token_literal(&block_comment.token_literal0),
+                )),
                 ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(_) => {
                     me.auto_newline_off = true
                 }
@@ -704,6 +777,12 @@ impl TryFrom<&parol_grammar_trait::ScannerState<'_>> for ScannerConfig {
                 ScannerDirectives::PercentSkipIdentifierList(skip) => {
                     me.add_skips(&skip.identifier_list)
                 }
+                ScannerDirectives::PercentOffsideIndentDedentNewline(offside) => {
+                    me.set_offside(offside)
+                }
+                ScannerDirectives::PercentExternalIdentifierList(external) => {
+                    me.add_externals(&external.identifier_list)
+                }
                 ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(
                     scanner_directives_percent_on_identifier_list_scanner_state_directives,
                 ) => {
@@ -765,6 +844,9 @@ pub struct ParolGrammar<'t> {
     pub t_type_def: Option<UserDefinedTypeName>,
     /// The grammar type
     pub grammar_type: GrammarType,
+    /// Non-terminals declared with `%entry`. The parser provides additional parse functions that
+    /// start with these non-terminals instead of the start symbol.
+    pub entry_points: Vec<Token<'static>>,
     /// Contains information about token aliases:
     /// (LHS identifier as Token to keep location, expanded text)
     token_aliases: Vec<(Token<'static>, String)>,
@@ -834,6 +916,9 @@ impl ParolGrammar<'_> {
             Declaration::PercentGrammarUnderscoreTypeRawString(grammar_type) => {
                 self.process_grammar_type_declaration(&grammar_type.raw_string.raw_string)?
             }
+            Declaration::PercentEntryIdentifierList(entry) => {
+                self.add_entry_points(&entry.identifier_list)
+            }
         }
         Ok(())
     }
@@ -852,6 +937,14 @@ impl ParolGrammar<'_> {
                     Self::expanded_token_literal(&block_comment.token_literal),
                     Self::expanded_token_literal(&block_comment.token_literal0),
                 )),
+            ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+                block_comment,
+            ) => self.scanner_configurations[INITIAL_STATE]
+                .nested_block_comments
+                .push((
+                    Self::expanded_token_literal(&block_comment.token_literal),
+                    Self::expanded_token_literal(&block_comment.token_literal0),
+                )),
             ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(_) => {
                 self.scanner_configurations[INITIAL_STATE].auto_newline_off = true
             }
@@ -861,6 +954,12 @@ impl ParolGrammar<'_> {
             ScannerDirectives::PercentSkipIdentifierList(skip) => {
                 self.scanner_configurations[INITIAL_STATE].add_skips(&skip.identifier_list)
             }
+            ScannerDirectives::PercentOffsideIndentDedentNewline(offside) => {
+                self.scanner_configurations[INITIAL_STATE].set_offside(offside)
+            }
+            ScannerDirectives::PercentExternalIdentifierList(external) => {
+                self.scanner_configurations[INITIAL_STATE].add_externals(&external.identifier_list)
+            }
             ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(transitions) => {
                 self.scanner_configurations[INITIAL_STATE].add_transitions(transitions)
             }
@@ -888,6 +987,26 @@ impl ParolGrammar<'_> {
         self.process_productions(&productions)
     }
 
+    fn add_entry_points(&mut self, identifier_list: &parol_grammar_trait::IdentifierList<'_>) {
+        std::iter::once(&identifier_list.identifier)
+            .chain(
+                identifier_list
+                    .identifier_list_list
+                    .iter()
+                    .map(|i| &i.identifier),
+            )
+            .for_each(|i| {
+                // Declaring an entry point twice has no further effect
+                if !self
+                    .entry_points
+                    .iter()
+                    .any(|e| e.text() == i.identifier.text())
+                {
+                    self.entry_points.push(i.identifier.to_owned());
+                }
+            });
+    }
+
     fn process_start_declaration(&mut self, start_declaration: &StartDeclaration) -> Result<()> {
         self.start_symbol = start_declaration.identifier.identifier.text().to_string();
         Ok(())
@@ -922,22 +1041,52 @@ impl ParolGrammar<'_> {
     fn process_production(&mut self, prod: &parol_grammar_trait::Production) -> Result<()> {
         let lhs = prod.identifier.identifier.text().to_string();
         let alternations = Self::to_alternation_vec(&prod.alternations);
-        let rhs = self.process_alternations(&alternations)?;
+        let rhs = self.process_alternations(&alternations, true)?;
         self.productions.push(Production { lhs, rhs });
         Ok(())
     }
 
+    /// Semantic predicates are only allowed in the alternations of a production, i.e. not in
+    /// nested ones of groups, optionals and repetitions.
     fn process_alternations(
         &mut self,
         alternations: &[&parol_grammar_trait::Alternation],
+        allow_predicates: bool,
     ) -> Result<Alternations> {
         let mut result = Alternations::new();
         for a in alternations {
-            result.insert(self.process_alternation(&a.alternation_list)?);
+            let predicate = a
+                .alternation_opt
+                .as_ref()
+                .map(|o| self.process_predicate(&o.predicate, allow_predicates))
+                .transpose()?;
+            result.insert(
+                self.process_alternation(&a.alternation_list)?
+                    .with_predicate(predicate),
+            );
         }
         Ok(result)
     }
 
+    fn process_predicate(
+        &self,
+        predicate: &parol_grammar_trait::Predicate,
+        allow_predicates: bool,
+    ) -> Result<String> {
+        let name = &predicate.identifier.identifier;
+        if !allow_predicates || self.grammar_type == GrammarType::LALR1 {
+            bail!(ParolParserError::UnsupportedFeature {
+                feature: format!("Semantic predicate &{{{}}}", name.text()),
+                hint: "Semantic predicates can only guard the alternations of productions in \
+                    LL(k) grammars"
+                    .to_string(),
+                input: name.location.file_name.to_path_buf(),
+                token: name.location.clone(),
+            });
+        }
+        Ok(name.text().to_string())
+    }
+
     fn process_alternation(&mut self, alternation_list: &[AlternationList]) -> Result<Alternation> {
         let mut result = Alternation::new();
         for a in alternation_list {
@@ -952,7 +1101,7 @@ impl ParolGrammar<'_> {
         match factor {
             parol_grammar_trait::Factor::Group(group) => {
                 let alternations = Self::to_alternation_vec(&group.group.alternations);
-                let factors = self.process_alternations(&alternations)?;
+                let factors = self.process_alternations(&alternations, false)?;
                 if factors.is_empty() {
                     Err(parol!(ParolParserError::EmptyGroup {
                         context: context.to_string(),
@@ -966,7 +1115,7 @@ impl ParolGrammar<'_> {
             }
             parol_grammar_trait::Factor::Repeat(repeat) => {
                 let alternations = Self::to_alternation_vec(&repeat.repeat.alternations);
-                let factors = self.process_alternations(&alternations)?;
+                let factors = self.process_alternations(&alternations, false)?;
                 if factors.is_empty() {
                     Err(parol!(ParolParserError::EmptyRepetition {
                         context: context.to_string(),
@@ -980,7 +1129,7 @@ impl ParolGrammar<'_> {
             }
             parol_grammar_trait::Factor::Optional(optional) => {
                 let alternations = Self::to_alternation_vec(&optional.optional.alternations);
-                let factors = self.process_alternations(&alternations)?;
+                let factors = self.process_alternations(&alternations, false)?;
                 if factors.is_empty() {
                     Err(parol!(ParolParserError::EmptyOptional {
                         context: context.to_string(),
@@ -1042,6 +1191,17 @@ impl ParolGrammar<'_> {
             parol_grammar_trait::TokenLiteral::RawString(l) => {
                 (l.raw_string.raw_string.text(), TerminalKind::Raw)
             }
+            parol_grammar_trait::TokenLiteral::CaseInsensitiveRawString(l) => {
+                let text = l
+                    .case_insensitive_raw_string
+                    .case_insensitive_raw_string
+                    .text();
+                (
+                    text.strip_suffix(TerminalKind::RawCaseInsensitive.suffix())
+                        .unwrap_or(text),
+                    TerminalKind::RawCaseInsensitive,
+                )
+            }
             parol_grammar_trait::TokenLiteral::Regex(r) => {
                 (r.regex.regex.text(), TerminalKind::Regex)
             }
@@ -1289,15 +1449,8 @@ impl ParolGrammar<'_> {
     }
 
     fn expanded_token_literal(token_literal: &parol_grammar_trait::TokenLiteral) -> String {
-        match token_literal {
-            TokenLiteral::String(s) => TerminalKind::Legacy
-                .expand(ParolGrammar::trim_quotes(s.string.string.text()).as_str()),
-            TokenLiteral::RawString(l) => TerminalKind::Raw
-                .expand(ParolGrammar::trim_quotes(l.raw_string.raw_string.text()).as_str()),
-            TokenLiteral::Regex(r) => {
-                TerminalKind::Regex.expand(ParolGrammar::trim_quotes(r.regex.regex.text()).as_str())
-            }
-        }
+        let (content, kind) = Self::measure_token_literal(token_literal);
+        kind.expand(ParolGrammar::trim_quotes(content).as_str())
     }
 
     fn check(&self) -> Result<()> {
@@ -1321,8 +1474,25 @@ impl ParolGrammar<'_> {
             .enumerate()
             .try_for_each(|(i, s)| {
                 self.check_skipped_tokens(i, s)?;
+                self.check_offside_tokens(i, s)?;
+                self.check_external_tokens(i, s)?;
                 self.check_transitions(i, s)
-            })
+            })?;
+
+        self.check_entry_points()
+    }
+
+    fn check_entry_points(&self) -> Result<()> {
+        self.entry_points.iter().try_for_each(|e| {
+            if !self.productions.iter().any(|p| p.lhs == e.text()) {
+                bail!(ParolParserError::UnknownEntryPoint {
+                    entry: e.text().to_string(),
+                    input: e.location.file_name.to_path_buf(),
+                    location: e.location.clone(),
+                });
+            }
+            Ok(())
+        })
     }
 
     fn check_skipped_tokens(&self, index: usize, s: &ScannerConfig) -> Result<()> {
@@ -1348,6 +1518,66 @@ impl ParolGrammar<'_> {
         })
     }
 
+    fn check_external_tokens(&self, index: usize, s: &ScannerConfig) -> Result<()> {
+        s.external.iter().try_for_each(|k| {
+            if !self.is_primary_non_terminal(k) {
+                bail!(ParolParserError::InvalidTokenInTransition {
+                    context: "check_external_tokens".to_string(),
+                    token: k.text().to_string(),
+                    input: k.location.file_name.to_path_buf(),
+                    location: k.location.clone(),
+                });
+            }
+            if !self.is_terminal_in_scanner(k, index) {
+                bail!(ParolParserError::TokenIsNotInScanner {
+                    context: "check_external_tokens".to_string(),
+                    scanner: s.name.clone(),
+                    token: k.text().to_string(),
+                    input: k.location.file_name.to_path_buf(),
+                    location: k.location.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
+    fn check_offside_tokens(&self, index: usize, s: &ScannerConfig) -> Result<()> {
+        let Some(offside) = &s.offside else {
+            return Ok(());
+        };
+        if s.auto_newline_off || s.auto_ws_off {
+            let k = &offside[0];
+            bail!(ParolParserError::UnsupportedFeature {
+                feature: "%offside in combination with %auto_newline_off or %auto_ws_off"
+                    .to_string(),
+                hint: "The offside rule needs the newlines and whitespace that are handled by the scanner"
+                    .to_string(),
+                input: k.location.file_name.to_path_buf(),
+                token: k.location.clone(),
+            });
+        }
+        offside.iter().try_for_each(|k| {
+            if !self.is_primary_non_terminal(k) {
+                bail!(ParolParserError::InvalidTokenInTransition {
+                    context: "check_offside_tokens".to_string(),
+                    token: k.text().to_string(),
+                    input: k.location.file_name.to_path_buf(),
+                    location: k.location.clone(),
+                });
+            }
+            if !self.is_terminal_in_scanner(k, index) {
+                bail!(ParolParserError::TokenIsNotInScanner {
+                    context: "check_offside_tokens".to_string(),
+                    scanner: s.name.clone(),
+                    token: k.text().to_string(),
+                    input: k.location.file_name.to_path_buf(),
+                    location: k.location.clone(),
+                });
+            }
+            Ok(())
+        })
+    }
+
     fn check_transitions(&self, index: usize, s: &ScannerConfig) -> Result<()> {
         s.transitions.iter().try_for_each(|(k, v)| {
             if !self.is_primary_non_terminal(k) {
@@ -1489,6 +1719,7 @@ impl Default for ParolGrammar<'_> {
             nt_type_definitions: BTreeMap::new(),
             t_type_def: None,
             grammar_type: GrammarType::LLK,
+            entry_points: Vec::new(),
             token_aliases: Vec::new(),
             phantom: PhantomData,
         }
@@ -1517,6 +1748,28 @@ mod tests {
         assert_eq!(pg.user_type_definitions.len(), 0);
         assert_eq!(pg.nt_type_definitions.len(), 0);
         assert_eq!(pg.grammar_type, GrammarType::LLK);
+        assert_eq!(pg.entry_points.len(), 0);
         assert_eq!(pg.productions.len(), 0);
     }
+
+    #[test]
+    fn case_insensitive_suffix_is_not_taken_from_a_following_identifier() {
+        let grammar_config = crate::obtain_grammar_config_from_string(
+            "%start S %% S: 'a'i 'b'ident; ident: 'c';",
+            false,
+        )
+        .unwrap();
+        let rhs = grammar_config.cfg.pr[0].get_r();
+        assert!(
+            matches!(
+                &rhs[..],
+                [
+                    crate::Symbol::T(crate::Terminal::Trm(a, TerminalKind::RawCaseInsensitive, ..)),
+                    crate::Symbol::T(crate::Terminal::Trm(b, TerminalKind::Raw, ..)),
+                    crate::Symbol::N(n, ..),
+                ] if a == "a" && b == "b" && n == "ident"
+            ),
+            "{rhs:?}"
+        );
+    }
 }
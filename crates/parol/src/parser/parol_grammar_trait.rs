@@ -73,6 +73,11 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'Predicate'
+    fn predicate(&mut self, _arg: &Predicate<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Factor'
     fn factor(&mut self, _arg: &Factor<'t>) -> Result<()> {
         Ok(())
@@ -113,6 +118,11 @@ pub trait ParolGrammarTrait<'t> {
         Ok(())
     }
 
+    /// Semantic action for non-terminal 'CaseInsensitiveRawString'
+    fn case_insensitive_raw_string(&mut self, _arg: &CaseInsensitiveRawString<'t>) -> Result<()> {
+        Ok(())
+    }
+
     /// Semantic action for non-terminal 'Regex'
     fn regex(&mut self, _arg: &Regex<'t>) -> Result<()> {
         Ok(())
@@ -274,6 +284,17 @@ pub struct DeclarationPercentGrammarUnderscoreTypeRawString<'t> {
 ///
 /// Type derived for production 13
 ///
+/// `Declaration: '%entry'^ /* Clipped */ IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeclarationPercentEntryIdentifierList<'t> {
+    pub identifier_list: IdentifierList<'t>,
+}
+
+///
+/// Type derived for production 14
+///
 /// `Declaration: ScannerDirectives;`
 ///
 #[allow(dead_code)]
@@ -283,7 +304,7 @@ pub struct DeclarationScannerDirectives<'t> {
 }
 
 ///
-/// Type derived for production 14
+/// Type derived for production 15
 ///
 /// `ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;`
 ///
@@ -294,7 +315,7 @@ pub struct ScannerDirectivesPercentLineUnderscoreCommentTokenLiteral<'t> {
 }
 
 ///
-/// Type derived for production 15
+/// Type derived for production 16
 ///
 /// `ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
 ///
@@ -306,7 +327,21 @@ pub struct ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLitera
 }
 
 ///
-/// Type derived for production 16
+/// Type derived for production 17
+///
+/// `ScannerDirectives: '%nested_block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral<
+    't,
+> {
+    pub token_literal: TokenLiteral<'t>,
+    pub token_literal0: TokenLiteral<'t>,
+}
+
+///
+/// Type derived for production 18
 ///
 /// `ScannerDirectives: '%auto_newline_off'^ /* Clipped */;`
 ///
@@ -315,7 +350,7 @@ pub struct ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLitera
 pub struct ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {}
 
 ///
-/// Type derived for production 17
+/// Type derived for production 19
 ///
 /// `ScannerDirectives: '%auto_ws_off'^ /* Clipped */;`
 ///
@@ -324,7 +359,7 @@ pub struct ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {}
 pub struct ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {}
 
 ///
-/// Type derived for production 18
+/// Type derived for production 20
 ///
 /// `ScannerDirectives: '%skip'^ /* Clipped */ IdentifierList;`
 ///
@@ -335,7 +370,7 @@ pub struct ScannerDirectivesPercentSkipIdentifierList<'t> {
 }
 
 ///
-/// Type derived for production 19
+/// Type derived for production 21
 ///
 /// `ScannerDirectives: '%on'^ /* Clipped */ IdentifierList ScannerStateDirectives;`
 ///
@@ -347,7 +382,7 @@ pub struct ScannerDirectivesPercentOnIdentifierListScannerStateDirectives<'t> {
 }
 
 ///
-/// Type derived for production 20
+/// Type derived for production 22
 ///
 /// `ScannerDirectives: '%allow_unmatched'^ /* Clipped */;`
 ///
@@ -356,7 +391,31 @@ pub struct ScannerDirectivesPercentOnIdentifierListScannerStateDirectives<'t> {
 pub struct ScannerDirectivesPercentAllowUnderscoreUnmatched {}
 
 ///
-/// Type derived for production 21
+/// Type derived for production 23
+///
+/// `ScannerDirectives: '%offside'^ /* Clipped */ Identifier@indent Identifier@dedent Identifier@newline;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ScannerDirectivesPercentOffsideIndentDedentNewline<'t> {
+    pub indent: Identifier<'t>,
+    pub dedent: Identifier<'t>,
+    pub newline: Identifier<'t>,
+}
+
+///
+/// Type derived for production 24
+///
+/// `ScannerDirectives: '%external'^ /* Clipped */ IdentifierList;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ScannerDirectivesPercentExternalIdentifierList<'t> {
+    pub identifier_list: IdentifierList<'t>,
+}
+
+///
+/// Type derived for production 25
 ///
 /// `ScannerStateDirectives: '%enter'^ /* Clipped */ Identifier;`
 ///
@@ -367,7 +426,7 @@ pub struct ScannerStateDirectivesPercentEnterIdentifier<'t> {
 }
 
 ///
-/// Type derived for production 22
+/// Type derived for production 26
 ///
 /// `ScannerStateDirectives: '%push'^ /* Clipped */ Identifier;`
 ///
@@ -378,7 +437,7 @@ pub struct ScannerStateDirectivesPercentPushIdentifier<'t> {
 }
 
 ///
-/// Type derived for production 23
+/// Type derived for production 27
 ///
 /// `ScannerStateDirectives: '%pop';`
 ///
@@ -389,7 +448,7 @@ pub struct ScannerStateDirectivesPercentPop<'t> {
 }
 
 ///
-/// Type derived for production 35
+/// Type derived for production 42
 ///
 /// `Factor: Group;`
 ///
@@ -400,7 +459,7 @@ pub struct FactorGroup<'t> {
 }
 
 ///
-/// Type derived for production 36
+/// Type derived for production 43
 ///
 /// `Factor: Repeat;`
 ///
@@ -411,7 +470,7 @@ pub struct FactorRepeat<'t> {
 }
 
 ///
-/// Type derived for production 37
+/// Type derived for production 44
 ///
 /// `Factor: Optional;`
 ///
@@ -422,7 +481,7 @@ pub struct FactorOptional<'t> {
 }
 
 ///
-/// Type derived for production 38
+/// Type derived for production 45
 ///
 /// `Factor: Symbol;`
 ///
@@ -433,7 +492,7 @@ pub struct FactorSymbol<'t> {
 }
 
 ///
-/// Type derived for production 39
+/// Type derived for production 46
 ///
 /// `Symbol: NonTerminal;`
 ///
@@ -444,7 +503,7 @@ pub struct SymbolNonTerminal<'t> {
 }
 
 ///
-/// Type derived for production 40
+/// Type derived for production 47
 ///
 /// `Symbol: SimpleToken;`
 ///
@@ -455,7 +514,7 @@ pub struct SymbolSimpleToken<'t> {
 }
 
 ///
-/// Type derived for production 41
+/// Type derived for production 48
 ///
 /// `Symbol: TokenWithStates;`
 ///
@@ -466,7 +525,7 @@ pub struct SymbolTokenWithStates<'t> {
 }
 
 ///
-/// Type derived for production 42
+/// Type derived for production 49
 ///
 /// `TokenLiteral: String;`
 ///
@@ -477,7 +536,7 @@ pub struct TokenLiteralString<'t> {
 }
 
 ///
-/// Type derived for production 43
+/// Type derived for production 50
 ///
 /// `TokenLiteral: RawString;`
 ///
@@ -488,7 +547,18 @@ pub struct TokenLiteralRawString<'t> {
 }
 
 ///
-/// Type derived for production 44
+/// Type derived for production 51
+///
+/// `TokenLiteral: CaseInsensitiveRawString;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TokenLiteralCaseInsensitiveRawString<'t> {
+    pub case_insensitive_raw_string: CaseInsensitiveRawString<'t>,
+}
+
+///
+/// Type derived for production 52
 ///
 /// `TokenLiteral: Regex;`
 ///
@@ -499,7 +569,7 @@ pub struct TokenLiteralRegex<'t> {
 }
 
 ///
-/// Type derived for production 70
+/// Type derived for production 79
 ///
 /// `ASTControl: CutOperator;`
 ///
@@ -510,7 +580,7 @@ pub struct ASTControlCutOperator {
 }
 
 ///
-/// Type derived for production 71
+/// Type derived for production 80
 ///
 /// `ASTControl: MemberName ASTControlOpt /* Option */;`
 ///
@@ -522,7 +592,7 @@ pub struct ASTControlMemberNameASTControlOpt<'t> {
 }
 
 ///
-/// Type derived for production 72
+/// Type derived for production 81
 ///
 /// `ASTControl: UserTypeDeclaration;`
 ///
@@ -533,7 +603,7 @@ pub struct ASTControlUserTypeDeclaration {
 }
 
 ///
-/// Type derived for production 82
+/// Type derived for production 91
 ///
 /// `LookAheadGroup: PositiveLookahead;`
 ///
@@ -544,7 +614,7 @@ pub struct LookAheadGroupPositiveLookahead {
 }
 
 ///
-/// Type derived for production 83
+/// Type derived for production 92
 ///
 /// `LookAheadGroup: NegativeLookahead;`
 ///
@@ -585,6 +655,7 @@ pub struct ASTControlOpt {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Alternation<'t> {
+    pub alternation_opt: Option<AlternationOpt<'t>>,
     pub alternation_list: Vec<AlternationList<'t>>,
 }
 
@@ -597,6 +668,15 @@ pub struct AlternationList<'t> {
     pub factor: Factor<'t>,
 }
 
+///
+/// Type derived for non-terminal AlternationOpt
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct AlternationOpt<'t> {
+    pub predicate: Predicate<'t>,
+}
+
 ///
 /// Type derived for non-terminal Alternations
 ///
@@ -616,6 +696,15 @@ pub struct AlternationsList<'t> {
     pub alternation: Alternation<'t>,
 }
 
+///
+/// Type derived for non-terminal CaseInsensitiveRawString
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct CaseInsensitiveRawString<'t> {
+    pub case_insensitive_raw_string: Token<'t>, /* '(\\.|[^'])*'i */
+}
+
 ///
 /// Type derived for non-terminal CutOperator
 ///
@@ -637,6 +726,7 @@ pub enum Declaration<'t> {
     PercentNtUnderscoreTypeNtNameEquNtType(DeclarationPercentNtUnderscoreTypeNtNameEquNtType<'t>),
     PercentTUnderscoreTypeTType(DeclarationPercentTUnderscoreTypeTType),
     PercentGrammarUnderscoreTypeRawString(DeclarationPercentGrammarUnderscoreTypeRawString<'t>),
+    PercentEntryIdentifierList(DeclarationPercentEntryIdentifierList<'t>),
     ScannerDirectives(DeclarationScannerDirectives<'t>),
 }
 
@@ -802,6 +892,15 @@ pub struct Parol<'t> {
 #[derive(Debug, Clone)]
 pub struct PositiveLookahead {}
 
+///
+/// Type derived for non-terminal Predicate
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Predicate<'t> {
+    pub identifier: Identifier<'t>,
+}
+
 ///
 /// Type derived for non-terminal Production
 ///
@@ -882,6 +981,9 @@ pub enum ScannerDirectives<'t> {
     PercentBlockUnderscoreCommentTokenLiteralTokenLiteral(
         ScannerDirectivesPercentBlockUnderscoreCommentTokenLiteralTokenLiteral<'t>,
     ),
+    PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(
+        ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral<'t>,
+    ),
     PercentAutoUnderscoreNewlineUnderscoreOff(
         ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff,
     ),
@@ -891,6 +993,8 @@ pub enum ScannerDirectives<'t> {
         ScannerDirectivesPercentOnIdentifierListScannerStateDirectives<'t>,
     ),
     PercentAllowUnderscoreUnmatched(ScannerDirectivesPercentAllowUnderscoreUnmatched),
+    PercentOffsideIndentDedentNewline(ScannerDirectivesPercentOffsideIndentDedentNewline<'t>),
+    PercentExternalIdentifierList(ScannerDirectivesPercentExternalIdentifierList<'t>),
 }
 
 ///
@@ -998,6 +1102,7 @@ pub struct TokenExpressionOpt<'t> {
 pub enum TokenLiteral<'t> {
     String(TokenLiteralString<'t>),
     RawString(TokenLiteralRawString<'t>),
+    CaseInsensitiveRawString(TokenLiteralCaseInsensitiveRawString<'t>),
     Regex(TokenLiteralRegex<'t>),
 }
 
@@ -1061,8 +1166,10 @@ pub enum ASTType<'t> {
     ASTControlOpt(Option<ASTControlOpt>),
     Alternation(Alternation<'t>),
     AlternationList(Vec<AlternationList<'t>>),
+    AlternationOpt(Option<AlternationOpt<'t>>),
     Alternations(Alternations<'t>),
     AlternationsList(Vec<AlternationsList<'t>>),
+    CaseInsensitiveRawString(CaseInsensitiveRawString<'t>),
     CutOperator(CutOperator),
     Declaration(Declaration<'t>),
     DoubleColon(DoubleColon<'t>),
@@ -1082,6 +1189,7 @@ pub enum ASTType<'t> {
     Optional(Optional<'t>),
     Parol(Parol<'t>),
     PositiveLookahead(PositiveLookahead),
+    Predicate(Predicate<'t>),
     Production(Production<'t>),
     Prolog(Prolog<'t>),
     PrologList(Vec<PrologList<'t>>),
@@ -1464,15 +1572,19 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 13:
     ///
-    /// `Declaration: ScannerDirectives;`
+    /// `Declaration: '%entry'^ /* Clipped */ IdentifierList;`
     ///
     #[parol_runtime::function_name::named]
-    fn declaration_6(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+    fn declaration_6(
+        &mut self,
+        _percent_entry: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
-        let declaration_6_built = DeclarationScannerDirectives { scanner_directives };
-        let declaration_6_built = Declaration::ScannerDirectives(declaration_6_built);
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let declaration_6_built = DeclarationPercentEntryIdentifierList { identifier_list };
+        let declaration_6_built = Declaration::PercentEntryIdentifierList(declaration_6_built);
         // Calling user action here
         self.user_grammar.declaration(&declaration_6_built)?;
         self.push(ASTType::Declaration(declaration_6_built), context);
@@ -1481,6 +1593,23 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 14:
     ///
+    /// `Declaration: ScannerDirectives;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_7(&mut self, _scanner_directives: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_directives = pop_item!(self, scanner_directives, ScannerDirectives, context);
+        let declaration_7_built = DeclarationScannerDirectives { scanner_directives };
+        let declaration_7_built = Declaration::ScannerDirectives(declaration_7_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_7_built)?;
+        self.push(ASTType::Declaration(declaration_7_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
     /// `ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
@@ -1506,7 +1635,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 15:
+    /// Semantic action for production 16:
     ///
     /// `ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
     ///
@@ -1540,23 +1669,26 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 16:
+    /// Semantic action for production 17:
     ///
-    /// `ScannerDirectives: '%auto_newline_off'^ /* Clipped */;`
+    /// `ScannerDirectives: '%nested_block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;`
     ///
     #[parol_runtime::function_name::named]
     fn scanner_directives_2(
         &mut self,
-        _percent_auto_underscore_newline_underscore_off: &ParseTreeType<'t>,
+        _percent_nested_underscore_block_underscore_comment: &ParseTreeType<'t>,
+        _token_literal: &ParseTreeType<'t>,
+        _token_literal0: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives_2_built =
-            ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {};
-        let scanner_directives_2_built =
-            ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(
-                scanner_directives_2_built,
-            );
+        let token_literal0 = pop_item!(self, token_literal0, TokenLiteral, context);
+        let token_literal = pop_item!(self, token_literal, TokenLiteral, context);
+        let scanner_directives_2_built = ScannerDirectivesPercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral {
+            token_literal,
+            token_literal0,
+        };
+        let scanner_directives_2_built = ScannerDirectives::PercentNestedUnderscoreBlockUnderscoreCommentTokenLiteralTokenLiteral(scanner_directives_2_built);
         // Calling user action here
         self.user_grammar
             .scanner_directives(&scanner_directives_2_built)?;
@@ -1567,20 +1699,23 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 17:
+    /// Semantic action for production 18:
     ///
-    /// `ScannerDirectives: '%auto_ws_off'^ /* Clipped */;`
+    /// `ScannerDirectives: '%auto_newline_off'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
     fn scanner_directives_3(
         &mut self,
-        _percent_auto_underscore_ws_underscore_off: &ParseTreeType<'t>,
+        _percent_auto_underscore_newline_underscore_off: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives_3_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {};
         let scanner_directives_3_built =
-            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_3_built);
+            ScannerDirectivesPercentAutoUnderscoreNewlineUnderscoreOff {};
+        let scanner_directives_3_built =
+            ScannerDirectives::PercentAutoUnderscoreNewlineUnderscoreOff(
+                scanner_directives_3_built,
+            );
         // Calling user action here
         self.user_grammar
             .scanner_directives(&scanner_directives_3_built)?;
@@ -1591,12 +1726,36 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 18:
+    /// Semantic action for production 19:
     ///
-    /// `ScannerDirectives: '%skip'^ /* Clipped */ IdentifierList;`
+    /// `ScannerDirectives: '%auto_ws_off'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
     fn scanner_directives_4(
+        &mut self,
+        _percent_auto_underscore_ws_underscore_off: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let scanner_directives_4_built = ScannerDirectivesPercentAutoUnderscoreWsUnderscoreOff {};
+        let scanner_directives_4_built =
+            ScannerDirectives::PercentAutoUnderscoreWsUnderscoreOff(scanner_directives_4_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_4_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_4_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 20:
+    ///
+    /// `ScannerDirectives: '%skip'^ /* Clipped */ IdentifierList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_5(
         &mut self,
         _percent_skip: &ParseTreeType<'t>,
         _identifier_list: &ParseTreeType<'t>,
@@ -1604,26 +1763,26 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let scanner_directives_4_built =
+        let scanner_directives_5_built =
             ScannerDirectivesPercentSkipIdentifierList { identifier_list };
-        let scanner_directives_4_built =
-            ScannerDirectives::PercentSkipIdentifierList(scanner_directives_4_built);
+        let scanner_directives_5_built =
+            ScannerDirectives::PercentSkipIdentifierList(scanner_directives_5_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_4_built)?;
+            .scanner_directives(&scanner_directives_5_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_4_built),
+            ASTType::ScannerDirectives(scanner_directives_5_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 19:
+    /// Semantic action for production 21:
     ///
     /// `ScannerDirectives: '%on'^ /* Clipped */ IdentifierList ScannerStateDirectives;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_5(
+    fn scanner_directives_6(
         &mut self,
         _percent_on: &ParseTreeType<'t>,
         _identifier_list: &ParseTreeType<'t>,
@@ -1638,52 +1797,113 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
             context
         );
         let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
-        let scanner_directives_5_built =
+        let scanner_directives_6_built =
             ScannerDirectivesPercentOnIdentifierListScannerStateDirectives {
                 identifier_list,
                 scanner_state_directives: (&scanner_state_directives)
                     .try_into()
                     .map_err(parol_runtime::ParolError::UserError)?,
             };
-        let scanner_directives_5_built =
+        let scanner_directives_6_built =
             ScannerDirectives::PercentOnIdentifierListScannerStateDirectives(
-                scanner_directives_5_built,
+                scanner_directives_6_built,
             );
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_5_built)?;
+            .scanner_directives(&scanner_directives_6_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_5_built),
+            ASTType::ScannerDirectives(scanner_directives_6_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 20:
+    /// Semantic action for production 22:
     ///
     /// `ScannerDirectives: '%allow_unmatched'^ /* Clipped */;`
     ///
     #[parol_runtime::function_name::named]
-    fn scanner_directives_6(
+    fn scanner_directives_7(
         &mut self,
         _percent_allow_underscore_unmatched: &ParseTreeType<'t>,
     ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let scanner_directives_6_built = ScannerDirectivesPercentAllowUnderscoreUnmatched {};
-        let scanner_directives_6_built =
-            ScannerDirectives::PercentAllowUnderscoreUnmatched(scanner_directives_6_built);
+        let scanner_directives_7_built = ScannerDirectivesPercentAllowUnderscoreUnmatched {};
+        let scanner_directives_7_built =
+            ScannerDirectives::PercentAllowUnderscoreUnmatched(scanner_directives_7_built);
         // Calling user action here
         self.user_grammar
-            .scanner_directives(&scanner_directives_6_built)?;
+            .scanner_directives(&scanner_directives_7_built)?;
         self.push(
-            ASTType::ScannerDirectives(scanner_directives_6_built),
+            ASTType::ScannerDirectives(scanner_directives_7_built),
             context,
         );
         Ok(())
     }
 
-    /// Semantic action for production 21:
+    /// Semantic action for production 23:
+    ///
+    /// `ScannerDirectives: '%offside'^ /* Clipped */ Identifier@indent Identifier@dedent Identifier@newline;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_8(
+        &mut self,
+        _percent_offside: &ParseTreeType<'t>,
+        _indent: &ParseTreeType<'t>,
+        _dedent: &ParseTreeType<'t>,
+        _newline: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let newline = pop_item!(self, newline, Identifier, context);
+        let dedent = pop_item!(self, dedent, Identifier, context);
+        let indent = pop_item!(self, indent, Identifier, context);
+        let scanner_directives_8_built = ScannerDirectivesPercentOffsideIndentDedentNewline {
+            indent,
+            dedent,
+            newline,
+        };
+        let scanner_directives_8_built =
+            ScannerDirectives::PercentOffsideIndentDedentNewline(scanner_directives_8_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_8_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_8_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 24:
+    ///
+    /// `ScannerDirectives: '%external'^ /* Clipped */ IdentifierList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn scanner_directives_9(
+        &mut self,
+        _percent_external: &ParseTreeType<'t>,
+        _identifier_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier_list = pop_item!(self, identifier_list, IdentifierList, context);
+        let scanner_directives_9_built =
+            ScannerDirectivesPercentExternalIdentifierList { identifier_list };
+        let scanner_directives_9_built =
+            ScannerDirectives::PercentExternalIdentifierList(scanner_directives_9_built);
+        // Calling user action here
+        self.user_grammar
+            .scanner_directives(&scanner_directives_9_built)?;
+        self.push(
+            ASTType::ScannerDirectives(scanner_directives_9_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 25:
     ///
     /// `ScannerStateDirectives: '%enter'^ /* Clipped */ Identifier;`
     ///
@@ -1710,7 +1930,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 22:
+    /// Semantic action for production 26:
     ///
     /// `ScannerStateDirectives: '%push'^ /* Clipped */ Identifier;`
     ///
@@ -1737,7 +1957,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 23:
+    /// Semantic action for production 27:
     ///
     /// `ScannerStateDirectives: '%pop';`
     ///
@@ -1759,7 +1979,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 24:
+    /// Semantic action for production 28:
     ///
     /// `GrammarDefinition: '%%'^ /* Clipped */ Production GrammarDefinitionList /* Vec */;`
     ///
@@ -1793,7 +2013,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 25:
+    /// Semantic action for production 29:
     ///
     /// `GrammarDefinitionList /* Vec<T>::Push */: Production GrammarDefinitionList;`
     ///
@@ -1822,7 +2042,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 26:
+    /// Semantic action for production 30:
     ///
     /// `GrammarDefinitionList /* Vec<T>::New */: ;`
     ///
@@ -1838,7 +2058,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 27:
+    /// Semantic action for production 31:
     ///
     /// `DoubleColon: '::';`
     ///
@@ -1854,7 +2074,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 28:
+    /// Semantic action for production 32:
     ///
     /// `Production: Identifier ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;`
     ///
@@ -1880,7 +2100,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 29:
+    /// Semantic action for production 33:
     ///
     /// `Alternations: Alternation AlternationsList /* Vec */;`
     ///
@@ -1905,7 +2125,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 30:
+    /// Semantic action for production 34:
     ///
     /// `AlternationsList /* Vec<T>::Push */: '|'^ /* Clipped */ Alternation AlternationsList;`
     ///
@@ -1927,7 +2147,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 31:
+    /// Semantic action for production 35:
     ///
     /// `AlternationsList /* Vec<T>::New */: ;`
     ///
@@ -1943,24 +2163,32 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 32:
+    /// Semantic action for production 36:
     ///
-    /// `Alternation: AlternationList /* Vec */;`
+    /// `Alternation: AlternationOpt /* Option */ AlternationList /* Vec */;`
     ///
     #[parol_runtime::function_name::named]
-    fn alternation(&mut self, _alternation_list: &ParseTreeType<'t>) -> Result<()> {
+    fn alternation(
+        &mut self,
+        _alternation_opt: &ParseTreeType<'t>,
+        _alternation_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
         let alternation_list =
             pop_and_reverse_item!(self, alternation_list, AlternationList, context);
-        let alternation_built = Alternation { alternation_list };
+        let alternation_opt = pop_item!(self, alternation_opt, AlternationOpt, context);
+        let alternation_built = Alternation {
+            alternation_opt,
+            alternation_list,
+        };
         // Calling user action here
         self.user_grammar.alternation(&alternation_built)?;
         self.push(ASTType::Alternation(alternation_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 33:
+    /// Semantic action for production 37:
     ///
     /// `AlternationList /* Vec<T>::Push */: Factor AlternationList;`
     ///
@@ -1981,7 +2209,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 34:
+    /// Semantic action for production 38:
     ///
     /// `AlternationList /* Vec<T>::New */: ;`
     ///
@@ -1994,7 +2222,57 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 35:
+    /// Semantic action for production 39:
+    ///
+    /// `AlternationOpt /* Option<T>::Some */: Predicate;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_0(&mut self, _predicate: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let predicate = pop_item!(self, predicate, Predicate, context);
+        let alternation_opt_0_built = AlternationOpt { predicate };
+        self.push(
+            ASTType::AlternationOpt(Some(alternation_opt_0_built)),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 40:
+    ///
+    /// `AlternationOpt /* Option<T>::None */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn alternation_opt_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.push(ASTType::AlternationOpt(None), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 41:
+    ///
+    /// `Predicate: '&{'^ /* Clipped */ Identifier '}'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn predicate(
+        &mut self,
+        _amp_l_brace: &ParseTreeType<'t>,
+        _identifier: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let identifier = pop_item!(self, identifier, Identifier, context);
+        let predicate_built = Predicate { identifier };
+        // Calling user action here
+        self.user_grammar.predicate(&predicate_built)?;
+        self.push(ASTType::Predicate(predicate_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 42:
     ///
     /// `Factor: Group;`
     ///
@@ -2011,7 +2289,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 36:
+    /// Semantic action for production 43:
     ///
     /// `Factor: Repeat;`
     ///
@@ -2028,7 +2306,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 37:
+    /// Semantic action for production 44:
     ///
     /// `Factor: Optional;`
     ///
@@ -2045,7 +2323,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 38:
+    /// Semantic action for production 45:
     ///
     /// `Factor: Symbol;`
     ///
@@ -2062,7 +2340,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 39:
+    /// Semantic action for production 46:
     ///
     /// `Symbol: NonTerminal;`
     ///
@@ -2079,7 +2357,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 40:
+    /// Semantic action for production 47:
     ///
     /// `Symbol: SimpleToken;`
     ///
@@ -2096,7 +2374,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 41:
+    /// Semantic action for production 48:
     ///
     /// `Symbol: TokenWithStates;`
     ///
@@ -2113,7 +2391,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 42:
+    /// Semantic action for production 49:
     ///
     /// `TokenLiteral: String;`
     ///
@@ -2130,7 +2408,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 43:
+    /// Semantic action for production 50:
     ///
     /// `TokenLiteral: RawString;`
     ///
@@ -2147,24 +2425,48 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 44:
+    /// Semantic action for production 51:
     ///
-    /// `TokenLiteral: Regex;`
+    /// `TokenLiteral: CaseInsensitiveRawString;`
     ///
     #[parol_runtime::function_name::named]
-    fn token_literal_2(&mut self, _regex: &ParseTreeType<'t>) -> Result<()> {
+    fn token_literal_2(&mut self, _case_insensitive_raw_string: &ParseTreeType<'t>) -> Result<()> {
         let context = function_name!();
         trace!("{}", self.trace_item_stack(context));
-        let regex = pop_item!(self, regex, Regex, context);
-        let token_literal_2_built = TokenLiteralRegex { regex };
-        let token_literal_2_built = TokenLiteral::Regex(token_literal_2_built);
+        let case_insensitive_raw_string = pop_item!(
+            self,
+            case_insensitive_raw_string,
+            CaseInsensitiveRawString,
+            context
+        );
+        let token_literal_2_built = TokenLiteralCaseInsensitiveRawString {
+            case_insensitive_raw_string,
+        };
+        let token_literal_2_built = TokenLiteral::CaseInsensitiveRawString(token_literal_2_built);
         // Calling user action here
         self.user_grammar.token_literal(&token_literal_2_built)?;
         self.push(ASTType::TokenLiteral(token_literal_2_built), context);
         Ok(())
     }
 
-    /// Semantic action for production 45:
+    /// Semantic action for production 52:
+    ///
+    /// `TokenLiteral: Regex;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn token_literal_3(&mut self, _regex: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let regex = pop_item!(self, regex, Regex, context);
+        let token_literal_3_built = TokenLiteralRegex { regex };
+        let token_literal_3_built = TokenLiteral::Regex(token_literal_3_built);
+        // Calling user action here
+        self.user_grammar.token_literal(&token_literal_3_built)?;
+        self.push(ASTType::TokenLiteral(token_literal_3_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 53:
     ///
     /// `TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;`
     ///
@@ -2190,7 +2492,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 46:
+    /// Semantic action for production 54:
     ///
     /// `TokenExpressionOpt /* Option<T>::Some */: LookAhead;`
     ///
@@ -2207,7 +2509,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 47:
+    /// Semantic action for production 55:
     ///
     /// `TokenExpressionOpt /* Option<T>::None */: ;`
     ///
@@ -2219,7 +2521,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 48:
+    /// Semantic action for production 56:
     ///
     /// `SimpleToken: TokenExpression SimpleTokenOpt /* Option */;`
     ///
@@ -2243,7 +2545,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 49:
+    /// Semantic action for production 57:
     ///
     /// `SimpleTokenOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2260,7 +2562,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 50:
+    /// Semantic action for production 58:
     ///
     /// `SimpleTokenOpt /* Option<T>::None */: ;`
     ///
@@ -2272,7 +2574,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 51:
+    /// Semantic action for production 59:
     ///
     /// `TokenWithStates: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */ TokenExpression TokenWithStatesOpt /* Option */;`
     ///
@@ -2303,7 +2605,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 52:
+    /// Semantic action for production 60:
     ///
     /// `TokenWithStatesOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2320,7 +2622,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 53:
+    /// Semantic action for production 61:
     ///
     /// `TokenWithStatesOpt /* Option<T>::None */: ;`
     ///
@@ -2332,7 +2634,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 54:
+    /// Semantic action for production 62:
     ///
     /// `String: /"(\\.|[^"])*"/;`
     ///
@@ -2348,7 +2650,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 55:
+    /// Semantic action for production 63:
     ///
     /// `RawString: /'(\\.|[^'])*'/;`
     ///
@@ -2364,7 +2666,32 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 56:
+    /// Semantic action for production 64:
+    ///
+    /// `CaseInsensitiveRawString: /'(\\.|[^'])*'i/ ?! /[a-zA-Z0-9_]/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn case_insensitive_raw_string(
+        &mut self,
+        case_insensitive_raw_string: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let case_insensitive_raw_string = case_insensitive_raw_string.token()?.clone();
+        let case_insensitive_raw_string_built = CaseInsensitiveRawString {
+            case_insensitive_raw_string,
+        };
+        // Calling user action here
+        self.user_grammar
+            .case_insensitive_raw_string(&case_insensitive_raw_string_built)?;
+        self.push(
+            ASTType::CaseInsensitiveRawString(case_insensitive_raw_string_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 65:
     ///
     /// `Regex: "/(\\.|[^\/])*/";`
     ///
@@ -2380,7 +2707,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 57:
+    /// Semantic action for production 66:
     ///
     /// `Group: '(' Alternations ')';`
     ///
@@ -2407,7 +2734,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 58:
+    /// Semantic action for production 67:
     ///
     /// `Optional: '[' Alternations ']';`
     ///
@@ -2434,7 +2761,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 59:
+    /// Semantic action for production 68:
     ///
     /// `Repeat: '{' Alternations '}';`
     ///
@@ -2461,7 +2788,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 60:
+    /// Semantic action for production 69:
     ///
     /// `NonTerminal: Identifier NonTerminalOpt /* Option */;`
     ///
@@ -2485,7 +2812,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 61:
+    /// Semantic action for production 70:
     ///
     /// `NonTerminalOpt /* Option<T>::Some */: ASTControl;`
     ///
@@ -2502,7 +2829,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 62:
+    /// Semantic action for production 71:
     ///
     /// `NonTerminalOpt /* Option<T>::None */: ;`
     ///
@@ -2514,7 +2841,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 63:
+    /// Semantic action for production 72:
     ///
     /// `Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;`
     ///
@@ -2530,7 +2857,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 64:
+    /// Semantic action for production 73:
     ///
     /// `ScannerState: '%scanner'^ /* Clipped */ Identifier@state_name '{'^ /* Clipped */ ScannerStateList /* Vec */ '}'^ /* Clipped */;`
     ///
@@ -2558,7 +2885,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 65:
+    /// Semantic action for production 74:
     ///
     /// `ScannerStateList /* Vec<T>::Push */: ScannerDirectives ScannerStateList;`
     ///
@@ -2579,7 +2906,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 66:
+    /// Semantic action for production 75:
     ///
     /// `ScannerStateList /* Vec<T>::New */: ;`
     ///
@@ -2595,7 +2922,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 67:
+    /// Semantic action for production 76:
     ///
     /// `IdentifierList: Identifier IdentifierListList /* Vec */;`
     ///
@@ -2620,7 +2947,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 68:
+    /// Semantic action for production 77:
     ///
     /// `IdentifierListList /* Vec<T>::Push */: ','^ /* Clipped */ Identifier IdentifierListList;`
     ///
@@ -2643,7 +2970,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 69:
+    /// Semantic action for production 78:
     ///
     /// `IdentifierListList /* Vec<T>::New */: ;`
     ///
@@ -2659,7 +2986,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 70:
+    /// Semantic action for production 79:
     ///
     /// `ASTControl: CutOperator;`
     ///
@@ -2676,7 +3003,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 71:
+    /// Semantic action for production 80:
     ///
     /// `ASTControl: MemberName ASTControlOpt /* Option */;`
     ///
@@ -2701,7 +3028,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 72:
+    /// Semantic action for production 81:
     ///
     /// `ASTControl: UserTypeDeclaration;`
     ///
@@ -2721,7 +3048,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 73:
+    /// Semantic action for production 82:
     ///
     /// `ASTControlOpt /* Option<T>::Some */: UserTypeDeclaration;`
     ///
@@ -2741,7 +3068,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 74:
+    /// Semantic action for production 83:
     ///
     /// `ASTControlOpt /* Option<T>::None */: ;`
     ///
@@ -2753,7 +3080,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 75:
+    /// Semantic action for production 84:
     ///
     /// `MemberName: '@'^ /* Clipped */ Identifier;`
     ///
@@ -2773,7 +3100,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 76:
+    /// Semantic action for production 85:
     ///
     /// `CutOperator: '^'^ /* Clipped */;`
     ///
@@ -2788,7 +3115,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 77:
+    /// Semantic action for production 86:
     ///
     /// `UserTypeDeclaration: ':'^ /* Clipped */ UserTypeName;`
     ///
@@ -2816,7 +3143,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 78:
+    /// Semantic action for production 87:
     ///
     /// `UserTypeName: Identifier UserTypeNameList /* Vec */;`
     ///
@@ -2841,7 +3168,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 79:
+    /// Semantic action for production 88:
     ///
     /// `UserTypeNameList /* Vec<T>::Push */: DoubleColon^ /* Clipped */ Identifier UserTypeNameList;`
     ///
@@ -2865,7 +3192,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 80:
+    /// Semantic action for production 89:
     ///
     /// `UserTypeNameList /* Vec<T>::New */: ;`
     ///
@@ -2881,7 +3208,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 81:
+    /// Semantic action for production 90:
     ///
     /// `LookAhead: LookAheadGroup TokenLiteral;`
     ///
@@ -2905,7 +3232,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 82:
+    /// Semantic action for production 91:
     ///
     /// `LookAheadGroup: PositiveLookahead;`
     ///
@@ -2920,7 +3247,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 83:
+    /// Semantic action for production 92:
     ///
     /// `LookAheadGroup: NegativeLookahead;`
     ///
@@ -2935,7 +3262,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 84:
+    /// Semantic action for production 93:
     ///
     /// `PositiveLookahead: '?='^ /* Clipped */;`
     ///
@@ -2954,7 +3281,7 @@ impl<'t, 'u> ParolGrammarAuto<'t, 'u> {
         Ok(())
     }
 
-    /// Semantic action for production 85:
+    /// Semantic action for production 94:
     ///
     /// `NegativeLookahead: '?!'^ /* Clipped */;`
     ///
@@ -2997,91 +3324,100 @@ impl<'t> UserActionsTrait<'t> for ParolGrammarAuto<'t, '_> {
             10 => self.declaration_3(&children[0], &children[1], &children[2], &children[3]),
             11 => self.declaration_4(&children[0], &children[1]),
             12 => self.declaration_5(&children[0], &children[1]),
-            13 => self.declaration_6(&children[0]),
-            14 => self.scanner_directives_0(&children[0], &children[1]),
-            15 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
-            16 => self.scanner_directives_2(&children[0]),
-            17 => self.scanner_directives_3(&children[0]),
-            18 => self.scanner_directives_4(&children[0], &children[1]),
-            19 => self.scanner_directives_5(&children[0], &children[1], &children[2]),
-            20 => self.scanner_directives_6(&children[0]),
-            21 => self.scanner_state_directives_0(&children[0], &children[1]),
-            22 => self.scanner_state_directives_1(&children[0], &children[1]),
-            23 => self.scanner_state_directives_2(&children[0]),
-            24 => self.grammar_definition(&children[0], &children[1], &children[2]),
-            25 => self.grammar_definition_list_0(&children[0], &children[1]),
-            26 => self.grammar_definition_list_1(),
-            27 => self.double_colon(&children[0]),
-            28 => self.production(&children[0], &children[1], &children[2], &children[3]),
-            29 => self.alternations(&children[0], &children[1]),
-            30 => self.alternations_list_0(&children[0], &children[1], &children[2]),
-            31 => self.alternations_list_1(),
-            32 => self.alternation(&children[0]),
-            33 => self.alternation_list_0(&children[0], &children[1]),
-            34 => self.alternation_list_1(),
-            35 => self.factor_0(&children[0]),
-            36 => self.factor_1(&children[0]),
-            37 => self.factor_2(&children[0]),
-            38 => self.factor_3(&children[0]),
-            39 => self.symbol_0(&children[0]),
-            40 => self.symbol_1(&children[0]),
-            41 => self.symbol_2(&children[0]),
-            42 => self.token_literal_0(&children[0]),
-            43 => self.token_literal_1(&children[0]),
-            44 => self.token_literal_2(&children[0]),
-            45 => self.token_expression(&children[0], &children[1]),
-            46 => self.token_expression_opt_0(&children[0]),
-            47 => self.token_expression_opt_1(),
-            48 => self.simple_token(&children[0], &children[1]),
-            49 => self.simple_token_opt_0(&children[0]),
-            50 => self.simple_token_opt_1(),
-            51 => self.token_with_states(
+            13 => self.declaration_6(&children[0], &children[1]),
+            14 => self.declaration_7(&children[0]),
+            15 => self.scanner_directives_0(&children[0], &children[1]),
+            16 => self.scanner_directives_1(&children[0], &children[1], &children[2]),
+            17 => self.scanner_directives_2(&children[0], &children[1], &children[2]),
+            18 => self.scanner_directives_3(&children[0]),
+            19 => self.scanner_directives_4(&children[0]),
+            20 => self.scanner_directives_5(&children[0], &children[1]),
+            21 => self.scanner_directives_6(&children[0], &children[1], &children[2]),
+            22 => self.scanner_directives_7(&children[0]),
+            23 => self.scanner_directives_8(&children[0], &children[1], &children[2], &children[3]),
+            24 => self.scanner_directives_9(&children[0], &children[1]),
+            25 => self.scanner_state_directives_0(&children[0], &children[1]),
+            26 => self.scanner_state_directives_1(&children[0], &children[1]),
+            27 => self.scanner_state_directives_2(&children[0]),
+            28 => self.grammar_definition(&children[0], &children[1], &children[2]),
+            29 => self.grammar_definition_list_0(&children[0], &children[1]),
+            30 => self.grammar_definition_list_1(),
+            31 => self.double_colon(&children[0]),
+            32 => self.production(&children[0], &children[1], &children[2], &children[3]),
+            33 => self.alternations(&children[0], &children[1]),
+            34 => self.alternations_list_0(&children[0], &children[1], &children[2]),
+            35 => self.alternations_list_1(),
+            36 => self.alternation(&children[0], &children[1]),
+            37 => self.alternation_list_0(&children[0], &children[1]),
+            38 => self.alternation_list_1(),
+            39 => self.alternation_opt_0(&children[0]),
+            40 => self.alternation_opt_1(),
+            41 => self.predicate(&children[0], &children[1], &children[2]),
+            42 => self.factor_0(&children[0]),
+            43 => self.factor_1(&children[0]),
+            44 => self.factor_2(&children[0]),
+            45 => self.factor_3(&children[0]),
+            46 => self.symbol_0(&children[0]),
+            47 => self.symbol_1(&children[0]),
+            48 => self.symbol_2(&children[0]),
+            49 => self.token_literal_0(&children[0]),
+            50 => self.token_literal_1(&children[0]),
+            51 => self.token_literal_2(&children[0]),
+            52 => self.token_literal_3(&children[0]),
+            53 => self.token_expression(&children[0], &children[1]),
+            54 => self.token_expression_opt_0(&children[0]),
+            55 => self.token_expression_opt_1(),
+            56 => self.simple_token(&children[0], &children[1]),
+            57 => self.simple_token_opt_0(&children[0]),
+            58 => self.simple_token_opt_1(),
+            59 => self.token_with_states(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            52 => self.token_with_states_opt_0(&children[0]),
-            53 => self.token_with_states_opt_1(),
-            54 => self.string(&children[0]),
-            55 => self.raw_string(&children[0]),
-            56 => self.regex(&children[0]),
-            57 => self.group(&children[0], &children[1], &children[2]),
-            58 => self.optional(&children[0], &children[1], &children[2]),
-            59 => self.repeat(&children[0], &children[1], &children[2]),
-            60 => self.non_terminal(&children[0], &children[1]),
-            61 => self.non_terminal_opt_0(&children[0]),
-            62 => self.non_terminal_opt_1(),
-            63 => self.identifier(&children[0]),
-            64 => self.scanner_state(
+            60 => self.token_with_states_opt_0(&children[0]),
+            61 => self.token_with_states_opt_1(),
+            62 => self.string(&children[0]),
+            63 => self.raw_string(&children[0]),
+            64 => self.case_insensitive_raw_string(&children[0]),
+            65 => self.regex(&children[0]),
+            66 => self.group(&children[0], &children[1], &children[2]),
+            67 => self.optional(&children[0], &children[1], &children[2]),
+            68 => self.repeat(&children[0], &children[1], &children[2]),
+            69 => self.non_terminal(&children[0], &children[1]),
+            70 => self.non_terminal_opt_0(&children[0]),
+            71 => self.non_terminal_opt_1(),
+            72 => self.identifier(&children[0]),
+            73 => self.scanner_state(
                 &children[0],
                 &children[1],
                 &children[2],
                 &children[3],
                 &children[4],
             ),
-            65 => self.scanner_state_list_0(&children[0], &children[1]),
-            66 => self.scanner_state_list_1(),
-            67 => self.identifier_list(&children[0], &children[1]),
-            68 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
-            69 => self.identifier_list_list_1(),
-            70 => self.a_s_t_control_0(&children[0]),
-            71 => self.a_s_t_control_1(&children[0], &children[1]),
-            72 => self.a_s_t_control_2(&children[0]),
-            73 => self.a_s_t_control_opt_0(&children[0]),
-            74 => self.a_s_t_control_opt_1(),
-            75 => self.member_name(&children[0], &children[1]),
-            76 => self.cut_operator(&children[0]),
-            77 => self.user_type_declaration(&children[0], &children[1]),
-            78 => self.user_type_name(&children[0], &children[1]),
-            79 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
-            80 => self.user_type_name_list_1(),
-            81 => self.look_ahead(&children[0], &children[1]),
-            82 => self.look_ahead_group_0(&children[0]),
-            83 => self.look_ahead_group_1(&children[0]),
-            84 => self.positive_lookahead(&children[0]),
-            85 => self.negative_lookahead(&children[0]),
+            74 => self.scanner_state_list_0(&children[0], &children[1]),
+            75 => self.scanner_state_list_1(),
+            76 => self.identifier_list(&children[0], &children[1]),
+            77 => self.identifier_list_list_0(&children[0], &children[1], &children[2]),
+            78 => self.identifier_list_list_1(),
+            79 => self.a_s_t_control_0(&children[0]),
+            80 => self.a_s_t_control_1(&children[0], &children[1]),
+            81 => self.a_s_t_control_2(&children[0]),
+            82 => self.a_s_t_control_opt_0(&children[0]),
+            83 => self.a_s_t_control_opt_1(),
+            84 => self.member_name(&children[0], &children[1]),
+            85 => self.cut_operator(&children[0]),
+            86 => self.user_type_declaration(&children[0], &children[1]),
+            87 => self.user_type_name(&children[0], &children[1]),
+            88 => self.user_type_name_list_0(&children[0], &children[1], &children[2]),
+            89 => self.user_type_name_list_1(),
+            90 => self.look_ahead(&children[0], &children[1]),
+            91 => self.look_ahead_group_0(&children[0]),
+            92 => self.look_ahead_group_1(&children[0]),
+            93 => self.positive_lookahead(&children[0]),
+            94 => self.negative_lookahead(&children[0]),
             _ => Err(ParserError::InternalError(format!(
                 "Unhandled production number: {prod_num}"
             ))
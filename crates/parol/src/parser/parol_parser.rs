@@ -16,7 +16,7 @@ use std::path::Path;
 use crate::parser::parol_grammar::ParolGrammar;
 use crate::parser::parol_grammar_trait::ParolGrammarAuto;
 
-pub const TERMINAL_NAMES: &[&str; 47] = &[
+pub const TERMINAL_NAMES: &[&str; 53] = &[
     /*  0 */ "EndOfInput",
     /*  1 */ "Newline",
     /*  2 */ "Whitespace",
@@ -30,40 +30,46 @@ pub const TERMINAL_NAMES: &[&str; 47] = &[
     /* 10 */ "PercentNtUnderscoreType",
     /* 11 */ "PercentTUnderscoreType",
     /* 12 */ "PercentGrammarUnderscoreType",
-    /* 13 */ "PercentLineUnderscoreComment",
-    /* 14 */ "PercentBlockUnderscoreComment",
-    /* 15 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
-    /* 16 */ "PercentAutoUnderscoreWsUnderscoreOff",
-    /* 17 */ "PercentSkip",
-    /* 18 */ "PercentOn",
-    /* 19 */ "PercentAllowUnderscoreUnmatched",
-    /* 20 */ "PercentEnter",
-    /* 21 */ "PercentPush",
-    /* 22 */ "PercentPop",
-    /* 23 */ "PercentPercent",
-    /* 24 */ "DoubleColon",
-    /* 25 */ "Colon",
-    /* 26 */ "Semicolon",
-    /* 27 */ "Or",
-    /* 28 */ "LT",
-    /* 29 */ "GT",
-    /* 30 */ "String",
-    /* 31 */ "RawString",
-    /* 32 */ "Regex",
-    /* 33 */ "LParen",
-    /* 34 */ "RParen",
-    /* 35 */ "LBracket",
-    /* 36 */ "RBracket",
-    /* 37 */ "LBrace",
-    /* 38 */ "RBrace",
-    /* 39 */ "Identifier",
-    /* 40 */ "PercentScanner",
-    /* 41 */ "Comma",
-    /* 42 */ "At",
-    /* 43 */ "CutOperator",
-    /* 44 */ "PositiveLookahead",
-    /* 45 */ "NegativeLookahead",
-    /* 46 */ "Error",
+    /* 13 */ "PercentEntry",
+    /* 14 */ "PercentLineUnderscoreComment",
+    /* 15 */ "PercentBlockUnderscoreComment",
+    /* 16 */ "PercentNestedUnderscoreBlockUnderscoreComment",
+    /* 17 */ "PercentAutoUnderscoreNewlineUnderscoreOff",
+    /* 18 */ "PercentAutoUnderscoreWsUnderscoreOff",
+    /* 19 */ "PercentSkip",
+    /* 20 */ "PercentOn",
+    /* 21 */ "PercentAllowUnderscoreUnmatched",
+    /* 22 */ "PercentOffside",
+    /* 23 */ "PercentExternal",
+    /* 24 */ "PercentEnter",
+    /* 25 */ "PercentPush",
+    /* 26 */ "PercentPop",
+    /* 27 */ "PercentPercent",
+    /* 28 */ "DoubleColon",
+    /* 29 */ "Colon",
+    /* 30 */ "Semicolon",
+    /* 31 */ "Or",
+    /* 32 */ "AmpLBrace",
+    /* 33 */ "RBrace",
+    /* 34 */ "LT",
+    /* 35 */ "GT",
+    /* 36 */ "String",
+    /* 37 */ "RawString",
+    /* 38 */ "CaseInsensitiveRawString",
+    /* 39 */ "Regex",
+    /* 40 */ "LParen",
+    /* 41 */ "RParen",
+    /* 42 */ "LBracket",
+    /* 43 */ "RBracket",
+    /* 44 */ "LBrace",
+    /* 45 */ "Identifier",
+    /* 46 */ "PercentScanner",
+    /* 47 */ "Comma",
+    /* 48 */ "At",
+    /* 49 */ "CutOperator",
+    /* 50 */ "PositiveLookahead",
+    /* 51 */ "NegativeLookahead",
+    /* 52 */ "Error",
 ];
 
 scanner! {
@@ -81,40 +87,46 @@ scanner! {
             token r"%nt_type" => 10; // "PercentNtUnderscoreType"
             token r"%t_type" => 11; // "PercentTUnderscoreType"
             token r"%grammar_type" => 12; // "PercentGrammarUnderscoreType"
-            token r"%line_comment" => 13; // "PercentLineUnderscoreComment"
-            token r"%block_comment" => 14; // "PercentBlockUnderscoreComment"
-            token r"%auto_newline_off" => 15; // "PercentAutoUnderscoreNewlineUnderscoreOff"
-            token r"%auto_ws_off" => 16; // "PercentAutoUnderscoreWsUnderscoreOff"
-            token r"%skip" => 17; // "PercentSkip"
-            token r"%on" => 18; // "PercentOn"
-            token r"%allow_unmatched" => 19; // "PercentAllowUnderscoreUnmatched"
-            token r"%enter" => 20; // "PercentEnter"
-            token r"%push" => 21; // "PercentPush"
-            token r"%pop" => 22; // "PercentPop"
-            token r"%%" => 23; // "PercentPercent"
-            token r"::" => 24; // "DoubleColon"
-            token r":" => 25; // "Colon"
-            token r";" => 26; // "Semicolon"
-            token r"\|" => 27; // "Or"
-            token r"<" => 28; // "LT"
-            token r">" => 29; // "GT"
-            token r#""(\\.|[^"])*""# => 30; // "String"
-            token r"'(\\.|[^'])*'" => 31; // "RawString"
-            token r"/(\\.|[^\/])*/" => 32; // "Regex"
-            token r"\(" => 33; // "LParen"
-            token r"\)" => 34; // "RParen"
-            token r"\[" => 35; // "LBracket"
-            token r"\]" => 36; // "RBracket"
-            token r"\{" => 37; // "LBrace"
-            token r"\}" => 38; // "RBrace"
-            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 39; // "Identifier"
-            token r"%scanner" => 40; // "PercentScanner"
-            token r"," => 41; // "Comma"
-            token r"@" => 42; // "At"
-            token r"\^" => 43; // "CutOperator"
-            token r"\?=" => 44; // "PositiveLookahead"
-            token r"\?!" => 45; // "NegativeLookahead"
-            token r"." => 46; // "Error"
+            token r"%entry" => 13; // "PercentEntry"
+            token r"%line_comment" => 14; // "PercentLineUnderscoreComment"
+            token r"%block_comment" => 15; // "PercentBlockUnderscoreComment"
+            token r"%nested_block_comment" => 16; // "PercentNestedUnderscoreBlockUnderscoreComment"
+            token r"%auto_newline_off" => 17; // "PercentAutoUnderscoreNewlineUnderscoreOff"
+            token r"%auto_ws_off" => 18; // "PercentAutoUnderscoreWsUnderscoreOff"
+            token r"%skip" => 19; // "PercentSkip"
+            token r"%on" => 20; // "PercentOn"
+            token r"%allow_unmatched" => 21; // "PercentAllowUnderscoreUnmatched"
+            token r"%offside" => 22; // "PercentOffside"
+            token r"%external" => 23; // "PercentExternal"
+            token r"%enter" => 24; // "PercentEnter"
+            token r"%push" => 25; // "PercentPush"
+            token r"%pop" => 26; // "PercentPop"
+            token r"%%" => 27; // "PercentPercent"
+            token r"::" => 28; // "DoubleColon"
+            token r":" => 29; // "Colon"
+            token r";" => 30; // "Semicolon"
+            token r"\|" => 31; // "Or"
+            token r"\&\{" => 32; // "AmpLBrace"
+            token r"\}" => 33; // "RBrace"
+            token r"<" => 34; // "LT"
+            token r">" => 35; // "GT"
+            token r#""(\\.|[^"])*""# => 36; // "String"
+            token r"'(\\.|[^'])*'" => 37; // "RawString"
+            token r"'(\\.|[^'])*'i"  not followed by r"[a-zA-Z0-9_]"=> 38; // "CaseInsensitiveRawString"
+            token r"/(\\.|[^\/])*/" => 39; // "Regex"
+            token r"\(" => 40; // "LParen"
+            token r"\)" => 41; // "RParen"
+            token r"\[" => 42; // "LBracket"
+            token r"\]" => 43; // "RBracket"
+            token r"\{" => 44; // "LBrace"
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 45; // "Identifier"
+            token r"%scanner" => 46; // "PercentScanner"
+            token r"," => 47; // "Comma"
+            token r"@" => 48; // "At"
+            token r"\^" => 49; // "CutOperator"
+            token r"\?=" => 50; // "PositiveLookahead"
+            token r"\?!" => 51; // "NegativeLookahead"
+            token r"." => 52; // "Error"
         }
     }
 }
@@ -122,66 +134,69 @@ scanner! {
 const MAX_K: usize = 1;
 pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
 
-pub const NON_TERMINALS: &[&str; 49] = &[
+pub const NON_TERMINALS: &[&str; 52] = &[
     /*  0 */ "ASTControl",
     /*  1 */ "ASTControlOpt",
     /*  2 */ "Alternation",
     /*  3 */ "AlternationList",
-    /*  4 */ "Alternations",
-    /*  5 */ "AlternationsList",
-    /*  6 */ "CutOperator",
-    /*  7 */ "Declaration",
-    /*  8 */ "DoubleColon",
-    /*  9 */ "Factor",
-    /* 10 */ "GrammarDefinition",
-    /* 11 */ "GrammarDefinitionList",
-    /* 12 */ "Group",
-    /* 13 */ "Identifier",
-    /* 14 */ "IdentifierList",
-    /* 15 */ "IdentifierListList",
-    /* 16 */ "LookAhead",
-    /* 17 */ "LookAheadGroup",
-    /* 18 */ "MemberName",
-    /* 19 */ "NegativeLookahead",
-    /* 20 */ "NonTerminal",
-    /* 21 */ "NonTerminalOpt",
-    /* 22 */ "Optional",
-    /* 23 */ "Parol",
-    /* 24 */ "PositiveLookahead",
-    /* 25 */ "Production",
-    /* 26 */ "Prolog",
-    /* 27 */ "PrologList",
-    /* 28 */ "PrologList0",
-    /* 29 */ "RawString",
-    /* 30 */ "Regex",
-    /* 31 */ "Repeat",
-    /* 32 */ "ScannerDirectives",
-    /* 33 */ "ScannerState",
-    /* 34 */ "ScannerStateDirectives",
-    /* 35 */ "ScannerStateList",
-    /* 36 */ "SimpleToken",
-    /* 37 */ "SimpleTokenOpt",
-    /* 38 */ "StartDeclaration",
-    /* 39 */ "String",
-    /* 40 */ "Symbol",
-    /* 41 */ "TokenExpression",
-    /* 42 */ "TokenExpressionOpt",
-    /* 43 */ "TokenLiteral",
-    /* 44 */ "TokenWithStates",
-    /* 45 */ "TokenWithStatesOpt",
-    /* 46 */ "UserTypeDeclaration",
-    /* 47 */ "UserTypeName",
-    /* 48 */ "UserTypeNameList",
+    /*  4 */ "AlternationOpt",
+    /*  5 */ "Alternations",
+    /*  6 */ "AlternationsList",
+    /*  7 */ "CaseInsensitiveRawString",
+    /*  8 */ "CutOperator",
+    /*  9 */ "Declaration",
+    /* 10 */ "DoubleColon",
+    /* 11 */ "Factor",
+    /* 12 */ "GrammarDefinition",
+    /* 13 */ "GrammarDefinitionList",
+    /* 14 */ "Group",
+    /* 15 */ "Identifier",
+    /* 16 */ "IdentifierList",
+    /* 17 */ "IdentifierListList",
+    /* 18 */ "LookAhead",
+    /* 19 */ "LookAheadGroup",
+    /* 20 */ "MemberName",
+    /* 21 */ "NegativeLookahead",
+    /* 22 */ "NonTerminal",
+    /* 23 */ "NonTerminalOpt",
+    /* 24 */ "Optional",
+    /* 25 */ "Parol",
+    /* 26 */ "PositiveLookahead",
+    /* 27 */ "Predicate",
+    /* 28 */ "Production",
+    /* 29 */ "Prolog",
+    /* 30 */ "PrologList",
+    /* 31 */ "PrologList0",
+    /* 32 */ "RawString",
+    /* 33 */ "Regex",
+    /* 34 */ "Repeat",
+    /* 35 */ "ScannerDirectives",
+    /* 36 */ "ScannerState",
+    /* 37 */ "ScannerStateDirectives",
+    /* 38 */ "ScannerStateList",
+    /* 39 */ "SimpleToken",
+    /* 40 */ "SimpleTokenOpt",
+    /* 41 */ "StartDeclaration",
+    /* 42 */ "String",
+    /* 43 */ "Symbol",
+    /* 44 */ "TokenExpression",
+    /* 45 */ "TokenExpressionOpt",
+    /* 46 */ "TokenLiteral",
+    /* 47 */ "TokenWithStates",
+    /* 48 */ "TokenWithStatesOpt",
+    /* 49 */ "UserTypeDeclaration",
+    /* 50 */ "UserTypeName",
+    /* 51 */ "UserTypeNameList",
 ];
 
-pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 49] = &[
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 52] = &[
     /* 0 - "ASTControl" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 3, 72),
-            Trans(0, 42, 2, 71),
-            Trans(0, 43, 1, 70),
+            Trans(0, 29, 3, 81),
+            Trans(0, 48, 2, 80),
+            Trans(0, 49, 1, 79),
         ],
         k: 1,
     },
@@ -189,26 +204,27 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 49] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 73),
-            Trans(0, 26, 2, 74),
-            Trans(0, 27, 2, 74),
-            Trans(0, 28, 2, 74),
-            Trans(0, 30, 2, 74),
-            Trans(0, 31, 2, 74),
-            Trans(0, 32, 2, 74),
-            Trans(0, 33, 2, 74),
-            Trans(0, 34, 2, 74),
-            Trans(0, 35, 2, 74),
-            Trans(0, 36, 2, 74),
-            Trans(0, 37, 2, 74),
-            Trans(0, 38, 2, 74),
-            Trans(0, 39, 2, 74),
+            Trans(0, 29, 1, 82),
+            Trans(0, 30, 2, 83),
+            Trans(0, 31, 2, 83),
+            Trans(0, 33, 2, 83),
+            Trans(0, 34, 2, 83),
+            Trans(0, 36, 2, 83),
+            Trans(0, 37, 2, 83),
+            Trans(0, 38, 2, 83),
+            Trans(0, 39, 2, 83),
+            Trans(0, 40, 2, 83),
+            Trans(0, 41, 2, 83),
+            Trans(0, 42, 2, 83),
+            Trans(0, 43, 2, 83),
+            Trans(0, 44, 2, 83),
+            Trans(0, 45, 2, 83),
         ],
         k: 1,
     },
     /* 2 - "Alternation" */
     LookaheadDFA {
-        prod0: 32,
+        prod0: 36,
         transitions: &[],
         k: 0,
     },
@@ -216,47 +232,76 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 49] = &[
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 26, 2, 34),
-            Trans(0, 27, 2, 34),
-            Trans(0, 28, 1, 33),
-            Trans(0, 30, 1, 33),
-            Trans(0, 31, 1, 33),
-            Trans(0, 32, 1, 33),
-            Trans(0, 33, 1, 33),
-            Trans(0, 34, 2, 34),
-            Trans(0, 35, 1, 33),
-            Trans(0, 36, 2, 34),
-            Trans(0, 37, 1, 33),
-            Trans(0, 38, 2, 34),
-            Trans(0, 39, 1, 33),
+            Trans(0, 30, 2, 38),
+            Trans(0, 31, 2, 38),
+            Trans(0, 33, 2, 38),
+            Trans(0, 34, 1, 37),
+            Trans(0, 36, 1, 37),
+            Trans(0, 37, 1, 37),
+            Trans(0, 38, 1, 37),
+            Trans(0, 39, 1, 37),
+            Trans(0, 40, 1, 37),
+            Trans(0, 41, 2, 38),
+            Trans(0, 42, 1, 37),
+            Trans(0, 43, 2, 38),
+            Trans(0, 44, 1, 37),
+            Trans(0, 45, 1, 37),
+        ],
+        k: 1,
+    },
+    /* 4 - "AlternationOpt" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 30, 2, 40),
+            Trans(0, 31, 2, 40),
+            Trans(0, 32, 1, 39),
+            Trans(0, 33, 2, 40),
+            Trans(0, 34, 2, 40),
+            Trans(0, 36, 2, 40),
+            Trans(0, 37, 2, 40),
+            Trans(0, 38, 2, 40),
+            Trans(0, 39, 2, 40),
+            Trans(0, 40, 2, 40),
+            Trans(0, 41, 2, 40),
+            Trans(0, 42, 2, 40),
+            Trans(0, 43, 2, 40),
+            Trans(0, 44, 2, 40),
+            Trans(0, 45, 2, 40),
         ],
         k: 1,
     },
-    /* 4 - "Alternations" */
+    /* 5 - "Alternations" */
     LookaheadDFA {
-        prod0: 29,
+        prod0: 33,
         transitions: &[],
         k: 0,
     },
-    /* 5 - "AlternationsList" */
+    /* 6 - "AlternationsList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 26, 2, 31),
-            Trans(0, 27, 1, 30),
-            Trans(0, 34, 2, 31),
-            Trans(0, 36, 2, 31),
-            Trans(0, 38, 2, 31),
+            Trans(0, 30, 2, 35),
+            Trans(0, 31, 1, 34),
+            Trans(0, 33, 2, 35),
+            Trans(0, 41, 2, 35),
+            Trans(0, 43, 2, 35),
         ],
         k: 1,
     },
-    /* 6 - "CutOperator" */
+    /* 7 - "CaseInsensitiveRawString" */
     LookaheadDFA {
-        prod0: 76,
+        prod0: 64,
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "CutOperator" */
+    LookaheadDFA {
+        prod0: 85,
         transitions: &[],
         k: 0,
     },
-    /* 7 - "Declaration" */
+    /* 9 - "Declaration" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -267,178 +312,194 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 49] = &[
             Trans(0, 11, 5, 11),
             Trans(0, 12, 6, 12),
             Trans(0, 13, 7, 13),
-            Trans(0, 14, 7, 13),
-            Trans(0, 15, 7, 13),
-            Trans(0, 16, 7, 13),
-            Trans(0, 17, 7, 13),
-            Trans(0, 18, 7, 13),
-            Trans(0, 19, 7, 13),
+            Trans(0, 14, 8, 14),
+            Trans(0, 15, 8, 14),
+            Trans(0, 16, 8, 14),
+            Trans(0, 17, 8, 14),
+            Trans(0, 18, 8, 14),
+            Trans(0, 19, 8, 14),
+            Trans(0, 20, 8, 14),
+            Trans(0, 21, 8, 14),
+            Trans(0, 22, 8, 14),
+            Trans(0, 23, 8, 14),
         ],
         k: 1,
     },
-    /* 8 - "DoubleColon" */
+    /* 10 - "DoubleColon" */
     LookaheadDFA {
-        prod0: 27,
+        prod0: 31,
         transitions: &[],
         k: 0,
     },
-    /* 9 - "Factor" */
+    /* 11 - "Factor" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 28, 4, 38),
-            Trans(0, 30, 4, 38),
-            Trans(0, 31, 4, 38),
-            Trans(0, 32, 4, 38),
-            Trans(0, 33, 1, 35),
-            Trans(0, 35, 3, 37),
-            Trans(0, 37, 2, 36),
-            Trans(0, 39, 4, 38),
+            Trans(0, 34, 4, 45),
+            Trans(0, 36, 4, 45),
+            Trans(0, 37, 4, 45),
+            Trans(0, 38, 4, 45),
+            Trans(0, 39, 4, 45),
+            Trans(0, 40, 1, 42),
+            Trans(0, 42, 3, 44),
+            Trans(0, 44, 2, 43),
+            Trans(0, 45, 4, 45),
         ],
         k: 1,
     },
-    /* 10 - "GrammarDefinition" */
+    /* 12 - "GrammarDefinition" */
     LookaheadDFA {
-        prod0: 24,
+        prod0: 28,
         transitions: &[],
         k: 0,
     },
-    /* 11 - "GrammarDefinitionList" */
+    /* 13 - "GrammarDefinitionList" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 0, 2, 26), Trans(0, 39, 1, 25)],
+        transitions: &[Trans(0, 0, 2, 30), Trans(0, 45, 1, 29)],
         k: 1,
     },
-    /* 12 - "Group" */
+    /* 14 - "Group" */
     LookaheadDFA {
-        prod0: 57,
+        prod0: 66,
         transitions: &[],
         k: 0,
     },
-    /* 13 - "Identifier" */
+    /* 15 - "Identifier" */
     LookaheadDFA {
-        prod0: 63,
+        prod0: 72,
         transitions: &[],
         k: 0,
     },
-    /* 14 - "IdentifierList" */
+    /* 16 - "IdentifierList" */
     LookaheadDFA {
-        prod0: 67,
+        prod0: 76,
         transitions: &[],
         k: 0,
     },
-    /* 15 - "IdentifierListList" */
+    /* 17 - "IdentifierListList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 2, 69),
-            Trans(0, 7, 2, 69),
-            Trans(0, 8, 2, 69),
-            Trans(0, 10, 2, 69),
-            Trans(0, 11, 2, 69),
-            Trans(0, 12, 2, 69),
-            Trans(0, 13, 2, 69),
-            Trans(0, 14, 2, 69),
-            Trans(0, 15, 2, 69),
-            Trans(0, 16, 2, 69),
-            Trans(0, 17, 2, 69),
-            Trans(0, 18, 2, 69),
-            Trans(0, 19, 2, 69),
-            Trans(0, 20, 2, 69),
-            Trans(0, 21, 2, 69),
-            Trans(0, 22, 2, 69),
-            Trans(0, 23, 2, 69),
-            Trans(0, 29, 2, 69),
-            Trans(0, 38, 2, 69),
-            Trans(0, 40, 2, 69),
-            Trans(0, 41, 1, 68),
+            Trans(0, 6, 2, 78),
+            Trans(0, 7, 2, 78),
+            Trans(0, 8, 2, 78),
+            Trans(0, 10, 2, 78),
+            Trans(0, 11, 2, 78),
+            Trans(0, 12, 2, 78),
+            Trans(0, 13, 2, 78),
+            Trans(0, 14, 2, 78),
+            Trans(0, 15, 2, 78),
+            Trans(0, 16, 2, 78),
+            Trans(0, 17, 2, 78),
+            Trans(0, 18, 2, 78),
+            Trans(0, 19, 2, 78),
+            Trans(0, 20, 2, 78),
+            Trans(0, 21, 2, 78),
+            Trans(0, 22, 2, 78),
+            Trans(0, 23, 2, 78),
+            Trans(0, 24, 2, 78),
+            Trans(0, 25, 2, 78),
+            Trans(0, 26, 2, 78),
+            Trans(0, 27, 2, 78),
+            Trans(0, 33, 2, 78),
+            Trans(0, 35, 2, 78),
+            Trans(0, 46, 2, 78),
+            Trans(0, 47, 1, 77),
         ],
         k: 1,
     },
-    /* 16 - "LookAhead" */
+    /* 18 - "LookAhead" */
     LookaheadDFA {
-        prod0: 81,
+        prod0: 90,
         transitions: &[],
         k: 0,
     },
-    /* 17 - "LookAheadGroup" */
+    /* 19 - "LookAheadGroup" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 44, 1, 82), Trans(0, 45, 2, 83)],
+        transitions: &[Trans(0, 50, 1, 91), Trans(0, 51, 2, 92)],
         k: 1,
     },
-    /* 18 - "MemberName" */
+    /* 20 - "MemberName" */
     LookaheadDFA {
-        prod0: 75,
+        prod0: 84,
         transitions: &[],
         k: 0,
     },
-    /* 19 - "NegativeLookahead" */
+    /* 21 - "NegativeLookahead" */
     LookaheadDFA {
-        prod0: 85,
+        prod0: 94,
         transitions: &[],
         k: 0,
     },
-    /* 20 - "NonTerminal" */
+    /* 22 - "NonTerminal" */
     LookaheadDFA {
-        prod0: 60,
+        prod0: 69,
         transitions: &[],
         k: 0,
     },
-    /* 21 - "NonTerminalOpt" */
+    /* 23 - "NonTerminalOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 61),
-            Trans(0, 26, 2, 62),
-            Trans(0, 27, 2, 62),
-            Trans(0, 28, 2, 62),
-            Trans(0, 30, 2, 62),
-            Trans(0, 31, 2, 62),
-            Trans(0, 32, 2, 62),
-            Trans(0, 33, 2, 62),
-            Trans(0, 34, 2, 62),
-            Trans(0, 35, 2, 62),
-            Trans(0, 36, 2, 62),
-            Trans(0, 37, 2, 62),
-            Trans(0, 38, 2, 62),
-            Trans(0, 39, 2, 62),
-            Trans(0, 42, 1, 61),
-            Trans(0, 43, 1, 61),
+            Trans(0, 29, 1, 70),
+            Trans(0, 30, 2, 71),
+            Trans(0, 31, 2, 71),
+            Trans(0, 33, 2, 71),
+            Trans(0, 34, 2, 71),
+            Trans(0, 36, 2, 71),
+            Trans(0, 37, 2, 71),
+            Trans(0, 38, 2, 71),
+            Trans(0, 39, 2, 71),
+            Trans(0, 40, 2, 71),
+            Trans(0, 41, 2, 71),
+            Trans(0, 42, 2, 71),
+            Trans(0, 43, 2, 71),
+            Trans(0, 44, 2, 71),
+            Trans(0, 45, 2, 71),
+            Trans(0, 48, 1, 70),
+            Trans(0, 49, 1, 70),
         ],
         k: 1,
     },
-    /* 22 - "Optional" */
+    /* 24 - "Optional" */
     LookaheadDFA {
-        prod0: 58,
+        prod0: 67,
         transitions: &[],
         k: 0,
     },
-    /* 23 - "Parol" */
+    /* 25 - "Parol" */
     LookaheadDFA {
         prod0: 0,
         transitions: &[],
         k: 0,
     },
-    /* 24 - "PositiveLookahead" */
+    /* 26 - "PositiveLookahead" */
     LookaheadDFA {
-        prod0: 84,
+        prod0: 93,
         transitions: &[],
         k: 0,
     },
-    /* 25 - "Production" */
+    /* 27 - "Predicate" */
     LookaheadDFA {
-        prod0: 28,
+        prod0: 41,
+        transitions: &[],
+        k: 0,
+    },
+    /* 28 - "Production" */
+    LookaheadDFA {
+        prod0: 32,
         transitions: &[],
         k: 0,
     },
-    /* 26 - "Prolog" */
+    /* 29 - "Prolog" */
     LookaheadDFA {
         prod0: 1,
         transitions: &[],
         k: 0,
     },
-    /* 27 - "PrologList" */
+    /* 30 - "PrologList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
@@ -455,795 +516,874 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 49] = &[
             Trans(0, 17, 1, 4),
             Trans(0, 18, 1, 4),
             Trans(0, 19, 1, 4),
-            Trans(0, 23, 2, 5),
-            Trans(0, 40, 2, 5),
+            Trans(0, 20, 1, 4),
+            Trans(0, 21, 1, 4),
+            Trans(0, 22, 1, 4),
+            Trans(0, 23, 1, 4),
+            Trans(0, 27, 2, 5),
+            Trans(0, 46, 2, 5),
         ],
         k: 1,
     },
-    /* 28 - "PrologList0" */
+    /* 31 - "PrologList0" */
     LookaheadDFA {
         prod0: -1,
-        transitions: &[Trans(0, 23, 2, 3), Trans(0, 40, 1, 2)],
+        transitions: &[Trans(0, 27, 2, 3), Trans(0, 46, 1, 2)],
         k: 1,
     },
-    /* 29 - "RawString" */
+    /* 32 - "RawString" */
     LookaheadDFA {
-        prod0: 55,
+        prod0: 63,
         transitions: &[],
         k: 0,
     },
-    /* 30 - "Regex" */
+    /* 33 - "Regex" */
     LookaheadDFA {
-        prod0: 56,
+        prod0: 65,
         transitions: &[],
         k: 0,
     },
-    /* 31 - "Repeat" */
+    /* 34 - "Repeat" */
     LookaheadDFA {
-        prod0: 59,
+        prod0: 68,
         transitions: &[],
         k: 0,
     },
-    /* 32 - "ScannerDirectives" */
+    /* 35 - "ScannerDirectives" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 13, 1, 14),
-            Trans(0, 14, 2, 15),
-            Trans(0, 15, 3, 16),
-            Trans(0, 16, 4, 17),
-            Trans(0, 17, 5, 18),
-            Trans(0, 18, 6, 19),
-            Trans(0, 19, 7, 20),
+            Trans(0, 14, 1, 15),
+            Trans(0, 15, 2, 16),
+            Trans(0, 16, 3, 17),
+            Trans(0, 17, 4, 18),
+            Trans(0, 18, 5, 19),
+            Trans(0, 19, 6, 20),
+            Trans(0, 20, 7, 21),
+            Trans(0, 21, 8, 22),
+            Trans(0, 22, 9, 23),
+            Trans(0, 23, 10, 24),
         ],
         k: 1,
     },
-    /* 33 - "ScannerState" */
+    /* 36 - "ScannerState" */
     LookaheadDFA {
-        prod0: 64,
+        prod0: 73,
         transitions: &[],
         k: 0,
     },
-    /* 34 - "ScannerStateDirectives" */
+    /* 37 - "ScannerStateDirectives" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 20, 1, 21),
-            Trans(0, 21, 2, 22),
-            Trans(0, 22, 3, 23),
+            Trans(0, 24, 1, 25),
+            Trans(0, 25, 2, 26),
+            Trans(0, 26, 3, 27),
         ],
         k: 1,
     },
-    /* 35 - "ScannerStateList" */
+    /* 38 - "ScannerStateList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 13, 1, 65),
-            Trans(0, 14, 1, 65),
-            Trans(0, 15, 1, 65),
-            Trans(0, 16, 1, 65),
-            Trans(0, 17, 1, 65),
-            Trans(0, 18, 1, 65),
-            Trans(0, 19, 1, 65),
-            Trans(0, 38, 2, 66),
+            Trans(0, 14, 1, 74),
+            Trans(0, 15, 1, 74),
+            Trans(0, 16, 1, 74),
+            Trans(0, 17, 1, 74),
+            Trans(0, 18, 1, 74),
+            Trans(0, 19, 1, 74),
+            Trans(0, 20, 1, 74),
+            Trans(0, 21, 1, 74),
+            Trans(0, 22, 1, 74),
+            Trans(0, 23, 1, 74),
+            Trans(0, 33, 2, 75),
         ],
         k: 1,
     },
-    /* 36 - "SimpleToken" */
+    /* 39 - "SimpleToken" */
     LookaheadDFA {
-        prod0: 48,
+        prod0: 56,
         transitions: &[],
         k: 0,
     },
-    /* 37 - "SimpleTokenOpt" */
+    /* 40 - "SimpleTokenOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 49),
-            Trans(0, 26, 2, 50),
-            Trans(0, 27, 2, 50),
-            Trans(0, 28, 2, 50),
-            Trans(0, 30, 2, 50),
-            Trans(0, 31, 2, 50),
-            Trans(0, 32, 2, 50),
-            Trans(0, 33, 2, 50),
-            Trans(0, 34, 2, 50),
-            Trans(0, 35, 2, 50),
-            Trans(0, 36, 2, 50),
-            Trans(0, 37, 2, 50),
-            Trans(0, 38, 2, 50),
-            Trans(0, 39, 2, 50),
-            Trans(0, 42, 1, 49),
-            Trans(0, 43, 1, 49),
+            Trans(0, 29, 1, 57),
+            Trans(0, 30, 2, 58),
+            Trans(0, 31, 2, 58),
+            Trans(0, 33, 2, 58),
+            Trans(0, 34, 2, 58),
+            Trans(0, 36, 2, 58),
+            Trans(0, 37, 2, 58),
+            Trans(0, 38, 2, 58),
+            Trans(0, 39, 2, 58),
+            Trans(0, 40, 2, 58),
+            Trans(0, 41, 2, 58),
+            Trans(0, 42, 2, 58),
+            Trans(0, 43, 2, 58),
+            Trans(0, 44, 2, 58),
+            Trans(0, 45, 2, 58),
+            Trans(0, 48, 1, 57),
+            Trans(0, 49, 1, 57),
         ],
         k: 1,
     },
-    /* 38 - "StartDeclaration" */
+    /* 41 - "StartDeclaration" */
     LookaheadDFA {
         prod0: 6,
         transitions: &[],
         k: 0,
     },
-    /* 39 - "String" */
+    /* 42 - "String" */
     LookaheadDFA {
-        prod0: 54,
+        prod0: 62,
         transitions: &[],
         k: 0,
     },
-    /* 40 - "Symbol" */
+    /* 43 - "Symbol" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 28, 3, 41),
-            Trans(0, 30, 2, 40),
-            Trans(0, 31, 2, 40),
-            Trans(0, 32, 2, 40),
-            Trans(0, 39, 1, 39),
+            Trans(0, 34, 3, 48),
+            Trans(0, 36, 2, 47),
+            Trans(0, 37, 2, 47),
+            Trans(0, 38, 2, 47),
+            Trans(0, 39, 2, 47),
+            Trans(0, 45, 1, 46),
         ],
         k: 1,
     },
-    /* 41 - "TokenExpression" */
+    /* 44 - "TokenExpression" */
     LookaheadDFA {
-        prod0: 45,
+        prod0: 53,
         transitions: &[],
         k: 0,
     },
-    /* 42 - "TokenExpressionOpt" */
+    /* 45 - "TokenExpressionOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 2, 47),
-            Trans(0, 26, 2, 47),
-            Trans(0, 27, 2, 47),
-            Trans(0, 28, 2, 47),
-            Trans(0, 30, 2, 47),
-            Trans(0, 31, 2, 47),
-            Trans(0, 32, 2, 47),
-            Trans(0, 33, 2, 47),
-            Trans(0, 34, 2, 47),
-            Trans(0, 35, 2, 47),
-            Trans(0, 36, 2, 47),
-            Trans(0, 37, 2, 47),
-            Trans(0, 38, 2, 47),
-            Trans(0, 39, 2, 47),
-            Trans(0, 42, 2, 47),
-            Trans(0, 43, 2, 47),
-            Trans(0, 44, 1, 46),
-            Trans(0, 45, 1, 46),
+            Trans(0, 29, 2, 55),
+            Trans(0, 30, 2, 55),
+            Trans(0, 31, 2, 55),
+            Trans(0, 33, 2, 55),
+            Trans(0, 34, 2, 55),
+            Trans(0, 36, 2, 55),
+            Trans(0, 37, 2, 55),
+            Trans(0, 38, 2, 55),
+            Trans(0, 39, 2, 55),
+            Trans(0, 40, 2, 55),
+            Trans(0, 41, 2, 55),
+            Trans(0, 42, 2, 55),
+            Trans(0, 43, 2, 55),
+            Trans(0, 44, 2, 55),
+            Trans(0, 45, 2, 55),
+            Trans(0, 48, 2, 55),
+            Trans(0, 49, 2, 55),
+            Trans(0, 50, 1, 54),
+            Trans(0, 51, 1, 54),
         ],
         k: 1,
     },
-    /* 43 - "TokenLiteral" */
+    /* 46 - "TokenLiteral" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 30, 1, 42),
-            Trans(0, 31, 2, 43),
-            Trans(0, 32, 3, 44),
+            Trans(0, 36, 1, 49),
+            Trans(0, 37, 2, 50),
+            Trans(0, 38, 3, 51),
+            Trans(0, 39, 4, 52),
         ],
         k: 1,
     },
-    /* 44 - "TokenWithStates" */
+    /* 47 - "TokenWithStates" */
     LookaheadDFA {
-        prod0: 51,
+        prod0: 59,
         transitions: &[],
         k: 0,
     },
-    /* 45 - "TokenWithStatesOpt" */
+    /* 48 - "TokenWithStatesOpt" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 25, 1, 52),
-            Trans(0, 26, 2, 53),
-            Trans(0, 27, 2, 53),
-            Trans(0, 28, 2, 53),
-            Trans(0, 30, 2, 53),
-            Trans(0, 31, 2, 53),
-            Trans(0, 32, 2, 53),
-            Trans(0, 33, 2, 53),
-            Trans(0, 34, 2, 53),
-            Trans(0, 35, 2, 53),
-            Trans(0, 36, 2, 53),
-            Trans(0, 37, 2, 53),
-            Trans(0, 38, 2, 53),
-            Trans(0, 39, 2, 53),
-            Trans(0, 42, 1, 52),
-            Trans(0, 43, 1, 52),
+            Trans(0, 29, 1, 60),
+            Trans(0, 30, 2, 61),
+            Trans(0, 31, 2, 61),
+            Trans(0, 33, 2, 61),
+            Trans(0, 34, 2, 61),
+            Trans(0, 36, 2, 61),
+            Trans(0, 37, 2, 61),
+            Trans(0, 38, 2, 61),
+            Trans(0, 39, 2, 61),
+            Trans(0, 40, 2, 61),
+            Trans(0, 41, 2, 61),
+            Trans(0, 42, 2, 61),
+            Trans(0, 43, 2, 61),
+            Trans(0, 44, 2, 61),
+            Trans(0, 45, 2, 61),
+            Trans(0, 48, 1, 60),
+            Trans(0, 49, 1, 60),
         ],
         k: 1,
     },
-    /* 46 - "UserTypeDeclaration" */
+    /* 49 - "UserTypeDeclaration" */
     LookaheadDFA {
-        prod0: 77,
+        prod0: 86,
         transitions: &[],
         k: 0,
     },
-    /* 47 - "UserTypeName" */
+    /* 50 - "UserTypeName" */
     LookaheadDFA {
-        prod0: 78,
+        prod0: 87,
         transitions: &[],
         k: 0,
     },
-    /* 48 - "UserTypeNameList" */
+    /* 51 - "UserTypeNameList" */
     LookaheadDFA {
         prod0: -1,
         transitions: &[
-            Trans(0, 6, 2, 80),
-            Trans(0, 7, 2, 80),
-            Trans(0, 8, 2, 80),
-            Trans(0, 10, 2, 80),
-            Trans(0, 11, 2, 80),
-            Trans(0, 12, 2, 80),
-            Trans(0, 13, 2, 80),
-            Trans(0, 14, 2, 80),
-            Trans(0, 15, 2, 80),
-            Trans(0, 16, 2, 80),
-            Trans(0, 17, 2, 80),
-            Trans(0, 18, 2, 80),
-            Trans(0, 19, 2, 80),
-            Trans(0, 23, 2, 80),
-            Trans(0, 24, 1, 79),
-            Trans(0, 26, 2, 80),
-            Trans(0, 27, 2, 80),
-            Trans(0, 28, 2, 80),
-            Trans(0, 30, 2, 80),
-            Trans(0, 31, 2, 80),
-            Trans(0, 32, 2, 80),
-            Trans(0, 33, 2, 80),
-            Trans(0, 34, 2, 80),
-            Trans(0, 35, 2, 80),
-            Trans(0, 36, 2, 80),
-            Trans(0, 37, 2, 80),
-            Trans(0, 38, 2, 80),
-            Trans(0, 39, 2, 80),
-            Trans(0, 40, 2, 80),
+            Trans(0, 6, 2, 89),
+            Trans(0, 7, 2, 89),
+            Trans(0, 8, 2, 89),
+            Trans(0, 10, 2, 89),
+            Trans(0, 11, 2, 89),
+            Trans(0, 12, 2, 89),
+            Trans(0, 13, 2, 89),
+            Trans(0, 14, 2, 89),
+            Trans(0, 15, 2, 89),
+            Trans(0, 16, 2, 89),
+            Trans(0, 17, 2, 89),
+            Trans(0, 18, 2, 89),
+            Trans(0, 19, 2, 89),
+            Trans(0, 20, 2, 89),
+            Trans(0, 21, 2, 89),
+            Trans(0, 22, 2, 89),
+            Trans(0, 23, 2, 89),
+            Trans(0, 27, 2, 89),
+            Trans(0, 28, 1, 88),
+            Trans(0, 30, 2, 89),
+            Trans(0, 31, 2, 89),
+            Trans(0, 33, 2, 89),
+            Trans(0, 34, 2, 89),
+            Trans(0, 36, 2, 89),
+            Trans(0, 37, 2, 89),
+            Trans(0, 38, 2, 89),
+            Trans(0, 39, 2, 89),
+            Trans(0, 40, 2, 89),
+            Trans(0, 41, 2, 89),
+            Trans(0, 42, 2, 89),
+            Trans(0, 43, 2, 89),
+            Trans(0, 44, 2, 89),
+            Trans(0, 45, 2, 89),
+            Trans(0, 46, 2, 89),
         ],
         k: 1,
     },
 ];
 
-pub const PRODUCTIONS: &[Production; 86] = &[
+pub const PRODUCTIONS: &[Production; 95] = &[
     // 0 - Parol: Prolog GrammarDefinition;
     Production {
-        lhs: 23,
-        production: &[ParseType::N(10), ParseType::N(26)],
+        lhs: 25,
+        production: &[ParseType::N(12), ParseType::N(29)],
         is_push_production: false,
     },
     // 1 - Prolog: StartDeclaration PrologList /* Vec */ PrologList0 /* Vec */;
     Production {
-        lhs: 26,
-        production: &[ParseType::N(28), ParseType::N(27), ParseType::N(38)],
+        lhs: 29,
+        production: &[ParseType::N(31), ParseType::N(30), ParseType::N(41)],
         is_push_production: false,
     },
     // 2 - PrologList0: ScannerState : crate::parser::parol_grammar::ScannerConfig  PrologList0;
     Production {
-        lhs: 28,
-        production: &[ParseType::N(28), ParseType::N(33)],
+        lhs: 31,
+        production: &[ParseType::N(31), ParseType::N(36)],
         is_push_production: true,
     },
     // 3 - PrologList0: ;
     Production {
-        lhs: 28,
+        lhs: 31,
         production: &[],
         is_push_production: false,
     },
     // 4 - PrologList: Declaration PrologList;
     Production {
-        lhs: 27,
-        production: &[ParseType::N(27), ParseType::N(7)],
+        lhs: 30,
+        production: &[ParseType::N(30), ParseType::N(9)],
         is_push_production: true,
     },
     // 5 - PrologList: ;
     Production {
-        lhs: 27,
+        lhs: 30,
         production: &[],
         is_push_production: false,
     },
     // 6 - StartDeclaration: '%start'^ /* Clipped */ Identifier;
     Production {
-        lhs: 38,
-        production: &[ParseType::N(13), ParseType::T(5)],
+        lhs: 41,
+        production: &[ParseType::N(15), ParseType::T(5)],
         is_push_production: false,
     },
     // 7 - Declaration: '%title'^ /* Clipped */ String;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(39), ParseType::T(6)],
+        lhs: 9,
+        production: &[ParseType::N(42), ParseType::T(6)],
         is_push_production: false,
     },
     // 8 - Declaration: '%comment'^ /* Clipped */ String;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(39), ParseType::T(7)],
+        lhs: 9,
+        production: &[ParseType::N(42), ParseType::T(7)],
         is_push_production: false,
     },
     // 9 - Declaration: '%user_type'^ /* Clipped */ Identifier '='^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName ;
     Production {
-        lhs: 7,
+        lhs: 9,
         production: &[
-            ParseType::N(47),
+            ParseType::N(50),
             ParseType::T(9),
-            ParseType::N(13),
+            ParseType::N(15),
             ParseType::T(8),
         ],
         is_push_production: false,
     },
     // 10 - Declaration: "%nt_type"^ /* Clipped */ Identifier@nt_name '='^ /* Clipped */ UserTypeName@nt_type : crate::parser::parol_grammar::UserDefinedTypeName ;
     Production {
-        lhs: 7,
+        lhs: 9,
         production: &[
-            ParseType::N(47),
+            ParseType::N(50),
             ParseType::T(9),
-            ParseType::N(13),
+            ParseType::N(15),
             ParseType::T(10),
         ],
         is_push_production: false,
     },
     // 11 - Declaration: "%t_type"^ /* Clipped */ UserTypeName@t_type : crate::parser::parol_grammar::UserDefinedTypeName ;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(47), ParseType::T(11)],
+        lhs: 9,
+        production: &[ParseType::N(50), ParseType::T(11)],
         is_push_production: false,
     },
     // 12 - Declaration: '%grammar_type'^ /* Clipped */ RawString;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(29), ParseType::T(12)],
+        lhs: 9,
+        production: &[ParseType::N(32), ParseType::T(12)],
         is_push_production: false,
     },
-    // 13 - Declaration: ScannerDirectives;
+    // 13 - Declaration: '%entry'^ /* Clipped */ IdentifierList;
     Production {
-        lhs: 7,
-        production: &[ParseType::N(32)],
+        lhs: 9,
+        production: &[ParseType::N(16), ParseType::T(13)],
         is_push_production: false,
     },
-    // 14 - ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;
+    // 14 - Declaration: ScannerDirectives;
     Production {
-        lhs: 32,
-        production: &[ParseType::N(43), ParseType::T(13)],
+        lhs: 9,
+        production: &[ParseType::N(35)],
         is_push_production: false,
     },
-    // 15 - ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;
+    // 15 - ScannerDirectives: '%line_comment'^ /* Clipped */ TokenLiteral;
     Production {
-        lhs: 32,
-        production: &[ParseType::N(43), ParseType::N(43), ParseType::T(14)],
+        lhs: 35,
+        production: &[ParseType::N(46), ParseType::T(14)],
         is_push_production: false,
     },
-    // 16 - ScannerDirectives: '%auto_newline_off'^ /* Clipped */;
+    // 16 - ScannerDirectives: '%block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;
     Production {
-        lhs: 32,
-        production: &[ParseType::T(15)],
+        lhs: 35,
+        production: &[ParseType::N(46), ParseType::N(46), ParseType::T(15)],
         is_push_production: false,
     },
-    // 17 - ScannerDirectives: '%auto_ws_off'^ /* Clipped */;
+    // 17 - ScannerDirectives: '%nested_block_comment'^ /* Clipped */ TokenLiteral TokenLiteral;
     Production {
-        lhs: 32,
-        production: &[ParseType::T(16)],
+        lhs: 35,
+        production: &[ParseType::N(46), ParseType::N(46), ParseType::T(16)],
         is_push_production: false,
     },
-    // 18 - ScannerDirectives: '%skip'^ /* Clipped */ IdentifierList;
+    // 18 - ScannerDirectives: '%auto_newline_off'^ /* Clipped */;
     Production {
-        lhs: 32,
-        production: &[ParseType::N(14), ParseType::T(17)],
+        lhs: 35,
+        production: &[ParseType::T(17)],
         is_push_production: false,
     },
-    // 19 - ScannerDirectives: '%on'^ /* Clipped */ IdentifierList ScannerStateDirectives : crate::parser::parol_grammar::ScannerStateSwitch ;
+    // 19 - ScannerDirectives: '%auto_ws_off'^ /* Clipped */;
     Production {
-        lhs: 32,
-        production: &[ParseType::N(34), ParseType::N(14), ParseType::T(18)],
+        lhs: 35,
+        production: &[ParseType::T(18)],
         is_push_production: false,
     },
-    // 20 - ScannerDirectives: '%allow_unmatched'^ /* Clipped */;
+    // 20 - ScannerDirectives: '%skip'^ /* Clipped */ IdentifierList;
     Production {
-        lhs: 32,
-        production: &[ParseType::T(19)],
+        lhs: 35,
+        production: &[ParseType::N(16), ParseType::T(19)],
         is_push_production: false,
     },
-    // 21 - ScannerStateDirectives: '%enter'^ /* Clipped */ Identifier;
+    // 21 - ScannerDirectives: '%on'^ /* Clipped */ IdentifierList ScannerStateDirectives : crate::parser::parol_grammar::ScannerStateSwitch ;
     Production {
-        lhs: 34,
-        production: &[ParseType::N(13), ParseType::T(20)],
+        lhs: 35,
+        production: &[ParseType::N(37), ParseType::N(16), ParseType::T(20)],
         is_push_production: false,
     },
-    // 22 - ScannerStateDirectives: '%push'^ /* Clipped */ Identifier;
+    // 22 - ScannerDirectives: '%allow_unmatched'^ /* Clipped */;
     Production {
-        lhs: 34,
-        production: &[ParseType::N(13), ParseType::T(21)],
+        lhs: 35,
+        production: &[ParseType::T(21)],
         is_push_production: false,
     },
-    // 23 - ScannerStateDirectives: '%pop';
+    // 23 - ScannerDirectives: '%offside'^ /* Clipped */ Identifier@indent Identifier@dedent Identifier@newline;
     Production {
-        lhs: 34,
-        production: &[ParseType::T(22)],
+        lhs: 35,
+        production: &[
+            ParseType::N(15),
+            ParseType::N(15),
+            ParseType::N(15),
+            ParseType::T(22),
+        ],
         is_push_production: false,
     },
-    // 24 - GrammarDefinition: '%%'^ /* Clipped */ Production GrammarDefinitionList /* Vec */;
+    // 24 - ScannerDirectives: '%external'^ /* Clipped */ IdentifierList;
     Production {
-        lhs: 10,
-        production: &[ParseType::N(11), ParseType::N(25), ParseType::T(23)],
+        lhs: 35,
+        production: &[ParseType::N(16), ParseType::T(23)],
         is_push_production: false,
     },
-    // 25 - GrammarDefinitionList: Production GrammarDefinitionList;
+    // 25 - ScannerStateDirectives: '%enter'^ /* Clipped */ Identifier;
     Production {
-        lhs: 11,
-        production: &[ParseType::N(11), ParseType::N(25)],
+        lhs: 37,
+        production: &[ParseType::N(15), ParseType::T(24)],
+        is_push_production: false,
+    },
+    // 26 - ScannerStateDirectives: '%push'^ /* Clipped */ Identifier;
+    Production {
+        lhs: 37,
+        production: &[ParseType::N(15), ParseType::T(25)],
+        is_push_production: false,
+    },
+    // 27 - ScannerStateDirectives: '%pop';
+    Production {
+        lhs: 37,
+        production: &[ParseType::T(26)],
+        is_push_production: false,
+    },
+    // 28 - GrammarDefinition: '%%'^ /* Clipped */ Production GrammarDefinitionList /* Vec */;
+    Production {
+        lhs: 12,
+        production: &[ParseType::N(13), ParseType::N(28), ParseType::T(27)],
+        is_push_production: false,
+    },
+    // 29 - GrammarDefinitionList: Production GrammarDefinitionList;
+    Production {
+        lhs: 13,
+        production: &[ParseType::N(13), ParseType::N(28)],
         is_push_production: true,
     },
-    // 26 - GrammarDefinitionList: ;
+    // 30 - GrammarDefinitionList: ;
     Production {
-        lhs: 11,
+        lhs: 13,
         production: &[],
         is_push_production: false,
     },
-    // 27 - DoubleColon: '::';
+    // 31 - DoubleColon: '::';
     Production {
-        lhs: 8,
-        production: &[ParseType::T(24)],
+        lhs: 10,
+        production: &[ParseType::T(28)],
         is_push_production: false,
     },
-    // 28 - Production: Identifier ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;
+    // 32 - Production: Identifier ':'^ /* Clipped */ Alternations ';'^ /* Clipped */;
     Production {
-        lhs: 25,
+        lhs: 28,
         production: &[
-            ParseType::T(26),
-            ParseType::N(4),
-            ParseType::T(25),
-            ParseType::N(13),
+            ParseType::T(30),
+            ParseType::N(5),
+            ParseType::T(29),
+            ParseType::N(15),
         ],
         is_push_production: false,
     },
-    // 29 - Alternations: Alternation AlternationsList /* Vec */;
+    // 33 - Alternations: Alternation AlternationsList /* Vec */;
     Production {
-        lhs: 4,
-        production: &[ParseType::N(5), ParseType::N(2)],
+        lhs: 5,
+        production: &[ParseType::N(6), ParseType::N(2)],
         is_push_production: false,
     },
-    // 30 - AlternationsList: '|'^ /* Clipped */ Alternation AlternationsList;
+    // 34 - AlternationsList: '|'^ /* Clipped */ Alternation AlternationsList;
     Production {
-        lhs: 5,
-        production: &[ParseType::N(5), ParseType::N(2), ParseType::T(27)],
+        lhs: 6,
+        production: &[ParseType::N(6), ParseType::N(2), ParseType::T(31)],
         is_push_production: true,
     },
-    // 31 - AlternationsList: ;
+    // 35 - AlternationsList: ;
     Production {
-        lhs: 5,
+        lhs: 6,
         production: &[],
         is_push_production: false,
     },
-    // 32 - Alternation: AlternationList /* Vec */;
+    // 36 - Alternation: AlternationOpt /* Option */ AlternationList /* Vec */;
     Production {
         lhs: 2,
-        production: &[ParseType::N(3)],
+        production: &[ParseType::N(3), ParseType::N(4)],
         is_push_production: false,
     },
-    // 33 - AlternationList: Factor AlternationList;
+    // 37 - AlternationList: Factor AlternationList;
     Production {
         lhs: 3,
-        production: &[ParseType::N(3), ParseType::N(9)],
+        production: &[ParseType::N(3), ParseType::N(11)],
         is_push_production: true,
     },
-    // 34 - AlternationList: ;
+    // 38 - AlternationList: ;
     Production {
         lhs: 3,
         production: &[],
         is_push_production: false,
     },
-    // 35 - Factor: Group;
+    // 39 - AlternationOpt: Predicate;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(12)],
+        lhs: 4,
+        production: &[ParseType::N(27)],
         is_push_production: false,
     },
-    // 36 - Factor: Repeat;
+    // 40 - AlternationOpt: ;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(31)],
+        lhs: 4,
+        production: &[],
         is_push_production: false,
     },
-    // 37 - Factor: Optional;
+    // 41 - Predicate: '&{'^ /* Clipped */ Identifier '}'^ /* Clipped */;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(22)],
+        lhs: 27,
+        production: &[ParseType::T(33), ParseType::N(15), ParseType::T(32)],
         is_push_production: false,
     },
-    // 38 - Factor: Symbol;
+    // 42 - Factor: Group;
     Production {
-        lhs: 9,
-        production: &[ParseType::N(40)],
+        lhs: 11,
+        production: &[ParseType::N(14)],
         is_push_production: false,
     },
-    // 39 - Symbol: NonTerminal;
+    // 43 - Factor: Repeat;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(20)],
+        lhs: 11,
+        production: &[ParseType::N(34)],
         is_push_production: false,
     },
-    // 40 - Symbol: SimpleToken;
+    // 44 - Factor: Optional;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(36)],
+        lhs: 11,
+        production: &[ParseType::N(24)],
         is_push_production: false,
     },
-    // 41 - Symbol: TokenWithStates;
+    // 45 - Factor: Symbol;
     Production {
-        lhs: 40,
-        production: &[ParseType::N(44)],
+        lhs: 11,
+        production: &[ParseType::N(43)],
         is_push_production: false,
     },
-    // 42 - TokenLiteral: String;
+    // 46 - Symbol: NonTerminal;
     Production {
         lhs: 43,
-        production: &[ParseType::N(39)],
+        production: &[ParseType::N(22)],
         is_push_production: false,
     },
-    // 43 - TokenLiteral: RawString;
+    // 47 - Symbol: SimpleToken;
     Production {
         lhs: 43,
-        production: &[ParseType::N(29)],
+        production: &[ParseType::N(39)],
         is_push_production: false,
     },
-    // 44 - TokenLiteral: Regex;
+    // 48 - Symbol: TokenWithStates;
     Production {
         lhs: 43,
-        production: &[ParseType::N(30)],
+        production: &[ParseType::N(47)],
         is_push_production: false,
     },
-    // 45 - TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;
+    // 49 - TokenLiteral: String;
     Production {
-        lhs: 41,
-        production: &[ParseType::N(42), ParseType::N(43)],
+        lhs: 46,
+        production: &[ParseType::N(42)],
         is_push_production: false,
     },
-    // 46 - TokenExpressionOpt: LookAhead;
+    // 50 - TokenLiteral: RawString;
     Production {
-        lhs: 42,
-        production: &[ParseType::N(16)],
+        lhs: 46,
+        production: &[ParseType::N(32)],
         is_push_production: false,
     },
-    // 47 - TokenExpressionOpt: ;
+    // 51 - TokenLiteral: CaseInsensitiveRawString;
     Production {
-        lhs: 42,
+        lhs: 46,
+        production: &[ParseType::N(7)],
+        is_push_production: false,
+    },
+    // 52 - TokenLiteral: Regex;
+    Production {
+        lhs: 46,
+        production: &[ParseType::N(33)],
+        is_push_production: false,
+    },
+    // 53 - TokenExpression: TokenLiteral TokenExpressionOpt /* Option */;
+    Production {
+        lhs: 44,
+        production: &[ParseType::N(45), ParseType::N(46)],
+        is_push_production: false,
+    },
+    // 54 - TokenExpressionOpt: LookAhead;
+    Production {
+        lhs: 45,
+        production: &[ParseType::N(18)],
+        is_push_production: false,
+    },
+    // 55 - TokenExpressionOpt: ;
+    Production {
+        lhs: 45,
         production: &[],
         is_push_production: false,
     },
-    // 48 - SimpleToken: TokenExpression SimpleTokenOpt /* Option */;
+    // 56 - SimpleToken: TokenExpression SimpleTokenOpt /* Option */;
     Production {
-        lhs: 36,
-        production: &[ParseType::N(37), ParseType::N(41)],
+        lhs: 39,
+        production: &[ParseType::N(40), ParseType::N(44)],
         is_push_production: false,
     },
-    // 49 - SimpleTokenOpt: ASTControl;
+    // 57 - SimpleTokenOpt: ASTControl;
     Production {
-        lhs: 37,
+        lhs: 40,
         production: &[ParseType::N(0)],
         is_push_production: false,
     },
-    // 50 - SimpleTokenOpt: ;
+    // 58 - SimpleTokenOpt: ;
     Production {
-        lhs: 37,
+        lhs: 40,
         production: &[],
         is_push_production: false,
     },
-    // 51 - TokenWithStates: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */ TokenExpression TokenWithStatesOpt /* Option */;
+    // 59 - TokenWithStates: '<'^ /* Clipped */ IdentifierList '>'^ /* Clipped */ TokenExpression TokenWithStatesOpt /* Option */;
     Production {
-        lhs: 44,
+        lhs: 47,
         production: &[
-            ParseType::N(45),
-            ParseType::N(41),
-            ParseType::T(29),
-            ParseType::N(14),
-            ParseType::T(28),
+            ParseType::N(48),
+            ParseType::N(44),
+            ParseType::T(35),
+            ParseType::N(16),
+            ParseType::T(34),
         ],
         is_push_production: false,
     },
-    // 52 - TokenWithStatesOpt: ASTControl;
+    // 60 - TokenWithStatesOpt: ASTControl;
     Production {
-        lhs: 45,
+        lhs: 48,
         production: &[ParseType::N(0)],
         is_push_production: false,
     },
-    // 53 - TokenWithStatesOpt: ;
+    // 61 - TokenWithStatesOpt: ;
     Production {
-        lhs: 45,
+        lhs: 48,
         production: &[],
         is_push_production: false,
     },
-    // 54 - String: /"(\\.|[^"])*"/;
+    // 62 - String: /"(\\.|[^"])*"/;
     Production {
-        lhs: 39,
-        production: &[ParseType::T(30)],
+        lhs: 42,
+        production: &[ParseType::T(36)],
         is_push_production: false,
     },
-    // 55 - RawString: /'(\\.|[^'])*'/;
+    // 63 - RawString: /'(\\.|[^'])*'/;
     Production {
-        lhs: 29,
-        production: &[ParseType::T(31)],
+        lhs: 32,
+        production: &[ParseType::T(37)],
         is_push_production: false,
     },
-    // 56 - Regex: "/(\\.|[^\/])*/";
+    // 64 - CaseInsensitiveRawString: /'(\\.|[^'])*'i/;
     Production {
-        lhs: 30,
-        production: &[ParseType::T(32)],
+        lhs: 7,
+        production: &[ParseType::T(38)],
         is_push_production: false,
     },
-    // 57 - Group: '(' Alternations ')';
+    // 65 - Regex: "/(\\.|[^\/])*/";
     Production {
-        lhs: 12,
-        production: &[ParseType::T(34), ParseType::N(4), ParseType::T(33)],
+        lhs: 33,
+        production: &[ParseType::T(39)],
         is_push_production: false,
     },
-    // 58 - Optional: '[' Alternations ']';
+    // 66 - Group: '(' Alternations ')';
     Production {
-        lhs: 22,
-        production: &[ParseType::T(36), ParseType::N(4), ParseType::T(35)],
+        lhs: 14,
+        production: &[ParseType::T(41), ParseType::N(5), ParseType::T(40)],
         is_push_production: false,
     },
-    // 59 - Repeat: '{' Alternations '}';
+    // 67 - Optional: '[' Alternations ']';
     Production {
-        lhs: 31,
-        production: &[ParseType::T(38), ParseType::N(4), ParseType::T(37)],
+        lhs: 24,
+        production: &[ParseType::T(43), ParseType::N(5), ParseType::T(42)],
         is_push_production: false,
     },
-    // 60 - NonTerminal: Identifier NonTerminalOpt /* Option */;
+    // 68 - Repeat: '{' Alternations '}';
     Production {
-        lhs: 20,
-        production: &[ParseType::N(21), ParseType::N(13)],
+        lhs: 34,
+        production: &[ParseType::T(33), ParseType::N(5), ParseType::T(44)],
         is_push_production: false,
     },
-    // 61 - NonTerminalOpt: ASTControl;
+    // 69 - NonTerminal: Identifier NonTerminalOpt /* Option */;
     Production {
-        lhs: 21,
+        lhs: 22,
+        production: &[ParseType::N(23), ParseType::N(15)],
+        is_push_production: false,
+    },
+    // 70 - NonTerminalOpt: ASTControl;
+    Production {
+        lhs: 23,
         production: &[ParseType::N(0)],
         is_push_production: false,
     },
-    // 62 - NonTerminalOpt: ;
+    // 71 - NonTerminalOpt: ;
     Production {
-        lhs: 21,
+        lhs: 23,
         production: &[],
         is_push_production: false,
     },
-    // 63 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    // 72 - Identifier: /[a-zA-Z_][a-zA-Z0-9_]*/;
     Production {
-        lhs: 13,
-        production: &[ParseType::T(39)],
+        lhs: 15,
+        production: &[ParseType::T(45)],
         is_push_production: false,
     },
-    // 64 - ScannerState: '%scanner'^ /* Clipped */ Identifier@state_name '{'^ /* Clipped */ ScannerStateList /* Vec */ '}'^ /* Clipped */;
+    // 73 - ScannerState: '%scanner'^ /* Clipped */ Identifier@state_name '{'^ /* Clipped */ ScannerStateList /* Vec */ '}'^ /* Clipped */;
     Production {
-        lhs: 33,
+        lhs: 36,
         production: &[
-            ParseType::T(38),
-            ParseType::N(35),
-            ParseType::T(37),
-            ParseType::N(13),
-            ParseType::T(40),
+            ParseType::T(33),
+            ParseType::N(38),
+            ParseType::T(44),
+            ParseType::N(15),
+            ParseType::T(46),
         ],
         is_push_production: false,
     },
-    // 65 - ScannerStateList: ScannerDirectives ScannerStateList;
+    // 74 - ScannerStateList: ScannerDirectives ScannerStateList;
     Production {
-        lhs: 35,
-        production: &[ParseType::N(35), ParseType::N(32)],
+        lhs: 38,
+        production: &[ParseType::N(38), ParseType::N(35)],
         is_push_production: true,
     },
-    // 66 - ScannerStateList: ;
+    // 75 - ScannerStateList: ;
     Production {
-        lhs: 35,
+        lhs: 38,
         production: &[],
         is_push_production: false,
     },
-    // 67 - IdentifierList: Identifier IdentifierListList /* Vec */;
+    // 76 - IdentifierList: Identifier IdentifierListList /* Vec */;
     Production {
-        lhs: 14,
-        production: &[ParseType::N(15), ParseType::N(13)],
+        lhs: 16,
+        production: &[ParseType::N(17), ParseType::N(15)],
         is_push_production: false,
     },
-    // 68 - IdentifierListList: ','^ /* Clipped */ Identifier IdentifierListList;
+    // 77 - IdentifierListList: ','^ /* Clipped */ Identifier IdentifierListList;
     Production {
-        lhs: 15,
-        production: &[ParseType::N(15), ParseType::N(13), ParseType::T(41)],
+        lhs: 17,
+        production: &[ParseType::N(17), ParseType::N(15), ParseType::T(47)],
         is_push_production: true,
     },
-    // 69 - IdentifierListList: ;
+    // 78 - IdentifierListList: ;
     Production {
-        lhs: 15,
+        lhs: 17,
         production: &[],
         is_push_production: false,
     },
-    // 70 - ASTControl: CutOperator;
+    // 79 - ASTControl: CutOperator;
     Production {
         lhs: 0,
-        production: &[ParseType::N(6)],
+        production: &[ParseType::N(8)],
         is_push_production: false,
     },
-    // 71 - ASTControl: MemberName ASTControlOpt /* Option */;
+    // 80 - ASTControl: MemberName ASTControlOpt /* Option */;
     Production {
         lhs: 0,
-        production: &[ParseType::N(1), ParseType::N(18)],
+        production: &[ParseType::N(1), ParseType::N(20)],
         is_push_production: false,
     },
-    // 72 - ASTControl: UserTypeDeclaration;
+    // 81 - ASTControl: UserTypeDeclaration;
     Production {
         lhs: 0,
-        production: &[ParseType::N(46)],
+        production: &[ParseType::N(49)],
         is_push_production: false,
     },
-    // 73 - ASTControlOpt: UserTypeDeclaration;
+    // 82 - ASTControlOpt: UserTypeDeclaration;
     Production {
         lhs: 1,
-        production: &[ParseType::N(46)],
+        production: &[ParseType::N(49)],
         is_push_production: false,
     },
-    // 74 - ASTControlOpt: ;
+    // 83 - ASTControlOpt: ;
     Production {
         lhs: 1,
         production: &[],
         is_push_production: false,
     },
-    // 75 - MemberName: '@'^ /* Clipped */ Identifier;
+    // 84 - MemberName: '@'^ /* Clipped */ Identifier;
     Production {
-        lhs: 18,
-        production: &[ParseType::N(13), ParseType::T(42)],
+        lhs: 20,
+        production: &[ParseType::N(15), ParseType::T(48)],
         is_push_production: false,
     },
-    // 76 - CutOperator: '^'^ /* Clipped */;
+    // 85 - CutOperator: '^'^ /* Clipped */;
     Production {
-        lhs: 6,
-        production: &[ParseType::T(43)],
+        lhs: 8,
+        production: &[ParseType::T(49)],
         is_push_production: false,
     },
-    // 77 - UserTypeDeclaration: ':'^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName ;
+    // 86 - UserTypeDeclaration: ':'^ /* Clipped */ UserTypeName : crate::parser::parol_grammar::UserDefinedTypeName ;
     Production {
-        lhs: 46,
-        production: &[ParseType::N(47), ParseType::T(25)],
+        lhs: 49,
+        production: &[ParseType::N(50), ParseType::T(29)],
         is_push_production: false,
     },
-    // 78 - UserTypeName: Identifier UserTypeNameList /* Vec */;
+    // 87 - UserTypeName: Identifier UserTypeNameList /* Vec */;
     Production {
-        lhs: 47,
-        production: &[ParseType::N(48), ParseType::N(13)],
+        lhs: 50,
+        production: &[ParseType::N(51), ParseType::N(15)],
         is_push_production: false,
     },
-    // 79 - UserTypeNameList: DoubleColon^ /* Clipped */ Identifier UserTypeNameList;
+    // 88 - UserTypeNameList: DoubleColon^ /* Clipped */ Identifier UserTypeNameList;
     Production {
-        lhs: 48,
-        production: &[ParseType::N(48), ParseType::N(13), ParseType::N(8)],
+        lhs: 51,
+        production: &[ParseType::N(51), ParseType::N(15), ParseType::N(10)],
         is_push_production: true,
     },
-    // 80 - UserTypeNameList: ;
+    // 89 - UserTypeNameList: ;
     Production {
-        lhs: 48,
+        lhs: 51,
         production: &[],
         is_push_production: false,
     },
-    // 81 - LookAhead: LookAheadGroup TokenLiteral;
+    // 90 - LookAhead: LookAheadGroup TokenLiteral;
     Production {
-        lhs: 16,
-        production: &[ParseType::N(43), ParseType::N(17)],
+        lhs: 18,
+        production: &[ParseType::N(46), ParseType::N(19)],
         is_push_production: false,
     },
-    // 82 - LookAheadGroup: PositiveLookahead;
+    // 91 - LookAheadGroup: PositiveLookahead;
     Production {
-        lhs: 17,
-        production: &[ParseType::N(24)],
+        lhs: 19,
+        production: &[ParseType::N(26)],
         is_push_production: false,
     },
-    // 83 - LookAheadGroup: NegativeLookahead;
+    // 92 - LookAheadGroup: NegativeLookahead;
     Production {
-        lhs: 17,
-        production: &[ParseType::N(19)],
+        lhs: 19,
+        production: &[ParseType::N(21)],
         is_push_production: false,
     },
-    // 84 - PositiveLookahead: '?='^ /* Clipped */;
+    // 93 - PositiveLookahead: '?='^ /* Clipped */;
     Production {
-        lhs: 24,
-        production: &[ParseType::T(44)],
+        lhs: 26,
+        production: &[ParseType::T(50)],
         is_push_production: false,
     },
-    // 85 - NegativeLookahead: '?!'^ /* Clipped */;
+    // 94 - NegativeLookahead: '?!'^ /* Clipped */;
     Production {
-        lhs: 19,
-        production: &[ParseType::T(45)],
+        lhs: 21,
+        production: &[ParseType::T(51)],
         is_push_production: false,
     },
 ];
@@ -1276,7 +1416,7 @@ where
 {
     use parol_grammar_scanner::ParolGrammarScanner;
     let mut llk_parser = LLKParser::new(
-        23,
+        25,
         LOOKAHEAD_AUTOMATA,
         PRODUCTIONS,
         TERMINAL_NAMES,
@@ -2,6 +2,7 @@ use crate::parser::{Factor, ParolGrammar};
 use crate::transformation::transform_productions;
 use crate::{Cfg, GrammarConfig, ScannerConfig, Symbol, Terminal, TerminalKind, generators};
 use anyhow::{Result, bail};
+use parol_runtime::lexer::OffsideTokens;
 use std::collections::{BTreeMap, BTreeSet};
 
 use super::parol_grammar::{LookaheadExpression, ScannerStateSwitch};
@@ -23,6 +24,11 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
             });
     let pr = transform_productions(parol_grammar.productions, parol_grammar.grammar_type)?;
     let cfg = Cfg { st, pr };
+    let entry_points = parol_grammar
+        .entry_points
+        .iter()
+        .map(|e| e.text().to_string())
+        .collect();
     let title = parol_grammar.title;
     let comment = parol_grammar.comment;
     // The first scanner configuration should always be the default configuration
@@ -33,6 +39,9 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
     let block_comments = parol_grammar.scanner_configurations[0]
         .block_comments
         .clone();
+    let nested_block_comments = parol_grammar.scanner_configurations[0]
+        .nested_block_comments
+        .clone();
     let auto_newline = !parol_grammar.scanner_configurations[0].auto_newline_off;
     let auto_ws = !parol_grammar.scanner_configurations[0].auto_ws_off;
     let allow_unmatched = parol_grammar.scanner_configurations[0].allow_unmatched;
@@ -41,6 +50,7 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
     let scanner_config = ScannerConfig::default()
         .with_line_comments(line_comments)
         .with_block_comments(block_comments)
+        .with_nested_block_comments(nested_block_comments)
         .with_auto_newline(auto_newline)
         .with_auto_ws(auto_ws)
         .with_allow_unmatched(allow_unmatched);
@@ -50,6 +60,7 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
         .with_non_terminals(non_terminals)
         .with_comment(comment)
         .with_grammar_type(parol_grammar.grammar_type)
+        .with_entry_points(entry_points)
         .add_scanner(scanner_config);
 
     grammar_config.unreachable_non_terminals_to_ignore = unreachable_non_terminals_to_ignore;
@@ -121,6 +132,32 @@ pub(crate) fn try_to_convert(parol_grammar: ParolGrammar) -> Result<GrammarConfi
             )
         })?;
 
+    grammar_config
+        .scanner_configurations
+        .iter_mut()
+        .try_for_each(|sc| {
+            insert_offside_tokens(
+                sc,
+                &parol_grammar.scanner_configurations,
+                &terminal_resolver,
+                scanner_resolver,
+                &terminal_finder,
+            )
+        })?;
+
+    grammar_config
+        .scanner_configurations
+        .iter_mut()
+        .try_for_each(|sc| {
+            insert_external_tokens(
+                sc,
+                &parol_grammar.scanner_configurations,
+                &terminal_resolver,
+                scanner_resolver,
+                &terminal_finder,
+            )
+        })?;
+
     Ok(grammar_config)
 }
 
@@ -194,6 +231,7 @@ fn try_from_scanner_config(
     let scanner_config = ScannerConfig::new(sc.name.clone(), scanner_state)
         .with_line_comments(sc.line_comments.clone())
         .with_block_comments(sc.block_comments.clone())
+        .with_nested_block_comments(sc.nested_block_comments.clone())
         .with_auto_newline(!sc.auto_newline_off)
         .with_auto_ws(!sc.auto_ws_off)
         .with_skip_tokens(Vec::new())
@@ -230,6 +268,66 @@ fn insert_skips(
     Ok(())
 }
 
+fn insert_offside_tokens(
+    sc: &mut generators::ScannerConfig,
+    scanner_configurations: &[crate::parser::parol_grammar::ScannerConfig],
+    terminal_resolver: &impl crate::grammar::cfg::TerminalIndexFn,
+    scanner_resolver: impl Fn(&str) -> Option<usize>,
+    terminal_finder: impl Fn(&str) -> Option<(String, TerminalKind, Option<LookaheadExpression>)>,
+) -> Result<()> {
+    if let Some(source_configuration) = scanner_resolver(&sc.scanner_name) {
+        if let Some(offside) = &scanner_configurations[source_configuration].offside {
+            let mut indices = Vec::with_capacity(offside.len());
+            for token in offside {
+                if let Some((txt, kind, la)) = terminal_finder(token.text()) {
+                    indices.push(terminal_resolver.terminal_index(&txt, kind, &la));
+                } else {
+                    bail!("Terminal {} not found", token);
+                }
+            }
+            sc.offside_tokens = Some(OffsideTokens {
+                indent: indices[0],
+                dedent: indices[1],
+                newline: indices[2],
+            });
+        }
+    } else {
+        bail!("Scanner configuration {} not found", sc.scanner_name);
+    }
+    Ok(())
+}
+
+fn insert_external_tokens(
+    sc: &mut generators::ScannerConfig,
+    scanner_configurations: &[crate::parser::parol_grammar::ScannerConfig],
+    terminal_resolver: &impl crate::grammar::cfg::TerminalIndexFn,
+    scanner_resolver: impl Fn(&str) -> Option<usize>,
+    terminal_finder: impl Fn(&str) -> Option<(String, TerminalKind, Option<LookaheadExpression>)>,
+) -> Result<()> {
+    if let Some(source_configuration) = scanner_resolver(&sc.scanner_name) {
+        let mut external_tokens = Vec::new();
+        scanner_configurations[source_configuration]
+            .external
+            .iter()
+            .try_for_each(|token| {
+                if let Some((txt, kind, la)) = terminal_finder(token.text()) {
+                    let terminal_index = terminal_resolver.terminal_index(&txt, kind, &la);
+                    // The order of declaration is the order in which the terminals are tried
+                    if !external_tokens.contains(&terminal_index) {
+                        external_tokens.push(terminal_index);
+                    }
+                } else {
+                    bail!("Terminal {} not found", token);
+                }
+                Ok(())
+            })?;
+        sc.external_tokens = external_tokens;
+    } else {
+        bail!("Scanner configuration {} not found", sc.scanner_name);
+    }
+    Ok(())
+}
+
 fn collect_scanner_control_skip_non_terminals(parol_grammar: &ParolGrammar) -> Vec<String> {
     let rhs_non_terminal_usages = parol_grammar
         .productions
@@ -0,0 +1,302 @@
+//! Parsers for grammar descriptions that are built at runtime.
+//!
+//! The parse tables are calculated like for the generated parsers and given to the parsers of
+//! `parol_runtime`. It is used where a grammar description is applied to an input without
+//! generating code, e.g. by the coverage measurement and by the input preview of the language
+//! server.
+
+use crate::generators::parser_model::{
+    ProductionSymbolModel, build_lalr_parse_table_model, build_lookahead_automata_model,
+    build_production_model, find_start_symbol_index, ordered_non_terminal_names,
+};
+use crate::parser::GrammarType;
+use crate::{GrammarConfig, calculate_lalr1_parse_table, calculate_lookahead_dfas};
+use anyhow::Result;
+use parol_runtime::lr_parser::{LR1State, LRAction, LRParseTable, LRParser, LRProduction};
+use parol_runtime::parser::parse_tree_type::TreeConstruct;
+use parol_runtime::{
+    Coverage, LLKParser, LookaheadDFA, ParolError, ParseTreeType, ParseType, Production, Token,
+    TokenStream, Trans, UserActionsTrait,
+};
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+///
+/// The names of the symbols are handed out to the tree builders with the `'static` lifetime.
+/// Each distinct name is allocated only once.
+///
+static NAMES: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Default::default);
+
+fn intern(names: &[String]) -> Vec<&'static str> {
+    let mut interned = NAMES.lock().unwrap();
+    names
+        .iter()
+        .map(|name| match interned.get(name.as_str()) {
+            Some(name) => *name,
+            None => {
+                let name: &'static str = Box::leak(name.clone().into_boxed_str());
+                interned.insert(name);
+                name
+            }
+        })
+        .collect()
+}
+
+///
+/// Owns the slices the parse tables refer to.
+///
+/// The runtime parsers require their tables with the `'static` lifetime. These references must
+/// not be used after the storage has been dropped, thus they are never handed out.
+///
+#[derive(Default)]
+struct Storage(Vec<Box<dyn Any + Send + Sync>>);
+
+impl Storage {
+    fn store<T: Send + Sync + 'static>(&mut self, items: Vec<T>) -> &'static [T] {
+        let items = items.into_boxed_slice();
+        // SAFETY: The content of the box is never moved or dropped before the storage is dropped
+        let slice = unsafe { &*(&*items as *const [T]) };
+        self.0.push(Box::new(items));
+        slice
+    }
+}
+
+impl std::fmt::Debug for Storage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Storage").field(&self.0.len()).finish()
+    }
+}
+
+/// The parse tables of the transformed grammar
+#[derive(Debug)]
+enum ParseTables {
+    Llk {
+        start_symbol_index: usize,
+        lookahead_automata: &'static [LookaheadDFA],
+        productions: &'static [Production],
+    },
+    Lalr1 {
+        start_symbol_index: usize,
+        parse_table: &'static LRParseTable,
+        productions: &'static [LRProduction],
+    },
+}
+
+/// User actions that ignore the parsed input
+struct NoUserActions;
+
+impl<'t> UserActionsTrait<'t> for NoUserActions {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        _prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> parol_runtime::Result<()> {
+        Ok(())
+    }
+
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+///
+/// A parser for a grammar that is built at runtime.
+///
+/// The parser stops at the first syntax error.
+///
+#[derive(Debug)]
+pub struct RuntimeParser {
+    parse_tables: ParseTables,
+    terminal_names: &'static [&'static str],
+    non_terminal_names: &'static [&'static str],
+    // Must be the last field, the tables refer to it
+    _storage: Storage,
+}
+
+impl RuntimeParser {
+    ///
+    /// Creates the parser for the given, already transformed grammar configuration.
+    /// The `terminal_names` are the generated names of all terminals, see
+    /// [crate::generators::generate_terminal_names].
+    /// LL(k) grammars are analyzed with the lookahead size of the grammar configuration.
+    ///
+    pub fn try_new(grammar_config: &GrammarConfig, terminal_names: &[String]) -> Result<Self> {
+        let non_terminal_names = ordered_non_terminal_names(grammar_config);
+        let mut storage = Storage::default();
+        let parse_tables =
+            Self::build_parse_tables(grammar_config, &non_terminal_names, &mut storage)?;
+        Ok(Self {
+            parse_tables,
+            terminal_names: storage.store(intern(terminal_names)),
+            non_terminal_names: storage.store(intern(&non_terminal_names)),
+            _storage: storage,
+        })
+    }
+
+    ///
+    /// Parses the given tokens, e.g. the tokens of [crate::scanner_emulation::Scanner::tokenize],
+    /// into the tree builder.
+    /// If `coverage` is given the usage of the productions and terminals is added to it, also if
+    /// a syntax error occurs.
+    ///
+    pub fn parse_into<'t, T: TreeConstruct<'t>>(
+        &self,
+        tree_builder: &mut T,
+        tokens: Vec<Token<'t>>,
+        coverage: Option<&mut Coverage>,
+    ) -> parol_runtime::Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
+        let mut user_actions = NoUserActions;
+        let (result, parser_coverage) = match self.parse_tables {
+            ParseTables::Llk {
+                start_symbol_index,
+                lookahead_automata,
+                productions,
+            } => {
+                let mut parser = LLKParser::new(
+                    start_symbol_index,
+                    lookahead_automata,
+                    productions,
+                    self.terminal_names,
+                    self.non_terminal_names,
+                );
+                parser.disable_recovery();
+                if coverage.is_some() {
+                    parser.enable_coverage();
+                }
+                let k = lookahead_automata
+                    .iter()
+                    .map(|dfa| dfa.k)
+                    .max()
+                    .unwrap_or(1);
+                let result = TokenStream::from_tokens(tokens, k)
+                    .map_err(|e| e.into())
+                    .and_then(|stream| parser.parse_into(tree_builder, stream, &mut user_actions));
+                (result, parser.coverage().cloned())
+            }
+            ParseTables::Lalr1 {
+                start_symbol_index,
+                parse_table,
+                productions,
+            } => {
+                let mut parser = LRParser::new(
+                    start_symbol_index,
+                    parse_table,
+                    productions,
+                    self.terminal_names,
+                    self.non_terminal_names,
+                );
+                if coverage.is_some() {
+                    parser.enable_coverage();
+                }
+                let result = TokenStream::from_tokens(tokens, 1)
+                    .map_err(|e| e.into())
+                    .and_then(|stream| parser.parse_into(tree_builder, stream, &mut user_actions));
+                (result, parser.coverage().cloned())
+            }
+        };
+        if let (Some(coverage), Some(parser_coverage)) = (coverage, parser_coverage) {
+            coverage.merge(&parser_coverage);
+        }
+        result
+    }
+
+    fn build_parse_tables(
+        grammar_config: &GrammarConfig,
+        non_terminal_names: &[String],
+        storage: &mut Storage,
+    ) -> Result<ParseTables> {
+        let start_symbol_index = find_start_symbol_index(non_terminal_names, grammar_config)?;
+        let production_model = build_production_model(grammar_config, non_terminal_names)?;
+        match grammar_config.grammar_type {
+            GrammarType::LLK => {
+                let la_dfa =
+                    calculate_lookahead_dfas(grammar_config, grammar_config.lookahead_size)?;
+                let automata_model = build_lookahead_automata_model(&la_dfa, non_terminal_names);
+                let lookahead_automata = (0..non_terminal_names.len())
+                    .map(|non_terminal_index| {
+                        automata_model
+                            .iter()
+                            .find(|a| a.non_terminal_index == non_terminal_index)
+                            .map_or_else(LookaheadDFA::default, |a| LookaheadDFA {
+                                prod0: a.prod0,
+                                transitions: storage.store(
+                                    a.transitions
+                                        .iter()
+                                        .map(|t| {
+                                            Trans(t.from_state, t.term, t.to_state, t.prod_num)
+                                        })
+                                        .collect(),
+                                ),
+                                k: a.k,
+                            })
+                    })
+                    .collect();
+                // The right-hand sides are stored in reversed order
+                let productions = production_model
+                    .iter()
+                    .map(|p| Production {
+                        lhs: p.lhs_index,
+                        production: storage.store(
+                            p.rhs
+                                .iter()
+                                .rev()
+                                .map(|s| match s {
+                                    ProductionSymbolModel::NonTerminal(n) => ParseType::N(*n),
+                                    ProductionSymbolModel::Terminal { index, .. } => {
+                                        ParseType::T(*index)
+                                    }
+                                })
+                                .collect(),
+                        ),
+                        is_push_production: p.is_push_production,
+                    })
+                    .collect();
+                Ok(ParseTables::Llk {
+                    start_symbol_index,
+                    lookahead_automata: storage.store(lookahead_automata),
+                    productions: storage.store(productions),
+                })
+            }
+            GrammarType::LALR1 => {
+                let parse_table = calculate_lalr1_parse_table(grammar_config)?.0;
+                let table_model = build_lalr_parse_table_model(&parse_table);
+                let actions = table_model
+                    .actions
+                    .iter()
+                    .map(|a| match a {
+                        crate::LRAction::Shift(s) => LRAction::Shift(*s),
+                        crate::LRAction::Reduce(n, p) => LRAction::Reduce(*n, *p),
+                        crate::LRAction::Accept => LRAction::Accept,
+                    })
+                    .collect();
+                let states = table_model
+                    .states
+                    .into_iter()
+                    .map(|s| LR1State {
+                        actions: storage.store(s.actions),
+                        gotos: storage.store(s.gotos),
+                    })
+                    .collect();
+                let productions = production_model
+                    .iter()
+                    .map(|p| LRProduction {
+                        lhs: p.lhs_index,
+                        len: p.rhs.len(),
+                        is_push_production: p.is_push_production,
+                    })
+                    .collect();
+                let parse_table = LRParseTable {
+                    actions: storage.store(actions),
+                    states: storage.store(states),
+                };
+                Ok(ParseTables::Lalr1 {
+                    start_symbol_index,
+                    parse_table: &storage.store(vec![parse_table])[0],
+                    productions: storage.store(productions),
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Emulation of the generated scanners with the `regex` and `regex-automata` crates.
+//!
+//! Like the generated scanners it supports scanner states and their transitions and prefers the
+//! longest match and among matches of the same length the terminal that was defined first.
+//! It is used where a grammar description is applied to an input without generating code, e.g. by
+//! the coverage measurement and by the input preview of the language server.
+
+use crate::GrammarConfig;
+use crate::parser::parol_grammar::ScannerStateSwitch;
+use anyhow::{Result, anyhow, bail};
+use parol_runtime::lexer::{BLOCK_COMMENT, LINE_COMMENT, NEW_LINE, WHITESPACE};
+use parol_runtime::{Location, TerminalIndex, Token};
+use regex::Regex;
+use regex_automata::hybrid::dfa::{Cache, DFA};
+use regex_automata::util::start;
+use regex_automata::{Anchored, MatchKind};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Debug)]
+struct Pattern {
+    token_type: TerminalIndex,
+    /// Positive (true) or negative lookahead
+    lookahead: Option<(bool, Regex)>,
+}
+
+#[derive(Debug)]
+struct ScannerMode {
+    name: String,
+    /// A lazy DFA that reports all patterns that match, the pattern IDs are the indices into
+    /// `patterns`
+    dfa: DFA,
+    patterns: Vec<Pattern>,
+    transitions: Vec<(TerminalIndex, ScannerStateSwitch)>,
+    skip_tokens: Vec<TerminalIndex>,
+    /// Input that no terminal matches is skipped silently instead of being reported
+    allow_unmatched: bool,
+}
+
+/// The result of [Scanner::tokenize]
+#[derive(Debug, Default)]
+pub struct ScannedTokens<'t> {
+    /// The tokens of the input without the skip tokens
+    pub tokens: Vec<Token<'t>>,
+    /// Input that no terminal matches as error tokens, consecutive characters are merged.
+    /// Scanner states that allow unmatched input don't report it.
+    pub unmatched: Vec<Token<'t>>,
+}
+
+/// A scanner for the terminals of a grammar that is built at runtime
+#[derive(Debug)]
+pub struct Scanner {
+    modes: Vec<ScannerMode>,
+    /// The token type of the error token that matches any character no terminal matches
+    error_token_type: TerminalIndex,
+}
+
+impl Scanner {
+    ///
+    /// Creates the scanner for the given, already transformed grammar configuration.
+    /// The `terminal_names` are the generated names of all terminals, see
+    /// [crate::generators::generate_terminal_names].
+    ///
+    /// External terminals, the offside rule and nested block comments are not supported, because
+    /// their terminals are not produced by regular expressions.
+    ///
+    pub fn try_new(grammar_config: &GrammarConfig, terminal_names: &[String]) -> Result<Self> {
+        if let Some(sc) = grammar_config.scanner_configurations.iter().find(|sc| {
+            !sc.external_tokens.is_empty()
+                || sc.offside_tokens.is_some()
+                || !sc.nested_block_comments.is_empty()
+        }) {
+            bail!(
+                "Scanner state '{}' uses external terminals, the offside rule or nested block \
+                comments which can't be emulated",
+                sc.scanner_name
+            );
+        }
+        let anchored = |rx: &str| {
+            Regex::new(&format!(r"\A(?:{rx})"))
+                .map_err(|e| anyhow!("Unsupported regular expression {rx}: {e}"))
+        };
+        let modes = grammar_config
+            .scanner_configurations
+            .iter()
+            .map(|scanner_config| {
+                let (terminal_mappings, transitions) =
+                    scanner_config.generate_build_information(grammar_config, terminal_names)?;
+                // With MatchKind::All the DFA reports every match and not only the leftmost-first
+                // one, thus the longest match can be selected like the generated scanners do.
+                let dfa = DFA::builder()
+                    .configure(
+                        DFA::config()
+                            .match_kind(MatchKind::All)
+                            .unicode_word_boundary(true),
+                    )
+                    .build_many(
+                        &terminal_mappings
+                            .iter()
+                            .map(|(rx, ..)| rx.as_str())
+                            .collect::<Vec<&str>>(),
+                    )
+                    .map_err(|e| {
+                        anyhow!(
+                            "Unsupported regular expression in scanner state '{}': {e}",
+                            scanner_config.scanner_name
+                        )
+                    })?;
+                let patterns = terminal_mappings
+                    .iter()
+                    .map(|(_, token_type, lookahead, _)| {
+                        Ok(Pattern {
+                            token_type: *token_type,
+                            lookahead: lookahead
+                                .as_ref()
+                                .map(|(is_positive, rx)| anchored(rx).map(|r| (*is_positive, r)))
+                                .transpose()?,
+                        })
+                    })
+                    .collect::<Result<Vec<Pattern>>>()?;
+                Ok(ScannerMode {
+                    name: scanner_config.scanner_name.clone(),
+                    dfa,
+                    patterns,
+                    transitions,
+                    skip_tokens: scanner_config.skip_tokens.clone(),
+                    allow_unmatched: scanner_config.allow_unmatched,
+                })
+            })
+            .collect::<Result<Vec<ScannerMode>>>()?;
+        Ok(Self {
+            modes,
+            error_token_type: (terminal_names.len() - 1) as TerminalIndex,
+        })
+    }
+
+    ///
+    /// Splits the input into tokens.
+    /// The columns of the token locations are counted in characters.
+    ///
+    pub fn tokenize<'t>(&self, input: &'t str, file_name: Arc<PathBuf>) -> ScannedTokens<'t> {
+        let mut result = ScannedTokens::default();
+        let mut caches = self
+            .modes
+            .iter()
+            .map(|m| m.dfa.create_cache())
+            .collect::<Vec<Cache>>();
+        let mut mode = 0;
+        let mut mode_stack = Vec::new();
+        let mut offset = 0;
+        let (mut line, mut column) = (1, 1);
+        while offset < input.len() {
+            let rest = &input[offset..];
+            let best = self.longest_match(mode, &mut caches[mode], rest);
+            // Without a match a single character is unmatched
+            let (len, token_type) = best.map_or_else(
+                || (rest.chars().next().map_or(1, char::len_utf8), None),
+                |(len, p)| (len, Some(p.token_type)),
+            );
+            let text = &rest[..len];
+            let (start_line, start_column) = (line, column);
+            for c in text.chars() {
+                if c == '\n' {
+                    line += 1;
+                    column = 1;
+                } else {
+                    column += 1;
+                }
+            }
+            let start = offset;
+            offset += len;
+            let location = Location {
+                start_line,
+                start_column,
+                end_line: line,
+                end_column: column,
+                start: start as _,
+                end: offset as _,
+                file_name: file_name.clone(),
+            };
+            let Some(token_type) = token_type.filter(|t| *t != self.error_token_type) else {
+                if !self.modes[mode].allow_unmatched {
+                    let token_number = result.unmatched.len() as _;
+                    match result.unmatched.last_mut() {
+                        Some(last) if last.location.end as usize == start => {
+                            let location = Location {
+                                start_line: last.location.start_line,
+                                start_column: last.location.start_column,
+                                start: last.location.start,
+                                ..location
+                            };
+                            let text = &input[location.start as usize..offset];
+                            *last = Token::with(
+                                text,
+                                self.error_token_type,
+                                location,
+                                last.token_number,
+                            );
+                        }
+                        _ => result.unmatched.push(Token::with(
+                            text,
+                            self.error_token_type,
+                            location,
+                            token_number,
+                        )),
+                    }
+                }
+                continue;
+            };
+            if !self.is_skip_token(mode, token_type) {
+                let token_number = result.tokens.len() as _;
+                result
+                    .tokens
+                    .push(Token::with(text, token_type, location, token_number));
+            }
+            mode = self.next_mode(mode, token_type, &mut mode_stack);
+        }
+        result
+    }
+
+    ///
+    /// Returns the longest non-empty match at the start of `rest` whose lookahead is satisfied.
+    /// Among matches of the same length the first defined pattern wins.
+    /// If the lookahead of the longest match fails shorter matches are considered.
+    ///
+    fn longest_match(
+        &self,
+        mode: usize,
+        cache: &mut Cache,
+        rest: &str,
+    ) -> Option<(usize, &Pattern)> {
+        let ScannerMode { dfa, patterns, .. } = &self.modes[mode];
+        let bytes = rest.as_bytes();
+        // (length, pattern indices) of all matches
+        let mut matches: Vec<(usize, Vec<usize>)> = Vec::new();
+        let mut state = dfa
+            .start_state(cache, &start::Config::new().anchored(Anchored::Yes))
+            .ok()?;
+        // The DFA reports matches delayed by one byte, i.e. the state after the byte at `len`
+        // knows about the matches of the length `len`.
+        for len in 0..=bytes.len() {
+            let next = if len < bytes.len() {
+                dfa.next_state(cache, state, bytes[len])
+            } else {
+                dfa.next_eoi_state(cache, state)
+            };
+            let Ok(next) = next else {
+                break;
+            };
+            if next.is_match() && len > 0 && rest.is_char_boundary(len) {
+                let mut pattern_indices = (0..dfa.match_len(cache, next))
+                    .map(|i| dfa.match_pattern(cache, next, i).as_usize())
+                    .collect::<Vec<usize>>();
+                pattern_indices.sort_unstable();
+                matches.push((len, pattern_indices));
+            }
+            if next.is_dead() || next.is_quit() {
+                break;
+            }
+            state = next;
+        }
+        matches.iter().rev().find_map(|(len, pattern_indices)| {
+            pattern_indices
+                .iter()
+                .map(|i| &patterns[*i])
+                .find(|p| Self::lookahead_matches(p, &rest[*len..]))
+                .map(|p| (*len, p))
+        })
+    }
+
+    fn lookahead_matches(pattern: &Pattern, rest: &str) -> bool {
+        pattern
+            .lookahead
+            .as_ref()
+            .is_none_or(|(is_positive, rx)| rx.is_match(rest) == *is_positive)
+    }
+
+    fn is_skip_token(&self, mode: usize, token_type: TerminalIndex) -> bool {
+        matches!(
+            token_type,
+            NEW_LINE | WHITESPACE | LINE_COMMENT | BLOCK_COMMENT
+        ) || self.modes[mode].skip_tokens.contains(&token_type)
+    }
+
+    fn next_mode(
+        &self,
+        mode: usize,
+        token_type: TerminalIndex,
+        mode_stack: &mut Vec<usize>,
+    ) -> usize {
+        let index_of = |name: &str| self.modes.iter().position(|m| m.name == name);
+        match self.modes[mode]
+            .transitions
+            .iter()
+            .find(|(t, _)| *t == token_type)
+            .map(|(_, switch)| switch)
+        {
+            Some(ScannerStateSwitch::Switch(name, _)) => index_of(name).unwrap_or(mode),
+            Some(ScannerStateSwitch::SwitchPush(name, _)) => {
+                mode_stack.push(mode);
+                index_of(name).unwrap_or(mode)
+            }
+            Some(ScannerStateSwitch::SwitchPop(_)) => mode_stack.pop().unwrap_or(mode),
+            None => mode,
+        }
+    }
+}
@@ -48,7 +48,8 @@ fn finalize(productions: Vec<Production>) -> Result<Vec<Pr>> {
                             });
                     prod?
                 },
-                single_alternative.1
+                single_alternative.1,
+                single_alternative.2,
             ))
         })
         .collect()
@@ -258,6 +259,7 @@ fn extract_options(opd: TransformationOperand) -> TransformationOperand {
                     Alternations(vec![Alternation(
                         vec![Factor::Group(alts)],
                         ProductionAttribute::OptionalSome,
+                        None,
                     )]),
                 ),
             );
@@ -108,10 +108,15 @@ where
 
 /// Finds the longest left prefixes in rules given.
 /// Can be used to factor out these left prefixes later.
+/// Rules guarded by semantic predicates are left unchanged because factoring out a prefix would
+/// defer the evaluation of the predicate behind the prefix.
 fn find_longest_prefixes(rules: &[Pr]) -> Vec<(String, Rhs)> {
     let rule_groups = group_by(rules, |r| r.get_n_str().to_owned());
     rule_groups.iter().fold(Vec::new(), |mut acc, e| {
         let (rule_name, rules) = &e;
+        if rules.iter().any(|r| r.get_predicate().is_some()) {
+            return acc;
+        }
         let prefix = find_prefix(
             &rules
                 .iter()
@@ -0,0 +1,27 @@
+use parol::Language;
+use parol::build::Builder;
+
+#[test]
+fn test_builder_rejects_rust_only_features_for_csharp() {
+    let error = Builder::with_explicit_output_dir(".")
+        .grammar_file("tests/data/arg_tests/generate.par")
+        .language(Language::CSharp)
+        .max_parsing_depth(100)
+        .generate_parser()
+        .unwrap_err();
+    assert_eq!(
+        "Maximum parsing depth is only supported for Rust parsers",
+        error.to_string()
+    );
+
+    let error = Builder::with_explicit_output_dir(".")
+        .grammar_file("tests/data/arg_tests/generate.par")
+        .language(Language::CSharp)
+        .arena_allocation()
+        .generate_parser_and_export_node_infos()
+        .unwrap_err();
+    assert_eq!(
+        "Arena allocation is only supported for Rust parsers",
+        error.to_string()
+    );
+}
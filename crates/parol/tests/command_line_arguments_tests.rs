@@ -62,6 +62,113 @@ fn test_subcommand_generate() {
         ));
 }
 
+#[test]
+fn test_subcommand_fuzz() {
+    let grammar_file = PathBuf::from("tests/data/arg_tests/generate.par");
+    let fuzz_dir = tempfile::tempdir().unwrap();
+
+    cargo_bin_cmd!("parol")
+        .args([
+            "fuzz",
+            "-f",
+            grammar_file.to_str().unwrap(),
+            "-p",
+            fuzz_dir.path().to_str().unwrap(),
+            "-l",
+            "512",
+        ])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("cargo fuzz run generate"));
+
+    let cargo_toml = fs::read_to_string(fuzz_dir.path().join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("cargo-fuzz = true"));
+    assert!(cargo_toml.contains("path = \"fuzz_targets/generate.rs\""));
+    assert!(cargo_toml.contains(concat!(
+        "parol_runtime = \"",
+        env!("CARGO_PKG_VERSION"),
+        "\""
+    )));
+    assert!(cargo_toml.contains("scnr2 = \"0.5.2\""));
+    let build_rs = fs::read_to_string(fuzz_dir.path().join("build.rs")).unwrap();
+    assert!(build_rs.contains(".user_type_name(\"GenerateGrammar\")"));
+    let fuzz_target =
+        fs::read_to_string(fuzz_dir.path().join("fuzz_targets").join("generate.rs")).unwrap();
+    assert!(fuzz_target.contains("const MAX_SENTENCE_LENGTH: usize = 512;"));
+    assert!(fuzz_target.contains("impl<'t> GenerateGrammarTrait<'t> for GenerateGrammar<'t> {}"));
+
+    // An existing fuzz crate is not overwritten
+    cargo_bin_cmd!("parol")
+        .args([
+            "fuzz",
+            "-f",
+            grammar_file.to_str().unwrap(),
+            "-p",
+            fuzz_dir.path().to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("already exists"));
+
+    // This subcommand only supports LL grammars
+    let grammar_file = PathBuf::from("tests/data/arg_tests/generate_lr.par");
+    cargo_bin_cmd!("parol")
+        .args([
+            "fuzz",
+            "-f",
+            grammar_file.to_str().unwrap(),
+            "-p",
+            fuzz_dir.path().join("lr").to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "Only LL grammars are supported for fuzzing",
+        ));
+}
+
+#[test]
+fn test_subcommand_coverage() {
+    let grammar_file = PathBuf::from("tests/data/arg_tests/generate.par");
+    let corpus_dir = tempfile::tempdir().unwrap();
+    fs::write(corpus_dir.path().join("empty.txt"), "Var End").unwrap();
+    fs::write(corpus_dir.path().join("invalid.txt"), "Var a").unwrap();
+    let lcov_file = corpus_dir.path().join("lcov.info");
+
+    cargo_bin_cmd!("parol")
+        .args([
+            "coverage",
+            "-f",
+            grammar_file.to_str().unwrap(),
+            "--lcov",
+            lcov_file.to_str().unwrap(),
+            corpus_dir.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::contains("Parsed 2 input files, 1 with syntax errors")
+                .and(predicates::str::contains("Repetitions: 1 of 1 repeated")),
+        );
+
+    let lcov = fs::read_to_string(&lcov_file).unwrap();
+    assert!(lcov.contains("SF:tests/data/arg_tests/generate.par\n"));
+    assert!(lcov.contains("DA:3,1\n"));
+
+    cargo_bin_cmd!("parol")
+        .args([
+            "coverage",
+            "-f",
+            grammar_file.to_str().unwrap(),
+            corpus_dir.path().join("empty.txt").to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "generate.par:3:14: `{ /[a-z_][a-zA-Z0-9_]*/ }` is never repeated",
+        ));
+}
+
 #[test]
 fn test_subcommand_left_factor() {
     let grammar_file = PathBuf::from("tests/data/arg_tests/left_factor.par");
@@ -81,6 +81,86 @@ fn run_examples_test() -> Result<()> {
         )],
     )?;
 
+    println!("Running Oberon-2 example with nested comments...");
+    let exit_status = run(
+        &example_path!("oberon2"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/oberon2/Oberon2Source/NestedComments.mod"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Offside example...");
+    let exit_status = run(
+        &example_path!("offside"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/offside/offside_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Raw Strings example...");
+    let exit_status = run(
+        &example_path!("raw_strings"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/raw_strings/raw_strings_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Byte Input example...");
+    let exit_status = run(
+        &example_path!("byte_input"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/byte_input/byte_input_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Contextual Keywords example...");
+    let exit_status = run(
+        &example_path!("contextual_keywords"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/contextual_keywords/contextual_keywords_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Typedef Names example...");
+    let exit_status = run(
+        &example_path!("typedef_names"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/typedef_names/typedef_names_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Highlighter example...");
+    let exit_status = run(
+        &example_path!("highlighter"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/highlighter/highlighter_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
+    println!("Running Includes example...");
+    let exit_status = run(
+        &example_path!("includes"),
+        &[concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../examples/includes/includes_test.txt"
+        )],
+    )?;
+    assert!(exit_status.success());
+
     println!("Running Boolean Parser example...");
     run(
         &example_path!("boolean_parser"),
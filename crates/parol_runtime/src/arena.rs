@@ -0,0 +1,10 @@
+//!
+//! The arena that the generated adapter allocates the nodes of arena-allocated ASTs in.
+//!
+//! The nodes refer to their children with plain references `&'t T` and to their repetitions with
+//! slices `&'t [T]`. Values in the arena are never dropped, their memory is released with the arena
+//! all at once. Memory a node owns outside of the arena, e.g. the shared file name of its tokens, is
+//! therefore not released.
+//!
+
+pub use bumpalo::Bump;
@@ -6,7 +6,7 @@ use std::path::Path;
 
 use crate::ParolError;
 #[cfg(feature = "reporting")]
-use crate::{LexerError, ParserError, Span, SyntaxError};
+use crate::{LexerError, ParserError, Span, SyntaxError, lexer::byte_input::report_text};
 #[cfg(feature = "reporting")]
 use codespan_reporting::diagnostic::{Diagnostic, Label};
 #[cfg(feature = "reporting")]
@@ -82,7 +82,11 @@ pub trait Report {
         let config = codespan_reporting::term::Config::default();
 
         let mut files = SimpleFiles::new();
-        let content = fs::read_to_string(file_name.as_ref()).unwrap_or_default();
+        // Files scanned as byte input may not be valid UTF-8. Their bytes are mapped one to one so
+        // that the byte offsets of the locations stay valid.
+        let content = fs::read(file_name.as_ref())
+            .map(|bytes| String::from_utf8(bytes).unwrap_or_else(|e| report_text(e.into_bytes())))
+            .unwrap_or_default();
         let file_id = files.add(file_name.as_ref().display().to_string(), content);
 
         let report_lexer_error = |err: &LexerError| -> anyhow::Result<()> {
@@ -141,6 +145,65 @@ pub trait Report {
                         .with_message(format!("Lexer recovery error: {e}"))
                         .with_code("parol_runtime::lexer::recovery"),
                 )?),
+                LexerError::UnterminatedBlockComment { location } => {
+                    let range: Range<usize> = (&**location).into();
+                    Ok(term::emit_to_write_style(
+                        &mut writer,
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message("Unterminated nested block comment")
+                            .with_code("parol_runtime::lexer::unterminated_block_comment")
+                            .with_labels(vec![
+                                Label::primary(file_id, range).with_message("Comment starts here"),
+                            ])
+                            .with_notes(vec![
+                                "Each comment start needs a matching comment end.".to_string(),
+                            ]),
+                    )?)
+                }
+                LexerError::InconsistentIndentation { location } => {
+                    let range: Range<usize> = (&**location).into();
+                    Ok(term::emit_to_write_style(
+                        &mut writer,
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message("Inconsistent indentation")
+                            .with_code("parol_runtime::lexer::inconsistent_indentation")
+                            .with_labels(vec![
+                                Label::primary(file_id, range)
+                                    .with_message("Indentation does not match any outer level"),
+                            ])
+                            .with_notes(vec![
+                                "A dedented line must return to the indentation of an enclosing block and use the same whitespace characters.".to_string(),
+                            ]),
+                    )?)
+                }
+                LexerError::InvalidExternalMatch {
+                    terminal,
+                    length,
+                    location,
+                } => {
+                    let range: Range<usize> = (&**location).into();
+                    Ok(term::emit_to_write_style(
+                        &mut writer,
+                        &config,
+                        &files,
+                        &Diagnostic::error()
+                            .with_message(format!(
+                                "External scanner returned an invalid match length {length} for terminal {terminal}"
+                            ))
+                            .with_code("parol_runtime::lexer::invalid_external_match")
+                            .with_labels(vec![
+                                Label::primary(file_id, range)
+                                    .with_message("External token starts here"),
+                            ])
+                            .with_notes(vec![
+                                "The length must not exceed the remaining input and must end on a character boundary.".to_string(),
+                            ]),
+                    )?)
+                }
             }
         };
 
@@ -182,6 +245,7 @@ pub trait Report {
                              unexpected_tokens,
                              expected_tokens,
                              source,
+                             recovery_edits,
                              ..
                          }|
                          -> anyhow::Result<()> {
@@ -204,6 +268,16 @@ pub trait Report {
                                     );
                                     acc
                                 });
+                            let recovery_edit_labels = recovery_edits
+                                .iter()
+                                .map(|edit| {
+                                    Label::secondary(
+                                        file_id,
+                                        Into::<Range<usize>>::into(edit.location()),
+                                    )
+                                    .with_message(edit.to_string())
+                                })
+                                .collect::<Vec<_>>();
                             Ok(term::emit_to_write_style(
                                 &mut writer,
                                 &config,
@@ -215,6 +289,7 @@ pub trait Report {
                                         Label::primary(file_id, range).with_message("Found"),
                                     ])
                                     .with_labels(unexpected_tokens_labels)
+                                    .with_labels(recovery_edit_labels)
                                     .with_notes(if expected_tokens.is_empty() {
                                         vec![cause.to_string()]
                                     } else {
@@ -312,6 +387,29 @@ pub trait Report {
                             "Consider checking for infinite recursion in your grammar rules.".to_string(),
                         ]),
                 )?),
+                ParserError::Cancelled => Ok(term::emit_to_write_style(
+                    &mut writer,
+                    &config,
+                    &files,
+                    &Diagnostic::note()
+                        .with_message("Parsing cancelled")
+                        .with_code("parol_runtime::parser::cancelled")
+                        .with_notes(vec![
+                            "The parser has stopped because it was cancelled.".to_string(),
+                        ]),
+                )?),
+                ParserError::BudgetExceeded { steps } => Ok(term::emit_to_write_style(
+                    &mut writer,
+                    &config,
+                    &files,
+                    &Diagnostic::error()
+                        .with_message(format!("Parse step budget ({steps}) exceeded"))
+                        .with_code("parol_runtime::parser::budget_exceeded")
+                        .with_notes(vec![
+                            "The parser has stopped because it used up its budget of parse steps."
+                                .to_string(),
+                        ]),
+                )?),
             }
         };
 
@@ -1,5 +1,7 @@
+use crate::lexer::byte_input::report_text;
 use crate::lexer::token_stream::TokenStream;
-use crate::lexer::{Location, Token};
+use crate::lexer::{Location, TerminalIndex, Token};
+use crate::parser::recovery::RecoveryEdit;
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -39,6 +41,12 @@ pub enum ParserError {
     #[error("Max parsing depth exceeded: {depth}")]
     MaxParsingDepthExceeded { depth: usize },
 
+    #[error("Parsing cancelled")]
+    Cancelled,
+
+    #[error("Parse step budget exceeded: {steps}")]
+    BudgetExceeded { steps: usize },
+
     #[error("Error recovery failed")]
     RecoveryFailed,
 
@@ -65,6 +73,19 @@ pub enum LexerError {
 
     #[error("{0}")]
     RecoveryError(String),
+
+    #[error("Unterminated nested block comment")]
+    UnterminatedBlockComment { location: Box<Location> },
+
+    #[error("Inconsistent indentation")]
+    InconsistentIndentation { location: Box<Location> },
+
+    #[error("External scanner returned an invalid match length {length} for terminal {terminal}")]
+    InvalidExternalMatch {
+        terminal: TerminalIndex,
+        length: usize,
+        location: Box<Location>,
+    },
 }
 
 #[derive(Error, Debug)]
@@ -92,6 +113,8 @@ pub struct SyntaxError {
     pub unexpected_tokens: Vec<UnexpectedToken>,
     pub expected_tokens: TokenVec,
     pub source: Option<Box<ParolError>>,
+    /// The edits the parser applied to the input to recover from this error
+    pub recovery_edits: Vec<RecoveryEdit>,
 }
 
 impl SyntaxError {
@@ -200,7 +223,12 @@ impl FileSource {
         token_stream: &TokenStream<'_, F>,
     ) -> Self {
         let file_name = token_stream.file_name.clone();
-        let input = token_stream.input.to_string();
+        let input = if token_stream.byte_input {
+            // The locations are byte offsets, so each byte has to be a single character
+            report_text(token_stream.input.chars().map(|c| c as u8))
+        } else {
+            token_stream.input.to_string()
+        };
         Self { file_name, input }
     }
 }
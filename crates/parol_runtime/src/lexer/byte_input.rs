@@ -0,0 +1,114 @@
+///
+/// Input for formats that contain arbitrary bytes, e.g. Latin-1 encoded files or protocol dumps.
+///
+/// The scanner operates on text, so each byte is mapped to the character with the same code
+/// point, i.e. the input is decoded as Latin-1. Thus a terminal like `\xFF` matches the byte
+/// `0xFF` and `[\x80-\xFF]` matches all bytes with the high bit set. Regular expressions that
+/// contain characters beyond `\u{FF}` never match.
+///
+/// The token stream has to be created with [crate::lexer::TokenStreamOptions::byte_input] so that
+/// the [crate::lexer::Location] of the tokens reports byte offsets of the input instead of offsets
+/// of the decoded text. Since each byte is one character, the columns are byte columns, too.
+/// Use [crate::lexer::Token::bytes] to obtain the original bytes of a token.
+///
+#[derive(Debug, Clone)]
+pub struct ByteInput<'b> {
+    bytes: &'b [u8],
+    text: String,
+}
+
+impl<'b> ByteInput<'b> {
+    /// Creates the input from the given bytes.
+    pub fn new(bytes: &'b [u8]) -> Self {
+        Self {
+            bytes,
+            text: bytes.iter().map(|b| *b as char).collect(),
+        }
+    }
+
+    /// Returns the decoded text that is scanned.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the original bytes.
+    pub fn bytes(&self) -> &'b [u8] {
+        self.bytes
+    }
+}
+
+///
+/// Converts between the offsets of the scanned text and the offsets of the original bytes.
+///
+/// Each conversion starts at the last converted position. Since the scanner mostly asks for
+/// increasing offsets, the conversions of a whole input take linear time.
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct OffsetMap {
+    /// The last converted offset of the text
+    text_offset: usize,
+    /// The byte offset that corresponds to `text_offset`
+    byte_offset: usize,
+}
+
+impl OffsetMap {
+    /// Converts an offset of the text into the offset of the corresponding byte.
+    pub(crate) fn byte_offset(&mut self, text: &str, text_offset: usize) -> usize {
+        self.byte_offset = if self.text_offset <= text_offset {
+            self.byte_offset + text[self.text_offset..text_offset].chars().count()
+        } else {
+            self.byte_offset - text[text_offset..self.text_offset].chars().count()
+        };
+        self.text_offset = text_offset;
+        self.byte_offset
+    }
+
+    /// Converts the offset of a byte into the offset of the corresponding character of the text.
+    pub(crate) fn text_offset(&mut self, text: &str, byte_offset: usize) -> usize {
+        self.text_offset = if self.byte_offset <= byte_offset {
+            text[self.text_offset..]
+                .char_indices()
+                .nth(byte_offset - self.byte_offset)
+                .map_or(text.len(), |(offset, _)| self.text_offset + offset)
+        } else {
+            text[..self.text_offset]
+                .char_indices()
+                .nth_back(self.byte_offset - byte_offset - 1)
+                .map_or(0, |(offset, _)| offset)
+        };
+        self.byte_offset = byte_offset;
+        self.text_offset
+    }
+}
+
+///
+/// Returns a text that has one byte per byte of the input, so that byte offsets of the input are
+/// valid offsets of the text, e.g. for error reports.
+/// ASCII characters are retained, all other bytes are replaced by a dot.
+///
+pub(crate) fn report_text(bytes: impl IntoIterator<Item = u8>) -> String {
+    bytes
+        .into_iter()
+        .map(|b| if b.is_ascii() { b as char } else { '.' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OffsetMap;
+
+    #[test]
+    fn offset_map_converts_in_both_directions() {
+        // The bytes b"a\xE9b\xFF" decoded as Latin-1
+        let text = "a\u{e9}b\u{ff}";
+        let mut map = OffsetMap::default();
+        assert_eq!(3, map.byte_offset(text, 4));
+        assert_eq!(1, map.byte_offset(text, 1));
+        assert_eq!(4, map.byte_offset(text, text.len()));
+        assert_eq!(3, map.text_offset(text, 2));
+        assert_eq!(1, map.text_offset(text, 1));
+        assert_eq!(0, map.text_offset(text, 0));
+        assert_eq!(text.len(), map.text_offset(text, 4));
+        assert_eq!(4, map.text_offset(text, 3));
+    }
+}
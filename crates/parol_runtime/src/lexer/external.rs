@@ -0,0 +1,48 @@
+use crate::{TerminalIndex, parser::ScannerIndex};
+
+///
+/// A scanner for terminals that can't be described by a regular expression, e.g. heredocs, raw
+/// strings with a variable number of delimiters or length-prefixed literals.
+/// The terminals are declared with the `%external` directive of the grammar description.
+///
+/// The token iterator calls the scanner at the start of each token in the scanner states that
+/// declare external terminals, before the regular expressions are tried. The terminals are tried
+/// in the order of their declaration and the first match wins.
+///
+pub trait ExternalScanner {
+    ///
+    /// Tries to match the given terminal at the start of `input`, which is the remaining input of
+    /// the token stream. The scanner state is the one the token would be scanned in.
+    ///
+    /// Returns the length of the match in bytes, or None if the terminal doesn't start here.
+    /// A length of zero is treated like None.
+    ///
+    fn scan(
+        &mut self,
+        terminal: TerminalIndex,
+        input: &str,
+        scanner_state: ScannerIndex,
+    ) -> Option<usize>;
+}
+
+impl<S: ExternalScanner + ?Sized> ExternalScanner for &mut S {
+    fn scan(
+        &mut self,
+        terminal: TerminalIndex,
+        input: &str,
+        scanner_state: ScannerIndex,
+    ) -> Option<usize> {
+        (**self).scan(terminal, input, scanner_state)
+    }
+}
+
+impl<S: ExternalScanner + ?Sized> ExternalScanner for Box<S> {
+    fn scan(
+        &mut self,
+        terminal: TerminalIndex,
+        input: &str,
+        scanner_state: ScannerIndex,
+    ) -> Option<usize> {
+        (**self).scan(terminal, input, scanner_state)
+    }
+}
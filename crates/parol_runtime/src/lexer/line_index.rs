@@ -0,0 +1,202 @@
+///
+/// The unit in which columns are counted.
+///
+/// The scanner counts columns in characters. Editors and language servers often need other units,
+/// e.g. the Language Server Protocol uses UTF-16 code units by default.
+///
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// Unicode scalar values, i.e. Rust `char`s
+    #[default]
+    Chars,
+    /// Bytes of the UTF-8 encoding
+    Utf8Bytes,
+    /// Code units of the UTF-16 encoding
+    Utf16,
+}
+
+impl ColumnUnit {
+    /// Returns the length of the given text in this unit.
+    pub fn count(self, text: &str) -> usize {
+        match self {
+            ColumnUnit::Chars => text.chars().count(),
+            ColumnUnit::Utf8Bytes => text.len(),
+            ColumnUnit::Utf16 => text.encode_utf16().count(),
+        }
+    }
+}
+
+///
+/// Index of the line starts of a text that converts byte offsets to line and column numbers and
+/// back.
+///
+/// With this helper the positions can be computed lazily, e.g. only for the tokens that are
+/// reported in a diagnostic. Like the scanner, only `\n` starts a new line.
+/// Lines and columns start by 1.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineIndex {
+    /// The byte offsets at which the lines start
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Creates the index for the given text.
+    pub fn new(text: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(offset, _)| offset + 1))
+            .collect();
+        Self { line_starts }
+    }
+
+    /// Returns the number of lines.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    ///
+    /// Returns the line and the column of the given byte offset.
+    /// The text must be the one the index was created for.
+    ///
+    pub fn position(&self, text: &str, offset: usize, unit: ColumnUnit) -> (u32, u32) {
+        let offset = offset.min(text.len());
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = unit.count(&text[line_start..offset]) + 1;
+        (line as u32, column as u32)
+    }
+
+    ///
+    /// Returns the byte offset of the given line and column.
+    /// The text must be the one the index was created for.
+    ///
+    /// Returns None if the line doesn't exist or the column lies beyond the end of the line or
+    /// inside of a character.
+    ///
+    pub fn offset(&self, text: &str, line: u32, column: u32, unit: ColumnUnit) -> Option<usize> {
+        let line_start = *self.line_starts.get((line as usize).checked_sub(1)?)?;
+        let line_end = self
+            .line_starts
+            .get(line as usize)
+            .copied()
+            .unwrap_or(text.len());
+        let mut units = (column as usize).checked_sub(1)?;
+        let mut offset = line_start;
+        for ch in text[line_start..line_end].chars() {
+            if units == 0 {
+                break;
+            }
+            units = units.checked_sub(unit.count(ch.encode_utf8(&mut [0; 4])))?;
+            offset += ch.len_utf8();
+        }
+        (units == 0).then_some(offset)
+    }
+}
+
+///
+/// Converts the character columns of the scanner into another unit.
+///
+/// The positions of consecutive tokens are mostly increasing, so the counting continues from the
+/// last converted position on the same line.
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ColumnConverter {
+    unit: ColumnUnit,
+    line: u32,
+    offset: usize,
+    units: usize,
+}
+
+impl ColumnConverter {
+    pub(crate) fn new(unit: ColumnUnit) -> Self {
+        Self {
+            unit,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Converts the given character column at the given byte offset of the text.
+    /// The column is the one the scanner reports for the offset.
+    ///
+    pub(crate) fn column(&mut self, text: &str, line: u32, column: u32, offset: usize) -> u32 {
+        if self.unit == ColumnUnit::Chars {
+            return column;
+        }
+        if line != self.line || offset < self.offset {
+            // The column tells how many characters of the line precede the offset
+            let line_start = offset
+                - text[..offset]
+                    .chars()
+                    .rev()
+                    .take(column.saturating_sub(1) as usize)
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+            *self = Self {
+                unit: self.unit,
+                line,
+                offset: line_start,
+                units: 0,
+            };
+        }
+        self.units += match self.unit {
+            ColumnUnit::Utf8Bytes => offset - self.offset,
+            unit => unit.count(&text[self.offset..offset]),
+        };
+        self.offset = offset;
+        self.units as u32 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "a\u{e9}\u{1F600}b\nx\r\n\u{1F600}";
+
+    #[test]
+    fn positions_in_all_units() {
+        let index = LineIndex::new(TEXT);
+        assert_eq!(3, index.line_count());
+        // Offset of 'b'
+        assert_eq!((1, 4), index.position(TEXT, 7, ColumnUnit::Chars));
+        assert_eq!((1, 8), index.position(TEXT, 7, ColumnUnit::Utf8Bytes));
+        assert_eq!((1, 5), index.position(TEXT, 7, ColumnUnit::Utf16));
+        // The carriage return belongs to the line
+        assert_eq!((2, 2), index.position(TEXT, 10, ColumnUnit::Chars));
+        assert_eq!((3, 1), index.position(TEXT, 12, ColumnUnit::Utf16));
+        assert_eq!((3, 3), index.position(TEXT, TEXT.len(), ColumnUnit::Utf16));
+    }
+
+    #[test]
+    fn offsets_in_all_units() {
+        let index = LineIndex::new(TEXT);
+        assert_eq!(Some(7), index.offset(TEXT, 1, 4, ColumnUnit::Chars));
+        assert_eq!(Some(7), index.offset(TEXT, 1, 8, ColumnUnit::Utf8Bytes));
+        assert_eq!(Some(7), index.offset(TEXT, 1, 5, ColumnUnit::Utf16));
+        assert_eq!(Some(16), index.offset(TEXT, 3, 3, ColumnUnit::Utf16));
+        // Inside of the surrogate pair
+        assert_eq!(None, index.offset(TEXT, 1, 4, ColumnUnit::Utf16));
+        // Beyond the end of the line and the text
+        assert_eq!(None, index.offset(TEXT, 2, 5, ColumnUnit::Chars));
+        assert_eq!(None, index.offset(TEXT, 4, 1, ColumnUnit::Chars));
+    }
+
+    #[test]
+    fn converter_agrees_with_line_index() {
+        let index = LineIndex::new(TEXT);
+        for unit in [ColumnUnit::Utf8Bytes, ColumnUnit::Utf16] {
+            let mut converter = ColumnConverter::new(unit);
+            for (offset, _) in TEXT
+                .char_indices()
+                .chain(std::iter::once((TEXT.len(), ' ')))
+            {
+                let (line, column) = index.position(TEXT, offset, ColumnUnit::Chars);
+                assert_eq!(
+                    index.position(TEXT, offset, unit),
+                    (line, converter.column(TEXT, line, column, offset))
+                );
+            }
+        }
+    }
+}
@@ -7,6 +7,20 @@ use std::{
 
 use derive_builder::Builder;
 
+///
+/// Type of the start and end offsets of a [Location].
+/// With the feature `offsets64` offsets are 64 bits wide to support inputs larger than 4 GiB.
+///
+#[cfg(not(feature = "offsets64"))]
+pub type Offset = u32;
+
+///
+/// Type of the start and end offsets of a [Location].
+/// With the feature `offsets64` offsets are 64 bits wide to support inputs larger than 4 GiB.
+///
+#[cfg(feature = "offsets64")]
+pub type Offset = u64;
+
 ///
 /// Common Location type
 /// This type is used to store the location of a token in the input text.
@@ -24,6 +38,7 @@ pub struct Location {
     pub start_line: u32,
 
     /// Position information: column number, starting by 1
+    /// The unit of the columns is selected with [crate::lexer::TokenStreamOptions::column_unit].
     /// A value of 0 indicates an invalid position, for instance for EOF token.
     #[builder(default)]
     pub start_column: u32,
@@ -42,14 +57,14 @@ pub struct Location {
     /// We use 0 also when dealing with artificial tokens introduced by the parser during error
     /// recovery.
     #[builder(default)]
-    pub start: u32,
+    pub start: Offset,
 
     /// The end of the span of the token in the input text
     /// The end is exclusive. It is the first character after the span.
     /// We use 0 also when dealing with artificial tokens introduced by the parser during error
     /// recovery.
     #[builder(default)]
-    pub end: u32,
+    pub end: Offset,
 
     /// The name of the input file
     pub file_name: Arc<PathBuf>,
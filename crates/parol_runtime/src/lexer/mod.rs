@@ -32,7 +32,13 @@ pub use format_token::FormatToken;
 /// Module with a location type
 ///
 pub mod location;
-pub use location::{Location, LocationBuilder};
+pub use location::{Location, LocationBuilder, Offset};
+
+///
+/// Module that provides the conversion of offsets to line and column numbers.
+///
+pub mod line_index;
+pub use line_index::{ColumnUnit, LineIndex};
 
 ///
 /// Module to support handling of std::ops::Range
@@ -59,7 +65,31 @@ pub use token_iter::TokenIter;
 /// Module that provides the TokenStream type.
 ///
 pub mod token_stream;
-pub use token_stream::TokenStream;
+pub use token_stream::{TokenStream, TokenStreamOptions};
+
+///
+/// Module that provides the Tokenizer type for scanning without a parser.
+///
+pub mod tokenizer;
+pub use tokenizer::Tokenizer;
+
+///
+/// Module that provides the synthesis of tokens for indentation-sensitive languages.
+///
+pub mod offside;
+pub use offside::OffsideTokens;
+
+///
+/// Module that provides the interface for user-defined scanners of external terminals.
+///
+pub mod external;
+pub use external::ExternalScanner;
+
+///
+/// Module that provides byte input for binary and non-UTF-8 formats.
+///
+pub mod byte_input;
+pub use byte_input::ByteInput;
 
 ///
 /// This is an  unmatchable regular expression.
@@ -86,3 +116,10 @@ pub const WHITESPACE_TOKEN: &str = r"[\s--\r\n]+";
 /// analysis.
 ///
 pub const ERROR_TOKEN: &str = r###"."###;
+
+///
+/// Prefix of the names of the scanner modes that match a single terminal each.
+/// With contextual lexing the lexer generator appends such a mode for each terminal, e.g.
+/// `__TERMINAL_5`, so that the scanner can match exactly the terminals the parser accepts.
+///
+pub const TERMINAL_MODE_PREFIX: &str = "__TERMINAL_";
@@ -0,0 +1,165 @@
+use crate::{
+    LexerError, Location, TerminalIndex, Token,
+    lexer::{BLOCK_COMMENT, EOI, LINE_COMMENT, NEW_LINE, WHITESPACE},
+};
+
+///
+/// The terminals that are synthesized in a scanner state with offside rule.
+/// They are given by the `%offside` directive of the grammar description.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OffsideTokens {
+    /// Emitted before the first token of a line that is indented deeper than the enclosing block
+    pub indent: TerminalIndex,
+    /// Emitted for each block that is closed by a line with less indentation
+    pub dedent: TerminalIndex,
+    /// Emitted at the end of each line that contains tokens
+    pub newline: TerminalIndex,
+}
+
+///
+/// Tracks the indentation of the lines and synthesizes the tokens of the offside rule.
+///
+/// The indentation of a line is the text of the whitespace token at its start. An indentation is
+/// deeper than another one if it starts with the other one. This way mixing tabs and spaces is
+/// only accepted if it is done consistently.
+///
+/// Lines that contain only whitespace and comments are ignored.
+///
+#[derive(Debug)]
+pub(crate) struct OffsideTracker<'t> {
+    /// The indentations of the enclosing blocks, the innermost is the last one.
+    /// The outermost level without indentation is not stored.
+    indentations: Vec<&'t str>,
+    /// The indentation of the current line
+    line_indentation: &'t str,
+    /// True until the first token of the current line is seen
+    at_line_start: bool,
+    /// True if a token was seen since the last synthesized newline
+    line_has_tokens: bool,
+}
+
+impl Default for OffsideTracker<'_> {
+    fn default() -> Self {
+        Self {
+            indentations: Vec::new(),
+            line_indentation: "",
+            at_line_start: true,
+            line_has_tokens: false,
+        }
+    }
+}
+
+impl<'t> OffsideTracker<'t> {
+    ///
+    /// Processes the given token and returns the tokens to insert before it.
+    /// The synthesized tokens have an empty location at the start of the given token and a token
+    /// number of zero.
+    ///
+    pub(crate) fn layout_tokens(
+        &mut self,
+        token: &Token<'t>,
+        text: &'t str,
+        offside_tokens: &OffsideTokens,
+    ) -> Result<Vec<Token<'t>>, LexerError> {
+        match token.token_type {
+            NEW_LINE => {
+                self.start_line();
+                Ok(Vec::new())
+            }
+            WHITESPACE => {
+                if self.at_line_start {
+                    self.line_indentation = text;
+                }
+                Ok(Vec::new())
+            }
+            // Line comments include the line break
+            LINE_COMMENT => {
+                if text.ends_with(['\n', '\r']) {
+                    self.start_line();
+                }
+                Ok(Vec::new())
+            }
+            BLOCK_COMMENT => Ok(Vec::new()),
+            EOI => Ok(self.finish(token, offside_tokens)),
+            _ if token.is_effectively_skip_token() => Ok(Vec::new()),
+            _ => self.first_token_of_line(token, offside_tokens),
+        }
+    }
+
+    fn start_line(&mut self) {
+        self.at_line_start = true;
+        self.line_indentation = "";
+    }
+
+    fn first_token_of_line(
+        &mut self,
+        token: &Token<'t>,
+        offside_tokens: &OffsideTokens,
+    ) -> Result<Vec<Token<'t>>, LexerError> {
+        let mut tokens = Vec::new();
+        if !self.at_line_start {
+            return Ok(tokens);
+        }
+        self.at_line_start = false;
+        if self.line_has_tokens {
+            tokens.push(Self::synthesize(offside_tokens.newline, token));
+        }
+        self.line_has_tokens = true;
+
+        let indentation = self.line_indentation;
+        let current = self.indentations.last().copied().unwrap_or("");
+        if indentation == current {
+            return Ok(tokens);
+        }
+        if indentation.starts_with(current) {
+            self.indentations.push(indentation);
+            tokens.push(Self::synthesize(offside_tokens.indent, token));
+            return Ok(tokens);
+        }
+        while let Some(outer) = self.indentations.last() {
+            if *outer == indentation || !outer.starts_with(indentation) {
+                break;
+            }
+            self.indentations.pop();
+            tokens.push(Self::synthesize(offside_tokens.dedent, token));
+        }
+        if self.indentations.last().copied().unwrap_or("") != indentation {
+            return Err(LexerError::InconsistentIndentation {
+                location: Box::new(token.location.clone()),
+            });
+        }
+        Ok(tokens)
+    }
+
+    /// Closes the current line and all open blocks at the end of the input
+    fn finish(&mut self, token: &Token<'t>, offside_tokens: &OffsideTokens) -> Vec<Token<'t>> {
+        let mut tokens = Vec::new();
+        if self.line_has_tokens {
+            tokens.push(Self::synthesize(offside_tokens.newline, token));
+            self.line_has_tokens = false;
+        }
+        for _ in self.indentations.drain(..) {
+            tokens.push(Self::synthesize(offside_tokens.dedent, token));
+        }
+        tokens
+    }
+
+    fn synthesize(token_type: TerminalIndex, before: &Token<'t>) -> Token<'t> {
+        let location = &before.location;
+        Token::with(
+            "",
+            token_type,
+            Location {
+                start_line: location.start_line,
+                start_column: location.start_column,
+                end_line: location.start_line,
+                end_column: location.start_column,
+                start: location.start,
+                end: location.start,
+                file_name: location.file_name.clone(),
+            },
+            0,
+        )
+    }
+}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use std::convert::From;
 use std::fmt::{Debug, Display, Error, Formatter};
 
-use super::{Location, TokenNumber};
+use super::{ByteInput, Location, TokenNumber};
 
 //
 // Special token constants the lexer has to deal with regularly.
@@ -66,6 +66,12 @@ pub struct Token<'t> {
 
     /// Indicates whether this token should be skipped in the scanner state it was read in.
     pub(crate) state_skip: bool,
+
+    /// Indicates whether the parser skipped this token during error recovery.
+    pub(crate) recovery_skip: bool,
+
+    /// Indicates whether the parser inserted this token during error recovery.
+    pub(crate) missing: bool,
 }
 
 impl<'t> Token<'t> {
@@ -79,6 +85,8 @@ impl<'t> Token<'t> {
             location: Location::default(),
             token_number,
             state_skip: false,
+            recovery_skip: false,
+            missing: false,
         }
     }
 
@@ -100,6 +108,8 @@ impl<'t> Token<'t> {
             location,
             token_number,
             state_skip: false,
+            recovery_skip: false,
+            missing: false,
         }
     }
 
@@ -136,10 +146,45 @@ impl<'t> Token<'t> {
             || self.token_type == INVALID_TOKEN
     }
 
-    /// Indicates whether the token is skipped, considering scanner-state-dependent skip settings.
+    /// Indicates whether the token is skipped, considering scanner-state-dependent skip settings
+    /// and tokens skipped during error recovery.
     #[inline]
     pub fn is_effectively_skip_token(&self) -> bool {
-        self.is_skip_token() || self.state_skip
+        self.is_skip_token() || self.state_skip || self.recovery_skip
+    }
+
+    ///
+    /// Indicates whether the parser skipped the token during error recovery.
+    /// Such tokens are wrapped in error nodes of the parse tree.
+    ///
+    #[inline]
+    pub fn is_skipped_by_recovery(&self) -> bool {
+        self.recovery_skip
+    }
+
+    ///
+    /// Indicates whether the parser inserted the token during error recovery.
+    /// Such tokens have no text and become missing nodes of the parse tree.
+    ///
+    #[inline]
+    pub fn is_missing(&self) -> bool {
+        self.missing
+    }
+
+    /// Creates a token the parser inserted during error recovery.
+    /// The token gets an empty span at the start of the given location.
+    pub(crate) fn missing(token_type: TerminalIndex, location: Location) -> Self {
+        Self {
+            token_type,
+            location: Location {
+                end_line: location.start_line,
+                end_column: location.start_column,
+                end: location.start,
+                ..location
+            },
+            missing: true,
+            ..Default::default()
+        }
     }
 
     ///
@@ -157,6 +202,14 @@ impl<'t> Token<'t> {
         self.text.as_ref()
     }
 
+    ///
+    /// Accesses the token's original bytes.
+    /// The token must have been scanned from the given input with byte offsets, see [ByteInput].
+    ///
+    pub fn bytes<'b>(&self, input: &ByteInput<'b>) -> &'b [u8] {
+        &input.bytes()[self.location.range()]
+    }
+
     ///
     /// Creates an owned instance of the token from a shared reference
     ///
@@ -167,6 +220,8 @@ impl<'t> Token<'t> {
             location: self.location.clone(),
             token_number: self.token_number,
             state_skip: self.state_skip,
+            recovery_skip: self.recovery_skip,
+            missing: self.missing,
         }
     }
 
@@ -180,6 +235,8 @@ impl<'t> Token<'t> {
             location: self.location,
             token_number: self.token_number,
             state_skip: self.state_skip,
+            recovery_skip: self.recovery_skip,
+            missing: self.missing,
         }
     }
 }
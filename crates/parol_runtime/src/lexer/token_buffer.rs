@@ -7,15 +7,23 @@
 //! or comments. They are typically ignored by the parser, but they are built into the
 //! so called lossless parse tree, which can be used by users to understand the structure
 //! of the input text.
-use super::Token;
+use super::byte_input::OffsetMap;
+use super::{Offset, Token};
 use crate::{LexerError, TokenNumber};
 
 /// Buffer for tokens
 #[derive(Debug, Default)]
 pub struct TokenBuffer<'t> {
     tokens: Vec<Token<'t>>,
-    last_token_location: u32,
+    last_token_location: Offset,
     last_token_number: TokenNumber,
+    /// If true, the locations are offsets in characters, see [crate::lexer::ByteInput]
+    byte_input: bool,
+    /// Converts the byte offsets of the locations into offsets of the input
+    offsets: OffsetMap,
+    /// If true, no tokens are inserted for the text between the tokens, see
+    /// [crate::TokenStream::from_tokens]
+    without_gaps: bool,
 }
 
 impl<'t> TokenBuffer<'t> {
@@ -25,13 +33,29 @@ impl<'t> TokenBuffer<'t> {
             tokens: Vec::new(),
             last_token_location: 0,
             last_token_number: 0,
+            byte_input: false,
+            offsets: OffsetMap::default(),
+            without_gaps: false,
         }
     }
 
+    /// Interprets the locations of the tokens as offsets in characters
+    pub(crate) fn with_byte_input(mut self, byte_input: bool) -> Self {
+        self.byte_input = byte_input;
+        self
+    }
+
+    /// Doesn't insert tokens for the text between the tokens, because the tokens don't stem from
+    /// one input text
+    pub(crate) fn without_gaps(mut self) -> Self {
+        self.without_gaps = true;
+        self
+    }
+
     /// Adds a token to the buffer
     pub(crate) fn add(&mut self, token: Token<'t>, input: &'t str) {
         let new_start = token.location.start;
-        if self.last_token_location < new_start {
+        if !self.without_gaps && self.last_token_location < new_start {
             use crate::lexer::location::Location;
             use crate::lexer::token::INVALID_TOKEN;
             let gap_location = Location {
@@ -46,10 +70,14 @@ impl<'t> TokenBuffer<'t> {
             } else {
                 self.last_token_number + 1
             };
+            let gap = if self.byte_input {
+                self.offsets.text_offset(input, gap_location.start as usize)
+                    ..self.offsets.text_offset(input, gap_location.end as usize)
+            } else {
+                gap_location.range()
+            };
             let invalid_token = Token::with(
-                std::convert::Into::<std::borrow::Cow<'t, str>>::into(
-                    &input[gap_location.start as usize..gap_location.end as usize],
-                ),
+                std::convert::Into::<std::borrow::Cow<'t, str>>::into(&input[gap]),
                 INVALID_TOKEN,
                 gap_location,
                 next_token_number,
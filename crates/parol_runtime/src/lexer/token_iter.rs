@@ -1,11 +1,17 @@
 use crate::{
-    TokenNumber,
-    lexer::{Token, location},
+    LexerError, TerminalIndex, TokenNumber,
+    lexer::{
+        BLOCK_COMMENT, ColumnUnit, ExternalScanner, FIRST_USER_TOKEN, Location, Offset,
+        TERMINAL_MODE_PREFIX, Token, byte_input::OffsetMap, line_index::ColumnConverter, location,
+    },
 };
 use location::LocationBuilder;
 use log::trace;
-use scnr2::{FindMatchesWithPosition, Match};
-use std::{path::PathBuf, sync::Arc};
+use scnr2::{
+    AcceptData, Dfa, FindMatchesWithPosition, Lookahead, Match, Position, Positions, ScannerMode,
+    internals::find_matches::FindMatchesTrait,
+};
+use std::{ops::Range, path::PathBuf, sync::Arc};
 
 ///
 /// The TokenIter type provides iterator functionality for Token<'t> objects.
@@ -28,6 +34,37 @@ where
     pub file_name: Arc<PathBuf>,
 
     token_number: TokenNumber,
+
+    /// The start and end delimiters of nested block comments, indexed by scanner state
+    nested_block_comments_by_state: &'static [&'static [(&'static str, &'static str)]],
+
+    /// The scanner of the external terminals
+    external_scanner: Option<Box<dyn ExternalScanner + 't>>,
+
+    /// The external terminals, indexed by scanner state
+    external_tokens_by_state: &'static [&'static [TerminalIndex]],
+
+    /// The terminals the parser accepts at the next token, if restricted
+    acceptable_terminals: Option<Vec<TerminalIndex>>,
+
+    /// The DFAs of the scanner modes that match a single terminal, indexed by terminal
+    terminal_dfas: Vec<Option<&'static Dfa>>,
+
+    /// The user terminals each scanner mode can match, indexed by scanner mode
+    terminals_by_mode: Vec<Vec<TerminalIndex>>,
+
+    /// If true, each character of the input stands for one byte and locations report byte
+    /// offsets, see [crate::lexer::ByteInput]
+    byte_input: bool,
+
+    /// Converts the offsets of the text into byte offsets
+    offsets: OffsetMap,
+
+    /// Converts the columns of the scanner into the selected unit
+    columns: ColumnConverter,
+
+    /// An error detected while scanning that ends the iteration
+    error: Option<LexerError>,
 }
 
 impl<'t, F> TokenIter<'t, F>
@@ -50,7 +87,139 @@ where
             k,
             file_name: file_name.clone(),
             token_number: 0,
+            nested_block_comments_by_state: &[],
+            external_scanner: None,
+            external_tokens_by_state: &[],
+            acceptable_terminals: None,
+            terminal_dfas: Vec::new(),
+            terminals_by_mode: Vec::new(),
+            byte_input: false,
+            offsets: OffsetMap::default(),
+            columns: ColumnConverter::default(),
+            error: None,
+        }
+    }
+
+    ///
+    /// Sets the delimiters of nested block comments for each scanner state.
+    ///
+    /// The scanner only matches the start delimiter of a nested block comment as a
+    /// [BLOCK_COMMENT] token. The iterator then extends the token up to the matching end
+    /// delimiter while counting the nesting depth.
+    ///
+    pub fn with_nested_block_comments(
+        mut self,
+        nested_block_comments_by_state: &'static [&'static [(&'static str, &'static str)]],
+    ) -> Self {
+        self.nested_block_comments_by_state = nested_block_comments_by_state;
+        self
+    }
+
+    ///
+    /// Sets the scanner of the external terminals and the external terminals of each scanner
+    /// state.
+    ///
+    /// At the start of each token in a scanner state with external terminals the iterator asks
+    /// the external scanner for a match before the regular expressions are tried.
+    ///
+    pub fn with_external_scanner(
+        mut self,
+        external_scanner: Box<dyn ExternalScanner + 't>,
+        external_tokens_by_state: &'static [&'static [TerminalIndex]],
+    ) -> Self {
+        self.external_scanner = Some(external_scanner);
+        self.external_tokens_by_state = external_tokens_by_state;
+        self
+    }
+
+    ///
+    /// Registers the scanner modes that match a single terminal each.
+    ///
+    /// These modes are named with the [TERMINAL_MODE_PREFIX] followed by the terminal index.
+    /// They are used to match the terminals the parser accepts when the terminal the scanner
+    /// would match in the current mode is not acceptable.
+    ///
+    pub fn with_terminal_modes(mut self, modes: &'static [ScannerMode]) -> Self {
+        for mode in modes {
+            match mode
+                .name
+                .strip_prefix(TERMINAL_MODE_PREFIX)
+                .and_then(|terminal| terminal.parse::<usize>().ok())
+            {
+                Some(terminal) => {
+                    if self.terminal_dfas.len() <= terminal {
+                        self.terminal_dfas.resize(terminal + 1, None);
+                    }
+                    self.terminal_dfas[terminal] = Some(&mode.dfa);
+                    self.terminals_by_mode.push(Vec::new());
+                }
+                None => {
+                    let mut terminals = mode
+                        .dfa
+                        .states
+                        .iter()
+                        .flat_map(|state| state.accept_data.iter())
+                        .map(|accept_data| accept_data.token_type as TerminalIndex)
+                        .filter(|terminal| *terminal >= FIRST_USER_TOKEN)
+                        .collect::<Vec<_>>();
+                    terminals.sort_unstable();
+                    terminals.dedup();
+                    self.terminals_by_mode.push(terminals);
+                }
+            }
+        }
+        self
+    }
+
+    ///
+    /// Treats each character of the input as one byte, see [crate::lexer::ByteInput].
+    /// The locations of the tokens then report byte offsets of the original input.
+    ///
+    pub fn with_byte_input(mut self, byte_input: bool) -> Self {
+        self.byte_input = byte_input;
+        self
+    }
+
+    ///
+    /// Converts a byte offset of the scanned text into an offset of the input.
+    /// With byte input the offset of the input is the number of characters before the offset.
+    ///
+    fn input_offset(&mut self, offset: usize) -> Offset {
+        if !self.byte_input {
+            return offset as Offset;
         }
+        self.offsets.byte_offset(self.input, offset) as Offset
+    }
+
+    ///
+    /// Counts the columns of the tokens in the given unit instead of characters.
+    ///
+    pub fn with_column_unit(mut self, column_unit: ColumnUnit) -> Self {
+        self.columns = ColumnConverter::new(column_unit);
+        self
+    }
+
+    ///
+    /// Restricts the matching of the following tokens to the given terminals.
+    /// Newlines, whitespace and comments are always matched.
+    ///
+    /// If no acceptable terminal matches at the current position, the unrestricted match is
+    /// returned so that the parser can report the error.
+    ///
+    pub(crate) fn set_acceptable_terminals(&mut self, terminals: Option<Vec<TerminalIndex>>) {
+        self.acceptable_terminals = terminals;
+    }
+
+    #[inline]
+    fn is_acceptable(&self, terminal: TerminalIndex) -> bool {
+        self.acceptable_terminals
+            .as_ref()
+            .is_none_or(|terminals| terminal < FIRST_USER_TOKEN || terminals.contains(&terminal))
+    }
+
+    /// Returns the error that ended the iteration, if any.
+    pub(crate) fn take_error(&mut self) -> Option<LexerError> {
+        self.error.take()
     }
 
     /// Returns the name of the scanner mode with the given index.
@@ -63,19 +232,44 @@ where
         self.find_iter.current_mode()
     }
 
+    ///
+    /// Creates the location of the given span of the text from the positions the scanner reports
+    /// for it. The columns are converted into the selected unit and the offsets into offsets of
+    /// the input.
+    ///
+    fn location(&mut self, span: Range<usize>, positions: &Positions) -> Result<Location, String> {
+        let start_line = positions.start_position.line as u32;
+        let end_line = positions.end_position.line as u32;
+        let start_column = self.columns.column(
+            self.input,
+            start_line,
+            positions.start_position.column as u32,
+            span.start,
+        );
+        let end_column = self.columns.column(
+            self.input,
+            end_line,
+            positions.end_position.column as u32,
+            span.end,
+        );
+        let start = self.input_offset(span.start);
+        let end = self.input_offset(span.end);
+        LocationBuilder::default()
+            .start_line(start_line)
+            .start_column(start_column)
+            .end_line(end_line)
+            .end_column(end_column)
+            .start(start)
+            .end(end)
+            .file_name(Arc::clone(&self.file_name))
+            .build()
+            .map_err(|e| e.to_string())
+    }
+
     #[inline(always)]
     pub(crate) fn token_from_match(&mut self, matched: Match) -> Option<Token<'t>> {
         let positions = matched.positions?;
-        let location = LocationBuilder::default()
-            .start_line(positions.start_position.line as u32)
-            .start_column(positions.start_position.column as u32)
-            .end_line(positions.end_position.line as u32)
-            .end_column(positions.end_position.column as u32)
-            .start(matched.span.start as u32)
-            .end(matched.span.end as u32)
-            .file_name(Arc::clone(&self.file_name))
-            .build()
-            .ok()?;
+        let location = self.location(matched.span.clone(), &positions).ok()?;
 
         let text = &self.input[matched.span];
         let token = Token::with(text, matched.token_type as u16, location, self.token_number);
@@ -86,6 +280,268 @@ where
         }
         Some(token)
     }
+
+    ///
+    /// Extends a match of the start delimiter of a nested block comment to the whole comment.
+    /// Returns the match unchanged if it is no such start delimiter.
+    ///
+    fn extend_nested_block_comment(
+        &mut self,
+        matched: Match,
+        scanner_state: usize,
+    ) -> Result<Match, LexerError> {
+        let Some(delimiters) = self.nested_block_comments_by_state.get(scanner_state) else {
+            return Ok(matched);
+        };
+        let text = &self.input[matched.span.clone()];
+        let Some((start, end)) = delimiters.iter().find(|(start, _)| *start == text) else {
+            return Ok(matched);
+        };
+        let mut depth = 1usize;
+        let mut offset = matched.span.end;
+        while depth > 0 {
+            let rest = &self.input[offset..];
+            let len = if rest.starts_with(start) {
+                depth += 1;
+                start.len()
+            } else if rest.starts_with(end) {
+                depth -= 1;
+                end.len()
+            } else if let Some(ch) = rest.chars().next() {
+                ch.len_utf8()
+            } else {
+                let positions = matched.positions.unwrap_or_default();
+                let location = self
+                    .location(matched.span.clone(), &positions)
+                    .map_err(LexerError::InternalError)?;
+                return Err(LexerError::UnterminatedBlockComment {
+                    location: Box::new(location),
+                });
+            };
+            offset += len;
+        }
+        // Move the scanner behind the comment and keep track of the end position
+        let end_position = self.advance_to(
+            matched.span.end,
+            offset,
+            matched.positions.as_ref().map(|p| p.end_position),
+        );
+        trace!("Nested block comment {}..{}", matched.span.start, offset);
+        Ok(
+            Match::new(matched.span.start..offset, matched.token_type).with_positions(
+                matched
+                    .positions
+                    .zip(end_position)
+                    .map(|(p, end)| Positions::new(p.start_position, end)),
+            ),
+        )
+    }
+
+    ///
+    /// Tries the external terminals of the given scanner state at the current position.
+    /// On success the scanner is moved behind the match.
+    ///
+    fn scan_external(&mut self, scanner_state: usize) -> Result<Option<Match>, LexerError> {
+        let Some(terminals) = self
+            .external_tokens_by_state
+            .get(scanner_state)
+            .filter(|terminals| !terminals.is_empty())
+        else {
+            return Ok(None);
+        };
+        let Some(external_scanner) = self.external_scanner.as_mut() else {
+            return Ok(None);
+        };
+        let Some(char_item) = self.find_iter.peek() else {
+            return Ok(None);
+        };
+        let start = char_item.byte_index;
+        let rest = &self.input[start..];
+        let acceptable_terminals = &self.acceptable_terminals;
+        let Some((terminal, length)) = terminals
+            .iter()
+            .filter(|terminal| {
+                acceptable_terminals
+                    .as_ref()
+                    .is_none_or(|acceptable| acceptable.contains(terminal))
+            })
+            .find_map(|terminal| {
+                external_scanner
+                    .scan(*terminal, rest, scanner_state)
+                    .filter(|length| *length > 0)
+                    .map(|length| (*terminal, length))
+            })
+        else {
+            return Ok(None);
+        };
+        let start_position = char_item.position.unwrap_or_default();
+        if length > rest.len() || !rest.is_char_boundary(length) {
+            let location = self
+                .location(
+                    start..start,
+                    &Positions::new(start_position, start_position),
+                )
+                .map_err(LexerError::InternalError)?;
+            return Err(LexerError::InvalidExternalMatch {
+                terminal,
+                length,
+                location: Box::new(location),
+            });
+        }
+        let end = start + length;
+        let end_position = self.advance_to(start, end, char_item.position);
+        // Scanner state transitions apply to external terminals as well
+        self.find_iter.handle_mode_transition(terminal as usize);
+        trace!("External token {} at {}..{}", terminal, start, end);
+        Ok(Some(
+            Match::new(start..end, terminal as usize).with_positions(
+                char_item
+                    .position
+                    .zip(end_position)
+                    .map(|(start, end)| Positions::new(start, end)),
+            ),
+        ))
+    }
+
+    ///
+    /// Finds the longest match of an acceptable terminal at the current position.
+    /// If the terminal the scanner would match is not acceptable, the terminal modes of the
+    /// acceptable terminals are tried instead. The longest of their matches wins, on equal length
+    /// the terminal with the lower index.
+    /// On success the scanner is moved behind the match.
+    ///
+    fn find_acceptable_match(&mut self) -> Option<Match> {
+        let start = self.find_iter.peek()?;
+        let scanner_state = self.find_iter.current_mode();
+        let (end, token_type) =
+            match Self::longest_match(&self.find_iter, self.find_iter.current_dfa()) {
+                Some((end, token_type)) if self.is_acceptable(token_type as TerminalIndex) => {
+                    (end, token_type)
+                }
+                _ => self
+                    .terminals_by_mode
+                    .get(scanner_state)?
+                    .iter()
+                    .filter(|terminal| self.is_acceptable(**terminal))
+                    .filter_map(|terminal| {
+                        let dfa = self.terminal_dfas.get(*terminal as usize)?.as_ref()?;
+                        Self::longest_match(&self.find_iter, dfa)
+                    })
+                    .fold(None, |acc: Option<(usize, usize)>, m| match acc {
+                        Some(best) if best.0 >= m.0 => Some(best),
+                        _ => Some(m),
+                    })?,
+            };
+        let end_position = self.advance_to(start.byte_index, end, start.position);
+        self.find_iter.handle_mode_transition(token_type);
+        trace!(
+            "Acceptable token {} at {}..{}",
+            token_type, start.byte_index, end
+        );
+        Some(
+            Match::new(start.byte_index..end, token_type).with_positions(
+                start
+                    .position
+                    .zip(end_position)
+                    .map(|(start, end)| Positions::new(start, end)),
+            ),
+        )
+    }
+
+    ///
+    /// Runs the given DFA at the current position without moving the scanner.
+    /// Returns the end and the terminal of the longest match like the scanner would.
+    ///
+    fn longest_match(
+        find_iter: &FindMatchesWithPosition<'t, F>,
+        dfa: &Dfa,
+    ) -> Option<(usize, usize)> {
+        let mut find_iter = find_iter.clone();
+        let mut state = 0;
+        let mut match_end = None;
+        while let Some(char_item) = find_iter.peek() {
+            let Some(Some(transition)) = find_iter
+                .get_disjoint_class(char_item.ch)
+                .and_then(|class| dfa.states[state].transitions.get(class))
+            else {
+                break;
+            };
+            state = transition.to;
+            find_iter.advance_char_iter();
+            // The accept data is sorted by priority
+            if let Some(accept_data) = dfa.states[state]
+                .accept_data
+                .iter()
+                .find(|accept_data| Self::lookahead_satisfied(&find_iter, accept_data))
+            {
+                match_end = Some((
+                    char_item.byte_index + char_item.ch.len_utf8(),
+                    accept_data.token_type,
+                ));
+            }
+        }
+        match_end
+    }
+
+    /// Evaluates the lookahead of a terminal behind its match.
+    fn lookahead_satisfied(
+        find_iter: &FindMatchesWithPosition<'t, F>,
+        accept_data: &AcceptData,
+    ) -> bool {
+        fn matches<F: FindMatchesTrait>(mut find_iter: F, dfa: &Dfa) -> bool {
+            let mut state = 0;
+            while let Some(char_item) = find_iter.peek() {
+                let Some(Some(transition)) = find_iter
+                    .get_disjoint_class(char_item.ch)
+                    .and_then(|class| dfa.states[state].transitions.get(class))
+                else {
+                    return false;
+                };
+                state = transition.to;
+                if !dfa.states[state].accept_data.is_empty() {
+                    return true;
+                }
+                find_iter.advance_char_iter();
+            }
+            false
+        }
+        match &accept_data.lookahead {
+            Lookahead::None => true,
+            Lookahead::Positive(dfa) => matches(find_iter.clone(), dfa),
+            Lookahead::Negative(dfa) => !matches(find_iter.clone(), dfa),
+        }
+    }
+
+    ///
+    /// Moves the scanner from byte offset `from` to `to` of the input.
+    /// Returns the position at `to` if the position at `from` is given.
+    ///
+    fn advance_to(
+        &mut self,
+        from: usize,
+        to: usize,
+        mut position: Option<Position>,
+    ) -> Option<Position> {
+        for ch in self.input[from..to].chars() {
+            self.find_iter.advance_char_iter();
+            position = position.map(|p| {
+                if ch == '\n' {
+                    Position::new(p.line + 1, 1)
+                } else {
+                    Position::new(p.line, p.column + 1)
+                }
+            });
+        }
+        position
+    }
+
+    /// Skips the given number of token numbers.
+    /// Used for tokens that are inserted before the last token.
+    #[inline]
+    pub(crate) fn reserve_token_numbers(&mut self, count: TokenNumber) {
+        self.token_number += count;
+    }
+
     #[inline]
     pub(crate) fn next_token_number(&mut self) -> TokenNumber {
         self.token_number += 1;
@@ -99,17 +555,46 @@ where
 {
     type Item = Token<'t>;
     fn next(&mut self) -> Option<Token<'t>> {
-        if let Some(matched) = self.find_iter.next() {
+        if self.error.is_some() {
+            return None;
+        }
+        let scanner_state = self.find_iter.current_mode();
+        match self.scan_external(scanner_state) {
+            Ok(Some(matched)) => return self.token_from_match(matched),
+            Ok(None) => (),
+            Err(err) => {
+                self.error = Some(err);
+                return None;
+            }
+        }
+        let acceptable_match = if self.acceptable_terminals.is_some() {
+            self.find_acceptable_match()
+        } else {
+            None
+        };
+        if let Some(mut matched) = acceptable_match.or_else(|| self.find_iter.next()) {
+            if matched.token_type == BLOCK_COMMENT as usize
+                && !self.nested_block_comments_by_state.is_empty()
+            {
+                match self.extend_nested_block_comment(matched, scanner_state) {
+                    Ok(extended) => matched = extended,
+                    Err(err) => {
+                        self.error = Some(err);
+                        return None;
+                    }
+                }
+            }
             self.token_from_match(matched)
         } else if self.k > 0 {
             // Return at most k EOI tokens
             self.k -= 1;
             trace!("EOI");
+            let end = self.input_offset(self.input.len());
             Some(
                 Token::eoi(self.next_token_number()).with_location(
                     LocationBuilder::default()
-                        .start(self.input.len() as u32)
-                        .end(self.input.len() as u32)
+                        .start(end)
+                        .end(end)
                         .file_name(Arc::clone(&self.file_name))
                         .build()
                         .ok()?,
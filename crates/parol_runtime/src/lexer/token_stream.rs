@@ -1,6 +1,7 @@
 use crate::lexer::EOI;
 use crate::parser::ScannerIndex;
-use crate::{LexerError, LocationBuilder, TerminalIndex, Token, TokenIter, TokenNumber};
+use crate::parser_common::ScanProfile;
+use crate::{LexerError, Location, LocationBuilder, TerminalIndex, Token, TokenIter, TokenNumber};
 use log::trace;
 use scnr2::ScannerImpl;
 
@@ -8,8 +9,146 @@ use std::cell::RefCell;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Instant;
 
-use super::TokenBuffer;
+use super::ColumnUnit;
+use super::byte_input::OffsetMap;
+use super::offside::{OffsideTokens, OffsideTracker};
+use super::{ExternalScanner, TokenBuffer};
+
+///
+/// Optional scanner features of a [TokenStream].
+/// The slices are indexed by scanner state. Scanner states beyond the length of a slice don't use
+/// the respective feature.
+///
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokenStreamOptions {
+    /// Additional token types to skip
+    pub skip_tokens_by_state: &'static [&'static [TerminalIndex]],
+
+    /// The start and end delimiters of nested block comments.
+    /// Nested block comments are delivered as [crate::lexer::BLOCK_COMMENT] tokens.
+    pub nested_block_comments_by_state: &'static [&'static [(&'static str, &'static str)]],
+
+    /// The tokens that are synthesized from the indentation of the lines
+    pub offside_tokens_by_state: &'static [Option<OffsideTokens>],
+
+    /// The terminals that are scanned by an [ExternalScanner].
+    /// They are only used by [TokenStream::new_with_external_scanner].
+    pub external_tokens_by_state: &'static [&'static [TerminalIndex]],
+
+    /// Scans each token only when the parser needs it and only matches the terminals the parser
+    /// accepts at this position, see [TokenStream::restrict_lookahead].
+    pub contextual_lexing: bool,
+
+    /// Treats each character of the input as one byte, see [crate::lexer::ByteInput].
+    /// The locations of the tokens report byte offsets of the original input.
+    pub byte_input: bool,
+
+    /// The unit in which the columns of the token locations are counted
+    pub column_unit: ColumnUnit,
+}
+
+///
+/// The source of the tokens of a [TokenStream].
+/// There is only one per token stream, so the size of the scanner variant doesn't matter.
+///
+#[allow(clippy::large_enum_variant)]
+enum TokenSource<'t, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    /// The tokens are scanned from the input text
+    Scanner(TokenIter<'t, F>),
+    /// The tokens are supplied by the user, see [TokenStream::from_tokens]
+    Tokens(InjectedTokens<'t>),
+}
+
+impl<F> TokenSource<'_, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    fn current_mode(&self) -> ScannerIndex {
+        match self {
+            TokenSource::Scanner(token_iter) => token_iter.current_mode(),
+            TokenSource::Tokens(_) => 0,
+        }
+    }
+
+    fn scanner_mode_name(&self, index: ScannerIndex) -> Option<&'static str> {
+        match self {
+            TokenSource::Scanner(token_iter) => token_iter.scanner_mode_name(index),
+            TokenSource::Tokens(_) => None,
+        }
+    }
+
+    fn take_error(&mut self) -> Option<LexerError> {
+        match self {
+            TokenSource::Scanner(token_iter) => token_iter.take_error(),
+            TokenSource::Tokens(_) => None,
+        }
+    }
+
+    fn set_acceptable_terminals(&mut self, terminals: Option<Vec<TerminalIndex>>) {
+        if let TokenSource::Scanner(token_iter) = self {
+            token_iter.set_acceptable_terminals(terminals);
+        }
+    }
+
+    fn reserve_token_numbers(&mut self, count: TokenNumber) {
+        match self {
+            TokenSource::Scanner(token_iter) => token_iter.reserve_token_numbers(count),
+            TokenSource::Tokens(tokens) => tokens.token_number += count,
+        }
+    }
+}
+
+impl<'t, F> Iterator for TokenSource<'t, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    type Item = Token<'t>;
+
+    fn next(&mut self) -> Option<Token<'t>> {
+        match self {
+            TokenSource::Scanner(token_iter) => token_iter.next(),
+            TokenSource::Tokens(tokens) => tokens.next(),
+        }
+    }
+}
+
+///
+/// Tokens supplied by the user.
+/// They are numbered in their order and followed by an end of input token located at the end of
+/// the last token.
+///
+struct InjectedTokens<'t> {
+    tokens: Box<dyn Iterator<Item = Token<'t>> + 't>,
+    token_number: TokenNumber,
+    end: Option<Location>,
+}
+
+impl<'t> Iterator for InjectedTokens<'t> {
+    type Item = Token<'t>;
+
+    fn next(&mut self) -> Option<Token<'t>> {
+        if let Some(mut token) = self.tokens.next() {
+            token.token_number = self.token_number;
+            self.token_number += 1;
+            self.end = Some(Location {
+                start: token.location.end,
+                start_line: token.location.end_line,
+                start_column: token.location.end_column,
+                ..token.location.clone()
+            });
+            Some(token)
+        } else {
+            let location = self.end.take()?;
+            trace!("EOI");
+            Some(Token::eoi(self.token_number).with_location(location))
+        }
+    }
+}
 
 ///
 /// The TokenStream<'t> type is the interface the parser actually uses.
@@ -30,9 +169,8 @@ where
     /// The name of the input file
     pub file_name: Arc<PathBuf>,
 
-    /// The actual token iterator.
-    /// It is replaced by a new one in case of scanner state switch.
-    token_iter: TokenIter<'t, F>,
+    /// The source of the tokens, normally the scanner
+    token_iter: TokenSource<'t, F>,
 
     /// Lookahead token buffer, maximum size is k
     pub tokens: TokenBuffer<'t>,
@@ -42,6 +180,27 @@ where
 
     /// Additional scanner-state-dependent token types to skip.
     pub(crate) skip_tokens_by_state: &'static [&'static [TerminalIndex]],
+
+    /// Scanner-state-dependent tokens of the offside rule
+    offside_tokens_by_state: &'static [Option<OffsideTokens>],
+
+    /// Indentation tracking for scanner states with offside rule
+    offside: OffsideTracker<'t>,
+
+    /// Tokens are restricted to the terminals acceptable for the parser
+    contextual_lexing: bool,
+
+    /// Tokens are scanned when they are requested instead of filling the lookahead buffer
+    scan_on_demand: bool,
+
+    /// Each character of the input stands for one byte and locations are offsets in characters
+    pub(crate) byte_input: bool,
+
+    /// Converts the byte offsets of the locations into offsets of the input
+    offsets: OffsetMap,
+
+    /// Statistics of the scanner, collected if the parser profiles the parse
+    scan_profile: Option<ScanProfile>,
 }
 
 impl<'t, F> TokenStream<'t, F>
@@ -79,6 +238,91 @@ where
         k: usize,
         skip_tokens_by_state: &'static [&'static [TerminalIndex]],
     ) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {
+        Self::new_with_options(
+            input,
+            file_name,
+            scanner_impl,
+            match_function,
+            k,
+            TokenStreamOptions {
+                skip_tokens_by_state,
+                ..Default::default()
+            },
+        )
+    }
+
+    ///
+    /// Creates a new TokenStream with the given scanner features.
+    ///
+    /// Fails if the first tokens can't be scanned, e.g. because of an unterminated nested block
+    /// comment. With contextual lexing such errors are reported when the tokens are requested.
+    ///
+    pub fn new_with_options<T>(
+        input: &'t str,
+        file_name: T,
+        scanner_impl: Rc<RefCell<ScannerImpl>>,
+        match_function: &'static F,
+        k: usize,
+        options: TokenStreamOptions,
+    ) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {
+        Self::create(
+            input,
+            file_name,
+            scanner_impl,
+            match_function,
+            k,
+            options,
+            None,
+            options.contextual_lexing,
+        )
+    }
+
+    ///
+    /// Creates a new TokenStream with the given scanner features whose external terminals
+    /// (`TokenStreamOptions::external_tokens_by_state`) are scanned by the given external
+    /// scanner.
+    ///
+    pub fn new_with_external_scanner<T>(
+        input: &'t str,
+        file_name: T,
+        scanner_impl: Rc<RefCell<ScannerImpl>>,
+        match_function: &'static F,
+        k: usize,
+        options: TokenStreamOptions,
+        external_scanner: Box<dyn ExternalScanner + 't>,
+    ) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {
+        Self::create(
+            input,
+            file_name,
+            scanner_impl,
+            match_function,
+            k,
+            options,
+            Some(external_scanner),
+            options.contextual_lexing,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn create<T>(
+        input: &'t str,
+        file_name: T,
+        scanner_impl: Rc<RefCell<ScannerImpl>>,
+        match_function: &'static F,
+        k: usize,
+        options: TokenStreamOptions,
+        external_scanner: Option<Box<dyn ExternalScanner + 't>>,
+        scan_on_demand: bool,
+    ) -> Result<Self, LexerError>
     where
         T: AsRef<Path>,
     {
@@ -86,12 +330,23 @@ where
         // To output the compiled automata as dot files uncomment the following two lines
         // const TARGET_FOLDER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target");
         // let _ = scanner.generate_compiled_automata_as_dot("Parol", Path::new(TARGET_FOLDER));
-        let token_iter = TokenIter::new(
+        let modes = scanner_impl.borrow().modes();
+        let mut token_iter = TokenIter::new(
             ScannerImpl::find_matches_with_position(scanner_impl, input, 0, match_function),
             input,
             file_name.clone(),
             k,
-        );
+        )
+        .with_nested_block_comments(options.nested_block_comments_by_state)
+        .with_byte_input(options.byte_input)
+        .with_column_unit(options.column_unit);
+        if options.contextual_lexing {
+            token_iter = token_iter.with_terminal_modes(modes);
+        }
+        if let Some(external_scanner) = external_scanner {
+            token_iter = token_iter
+                .with_external_scanner(external_scanner, options.external_tokens_by_state);
+        }
 
         // issue #54 "Lookahead exceeds token buffer length" with simple grammar:
         // Ensure that k is at least 1 and at most MAX_K
@@ -101,12 +356,23 @@ where
             k,
             input,
             file_name,
-            token_iter,
-            tokens: TokenBuffer::new(),
+            token_iter: TokenSource::Scanner(token_iter),
+            tokens: TokenBuffer::new().with_byte_input(options.byte_input),
             recovering: false,
-            skip_tokens_by_state,
+            skip_tokens_by_state: options.skip_tokens_by_state,
+            offside_tokens_by_state: options.offside_tokens_by_state,
+            offside: OffsideTracker::default(),
+            contextual_lexing: options.contextual_lexing,
+            scan_on_demand,
+            byte_input: options.byte_input,
+            offsets: OffsetMap::default(),
+            scan_profile: None,
         };
-        token_stream.read_tokens(k)?;
+        // With contextual lexing the first token is scanned when the parser knows which terminals
+        // it accepts
+        if !token_stream.scan_on_demand {
+            token_stream.read_tokens(k)?;
+        }
         Ok(token_stream)
     }
 
@@ -127,7 +393,7 @@ where
             Err(LexerError::LookaheadExceedsMaximum)
         } else {
             // Fill buffer to lookahead size k relative to pos
-            self.ensure_buffer()?;
+            self.fill_buffer(n + 1)?;
             if n >= self.tokens.len() {
                 if self.tokens.is_empty() && self.recovering {
                     trace!("lookahead LA({n}): EOI for recovery");
@@ -159,7 +425,7 @@ where
             Err(LexerError::LookaheadExceedsMaximum)
         } else {
             // Fill buffer to lookahead size k relative to pos
-            self.ensure_buffer()?;
+            self.fill_buffer(n + 1)?;
             if n >= self.tokens.len() {
                 if self.tokens.is_empty() && self.recovering {
                     trace!("lookahead_token_type LA({n}): EOI for recovery");
@@ -180,6 +446,31 @@ where
         }
     }
 
+    ///
+    /// Restricts the scanning of the lookahead token LA(n) to the given terminals if contextual
+    /// lexing is enabled and LA(n) is the next token to be scanned.
+    /// This way terminals like contextual keywords are only matched where the parser accepts them.
+    ///
+    /// Newlines, whitespace and comments are always matched. If none of the given terminals
+    /// matches, the token is scanned as usual.
+    ///
+    pub fn restrict_lookahead(
+        &mut self,
+        n: usize,
+        terminals: impl IntoIterator<Item = TerminalIndex>,
+    ) {
+        if !self.contextual_lexing || self.tokens.len() != n {
+            return;
+        }
+        let scanner_state = self.token_iter.current_mode();
+        let mut terminals = terminals.into_iter().collect::<Vec<TerminalIndex>>();
+        if let Some(skip_tokens) = self.skip_tokens_by_state.get(scanner_state) {
+            terminals.extend_from_slice(skip_tokens);
+        }
+        trace!("Restrict LA({n}) to {terminals:?}");
+        self.token_iter.set_acceptable_terminals(Some(terminals));
+    }
+
     /// Returns all skip tokens at the beginning of the token buffer.
     /// The tokens are removed from the buffer and the line and column numbers are updated.
     #[inline]
@@ -194,7 +485,7 @@ where
     /// The token's positions are captured to support scanner switching.
     ///
     pub fn consume(&mut self) -> Result<Token<'t>, LexerError> {
-        self.ensure_buffer()?;
+        self.fill_buffer(1)?;
         let token;
         if self.tokens.is_empty() {
             return Err(LexerError::InternalError(
@@ -209,7 +500,9 @@ where
             })?;
             trace!("Consuming {}", token_ref);
             token = self.tokens.consume()?;
-            self.ensure_buffer()?;
+            if !self.scan_on_demand {
+                self.ensure_buffer()?;
+            }
         }
         Ok(token)
     }
@@ -266,20 +559,45 @@ where
     ///
     fn read_tokens(&mut self, n: usize) -> Result<usize, LexerError> {
         let mut tokens_read = 0usize;
-        while let Some((scanner_state, mut token)) = {
-            let scanner_state = self.token_iter.current_mode();
-            self.token_iter.next().map(|token| (scanner_state, token))
-        } {
+        while let Some((scanner_state, mut token)) = self.next_token() {
             token.set_state_skip(self.is_state_skip_token(token.token_type, scanner_state));
+            if let Some(Some(offside_tokens)) = self.offside_tokens_by_state.get(scanner_state) {
+                let range = if self.byte_input {
+                    self.offsets
+                        .text_offset(self.input, token.location.start as usize)
+                        ..self
+                            .offsets
+                            .text_offset(self.input, token.location.end as usize)
+                } else {
+                    token.location.range()
+                };
+                let text = &self.input[range];
+                let layout_tokens = self.offside.layout_tokens(&token, text, offside_tokens)?;
+                self.token_iter
+                    .reserve_token_numbers(layout_tokens.len() as TokenNumber);
+                for mut layout_token in layout_tokens {
+                    // The synthesized tokens precede the token that caused them
+                    layout_token.token_number = token.token_number;
+                    token.token_number += 1;
+                    trace!("Synthesized {}: {}", self.tokens.len(), layout_token);
+                    tokens_read += 1;
+                    self.tokens.add(layout_token, self.input);
+                }
+            }
             trace!("Read {}: {}", self.tokens.len(), token);
             if !token.is_effectively_skip_token() {
                 tokens_read += 1;
+                // A restriction only applies to the next token
+                self.token_iter.set_acceptable_terminals(None);
             }
             self.tokens.add(token, self.input);
             if tokens_read >= n {
                 break;
             }
         }
+        if let Some(err) = self.token_iter.take_error() {
+            return Err(err);
+        }
         while tokens_read < n {
             trace!("read_tokens: Filling with EOI at end of input");
             self.tokens.add(Token::eoi(TokenNumber::MAX), self.input);
@@ -291,6 +609,42 @@ where
         Ok(tokens_read)
     }
 
+    /// Scans the next token and returns it together with the scanner state it was scanned in
+    fn next_token(&mut self) -> Option<(ScannerIndex, Token<'t>)> {
+        let scanner_state = self.token_iter.current_mode();
+        let Some(profile) = self.scan_profile.as_mut() else {
+            return self.token_iter.next().map(|token| (scanner_state, token));
+        };
+        let started = Instant::now();
+        let token = self.token_iter.next();
+        profile.time += started.elapsed();
+        if token.is_some() {
+            profile.tokens += 1;
+        }
+        if self.token_iter.current_mode() != scanner_state {
+            profile.scanner_switches += 1;
+        }
+        token.map(|token| (scanner_state, token))
+    }
+
+    ///
+    /// Starts collecting statistics of the scanner.
+    /// The tokens scanned when the stream was created are counted without their scan time.
+    ///
+    pub(crate) fn enable_profiling(&mut self) {
+        if self.scan_profile.is_none() {
+            self.scan_profile = Some(ScanProfile {
+                tokens: self.tokens.len(),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Returns the statistics of the scanner collected since the last call and resets them
+    pub(crate) fn take_scan_profile(&mut self) -> Option<ScanProfile> {
+        self.scan_profile.as_mut().map(std::mem::take)
+    }
+
     ///
     /// The function fills the lookahead buffer (self.tokens) with k tokens.
     /// It returns the number of tokens read.
@@ -305,6 +659,22 @@ where
         }
     }
 
+    ///
+    /// Fills the lookahead buffer with at least n tokens.
+    /// Unless tokens are scanned on demand the buffer is always filled with k tokens.
+    ///
+    fn fill_buffer(&mut self, n: usize) -> Result<usize, LexerError> {
+        if !self.scan_on_demand {
+            return self.ensure_buffer();
+        }
+        let fill_len = self.tokens.len();
+        if fill_len < n {
+            self.read_tokens(n - fill_len)
+        } else {
+            Ok(0)
+        }
+    }
+
     /// Returns the token types of the tokens in the lookahead buffer.
     /// It only considers non-skip-tokens.
     pub(crate) fn token_types(&self) -> Vec<TerminalIndex> {
@@ -323,7 +693,9 @@ where
         )
     }
 
-    pub(crate) fn replace_token_type_at(
+    /// Used in recovery mode to replace a token by a token of another type.
+    /// The replaced token is skipped and the new one is inserted as missing token behind it.
+    pub(crate) fn replace_token_at(
         &mut self,
         index: usize,
         token_type: TerminalIndex,
@@ -348,7 +720,10 @@ where
                         "non_skip_token_at_mut returned None despite len check".into()
                     )
                 })?;
-                token_mut.token_type = token_type;
+                token_mut.recovery_skip = true;
+                let location = token_mut.location.clone();
+                self.tokens
+                    .insert(index, Token::missing(token_type, location));
                 Ok(())
             }
         } else {
@@ -358,17 +733,31 @@ where
         }
     }
 
-    /// Removes a token from the token buffer.
-    /// It is used in recovery mode to remove tokens that are not needed.
-    pub(crate) fn remove_token_at(&mut self, index: usize) -> Result<(), LexerError> {
-        if self.tokens.len() > index {
-            trace!("removing token at index {index}");
-            self.tokens.remove(index);
-            Ok(())
-        } else {
-            Err(LexerError::RecoveryError(
+    /// Returns the location of the non-skip token at the given index of the token buffer
+    pub(crate) fn token_location_at(&self, index: usize) -> Option<Location> {
+        self.tokens
+            .non_skip_token_at(index)
+            .map(|token| token.location.clone())
+    }
+
+    /// Skips a token of the token buffer.
+    /// It is used in recovery mode to remove tokens that are not needed. The token stays in the
+    /// buffer as skip token to be wrapped in an error node of the parse tree.
+    pub(crate) fn skip_token_at(&mut self, index: usize) -> Result<(), LexerError> {
+        match self.tokens.non_skip_token_at_mut(index) {
+            Some(token) if token.token_type == EOI => {
+                trace!("removing end of input at index {index}");
+                self.tokens.remove(index);
+                Ok(())
+            }
+            Some(token) => {
+                trace!("skipping token at index {index}");
+                token.recovery_skip = true;
+                Ok(())
+            }
+            None => Err(LexerError::RecoveryError(
                 "Can't remove from token buffer".to_owned(),
-            ))
+            )),
         }
     }
 
@@ -392,12 +781,8 @@ where
                     .build()
                     .map_err(|e| LexerError::InternalError(e.to_string()))?
             };
-            self.tokens.insert(
-                index,
-                Token::default()
-                    .with_type(token_type)
-                    .with_location(location),
-            );
+            self.tokens
+                .insert(index, Token::missing(token_type, location));
             Ok(())
         } else {
             Err(LexerError::RecoveryError(format!(
@@ -421,3 +806,52 @@ where
         self.recovering = true;
     }
 }
+
+impl<'t> TokenStream<'t, fn(char) -> Option<usize>> {
+    ///
+    /// Creates a TokenStream from tokens that are supplied by the user instead of the scanner,
+    /// e.g. the output of a preprocessor or of a macro expansion.
+    /// The k determines the number of lookahead tokens the stream supports.
+    ///
+    /// The token types must be the terminal indices of the generated terminal table. Tokens with
+    /// the types of newlines, whitespace and comments are handled as skip tokens.
+    /// The tokens are numbered in their order, their locations are kept and may point into
+    /// different files. No tokens are inserted for the text between them.
+    ///
+    /// As there is no input text the [crate::FileSource] of syntax errors contains an empty input.
+    ///
+    pub fn from_tokens<I>(tokens: I, k: usize) -> Result<Self, LexerError>
+    where
+        I: IntoIterator<Item = Token<'t>>,
+        I::IntoIter: 't,
+    {
+        let mut tokens = tokens.into_iter().peekable();
+        let file_name = tokens
+            .peek()
+            .map(|token| token.location.file_name.clone())
+            .unwrap_or_default();
+        let k = std::cmp::max(1, k);
+        let mut token_stream = Self {
+            k,
+            input: "",
+            file_name,
+            token_iter: TokenSource::Tokens(InjectedTokens {
+                tokens: Box::new(tokens),
+                token_number: 0,
+                end: None,
+            }),
+            tokens: TokenBuffer::new().without_gaps(),
+            recovering: false,
+            skip_tokens_by_state: &[],
+            offside_tokens_by_state: &[],
+            offside: OffsideTracker::default(),
+            contextual_lexing: false,
+            scan_on_demand: false,
+            byte_input: false,
+            offsets: OffsetMap::default(),
+            scan_profile: None,
+        };
+        token_stream.read_tokens(k)?;
+        Ok(token_stream)
+    }
+}
@@ -0,0 +1,138 @@
+use crate::lexer::EOI;
+use crate::parser::ScannerIndex;
+use crate::{LexerError, Token, TokenStream};
+use scnr2::ScannerImpl;
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::rc::Rc;
+
+use super::TokenStreamOptions;
+
+///
+/// Iterator over the tokens of an input that needs no parser, e.g. for syntax highlighting or
+/// token metrics.
+///
+/// By default it yields the tokens a parser would receive. With [Tokenizer::with_skip_tokens]
+/// also newlines, whitespace, comments and unmatched text are yielded, so that the tokens cover
+/// the whole input.
+///
+/// The tokens are scanned on demand. Thus [Tokenizer::scanner_state] returns the scanner state in
+/// which the scanning continues after the tokens yielded so far.
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+///
+pub struct Tokenizer<'t, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    /// The token stream that scans the tokens
+    token_stream: TokenStream<'t, F>,
+
+    /// Yield the skip tokens, too
+    skip_tokens: bool,
+
+    /// Tokens that have been scanned but not yet yielded
+    pending: VecDeque<Token<'t>>,
+
+    /// The end of the input or an error has been reached
+    finished: bool,
+}
+
+impl<'t, F> Tokenizer<'t, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    ///
+    /// Creates a new Tokenizer with the given scanner features.
+    ///
+    /// Contextual lexing has no effect because there is no parser that restricts the terminals.
+    ///
+    pub fn new<T>(
+        input: &'t str,
+        file_name: T,
+        scanner_impl: Rc<RefCell<ScannerImpl>>,
+        match_function: &'static F,
+        options: TokenStreamOptions,
+    ) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {
+        let token_stream = TokenStream::create(
+            input,
+            file_name,
+            scanner_impl,
+            match_function,
+            1,
+            options,
+            None,
+            true,
+        )?;
+        Ok(Self {
+            token_stream,
+            skip_tokens: false,
+            pending: VecDeque::new(),
+            finished: false,
+        })
+    }
+
+    ///
+    /// Sets whether newlines, whitespace, comments and unmatched text are yielded, too.
+    ///
+    pub fn with_skip_tokens(mut self, skip_tokens: bool) -> Self {
+        self.skip_tokens = skip_tokens;
+        self
+    }
+
+    /// Returns the index of the scanner state in which the scanning continues.
+    pub fn scanner_state(&self) -> ScannerIndex {
+        self.token_stream.current_scanner_index()
+    }
+
+    /// Returns the name of the scanner state in which the scanning continues.
+    pub fn scanner_state_name(&self) -> &str {
+        self.token_stream.current_scanner()
+    }
+}
+
+impl<'t, F> Iterator for Tokenizer<'t, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    type Item = Result<Token<'t>, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(token) = self.pending.pop_front() {
+                return Some(Ok(token));
+            }
+            if self.finished {
+                return None;
+            }
+            // Scans the skip tokens up to the next token or the end of the input
+            let token_type = match self.token_stream.lookahead_token_type(0) {
+                Ok(token_type) => token_type,
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+            };
+            let skip_tokens = self.token_stream.take_skip_tokens();
+            if self.skip_tokens {
+                self.pending.extend(skip_tokens);
+            }
+            if token_type == EOI {
+                self.finished = true;
+                continue;
+            }
+            match self.token_stream.consume() {
+                Ok(token) => self.pending.push_back(token),
+                Err(err) => {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
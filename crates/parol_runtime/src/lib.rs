@@ -19,22 +19,33 @@ pub use lexer::{
 };
 
 pub mod parser_common;
-pub use parser_common::ParseTreeStack;
+pub use parser_common::{
+    ActionProfile, Coverage, DecisionProfile, ParseEvent, ParseProfile, ParseTreeStack,
+};
 
 ///
 /// Module that provides types for syntactical analysis.
 ///
 pub mod parser;
 pub use parser::{
-    LLKParser, LookaheadDFA, NonTerminalIndex, ParseStack, ParseTree, ParseTreeType, ParseType,
-    Production, ProductionIndex, ScannerIndex, StateIndex, Trans, UserActionsTrait,
+    LLKParseEvents, LLKParser, LookaheadDFA, NonTerminalIndex, ParseStack, ParseTree,
+    ParseTreeType, ParseType, PredicateIndex, PredicatedDecision, Production, ProductionIndex,
+    ScannerIndex, StateIndex, Trans, UserActionsTrait,
 };
 
 ///
 /// Module that provides types for the LR parser.
 ///
 pub mod lr_parser;
-pub use lr_parser::{LR1State, LRAction, LRParseTable, LRParseTree, LRParser, LRProduction};
+pub use lr_parser::{
+    LR1State, LRAction, LRParseEvents, LRParseTable, LRParseTree, LRParser, LRProduction,
+};
+
+///
+/// Module that provides the arena for arena-allocated ASTs.
+///
+#[cfg(feature = "arena")]
+pub mod arena;
 
 ///
 /// Module with error types reported from this crate.
@@ -1,6 +1,6 @@
 #[forbid(missing_docs)]
 pub mod parser_types;
-pub use parser_types::{LR1State, LRAction, LRParseTable, LRParser, LRProduction};
+pub use parser_types::{LR1State, LRAction, LRParseEvents, LRParseTable, LRParser, LRProduction};
 
 pub mod parse_tree;
 pub use parse_tree::LRParseTree;
@@ -5,16 +5,23 @@
 //! This is suboptimal but necessary to avoid a dependency to the `lalr` crate here.
 
 use core::str;
-use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    rc::Rc,
+    sync::{Arc, atomic::AtomicBool},
+    time::Instant,
+};
 
 use log::trace;
 
 use crate::{
     FileSource, LRParseTree, NonTerminalIndex, ParolError, ParseTree, ParseTreeStack,
-    ParseTreeType, ParserError, ProductionIndex, Result, SyntaxError, TerminalIndex, TokenStream,
-    TokenVec, UnexpectedToken, UserActionsTrait,
+    ParseTreeType, ParserError, ProductionIndex, Result, SyntaxError, TerminalIndex, Token,
+    TokenStream, TokenVec, UnexpectedToken, UserActionsTrait,
     lr_parser::parse_tree::build_tree,
     parser::{parse_tree_type::TreeConstruct, parser_types::TreeBuilder},
+    parser_common::{Coverage, EventQueue, ParseControl, ParseEvent, ParseProfile},
 };
 
 /// The type of the index of a LR action in the parse table's actions array.
@@ -233,6 +240,23 @@ pub struct LRParser<'t> {
     /// To enable this call the method `set_max_parsing_depth` on the parser object before parsing.
     /// Default is `None`.
     max_parsing_depth: Option<usize>,
+
+    /// Cancellation and step budget of the parser.
+    /// To enable them call the methods `set_cancellation_token` and `set_step_budget` on the
+    /// parser object before parsing.
+    parse_control: ParseControl,
+
+    /// The parse table belongs to an entry point and not to the start symbol.
+    /// Such a parse table accepts the input after the entry point has been reduced.
+    entry_point: bool,
+
+    /// Usage counts of productions and terminals, collected if coverage is enabled.
+    /// To enable it call the method `enable_coverage` on the parser object before parsing.
+    coverage: Option<Coverage>,
+
+    /// Timings of the semantic actions and the scanner, collected if profiling is enabled.
+    /// To enable it call the method `enable_profiling` on the parser object before parsing.
+    profile: Option<ParseProfile>,
 }
 
 impl<'t> LRParser<'t> {
@@ -256,6 +280,35 @@ impl<'t> LRParser<'t> {
             non_terminal_names,
             trim_parse_tree: false,
             max_parsing_depth: None,
+            parse_control: ParseControl::default(),
+            entry_point: false,
+            coverage: None,
+            profile: None,
+        }
+    }
+
+    ///
+    /// Creates a new LR parser that starts with the given entry point instead of the start symbol.
+    ///
+    /// The parse table must be calculated for the entry point. It accepts the input when the entry
+    /// point is followed by the end of input.
+    ///
+    pub fn new_for_entry_point(
+        entry_point_index: NonTerminalIndex,
+        parse_table: &'static LRParseTable,
+        productions: &'static [LRProduction],
+        terminal_names: &'static [&'static str],
+        non_terminal_names: &'static [&'static str],
+    ) -> Self {
+        LRParser {
+            entry_point: true,
+            ..Self::new(
+                entry_point_index,
+                parse_table,
+                productions,
+                terminal_names,
+                non_terminal_names,
+            )
         }
     }
 
@@ -276,11 +329,89 @@ impl<'t> LRParser<'t> {
         self.max_parsing_depth = Some(max_depth);
     }
 
+    ///
+    /// Sets a flag that cancels the parser when it is set, e.g. from another thread.
+    /// The parser checks the flag in each parse step and returns [ParserError::Cancelled].
+    ///
+    pub fn set_cancellation_token(&mut self, cancellation_token: Arc<AtomicBool>) {
+        self.parse_control
+            .set_cancellation_token(cancellation_token);
+    }
+
+    ///
+    /// Sets the maximum number of parse steps, i.e. shifts and reductions.
+    /// If this is exceeded, the parser will stop and return [ParserError::BudgetExceeded].
+    ///
+    pub fn set_step_budget(&mut self, step_budget: usize) {
+        self.parse_control.set_step_budget(step_budget);
+    }
+
+    ///
+    /// Enables counting how often each production is reduced and each terminal is shifted.
+    /// The counts accumulate over all subsequent calls of the parse functions.
+    ///
+    pub fn enable_coverage(&mut self) {
+        if self.coverage.is_none() {
+            self.coverage = Some(Coverage::new(
+                self.productions.len(),
+                self.terminal_names.len(),
+            ));
+        }
+    }
+
+    ///
+    /// Returns the coverage collected so far, if coverage is enabled.
+    ///
+    pub fn coverage(&self) -> Option<&Coverage> {
+        self.coverage.as_ref()
+    }
+
+    ///
+    /// Enables measuring the semantic actions and the scanner.
+    /// The measurements accumulate over all subsequent calls of the parse functions.
+    ///
+    /// An LR parser makes no predictions, thus the lookahead decisions of the profile stay empty.
+    ///
+    pub fn enable_profiling(&mut self) {
+        if self.profile.is_none() {
+            self.profile = Some(ParseProfile::new(
+                self.non_terminal_names,
+                self.productions.iter().map(|p| p.lhs).collect(),
+            ));
+        }
+    }
+
+    ///
+    /// Returns the profile collected so far, if profiling is enabled.
+    ///
+    pub fn profile(&self) -> Option<&ParseProfile> {
+        self.profile.as_ref()
+    }
+
+    /// Adds the elapsed parse time and the statistics of the scanner to the profile
+    fn collect_profile<F: Fn(char) -> Option<usize> + Clone>(
+        &mut self,
+        stream: &RefCell<TokenStream<'t, F>>,
+        started: Option<Instant>,
+    ) {
+        if let Some(profile) = self.profile.as_mut() {
+            if let Some(started) = started {
+                profile.add_parse_time(started.elapsed());
+            }
+            if let Some(scan) = stream.borrow_mut().take_scan_profile() {
+                profile.add_scan(scan);
+            }
+        }
+    }
+
     fn call_action<'u>(
         &mut self,
         prod_num: ProductionIndex,
         user_actions: &'u mut dyn UserActionsTrait<'t>,
     ) -> Result<usize> {
+        if let Some(coverage) = self.coverage.as_mut() {
+            coverage.count_production(prod_num);
+        }
         // Calculate the number of symbols in the production
         let n = self.productions[prod_num].len;
 
@@ -317,7 +448,11 @@ impl<'t> LRParser<'t> {
 
         // With the argument built from children we can call the user's semantic action
         trace!("Call semantic action for production {prod_num}");
+        let started = self.profile.is_some().then(Instant::now);
         user_actions.call_semantic_action_for_production_number(prod_num, &arguments)?;
+        if let (Some(profile), Some(started)) = (self.profile.as_mut(), started) {
+            profile.add_action(prod_num, started.elapsed());
+        }
         Ok(n)
     }
 
@@ -325,6 +460,7 @@ impl<'t> LRParser<'t> {
         &mut self,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
         user_actions: &'u mut dyn UserActionsTrait<'t>,
+        mut events: Option<&mut EventQueue<'t>>,
     ) -> Result<()> {
         stream
             .borrow_mut()
@@ -334,6 +470,9 @@ impl<'t> LRParser<'t> {
                 if !self.trim_parse_tree {
                     self.parse_tree_stack.push(LRParseTree::Terminal(t.clone()));
                 }
+                if let Some(events) = events.as_deref_mut() {
+                    events.push(ParseEvent::Token(t.clone()));
+                }
                 if t.is_comment_token() {
                     user_actions.on_comment(t);
                 }
@@ -352,6 +491,22 @@ impl<'t> LRParser<'t> {
         Ok(builder.build()?)
     }
 
+    ///
+    /// Parses the given tokens, e.g. the output of a preprocessor, instead of scanning an input
+    /// text, see [TokenStream::from_tokens].
+    ///
+    pub fn parse_tokens<'u, I>(
+        &mut self,
+        tokens: I,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<ParseTree>
+    where
+        I: IntoIterator<Item = Token<'t>>,
+        I::IntoIter: 't,
+    {
+        self.parse(TokenStream::from_tokens(tokens, 1)?, user_actions)
+    }
+
     ///
     /// Parses the input text.
     ///
@@ -365,119 +520,212 @@ impl<'t> LRParser<'t> {
         ParolError: From<T::Error>,
     {
         let stream = Rc::new(RefCell::new(stream));
+        self.start_parse();
+        let started = self.profile.is_some().then(Instant::now);
+        if started.is_some() {
+            stream.borrow_mut().enable_profiling();
+        }
+        let result = self.parse_remaining(tree_builder, stream.clone(), user_actions);
+        self.collect_profile(&stream, started);
+        result
+    }
+
+    fn parse_remaining<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
+        &mut self,
+        tree_builder: &mut T,
+        stream: Rc<RefCell<TokenStream<'t, F>>>,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
+        while self.parse_step(stream.clone(), user_actions, None)? {}
+        if !self.trim_parse_tree {
+            // The parse tree stack should contain only one element at this point
+            // Handle additional tokens after the last token relevant for the grammar
+            debug_assert!(!self.parse_tree_stack.is_empty());
+            // With contextual lexing the rest of the input hasn't been scanned yet
+            stream.borrow_mut().ensure_buffer()?;
+            self.handle_additional_tokens(stream.clone(), user_actions, None)?;
+            // Add a root node to the tree that can receive besides the root symbol all other symbols
+            // of the parse tree, e.g. comments, whitespace, etc.
+            let parse_tree = LRParseTree::NonTerminal("", Some(self.parse_tree_stack.pop_all()));
+            build_tree::<T>(tree_builder, parse_tree)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns an iterator that parses the input on demand and yields the [ParseEvent]s of the
+    /// parse tree, similar to a pull parser.
+    ///
+    /// The parser only proceeds when the next event is requested, thus dropping the iterator stops
+    /// parsing early. The semantic actions are called as with [LRParser::parse_into].
+    /// Errors are returned as the last item.
+    ///
+    /// An LR parser recognizes a non-terminal when it is reduced, i.e. after all of its symbols.
+    /// Therefore the events are yielded in postfix order: the [ParseEvent::Token] events of a
+    /// non-terminal precede its [ParseEvent::Exit] event and there are no [ParseEvent::Enter]
+    /// events.
+    ///
+    pub fn events<'a, F: Fn(char) -> Option<usize> + Clone>(
+        &'a mut self,
+        stream: TokenStream<'t, F>,
+        user_actions: &'a mut dyn UserActionsTrait<'t>,
+    ) -> LRParseEvents<'a, 't, F> {
+        self.start_parse();
+        let stream = Rc::new(RefCell::new(stream));
+        if self.profile.is_some() {
+            stream.borrow_mut().enable_profiling();
+        }
+        LRParseEvents {
+            parser: self,
+            stream,
+            user_actions,
+            queue: EventQueue::default(),
+            finished: false,
+            error: None,
+        }
+    }
 
+    fn start_parse(&mut self) {
         // Initialize the parse stack and the parse tree stack.
         self.parser_stack = LRParseStack::new();
         self.parse_tree_stack = ParseTreeStack::new();
+        self.parse_control.reset();
+    }
 
-        loop {
-            if let Some(max_depth) = self.max_parsing_depth
-                && self.parser_stack.stack.len() > max_depth
-            {
-                return Err(ParolError::ParserError(
-                    ParserError::MaxParsingDepthExceeded {
-                        depth: self.parser_stack.stack.len(),
-                    },
-                ));
-            }
-            self.handle_additional_tokens(stream.clone(), user_actions)?;
-            let terminal_index = stream.borrow_mut().lookahead_token_type(0)?;
-            let current_state = self.parser_stack.current_state();
-            trace!(
-                "Current state: {}, token type: {} ({})",
-                current_state, terminal_index, self.terminal_names[terminal_index as usize]
-            );
-            // Get the action for the current state and the current terminal
-            let action = self.parse_table.action(current_state, terminal_index);
-
-            match action {
-                Some(action) => {
-                    match action {
-                        LRAction::Shift(next_state) => {
-                            // Consume the token
-                            let token = stream.borrow_mut().consume()?;
-                            trace!("Shift to state {next_state}");
-                            self.parser_stack.push(*next_state);
-                            trace!(
-                                "Push token {} ({})",
-                                token.text, self.terminal_names[token.token_type as usize]
-                            );
-                            let token = LRParseTree::Terminal(token.clone());
-                            self.parse_tree_stack.push(token);
+    /// Performs one shift or reduction.
+    /// Returns false if the input has been accepted.
+    fn parse_step<'u, F: Fn(char) -> Option<usize> + Clone>(
+        &mut self,
+        stream: Rc<RefCell<TokenStream<'t, F>>>,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+        mut events: Option<&mut EventQueue<'t>>,
+    ) -> Result<bool> {
+        self.parse_control.step()?;
+        if let Some(max_depth) = self.max_parsing_depth
+            && self.parser_stack.stack.len() > max_depth
+        {
+            return Err(ParolError::ParserError(
+                ParserError::MaxParsingDepthExceeded {
+                    depth: self.parser_stack.stack.len(),
+                },
+            ));
+        }
+        let current_state = self.parser_stack.current_state();
+        // With contextual lexing only the terminals viable in the current state are matched
+        stream.borrow_mut().restrict_lookahead(
+            0,
+            self.parse_table.states[current_state]
+                .actions
+                .iter()
+                .map(|(t, _)| *t),
+        );
+        let terminal_index = stream.borrow_mut().lookahead_token_type(0)?;
+        self.handle_additional_tokens(stream.clone(), user_actions, events.as_deref_mut())?;
+        trace!(
+            "Current state: {}, token type: {} ({})",
+            current_state, terminal_index, self.terminal_names[terminal_index as usize]
+        );
+        // Get the action for the current state and the current terminal
+        let action = self.parse_table.action(current_state, terminal_index);
+
+        match action {
+            Some(action) => {
+                match action {
+                    LRAction::Shift(next_state) => {
+                        // Consume the token
+                        let token = stream.borrow_mut().consume()?;
+                        if let Some(coverage) = self.coverage.as_mut() {
+                            coverage.count_terminal(token.token_type);
                         }
-                        LRAction::Reduce(nt_index, prod_index) => {
-                            trace!("Reduce by production {prod_index}");
-                            let nt_index = *nt_index;
-                            let prod_index = *prod_index;
-
-                            let n = self.call_action(prod_index, user_actions)?;
-                            for _ in 0..n {
-                                // Pop n states from the stack
-                                if self.parser_stack.stack.is_empty() {
-                                    return Err(ParserError::InternalError(
-                                        "Attempted to pop from an empty stack".to_owned(),
-                                    )
-                                    .into());
-                                }
-                                self.parser_stack.pop();
+                        trace!("Shift to state {next_state}");
+                        self.parser_stack.push(*next_state);
+                        trace!(
+                            "Push token {} ({})",
+                            token.text, self.terminal_names[token.token_type as usize]
+                        );
+                        if let Some(events) = events.as_deref_mut() {
+                            events.push(ParseEvent::Token(token.clone()));
+                        }
+                        let token = LRParseTree::Terminal(token.clone());
+                        self.parse_tree_stack.push(token);
+                    }
+                    LRAction::Reduce(nt_index, prod_index) => {
+                        trace!("Reduce by production {prod_index}");
+                        let nt_index = *nt_index;
+                        let prod_index = *prod_index;
+
+                        let n = self.call_action(prod_index, user_actions)?;
+                        if let Some(events) = events.as_deref_mut() {
+                            events.push(ParseEvent::Exit(self.non_terminal_names[nt_index]));
+                        }
+                        for _ in 0..n {
+                            // Pop n states from the stack
+                            if self.parser_stack.stack.is_empty() {
+                                return Err(ParserError::InternalError(
+                                    "Attempted to pop from an empty stack".to_owned(),
+                                )
+                                .into());
                             }
-                            // The new state is the one on top of the stack
-                            let state = self.parser_stack.current_state();
-                            trace!("Current state after removing {n} states is {state}");
-                            let goto = match self.parse_table.goto(state, nt_index) {
-                                Some(goto) => goto,
-                                None => {
-                                    return Err(ParserError::InternalError(format!(
-                                        "No goto for non-terminal '{nt_index}' in state {state}"
-                                    ))
-                                    .into());
-                                }
-                            };
-                            // Push the new state onto the stack
-                            trace!("Push goto state {goto}");
-                            self.parser_stack.push(goto);
+                            self.parser_stack.pop();
                         }
-                        LRAction::Accept => {
-                            trace!("Accept");
-                            // The non-terminal of the start symbol lies on top of the stack here
-                            trace!("Final parse stack: {:?}", self.parser_stack.stack);
-                            trace!("Final parse tree stack:\n{}", self.parse_tree_stack);
-                            // Find the production number of the start symbol
-                            let prod_index = if let Some(index) = self
-                                .productions
-                                .iter()
-                                .position(|p| p.lhs == self.start_symbol_index)
-                            {
-                                index
-                            } else {
+                        // The new state is the one on top of the stack
+                        let state = self.parser_stack.current_state();
+                        trace!("Current state after removing {n} states is {state}");
+                        let goto = match self.parse_table.goto(state, nt_index) {
+                            Some(goto) => goto,
+                            None => {
                                 return Err(ParserError::InternalError(format!(
-                                    "No production found for start symbol '{}'",
-                                    self.non_terminal_names[self.start_symbol_index]
+                                    "No goto for non-terminal '{nt_index}' in state {state}"
                                 ))
                                 .into());
-                            };
-                            // Call the action for the start symbol
-                            let _n = self.call_action(prod_index, user_actions)?;
-                            break;
+                            }
+                        };
+                        // Push the new state onto the stack
+                        trace!("Push goto state {goto}");
+                        self.parser_stack.push(goto);
+                    }
+                    LRAction::Accept => {
+                        trace!("Accept");
+                        // The non-terminal of the start symbol lies on top of the stack here
+                        trace!("Final parse stack: {:?}", self.parser_stack.stack);
+                        trace!("Final parse tree stack:\n{}", self.parse_tree_stack);
+                        if self.entry_point {
+                            // The entry point has already been reduced
+                            return Ok(false);
                         }
+                        // Find the production number of the start symbol
+                        let prod_index = if let Some(index) = self
+                            .productions
+                            .iter()
+                            .position(|p| p.lhs == self.start_symbol_index)
+                        {
+                            index
+                        } else {
+                            return Err(ParserError::InternalError(format!(
+                                "No production found for start symbol '{}'",
+                                self.non_terminal_names[self.start_symbol_index]
+                            ))
+                            .into());
+                        };
+                        // Call the action for the start symbol
+                        let _n = self.call_action(prod_index, user_actions)?;
+                        if let Some(events) = events {
+                            events.push(ParseEvent::Exit(
+                                self.non_terminal_names[self.start_symbol_index],
+                            ));
+                        }
+                        return Ok(false);
                     }
                 }
-                None => {
-                    self.handle_parse_error(&stream, current_state, terminal_index)?;
-                }
+            }
+            None => {
+                self.handle_parse_error(&stream, current_state, terminal_index)?;
             }
         }
-        if !self.trim_parse_tree {
-            // The parse tree stack should contain only one element at this point
-            // Handle additional tokens after the last token relevant for the grammar
-            debug_assert!(!self.parse_tree_stack.is_empty());
-            self.handle_additional_tokens(stream.clone(), user_actions)?;
-            // Add a root node to the tree that can receive besides the root symbol all other symbols
-            // of the parse tree, e.g. comments, whitespace, etc.
-            let parse_tree = LRParseTree::NonTerminal("", Some(self.parse_tree_stack.pop_all()));
-            build_tree::<T>(tree_builder, parse_tree)?;
-        }
-        Ok(())
+        Ok(true)
     }
 
     fn handle_parse_error<F: Fn(char) -> Option<usize> + Clone>(
@@ -514,6 +762,7 @@ impl<'t> LRParser<'t> {
                     acc
                 }),
             source: None,
+            recovery_edits: Vec::new(),
         }];
         Err(ParolError::ParserError(ParserError::SyntaxErrors {
             entries,
@@ -521,6 +770,73 @@ impl<'t> LRParser<'t> {
     }
 }
 
+///
+/// The iterator over the [ParseEvent]s of an LR parser, see [LRParser::events].
+///
+pub struct LRParseEvents<'a, 't, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    parser: &'a mut LRParser<'t>,
+    stream: Rc<RefCell<TokenStream<'t, F>>>,
+    user_actions: &'a mut dyn UserActionsTrait<'t>,
+    /// The events of the last parse step that are not consumed yet
+    queue: EventQueue<'t>,
+    /// The parser has stopped
+    finished: bool,
+    /// The error that is returned after the remaining events
+    error: Option<ParolError>,
+}
+
+impl<'t, F> LRParseEvents<'_, 't, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    /// Adds the skip tokens after the last token relevant for the grammar
+    fn finish(&mut self) -> Result<()> {
+        // With contextual lexing the rest of the input hasn't been scanned yet
+        self.stream.borrow_mut().ensure_buffer()?;
+        self.parser.handle_additional_tokens(
+            self.stream.clone(),
+            &mut *self.user_actions,
+            Some(&mut self.queue),
+        )
+    }
+}
+
+impl<'t, F> Iterator for LRParseEvents<'_, 't, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    type Item = Result<ParseEvent<'t>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let started = self.parser.profile.is_some().then(Instant::now);
+        while self.queue.is_empty() && !self.finished {
+            match self.parser.parse_step(
+                self.stream.clone(),
+                &mut *self.user_actions,
+                Some(&mut self.queue),
+            ) {
+                Ok(true) => (),
+                Ok(false) => {
+                    self.finished = true;
+                    self.error = self.finish().err();
+                }
+                Err(error) => {
+                    self.finished = true;
+                    self.error = Some(error);
+                }
+            }
+        }
+        self.parser.collect_profile(&self.stream, started);
+        match self.queue.pop() {
+            Some(event) => Some(Ok(event)),
+            None => self.error.take().map(Err),
+        }
+    }
+}
+
 impl From<syntree::Error> for ParolError {
     fn from(source: syntree::Error) -> Self {
         ParolError::ParserError(ParserError::TreeError { source })
@@ -605,6 +921,148 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn lr_parser_returns_cancelled_error_when_cancelled() {
+        let mut parser = LRParser::new(
+            0,
+            &PARSE_TABLE,
+            &PRODUCTIONS,
+            &TERMINAL_NAMES,
+            &NON_TERMINAL_NAMES,
+        );
+        let cancellation_token = Arc::new(AtomicBool::new(true));
+        parser.set_cancellation_token(cancellation_token.clone());
+
+        let err = parser
+            .parse_tokens(Vec::new(), &mut NoopActions)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ParolError::ParserError(ParserError::Cancelled)
+        ));
+
+        cancellation_token.store(false, std::sync::atomic::Ordering::Relaxed);
+        assert!(parser.parse_tokens(Vec::new(), &mut NoopActions).is_ok());
+    }
+
+    scanner! {
+        EntryScanner {
+            mode INITIAL {
+                token r"a" => 5;
+            }
+        }
+    }
+
+    // Item: 'a'; as entry point
+    static ENTRY_TERMINAL_NAMES: [&str; 6] = [
+        "EndOfInput",
+        "Newline",
+        "Whitespace",
+        "LineComment",
+        "BlockComment",
+        "A",
+    ];
+    static ENTRY_NON_TERMINAL_NAMES: [&str; 1] = ["Item"];
+    static ENTRY_PRODUCTIONS: [LRProduction; 1] = [LRProduction { lhs: 0, len: 1, is_push_production: false }];
+    static ENTRY_ACTIONS: [LRAction; 3] = [LRAction::Shift(1), LRAction::Reduce(0, 0), LRAction::Accept];
+    static ENTRY_STATES: [LR1State; 3] = [
+        LR1State {
+            actions: &[(5, 0)],
+            gotos: &[(0, 2)],
+        },
+        LR1State {
+            actions: &[(0, 1)],
+            gotos: &[],
+        },
+        LR1State {
+            actions: &[(0, 2)],
+            gotos: &[],
+        },
+    ];
+    static ENTRY_PARSE_TABLE: LRParseTable = LRParseTable {
+        actions: &ENTRY_ACTIONS,
+        states: &ENTRY_STATES,
+    };
+
+    #[derive(Default)]
+    struct CountingActions {
+        calls: Vec<usize>,
+    }
+
+    impl<'t> UserActionsTrait<'t> for CountingActions {
+        fn call_semantic_action_for_production_number(
+            &mut self,
+            prod_num: usize,
+            _children: &[ParseTreeType<'t>],
+        ) -> Result<()> {
+            self.calls.push(prod_num);
+            Ok(())
+        }
+
+        fn on_comment(&mut self, _token: Token<'t>) {}
+    }
+
+    #[test]
+    fn lr_parser_for_entry_point_accepts_after_reduction_of_entry_point() {
+        let mut parser = LRParser::new_for_entry_point(
+            0,
+            &ENTRY_PARSE_TABLE,
+            &ENTRY_PRODUCTIONS,
+            &ENTRY_TERMINAL_NAMES,
+            &ENTRY_NON_TERMINAL_NAMES,
+        );
+
+        let scanner = entry_scanner::EntryScanner::new();
+        let stream = TokenStream::new(
+            "a",
+            "test_input",
+            scanner.scanner_impl.clone(),
+            &entry_scanner::EntryScanner::match_function,
+            1,
+        )
+        .unwrap();
+        let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+        let mut actions = CountingActions::default();
+
+        parser
+            .parse_into(&mut builder, stream, &mut actions)
+            .unwrap();
+        // The production of the entry point is reduced exactly once
+        assert_eq!(vec![0], actions.calls);
+    }
+
+    #[test]
+    fn lr_parser_yields_events_in_postfix_order() {
+        let mut parser = LRParser::new_for_entry_point(
+            0,
+            &ENTRY_PARSE_TABLE,
+            &ENTRY_PRODUCTIONS,
+            &ENTRY_TERMINAL_NAMES,
+            &ENTRY_NON_TERMINAL_NAMES,
+        );
+
+        let scanner = entry_scanner::EntryScanner::new();
+        let stream = TokenStream::new(
+            "a",
+            "test_input",
+            scanner.scanner_impl.clone(),
+            &entry_scanner::EntryScanner::match_function,
+            1,
+        )
+        .unwrap();
+        let mut actions = CountingActions::default();
+
+        let events = parser
+            .events(stream, &mut actions)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert!(matches!(
+            &events[..],
+            [ParseEvent::Token(a), ParseEvent::Exit("Item")] if a.text() == "a"
+        ));
+        assert_eq!(vec![0], actions.calls);
+    }
+
     #[test]
     fn lr_production_has_push_flag() {
         // Verify that the is_push_production flag is accessible and correctly set
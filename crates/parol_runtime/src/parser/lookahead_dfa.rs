@@ -50,6 +50,32 @@ pub struct LookaheadDFA {
     pub k: usize,
 }
 
+///
+/// Index of a semantic predicate, i.e. the position of its name in the alphabetically sorted list
+/// of the predicate names of the grammar
+///
+pub type PredicateIndex = usize;
+
+///
+/// The productions of a non-terminal that are guarded by semantic predicates.
+///
+/// The lookahead DFA of the non-terminal prefers the predicated productions over conflicting
+/// unguarded ones. When it predicts a predicated production the user actions evaluate the
+/// predicate. If the predicate fails, the fallback DFA predicts one of the unguarded productions
+/// instead.
+///
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PredicatedDecision {
+    /// The non-terminal the decision is made for
+    pub non_terminal: NonTerminalIndex,
+
+    /// Pairs of the predicated productions and the indices of their predicates
+    pub predicates: &'static [(ProductionIndex, PredicateIndex)],
+
+    /// The lookahead DFA that only predicts the productions without predicates
+    pub fallback: LookaheadDFA,
+}
+
 impl LookaheadDFA {
     ///
     /// Creates a new instance with the given parameters.
@@ -72,9 +98,24 @@ impl LookaheadDFA {
         token_stream: &mut TokenStream<'_, F>,
         non_terminal: NonTerminalIndex,
     ) -> Result<ProductionIndex, ParolError> {
+        self.eval_with_depth(token_stream, non_terminal)
+            .map(|(prod_num, _)| prod_num)
+    }
+
+    ///
+    /// Like [LookaheadDFA::eval], but additionally returns the number of lookahead tokens that
+    /// led to the decision.
+    ///
+    #[inline(always)]
+    pub fn eval_with_depth<F: Fn(char) -> Option<usize> + Clone>(
+        &self,
+        token_stream: &mut TokenStream<'_, F>,
+        non_terminal: NonTerminalIndex,
+    ) -> Result<(ProductionIndex, usize), ParolError> {
         let mut state: StateIndex = 0;
         let mut prod_num: CompiledProductionIndex = self.prod0;
         let mut last_prod_num: CompiledProductionIndex = INVALID_PROD;
+        let mut last_depth = 0;
 
         if self.k > token_stream.k {
             return Err(ParserError::DataError(
@@ -88,6 +129,16 @@ impl LookaheadDFA {
             None
         };
         for i in 0..self.k {
+            let depth = i + 1;
+            // With contextual lexing only the terminals of the current state's transitions are
+            // matched if the token hasn't been scanned yet
+            token_stream.restrict_lookahead(
+                i,
+                self.transitions
+                    .iter()
+                    .filter(|t| t.0 == state)
+                    .map(|t| t.1),
+            );
             // Read the current lookahead token and extract it's type
             let current_lookahead_token = token_stream.lookahead_token_type(i)?;
 
@@ -132,6 +183,7 @@ impl LookaheadDFA {
                             // token stream's token buffer.
                             last_accepting_state = Some(state);
                             last_prod_num = prod_num;
+                            last_depth = depth;
                             trace!("State {} accepts", state);
                         }
                         break;
@@ -147,12 +199,12 @@ impl LookaheadDFA {
         if prod_num > INVALID_PROD {
             // The state is accepting, we can return the associated production number
             trace!("Predict production {prod_num} at state {state}");
-            Ok(prod_num as ProductionIndex)
+            Ok((prod_num as ProductionIndex, last_depth))
         } else if let Some(last_state) = last_accepting_state {
             // We stepped too far, but we can return the production number of the last accepting state.
             debug_assert!(last_prod_num > INVALID_PROD);
             trace!("Predict production {last_prod_num:?} from last accepting state {last_state}");
-            Ok(last_prod_num as ProductionIndex)
+            Ok((last_prod_num as ProductionIndex, last_depth))
         } else {
             trace!(
                 "Production prediction failed at state {} with tokens {:?}",
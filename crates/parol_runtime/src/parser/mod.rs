@@ -43,7 +43,7 @@ pub use parse_tree_type::ParseTreeType;
 ///
 #[forbid(missing_docs)]
 pub mod lookahead_dfa;
-pub use lookahead_dfa::{LookaheadDFA, Trans};
+pub use lookahead_dfa::{LookaheadDFA, PredicateIndex, PredicatedDecision, Trans};
 
 ///
 /// Module with types used in the parse stack.
@@ -57,7 +57,7 @@ pub use parse_type::{ParseStack, ParseType};
 ///
 #[forbid(missing_docs)]
 pub mod parser_types;
-pub use parser_types::{LLKParser, ParseTree, Production};
+pub use parser_types::{LLKParseEvents, LLKParser, ParseTree, Production};
 
 ///
 /// Module with the UserActionsTrait type.
@@ -67,6 +67,11 @@ pub mod user_access;
 pub use user_access::UserActionsTrait;
 
 ///
-/// Module with recovery algorithms
+/// Module with recovery algorithms and the strategies to recover from syntax errors
 ///
-pub(crate) mod recovery;
+#[forbid(missing_docs)]
+pub mod recovery;
+pub use recovery::{
+    NoRecovery, PanicMode, RecoveryAction, RecoveryContext, RecoveryEdit, RecoveryStrategy,
+    TokenRepair,
+};
@@ -81,6 +81,10 @@ pub enum SynTree {
     Terminal(PTToken),
     /// A non-terminal node
     NonTerminal(&'static str),
+    /// A node that wraps the tokens the parser skipped during error recovery
+    Error,
+    /// A token the parser inserted during error recovery. It spans no text.
+    Missing(PTToken),
 }
 
 impl Display for SynTree {
@@ -88,6 +92,8 @@ impl Display for SynTree {
         match self {
             SynTree::Terminal(t) => write!(f, "{t}"),
             SynTree::NonTerminal(n) => write!(f, "{n}"),
+            SynTree::Error => write!(f, "Error"),
+            SynTree::Missing(t) => write!(f, "Missing({t})"),
         }
     }
 }
@@ -112,6 +118,24 @@ pub trait TreeConstruct<'t> {
     /// Creates a token node.
     fn add_token(&mut self, token: &Token<'t>) -> Result<(), Self::Error>;
 
+    /// Creates an error node that wraps the tokens the parser skipped during error recovery.
+    /// Skip tokens like whitespace between them are included.
+    /// The default implementation wraps them in a non-terminal node named `Error`.
+    fn add_error(&mut self, skipped_tokens: &[Token<'t>]) -> Result<(), Self::Error> {
+        self.open_non_terminal("Error", Some(skipped_tokens.len()))?;
+        for token in skipped_tokens {
+            self.add_token(token)?;
+        }
+        self.close_non_terminal()
+    }
+
+    /// Creates a node for a token the parser inserted during error recovery.
+    /// The token has no text, see [Token::is_missing].
+    /// The default implementation adds it like a scanned token.
+    fn add_missing_token(&mut self, token: &Token<'t>) -> Result<(), Self::Error> {
+        self.add_token(token)
+    }
+
     /// Returns the tree.
     fn build(self) -> Result<Self::Tree, Self::Error>;
 }
@@ -139,6 +163,20 @@ impl<'t, T: AstNode<'t>> TreeConstruct<'t> for TreeBuilder<T> {
         Ok(())
     }
 
+    fn add_error(&mut self, skipped_tokens: &[Token<'t>]) -> Result<(), Self::Error> {
+        self.open(T::error())?;
+        for token in skipped_tokens {
+            self.add_token(token)?;
+        }
+        self.close()?;
+        Ok(())
+    }
+
+    fn add_missing_token(&mut self, token: &Token<'t>) -> Result<(), Self::Error> {
+        self.token(T::missing(token), 0)?;
+        Ok(())
+    }
+
     fn build(self) -> Result<Self::Tree, Self::Error> {
         self.build()
     }
@@ -150,6 +188,16 @@ pub trait AstNode<'t>: Copy {
     fn from_non_terminal(name: &'static str) -> Self;
     /// Creates a syntree node from a token.
     fn from_token(token: &Token<'t>) -> Self;
+    /// Creates a syntree node that wraps the tokens skipped during error recovery.
+    /// The default implementation creates a non-terminal node named `Error`.
+    fn error() -> Self {
+        Self::from_non_terminal("Error")
+    }
+    /// Creates a syntree node from a token inserted during error recovery.
+    /// The default implementation creates a token node.
+    fn missing(token: &Token<'t>) -> Self {
+        Self::from_token(token)
+    }
 }
 
 impl<'t> AstNode<'t> for SynTree {
@@ -159,4 +207,10 @@ impl<'t> AstNode<'t> for SynTree {
     fn from_non_terminal(name: &'static str) -> Self {
         SynTree::NonTerminal(name)
     }
+    fn error() -> Self {
+        SynTree::Error
+    }
+    fn missing(token: &Token<'t>) -> Self {
+        SynTree::Missing(token.into())
+    }
 }
@@ -1,12 +1,23 @@
 use crate::{
     FileSource, FormatToken, Location, LookaheadDFA, NonTerminalIndex, ParolError, ParseStack,
-    ParseTreeStack, ParseTreeType, ParseType, ParserError, ProductionIndex, Result, SyntaxError,
-    TerminalIndex, TokenStream, TokenVec, UnexpectedToken, UserActionsTrait,
+    ParseTreeStack, ParseTreeType, ParseType, ParserError, PredicatedDecision, ProductionIndex,
+    Result, SyntaxError, TerminalIndex, Token, TokenStream, TokenVec, UnexpectedToken,
+    UserActionsTrait,
     lexer::EOI,
-    parser::recovery::{EditOp, Recovery},
+    parser::recovery::{
+        DEFAULT_MAX_ERRORS, EditOp, Recovery, RecoveryAction, RecoveryContext, RecoveryEdit,
+        RecoveryStrategy, TokenRepair,
+    },
+    parser_common::{Coverage, EventQueue, ParseControl, ParseEvent, ParseProfile},
 };
 use log::trace;
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::BTreeSet,
+    rc::Rc,
+    sync::{Arc, atomic::AtomicBool},
+    time::Instant,
+};
 use syntree::{Builder, Tree};
 
 use super::parse_tree_type::{SynTree, TreeConstruct};
@@ -73,6 +84,42 @@ mod tests {
             ParolError::ParserError(ParserError::MaxParsingDepthExceeded { depth: 2 })
         ));
     }
+
+    struct NoopActions;
+
+    impl<'t> UserActionsTrait<'t> for NoopActions {
+        fn call_semantic_action_for_production_number(
+            &mut self,
+            _prod_num: usize,
+            _children: &[ParseTreeType<'t>],
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn on_comment(&mut self, _token: Token<'t>) {}
+    }
+
+    fn parse_with_step_budget(step_budget: usize) -> Result<ParseTree> {
+        let mut parser = LLKParser::new(
+            0,
+            &LOOKAHEAD_AUTOMATA,
+            &PRODUCTIONS,
+            &TERMINAL_NAMES,
+            &NON_TERMINAL_NAMES,
+        );
+        parser.set_step_budget(step_budget);
+        parser.parse_tokens(Vec::new(), &mut NoopActions)
+    }
+
+    #[test]
+    fn ll_parser_returns_budget_error_when_steps_are_exceeded() {
+        // Predicting the start symbol and finishing its production take two steps
+        assert!(matches!(
+            parse_with_step_budget(1).unwrap_err(),
+            ParolError::ParserError(ParserError::BudgetExceeded { steps: 1 })
+        ));
+        assert!(parse_with_step_budget(2).is_ok());
+    }
 }
 
 impl Production {
@@ -140,6 +187,11 @@ pub struct LLKParser<'t> {
     ///
     max_parsing_depth: Option<usize>,
 
+    ///
+    /// Cancellation and step budget of the parser
+    ///
+    parse_control: ParseControl,
+
     ///
     /// Temporary stack that receives recognized grammar symbols before they
     /// are added to the parse tree.
@@ -152,6 +204,11 @@ pub struct LLKParser<'t> {
     ///
     lookahead_automata: &'static [LookaheadDFA],
 
+    ///
+    /// The generated decisions of non-terminals with productions guarded by semantic predicates.
+    ///
+    predicated_decisions: &'static [PredicatedDecision],
+
     ///
     /// The array of generated grammar productions.
     ///
@@ -179,10 +236,26 @@ pub struct LLKParser<'t> {
     /// Enables error recovery
     enable_recovery: bool,
 
+    /// The strategy that decides how the parser recovers from syntax errors
+    recovery_strategy: Box<dyn RecoveryStrategy>,
+
+    /// The maximum number of syntax errors the parser collects before it stops parsing
+    max_errors: usize,
+
     ///
     /// The parser can generate multiple syntax errors during the course of recovering from an error
     ///
     error_entries: Vec<SyntaxError>,
+
+    ///
+    /// Usage counts of productions and terminals, collected if coverage is enabled
+    ///
+    coverage: Option<Coverage>,
+
+    ///
+    /// Lookahead decisions and timings, collected if profiling is enabled
+    ///
+    profile: Option<ParseProfile>,
 }
 
 impl<'t> LLKParser<'t> {
@@ -201,14 +274,20 @@ impl<'t> LLKParser<'t> {
             parser_stack: ParseStack::new(terminal_names, non_terminal_names),
             production_depth: 0,
             max_parsing_depth: None,
+            parse_control: ParseControl::default(),
             parse_tree_stack: ParseTreeStack::new(),
             lookahead_automata,
+            predicated_decisions: &[],
             productions,
             terminal_names,
             non_terminal_names,
             trim_parse_tree: false,
             enable_recovery: true,
+            recovery_strategy: Box::new(TokenRepair),
+            max_errors: DEFAULT_MAX_ERRORS,
             error_entries: Vec::new(),
+            coverage: None,
+            profile: None,
         }
     }
 
@@ -228,6 +307,86 @@ impl<'t> LLKParser<'t> {
         self.max_parsing_depth = Some(max_depth);
     }
 
+    ///
+    /// Sets a flag that cancels the parser when it is set, e.g. from another thread.
+    /// The parser checks the flag in each parse step and returns [ParserError::Cancelled].
+    ///
+    pub fn set_cancellation_token(&mut self, cancellation_token: Arc<AtomicBool>) {
+        self.parse_control
+            .set_cancellation_token(cancellation_token);
+    }
+
+    ///
+    /// Sets the maximum number of parse steps, i.e. predictions, reductions and consumed tokens.
+    /// If this is exceeded, the parser will stop and return [ParserError::BudgetExceeded].
+    ///
+    pub fn set_step_budget(&mut self, step_budget: usize) {
+        self.parse_control.set_step_budget(step_budget);
+    }
+
+    ///
+    /// Sets the decisions of the non-terminals with productions that are guarded by semantic
+    /// predicates. The predicates are evaluated by the user actions.
+    ///
+    pub fn set_predicated_decisions(
+        &mut self,
+        predicated_decisions: &'static [PredicatedDecision],
+    ) {
+        self.predicated_decisions = predicated_decisions;
+    }
+
+    ///
+    /// Enables counting how often each production is used and each terminal is matched.
+    /// The counts accumulate over all subsequent calls of the parse functions.
+    ///
+    pub fn enable_coverage(&mut self) {
+        if self.coverage.is_none() {
+            self.coverage = Some(Coverage::new(
+                self.productions.len(),
+                self.terminal_names.len(),
+            ));
+        }
+    }
+
+    /// Returns the coverage collected so far, if coverage is enabled
+    pub fn coverage(&self) -> Option<&Coverage> {
+        self.coverage.as_ref()
+    }
+
+    ///
+    /// Enables measuring the lookahead decisions, the semantic actions and the scanner.
+    /// The measurements accumulate over all subsequent calls of the parse functions.
+    ///
+    pub fn enable_profiling(&mut self) {
+        if self.profile.is_none() {
+            self.profile = Some(ParseProfile::new(
+                self.non_terminal_names,
+                self.productions.iter().map(|p| p.lhs).collect(),
+            ));
+        }
+    }
+
+    /// Returns the profile collected so far, if profiling is enabled
+    pub fn profile(&self) -> Option<&ParseProfile> {
+        self.profile.as_ref()
+    }
+
+    /// Adds the elapsed parse time and the statistics of the scanner to the profile
+    fn collect_profile<F: Fn(char) -> Option<usize> + Clone>(
+        &mut self,
+        stream: &RefCell<TokenStream<'t, F>>,
+        started: Option<Instant>,
+    ) {
+        if let Some(profile) = self.profile.as_mut() {
+            if let Some(started) = started {
+                profile.add_parse_time(started.elapsed());
+            }
+            if let Some(scan) = stream.borrow_mut().take_scan_profile() {
+                profile.add_scan(scan);
+            }
+        }
+    }
+
     /// Returns true if the parser is currently in error recovery mode
     #[inline]
     pub fn is_in_recovery_mode(&self) -> bool {
@@ -246,6 +405,18 @@ impl<'t> LLKParser<'t> {
         self.enable_recovery = false;
     }
 
+    /// Sets the strategy that decides how the parser recovers from syntax errors.
+    /// The default strategy is [TokenRepair].
+    pub fn set_recovery_strategy(&mut self, recovery_strategy: Box<dyn RecoveryStrategy>) {
+        self.recovery_strategy = recovery_strategy;
+    }
+
+    /// Sets the maximum number of syntax errors the parser collects before it stops parsing.
+    /// The default is [DEFAULT_MAX_ERRORS].
+    pub fn set_max_errors(&mut self, max_errors: usize) {
+        self.max_errors = max_errors;
+    }
+
     fn input_accepted(&self) -> bool {
         matches!(self.parser_stack.stack[..], [] | [ParseType::T(0)])
     }
@@ -269,7 +440,7 @@ impl<'t> LLKParser<'t> {
             return Err(ParserError::RecoveryFailed.into());
         }
         self.error_entries.push(error);
-        if self.error_entries.len() > 100 {
+        if self.error_entries.len() >= self.max_errors {
             return Err(ParserError::TooManyErrors {
                 count: self.error_entries.len(),
             }
@@ -287,6 +458,9 @@ impl<'t> LLKParser<'t> {
     where
         ParolError: From<T::Error>,
     {
+        if let Some(coverage) = self.coverage.as_mut() {
+            coverage.count_production(prod_num);
+        }
         self.parser_stack.stack.push(ParseType::E(prod_num));
         for s in self.productions[prod_num].production {
             self.parser_stack.stack.push(*s);
@@ -349,7 +523,11 @@ impl<'t> LLKParser<'t> {
 
         // With the children we can call the user's semantic action
         if !self.is_in_recovery_mode() {
+            let started = self.profile.is_some().then(Instant::now);
             user_actions.call_semantic_action_for_production_number(prod_num, &children)?;
+            if let (Some(profile), Some(started)) = (self.profile.as_mut(), started) {
+                profile.add_action(prod_num, started.elapsed());
+            }
         } else {
             trace!("Semantic action suppressed for production {}", prod_num);
         }
@@ -366,9 +544,31 @@ impl<'t> LLKParser<'t> {
         &mut self,
         non_terminal: NonTerminalIndex,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
+        user_actions: &mut dyn UserActionsTrait<'t>,
     ) -> Result<ProductionIndex> {
         let lookahead_dfa = &self.lookahead_automata[non_terminal];
-        lookahead_dfa.eval(&mut stream.borrow_mut(), non_terminal)
+        let (mut prod_num, mut depth) =
+            lookahead_dfa.eval_with_depth(&mut stream.borrow_mut(), non_terminal)?;
+        if let Some(decision) = self
+            .predicated_decisions
+            .iter()
+            .find(|d| d.non_terminal == non_terminal)
+            && let Some((_, predicate)) = decision.predicates.iter().find(|(p, _)| *p == prod_num)
+        {
+            let lookahead = stream.borrow_mut().lookahead(0)?;
+            if !user_actions.evaluate_predicate(*predicate, &lookahead)? {
+                trace!("Predicate {predicate} of production {prod_num} failed");
+                let fallback_depth;
+                (prod_num, fallback_depth) = decision
+                    .fallback
+                    .eval_with_depth(&mut stream.borrow_mut(), non_terminal)?;
+                depth = depth.max(fallback_depth);
+            }
+        }
+        if let Some(profile) = self.profile.as_mut() {
+            profile.count_prediction(non_terminal, depth);
+        }
+        Ok(prod_num)
     }
 
     fn diagnostic_message<F: Fn(char) -> Option<usize> + Clone>(
@@ -411,6 +611,29 @@ impl<'t> LLKParser<'t> {
         Ok(builder.build()?)
     }
 
+    ///
+    /// Parses the given tokens, e.g. the output of a preprocessor, instead of scanning an input
+    /// text. The tokens are provided via [TokenStream::from_tokens] with the maximum lookahead
+    /// of the parser.
+    ///
+    pub fn parse_tokens<'u, I>(
+        &mut self,
+        tokens: I,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<ParseTree>
+    where
+        I: IntoIterator<Item = Token<'t>>,
+        I::IntoIter: 't,
+    {
+        let k = self
+            .lookahead_automata
+            .iter()
+            .map(|dfa| dfa.k)
+            .max()
+            .unwrap_or(1);
+        self.parse(TokenStream::from_tokens(tokens, k)?, user_actions)
+    }
+
     ///
     /// The actual parsing function for a custom tree builder.
     /// It is normally not called directly.
@@ -426,75 +649,187 @@ impl<'t> LLKParser<'t> {
     where
         ParolError: From<T::Error>,
     {
+        self.start_parse(tree_builder)?;
+        let stream = Rc::new(RefCell::new(stream));
+        let started = self.profile.is_some().then(Instant::now);
+        if started.is_some() {
+            stream.borrow_mut().enable_profiling();
+        }
+        let mut result = Ok(true);
+        while let Ok(true) = result {
+            result = self.parse_step(tree_builder, stream.clone(), user_actions);
+        }
+        let result =
+            result.and_then(|_| self.finish_parse(tree_builder, stream.clone(), user_actions));
+        self.collect_profile(&stream, started);
+        result
+    }
+
+    ///
+    /// Returns an iterator that parses the input on demand and yields the [ParseEvent]s of the
+    /// parse tree, similar to a pull parser.
+    ///
+    /// The parser only proceeds when the next event is requested, thus dropping the iterator stops
+    /// parsing early. The semantic actions are called as with [LLKParser::parse_into].
+    /// Errors are returned as the last item.
+    /// Trimming the parse tree with [LLKParser::trim_parse_tree] also suppresses the
+    /// [ParseEvent::Enter], [ParseEvent::Exit] and [ParseEvent::Token] events.
+    ///
+    pub fn events<'a, F: Fn(char) -> Option<usize> + Clone>(
+        &'a mut self,
+        stream: TokenStream<'t, F>,
+        user_actions: &'a mut dyn UserActionsTrait<'t>,
+    ) -> LLKParseEvents<'a, 't, F> {
+        let mut queue = EventQueue::default();
+        let error = self.start_parse(&mut queue).err();
+        let stream = Rc::new(RefCell::new(stream));
+        if self.profile.is_some() {
+            stream.borrow_mut().enable_profiling();
+        }
+        LLKParseEvents {
+            parser: self,
+            stream,
+            user_actions,
+            queue,
+            finished: error.is_some(),
+            error,
+        }
+    }
+
+    fn start_parse<T: TreeConstruct<'t>>(&mut self, tree_builder: &mut T) -> Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
+        // A parse that has been stopped early leaves its state behind
+        self.parser_stack.stack.clear();
+        self.parse_tree_stack = ParseTreeStack::new();
+        self.production_depth = 0;
+        self.error_entries.clear();
+
         // Add a root node to the tree that can receive besides the root symbol all other symbols
         // of the parse tree, e.g. comments, whitespace, etc.
         tree_builder.open_non_terminal("", None)?;
 
-        let stream = Rc::new(RefCell::new(stream));
-        let prod_num = match self.predict_production(self.start_symbol_index, stream.clone()) {
-            Ok(prod_num) => prod_num,
-            Err(source) => {
-                self.handle_prediction_error(self.start_symbol_index, stream.clone(), source)?
-            }
-        };
+        // The start symbol is predicted like any other non-terminal, thus a syntax error at the
+        // beginning of the input is recovered the same way
+        self.parser_stack
+            .stack
+            .push(ParseType::N(self.start_symbol_index));
+
+        self.parse_control.reset();
+        Ok(())
+    }
 
-        self.push_production(tree_builder, prod_num)?;
-
-        'WHILE: while !self.input_accepted() {
-            if let Some(entry) = self.parser_stack.stack.last().cloned() {
-                match entry {
-                    ParseType::T(t) => {
-                        let token = stream.borrow_mut().lookahead(0)?;
-                        if token.token_type == t {
-                            trace!("Consuming token {token}");
-                            self.handle_additional_tokens(
-                                tree_builder,
-                                stream.clone(),
-                                user_actions,
-                            )?;
-                            stream.borrow_mut().consume()?;
-                            self.parser_stack.stack.pop();
-                            if !self.trim_parse_tree {
+    /// Processes the topmost entry of the parse stack.
+    /// Returns false if the input has been accepted or the parser can't recover from a syntax error.
+    fn parse_step<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
+        &mut self,
+        tree_builder: &mut T,
+        stream: Rc<RefCell<TokenStream<'t, F>>>,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<bool>
+    where
+        ParolError: From<T::Error>,
+    {
+        if self.input_accepted() {
+            return Ok(false);
+        }
+        self.parse_control.step()?;
+        if let Some(entry) = self.parser_stack.stack.last().cloned() {
+            match entry {
+                ParseType::T(t) => {
+                    stream.borrow_mut().restrict_lookahead(0, [t]);
+                    let token = stream.borrow_mut().lookahead(0)?;
+                    if token.token_type == t {
+                        trace!("Consuming token {token}");
+                        self.handle_additional_tokens(tree_builder, stream.clone(), user_actions)?;
+                        stream.borrow_mut().consume()?;
+                        self.parser_stack.stack.pop();
+                        if let Some(coverage) = self.coverage.as_mut()
+                            && !token.is_missing()
+                        {
+                            coverage.count_terminal(t);
+                        }
+                        if !self.trim_parse_tree {
+                            if token.is_missing() {
+                                tree_builder.add_missing_token(&token)?;
+                            } else {
                                 tree_builder.add_token(&token)?;
                             }
-                            self.parse_tree_stack.push(ParseTreeType::T(token));
-                        } else if self
-                            .handle_token_mismatch(t, token, stream.clone())
-                            .is_err()
-                        {
-                            break 'WHILE;
                         }
+                        self.parse_tree_stack.push(ParseTreeType::T(token));
+                    } else if self
+                        .handle_token_mismatch(t, token, tree_builder, stream.clone(), user_actions)
+                        .is_err()
+                    {
+                        return Ok(false);
                     }
-                    ParseType::N(n) => match self.predict_production(n, stream.clone()) {
-                        Ok(prod_num) => {
-                            self.parser_stack.stack.pop();
-                            self.push_production(tree_builder, prod_num)?;
-                        }
-                        Err(source) => {
-                            match self.handle_prediction_error(n, stream.clone(), source) {
-                                Err(_) => break 'WHILE,
-                                Ok(prod_num) => {
-                                    self.parser_stack.stack.pop();
-                                    self.push_production(tree_builder, prod_num)?;
-                                }
+                }
+                ParseType::N(n) => match self.predict_production(n, stream.clone(), user_actions) {
+                    Ok(prod_num) => {
+                        self.parser_stack.stack.pop();
+                        self.push_production(tree_builder, prod_num)?;
+                    }
+                    Err(source) => {
+                        match self.handle_prediction_error(
+                            n,
+                            tree_builder,
+                            stream.clone(),
+                            user_actions,
+                            source,
+                        ) {
+                            Err(_) => return Ok(false),
+                            Ok(Some(prod_num)) => {
+                                self.parser_stack.stack.pop();
+                                self.push_production(tree_builder, prod_num)?;
                             }
+                            // The parse stack has been synchronized with the input
+                            Ok(None) => (),
                         }
-                    },
-                    ParseType::E(p) => {
-                        // Only decrement depth for non-push productions (matching push_production)
-                        if !self.productions[p].is_push_production {
-                            self.production_depth -= 1;
-                        }
-                        trace!("Popped production {} -> depth {}", p, self.production_depth);
-                        self.parser_stack.stack.pop(); // Pop the End of production marker
-                        self.process_item_stack(tree_builder, p, user_actions)?;
                     }
+                },
+                ParseType::E(p) => {
+                    // Only decrement depth for non-push productions (matching push_production)
+                    if !self.productions[p].is_push_production {
+                        self.production_depth -= 1;
+                    }
+                    trace!("Popped production {} -> depth {}", p, self.production_depth);
+                    self.parser_stack.stack.pop(); // Pop the End of production marker
+                    self.process_item_stack(tree_builder, p, user_actions)?;
                 }
             }
         }
+        Ok(true)
+    }
+
+    fn finish_parse<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
+        &mut self,
+        tree_builder: &mut T,
+        stream: Rc<RefCell<TokenStream<'t, F>>>,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
+        // With contextual lexing the rest of the input hasn't been scanned yet
+        stream.borrow_mut().ensure_buffer()?;
         // Handle additional tokens after the last token relevant for the grammar
         self.handle_additional_tokens(tree_builder, stream.clone(), user_actions)?;
         if !self.error_entries.is_empty() {
+            // Close the nodes that are still open, thus the tree builder holds a tree with error
+            // nodes that tools can use despite the syntax errors
+            if !self.trim_parse_tree {
+                let open_productions = self
+                    .parser_stack
+                    .stack
+                    .iter()
+                    .filter(|entry| matches!(entry, ParseType::E(_)))
+                    .count();
+                for _ in 0..open_productions {
+                    tree_builder.close_non_terminal()?;
+                }
+            }
+            tree_builder.close_non_terminal()?;
             return Err(ParserError::SyntaxErrors {
                 entries: self.error_entries.drain(..).collect(),
             }
@@ -514,12 +849,17 @@ impl<'t> LLKParser<'t> {
         }
     }
 
-    fn handle_token_mismatch<F: Fn(char) -> Option<usize> + Clone>(
+    fn handle_token_mismatch<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
         &mut self,
         t: u16,
         token: crate::Token<'_>,
+        tree_builder: &mut T,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
-    ) -> Result<()> {
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
         let mut expected_tokens = TokenVec::default();
         expected_tokens.push(self.terminal_names[t as usize].to_string());
         self.add_error(SyntaxError {
@@ -542,16 +882,22 @@ impl<'t> LLKParser<'t> {
             )],
             expected_tokens,
             source: None,
+            recovery_edits: Vec::new(),
         })?;
-        self.recover_from_token_mismatch(stream.clone())
+        self.recover_from_token_mismatch(tree_builder, stream.clone(), user_actions)
     }
 
-    fn handle_prediction_error<F: Fn(char) -> Option<usize> + Clone>(
+    fn handle_prediction_error<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
         &mut self,
         non_terminal: NonTerminalIndex,
+        tree_builder: &mut T,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
         source: crate::ParolError,
-    ) -> Result<ProductionIndex> {
+    ) -> Result<Option<ProductionIndex>>
+    where
+        ParolError: From<T::Error>,
+    {
         let nt_name = self.non_terminal_names[non_terminal];
         let (message, unexpected_tokens, expected_tokens) =
             self.lookahead_automata[non_terminal]
@@ -575,89 +921,108 @@ impl<'t> LLKParser<'t> {
             unexpected_tokens,
             expected_tokens,
             source: Some(Box::new(source)),
+            recovery_edits: Vec::new(),
         })?;
-        self.recover_from_prediction_error(non_terminal, stream.clone())
+        self.recover_from_prediction_error(non_terminal, tree_builder, stream.clone(), user_actions)
+    }
+
+    /// Asks the recovery strategy how to continue after a syntax error
+    fn recovery_action(
+        &mut self,
+        scanned_terminals: &[TerminalIndex],
+        expected_terminal_strings: &BTreeSet<Vec<TerminalIndex>>,
+        non_terminal: Option<NonTerminalIndex>,
+    ) -> RecoveryAction {
+        let context = RecoveryContext {
+            scanned_terminals,
+            expected_terminal_strings,
+            non_terminal,
+            terminal_names: self.terminal_names,
+        };
+        let action = self.recovery_strategy.recover(&context);
+        trace!("Recovery action: {action:?}");
+        action
     }
 
-    fn recover_from_prediction_error<F: Fn(char) -> Option<usize> + Clone>(
+    /// Returns Ok(None) if the parse stack has been synchronized with the input instead of
+    /// predicting a production for the non-terminal
+    fn recover_from_prediction_error<
+        'u,
+        T: TreeConstruct<'t>,
+        F: Fn(char) -> Option<usize> + Clone,
+    >(
         &mut self,
         non_terminal: NonTerminalIndex,
+        tree_builder: &mut T,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
-    ) -> Result<ProductionIndex> {
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<Option<ProductionIndex>>
+    where
+        ParolError: From<T::Error>,
+    {
         if !self.is_recovery_enabled() {
             return Err(ParserError::RecoveryFailed.into());
         }
         stream.borrow_mut().enter_recovery_mode();
         let scanned_token_types = stream.borrow().token_types();
         let la_dfa = &self.lookahead_automata[non_terminal];
-        let mut possible_terminal_strings =
+        let possible_terminal_strings =
             Recovery::restore_terminal_strings(la_dfa.transitions, la_dfa.prod0);
 
-        if let Some(expected_token_types) =
-            Recovery::minimal_token_difference(&scanned_token_types, &mut possible_terminal_strings)
-        {
-            trace!("Sync with {expected_token_types:?}");
-            self.adjust_token_stream(scanned_token_types, expected_token_types, stream.clone())?;
-            let result = self.predict_production(non_terminal, stream);
-            match result {
-                Ok(prod_num) => {
-                    trace!("recovering with production {prod_num}");
-                    return Ok(prod_num);
-                }
-                Err(source) => {
-                    trace!("predict_production failed {source:?}");
-                    return Err(source);
+        match self.recovery_action(
+            &scanned_token_types,
+            &possible_terminal_strings,
+            Some(non_terminal),
+        ) {
+            RecoveryAction::Repair(expected_token_types) => {
+                trace!("Sync with {expected_token_types:?}");
+                self.adjust_token_stream(
+                    scanned_token_types,
+                    expected_token_types,
+                    stream.clone(),
+                )?;
+                let result = self.predict_production(non_terminal, stream, user_actions);
+                match result {
+                    Ok(prod_num) => {
+                        trace!("recovering with production {prod_num}");
+                        Ok(Some(prod_num))
+                    }
+                    Err(source) => {
+                        trace!("predict_production failed {source:?}");
+                        Err(source)
+                    }
                 }
             }
-        }
-
-        if !possible_terminal_strings.is_empty() {
-            // Steamroller tactics: sync with the first possible token string
-            // Prefer the ones that don't contain the EOI token
-            let forced_token_string = possible_terminal_strings
-                .iter()
-                .find(|ts| !ts.contains(&EOI))
-                .unwrap_or_else(|| possible_terminal_strings.iter().next().unwrap());
-            trace!("Force sync with {forced_token_string:?}");
-            self.sync_token_stream(
-                scanned_token_types,
-                forced_token_string.clone(),
-                stream.clone(),
-            )?;
-            let result = self.predict_production(non_terminal, stream);
-            match result {
-                Ok(prod_num) => {
-                    trace!("recovering with production {prod_num}");
-                    return Ok(prod_num);
-                }
-                Err(source) => {
-                    trace!("predict_production failed {source:?}");
-                    return Err(source);
-                }
+            RecoveryAction::Synchronize(sync_terminals) => {
+                self.synchronize(&sync_terminals, tree_builder, stream, user_actions)?;
+                Ok(None)
+            }
+            RecoveryAction::Abort => {
+                trace!(
+                    "{}",
+                    self.diagnostic_message("Can't recover prediction error", stream.clone())
+                );
+                let current_token = stream.borrow_mut().lookahead(0).unwrap_or_default();
+                let _ = self.add_error(
+                    SyntaxError::default()
+                        .with_cause("Can't recover")
+                        .with_location(current_token.location.clone()),
+                );
+                Err(ParserError::RecoveryFailed.into())
             }
         }
-
-        trace!(
-            "{}",
-            self.diagnostic_message("Can't recover prediction error", stream.clone())
-        );
-        let current_token = stream.borrow_mut().lookahead(0).unwrap_or_default();
-        let _ = self.add_error(
-            SyntaxError::default()
-                .with_cause("Can't recover")
-                .with_location(current_token.location.clone()),
-        );
-        Err(ParserError::SyntaxErrors {
-            entries: self.error_entries.drain(..).collect(),
-        }
-        .into())
     }
 
     // Sync input tokens with expected tokens if possible
-    fn recover_from_token_mismatch<F: Fn(char) -> Option<usize> + Clone>(
+    fn recover_from_token_mismatch<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
         &mut self,
+        tree_builder: &mut T,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
-    ) -> Result<()> {
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
         if !self.is_recovery_enabled() {
             return Err(ParserError::RecoveryFailed.into());
         }
@@ -668,7 +1033,19 @@ impl<'t> LLKParser<'t> {
         trace!("LA: [{scanned_token_types:?}]");
         trace!("PS: [{expected_token_types:?}]");
 
-        self.adjust_token_stream(scanned_token_types, expected_token_types, stream.clone())
+        match self.recovery_action(
+            &scanned_token_types,
+            &BTreeSet::from([expected_token_types]),
+            None,
+        ) {
+            RecoveryAction::Repair(expected_token_types) => {
+                self.adjust_token_stream(scanned_token_types, expected_token_types, stream)
+            }
+            RecoveryAction::Synchronize(sync_terminals) => {
+                self.synchronize(&sync_terminals, tree_builder, stream, user_actions)
+            }
+            RecoveryAction::Abort => Err(ParserError::RecoveryFailed.into()),
+        }
     }
 
     fn adjust_token_stream<F: Fn(char) -> Option<usize> + Clone>(
@@ -681,59 +1058,147 @@ impl<'t> LLKParser<'t> {
         trace!("Levenshtein ops: {ops:?}");
 
         let mut stream_idx = 0;
+        let mut scanned_idx = 0;
         let mut exp_idx = 0;
+        let mut edits = Vec::new();
 
         for op in ops {
+            let location = stream
+                .borrow()
+                .token_location_at(stream_idx)
+                .unwrap_or_default();
             match op {
                 EditOp::Keep => {
                     stream_idx += 1;
+                    scanned_idx += 1;
                     exp_idx += 1;
                 }
                 EditOp::Replace => {
                     stream
                         .borrow_mut()
-                        .replace_token_type_at(stream_idx, expected_token_types[exp_idx])?;
+                        .replace_token_at(stream_idx, expected_token_types[exp_idx])?;
+                    edits.push(RecoveryEdit::Replace {
+                        terminal: scanned_token_types[scanned_idx],
+                        replacement: expected_token_types[exp_idx],
+                        replacement_name: self.terminal_names
+                            [expected_token_types[exp_idx] as usize]
+                            .to_string(),
+                        location,
+                    });
                     stream_idx += 1;
+                    scanned_idx += 1;
                     exp_idx += 1;
                 }
                 EditOp::Insert => {
                     stream
                         .borrow_mut()
                         .insert_token_at(stream_idx, expected_token_types[exp_idx])?;
+                    edits.push(RecoveryEdit::Insert {
+                        terminal: expected_token_types[exp_idx],
+                        terminal_name: self.terminal_names[expected_token_types[exp_idx] as usize]
+                            .to_string(),
+                        location,
+                    });
                     stream_idx += 1;
                     exp_idx += 1;
                 }
                 EditOp::Delete => {
-                    stream.borrow_mut().remove_token_at(stream_idx)?;
+                    stream.borrow_mut().skip_token_at(stream_idx)?;
+                    edits.push(RecoveryEdit::Delete {
+                        terminal: scanned_token_types[scanned_idx],
+                        terminal_name: self.terminal_names
+                            [scanned_token_types[scanned_idx] as usize]
+                            .to_string(),
+                        location,
+                    });
+                    scanned_idx += 1;
                 }
             }
         }
+        self.add_recovery_edits(edits);
         Ok(())
     }
 
-    fn sync_token_stream<F: Fn(char) -> Option<usize> + Clone>(
+    /// Skips the input up to the next token of one of the synchronization terminals and removes
+    /// the pending grammar symbols from the parse stack until the parser can continue with this
+    /// token.
+    fn synchronize<'u, T: TreeConstruct<'t>, F: Fn(char) -> Option<usize> + Clone>(
         &mut self,
-        scanned_token_types: Vec<TerminalIndex>,
-        expected_token_types: Vec<TerminalIndex>,
+        sync_terminals: &[TerminalIndex],
+        tree_builder: &mut T,
         stream: Rc<RefCell<TokenStream<'t, F>>>,
-    ) -> Result<()> {
-        let mut replaced = false;
-        scanned_token_types
-            .iter()
-            .zip(expected_token_types.iter())
-            .enumerate()
-            .try_for_each(|(i, (_, exp_t))| -> Result<()> {
-                replaced = true;
-                Ok(stream.borrow_mut().replace_token_type_at(i, *exp_t)?)
-            })?;
-        if replaced {
-            Ok(())
-        } else {
-            let _ = self.add_error(SyntaxError::default().with_cause("Can't sync"));
-            Err(ParserError::SyntaxErrors {
-                entries: self.error_entries.drain(..).collect(),
+        user_actions: &'u mut dyn UserActionsTrait<'t>,
+    ) -> Result<()>
+    where
+        ParolError: From<T::Error>,
+    {
+        let mut edits = Vec::new();
+        let token = loop {
+            let token = stream.borrow_mut().lookahead(0)?;
+            if token.token_type == EOI || sync_terminals.contains(&token.token_type) {
+                break token;
             }
-            .into())
+            trace!("Skipping token {token}");
+            edits.push(RecoveryEdit::Delete {
+                terminal: token.token_type,
+                terminal_name: self.terminal_names[token.token_type as usize].to_string(),
+                location: (&token).into(),
+            });
+            stream.borrow_mut().skip_token_at(0)?;
+        };
+        trace!("Synchronizing with {token}");
+        // Add the skipped tokens to the tree before the missing ones
+        self.handle_additional_tokens(tree_builder, stream.clone(), user_actions)?;
+
+        loop {
+            match self.parser_stack.stack.last().cloned() {
+                None if token.token_type == EOI => break,
+                None => return Err(ParserError::RecoveryFailed.into()),
+                Some(ParseType::T(t)) => {
+                    if t == token.token_type {
+                        break;
+                    }
+                    // The missing terminal is taken as inserted
+                    self.parser_stack.stack.pop();
+                    edits.push(RecoveryEdit::Insert {
+                        terminal: t,
+                        terminal_name: self.terminal_names[t as usize].to_string(),
+                        location: (&token).into(),
+                    });
+                    let missing_token = Token::missing(t, (&token).into());
+                    if !self.trim_parse_tree {
+                        tree_builder.add_missing_token(&missing_token)?;
+                    }
+                    self.parse_tree_stack.push(ParseTreeType::T(missing_token));
+                }
+                Some(ParseType::N(n)) => {
+                    if self
+                        .predict_production(n, stream.clone(), user_actions)
+                        .is_ok()
+                    {
+                        break;
+                    }
+                    self.parser_stack.stack.pop();
+                    self.parse_tree_stack
+                        .push(ParseTreeType::N(self.non_terminal_names[n]));
+                }
+                Some(ParseType::E(p)) => {
+                    if !self.productions[p].is_push_production {
+                        self.production_depth -= 1;
+                    }
+                    self.parser_stack.stack.pop();
+                    self.process_item_stack(tree_builder, p, user_actions)?;
+                }
+            }
+        }
+        self.add_recovery_edits(edits);
+        Ok(())
+    }
+
+    /// Attaches the edits applied during recovery to the syntax error they recovered from
+    fn add_recovery_edits(&mut self, edits: Vec<RecoveryEdit>) {
+        if let Some(error) = self.error_entries.last_mut() {
+            error.recovery_edits.extend(edits);
         }
     }
 
@@ -746,18 +1211,101 @@ impl<'t> LLKParser<'t> {
     where
         ParolError: From<T::Error>,
     {
-        stream
-            .borrow_mut()
-            .take_skip_tokens()
+        let skip_tokens = stream.borrow_mut().take_skip_tokens();
+        if !self.trim_parse_tree {
+            // The tokens skipped during error recovery are wrapped in an error node together
+            // with the skip tokens between them
+            let is_skipped = |t: &Token<'t>| t.is_skipped_by_recovery();
+            match skip_tokens
+                .iter()
+                .position(is_skipped)
+                .zip(skip_tokens.iter().rposition(is_skipped))
+            {
+                Some((first, last)) => {
+                    for t in &skip_tokens[..first] {
+                        tree_builder.add_token(t)?;
+                    }
+                    tree_builder.add_error(&skip_tokens[first..=last])?;
+                    for t in &skip_tokens[last + 1..] {
+                        tree_builder.add_token(t)?;
+                    }
+                }
+                None => {
+                    for t in &skip_tokens {
+                        tree_builder.add_token(t)?;
+                    }
+                }
+            }
+        }
+        skip_tokens
             .into_iter()
-            .try_for_each(|t| {
-                if !self.trim_parse_tree {
-                    tree_builder.add_token(&t)?;
+            .filter(|t| t.is_comment_token())
+            .for_each(|t| user_actions.on_comment(t));
+        Ok(())
+    }
+}
+
+///
+/// The iterator over the [ParseEvent]s of an LL(k) parser, see [LLKParser::events].
+///
+pub struct LLKParseEvents<'a, 't, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    parser: &'a mut LLKParser<'t>,
+    stream: Rc<RefCell<TokenStream<'t, F>>>,
+    user_actions: &'a mut dyn UserActionsTrait<'t>,
+    /// The events of the last parse step that are not consumed yet
+    queue: EventQueue<'t>,
+    /// The parser has stopped
+    finished: bool,
+    /// The error that is returned after the remaining events
+    error: Option<ParolError>,
+}
+
+impl<'t, F> Iterator for LLKParseEvents<'_, 't, F>
+where
+    F: Fn(char) -> Option<usize> + 'static + Clone,
+{
+    type Item = Result<ParseEvent<'t>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let started = self.parser.profile.is_some().then(Instant::now);
+        while self.queue.is_empty() && !self.finished {
+            let error_count = self.parser.error_entries.len();
+            let result = self.parser.parse_step(
+                &mut self.queue,
+                self.stream.clone(),
+                &mut *self.user_actions,
+            );
+            // The syntax errors precede the events of the recovery
+            for error in self.parser.error_entries[error_count..].iter().rev() {
+                self.queue
+                    .push_front(ParseEvent::Error((*error.error_location).clone()));
+            }
+            match result {
+                Ok(true) => (),
+                Ok(false) => {
+                    self.finished = true;
+                    self.error = self
+                        .parser
+                        .finish_parse(
+                            &mut self.queue,
+                            self.stream.clone(),
+                            &mut *self.user_actions,
+                        )
+                        .err();
                 }
-                if t.is_comment_token() {
-                    user_actions.on_comment(t);
+                Err(error) => {
+                    self.finished = true;
+                    self.error = Some(error);
                 }
-                Ok::<(), ParolError>(())
-            })
+            }
+        }
+        self.parser.collect_profile(&self.stream, started);
+        match self.queue.pop() {
+            Some(event) => Some(Ok(event)),
+            None => self.error.take().map(Err),
+        }
     }
 }
@@ -3,18 +3,180 @@ use std::collections::{BTreeMap, BTreeSet};
 use log::trace;
 use petgraph::{algo::all_simple_paths, prelude::DiGraph, visit::IntoNodeReferences};
 
-use crate::{TerminalIndex, Trans, lexer::EOI};
+use crate::{Location, NonTerminalIndex, TerminalIndex, Trans, lexer::EOI};
 
 use super::{CompiledProductionIndex, INVALID_PROD};
 
+/// The maximum number of syntax errors the parser collects by default before it stops parsing
+pub const DEFAULT_MAX_ERRORS: usize = 101;
+
+/// A single operation that transforms one token sequence into another
 #[derive(Debug, Clone, PartialEq)]
 pub enum EditOp {
+    /// The token is kept
     Keep,
+    /// A token is inserted
     Insert,
+    /// The token is deleted
     Delete,
+    /// The token is replaced by another one
     Replace,
 }
 
+/// An edit of the input that the parser applied to recover from a syntax error.
+/// Tooling can offer these edits as quick-fixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryEdit {
+    /// A token of the terminal is missing before the location
+    Insert {
+        /// The terminal index of the inserted token
+        terminal: TerminalIndex,
+        /// The name of the inserted terminal
+        terminal_name: String,
+        /// The location of the token before which the token was inserted
+        location: Location,
+    },
+    /// The token at the location is superfluous
+    Delete {
+        /// The terminal index of the deleted token
+        terminal: TerminalIndex,
+        /// The name of the deleted terminal
+        terminal_name: String,
+        /// The location of the deleted token
+        location: Location,
+    },
+    /// The token at the location is taken as another terminal
+    Replace {
+        /// The terminal index of the replaced token
+        terminal: TerminalIndex,
+        /// The terminal index the token was replaced with
+        replacement: TerminalIndex,
+        /// The name of the terminal the token was replaced with
+        replacement_name: String,
+        /// The location of the replaced token
+        location: Location,
+    },
+}
+
+impl RecoveryEdit {
+    /// The location in the input the edit applies to
+    pub fn location(&self) -> &Location {
+        match self {
+            RecoveryEdit::Insert { location, .. }
+            | RecoveryEdit::Delete { location, .. }
+            | RecoveryEdit::Replace { location, .. } => location,
+        }
+    }
+}
+
+impl std::fmt::Display for RecoveryEdit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecoveryEdit::Insert { terminal_name, .. } => write!(f, "Insert {terminal_name}"),
+            RecoveryEdit::Delete { terminal_name, .. } => write!(f, "Delete {terminal_name}"),
+            RecoveryEdit::Replace {
+                replacement_name, ..
+            } => write!(f, "Replace with {replacement_name}"),
+        }
+    }
+}
+
+/// The information a [RecoveryStrategy] decides on
+#[derive(Debug)]
+pub struct RecoveryContext<'a> {
+    /// The terminal indices of the tokens in the lookahead buffer
+    pub scanned_terminals: &'a [TerminalIndex],
+    /// The terminal strings the parser can continue with. On a token mismatch this is the single
+    /// sequence of terminals on top of the parse stack.
+    pub expected_terminal_strings: &'a BTreeSet<Vec<TerminalIndex>>,
+    /// The non-terminal that couldn't be predicted, or None on a token mismatch
+    pub non_terminal: Option<NonTerminalIndex>,
+    /// The names of the terminals, indexed by terminal index
+    pub terminal_names: &'static [&'static str],
+}
+
+/// The way the parser continues after a syntax error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecoveryAction {
+    /// Edit the tokens in the lookahead buffer with a minimal number of insertions, deletions and
+    /// replacements so that they match the given terminal string
+    Repair(Vec<TerminalIndex>),
+    /// Skip the input up to the next token of one of the given terminals and discard the pending
+    /// grammar symbols until the parser can continue with this token
+    Synchronize(Vec<TerminalIndex>),
+    /// Stop parsing and report the syntax errors collected so far
+    Abort,
+}
+
+///
+/// A strategy that decides how the LL(k) parser recovers from syntax errors.
+/// The parser applies the returned [RecoveryAction] and reports the resulting edits in the field
+/// `recovery_edits` of the [crate::SyntaxError].
+///
+pub trait RecoveryStrategy: std::fmt::Debug {
+    /// Called for each syntax error the parser detects
+    fn recover(&mut self, context: &RecoveryContext<'_>) -> RecoveryAction;
+}
+
+/// Repairs the lookahead tokens to the expected terminal string with the least Levenshtein
+/// distance. This is the default strategy.
+#[derive(Debug, Clone, Default)]
+pub struct TokenRepair;
+
+impl RecoveryStrategy for TokenRepair {
+    fn recover(&mut self, context: &RecoveryContext<'_>) -> RecoveryAction {
+        Recovery::minimal_token_difference(
+            context.scanned_terminals,
+            context.expected_terminal_strings,
+        )
+        .map_or(RecoveryAction::Abort, RecoveryAction::Repair)
+    }
+}
+
+/// Skips the input up to the next synchronization terminal, e.g. `;` or `}`, and continues parsing
+/// there
+#[derive(Debug, Clone, Default)]
+pub struct PanicMode {
+    sync_terminals: Vec<TerminalIndex>,
+}
+
+impl PanicMode {
+    /// Creates a panic mode strategy with the given synchronization terminals
+    pub fn new(sync_terminals: impl IntoIterator<Item = TerminalIndex>) -> Self {
+        Self {
+            sync_terminals: sync_terminals.into_iter().collect(),
+        }
+    }
+
+    /// Creates a panic mode strategy with synchronization terminals given by their names, e.g. the
+    /// generated `TERMINAL_NAMES`. Unknown names are ignored.
+    pub fn with_terminal_names(names: &[&str], terminal_names: &[&str]) -> Self {
+        Self::new(
+            terminal_names
+                .iter()
+                .enumerate()
+                .filter(|(_, n)| names.contains(n))
+                .map(|(i, _)| i as TerminalIndex),
+        )
+    }
+}
+
+impl RecoveryStrategy for PanicMode {
+    fn recover(&mut self, _context: &RecoveryContext<'_>) -> RecoveryAction {
+        RecoveryAction::Synchronize(self.sync_terminals.clone())
+    }
+}
+
+/// Stops parsing at the first syntax error
+#[derive(Debug, Clone, Default)]
+pub struct NoRecovery;
+
+impl RecoveryStrategy for NoRecovery {
+    fn recover(&mut self, _context: &RecoveryContext<'_>) -> RecoveryAction {
+        RecoveryAction::Abort
+    }
+}
+
 pub(crate) struct Recovery;
 
 impl Recovery {
@@ -125,7 +287,7 @@ impl Recovery {
     // with a maximum range.
     pub(crate) fn minimal_token_difference(
         scanned_token_types: &[TerminalIndex],
-        possible_terminal_strings: &mut BTreeSet<Vec<TerminalIndex>>,
+        possible_terminal_strings: &BTreeSet<Vec<TerminalIndex>>,
     ) -> Option<Vec<TerminalIndex>> {
         trace!("scanned_token_types: {scanned_token_types:?}");
         trace!("possible_terminal_strings: {possible_terminal_strings:?}");
@@ -1,4 +1,4 @@
-use crate::{ParseTreeType, Result, Token};
+use crate::{ParseTreeType, PredicateIndex, Result, Token};
 
 ///
 /// This trait is used as a coupling point between the generated parser and
@@ -29,4 +29,19 @@ pub trait UserActionsTrait<'t> {
     /// itself.
     ///
     fn on_comment(&mut self, _token: Token<'t>);
+
+    ///
+    /// This function is called when the parser predicts a production that is guarded by a
+    /// semantic predicate. `lookahead` is the first token of the production.
+    /// If the predicate fails, i.e. false is returned, the parser predicts one of the unguarded
+    /// alternatives instead.
+    /// The default implementation accepts all predicates.
+    ///
+    fn evaluate_predicate(
+        &mut self,
+        _predicate: PredicateIndex,
+        _lookahead: &Token<'t>,
+    ) -> Result<bool> {
+        Ok(true)
+    }
 }
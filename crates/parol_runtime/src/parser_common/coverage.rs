@@ -0,0 +1,92 @@
+use crate::{ProductionIndex, TerminalIndex};
+
+/// Counts how often a parser uses each production and matches each terminal.
+///
+/// The LL(k) parser counts a production when it pushes the production onto its parse stack, the
+/// LR parser when it reduces by the production. Terminals are counted when the parser consumes a
+/// token of the input. Tokens inserted during error recovery are not counted.
+///
+/// The counts accumulate over all inputs a parser processes after coverage has been enabled with
+/// [crate::LLKParser::enable_coverage] or [crate::LRParser::enable_coverage].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// Usage counts indexed by production index
+    productions: Vec<usize>,
+    /// Match counts indexed by terminal index
+    terminals: Vec<usize>,
+}
+
+impl Coverage {
+    /// Creates a coverage with zero counts for the given numbers of productions and terminals
+    pub fn new(production_count: usize, terminal_count: usize) -> Self {
+        Self {
+            productions: vec![0; production_count],
+            terminals: vec![0; terminal_count],
+        }
+    }
+
+    /// Returns the usage counts of all productions indexed by production index
+    pub fn production_counts(&self) -> &[usize] {
+        &self.productions
+    }
+
+    /// Returns the match counts of all terminals indexed by terminal index
+    pub fn terminal_counts(&self) -> &[usize] {
+        &self.terminals
+    }
+
+    /// Returns the indices of the productions that have never been used
+    pub fn uncovered_productions(&self) -> impl Iterator<Item = ProductionIndex> + '_ {
+        self.productions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, count)| (*count == 0).then_some(i))
+    }
+
+    /// Adds the counts of another coverage, e.g. of a parser that processed other inputs
+    pub fn merge(&mut self, other: &Coverage) {
+        fn add(counts: &mut Vec<usize>, other: &[usize]) {
+            if counts.len() < other.len() {
+                counts.resize(other.len(), 0);
+            }
+            counts.iter_mut().zip(other).for_each(|(c, o)| *c += o);
+        }
+        add(&mut self.productions, &other.productions);
+        add(&mut self.terminals, &other.terminals);
+    }
+
+    pub(crate) fn count_production(&mut self, prod_num: ProductionIndex) {
+        if let Some(count) = self.productions.get_mut(prod_num) {
+            *count += 1;
+        }
+    }
+
+    pub(crate) fn count_terminal(&mut self, terminal: TerminalIndex) {
+        if let Some(count) = self.terminals.get_mut(terminal as usize) {
+            *count += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_adds_counts() {
+        let mut coverage = Coverage::new(3, 2);
+        coverage.count_production(0);
+        coverage.count_production(0);
+        coverage.count_terminal(1);
+        let mut other = Coverage::new(3, 2);
+        other.count_production(2);
+        other.count_terminal(1);
+        coverage.merge(&other);
+        assert_eq!(&[2, 0, 1], coverage.production_counts());
+        assert_eq!(&[0, 2], coverage.terminal_counts());
+        assert_eq!(
+            vec![1],
+            coverage.uncovered_productions().collect::<Vec<_>>()
+        );
+    }
+}
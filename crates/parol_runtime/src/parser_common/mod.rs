@@ -1,2 +1,16 @@
+pub mod coverage;
+pub use coverage::Coverage;
+
 pub mod parse_tree_stack;
 pub use parse_tree_stack::ParseTreeStack;
+
+pub(crate) mod parse_control;
+pub(crate) use parse_control::ParseControl;
+
+pub mod profile;
+pub(crate) use profile::ScanProfile;
+pub use profile::{ActionProfile, DecisionProfile, ParseProfile};
+
+pub mod parse_event;
+pub(crate) use parse_event::EventQueue;
+pub use parse_event::ParseEvent;
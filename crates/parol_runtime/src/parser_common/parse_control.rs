@@ -0,0 +1,89 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use log::trace;
+
+use crate::ParserError;
+
+/// Stops a parser cooperatively when it is cancelled from another thread or when it has used up
+/// its budget of parse steps.
+///
+/// A parse step is one iteration of the parser's main loop, e.g. the prediction of a production,
+/// the consumption of a token or a reduction.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct ParseControl {
+    /// The parser stops as soon as this flag is set
+    cancellation_token: Option<Arc<AtomicBool>>,
+    /// The maximum number of parse steps
+    step_budget: Option<usize>,
+    /// The parse steps taken so far
+    steps: usize,
+}
+
+impl ParseControl {
+    /// Sets the flag that cancels the parser when it is set
+    pub(crate) fn set_cancellation_token(&mut self, cancellation_token: Arc<AtomicBool>) {
+        self.cancellation_token = Some(cancellation_token);
+    }
+
+    /// Sets the maximum number of parse steps
+    pub(crate) fn set_step_budget(&mut self, step_budget: usize) {
+        self.step_budget = Some(step_budget);
+    }
+
+    /// Starts counting the parse steps anew
+    pub(crate) fn reset(&mut self) {
+        self.steps = 0;
+    }
+
+    /// Accounts for the next parse step.
+    /// Fails if the parser has been cancelled or the budget is exhausted.
+    pub(crate) fn step(&mut self) -> Result<(), ParserError> {
+        if self
+            .cancellation_token
+            .as_ref()
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+        {
+            trace!("Parser cancelled after {} steps", self.steps);
+            return Err(ParserError::Cancelled);
+        }
+        self.steps += 1;
+        match self.step_budget {
+            Some(step_budget) if self.steps > step_budget => {
+                Err(ParserError::BudgetExceeded { steps: step_budget })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_fails_when_budget_is_exhausted() {
+        let mut control = ParseControl::default();
+        control.set_step_budget(2);
+        assert!(control.step().is_ok());
+        assert!(control.step().is_ok());
+        assert!(matches!(
+            control.step(),
+            Err(ParserError::BudgetExceeded { steps: 2 })
+        ));
+        control.reset();
+        assert!(control.step().is_ok());
+    }
+
+    #[test]
+    fn step_fails_when_cancelled() {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let mut control = ParseControl::default();
+        control.set_cancellation_token(cancelled.clone());
+        assert!(control.step().is_ok());
+        cancelled.store(true, Ordering::Relaxed);
+        assert!(matches!(control.step(), Err(ParserError::Cancelled)));
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::VecDeque;
+
+use crate::{Location, ParolError, Token, parser::parse_tree_type::TreeConstruct};
+
+/// An event of the pull parsers returned by [crate::LLKParser::events] and
+/// [crate::LRParser::events].
+///
+/// The events describe the parse tree in the order the parser recognizes it, thus a consumer can
+/// transform the input while it is parsed or stop parsing early.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseEvent<'t> {
+    /// The parser enters the non-terminal with the given name.
+    /// Only the LL(k) parser yields this event, because the LR parser recognizes a non-terminal
+    /// not before all of its symbols have been parsed.
+    Enter(&'static str),
+    /// A token of the input.
+    /// This includes skip tokens like comments and whitespace, tokens skipped during error
+    /// recovery (see [Token::is_skipped_by_recovery]) and tokens inserted during error recovery
+    /// (see [Token::is_missing]).
+    Token(Token<'t>),
+    /// The parser leaves the non-terminal with the given name.
+    Exit(&'static str),
+    /// The parser has recovered from a syntax error at the given location.
+    /// The details of all syntax errors are returned as [crate::ParserError::SyntaxErrors] after
+    /// the last event.
+    Error(Location),
+}
+
+/// The queue of the events the parser has produced but which aren't consumed yet.
+///
+/// It is also a tree builder that translates the nodes of the parse tree into events.
+#[derive(Debug, Default)]
+pub(crate) struct EventQueue<'t> {
+    events: VecDeque<ParseEvent<'t>>,
+    /// The names of the open non-terminals, the root node has an empty name
+    open_non_terminals: Vec<&'static str>,
+}
+
+impl<'t> EventQueue<'t> {
+    pub(crate) fn push(&mut self, event: ParseEvent<'t>) {
+        self.events.push_back(event);
+    }
+
+    /// Inserts the event before all events that are in the queue yet
+    pub(crate) fn push_front(&mut self, event: ParseEvent<'t>) {
+        self.events.push_front(event);
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<ParseEvent<'t>> {
+        self.events.pop_front()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl<'t> TreeConstruct<'t> for EventQueue<'t> {
+    type Error = ParolError;
+    type Tree = ();
+
+    fn open_non_terminal(
+        &mut self,
+        name: &'static str,
+        _size_hint: Option<usize>,
+    ) -> Result<(), Self::Error> {
+        self.open_non_terminals.push(name);
+        if !name.is_empty() {
+            self.push(ParseEvent::Enter(name));
+        }
+        Ok(())
+    }
+
+    fn close_non_terminal(&mut self) -> Result<(), Self::Error> {
+        match self.open_non_terminals.pop() {
+            Some("") => (),
+            Some(name) => self.push(ParseEvent::Exit(name)),
+            None => {
+                return Err(crate::ParserError::InternalError(
+                    "Closing a non-terminal that isn't open".to_owned(),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn add_token(&mut self, token: &Token<'t>) -> Result<(), Self::Error> {
+        self.push(ParseEvent::Token(token.clone()));
+        Ok(())
+    }
+
+    fn add_error(&mut self, skipped_tokens: &[Token<'t>]) -> Result<(), Self::Error> {
+        // The error itself has been reported with an Error event already
+        for token in skipped_tokens {
+            self.add_token(token)?;
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Result<Self::Tree, Self::Error> {
+        Ok(())
+    }
+}
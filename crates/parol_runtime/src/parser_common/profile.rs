@@ -0,0 +1,191 @@
+use crate::{NonTerminalIndex, ProductionIndex};
+use std::time::Duration;
+
+/// The lookahead decisions of the LL(k) parser for one non-terminal
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DecisionProfile {
+    /// The number of predicted productions
+    pub predictions: usize,
+    /// The sum of the lookahead depths of all predictions
+    pub total_lookahead: usize,
+    /// The maximum lookahead depth of a prediction
+    pub max_lookahead: usize,
+}
+
+impl DecisionProfile {
+    /// Returns the average number of lookahead tokens a prediction needed
+    pub fn average_lookahead(&self) -> f64 {
+        if self.predictions == 0 {
+            0.0
+        } else {
+            self.total_lookahead as f64 / self.predictions as f64
+        }
+    }
+}
+
+/// The semantic actions called for one production
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ActionProfile {
+    /// The number of calls of the semantic action
+    pub calls: usize,
+    /// The time spent in the semantic action
+    pub time: Duration,
+}
+
+/// Statistics of the scanner collected by the token stream while profiling
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub(crate) struct ScanProfile {
+    pub(crate) time: Duration,
+    pub(crate) tokens: usize,
+    pub(crate) scanner_switches: usize,
+}
+
+/// Measures where a parser spends its time.
+///
+/// For each non-terminal it counts the predictions of the LL(k) parser together with the depth of
+/// the lookahead the [crate::LookaheadDFA] needed. For each production it measures the calls of and
+/// the time spent in the semantic action. It also measures the time spent in the scanner and counts
+/// the scanned tokens and the switches between scanner states.
+///
+/// The measurements accumulate over all inputs a parser processes after profiling has been enabled
+/// with [crate::LLKParser::enable_profiling] or [crate::LRParser::enable_profiling].
+///
+/// With the feature `serde` the profile can be serialized, e.g. to JSON. Times are serialized as
+/// seconds and nanoseconds.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParseProfile {
+    non_terminal_names: &'static [&'static str],
+    /// The non-terminal of each production
+    production_lhs: Vec<NonTerminalIndex>,
+    decisions: Vec<DecisionProfile>,
+    actions: Vec<ActionProfile>,
+    parse_time: Duration,
+    scan: ScanProfile,
+}
+
+impl ParseProfile {
+    /// Creates an empty profile for the given non-terminals and the non-terminals of the
+    /// productions
+    pub fn new(
+        non_terminal_names: &'static [&'static str],
+        production_lhs: Vec<NonTerminalIndex>,
+    ) -> Self {
+        Self {
+            non_terminal_names,
+            decisions: vec![DecisionProfile::default(); non_terminal_names.len()],
+            actions: vec![ActionProfile::default(); production_lhs.len()],
+            production_lhs,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the lookahead decisions indexed by non-terminal index
+    pub fn decisions(&self) -> &[DecisionProfile] {
+        &self.decisions
+    }
+
+    /// Returns the semantic action calls indexed by production index
+    pub fn actions(&self) -> &[ActionProfile] {
+        &self.actions
+    }
+
+    /// Returns the total time spent parsing, including scanning and semantic actions
+    pub fn parse_time(&self) -> Duration {
+        self.parse_time
+    }
+
+    /// Returns the time spent in the scanner
+    pub fn scan_time(&self) -> Duration {
+        self.scan.time
+    }
+
+    /// Returns the time spent in all semantic actions
+    pub fn semantic_action_time(&self) -> Duration {
+        self.actions.iter().map(|a| a.time).sum()
+    }
+
+    /// Returns the number of tokens the scanner produced, including skip tokens
+    pub fn scanned_tokens(&self) -> usize {
+        self.scan.tokens
+    }
+
+    /// Returns how often the scanner switched to another scanner state
+    pub fn scanner_switches(&self) -> usize {
+        self.scan.scanner_switches
+    }
+
+    pub(crate) fn count_prediction(&mut self, non_terminal: NonTerminalIndex, lookahead: usize) {
+        if let Some(decision) = self.decisions.get_mut(non_terminal) {
+            decision.predictions += 1;
+            decision.total_lookahead += lookahead;
+            decision.max_lookahead = decision.max_lookahead.max(lookahead);
+        }
+    }
+
+    pub(crate) fn add_action(&mut self, prod_num: ProductionIndex, time: Duration) {
+        if let Some(action) = self.actions.get_mut(prod_num) {
+            action.calls += 1;
+            action.time += time;
+        }
+    }
+
+    pub(crate) fn add_parse_time(&mut self, time: Duration) {
+        self.parse_time += time;
+    }
+
+    pub(crate) fn add_scan(&mut self, scan: ScanProfile) {
+        self.scan.time += scan.time;
+        self.scan.tokens += scan.tokens;
+        self.scan.scanner_switches += scan.scanner_switches;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> ParseProfile {
+        let mut profile = ParseProfile::new(&["List", "Stmt"], vec![0, 0, 1]);
+        profile.count_prediction(1, 1);
+        profile.count_prediction(1, 3);
+        profile.add_action(2, Duration::from_nanos(40));
+        profile.add_scan(ScanProfile {
+            time: Duration::from_nanos(100),
+            tokens: 7,
+            scanner_switches: 2,
+        });
+        profile
+    }
+
+    #[test]
+    fn profile_accumulates_decisions_and_actions() {
+        let profile = profile();
+        assert_eq!(2.0, profile.decisions()[1].average_lookahead());
+        assert_eq!(3, profile.decisions()[1].max_lookahead);
+        assert_eq!(Duration::from_nanos(40), profile.semantic_action_time());
+        assert_eq!(Duration::from_nanos(100), profile.scan_time());
+        assert_eq!(2, profile.scanner_switches());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn profile_is_serialized() {
+        let json = serde_json::to_string(&profile()).unwrap();
+        assert!(
+            json.starts_with(r#"{"non_terminal_names":["List","Stmt"],"production_lhs":[0,0,1],"#)
+        );
+        assert!(json.contains(
+            r#""decisions":[{"predictions":0,"total_lookahead":0,"max_lookahead":0},{"predictions":2,"total_lookahead":4,"max_lookahead":3}]"#
+        ));
+        assert!(json.contains(r#"{"calls":1,"time":{"secs":0,"nanos":40}}"#));
+        assert!(
+            json.contains(
+                r#""scan":{"time":{"secs":0,"nanos":100},"tokens":7,"scanner_switches":2}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use parol_runtime::TokenStream;
+use parol_runtime::lexer::{ByteInput, TokenStreamOptions};
+use scnr2::scanner;
+
+scanner!(
+    ByteScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // token::NEW_LINE
+            token r"[\s--\r\n]+" => 2; // token::WHITESPACE
+            token r"\xFF\xFE" => 5; // Marker
+            token r"[a-z\xE0-\xFE]+" => 6; // Word
+            token r"." => 7; // token::ERROR_TOKEN
+        }
+    }
+);
+
+/// Returns the bytes, the token type and the byte offsets of all tokens
+fn tokens(bytes: &[u8]) -> Vec<(Vec<u8>, u16, usize, usize, u32)> {
+    let input = ByteInput::new(bytes);
+    let scanner = byte_scanner::ByteScanner::new();
+    let mut token_stream = TokenStream::new_with_options(
+        input.as_str(),
+        "bytes.bin",
+        scanner.scanner_impl.clone(),
+        &byte_scanner::ByteScanner::match_function,
+        1,
+        TokenStreamOptions {
+            byte_input: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let mut tokens = Vec::new();
+    while !token_stream.all_input_consumed() {
+        token_stream.take_skip_tokens();
+        let token = token_stream.consume().unwrap();
+        tokens.push((
+            token.bytes(&input).to_vec(),
+            token.token_type,
+            token.location.start(),
+            token.location.end(),
+            token.location.start_column,
+        ));
+    }
+    tokens
+}
+
+#[test]
+fn locations_report_byte_offsets() {
+    assert_eq!(
+        vec![
+            (b"ab\xE9".to_vec(), 6, 0, 3, 1),
+            (b"\xFF\xFE".to_vec(), 5, 4, 6, 5),
+            (b"\xE0".to_vec(), 6, 6, 7, 7),
+        ],
+        tokens(b"ab\xE9 \xFF\xFE\xE0")
+    );
+}
+
+#[test]
+fn token_bytes_are_taken_from_the_original_input() {
+    let input = ByteInput::new(b"\xE9t\xE9");
+    assert_eq!("\u{e9}t\u{e9}", input.as_str());
+    assert_eq!(
+        vec![(b"\xE9t\xE9".to_vec(), 6, 0, 3, 1)],
+        tokens(input.bytes())
+    );
+}
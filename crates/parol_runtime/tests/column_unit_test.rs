@@ -0,0 +1,79 @@
+use parol_runtime::TokenStream;
+use parol_runtime::lexer::{ColumnUnit, LineIndex, TokenStreamOptions};
+use scnr2::scanner;
+
+scanner!(
+    ColumnScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // token::NEW_LINE
+            token r"[\s--\r\n]+" => 2; // token::WHITESPACE
+            token r#""[^"]*""# => 5; // String
+            token r"\w+" => 6; // Identifier
+            token r"." => 7; // token::ERROR_TOKEN
+        }
+    }
+);
+
+/// The text and the start and end line and column of a token
+type TokenPosition = (String, (u32, u32), (u32, u32));
+
+const INPUT: &str = "s = \"\u{1F600}\u{e9}\" x\n\"\u{1F600}\nab\" y";
+
+/// Returns the text and the start and end positions of all tokens
+fn positions(column_unit: ColumnUnit) -> Vec<TokenPosition> {
+    let scanner = column_scanner::ColumnScanner::new();
+    let mut token_stream = TokenStream::new_with_options(
+        INPUT,
+        "columns.txt",
+        scanner.scanner_impl.clone(),
+        &column_scanner::ColumnScanner::match_function,
+        1,
+        TokenStreamOptions {
+            column_unit,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let mut positions = Vec::new();
+    while !token_stream.all_input_consumed() {
+        token_stream.take_skip_tokens();
+        let token = token_stream.consume().unwrap();
+        let location = &token.location;
+        positions.push((
+            token.text().to_string(),
+            (location.start_line, location.start_column),
+            (location.end_line, location.end_column),
+        ));
+    }
+    positions
+}
+
+#[test]
+fn columns_are_counted_in_the_selected_unit() {
+    let positions = positions(ColumnUnit::Utf16);
+    assert_eq!(
+        vec![
+            ("s".to_string(), (1, 1), (1, 2)),
+            ("=".to_string(), (1, 3), (1, 4)),
+            ("\"\u{1F600}\u{e9}\"".to_string(), (1, 5), (1, 10)),
+            ("x".to_string(), (1, 11), (1, 12)),
+            ("\"\u{1F600}\nab\"".to_string(), (2, 1), (3, 4)),
+            ("y".to_string(), (3, 5), (3, 6)),
+        ],
+        positions
+    );
+}
+
+#[test]
+fn columns_agree_with_line_index() {
+    let index = LineIndex::new(INPUT);
+    for column_unit in [ColumnUnit::Chars, ColumnUnit::Utf8Bytes, ColumnUnit::Utf16] {
+        let mut offset = 0;
+        for (text, start, end) in positions(column_unit) {
+            let start_offset = offset + INPUT[offset..].find(&text).unwrap();
+            offset = start_offset + text.len();
+            assert_eq!(start, index.position(INPUT, start_offset, column_unit));
+            assert_eq!(end, index.position(INPUT, offset, column_unit));
+        }
+    }
+}
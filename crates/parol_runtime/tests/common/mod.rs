@@ -0,0 +1,266 @@
+//! Grammars and helpers shared by the parser tests.
+//!
+//! Each test crate uses only some of the items.
+#![allow(dead_code)]
+
+use parol_runtime::{
+    LLKParser, Location, LookaheadDFA, ParseTreeType, ParseType, Production, Result, TerminalIndex,
+    Token, Trans, UserActionsTrait,
+    lr_parser::{LR1State, LRAction, LRParseTable, LRParser, LRProduction},
+};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub const WHITESPACE: TerminalIndex = 2;
+pub const NAME: TerminalIndex = 5;
+pub const SEMICOLON: TerminalIndex = 6;
+
+pub static TERMINAL_NAMES: &[&str] = &[
+    "EndOfInput",
+    "Newline",
+    "Whitespace",
+    "LineComment",
+    "BlockComment",
+    "Name",
+    "Semicolon",
+    "Error",
+];
+
+/// A list of items, each of them a name followed by a semicolon
+pub mod items {
+    use super::*;
+
+    // List: Item List;
+    // List: ;
+    // Item: Name Semicolon;
+    pub static NON_TERMINALS: &[&str] = &["Item", "List"];
+    pub static LOOKAHEAD_AUTOMATA: &[LookaheadDFA] = &[
+        LookaheadDFA {
+            prod0: 2,
+            transitions: &[],
+            k: 0,
+        },
+        LookaheadDFA {
+            prod0: -1,
+            transitions: &[Trans(0, 0, 1, 1), Trans(0, 5, 2, 0)],
+            k: 1,
+        },
+    ];
+    pub static PRODUCTIONS: &[Production] = &[
+        Production {
+            lhs: 1,
+            production: &[ParseType::N(1), ParseType::N(0)],
+            is_push_production: true,
+        },
+        Production {
+            lhs: 1,
+            production: &[],
+            is_push_production: false,
+        },
+        Production {
+            lhs: 0,
+            production: &[ParseType::T(SEMICOLON), ParseType::T(NAME)],
+            is_push_production: false,
+        },
+    ];
+
+    pub fn parser() -> LLKParser<'static> {
+        LLKParser::new(
+            1,
+            LOOKAHEAD_AUTOMATA,
+            PRODUCTIONS,
+            TERMINAL_NAMES,
+            NON_TERMINALS,
+        )
+    }
+
+    // The same language as LALR(1) grammar. The production of Item has the same number.
+    // List: Items;
+    // Items: Items Item;
+    // Item: Name Semicolon;
+    // Items: ;
+    pub static LR_NON_TERMINALS: &[&str] = &["Item", "Items", "List"];
+    pub static LR_PRODUCTIONS: &[LRProduction] = &[
+        LRProduction {
+            lhs: 2,
+            len: 1,
+            is_push_production: false,
+        },
+        LRProduction {
+            lhs: 1,
+            len: 2,
+            is_push_production: true,
+        },
+        LRProduction {
+            lhs: 0,
+            len: 2,
+            is_push_production: false,
+        },
+        LRProduction {
+            lhs: 1,
+            len: 0,
+            is_push_production: false,
+        },
+    ];
+    pub static LR_PARSE_TABLE: LRParseTable = LRParseTable {
+        actions: &[
+            /* 0 */ LRAction::Shift(2),
+            /* 1 */ LRAction::Shift(4),
+            /* 2 */ LRAction::Reduce(1 /* Items */, 3),
+            /* 3 */ LRAction::Reduce(1 /* Items */, 1),
+            /* 4 */ LRAction::Reduce(0 /* Item */, 2),
+            /* 5 */ LRAction::Accept,
+        ],
+        states: &[
+            // State 0
+            LR1State {
+                actions: &[(0, 2), (5, 2)],
+                gotos: &[(1, 1)],
+            },
+            // State 1
+            LR1State {
+                actions: &[(0, 5), (5, 0)],
+                gotos: &[(0, 3)],
+            },
+            // State 2
+            LR1State {
+                actions: &[(6, 1)],
+                gotos: &[],
+            },
+            // State 3
+            LR1State {
+                actions: &[(0, 3), (5, 3)],
+                gotos: &[],
+            },
+            // State 4
+            LR1State {
+                actions: &[(0, 4), (5, 4)],
+                gotos: &[],
+            },
+        ],
+    };
+
+    pub fn lr_parser() -> LRParser<'static> {
+        LRParser::new(
+            2,
+            &LR_PARSE_TABLE,
+            LR_PRODUCTIONS,
+            TERMINAL_NAMES,
+            LR_NON_TERMINALS,
+        )
+    }
+}
+
+/// A list of statements that need up to two tokens of lookahead
+pub mod statements {
+    use super::*;
+
+    // List: Stmt List;
+    // List: ;
+    // Stmt: Name Semicolon;
+    // Stmt: Name Name Semicolon;
+    // Stmt: Semicolon;
+    pub static NON_TERMINALS: &[&str] = &["List", "Stmt"];
+    pub static LOOKAHEAD_AUTOMATA: &[LookaheadDFA] = &[
+        LookaheadDFA {
+            prod0: -1,
+            transitions: &[Trans(0, 0, 1, 1), Trans(0, 5, 2, 0), Trans(0, 6, 3, 0)],
+            k: 1,
+        },
+        LookaheadDFA {
+            prod0: -1,
+            transitions: &[
+                Trans(0, 5, 1, -1),
+                Trans(0, 6, 2, 4),
+                Trans(1, 5, 3, 3),
+                Trans(1, 6, 4, 2),
+            ],
+            k: 2,
+        },
+    ];
+    pub static PRODUCTIONS: &[Production] = &[
+        Production {
+            lhs: 0,
+            production: &[ParseType::N(0), ParseType::N(1)],
+            is_push_production: true,
+        },
+        Production {
+            lhs: 0,
+            production: &[],
+            is_push_production: false,
+        },
+        Production {
+            lhs: 1,
+            production: &[ParseType::T(SEMICOLON), ParseType::T(NAME)],
+            is_push_production: false,
+        },
+        Production {
+            lhs: 1,
+            production: &[
+                ParseType::T(SEMICOLON),
+                ParseType::T(NAME),
+                ParseType::T(NAME),
+            ],
+            is_push_production: false,
+        },
+        Production {
+            lhs: 1,
+            production: &[ParseType::T(SEMICOLON)],
+            is_push_production: false,
+        },
+    ];
+
+    pub fn parser() -> LLKParser<'static> {
+        LLKParser::new(
+            0,
+            LOOKAHEAD_AUTOMATA,
+            PRODUCTIONS,
+            TERMINAL_NAMES,
+            NON_TERMINALS,
+        )
+    }
+}
+
+pub struct NoActions;
+
+impl<'t> UserActionsTrait<'t> for NoActions {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        _prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+/// Creates a token on the first line of the given file that starts at the given offset
+pub fn token(
+    text: &'static str,
+    token_type: TerminalIndex,
+    file_name: &str,
+    start: u32,
+) -> Token<'static> {
+    let location = Location {
+        start_line: 1,
+        start_column: start + 1,
+        end_line: 1,
+        end_column: start + 1 + text.len() as u32,
+        start: start as _,
+        end: (start as usize + text.len()) as _,
+        file_name: Arc::new(PathBuf::from(file_name)),
+    };
+    Token::with(text, token_type, location, 0)
+}
+
+/// Creates the tokens for the given text, names are single letters
+pub fn tokens(text: &'static str) -> Vec<Token<'static>> {
+    text.char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(i, c)| {
+            let token_type = if c == ';' { SEMICOLON } else { NAME };
+            token(&text[i..i + 1], token_type, "test.txt", i as u32)
+        })
+        .collect()
+}
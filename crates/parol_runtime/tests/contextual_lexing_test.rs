@@ -0,0 +1,80 @@
+use parol_runtime::lexer::TokenStreamOptions;
+use parol_runtime::{TerminalIndex, TokenStream};
+use scnr2::scanner;
+
+scanner!(
+    ContextualScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // token::NEW_LINE
+            token r"[\s--\r\n]+" => 2; // token::WHITESPACE
+            token r"where" => 5; // Where
+            token r"[a-zA-Z_]\w*" => 6; // Identifier
+            token r"." => 10; // token::ERROR_TOKEN
+        }
+        // The terminal modes the lexer generator adds for contextual lexing
+        mode __TERMINAL_5 {
+            token r"where" => 5;
+        }
+        mode __TERMINAL_6 {
+            token r"[a-zA-Z_]\w*" => 6;
+        }
+    }
+);
+
+const WHERE: TerminalIndex = 5;
+const IDENTIFIER: TerminalIndex = 6;
+
+/// Scans the input and restricts each token to the given set of terminals, if any.
+fn tokens(input: &str, acceptable: &[Option<&[TerminalIndex]>]) -> Vec<(TerminalIndex, String)> {
+    let scanner = contextual_scanner::ContextualScanner::new();
+    let mut token_stream = TokenStream::new_with_options(
+        input,
+        "contextual.txt",
+        scanner.scanner_impl.clone(),
+        &contextual_scanner::ContextualScanner::match_function,
+        1,
+        TokenStreamOptions {
+            contextual_lexing: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let mut tokens = Vec::new();
+    for terminals in acceptable {
+        if let Some(terminals) = terminals {
+            token_stream.restrict_lookahead(0, terminals.iter().copied());
+        }
+        token_stream.lookahead_token_type(0).unwrap();
+        token_stream.take_skip_tokens();
+        let token = token_stream.consume().unwrap();
+        tokens.push((token.token_type, token.text().to_string()));
+    }
+    tokens
+}
+
+#[test]
+fn keyword_is_scanned_as_identifier_where_only_identifiers_are_acceptable() {
+    assert_eq!(
+        vec![
+            (WHERE, "where".to_string()),
+            (IDENTIFIER, "where".to_string())
+        ],
+        tokens("where where", &[Some(&[WHERE]), Some(&[IDENTIFIER])])
+    );
+}
+
+#[test]
+fn unrestricted_scanning_prefers_the_keyword() {
+    assert_eq!(
+        vec![(WHERE, "where".to_string()), (WHERE, "where".to_string())],
+        tokens("where where", &[Some(&[WHERE, IDENTIFIER]), None])
+    );
+}
+
+#[test]
+fn unrestricted_match_is_used_if_no_acceptable_terminal_matches() {
+    assert_eq!(
+        vec![(IDENTIFIER, "foo".to_string())],
+        tokens("foo", &[Some(&[WHERE])])
+    );
+}
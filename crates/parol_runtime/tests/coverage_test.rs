@@ -0,0 +1,39 @@
+mod common;
+
+use common::{NAME, NoActions, SEMICOLON, statements::parser, tokens};
+
+#[test]
+fn coverage_is_not_collected_by_default() {
+    let mut parser = parser();
+    parser.parse_tokens(tokens("a;"), &mut NoActions).unwrap();
+    assert!(parser.coverage().is_none());
+}
+
+#[test]
+fn coverage_counts_productions_and_terminals() {
+    let mut parser = parser();
+    parser.enable_coverage();
+    parser
+        .parse_tokens(tokens("a; b;"), &mut NoActions)
+        .unwrap();
+    let coverage = parser.coverage().unwrap();
+    assert_eq!(&[2, 1, 2, 0, 0], coverage.production_counts());
+    assert_eq!(2, coverage.terminal_counts()[NAME as usize]);
+    assert_eq!(2, coverage.terminal_counts()[SEMICOLON as usize]);
+    assert_eq!(
+        vec![3, 4],
+        coverage.uncovered_productions().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn coverage_accumulates_over_inputs() {
+    let mut parser = parser();
+    parser.enable_coverage();
+    parser.parse_tokens(tokens("a;"), &mut NoActions).unwrap();
+    parser.parse_tokens(tokens(";"), &mut NoActions).unwrap();
+    parser.parse_tokens(tokens("a b;"), &mut NoActions).unwrap();
+    let coverage = parser.coverage().unwrap();
+    assert_eq!(&[3, 3, 1, 1, 1], coverage.production_counts());
+    assert_eq!(0, coverage.uncovered_productions().count());
+}
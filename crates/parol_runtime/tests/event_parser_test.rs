@@ -0,0 +1,129 @@
+mod common;
+
+use common::{SEMICOLON, items::parser, tokens};
+use parol_runtime::{
+    ParolError, ParseEvent, ParseTreeType, ParserError, Result, Token, TokenStream,
+    UserActionsTrait, parser::PanicMode,
+};
+
+/// Collects the numbers of the reduced productions
+#[derive(Default)]
+struct Items {
+    productions: Vec<usize>,
+}
+
+impl<'t> UserActionsTrait<'t> for Items {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        self.productions.push(prod_num);
+        Ok(())
+    }
+
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+/// Creates the token stream for the given text, names are single letters
+fn stream(text: &'static str) -> TokenStream<'static, fn(char) -> Option<usize>> {
+    TokenStream::from_tokens(tokens(text), 1).unwrap()
+}
+
+/// Renders the events in a compact form, tokens by their text
+fn render(events: &[ParseEvent]) -> Vec<String> {
+    events
+        .iter()
+        .map(|e| match e {
+            ParseEvent::Enter(n) => format!("<{n}>"),
+            ParseEvent::Token(t) if t.is_missing() => format!("missing {}", t.token_type),
+            ParseEvent::Token(t) if t.is_skipped_by_recovery() => format!("skipped {}", t.text()),
+            ParseEvent::Token(t) => t.text().to_string(),
+            ParseEvent::Exit(n) => format!("</{n}>"),
+            ParseEvent::Error(l) => format!("error at {}", l.start),
+        })
+        .collect()
+}
+
+#[test]
+fn events_describe_the_parse_tree() {
+    let mut parser = parser();
+    let mut items = Items::default();
+    let events = parser
+        .events(stream("a;"), &mut items)
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        vec![
+            "<List>", "<Item>", "a", ";", "</Item>", "<List>", "</List>", "</List>"
+        ],
+        render(&events)
+    );
+    assert_eq!(vec![2, 1, 0], items.productions);
+}
+
+#[test]
+fn dropping_the_events_stops_parsing() {
+    let mut parser = parser();
+    let mut items = Items::default();
+    let first_items = parser
+        .events(stream("a; b; c; d;"), &mut items)
+        .filter(|e| matches!(e, Ok(ParseEvent::Exit("Item"))))
+        .take(2)
+        .count();
+    assert_eq!(2, first_items);
+    // Only the first two items have been parsed
+    assert_eq!(vec![2, 2], items.productions);
+
+    // The parser can be used again afterwards
+    let mut items = Items::default();
+    assert!(parser.events(stream("a;"), &mut items).all(|e| e.is_ok()));
+    assert_eq!(vec![2, 1, 0], items.productions);
+}
+
+#[test]
+fn events_report_recovered_syntax_errors() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(PanicMode::new([SEMICOLON])));
+    let mut items = Items::default();
+    let mut events = parser.events(stream("a b; c;"), &mut items);
+    let recovered = events.by_ref().map_while(|e| e.ok()).collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            "<List>",
+            "<Item>",
+            "a",
+            "error at 2",
+            "skipped b",
+            ";",
+            "</Item>",
+            "<List>",
+            "<Item>",
+            "c",
+            ";",
+            "</Item>",
+            "<List>",
+            "</List>",
+            "</List>",
+            "</List>"
+        ],
+        render(&recovered)
+    );
+    // The details of the syntax errors have been returned after the last event
+    assert!(events.next().is_none());
+}
+
+#[test]
+fn events_end_with_the_syntax_errors() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(PanicMode::new([SEMICOLON])));
+    let mut items = Items::default();
+    let last = parser.events(stream("a;b"), &mut items).last();
+    match last {
+        Some(Err(ParolError::ParserError(ParserError::SyntaxErrors { entries }))) => {
+            assert_eq!(1, entries.len());
+            assert_eq!(3, entries[0].error_location.start);
+        }
+        event => panic!("Expected syntax errors, got {event:?}"),
+    }
+}
@@ -0,0 +1,137 @@
+use parol_runtime::lexer::{ExternalScanner, TokenStreamOptions};
+use parol_runtime::{LexerError, TerminalIndex, TokenStream};
+use scnr2::scanner;
+
+scanner!(
+    ExternalScannerTest {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // token::NEW_LINE
+            token r"[\s--\r\n]+" => 2; // token::WHITESPACE
+            token r"[a-zA-Z_]\w*" => 5; // Identifier
+            token r"." => 7; // token::ERROR_TOKEN
+        }
+    }
+);
+
+const RAW_STRING: TerminalIndex = 6;
+
+const EXTERNAL_TOKENS: &[&[TerminalIndex]] = &[&[RAW_STRING]];
+
+/// Scans raw strings like r#"..."# with any number of hashes
+#[derive(Default)]
+struct RawStringScanner {
+    calls: usize,
+}
+
+impl ExternalScanner for RawStringScanner {
+    fn scan(
+        &mut self,
+        terminal: TerminalIndex,
+        input: &str,
+        scanner_state: usize,
+    ) -> Option<usize> {
+        assert_eq!(RAW_STRING, terminal);
+        assert_eq!(0, scanner_state);
+        self.calls += 1;
+        let rest = input.strip_prefix('r')?;
+        let hashes = rest.len() - rest.trim_start_matches('#').len();
+        let rest = rest[hashes..].strip_prefix('"')?;
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        let end = rest.find(&terminator)?;
+        Some(1 + hashes + 1 + end + terminator.len())
+    }
+}
+
+/// An external scanner that returns a length beyond the end of the input
+struct BrokenScanner;
+
+impl ExternalScanner for BrokenScanner {
+    fn scan(
+        &mut self,
+        _terminal: TerminalIndex,
+        input: &str,
+        _scanner_state: usize,
+    ) -> Option<usize> {
+        input.starts_with('!').then_some(input.len() + 1)
+    }
+}
+
+fn tokens<'t>(
+    input: &'t str,
+    external_scanner: impl ExternalScanner + 't,
+) -> Result<Vec<(TerminalIndex, String)>, LexerError> {
+    let scanner = external_scanner_test::ExternalScannerTest::new();
+    let mut token_stream = TokenStream::new_with_external_scanner(
+        input,
+        "external.txt",
+        scanner.scanner_impl.clone(),
+        &external_scanner_test::ExternalScannerTest::match_function,
+        1,
+        TokenStreamOptions {
+            external_tokens_by_state: EXTERNAL_TOKENS,
+            ..Default::default()
+        },
+        Box::new(external_scanner),
+    )?;
+    let mut tokens = Vec::new();
+    while !token_stream.all_input_consumed() {
+        token_stream.take_skip_tokens();
+        let token = token_stream.consume()?;
+        tokens.push((token.token_type, token.text().to_string()));
+    }
+    Ok(tokens)
+}
+
+#[test]
+fn raw_strings_are_scanned_externally() {
+    let mut scanner = RawStringScanner::default();
+    let input = r####"a r##"b "# c"## rust"####;
+    assert_eq!(
+        vec![
+            (5, "a".to_string()),
+            (RAW_STRING, r###"r##"b "# c"##"###.to_string()),
+            (5, "rust".to_string()),
+        ],
+        tokens(input, &mut scanner).unwrap()
+    );
+    // Called at the start of each token including whitespace
+    assert_eq!(5, scanner.calls);
+}
+
+#[test]
+fn locations_of_external_tokens() {
+    let input = "x\n  r\"a\nb\" y";
+    let scanner = external_scanner_test::ExternalScannerTest::new();
+    let mut token_stream = TokenStream::new_with_external_scanner(
+        input,
+        "external.txt",
+        scanner.scanner_impl.clone(),
+        &external_scanner_test::ExternalScannerTest::match_function,
+        1,
+        TokenStreamOptions {
+            external_tokens_by_state: EXTERNAL_TOKENS,
+            ..Default::default()
+        },
+        Box::new(RawStringScanner::default()),
+    )
+    .unwrap();
+    let mut locations = Vec::new();
+    while !token_stream.all_input_consumed() {
+        token_stream.take_skip_tokens();
+        let token = token_stream.consume().unwrap();
+        let l = &token.location;
+        locations.push((l.start_line, l.start_column, l.end_line, l.end_column));
+    }
+    assert_eq!(vec![(1, 1, 1, 2), (2, 3, 3, 3), (3, 4, 3, 5)], locations);
+}
+
+#[test]
+fn invalid_match_length_is_an_error() {
+    let Err(LexerError::InvalidExternalMatch {
+        terminal, length, ..
+    }) = tokens("a !", BrokenScanner)
+    else {
+        panic!("InvalidExternalMatch expected");
+    };
+    assert_eq!((RAW_STRING, 2), (terminal, length));
+}
@@ -0,0 +1,93 @@
+use parol_runtime::lexer::{BLOCK_COMMENT, ColumnUnit, TokenStreamOptions};
+use parol_runtime::{LexerError, Token, TokenStream};
+use scnr2::scanner;
+
+scanner!(
+    NestedCommentScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // token::NEW_LINE
+            token r"[\s--\r\n]+" => 2; // token::WHITESPACE
+            token r"/\*" => 4; // token::BLOCK_COMMENT, start of a nested block comment
+            token r"[a-zA-Z_]\w*" => 5; // Identifier
+            token r"." => 6; // token::ERROR_TOKEN
+        }
+    }
+);
+
+const NESTED_BLOCK_COMMENTS: &[&[(&str, &str)]] = &[&[("/*", "*/")]];
+
+fn tokens(input: &str) -> Result<Vec<Token<'_>>, LexerError> {
+    tokens_with_column_unit(input, ColumnUnit::Chars)
+}
+
+fn tokens_with_column_unit(
+    input: &str,
+    column_unit: ColumnUnit,
+) -> Result<Vec<Token<'_>>, LexerError> {
+    let scanner = nested_comment_scanner::NestedCommentScanner::new();
+    let mut token_stream = TokenStream::new_with_options(
+        input,
+        "nested.txt",
+        scanner.scanner_impl.clone(),
+        &nested_comment_scanner::NestedCommentScanner::match_function,
+        1,
+        TokenStreamOptions {
+            nested_block_comments_by_state: NESTED_BLOCK_COMMENTS,
+            column_unit,
+            ..Default::default()
+        },
+    )?;
+    let mut tokens = Vec::new();
+    while !token_stream.all_input_consumed() {
+        tokens.extend(token_stream.take_skip_tokens());
+        tokens.push(token_stream.consume()?);
+    }
+    tokens.extend(token_stream.take_skip_tokens());
+    Ok(tokens)
+}
+
+#[test]
+fn nested_block_comment_is_one_token() {
+    let input = "a /* outer /* inner */ still\n comment */ b";
+    let tokens = tokens(input).unwrap();
+    let comments = tokens
+        .iter()
+        .filter(|t| t.token_type == BLOCK_COMMENT)
+        .collect::<Vec<_>>();
+    assert_eq!(1, comments.len());
+    assert_eq!(
+        "/* outer /* inner */ still\n comment */",
+        comments[0].text()
+    );
+    let location = &comments[0].location;
+    assert_eq!((1, 3), (location.start_line, location.start_column));
+    assert_eq!((2, 12), (location.end_line, location.end_column));
+    let identifiers = tokens
+        .iter()
+        .filter(|t| t.token_type == 5)
+        .map(|t| (t.text(), t.location.start_line, t.location.start_column))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![("a", 1, 1), ("b", 2, 13)], identifiers);
+}
+
+#[test]
+fn unterminated_nested_block_comment_is_an_error() {
+    let input = "a /* outer /* inner */ b";
+    assert!(matches!(
+        tokens(input),
+        Err(LexerError::UnterminatedBlockComment { .. })
+    ));
+}
+
+#[test]
+fn unterminated_nested_block_comment_reports_columns_in_the_selected_unit() {
+    // The emoji takes two UTF-16 code units
+    let input = "\u{1F600} /* outer";
+    let Err(LexerError::UnterminatedBlockComment { location }) =
+        tokens_with_column_unit(input, ColumnUnit::Utf16)
+    else {
+        panic!("expected an unterminated block comment");
+    };
+    assert_eq!((1, 4), (location.start_line, location.start_column));
+    assert_eq!((1, 6), (location.end_line, location.end_column));
+}
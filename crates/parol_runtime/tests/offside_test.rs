@@ -0,0 +1,95 @@
+use parol_runtime::lexer::{OffsideTokens, TokenStreamOptions};
+use parol_runtime::{LexerError, TerminalIndex, TokenStream};
+use scnr2::scanner;
+
+scanner!(
+    OffsideScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // token::NEW_LINE
+            token r"[\s--\r\n]+" => 2; // token::WHITESPACE
+            token r"#.*(\r\n|\r|\n)?" => 3; // token::LINE_COMMENT
+            token r":" => 5; // Colon
+            token r"[a-zA-Z_]\w*" => 6; // Identifier
+            token r"." => 10; // token::ERROR_TOKEN
+        }
+    }
+);
+
+const INDENT: TerminalIndex = 7;
+const DEDENT: TerminalIndex = 8;
+const NEWLINE: TerminalIndex = 9;
+
+const OFFSIDE_TOKENS: &[Option<OffsideTokens>] = &[Some(OffsideTokens {
+    indent: INDENT,
+    dedent: DEDENT,
+    newline: NEWLINE,
+})];
+
+fn token_names(input: &str) -> Result<Vec<String>, LexerError> {
+    let scanner = offside_scanner::OffsideScanner::new();
+    let mut token_stream = TokenStream::new_with_options(
+        input,
+        "offside.txt",
+        scanner.scanner_impl.clone(),
+        &offside_scanner::OffsideScanner::match_function,
+        1,
+        TokenStreamOptions {
+            offside_tokens_by_state: OFFSIDE_TOKENS,
+            ..Default::default()
+        },
+    )?;
+    let mut names = Vec::new();
+    while !token_stream.all_input_consumed() {
+        token_stream.take_skip_tokens();
+        let token = token_stream.consume()?;
+        names.push(match token.token_type {
+            INDENT => "INDENT".to_string(),
+            DEDENT => "DEDENT".to_string(),
+            NEWLINE => "NEWLINE".to_string(),
+            _ => token.text().to_string(),
+        });
+    }
+    Ok(names)
+}
+
+#[test]
+fn indentation_is_converted_into_tokens() {
+    let input = "a:\n  b:\n    c\n\n  # comment\n  d\ne\n";
+    assert_eq!(
+        vec![
+            "a", ":", "NEWLINE", "INDENT", "b", ":", "NEWLINE", "INDENT", "c", "NEWLINE", "DEDENT",
+            "d", "NEWLINE", "DEDENT", "e", "NEWLINE",
+        ],
+        token_names(input).unwrap()
+    );
+}
+
+#[test]
+fn open_blocks_are_closed_at_end_of_input() {
+    let input = "a:\n  b:\n    c";
+    assert_eq!(
+        vec![
+            "a", ":", "NEWLINE", "INDENT", "b", ":", "NEWLINE", "INDENT", "c", "NEWLINE", "DEDENT",
+            "DEDENT",
+        ],
+        token_names(input).unwrap()
+    );
+}
+
+#[test]
+fn dedent_to_unknown_level_is_an_error() {
+    let input = "a:\n    b\n  c\n";
+    let Err(LexerError::InconsistentIndentation { location }) = token_names(input) else {
+        panic!("Inconsistent indentation expected");
+    };
+    assert_eq!((3, 3), (location.start_line, location.start_column));
+}
+
+#[test]
+fn mixing_tabs_and_spaces_is_an_error() {
+    let input = "a:\n\tb\n    c\n";
+    assert!(matches!(
+        token_names(input),
+        Err(LexerError::InconsistentIndentation { .. })
+    ));
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::{NoActions, statements::parser, tokens};
+
+#[test]
+fn profile_is_not_collected_by_default() {
+    let mut parser = parser();
+    parser.parse_tokens(tokens("a;"), &mut NoActions).unwrap();
+    assert!(parser.profile().is_none());
+}
+
+#[test]
+fn profile_measures_lookahead_depth_per_non_terminal() {
+    let mut parser = parser();
+    parser.enable_profiling();
+    parser
+        .parse_tokens(tokens("a; a b; ;"), &mut NoActions)
+        .unwrap();
+    let profile = parser.profile().unwrap();
+    let list = profile.decisions()[0];
+    assert_eq!(4, list.predictions);
+    assert_eq!(1, list.max_lookahead);
+    let stmt = profile.decisions()[1];
+    assert_eq!(3, stmt.predictions);
+    assert_eq!(2, stmt.max_lookahead);
+    assert_eq!(5.0 / 3.0, stmt.average_lookahead());
+}
+
+#[test]
+fn profile_counts_semantic_actions_and_tokens() {
+    let mut parser = parser();
+    parser.enable_profiling();
+    parser.parse_tokens(tokens("a; ;"), &mut NoActions).unwrap();
+    parser.parse_tokens(tokens("a b;"), &mut NoActions).unwrap();
+    let profile = parser.profile().unwrap();
+    let calls = profile
+        .actions()
+        .iter()
+        .map(|a| a.calls)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![3, 2, 1, 1, 1], calls);
+    assert!(profile.scanned_tokens() >= 7);
+    assert_eq!(0, profile.scanner_switches());
+    assert!(profile.parse_time() >= profile.semantic_action_time());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn profile_is_exported_as_json() {
+    let mut parser = parser();
+    parser.enable_profiling();
+    parser.parse_tokens(tokens("a;"), &mut NoActions).unwrap();
+    let json = serde_json::to_string(parser.profile().unwrap()).unwrap();
+    assert!(json.starts_with('{') && json.ends_with('}'));
+    assert!(json.contains("\"non_terminal_names\":["));
+    assert!(json.contains("\"scan\":{\"time\":{\"secs\":"));
+    assert!(json.contains("{\"calls\":1,\"time\":"));
+}
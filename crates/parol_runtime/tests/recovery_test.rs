@@ -0,0 +1,198 @@
+mod common;
+
+use common::{NAME, NoActions, SEMICOLON, TERMINAL_NAMES, items::parser, tokens};
+use parol_runtime::{
+    LLKParser, ParolError, ParseTree, ParserError, SyntaxError, TerminalIndex, TokenStream,
+    parser::{
+        parse_tree_type::SynTree,
+        parser_types::SynTreeFlavor,
+        recovery::{
+            DEFAULT_MAX_ERRORS, NoRecovery, PanicMode, RecoveryAction, RecoveryContext,
+            RecoveryEdit, RecoveryStrategy,
+        },
+    },
+    syntree::Builder,
+};
+
+fn syntax_errors(parser: &mut LLKParser<'static>, text: &'static str) -> Vec<SyntaxError> {
+    match parser.parse_tokens(tokens(text), &mut NoActions) {
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { entries })) => entries,
+        result => panic!("Expected syntax errors, got {result:?}"),
+    }
+}
+
+/// Parses the erroneous text and returns the tree built despite the syntax errors
+fn tree_with_errors(parser: &mut LLKParser<'static>, text: &'static str) -> ParseTree {
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    let stream = TokenStream::from_tokens(tokens(text), 1).unwrap();
+    assert!(matches!(
+        parser.parse_into(&mut builder, stream, &mut NoActions),
+        Err(ParolError::ParserError(ParserError::SyntaxErrors { .. }))
+    ));
+    builder.build().unwrap()
+}
+
+/// Returns the token types of the children of the error nodes
+fn error_nodes(tree: &ParseTree) -> Vec<Vec<TerminalIndex>> {
+    tree.walk()
+        .filter(|n| matches!(n.value(), SynTree::Error))
+        .map(|n| {
+            n.children()
+                .map(|c| match c.value() {
+                    SynTree::Terminal(t) => t.token_type,
+                    v => panic!("Unexpected child {v} of error node"),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the token types of the missing nodes, which must span no text
+fn missing_nodes(tree: &ParseTree) -> Vec<TerminalIndex> {
+    tree.walk()
+        .filter_map(|n| match n.value() {
+            SynTree::Missing(t) => {
+                assert!(n.span().is_empty());
+                Some(t.token_type)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn token_repair_reports_its_edits() {
+    let errors = syntax_errors(&mut parser(), "a; b c");
+    assert_eq!(1, errors.len());
+    assert_eq!(
+        vec!["Replace with Semicolon".to_string()],
+        errors[0]
+            .recovery_edits
+            .iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(5, errors[0].recovery_edits[0].location().start);
+}
+
+#[test]
+fn panic_mode_skips_to_synchronization_terminal() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(PanicMode::new([SEMICOLON])));
+    let errors = syntax_errors(&mut parser, "a; b c d; e;");
+    assert_eq!(1, errors.len());
+    assert_eq!(
+        vec![NAME, NAME],
+        errors[0]
+            .recovery_edits
+            .iter()
+            .map(|e| match e {
+                RecoveryEdit::Delete { terminal, .. } => *terminal,
+                _ => panic!("Unexpected edit {e}"),
+            })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn panic_mode_inserts_missing_terminals_at_end_of_input() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(PanicMode::with_terminal_names(
+        &["Semicolon"],
+        TERMINAL_NAMES,
+    )));
+    let errors = syntax_errors(&mut parser, "a; b");
+    assert_eq!(1, errors.len());
+    assert!(matches!(
+        errors[0].recovery_edits[..],
+        [RecoveryEdit::Insert {
+            terminal: SEMICOLON,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn no_recovery_stops_at_first_error() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(NoRecovery));
+    let errors = syntax_errors(&mut parser, "a a; b b;");
+    assert_eq!(1, errors.len());
+    assert!(errors[0].recovery_edits.is_empty());
+
+    assert!(syntax_errors(&mut self::parser(), "a a; b b;").len() > 1);
+}
+
+#[test]
+fn error_limit_stops_parsing() {
+    let mut parser = parser();
+    parser.set_max_errors(1);
+    assert_eq!(1, syntax_errors(&mut parser, "a a; b b;").len());
+}
+
+#[test]
+fn default_error_limit_is_unchanged() {
+    let text: &'static str = "a a; ".repeat(200).leak();
+    let errors = syntax_errors(&mut parser(), text);
+    assert_eq!(101, errors.len());
+    assert_eq!(101, DEFAULT_MAX_ERRORS);
+}
+
+#[test]
+fn custom_strategy_receives_expected_terminals() {
+    // Repairs with the first expected terminal string and aborts at the second error
+    #[derive(Debug, Default)]
+    struct RepairOnce(bool);
+
+    impl RecoveryStrategy for RepairOnce {
+        fn recover(&mut self, context: &RecoveryContext<'_>) -> RecoveryAction {
+            if self.0 {
+                return RecoveryAction::Abort;
+            }
+            self.0 = true;
+            assert_eq!(None, context.non_terminal);
+            assert_eq!(&[NAME], context.scanned_terminals);
+            RecoveryAction::Repair(context.expected_terminal_strings.first().unwrap().clone())
+        }
+    }
+
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(RepairOnce::default()));
+    let errors = syntax_errors(&mut parser, "a a; b b;");
+    assert_eq!(2, errors.len());
+    assert!(matches!(
+        errors[0].recovery_edits[..],
+        [RecoveryEdit::Replace {
+            terminal: NAME,
+            replacement: SEMICOLON,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn skipped_tokens_are_wrapped_in_error_nodes() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(PanicMode::new([SEMICOLON])));
+    let tree = tree_with_errors(&mut parser, "a; b c d; e;");
+    assert_eq!(vec![vec![NAME, NAME]], error_nodes(&tree));
+    assert!(missing_nodes(&tree).is_empty());
+    // All scanned tokens are part of the tree
+    assert_eq!(8, tree.span().end);
+}
+
+#[test]
+fn inserted_tokens_become_missing_nodes() {
+    let mut parser = parser();
+    parser.set_recovery_strategy(Box::new(PanicMode::new([SEMICOLON])));
+    let tree = tree_with_errors(&mut parser, "a; b");
+    assert!(error_nodes(&tree).is_empty());
+    assert_eq!(vec![SEMICOLON], missing_nodes(&tree));
+}
+
+#[test]
+fn replaced_tokens_become_error_and_missing_nodes() {
+    let tree = tree_with_errors(&mut parser(), "a; b c");
+    assert_eq!(vec![vec![NAME]], error_nodes(&tree));
+    assert_eq!(vec![SEMICOLON], missing_nodes(&tree));
+}
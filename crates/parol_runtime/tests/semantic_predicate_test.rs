@@ -0,0 +1,192 @@
+mod common;
+
+use common::{NAME, SEMICOLON, TERMINAL_NAMES, statements::NON_TERMINALS, tokens};
+use parol_runtime::{
+    LLKParser, LookaheadDFA, ParseTreeType, ParseType, ParserError, PredicateIndex,
+    PredicatedDecision, Production, Result, Token, Trans, UserActionsTrait,
+};
+
+// List: Stmt List;
+// List: ;
+// Stmt: &{is_type_name} Name Semicolon;
+// Stmt: Name Semicolon;
+const DECLARATION: usize = 2;
+const EXPRESSION: usize = 3;
+
+static LOOKAHEAD_AUTOMATA: &[LookaheadDFA] = &[
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 1, 1), Trans(0, 5, 2, 0)],
+        k: 1,
+    },
+    // The predicated production wins the conflict
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 1, 2)],
+        k: 1,
+    },
+];
+static PREDICATED_DECISIONS: &[PredicatedDecision] = &[PredicatedDecision {
+    non_terminal: 1,
+    predicates: &[(DECLARATION, 0)],
+    fallback: LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 1, 3)],
+        k: 1,
+    },
+}];
+static PRODUCTIONS: &[Production] = &[
+    Production {
+        lhs: 0,
+        production: &[ParseType::N(0), ParseType::N(1)],
+        is_push_production: true,
+    },
+    Production {
+        lhs: 0,
+        production: &[],
+        is_push_production: false,
+    },
+    Production {
+        lhs: 1,
+        production: &[ParseType::T(SEMICOLON), ParseType::T(NAME)],
+        is_push_production: false,
+    },
+    Production {
+        lhs: 1,
+        production: &[ParseType::T(SEMICOLON), ParseType::T(NAME)],
+        is_push_production: false,
+    },
+];
+
+/// Records the statement productions and treats the given names as type names
+#[derive(Default)]
+struct Statements {
+    type_names: &'static str,
+    productions: Vec<usize>,
+    evaluations: usize,
+}
+
+impl<'t> UserActionsTrait<'t> for Statements {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        if prod_num == DECLARATION || prod_num == EXPRESSION {
+            self.productions.push(prod_num);
+        }
+        Ok(())
+    }
+
+    fn on_comment(&mut self, _token: Token<'t>) {}
+
+    fn evaluate_predicate(
+        &mut self,
+        predicate: PredicateIndex,
+        lookahead: &Token<'t>,
+    ) -> Result<bool> {
+        assert_eq!(0, predicate);
+        assert_eq!(NAME, lookahead.token_type);
+        self.evaluations += 1;
+        Ok(self.type_names.contains(lookahead.text()))
+    }
+}
+
+/// Accepts all predicates by the default implementation
+#[derive(Default)]
+struct AcceptAll {
+    productions: Vec<usize>,
+}
+
+impl<'t> UserActionsTrait<'t> for AcceptAll {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        _children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        if prod_num == DECLARATION || prod_num == EXPRESSION {
+            self.productions.push(prod_num);
+        }
+        Ok(())
+    }
+
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+fn parser() -> LLKParser<'static> {
+    let mut parser = LLKParser::new(
+        0,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    parser.set_predicated_decisions(PREDICATED_DECISIONS);
+    parser
+}
+
+#[test]
+fn failed_predicate_falls_back_to_unguarded_production() {
+    let mut statements = Statements {
+        type_names: "t",
+        ..Default::default()
+    };
+    parser()
+        .parse_tokens(tokens("t; a; t;"), &mut statements)
+        .unwrap();
+    assert_eq!(
+        vec![DECLARATION, EXPRESSION, DECLARATION],
+        statements.productions
+    );
+    assert_eq!(3, statements.evaluations);
+}
+
+#[test]
+fn predicates_are_accepted_by_default() {
+    let mut accept_all = AcceptAll::default();
+    parser()
+        .parse_tokens(tokens("t; a;"), &mut accept_all)
+        .unwrap();
+    assert_eq!(vec![DECLARATION, DECLARATION], accept_all.productions);
+}
+
+#[test]
+fn predicates_are_not_evaluated_without_predicated_decisions() {
+    let mut statements = Statements::default();
+    LLKParser::new(
+        0,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    )
+    .parse_tokens(tokens("a;"), &mut statements)
+    .unwrap();
+    assert_eq!(vec![DECLARATION], statements.productions);
+    assert_eq!(0, statements.evaluations);
+}
+
+#[test]
+fn predicate_errors_abort_parsing() {
+    struct Failing;
+    impl<'t> UserActionsTrait<'t> for Failing {
+        fn call_semantic_action_for_production_number(
+            &mut self,
+            _prod_num: usize,
+            _children: &[ParseTreeType<'t>],
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        fn on_comment(&mut self, _token: Token<'t>) {}
+
+        fn evaluate_predicate(
+            &mut self,
+            _predicate: PredicateIndex,
+            _lookahead: &Token<'t>,
+        ) -> Result<bool> {
+            Err(ParserError::InternalError("Predicate failed".to_string()).into())
+        }
+    }
+    assert!(parser().parse_tokens(tokens("a;"), &mut Failing).is_err());
+}
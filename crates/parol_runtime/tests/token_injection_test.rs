@@ -0,0 +1,97 @@
+mod common;
+
+use common::{NAME, SEMICOLON, WHITESPACE, items::lr_parser, items::parser, token};
+use parol_runtime::{ParseTreeType, Result, Token, UserActionsTrait};
+use std::path::PathBuf;
+
+/// Collects the names of the items together with the files they stem from
+#[derive(Default)]
+struct Items {
+    names: Vec<(String, PathBuf)>,
+}
+
+impl<'t> UserActionsTrait<'t> for Items {
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        if prod_num == 2 {
+            let name = children[0].token()?;
+            self.names.push((
+                name.text().to_string(),
+                name.location.file_name.to_path_buf(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+/// The tokens of an include file spliced into the tokens of the main file
+fn spliced_tokens() -> Vec<Token<'static>> {
+    vec![
+        token("a", NAME, "main.txt", 0),
+        token(";", SEMICOLON, "main.txt", 1),
+        token("b", NAME, "include.txt", 10),
+        token(" ", WHITESPACE, "include.txt", 11),
+        token(";", SEMICOLON, "include.txt", 12),
+        token("c", NAME, "main.txt", 2),
+        token(";", SEMICOLON, "main.txt", 3),
+    ]
+}
+
+fn spliced_names() -> Vec<(String, PathBuf)> {
+    vec![
+        ("a".to_string(), PathBuf::from("main.txt")),
+        ("b".to_string(), PathBuf::from("include.txt")),
+        ("c".to_string(), PathBuf::from("main.txt")),
+    ]
+}
+
+#[test]
+fn tokens_from_different_files_are_parsed() {
+    let mut items = Items::default();
+    parser().parse_tokens(spliced_tokens(), &mut items).unwrap();
+    assert_eq!(spliced_names(), items.names);
+}
+
+#[test]
+fn tokens_from_different_files_are_parsed_by_lr_parser() {
+    let mut items = Items::default();
+    lr_parser()
+        .parse_tokens(spliced_tokens(), &mut items)
+        .unwrap();
+    assert_eq!(spliced_names(), items.names);
+}
+
+#[test]
+fn syntax_errors_in_injected_tokens_are_reported() {
+    let tokens = vec![
+        token("a", NAME, "main.txt", 0),
+        token("b", NAME, "include.txt", 0),
+        token(";", SEMICOLON, "include.txt", 1),
+    ];
+    let mut items = Items::default();
+    assert!(parser().parse_tokens(tokens, &mut items).is_err());
+}
+
+#[test]
+fn syntax_errors_in_injected_tokens_are_reported_by_lr_parser() {
+    let tokens = vec![
+        token("a", NAME, "main.txt", 0),
+        token("b", NAME, "include.txt", 0),
+        token(";", SEMICOLON, "include.txt", 1),
+    ];
+    let mut items = Items::default();
+    assert!(lr_parser().parse_tokens(tokens, &mut items).is_err());
+}
+
+#[test]
+fn empty_token_sequence_is_accepted() {
+    let mut items = Items::default();
+    parser().parse_tokens(Vec::new(), &mut items).unwrap();
+    lr_parser().parse_tokens(Vec::new(), &mut items).unwrap();
+    assert!(items.names.is_empty());
+}
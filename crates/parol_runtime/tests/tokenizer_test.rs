@@ -0,0 +1,86 @@
+use parol_runtime::lexer::{TokenStreamOptions, Tokenizer};
+use scnr2::scanner;
+
+scanner!(
+    HighlightScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // NEW_LINE_TOKEN
+            token r"[\s--\r\n]+" => 2; // WHITESPACE_TOKEN
+            token r"//.*(\r\n|\r|\n)?" => 3; // LineComment
+            token r"[a-zA-Z_]\w*" => 5; // Identifier
+            token r"\u{22}" => 7; // StringDelimiter
+            token "." => 8; // ERROR_TOKEN
+            on 7 enter STRING;
+        }
+        mode STRING {
+            token r"[^\u{22}]+" => 6; // StringContent
+            token r"\u{22}" => 7; // StringDelimiter
+            token "." => 8; // ERROR_TOKEN
+            on 7 enter INITIAL;
+        }
+    }
+);
+
+const INPUT: &str = "a \"b c\" // d\ne";
+
+fn tokenizer(skip_tokens: bool) -> Tokenizer<'static, fn(char) -> Option<usize>> {
+    let scanner = highlight_scanner::HighlightScanner::new();
+    Tokenizer::new(
+        INPUT,
+        "highlight.txt",
+        scanner.scanner_impl.clone(),
+        &(highlight_scanner::HighlightScanner::match_function as fn(char) -> Option<usize>),
+        TokenStreamOptions::default(),
+    )
+    .unwrap()
+    .with_skip_tokens(skip_tokens)
+}
+
+#[test]
+fn tokenizer_yields_parser_tokens() {
+    let tokens = tokenizer(false)
+        .map(|token| token.map(|token| (token.token_type, token.text().to_string())))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(
+        vec![
+            (5, "a".to_string()),
+            (7, "\"".to_string()),
+            (6, "b c".to_string()),
+            (7, "\"".to_string()),
+            (5, "e".to_string()),
+        ],
+        tokens
+    );
+}
+
+#[test]
+fn tokenizer_with_skip_tokens_covers_the_input() {
+    let text = tokenizer(true)
+        .map(|token| token.unwrap().text().to_string())
+        .collect::<String>();
+    assert_eq!(INPUT, text);
+}
+
+#[test]
+fn tokenizer_tracks_the_scanner_state() {
+    let mut tokenizer = tokenizer(false);
+    assert_eq!(0, tokenizer.scanner_state());
+    let mut states = Vec::new();
+    while let Some(token) = tokenizer.next() {
+        states.push((
+            token.unwrap().text().to_string(),
+            tokenizer.scanner_state_name().to_string(),
+        ));
+    }
+    assert_eq!(
+        vec![
+            ("a".to_string(), "INITIAL".to_string()),
+            ("\"".to_string(), "STRING".to_string()),
+            ("b c".to_string(), "STRING".to_string()),
+            ("\"".to_string(), "INITIAL".to_string()),
+            ("e".to_string(), "INITIAL".to_string()),
+        ],
+        states
+    );
+}
@@ -0,0 +1,45 @@
+use crate::byte_input_grammar_trait::{ByteInputGrammarTrait, Config};
+use parol_runtime::Result;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+///
+/// Data structure that implements the semantic actions for our byte input grammar
+///
+#[derive(Debug, Default)]
+pub struct ByteInputGrammar<'t> {
+    pub config: Option<Config<'t>>,
+}
+
+impl ByteInputGrammar<'_> {
+    pub fn new() -> Self {
+        ByteInputGrammar::default()
+    }
+}
+
+impl Display for ByteInputGrammar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match &self.config {
+            Some(config) => config.config_list.iter().try_for_each(|e| {
+                // The text of the tokens is the Latin-1 decoded input
+                let value = &e.entry.value.value;
+                writeln!(
+                    f,
+                    "{} = {} at bytes {}..{}",
+                    e.entry.key.key.text(),
+                    value.text(),
+                    value.location.start(),
+                    value.location.end()
+                )
+            }),
+            None => write!(f, "No parse result"),
+        }
+    }
+}
+
+impl<'t> ByteInputGrammarTrait<'t> for ByteInputGrammar<'t> {
+    /// Semantic action for non-terminal 'Config'
+    fn config(&mut self, arg: &Config<'t>) -> Result<()> {
+        self.config = Some(arg.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{ParserError, Result, Token};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait ByteInputGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'Config'
+    fn config(&mut self, _arg: &Config<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Entry'
+    fn entry(&mut self, _arg: &Entry<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Key'
+    fn key(&mut self, _arg: &Key<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Value'
+    fn value(&mut self, _arg: &Value<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// This method provides skipped language comments.
+    /// If you need comments please provide your own implementation of this method.
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Config
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Config<'t> {
+    pub config_list: Vec<ConfigList<'t>>,
+}
+
+///
+/// Type derived for non-terminal ConfigList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ConfigList<'t> {
+    pub entry: Entry<'t>,
+}
+
+///
+/// Type derived for non-terminal Entry
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Entry<'t> {
+    pub key: Key<'t>,
+    pub value: Value<'t>,
+}
+
+///
+/// Type derived for non-terminal Key
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Key<'t> {
+    pub key: Token<'t>, /* [a-zA-Z_\xC0-\xFF][a-zA-Z0-9_\xC0-\xFF]* */
+}
+
+///
+/// Type derived for non-terminal Value
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Value<'t> {
+    pub value: Token<'t>, /* "[^"]*" */
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Config(Config<'t>),
+    ConfigList(Vec<ConfigList<'t>>),
+    Entry(Entry<'t>),
+    Key(Key<'t>),
+    Value(Value<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct ByteInputGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn ByteInputGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `ByteInputGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> ByteInputGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn ByteInputGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {context}: {item:?}");
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        let item = self.item_stack.pop();
+        if let Some(ref item) = item {
+            trace!("pop     {context}: {item:?}");
+        }
+        item
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {s:?}"))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// `Config: ConfigList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn config(&mut self, _config_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let config_list = pop_and_reverse_item!(self, config_list, ConfigList, context);
+        let config_built = Config { config_list };
+        // Calling user action here
+        self.user_grammar.config(&config_built)?;
+        self.push(ASTType::Config(config_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// `ConfigList /* Vec<T>::Push */: Entry ConfigList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn config_list_0(
+        &mut self,
+        _entry: &ParseTreeType<'t>,
+        _config_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut config_list = pop_item!(self, config_list, ConfigList, context);
+        let entry = pop_item!(self, entry, Entry, context);
+        let config_list_0_built = ConfigList { entry };
+        // Add an element to the vector
+        config_list.push(config_list_0_built);
+        self.push(ASTType::ConfigList(config_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// `ConfigList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn config_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let config_list_1_built = Vec::new();
+        self.push(ASTType::ConfigList(config_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Entry: Key '='^ /* Clipped */ Value ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry(
+        &mut self,
+        _key: &ParseTreeType<'t>,
+        _equ: &ParseTreeType<'t>,
+        _value: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let value = pop_item!(self, value, Value, context);
+        let key = pop_item!(self, key, Key, context);
+        let entry_built = Entry { key, value };
+        // Calling user action here
+        self.user_grammar.entry(&entry_built)?;
+        self.push(ASTType::Entry(entry_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `Key: /[a-zA-Z_\xC0-\xFF][a-zA-Z0-9_\xC0-\xFF]*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn key(&mut self, key: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let key = key.token()?.clone();
+        let key_built = Key { key };
+        // Calling user action here
+        self.user_grammar.key(&key_built)?;
+        self.push(ASTType::Key(key_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// `Value: /"[^"]*"/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value(&mut self, value: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let value = value.token()?.clone();
+        let value_built = Value { value };
+        // Calling user action here
+        self.user_grammar.value(&value_built)?;
+        self.push(ASTType::Value(value_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for ByteInputGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item ByteInputGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.config(&children[0]),
+            1 => self.config_list_0(&children[0], &children[1]),
+            2 => self.config_list_1(),
+            3 => self.entry(&children[0], &children[1], &children[2], &children[3]),
+            4 => self.key(&children[0]),
+            5 => self.value(&children[0]),
+            _ => Err(ParserError::InternalError(format!(
+                "Unhandled production number: {prod_num}"
+            ))
+            .into()),
+        }
+    }
+
+    fn on_comment(&mut self, token: Token<'t>) {
+        self.user_grammar.on_comment(token)
+    }
+}
@@ -0,0 +1,209 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{
+    ParolError, ParseTree, TokenStream,
+    parser::{
+        LLKParser, LookaheadDFA, ParseType, Production, Trans, parse_tree_type::TreeConstruct,
+    },
+};
+use scnr2::scanner;
+use std::path::Path;
+
+use crate::byte_input_grammar::ByteInputGrammar;
+use crate::byte_input_grammar_trait::ByteInputGrammarAuto;
+
+pub const TERMINAL_NAMES: &[&str; 10] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Equ",
+    /*  6 */ "Semicolon",
+    /*  7 */ "Key",
+    /*  8 */ "Value",
+    /*  9 */ "Error",
+];
+
+scanner! {
+    ByteInputGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"#.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"=" => 5; // "Equ"
+            token r";" => 6; // "Semicolon"
+            token r"[a-zA-Z_\xC0-\xFF][a-zA-Z0-9_\xC0-\xFF]*" => 7; // "Key"
+            token r#""[^"]*""# => 8; // "Value"
+            token r"." => 9; // "Error"
+        }
+    }
+}
+
+const MAX_K: usize = 1;
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+
+pub const NON_TERMINALS: &[&str; 5] = &[
+    /* 0 */ "Config",
+    /* 1 */ "ConfigList",
+    /* 2 */ "Entry",
+    /* 3 */ "Key",
+    /* 4 */ "Value",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 5] = &[
+    /* 0 - "Config" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "ConfigList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 7, 1, 1)],
+        k: 1,
+    },
+    /* 2 - "Entry" */
+    LookaheadDFA {
+        prod0: 3,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "Key" */
+    LookaheadDFA {
+        prod0: 4,
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "Value" */
+    LookaheadDFA {
+        prod0: 5,
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 6] = &[
+    // 0 - Config: ConfigList /* Vec */;
+    Production {
+        lhs: 0,
+        production: &[ParseType::N(1)],
+        is_push_production: false,
+    },
+    // 1 - ConfigList: Entry ConfigList;
+    Production {
+        lhs: 1,
+        production: &[ParseType::N(1), ParseType::N(2)],
+        is_push_production: true,
+    },
+    // 2 - ConfigList: ;
+    Production {
+        lhs: 1,
+        production: &[],
+        is_push_production: false,
+    },
+    // 3 - Entry: Key '='^ /* Clipped */ Value ';'^ /* Clipped */;
+    Production {
+        lhs: 2,
+        production: &[
+            ParseType::T(6),
+            ParseType::N(4),
+            ParseType::T(5),
+            ParseType::N(3),
+        ],
+        is_push_production: false,
+    },
+    // 4 - Key: /[a-zA-Z_\xC0-\xFF][a-zA-Z0-9_\xC0-\xFF]*/;
+    Production {
+        lhs: 3,
+        production: &[ParseType::T(7)],
+        is_push_production: false,
+    },
+    // 5 - Value: /"[^"]*"/;
+    Production {
+        lhs: 4,
+        production: &[ParseType::T(8)],
+        is_push_production: false,
+    },
+];
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut ByteInputGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    parse_into(input, &mut builder, file_name, user_actions)?;
+    Ok(builder.build()?)
+}
+#[allow(dead_code)]
+pub fn parse_into<'t, T: TreeConstruct<'t>>(
+    input: &'t str,
+    tree_builder: &mut T,
+    file_name: impl AsRef<Path>,
+    user_actions: &mut ByteInputGrammar<'t>,
+) -> Result<(), ParolError>
+where
+    ParolError: From<T::Error>,
+{
+    use byte_input_grammar_scanner::ByteInputGrammarScanner;
+    let mut llk_parser = LLKParser::new(
+        0,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let scanner = ByteInputGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = ByteInputGrammarAuto::new(user_actions);
+    llk_parser.parse_into(
+        tree_builder,
+        TokenStream::new_with_options(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &ByteInputGrammarScanner::match_function,
+            MAX_K,
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: &[],
+                contextual_lexing: false,
+                byte_input: true,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+        )?,
+        &mut user_actions,
+    )
+}
+
+///
+/// Parses the given bytes. Each byte is scanned as the character with the same code point and
+/// the locations of the tokens report byte offsets of the input.
+/// Use `Token::bytes` to obtain the bytes of a token.
+///
+#[allow(dead_code)]
+pub fn parse_bytes<'t, T>(
+    input: &'t parol_runtime::lexer::ByteInput<'_>,
+    file_name: T,
+    user_actions: &mut ByteInputGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    parse(input.as_str(), file_name, user_actions)
+}
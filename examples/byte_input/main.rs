@@ -0,0 +1,59 @@
+extern crate parol_runtime;
+
+mod byte_input_grammar;
+mod byte_input_grammar_trait;
+mod byte_input_parser;
+
+use crate::byte_input_grammar::ByteInputGrammar;
+use crate::byte_input_parser::parse_bytes;
+use anyhow::{Context, Result, anyhow};
+use parol_runtime::Report;
+use parol_runtime::lexer::ByteInput;
+use parol_runtime::log::debug;
+use std::env;
+use std::fs;
+
+// To generate:
+// parol -f ./examples/byte_input/byte_input.par -e ./examples/byte_input/byte_input-exp.par -p ./examples/byte_input/byte_input_parser.rs -a ./examples/byte_input/byte_input_grammar_trait.rs -t ByteInputGrammar -m byte_input_grammar -b --byte-input
+
+// To run the example
+// cargo run --example byte_input -- ./examples/byte_input/byte_input_test.txt
+
+struct ByteInputErrorReporter;
+impl Report for ByteInputErrorReporter {}
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        // The input is not valid UTF-8, so it is read as bytes
+        let bytes = fs::read(file_name.clone())
+            .with_context(|| format!("Can't read file {}", file_name))?;
+        let input = ByteInput::new(&bytes);
+        let mut byte_input_grammar = ByteInputGrammar::new();
+        match parse_bytes(&input, &file_name, &mut byte_input_grammar) {
+            Ok(_) => {
+                print!("{}", byte_input_grammar);
+                if let Some(config) = &byte_input_grammar.config {
+                    config.config_list.iter().for_each(|e| {
+                        println!(
+                            "{}: {:02X?}",
+                            e.entry.key.key.text(),
+                            e.entry.value.value.bytes(&input)
+                        )
+                    });
+                }
+                Ok(())
+            }
+            Err(e) => {
+                ByteInputErrorReporter::report_error(&e, file_name).unwrap_or(());
+                Err(anyhow!("Parsing failed!"))
+            }
+        }
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
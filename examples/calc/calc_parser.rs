@@ -551,6 +551,453 @@ pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 44] = &[
     },
 ];
 
+pub const LOOKAHEAD_AUTOMATA_INSTRUCTION: &[LookaheadDFA; 44] = &[
+    /* 0 - "AddOp" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 14, 1, 42), Trans(0, 15, 2, 43)],
+        k: 1,
+    },
+    /* 1 - "AssignItem" */
+    LookaheadDFA {
+        prod0: 17,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "AssignOp" */
+    LookaheadDFA {
+        prod0: 4,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "Assignment" */
+    LookaheadDFA {
+        prod0: 18,
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "AssignmentList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 15, 3, -1),
+            Trans(0, 18, 3, -1),
+            Trans(0, 20, 4, -1),
+            Trans(0, 21, 1, -1),
+            Trans(1, 0, 5, 20),
+            Trans(1, 5, 5, 20),
+            Trans(1, 6, 5, 20),
+            Trans(1, 7, 2, 19),
+            Trans(1, 8, 5, 20),
+            Trans(1, 9, 5, 20),
+            Trans(1, 10, 5, 20),
+            Trans(1, 11, 5, 20),
+            Trans(1, 12, 5, 20),
+            Trans(1, 13, 5, 20),
+            Trans(1, 14, 5, 20),
+            Trans(1, 15, 5, 20),
+            Trans(1, 16, 5, 20),
+            Trans(1, 17, 5, 20),
+            Trans(3, 15, 5, 20),
+            Trans(3, 18, 5, 20),
+            Trans(3, 20, 5, 20),
+            Trans(3, 21, 5, 20),
+            Trans(4, 0, 5, 20),
+            Trans(4, 5, 5, 20),
+            Trans(4, 6, 5, 20),
+            Trans(4, 8, 5, 20),
+            Trans(4, 9, 5, 20),
+            Trans(4, 10, 5, 20),
+            Trans(4, 11, 5, 20),
+            Trans(4, 12, 5, 20),
+            Trans(4, 13, 5, 20),
+            Trans(4, 14, 5, 20),
+            Trans(4, 15, 5, 20),
+            Trans(4, 16, 5, 20),
+            Trans(4, 17, 5, 20),
+        ],
+        k: 2,
+    },
+    /* 5 - "BitwiseAnd" */
+    LookaheadDFA {
+        prod0: 30,
+        transitions: &[],
+        k: 0,
+    },
+    /* 6 - "BitwiseAndList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 32),
+            Trans(0, 5, 2, 32),
+            Trans(0, 8, 2, 32),
+            Trans(0, 9, 2, 32),
+            Trans(0, 10, 2, 32),
+            Trans(0, 11, 1, 31),
+            Trans(0, 19, 2, 32),
+        ],
+        k: 1,
+    },
+    /* 7 - "BitwiseAndOp" */
+    LookaheadDFA {
+        prod0: 8,
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "BitwiseOr" */
+    LookaheadDFA {
+        prod0: 27,
+        transitions: &[],
+        k: 0,
+    },
+    /* 9 - "BitwiseOrList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 29),
+            Trans(0, 5, 2, 29),
+            Trans(0, 8, 2, 29),
+            Trans(0, 9, 2, 29),
+            Trans(0, 10, 1, 28),
+            Trans(0, 19, 2, 29),
+        ],
+        k: 1,
+    },
+    /* 10 - "BitwiseOrOp" */
+    LookaheadDFA {
+        prod0: 7,
+        transitions: &[],
+        k: 0,
+    },
+    /* 11 - "BitwiseShift" */
+    LookaheadDFA {
+        prod0: 39,
+        transitions: &[],
+        k: 0,
+    },
+    /* 12 - "BitwiseShiftList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 41),
+            Trans(0, 5, 2, 41),
+            Trans(0, 6, 2, 41),
+            Trans(0, 8, 2, 41),
+            Trans(0, 9, 2, 41),
+            Trans(0, 10, 2, 41),
+            Trans(0, 11, 2, 41),
+            Trans(0, 12, 1, 40),
+            Trans(0, 13, 2, 41),
+            Trans(0, 19, 2, 41),
+        ],
+        k: 1,
+    },
+    /* 13 - "BitwiseShiftOp" */
+    LookaheadDFA {
+        prod0: 9,
+        transitions: &[],
+        k: 0,
+    },
+    /* 14 - "Calc" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 15 - "CalcList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 15, 1, 1),
+            Trans(0, 18, 1, 1),
+            Trans(0, 20, 1, 1),
+            Trans(0, 21, 1, 1),
+        ],
+        k: 1,
+    },
+    /* 16 - "Equality" */
+    LookaheadDFA {
+        prod0: 33,
+        transitions: &[],
+        k: 0,
+    },
+    /* 17 - "EqualityList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 35),
+            Trans(0, 5, 2, 35),
+            Trans(0, 6, 1, 34),
+            Trans(0, 8, 2, 35),
+            Trans(0, 9, 2, 35),
+            Trans(0, 10, 2, 35),
+            Trans(0, 11, 2, 35),
+            Trans(0, 19, 2, 35),
+        ],
+        k: 1,
+    },
+    /* 18 - "EqualityOp" */
+    LookaheadDFA {
+        prod0: 3,
+        transitions: &[],
+        k: 0,
+    },
+    /* 19 - "Factor" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 15, 3, 56),
+            Trans(0, 18, 4, 57),
+            Trans(0, 20, 1, 54),
+            Trans(0, 21, 2, 55),
+        ],
+        k: 1,
+    },
+    /* 20 - "Id" */
+    LookaheadDFA {
+        prod0: 60,
+        transitions: &[],
+        k: 0,
+    },
+    /* 21 - "IdRef" */
+    LookaheadDFA {
+        prod0: 59,
+        transitions: &[],
+        k: 0,
+    },
+    /* 22 - "Instruction" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 15, 3, -1),
+            Trans(0, 18, 3, -1),
+            Trans(0, 20, 4, -1),
+            Trans(0, 21, 1, -1),
+            Trans(1, 0, 5, 16),
+            Trans(1, 5, 5, 16),
+            Trans(1, 6, 5, 16),
+            Trans(1, 7, 2, 15),
+            Trans(1, 8, 5, 16),
+            Trans(1, 9, 5, 16),
+            Trans(1, 10, 5, 16),
+            Trans(1, 11, 5, 16),
+            Trans(1, 12, 5, 16),
+            Trans(1, 13, 5, 16),
+            Trans(1, 14, 5, 16),
+            Trans(1, 15, 5, 16),
+            Trans(1, 16, 5, 16),
+            Trans(1, 17, 5, 16),
+            Trans(3, 15, 5, 16),
+            Trans(3, 18, 5, 16),
+            Trans(3, 20, 5, 16),
+            Trans(3, 21, 5, 16),
+            Trans(4, 0, 5, 16),
+            Trans(4, 5, 5, 16),
+            Trans(4, 6, 5, 16),
+            Trans(4, 8, 5, 16),
+            Trans(4, 9, 5, 16),
+            Trans(4, 10, 5, 16),
+            Trans(4, 11, 5, 16),
+            Trans(4, 12, 5, 16),
+            Trans(4, 13, 5, 16),
+            Trans(4, 14, 5, 16),
+            Trans(4, 15, 5, 16),
+            Trans(4, 16, 5, 16),
+            Trans(4, 17, 5, 16),
+        ],
+        k: 2,
+    },
+    /* 23 - "LogicalAnd" */
+    LookaheadDFA {
+        prod0: 24,
+        transitions: &[],
+        k: 0,
+    },
+    /* 24 - "LogicalAndList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 26),
+            Trans(0, 5, 2, 26),
+            Trans(0, 8, 2, 26),
+            Trans(0, 9, 1, 25),
+            Trans(0, 19, 2, 26),
+        ],
+        k: 1,
+    },
+    /* 25 - "LogicalAndOp" */
+    LookaheadDFA {
+        prod0: 6,
+        transitions: &[],
+        k: 0,
+    },
+    /* 26 - "LogicalOr" */
+    LookaheadDFA {
+        prod0: 21,
+        transitions: &[],
+        k: 0,
+    },
+    /* 27 - "LogicalOrList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 23),
+            Trans(0, 5, 2, 23),
+            Trans(0, 8, 1, 22),
+            Trans(0, 19, 2, 23),
+        ],
+        k: 1,
+    },
+    /* 28 - "LogicalOrOp" */
+    LookaheadDFA {
+        prod0: 5,
+        transitions: &[],
+        k: 0,
+    },
+    /* 29 - "Minus" */
+    LookaheadDFA {
+        prod0: 12,
+        transitions: &[],
+        k: 0,
+    },
+    /* 30 - "Mult" */
+    LookaheadDFA {
+        prod0: 47,
+        transitions: &[],
+        k: 0,
+    },
+    /* 31 - "MultList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 49),
+            Trans(0, 5, 2, 49),
+            Trans(0, 6, 2, 49),
+            Trans(0, 8, 2, 49),
+            Trans(0, 9, 2, 49),
+            Trans(0, 10, 2, 49),
+            Trans(0, 11, 2, 49),
+            Trans(0, 12, 2, 49),
+            Trans(0, 13, 2, 49),
+            Trans(0, 14, 2, 49),
+            Trans(0, 15, 2, 49),
+            Trans(0, 17, 1, 48),
+            Trans(0, 19, 2, 49),
+        ],
+        k: 1,
+    },
+    /* 32 - "MultOp" */
+    LookaheadDFA {
+        prod0: 14,
+        transitions: &[],
+        k: 0,
+    },
+    /* 33 - "Negate" */
+    LookaheadDFA {
+        prod0: 53,
+        transitions: &[],
+        k: 0,
+    },
+    /* 34 - "Number" */
+    LookaheadDFA {
+        prod0: 58,
+        transitions: &[],
+        k: 0,
+    },
+    /* 35 - "Plus" */
+    LookaheadDFA {
+        prod0: 11,
+        transitions: &[],
+        k: 0,
+    },
+    /* 36 - "PowOp" */
+    LookaheadDFA {
+        prod0: 13,
+        transitions: &[],
+        k: 0,
+    },
+    /* 37 - "Power" */
+    LookaheadDFA {
+        prod0: 50,
+        transitions: &[],
+        k: 0,
+    },
+    /* 38 - "PowerList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 52),
+            Trans(0, 5, 2, 52),
+            Trans(0, 6, 2, 52),
+            Trans(0, 8, 2, 52),
+            Trans(0, 9, 2, 52),
+            Trans(0, 10, 2, 52),
+            Trans(0, 11, 2, 52),
+            Trans(0, 12, 2, 52),
+            Trans(0, 13, 2, 52),
+            Trans(0, 14, 2, 52),
+            Trans(0, 15, 2, 52),
+            Trans(0, 16, 1, 51),
+            Trans(0, 17, 2, 52),
+            Trans(0, 19, 2, 52),
+        ],
+        k: 1,
+    },
+    /* 39 - "Relational" */
+    LookaheadDFA {
+        prod0: 36,
+        transitions: &[],
+        k: 0,
+    },
+    /* 40 - "RelationalList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 38),
+            Trans(0, 5, 2, 38),
+            Trans(0, 6, 2, 38),
+            Trans(0, 8, 2, 38),
+            Trans(0, 9, 2, 38),
+            Trans(0, 10, 2, 38),
+            Trans(0, 11, 2, 38),
+            Trans(0, 13, 1, 37),
+            Trans(0, 19, 2, 38),
+        ],
+        k: 1,
+    },
+    /* 41 - "RelationalOp" */
+    LookaheadDFA {
+        prod0: 10,
+        transitions: &[],
+        k: 0,
+    },
+    /* 42 - "Summ" */
+    LookaheadDFA {
+        prod0: 44,
+        transitions: &[],
+        k: 0,
+    },
+    /* 43 - "SummList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[
+            Trans(0, 0, 2, 46),
+            Trans(0, 5, 2, 46),
+            Trans(0, 6, 2, 46),
+            Trans(0, 8, 2, 46),
+            Trans(0, 9, 2, 46),
+            Trans(0, 10, 2, 46),
+            Trans(0, 11, 2, 46),
+            Trans(0, 12, 2, 46),
+            Trans(0, 13, 2, 46),
+            Trans(0, 14, 1, 45),
+            Trans(0, 15, 1, 45),
+            Trans(0, 19, 2, 46),
+        ],
+        k: 1,
+    },
+];
+
 pub const PRODUCTIONS: &[Production; 61] = &[
     // 0 - Calc: CalcList /* Vec */;
     Production {
@@ -972,3 +1419,49 @@ where
         &mut user_actions,
     )
 }
+
+///
+/// Parses the input as Instruction instead of the start symbol.
+/// The input must end after the Instruction.
+///
+#[allow(dead_code)]
+pub fn parse_instruction<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut CalcGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use calc_grammar_scanner::CalcGrammarScanner;
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut llk_parser = LLKParser::new(
+        22,
+        LOOKAHEAD_AUTOMATA_INSTRUCTION,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    llk_parser.trim_parse_tree();
+    let scanner = CalcGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = CalcGrammarAuto::new(user_actions);
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    llk_parser.parse_into(
+        &mut builder,
+        TokenStream::new_with_skip_tokens(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &CalcGrammarScanner::match_function,
+            MAX_K,
+            SKIP_TOKENS_BY_SCANNER_STATE,
+        )
+        .unwrap(),
+        &mut user_actions,
+    )?;
+    Ok(builder.build()?)
+}
@@ -56,3 +56,41 @@ fn test_parse_as() {
     let _syntax_tree = parse_into(&input, &mut builder, "test.parol", &mut calc_grammar).unwrap();
     println!("{}", calc_grammar);
 }
+
+#[test]
+fn test_parse_instruction() {
+    use crate::calc_parser::parse_instruction;
+    let mut calc_grammar = CalcGrammar::new();
+    assert!(parse_instruction("a = 1 + 2 * 3", "test.parol", &mut calc_grammar).is_ok());
+    let mut calc_grammar = CalcGrammar::new();
+    assert!(parse_instruction("1 + 2;", "test.parol", &mut calc_grammar).is_err());
+}
+
+#[test]
+fn test_parse_cancellable() {
+    use crate::calc_parser::parse_cancellable;
+    use parol_runtime::{ParolError, ParserError};
+    use std::sync::{Arc, atomic::AtomicBool};
+    let mut calc_grammar = CalcGrammar::new();
+    let cancellation_token = Arc::new(AtomicBool::new(false));
+    assert!(
+        parse_cancellable(
+            "1 + 2;",
+            "test.parol",
+            &mut calc_grammar,
+            cancellation_token
+        )
+        .is_ok()
+    );
+    let mut calc_grammar = CalcGrammar::new();
+    let cancellation_token = Arc::new(AtomicBool::new(true));
+    assert!(matches!(
+        parse_cancellable(
+            "1 + 2;",
+            "test.parol",
+            &mut calc_grammar,
+            cancellation_token
+        ),
+        Err(ParolError::ParserError(ParserError::Cancelled))
+    ));
+}
@@ -1320,6 +1320,1185 @@ static PARSE_TABLE: LRParseTable = LRParseTable {
     ],
 };
 
+static PARSE_TABLE_INSTRUCTION: LRParseTable = LRParseTable {
+    actions: &[
+        /* 0 */ LRAction::Shift(1),
+        /* 1 */ LRAction::Shift(2),
+        /* 2 */ LRAction::Shift(3),
+        /* 3 */ LRAction::Shift(4),
+        /* 4 */ LRAction::Shift(32),
+        /* 5 */ LRAction::Shift(40),
+        /* 6 */ LRAction::Shift(43),
+        /* 7 */ LRAction::Shift(45),
+        /* 8 */ LRAction::Shift(47),
+        /* 9 */ LRAction::Shift(49),
+        /* 10 */ LRAction::Shift(51),
+        /* 11 */ LRAction::Shift(53),
+        /* 12 */ LRAction::Shift(55),
+        /* 13 */ LRAction::Shift(59),
+        /* 14 */ LRAction::Shift(61),
+        /* 15 */ LRAction::Shift(63),
+        /* 16 */ LRAction::Reduce(0 /* AddOp */, 42),
+        /* 17 */ LRAction::Reduce(0 /* AddOp */, 43),
+        /* 18 */ LRAction::Reduce(1 /* AssignItem */, 17),
+        /* 19 */ LRAction::Reduce(2 /* AssignOp */, 4),
+        /* 20 */ LRAction::Reduce(3 /* Assignment */, 18),
+        /* 21 */ LRAction::Reduce(4 /* AssignmentList */, 19),
+        /* 22 */ LRAction::Reduce(4 /* AssignmentList */, 20),
+        /* 23 */ LRAction::Reduce(5 /* BitwiseAnd */, 30),
+        /* 24 */ LRAction::Reduce(6 /* BitwiseAndList */, 31),
+        /* 25 */ LRAction::Reduce(6 /* BitwiseAndList */, 32),
+        /* 26 */ LRAction::Reduce(7 /* BitwiseAndOp */, 8),
+        /* 27 */ LRAction::Reduce(8 /* BitwiseOr */, 27),
+        /* 28 */ LRAction::Reduce(9 /* BitwiseOrList */, 28),
+        /* 29 */ LRAction::Reduce(9 /* BitwiseOrList */, 29),
+        /* 30 */ LRAction::Reduce(10 /* BitwiseOrOp */, 7),
+        /* 31 */ LRAction::Reduce(11 /* BitwiseShift */, 39),
+        /* 32 */ LRAction::Reduce(12 /* BitwiseShiftList */, 40),
+        /* 33 */ LRAction::Reduce(12 /* BitwiseShiftList */, 41),
+        /* 34 */ LRAction::Reduce(13 /* BitwiseShiftOp */, 9),
+        /* 35 */ LRAction::Reduce(16 /* Equality */, 33),
+        /* 36 */ LRAction::Reduce(17 /* EqualityList */, 34),
+        /* 37 */ LRAction::Reduce(17 /* EqualityList */, 35),
+        /* 38 */ LRAction::Reduce(18 /* EqualityOp */, 3),
+        /* 39 */ LRAction::Reduce(19 /* Factor */, 54),
+        /* 40 */ LRAction::Reduce(19 /* Factor */, 55),
+        /* 41 */ LRAction::Reduce(19 /* Factor */, 56),
+        /* 42 */ LRAction::Reduce(19 /* Factor */, 57),
+        /* 43 */ LRAction::Reduce(20 /* Id */, 60),
+        /* 44 */ LRAction::Reduce(21 /* IdRef */, 59),
+        /* 45 */ LRAction::Reduce(22 /* Instruction */, 15),
+        /* 46 */ LRAction::Reduce(22 /* Instruction */, 16),
+        /* 47 */ LRAction::Reduce(23 /* LogicalAnd */, 24),
+        /* 48 */ LRAction::Reduce(24 /* LogicalAndList */, 25),
+        /* 49 */ LRAction::Reduce(24 /* LogicalAndList */, 26),
+        /* 50 */ LRAction::Reduce(25 /* LogicalAndOp */, 6),
+        /* 51 */ LRAction::Reduce(26 /* LogicalOr */, 21),
+        /* 52 */ LRAction::Reduce(27 /* LogicalOrList */, 22),
+        /* 53 */ LRAction::Reduce(27 /* LogicalOrList */, 23),
+        /* 54 */ LRAction::Reduce(28 /* LogicalOrOp */, 5),
+        /* 55 */ LRAction::Reduce(29 /* Minus */, 12),
+        /* 56 */ LRAction::Reduce(30 /* Mult */, 47),
+        /* 57 */ LRAction::Reduce(31 /* MultList */, 48),
+        /* 58 */ LRAction::Reduce(31 /* MultList */, 49),
+        /* 59 */ LRAction::Reduce(32 /* MultOp */, 14),
+        /* 60 */ LRAction::Reduce(33 /* Negate */, 53),
+        /* 61 */ LRAction::Reduce(34 /* Number */, 58),
+        /* 62 */ LRAction::Reduce(35 /* Plus */, 11),
+        /* 63 */ LRAction::Reduce(36 /* PowOp */, 13),
+        /* 64 */ LRAction::Reduce(37 /* Power */, 50),
+        /* 65 */ LRAction::Reduce(38 /* PowerList */, 51),
+        /* 66 */ LRAction::Reduce(38 /* PowerList */, 52),
+        /* 67 */ LRAction::Reduce(39 /* Relational */, 36),
+        /* 68 */ LRAction::Reduce(40 /* RelationalList */, 37),
+        /* 69 */ LRAction::Reduce(40 /* RelationalList */, 38),
+        /* 70 */ LRAction::Reduce(41 /* RelationalOp */, 10),
+        /* 71 */ LRAction::Reduce(42 /* Summ */, 44),
+        /* 72 */ LRAction::Reduce(43 /* SummList */, 45),
+        /* 73 */ LRAction::Reduce(43 /* SummList */, 46),
+        /* 74 */ LRAction::Accept,
+    ],
+    states: &[
+        // State 0
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (1, 5),   /* AssignItem => 5 */
+                (3, 6),   /* Assignment => 6 */
+                (5, 7),   /* BitwiseAnd => 7 */
+                (8, 8),   /* BitwiseOr => 8 */
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 10), /* Equality => 10 */
+                (19, 11), /* Factor => 11 */
+                (20, 12), /* Id => 12 */
+                (21, 13), /* IdRef => 13 */
+                (22, 14), /* Instruction => 14 */
+                (23, 15), /* LogicalAnd => 15 */
+                (26, 16), /* LogicalOr => 16 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 1
+        LR1State {
+            actions: &[
+                (15, 55), /* '-' => LRAction::Reduce(Minus, 12) */
+                (18, 55), /* '(' => LRAction::Reduce(Minus, 12) */
+                (20, 55), /* '0|[1-9][0-9]*' => LRAction::Reduce(Minus, 12) */
+                (21, 55), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(Minus, 12) */
+            ],
+            gotos: &[],
+        },
+        // State 2
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (5, 7),   /* BitwiseAnd => 7 */
+                (8, 8),   /* BitwiseOr => 8 */
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 10), /* Equality => 10 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (23, 15), /* LogicalAnd => 15 */
+                (26, 25), /* LogicalOr => 25 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 3
+        LR1State {
+            actions: &[
+                (0, 61),  /* '<$>' => LRAction::Reduce(Number, 58) */
+                (6, 61),  /* '==|!=' => LRAction::Reduce(Number, 58) */
+                (8, 61),  /* '||' => LRAction::Reduce(Number, 58) */
+                (9, 61),  /* '&&' => LRAction::Reduce(Number, 58) */
+                (10, 61), /* '|' => LRAction::Reduce(Number, 58) */
+                (11, 61), /* '&' => LRAction::Reduce(Number, 58) */
+                (12, 61), /* '<<|>>' => LRAction::Reduce(Number, 58) */
+                (13, 61), /* '<=|<|>=|>' => LRAction::Reduce(Number, 58) */
+                (14, 61), /* '+' => LRAction::Reduce(Number, 58) */
+                (15, 61), /* '-' => LRAction::Reduce(Number, 58) */
+                (16, 61), /* '**' => LRAction::Reduce(Number, 58) */
+                (17, 61), /* '\*|\/|%' => LRAction::Reduce(Number, 58) */
+                (19, 61), /* ')' => LRAction::Reduce(Number, 58) */
+            ],
+            gotos: &[],
+        },
+        // State 4
+        LR1State {
+            actions: &[
+                (0, 43),  /* '<$>' => LRAction::Reduce(Id, 60) */
+                (6, 43),  /* '==|!=' => LRAction::Reduce(Id, 60) */
+                (7, 43),  /* '(\+|-|\*|\/|%|<<|>>|&|\^|\|)?=' => LRAction::Reduce(Id, 60) */
+                (8, 43),  /* '||' => LRAction::Reduce(Id, 60) */
+                (9, 43),  /* '&&' => LRAction::Reduce(Id, 60) */
+                (10, 43), /* '|' => LRAction::Reduce(Id, 60) */
+                (11, 43), /* '&' => LRAction::Reduce(Id, 60) */
+                (12, 43), /* '<<|>>' => LRAction::Reduce(Id, 60) */
+                (13, 43), /* '<=|<|>=|>' => LRAction::Reduce(Id, 60) */
+                (14, 43), /* '+' => LRAction::Reduce(Id, 60) */
+                (15, 43), /* '-' => LRAction::Reduce(Id, 60) */
+                (16, 43), /* '**' => LRAction::Reduce(Id, 60) */
+                (17, 43), /* '\*|\/|%' => LRAction::Reduce(Id, 60) */
+                (19, 43), /* ')' => LRAction::Reduce(Id, 60) */
+            ],
+            gotos: &[],
+        },
+        // State 5
+        LR1State {
+            actions: &[
+                (15, 22), /* '-' => LRAction::Reduce(AssignmentList, 20) */
+                (18, 22), /* '(' => LRAction::Reduce(AssignmentList, 20) */
+                (20, 22), /* '0|[1-9][0-9]*' => LRAction::Reduce(AssignmentList, 20) */
+                (21, 22), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(AssignmentList, 20) */
+            ],
+            gotos: &[(4, 26) /* AssignmentList => 26 */],
+        },
+        // State 6
+        LR1State {
+            actions: &[
+                (0, 45), /* '<$>' => LRAction::Reduce(Instruction, 15) */
+            ],
+            gotos: &[],
+        },
+        // State 7
+        LR1State {
+            actions: &[
+                (0, 29),  /* '<$>' => LRAction::Reduce(BitwiseOrList, 29) */
+                (8, 29),  /* '||' => LRAction::Reduce(BitwiseOrList, 29) */
+                (9, 29),  /* '&&' => LRAction::Reduce(BitwiseOrList, 29) */
+                (10, 29), /* '|' => LRAction::Reduce(BitwiseOrList, 29) */
+                (19, 29), /* ')' => LRAction::Reduce(BitwiseOrList, 29) */
+            ],
+            gotos: &[(9, 27) /* BitwiseOrList => 27 */],
+        },
+        // State 8
+        LR1State {
+            actions: &[
+                (0, 49),  /* '<$>' => LRAction::Reduce(LogicalAndList, 26) */
+                (8, 49),  /* '||' => LRAction::Reduce(LogicalAndList, 26) */
+                (9, 49),  /* '&&' => LRAction::Reduce(LogicalAndList, 26) */
+                (19, 49), /* ')' => LRAction::Reduce(LogicalAndList, 26) */
+            ],
+            gotos: &[(24, 28) /* LogicalAndList => 28 */],
+        },
+        // State 9
+        LR1State {
+            actions: &[
+                (0, 69),  /* '<$>' => LRAction::Reduce(RelationalList, 38) */
+                (6, 69),  /* '==|!=' => LRAction::Reduce(RelationalList, 38) */
+                (8, 69),  /* '||' => LRAction::Reduce(RelationalList, 38) */
+                (9, 69),  /* '&&' => LRAction::Reduce(RelationalList, 38) */
+                (10, 69), /* '|' => LRAction::Reduce(RelationalList, 38) */
+                (11, 69), /* '&' => LRAction::Reduce(RelationalList, 38) */
+                (13, 69), /* '<=|<|>=|>' => LRAction::Reduce(RelationalList, 38) */
+                (19, 69), /* ')' => LRAction::Reduce(RelationalList, 38) */
+            ],
+            gotos: &[(40, 29) /* RelationalList => 29 */],
+        },
+        // State 10
+        LR1State {
+            actions: &[
+                (0, 25),  /* '<$>' => LRAction::Reduce(BitwiseAndList, 32) */
+                (8, 25),  /* '||' => LRAction::Reduce(BitwiseAndList, 32) */
+                (9, 25),  /* '&&' => LRAction::Reduce(BitwiseAndList, 32) */
+                (10, 25), /* '|' => LRAction::Reduce(BitwiseAndList, 32) */
+                (11, 25), /* '&' => LRAction::Reduce(BitwiseAndList, 32) */
+                (19, 25), /* ')' => LRAction::Reduce(BitwiseAndList, 32) */
+            ],
+            gotos: &[(6, 30) /* BitwiseAndList => 30 */],
+        },
+        // State 11
+        LR1State {
+            actions: &[
+                (0, 66),  /* '<$>' => LRAction::Reduce(PowerList, 52) */
+                (6, 66),  /* '==|!=' => LRAction::Reduce(PowerList, 52) */
+                (8, 66),  /* '||' => LRAction::Reduce(PowerList, 52) */
+                (9, 66),  /* '&&' => LRAction::Reduce(PowerList, 52) */
+                (10, 66), /* '|' => LRAction::Reduce(PowerList, 52) */
+                (11, 66), /* '&' => LRAction::Reduce(PowerList, 52) */
+                (12, 66), /* '<<|>>' => LRAction::Reduce(PowerList, 52) */
+                (13, 66), /* '<=|<|>=|>' => LRAction::Reduce(PowerList, 52) */
+                (14, 66), /* '+' => LRAction::Reduce(PowerList, 52) */
+                (15, 66), /* '-' => LRAction::Reduce(PowerList, 52) */
+                (16, 66), /* '**' => LRAction::Reduce(PowerList, 52) */
+                (17, 66), /* '\*|\/|%' => LRAction::Reduce(PowerList, 52) */
+                (19, 66), /* ')' => LRAction::Reduce(PowerList, 52) */
+            ],
+            gotos: &[(38, 31) /* PowerList => 31 */],
+        },
+        // State 12
+        LR1State {
+            actions: &[
+                (0, 44),  /* '<$>' => LRAction::Reduce(IdRef, 59) */
+                (6, 44),  /* '==|!=' => LRAction::Reduce(IdRef, 59) */
+                (7, 4),   /* '(\+|-|\*|\/|%|<<|>>|&|\^|\|)?=' => LRAction::Shift(32) */
+                (8, 44),  /* '||' => LRAction::Reduce(IdRef, 59) */
+                (9, 44),  /* '&&' => LRAction::Reduce(IdRef, 59) */
+                (10, 44), /* '|' => LRAction::Reduce(IdRef, 59) */
+                (11, 44), /* '&' => LRAction::Reduce(IdRef, 59) */
+                (12, 44), /* '<<|>>' => LRAction::Reduce(IdRef, 59) */
+                (13, 44), /* '<=|<|>=|>' => LRAction::Reduce(IdRef, 59) */
+                (14, 44), /* '+' => LRAction::Reduce(IdRef, 59) */
+                (15, 44), /* '-' => LRAction::Reduce(IdRef, 59) */
+                (16, 44), /* '**' => LRAction::Reduce(IdRef, 59) */
+                (17, 44), /* '\*|\/|%' => LRAction::Reduce(IdRef, 59) */
+            ],
+            gotos: &[(2, 33) /* AssignOp => 33 */],
+        },
+        // State 13
+        LR1State {
+            actions: &[
+                (0, 41),  /* '<$>' => LRAction::Reduce(Factor, 56) */
+                (6, 41),  /* '==|!=' => LRAction::Reduce(Factor, 56) */
+                (8, 41),  /* '||' => LRAction::Reduce(Factor, 56) */
+                (9, 41),  /* '&&' => LRAction::Reduce(Factor, 56) */
+                (10, 41), /* '|' => LRAction::Reduce(Factor, 56) */
+                (11, 41), /* '&' => LRAction::Reduce(Factor, 56) */
+                (12, 41), /* '<<|>>' => LRAction::Reduce(Factor, 56) */
+                (13, 41), /* '<=|<|>=|>' => LRAction::Reduce(Factor, 56) */
+                (14, 41), /* '+' => LRAction::Reduce(Factor, 56) */
+                (15, 41), /* '-' => LRAction::Reduce(Factor, 56) */
+                (16, 41), /* '**' => LRAction::Reduce(Factor, 56) */
+                (17, 41), /* '\*|\/|%' => LRAction::Reduce(Factor, 56) */
+                (19, 41), /* ')' => LRAction::Reduce(Factor, 56) */
+            ],
+            gotos: &[],
+        },
+        // State 14
+        LR1State {
+            actions: &[(0, 74) /* '<$>' => LRAction::Accept */],
+            gotos: &[],
+        },
+        // State 15
+        LR1State {
+            actions: &[
+                (0, 53),  /* '<$>' => LRAction::Reduce(LogicalOrList, 23) */
+                (8, 53),  /* '||' => LRAction::Reduce(LogicalOrList, 23) */
+                (19, 53), /* ')' => LRAction::Reduce(LogicalOrList, 23) */
+            ],
+            gotos: &[(27, 34) /* LogicalOrList => 34 */],
+        },
+        // State 16
+        LR1State {
+            actions: &[
+                (0, 46), /* '<$>' => LRAction::Reduce(Instruction, 16) */
+            ],
+            gotos: &[],
+        },
+        // State 17
+        LR1State {
+            actions: &[
+                (15, 60), /* '-' => LRAction::Reduce(Negate, 53) */
+                (18, 60), /* '(' => LRAction::Reduce(Negate, 53) */
+                (20, 60), /* '0|[1-9][0-9]*' => LRAction::Reduce(Negate, 53) */
+                (21, 60), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(Negate, 53) */
+            ],
+            gotos: &[],
+        },
+        // State 18
+        LR1State {
+            actions: &[
+                (0, 73),  /* '<$>' => LRAction::Reduce(SummList, 46) */
+                (6, 73),  /* '==|!=' => LRAction::Reduce(SummList, 46) */
+                (8, 73),  /* '||' => LRAction::Reduce(SummList, 46) */
+                (9, 73),  /* '&&' => LRAction::Reduce(SummList, 46) */
+                (10, 73), /* '|' => LRAction::Reduce(SummList, 46) */
+                (11, 73), /* '&' => LRAction::Reduce(SummList, 46) */
+                (12, 73), /* '<<|>>' => LRAction::Reduce(SummList, 46) */
+                (13, 73), /* '<=|<|>=|>' => LRAction::Reduce(SummList, 46) */
+                (14, 73), /* '+' => LRAction::Reduce(SummList, 46) */
+                (15, 73), /* '-' => LRAction::Reduce(SummList, 46) */
+                (19, 73), /* ')' => LRAction::Reduce(SummList, 46) */
+            ],
+            gotos: &[(43, 35) /* SummList => 35 */],
+        },
+        // State 19
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (19, 36), /* Factor => 36 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+            ],
+        },
+        // State 20
+        LR1State {
+            actions: &[
+                (0, 39),  /* '<$>' => LRAction::Reduce(Factor, 54) */
+                (6, 39),  /* '==|!=' => LRAction::Reduce(Factor, 54) */
+                (8, 39),  /* '||' => LRAction::Reduce(Factor, 54) */
+                (9, 39),  /* '&&' => LRAction::Reduce(Factor, 54) */
+                (10, 39), /* '|' => LRAction::Reduce(Factor, 54) */
+                (11, 39), /* '&' => LRAction::Reduce(Factor, 54) */
+                (12, 39), /* '<<|>>' => LRAction::Reduce(Factor, 54) */
+                (13, 39), /* '<=|<|>=|>' => LRAction::Reduce(Factor, 54) */
+                (14, 39), /* '+' => LRAction::Reduce(Factor, 54) */
+                (15, 39), /* '-' => LRAction::Reduce(Factor, 54) */
+                (16, 39), /* '**' => LRAction::Reduce(Factor, 54) */
+                (17, 39), /* '\*|\/|%' => LRAction::Reduce(Factor, 54) */
+                (19, 39), /* ')' => LRAction::Reduce(Factor, 54) */
+            ],
+            gotos: &[],
+        },
+        // State 21
+        LR1State {
+            actions: &[
+                (0, 58),  /* '<$>' => LRAction::Reduce(MultList, 49) */
+                (6, 58),  /* '==|!=' => LRAction::Reduce(MultList, 49) */
+                (8, 58),  /* '||' => LRAction::Reduce(MultList, 49) */
+                (9, 58),  /* '&&' => LRAction::Reduce(MultList, 49) */
+                (10, 58), /* '|' => LRAction::Reduce(MultList, 49) */
+                (11, 58), /* '&' => LRAction::Reduce(MultList, 49) */
+                (12, 58), /* '<<|>>' => LRAction::Reduce(MultList, 49) */
+                (13, 58), /* '<=|<|>=|>' => LRAction::Reduce(MultList, 49) */
+                (14, 58), /* '+' => LRAction::Reduce(MultList, 49) */
+                (15, 58), /* '-' => LRAction::Reduce(MultList, 49) */
+                (17, 58), /* '\*|\/|%' => LRAction::Reduce(MultList, 49) */
+                (19, 58), /* ')' => LRAction::Reduce(MultList, 49) */
+            ],
+            gotos: &[(31, 37) /* MultList => 37 */],
+        },
+        // State 22
+        LR1State {
+            actions: &[
+                (0, 37),  /* '<$>' => LRAction::Reduce(EqualityList, 35) */
+                (6, 37),  /* '==|!=' => LRAction::Reduce(EqualityList, 35) */
+                (8, 37),  /* '||' => LRAction::Reduce(EqualityList, 35) */
+                (9, 37),  /* '&&' => LRAction::Reduce(EqualityList, 35) */
+                (10, 37), /* '|' => LRAction::Reduce(EqualityList, 35) */
+                (11, 37), /* '&' => LRAction::Reduce(EqualityList, 35) */
+                (19, 37), /* ')' => LRAction::Reduce(EqualityList, 35) */
+            ],
+            gotos: &[(17, 38) /* EqualityList => 38 */],
+        },
+        // State 23
+        LR1State {
+            actions: &[
+                (0, 33),  /* '<$>' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (6, 33),  /* '==|!=' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (8, 33),  /* '||' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (9, 33),  /* '&&' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (10, 33), /* '|' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (11, 33), /* '&' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (12, 33), /* '<<|>>' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (13, 33), /* '<=|<|>=|>' => LRAction::Reduce(BitwiseShiftList, 41) */
+                (19, 33), /* ')' => LRAction::Reduce(BitwiseShiftList, 41) */
+            ],
+            gotos: &[(12, 39) /* BitwiseShiftList => 39 */],
+        },
+        // State 24
+        LR1State {
+            actions: &[
+                (0, 44),  /* '<$>' => LRAction::Reduce(IdRef, 59) */
+                (6, 44),  /* '==|!=' => LRAction::Reduce(IdRef, 59) */
+                (8, 44),  /* '||' => LRAction::Reduce(IdRef, 59) */
+                (9, 44),  /* '&&' => LRAction::Reduce(IdRef, 59) */
+                (10, 44), /* '|' => LRAction::Reduce(IdRef, 59) */
+                (11, 44), /* '&' => LRAction::Reduce(IdRef, 59) */
+                (12, 44), /* '<<|>>' => LRAction::Reduce(IdRef, 59) */
+                (13, 44), /* '<=|<|>=|>' => LRAction::Reduce(IdRef, 59) */
+                (14, 44), /* '+' => LRAction::Reduce(IdRef, 59) */
+                (15, 44), /* '-' => LRAction::Reduce(IdRef, 59) */
+                (16, 44), /* '**' => LRAction::Reduce(IdRef, 59) */
+                (17, 44), /* '\*|\/|%' => LRAction::Reduce(IdRef, 59) */
+                (19, 44), /* ')' => LRAction::Reduce(IdRef, 59) */
+            ],
+            gotos: &[],
+        },
+        // State 25
+        LR1State {
+            actions: &[(19, 5) /* ')' => LRAction::Shift(40) */],
+            gotos: &[],
+        },
+        // State 26
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (1, 41),  /* AssignItem => 41 */
+                (5, 7),   /* BitwiseAnd => 7 */
+                (8, 8),   /* BitwiseOr => 8 */
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 10), /* Equality => 10 */
+                (19, 11), /* Factor => 11 */
+                (20, 12), /* Id => 12 */
+                (21, 13), /* IdRef => 13 */
+                (23, 15), /* LogicalAnd => 15 */
+                (26, 42), /* LogicalOr => 42 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 27
+        LR1State {
+            actions: &[
+                (0, 27),  /* '<$>' => LRAction::Reduce(BitwiseOr, 27) */
+                (8, 27),  /* '||' => LRAction::Reduce(BitwiseOr, 27) */
+                (9, 27),  /* '&&' => LRAction::Reduce(BitwiseOr, 27) */
+                (10, 6),  /* '|' => LRAction::Shift(43) */
+                (19, 27), /* ')' => LRAction::Reduce(BitwiseOr, 27) */
+            ],
+            gotos: &[(10, 44) /* BitwiseOrOp => 44 */],
+        },
+        // State 28
+        LR1State {
+            actions: &[
+                (0, 47),  /* '<$>' => LRAction::Reduce(LogicalAnd, 24) */
+                (8, 47),  /* '||' => LRAction::Reduce(LogicalAnd, 24) */
+                (9, 7),   /* '&&' => LRAction::Shift(45) */
+                (19, 47), /* ')' => LRAction::Reduce(LogicalAnd, 24) */
+            ],
+            gotos: &[(25, 46) /* LogicalAndOp => 46 */],
+        },
+        // State 29
+        LR1State {
+            actions: &[
+                (0, 67),  /* '<$>' => LRAction::Reduce(Relational, 36) */
+                (6, 67),  /* '==|!=' => LRAction::Reduce(Relational, 36) */
+                (8, 67),  /* '||' => LRAction::Reduce(Relational, 36) */
+                (9, 67),  /* '&&' => LRAction::Reduce(Relational, 36) */
+                (10, 67), /* '|' => LRAction::Reduce(Relational, 36) */
+                (11, 67), /* '&' => LRAction::Reduce(Relational, 36) */
+                (13, 8),  /* '<=|<|>=|>' => LRAction::Shift(47) */
+                (19, 67), /* ')' => LRAction::Reduce(Relational, 36) */
+            ],
+            gotos: &[(41, 48) /* RelationalOp => 48 */],
+        },
+        // State 30
+        LR1State {
+            actions: &[
+                (0, 23),  /* '<$>' => LRAction::Reduce(BitwiseAnd, 30) */
+                (8, 23),  /* '||' => LRAction::Reduce(BitwiseAnd, 30) */
+                (9, 23),  /* '&&' => LRAction::Reduce(BitwiseAnd, 30) */
+                (10, 23), /* '|' => LRAction::Reduce(BitwiseAnd, 30) */
+                (11, 9),  /* '&' => LRAction::Shift(49) */
+                (19, 23), /* ')' => LRAction::Reduce(BitwiseAnd, 30) */
+            ],
+            gotos: &[(7, 50) /* BitwiseAndOp => 50 */],
+        },
+        // State 31
+        LR1State {
+            actions: &[
+                (0, 64),  /* '<$>' => LRAction::Reduce(Power, 50) */
+                (6, 64),  /* '==|!=' => LRAction::Reduce(Power, 50) */
+                (8, 64),  /* '||' => LRAction::Reduce(Power, 50) */
+                (9, 64),  /* '&&' => LRAction::Reduce(Power, 50) */
+                (10, 64), /* '|' => LRAction::Reduce(Power, 50) */
+                (11, 64), /* '&' => LRAction::Reduce(Power, 50) */
+                (12, 64), /* '<<|>>' => LRAction::Reduce(Power, 50) */
+                (13, 64), /* '<=|<|>=|>' => LRAction::Reduce(Power, 50) */
+                (14, 64), /* '+' => LRAction::Reduce(Power, 50) */
+                (15, 64), /* '-' => LRAction::Reduce(Power, 50) */
+                (16, 10), /* '**' => LRAction::Shift(51) */
+                (17, 64), /* '\*|\/|%' => LRAction::Reduce(Power, 50) */
+                (19, 64), /* ')' => LRAction::Reduce(Power, 50) */
+            ],
+            gotos: &[(36, 52) /* PowOp => 52 */],
+        },
+        // State 32
+        LR1State {
+            actions: &[
+                (15, 19), /* '-' => LRAction::Reduce(AssignOp, 4) */
+                (18, 19), /* '(' => LRAction::Reduce(AssignOp, 4) */
+                (20, 19), /* '0|[1-9][0-9]*' => LRAction::Reduce(AssignOp, 4) */
+                (21, 19), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(AssignOp, 4) */
+            ],
+            gotos: &[],
+        },
+        // State 33
+        LR1State {
+            actions: &[
+                (15, 18), /* '-' => LRAction::Reduce(AssignItem, 17) */
+                (18, 18), /* '(' => LRAction::Reduce(AssignItem, 17) */
+                (20, 18), /* '0|[1-9][0-9]*' => LRAction::Reduce(AssignItem, 17) */
+                (21, 18), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(AssignItem, 17) */
+            ],
+            gotos: &[],
+        },
+        // State 34
+        LR1State {
+            actions: &[
+                (0, 51),  /* '<$>' => LRAction::Reduce(LogicalOr, 21) */
+                (8, 11),  /* '||' => LRAction::Shift(53) */
+                (19, 51), /* ')' => LRAction::Reduce(LogicalOr, 21) */
+            ],
+            gotos: &[(28, 54) /* LogicalOrOp => 54 */],
+        },
+        // State 35
+        LR1State {
+            actions: &[
+                (0, 71),  /* '<$>' => LRAction::Reduce(Summ, 44) */
+                (6, 71),  /* '==|!=' => LRAction::Reduce(Summ, 44) */
+                (8, 71),  /* '||' => LRAction::Reduce(Summ, 44) */
+                (9, 71),  /* '&&' => LRAction::Reduce(Summ, 44) */
+                (10, 71), /* '|' => LRAction::Reduce(Summ, 44) */
+                (11, 71), /* '&' => LRAction::Reduce(Summ, 44) */
+                (12, 71), /* '<<|>>' => LRAction::Reduce(Summ, 44) */
+                (13, 71), /* '<=|<|>=|>' => LRAction::Reduce(Summ, 44) */
+                (14, 12), /* '+' => LRAction::Shift(55) */
+                (15, 0),  /* '-' => LRAction::Shift(1) */
+                (19, 71), /* ')' => LRAction::Reduce(Summ, 44) */
+            ],
+            gotos: &[
+                (0, 56),  /* AddOp => 56 */
+                (29, 57), /* Minus => 57 */
+                (35, 58), /* Plus => 58 */
+            ],
+        },
+        // State 36
+        LR1State {
+            actions: &[
+                (0, 40),  /* '<$>' => LRAction::Reduce(Factor, 55) */
+                (6, 40),  /* '==|!=' => LRAction::Reduce(Factor, 55) */
+                (8, 40),  /* '||' => LRAction::Reduce(Factor, 55) */
+                (9, 40),  /* '&&' => LRAction::Reduce(Factor, 55) */
+                (10, 40), /* '|' => LRAction::Reduce(Factor, 55) */
+                (11, 40), /* '&' => LRAction::Reduce(Factor, 55) */
+                (12, 40), /* '<<|>>' => LRAction::Reduce(Factor, 55) */
+                (13, 40), /* '<=|<|>=|>' => LRAction::Reduce(Factor, 55) */
+                (14, 40), /* '+' => LRAction::Reduce(Factor, 55) */
+                (15, 40), /* '-' => LRAction::Reduce(Factor, 55) */
+                (16, 40), /* '**' => LRAction::Reduce(Factor, 55) */
+                (17, 40), /* '\*|\/|%' => LRAction::Reduce(Factor, 55) */
+                (19, 40), /* ')' => LRAction::Reduce(Factor, 55) */
+            ],
+            gotos: &[],
+        },
+        // State 37
+        LR1State {
+            actions: &[
+                (0, 56),  /* '<$>' => LRAction::Reduce(Mult, 47) */
+                (6, 56),  /* '==|!=' => LRAction::Reduce(Mult, 47) */
+                (8, 56),  /* '||' => LRAction::Reduce(Mult, 47) */
+                (9, 56),  /* '&&' => LRAction::Reduce(Mult, 47) */
+                (10, 56), /* '|' => LRAction::Reduce(Mult, 47) */
+                (11, 56), /* '&' => LRAction::Reduce(Mult, 47) */
+                (12, 56), /* '<<|>>' => LRAction::Reduce(Mult, 47) */
+                (13, 56), /* '<=|<|>=|>' => LRAction::Reduce(Mult, 47) */
+                (14, 56), /* '+' => LRAction::Reduce(Mult, 47) */
+                (15, 56), /* '-' => LRAction::Reduce(Mult, 47) */
+                (17, 13), /* '\*|\/|%' => LRAction::Shift(59) */
+                (19, 56), /* ')' => LRAction::Reduce(Mult, 47) */
+            ],
+            gotos: &[(32, 60) /* MultOp => 60 */],
+        },
+        // State 38
+        LR1State {
+            actions: &[
+                (0, 35),  /* '<$>' => LRAction::Reduce(Equality, 33) */
+                (6, 14),  /* '==|!=' => LRAction::Shift(61) */
+                (8, 35),  /* '||' => LRAction::Reduce(Equality, 33) */
+                (9, 35),  /* '&&' => LRAction::Reduce(Equality, 33) */
+                (10, 35), /* '|' => LRAction::Reduce(Equality, 33) */
+                (11, 35), /* '&' => LRAction::Reduce(Equality, 33) */
+                (19, 35), /* ')' => LRAction::Reduce(Equality, 33) */
+            ],
+            gotos: &[(18, 62) /* EqualityOp => 62 */],
+        },
+        // State 39
+        LR1State {
+            actions: &[
+                (0, 31),  /* '<$>' => LRAction::Reduce(BitwiseShift, 39) */
+                (6, 31),  /* '==|!=' => LRAction::Reduce(BitwiseShift, 39) */
+                (8, 31),  /* '||' => LRAction::Reduce(BitwiseShift, 39) */
+                (9, 31),  /* '&&' => LRAction::Reduce(BitwiseShift, 39) */
+                (10, 31), /* '|' => LRAction::Reduce(BitwiseShift, 39) */
+                (11, 31), /* '&' => LRAction::Reduce(BitwiseShift, 39) */
+                (12, 15), /* '<<|>>' => LRAction::Shift(63) */
+                (13, 31), /* '<=|<|>=|>' => LRAction::Reduce(BitwiseShift, 39) */
+                (19, 31), /* ')' => LRAction::Reduce(BitwiseShift, 39) */
+            ],
+            gotos: &[(13, 64) /* BitwiseShiftOp => 64 */],
+        },
+        // State 40
+        LR1State {
+            actions: &[
+                (0, 42),  /* '<$>' => LRAction::Reduce(Factor, 57) */
+                (6, 42),  /* '==|!=' => LRAction::Reduce(Factor, 57) */
+                (8, 42),  /* '||' => LRAction::Reduce(Factor, 57) */
+                (9, 42),  /* '&&' => LRAction::Reduce(Factor, 57) */
+                (10, 42), /* '|' => LRAction::Reduce(Factor, 57) */
+                (11, 42), /* '&' => LRAction::Reduce(Factor, 57) */
+                (12, 42), /* '<<|>>' => LRAction::Reduce(Factor, 57) */
+                (13, 42), /* '<=|<|>=|>' => LRAction::Reduce(Factor, 57) */
+                (14, 42), /* '+' => LRAction::Reduce(Factor, 57) */
+                (15, 42), /* '-' => LRAction::Reduce(Factor, 57) */
+                (16, 42), /* '**' => LRAction::Reduce(Factor, 57) */
+                (17, 42), /* '\*|\/|%' => LRAction::Reduce(Factor, 57) */
+                (19, 42), /* ')' => LRAction::Reduce(Factor, 57) */
+            ],
+            gotos: &[],
+        },
+        // State 41
+        LR1State {
+            actions: &[
+                (15, 21), /* '-' => LRAction::Reduce(AssignmentList, 19) */
+                (18, 21), /* '(' => LRAction::Reduce(AssignmentList, 19) */
+                (20, 21), /* '0|[1-9][0-9]*' => LRAction::Reduce(AssignmentList, 19) */
+                (21, 21), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(AssignmentList, 19) */
+            ],
+            gotos: &[],
+        },
+        // State 42
+        LR1State {
+            actions: &[(0, 20) /* '<$>' => LRAction::Reduce(Assignment, 18) */],
+            gotos: &[],
+        },
+        // State 43
+        LR1State {
+            actions: &[
+                (15, 30), /* '-' => LRAction::Reduce(BitwiseOrOp, 7) */
+                (18, 30), /* '(' => LRAction::Reduce(BitwiseOrOp, 7) */
+                (20, 30), /* '0|[1-9][0-9]*' => LRAction::Reduce(BitwiseOrOp, 7) */
+                (21, 30), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(BitwiseOrOp, 7) */
+            ],
+            gotos: &[],
+        },
+        // State 44
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (5, 65),  /* BitwiseAnd => 65 */
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 10), /* Equality => 10 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 45
+        LR1State {
+            actions: &[
+                (15, 50), /* '-' => LRAction::Reduce(LogicalAndOp, 6) */
+                (18, 50), /* '(' => LRAction::Reduce(LogicalAndOp, 6) */
+                (20, 50), /* '0|[1-9][0-9]*' => LRAction::Reduce(LogicalAndOp, 6) */
+                (21, 50), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(LogicalAndOp, 6) */
+            ],
+            gotos: &[],
+        },
+        // State 46
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (5, 7),   /* BitwiseAnd => 7 */
+                (8, 66),  /* BitwiseOr => 66 */
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 10), /* Equality => 10 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 47
+        LR1State {
+            actions: &[
+                (15, 70), /* '-' => LRAction::Reduce(RelationalOp, 10) */
+                (18, 70), /* '(' => LRAction::Reduce(RelationalOp, 10) */
+                (20, 70), /* '0|[1-9][0-9]*' => LRAction::Reduce(RelationalOp, 10) */
+                (21, 70), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(RelationalOp, 10) */
+            ],
+            gotos: &[],
+        },
+        // State 48
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (11, 67), /* BitwiseShift => 67 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 49
+        LR1State {
+            actions: &[
+                (15, 26), /* '-' => LRAction::Reduce(BitwiseAndOp, 8) */
+                (18, 26), /* '(' => LRAction::Reduce(BitwiseAndOp, 8) */
+                (20, 26), /* '0|[1-9][0-9]*' => LRAction::Reduce(BitwiseAndOp, 8) */
+                (21, 26), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(BitwiseAndOp, 8) */
+            ],
+            gotos: &[],
+        },
+        // State 50
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 68), /* Equality => 68 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 51
+        LR1State {
+            actions: &[
+                (15, 63), /* '-' => LRAction::Reduce(PowOp, 13) */
+                (18, 63), /* '(' => LRAction::Reduce(PowOp, 13) */
+                (20, 63), /* '0|[1-9][0-9]*' => LRAction::Reduce(PowOp, 13) */
+                (21, 63), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(PowOp, 13) */
+            ],
+            gotos: &[],
+        },
+        // State 52
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (19, 69), /* Factor => 69 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+            ],
+        },
+        // State 53
+        LR1State {
+            actions: &[
+                (15, 54), /* '-' => LRAction::Reduce(LogicalOrOp, 5) */
+                (18, 54), /* '(' => LRAction::Reduce(LogicalOrOp, 5) */
+                (20, 54), /* '0|[1-9][0-9]*' => LRAction::Reduce(LogicalOrOp, 5) */
+                (21, 54), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(LogicalOrOp, 5) */
+            ],
+            gotos: &[],
+        },
+        // State 54
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (5, 7),   /* BitwiseAnd => 7 */
+                (8, 8),   /* BitwiseOr => 8 */
+                (11, 9),  /* BitwiseShift => 9 */
+                (16, 10), /* Equality => 10 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (23, 70), /* LogicalAnd => 70 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 22), /* Relational => 22 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 55
+        LR1State {
+            actions: &[
+                (15, 62), /* '-' => LRAction::Reduce(Plus, 11) */
+                (18, 62), /* '(' => LRAction::Reduce(Plus, 11) */
+                (20, 62), /* '0|[1-9][0-9]*' => LRAction::Reduce(Plus, 11) */
+                (21, 62), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(Plus, 11) */
+            ],
+            gotos: &[],
+        },
+        // State 56
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 71), /* Mult => 71 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+            ],
+        },
+        // State 57
+        LR1State {
+            actions: &[
+                (15, 17), /* '-' => LRAction::Reduce(AddOp, 43) */
+                (18, 17), /* '(' => LRAction::Reduce(AddOp, 43) */
+                (20, 17), /* '0|[1-9][0-9]*' => LRAction::Reduce(AddOp, 43) */
+                (21, 17), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(AddOp, 43) */
+            ],
+            gotos: &[],
+        },
+        // State 58
+        LR1State {
+            actions: &[
+                (15, 16), /* '-' => LRAction::Reduce(AddOp, 42) */
+                (18, 16), /* '(' => LRAction::Reduce(AddOp, 42) */
+                (20, 16), /* '0|[1-9][0-9]*' => LRAction::Reduce(AddOp, 42) */
+                (21, 16), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(AddOp, 42) */
+            ],
+            gotos: &[],
+        },
+        // State 59
+        LR1State {
+            actions: &[
+                (15, 59), /* '-' => LRAction::Reduce(MultOp, 14) */
+                (18, 59), /* '(' => LRAction::Reduce(MultOp, 14) */
+                (20, 59), /* '0|[1-9][0-9]*' => LRAction::Reduce(MultOp, 14) */
+                (21, 59), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(MultOp, 14) */
+            ],
+            gotos: &[],
+        },
+        // State 60
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 72), /* Power => 72 */
+            ],
+        },
+        // State 61
+        LR1State {
+            actions: &[
+                (15, 38), /* '-' => LRAction::Reduce(EqualityOp, 3) */
+                (18, 38), /* '(' => LRAction::Reduce(EqualityOp, 3) */
+                (20, 38), /* '0|[1-9][0-9]*' => LRAction::Reduce(EqualityOp, 3) */
+                (21, 38), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(EqualityOp, 3) */
+            ],
+            gotos: &[],
+        },
+        // State 62
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (11, 9),  /* BitwiseShift => 9 */
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (39, 73), /* Relational => 73 */
+                (42, 23), /* Summ => 23 */
+            ],
+        },
+        // State 63
+        LR1State {
+            actions: &[
+                (15, 34), /* '-' => LRAction::Reduce(BitwiseShiftOp, 9) */
+                (18, 34), /* '(' => LRAction::Reduce(BitwiseShiftOp, 9) */
+                (20, 34), /* '0|[1-9][0-9]*' => LRAction::Reduce(BitwiseShiftOp, 9) */
+                (21, 34), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Reduce(BitwiseShiftOp, 9) */
+            ],
+            gotos: &[],
+        },
+        // State 64
+        LR1State {
+            actions: &[
+                (15, 0), /* '-' => LRAction::Shift(1) */
+                (18, 1), /* '(' => LRAction::Shift(2) */
+                (20, 2), /* '0|[1-9][0-9]*' => LRAction::Shift(3) */
+                (21, 3), /* '[a-zA-Z_][a-zA-Z0-9_]*' => LRAction::Shift(4) */
+            ],
+            gotos: &[
+                (19, 11), /* Factor => 11 */
+                (20, 24), /* Id => 24 */
+                (21, 13), /* IdRef => 13 */
+                (29, 17), /* Minus => 17 */
+                (30, 18), /* Mult => 18 */
+                (33, 19), /* Negate => 19 */
+                (34, 20), /* Number => 20 */
+                (37, 21), /* Power => 21 */
+                (42, 74), /* Summ => 74 */
+            ],
+        },
+        // State 65
+        LR1State {
+            actions: &[
+                (0, 28),  /* '<$>' => LRAction::Reduce(BitwiseOrList, 28) */
+                (8, 28),  /* '||' => LRAction::Reduce(BitwiseOrList, 28) */
+                (9, 28),  /* '&&' => LRAction::Reduce(BitwiseOrList, 28) */
+                (10, 28), /* '|' => LRAction::Reduce(BitwiseOrList, 28) */
+                (19, 28), /* ')' => LRAction::Reduce(BitwiseOrList, 28) */
+            ],
+            gotos: &[],
+        },
+        // State 66
+        LR1State {
+            actions: &[
+                (0, 48),  /* '<$>' => LRAction::Reduce(LogicalAndList, 25) */
+                (8, 48),  /* '||' => LRAction::Reduce(LogicalAndList, 25) */
+                (9, 48),  /* '&&' => LRAction::Reduce(LogicalAndList, 25) */
+                (19, 48), /* ')' => LRAction::Reduce(LogicalAndList, 25) */
+            ],
+            gotos: &[],
+        },
+        // State 67
+        LR1State {
+            actions: &[
+                (0, 68),  /* '<$>' => LRAction::Reduce(RelationalList, 37) */
+                (6, 68),  /* '==|!=' => LRAction::Reduce(RelationalList, 37) */
+                (8, 68),  /* '||' => LRAction::Reduce(RelationalList, 37) */
+                (9, 68),  /* '&&' => LRAction::Reduce(RelationalList, 37) */
+                (10, 68), /* '|' => LRAction::Reduce(RelationalList, 37) */
+                (11, 68), /* '&' => LRAction::Reduce(RelationalList, 37) */
+                (13, 68), /* '<=|<|>=|>' => LRAction::Reduce(RelationalList, 37) */
+                (19, 68), /* ')' => LRAction::Reduce(RelationalList, 37) */
+            ],
+            gotos: &[],
+        },
+        // State 68
+        LR1State {
+            actions: &[
+                (0, 24),  /* '<$>' => LRAction::Reduce(BitwiseAndList, 31) */
+                (8, 24),  /* '||' => LRAction::Reduce(BitwiseAndList, 31) */
+                (9, 24),  /* '&&' => LRAction::Reduce(BitwiseAndList, 31) */
+                (10, 24), /* '|' => LRAction::Reduce(BitwiseAndList, 31) */
+                (11, 24), /* '&' => LRAction::Reduce(BitwiseAndList, 31) */
+                (19, 24), /* ')' => LRAction::Reduce(BitwiseAndList, 31) */
+            ],
+            gotos: &[],
+        },
+        // State 69
+        LR1State {
+            actions: &[
+                (0, 65),  /* '<$>' => LRAction::Reduce(PowerList, 51) */
+                (6, 65),  /* '==|!=' => LRAction::Reduce(PowerList, 51) */
+                (8, 65),  /* '||' => LRAction::Reduce(PowerList, 51) */
+                (9, 65),  /* '&&' => LRAction::Reduce(PowerList, 51) */
+                (10, 65), /* '|' => LRAction::Reduce(PowerList, 51) */
+                (11, 65), /* '&' => LRAction::Reduce(PowerList, 51) */
+                (12, 65), /* '<<|>>' => LRAction::Reduce(PowerList, 51) */
+                (13, 65), /* '<=|<|>=|>' => LRAction::Reduce(PowerList, 51) */
+                (14, 65), /* '+' => LRAction::Reduce(PowerList, 51) */
+                (15, 65), /* '-' => LRAction::Reduce(PowerList, 51) */
+                (16, 65), /* '**' => LRAction::Reduce(PowerList, 51) */
+                (17, 65), /* '\*|\/|%' => LRAction::Reduce(PowerList, 51) */
+                (19, 65), /* ')' => LRAction::Reduce(PowerList, 51) */
+            ],
+            gotos: &[],
+        },
+        // State 70
+        LR1State {
+            actions: &[
+                (0, 52),  /* '<$>' => LRAction::Reduce(LogicalOrList, 22) */
+                (8, 52),  /* '||' => LRAction::Reduce(LogicalOrList, 22) */
+                (19, 52), /* ')' => LRAction::Reduce(LogicalOrList, 22) */
+            ],
+            gotos: &[],
+        },
+        // State 71
+        LR1State {
+            actions: &[
+                (0, 72),  /* '<$>' => LRAction::Reduce(SummList, 45) */
+                (6, 72),  /* '==|!=' => LRAction::Reduce(SummList, 45) */
+                (8, 72),  /* '||' => LRAction::Reduce(SummList, 45) */
+                (9, 72),  /* '&&' => LRAction::Reduce(SummList, 45) */
+                (10, 72), /* '|' => LRAction::Reduce(SummList, 45) */
+                (11, 72), /* '&' => LRAction::Reduce(SummList, 45) */
+                (12, 72), /* '<<|>>' => LRAction::Reduce(SummList, 45) */
+                (13, 72), /* '<=|<|>=|>' => LRAction::Reduce(SummList, 45) */
+                (14, 72), /* '+' => LRAction::Reduce(SummList, 45) */
+                (15, 72), /* '-' => LRAction::Reduce(SummList, 45) */
+                (19, 72), /* ')' => LRAction::Reduce(SummList, 45) */
+            ],
+            gotos: &[],
+        },
+        // State 72
+        LR1State {
+            actions: &[
+                (0, 57),  /* '<$>' => LRAction::Reduce(MultList, 48) */
+                (6, 57),  /* '==|!=' => LRAction::Reduce(MultList, 48) */
+                (8, 57),  /* '||' => LRAction::Reduce(MultList, 48) */
+                (9, 57),  /* '&&' => LRAction::Reduce(MultList, 48) */
+                (10, 57), /* '|' => LRAction::Reduce(MultList, 48) */
+                (11, 57), /* '&' => LRAction::Reduce(MultList, 48) */
+                (12, 57), /* '<<|>>' => LRAction::Reduce(MultList, 48) */
+                (13, 57), /* '<=|<|>=|>' => LRAction::Reduce(MultList, 48) */
+                (14, 57), /* '+' => LRAction::Reduce(MultList, 48) */
+                (15, 57), /* '-' => LRAction::Reduce(MultList, 48) */
+                (17, 57), /* '\*|\/|%' => LRAction::Reduce(MultList, 48) */
+                (19, 57), /* ')' => LRAction::Reduce(MultList, 48) */
+            ],
+            gotos: &[],
+        },
+        // State 73
+        LR1State {
+            actions: &[
+                (0, 36),  /* '<$>' => LRAction::Reduce(EqualityList, 34) */
+                (6, 36),  /* '==|!=' => LRAction::Reduce(EqualityList, 34) */
+                (8, 36),  /* '||' => LRAction::Reduce(EqualityList, 34) */
+                (9, 36),  /* '&&' => LRAction::Reduce(EqualityList, 34) */
+                (10, 36), /* '|' => LRAction::Reduce(EqualityList, 34) */
+                (11, 36), /* '&' => LRAction::Reduce(EqualityList, 34) */
+                (19, 36), /* ')' => LRAction::Reduce(EqualityList, 34) */
+            ],
+            gotos: &[],
+        },
+        // State 74
+        LR1State {
+            actions: &[
+                (0, 32),  /* '<$>' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (6, 32),  /* '==|!=' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (8, 32),  /* '||' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (9, 32),  /* '&&' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (10, 32), /* '|' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (11, 32), /* '&' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (12, 32), /* '<<|>>' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (13, 32), /* '<=|<|>=|>' => LRAction::Reduce(BitwiseShiftList, 40) */
+                (19, 32), /* ')' => LRAction::Reduce(BitwiseShiftList, 40) */
+            ],
+            gotos: &[],
+        },
+    ],
+};
+
 pub const PRODUCTIONS: &[LRProduction; 61] = &[
     // 0 - Calc: CalcList /* Vec */;
     LRProduction {
@@ -1736,3 +2915,93 @@ where
         &mut user_actions,
     )
 }
+
+///
+/// Parses the input like `parse` but stops with `ParserError::Cancelled` as soon as the
+/// cancellation token is set, e.g. by another thread that starts parsing a newer input.
+///
+#[allow(dead_code)]
+pub fn parse_cancellable<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut CalcGrammar<'t>,
+    cancellation_token: std::sync::Arc<std::sync::atomic::AtomicBool>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use calc_grammar_scanner::CalcGrammarScanner;
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut lr_parser = LRParser::new(14, &PARSE_TABLE, PRODUCTIONS, TERMINAL_NAMES, NON_TERMINALS);
+    lr_parser.trim_parse_tree();
+
+    // Initialize wrapper
+    let mut user_actions = CalcGrammarAuto::new(user_actions);
+    let scanner = CalcGrammarScanner::new();
+    lr_parser.set_cancellation_token(cancellation_token);
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    lr_parser.parse_into(
+        &mut builder,
+        TokenStream::new_with_skip_tokens(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &CalcGrammarScanner::match_function,
+            1,
+            SKIP_TOKENS_BY_SCANNER_STATE,
+        )
+        .unwrap(),
+        &mut user_actions,
+    )?;
+    Ok(builder.build()?)
+}
+
+///
+/// Parses the input as Instruction instead of the start symbol.
+/// The input must end after the Instruction.
+///
+#[allow(dead_code)]
+pub fn parse_instruction<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut CalcGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use calc_grammar_scanner::CalcGrammarScanner;
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut lr_parser = LRParser::new_for_entry_point(
+        22,
+        &PARSE_TABLE_INSTRUCTION,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    lr_parser.trim_parse_tree();
+
+    // Initialize wrapper
+    let mut user_actions = CalcGrammarAuto::new(user_actions);
+    let scanner = CalcGrammarScanner::new();
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    lr_parser.parse_into(
+        &mut builder,
+        TokenStream::new_with_skip_tokens(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &CalcGrammarScanner::match_function,
+            1,
+            SKIP_TOKENS_BY_SCANNER_STATE,
+        )
+        .unwrap(),
+        &mut user_actions,
+    )?;
+    Ok(builder.build()?)
+}
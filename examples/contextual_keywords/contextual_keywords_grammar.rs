@@ -0,0 +1,63 @@
+use crate::contextual_keywords_grammar_trait::{
+    Accessor, ContextualKeywordsGrammarTrait, Declaration, Declarations,
+};
+use parol_runtime::Result;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+///
+/// Data structure that implements the semantic actions for our contextual keywords grammar
+///
+#[derive(Debug, Default)]
+pub struct ContextualKeywordsGrammar<'t> {
+    pub declarations: Option<Declarations<'t>>,
+}
+
+impl ContextualKeywordsGrammar<'_> {
+    pub fn new() -> Self {
+        ContextualKeywordsGrammar::default()
+    }
+}
+
+impl Display for ContextualKeywordsGrammar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match &self.declarations {
+            Some(declarations) => declarations
+                .declarations_list
+                .iter()
+                .try_for_each(|d| match &d.declaration {
+                    Declaration::Property(p) => {
+                        let accessors = p
+                            .property
+                            .property_list
+                            .iter()
+                            .map(|a| match &a.accessor {
+                                Accessor::GetSemicolon(g) => g.get.get.text(),
+                                Accessor::SetSemicolon(s) => s.set.set.text(),
+                            })
+                            .collect::<Vec<_>>();
+                        writeln!(
+                            f,
+                            "property {} [{}]",
+                            p.property.name.name.text(),
+                            accessors.join(", ")
+                        )
+                    }
+                    Declaration::Assignment(a) => writeln!(
+                        f,
+                        "{} = {}",
+                        a.assignment.name.name.text(),
+                        a.assignment.name0.name.text()
+                    ),
+                }),
+            None => write!(f, "No parse result"),
+        }
+    }
+}
+
+impl<'t> ContextualKeywordsGrammarTrait<'t> for ContextualKeywordsGrammar<'t> {
+    /// Semantic action for non-terminal 'Declarations'
+    fn declarations(&mut self, arg: &Declarations<'t>) -> Result<()> {
+        self.declarations = Some(arg.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,598 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{ParserError, Result, Token};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait ContextualKeywordsGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'Declarations'
+    fn declarations(&mut self, _arg: &Declarations<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Declaration'
+    fn declaration(&mut self, _arg: &Declaration<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Property'
+    fn property(&mut self, _arg: &Property<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Accessor'
+    fn accessor(&mut self, _arg: &Accessor<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Assignment'
+    fn assignment(&mut self, _arg: &Assignment<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Get'
+    fn get(&mut self, _arg: &Get<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Set'
+    fn set(&mut self, _arg: &Set<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Name'
+    fn name(&mut self, _arg: &Name<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// This method provides skipped language comments.
+    /// If you need comments please provide your own implementation of this method.
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 3
+///
+/// `Declaration: Property;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeclarationProperty<'t> {
+    pub property: Property<'t>,
+}
+
+///
+/// Type derived for production 4
+///
+/// `Declaration: Assignment;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeclarationAssignment<'t> {
+    pub assignment: Assignment<'t>,
+}
+
+///
+/// Type derived for production 8
+///
+/// `Accessor: Get ';'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct AccessorGetSemicolon<'t> {
+    pub get: Get<'t>,
+}
+
+///
+/// Type derived for production 9
+///
+/// `Accessor: Set ';'^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct AccessorSetSemicolon<'t> {
+    pub set: Set<'t>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Accessor
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Accessor<'t> {
+    GetSemicolon(AccessorGetSemicolon<'t>),
+    SetSemicolon(AccessorSetSemicolon<'t>),
+}
+
+///
+/// Type derived for non-terminal Assignment
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Assignment<'t> {
+    pub name: Name<'t>,
+    pub name0: Name<'t>,
+}
+
+///
+/// Type derived for non-terminal Declaration
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Declaration<'t> {
+    Property(DeclarationProperty<'t>),
+    Assignment(DeclarationAssignment<'t>),
+}
+
+///
+/// Type derived for non-terminal Declarations
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Declarations<'t> {
+    pub declarations_list: Vec<DeclarationsList<'t>>,
+}
+
+///
+/// Type derived for non-terminal DeclarationsList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct DeclarationsList<'t> {
+    pub declaration: Declaration<'t>,
+}
+
+///
+/// Type derived for non-terminal Get
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Get<'t> {
+    pub get: Token<'t>, /* get */
+}
+
+///
+/// Type derived for non-terminal Name
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Name<'t> {
+    pub name: Token<'t>, /* [a-zA-Z_][a-zA-Z0-9_]* */
+}
+
+///
+/// Type derived for non-terminal Property
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Property<'t> {
+    pub name: Name<'t>,
+    pub property_list: Vec<PropertyList<'t>>,
+}
+
+///
+/// Type derived for non-terminal PropertyList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct PropertyList<'t> {
+    pub accessor: Accessor<'t>,
+}
+
+///
+/// Type derived for non-terminal Set
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Set<'t> {
+    pub set: Token<'t>, /* set */
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Accessor(Accessor<'t>),
+    Assignment(Assignment<'t>),
+    Declaration(Declaration<'t>),
+    Declarations(Declarations<'t>),
+    DeclarationsList(Vec<DeclarationsList<'t>>),
+    Get(Get<'t>),
+    Name(Name<'t>),
+    Property(Property<'t>),
+    PropertyList(Vec<PropertyList<'t>>),
+    Set(Set<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct ContextualKeywordsGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn ContextualKeywordsGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `ContextualKeywordsGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> ContextualKeywordsGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn ContextualKeywordsGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {context}: {item:?}");
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        let item = self.item_stack.pop();
+        if let Some(ref item) = item {
+            trace!("pop     {context}: {item:?}");
+        }
+        item
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {s:?}"))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// `Declarations: DeclarationsList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declarations(&mut self, _declarations_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let declarations_list =
+            pop_and_reverse_item!(self, declarations_list, DeclarationsList, context);
+        let declarations_built = Declarations { declarations_list };
+        // Calling user action here
+        self.user_grammar.declarations(&declarations_built)?;
+        self.push(ASTType::Declarations(declarations_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// `DeclarationsList /* Vec<T>::Push */: Declaration DeclarationsList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declarations_list_0(
+        &mut self,
+        _declaration: &ParseTreeType<'t>,
+        _declarations_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut declarations_list = pop_item!(self, declarations_list, DeclarationsList, context);
+        let declaration = pop_item!(self, declaration, Declaration, context);
+        let declarations_list_0_built = DeclarationsList { declaration };
+        // Add an element to the vector
+        declarations_list.push(declarations_list_0_built);
+        self.push(ASTType::DeclarationsList(declarations_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// `DeclarationsList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declarations_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let declarations_list_1_built = Vec::new();
+        self.push(
+            ASTType::DeclarationsList(declarations_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Declaration: Property;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_0(&mut self, _property: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let property = pop_item!(self, property, Property, context);
+        let declaration_0_built = DeclarationProperty { property };
+        let declaration_0_built = Declaration::Property(declaration_0_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_0_built)?;
+        self.push(ASTType::Declaration(declaration_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `Declaration: Assignment;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration_1(&mut self, _assignment: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assignment = pop_item!(self, assignment, Assignment, context);
+        let declaration_1_built = DeclarationAssignment { assignment };
+        let declaration_1_built = Declaration::Assignment(declaration_1_built);
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_1_built)?;
+        self.push(ASTType::Declaration(declaration_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// `Property: 'property'^ /* Clipped */ Name '{'^ /* Clipped */ PropertyList /* Vec */ '}'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property(
+        &mut self,
+        _property: &ParseTreeType<'t>,
+        _name: &ParseTreeType<'t>,
+        _l_brace: &ParseTreeType<'t>,
+        _property_list: &ParseTreeType<'t>,
+        _r_brace: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let property_list = pop_and_reverse_item!(self, property_list, PropertyList, context);
+        let name = pop_item!(self, name, Name, context);
+        let property_built = Property {
+            name,
+            property_list,
+        };
+        // Calling user action here
+        self.user_grammar.property(&property_built)?;
+        self.push(ASTType::Property(property_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// `PropertyList /* Vec<T>::Push */: Accessor PropertyList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_list_0(
+        &mut self,
+        _accessor: &ParseTreeType<'t>,
+        _property_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut property_list = pop_item!(self, property_list, PropertyList, context);
+        let accessor = pop_item!(self, accessor, Accessor, context);
+        let property_list_0_built = PropertyList { accessor };
+        // Add an element to the vector
+        property_list.push(property_list_0_built);
+        self.push(ASTType::PropertyList(property_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// `PropertyList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn property_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let property_list_1_built = Vec::new();
+        self.push(ASTType::PropertyList(property_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// `Accessor: Get ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn accessor_0(
+        &mut self,
+        _get: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let get = pop_item!(self, get, Get, context);
+        let accessor_0_built = AccessorGetSemicolon { get };
+        let accessor_0_built = Accessor::GetSemicolon(accessor_0_built);
+        // Calling user action here
+        self.user_grammar.accessor(&accessor_0_built)?;
+        self.push(ASTType::Accessor(accessor_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// `Accessor: Set ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn accessor_1(
+        &mut self,
+        _set: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let set = pop_item!(self, set, Set, context);
+        let accessor_1_built = AccessorSetSemicolon { set };
+        let accessor_1_built = Accessor::SetSemicolon(accessor_1_built);
+        // Calling user action here
+        self.user_grammar.accessor(&accessor_1_built)?;
+        self.push(ASTType::Accessor(accessor_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 10:
+    ///
+    /// `Assignment: Name '='^ /* Clipped */ Name ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assignment(
+        &mut self,
+        _name: &ParseTreeType<'t>,
+        _equ: &ParseTreeType<'t>,
+        _name0: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name0 = pop_item!(self, name0, Name, context);
+        let name = pop_item!(self, name, Name, context);
+        let assignment_built = Assignment { name, name0 };
+        // Calling user action here
+        self.user_grammar.assignment(&assignment_built)?;
+        self.push(ASTType::Assignment(assignment_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 11:
+    ///
+    /// `Get: 'get';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn get(&mut self, get: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let get = get.token()?.clone();
+        let get_built = Get { get };
+        // Calling user action here
+        self.user_grammar.get(&get_built)?;
+        self.push(ASTType::Get(get_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 12:
+    ///
+    /// `Set: 'set';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn set(&mut self, set: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let set = set.token()?.clone();
+        let set_built = Set { set };
+        // Calling user action here
+        self.user_grammar.set(&set_built)?;
+        self.push(ASTType::Set(set_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 13:
+    ///
+    /// `Name: /[a-zA-Z_][a-zA-Z0-9_]*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn name(&mut self, name: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name = name.token()?.clone();
+        let name_built = Name { name };
+        // Calling user action here
+        self.user_grammar.name(&name_built)?;
+        self.push(ASTType::Name(name_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for ContextualKeywordsGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item ContextualKeywordsGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.declarations(&children[0]),
+            1 => self.declarations_list_0(&children[0], &children[1]),
+            2 => self.declarations_list_1(),
+            3 => self.declaration_0(&children[0]),
+            4 => self.declaration_1(&children[0]),
+            5 => self.property(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            6 => self.property_list_0(&children[0], &children[1]),
+            7 => self.property_list_1(),
+            8 => self.accessor_0(&children[0], &children[1]),
+            9 => self.accessor_1(&children[0], &children[1]),
+            10 => self.assignment(&children[0], &children[1], &children[2], &children[3]),
+            11 => self.get(&children[0]),
+            12 => self.set(&children[0]),
+            13 => self.name(&children[0]),
+            _ => Err(ParserError::InternalError(format!(
+                "Unhandled production number: {prod_num}"
+            ))
+            .into()),
+        }
+    }
+
+    fn on_comment(&mut self, token: Token<'t>) {
+        self.user_grammar.on_comment(token)
+    }
+}
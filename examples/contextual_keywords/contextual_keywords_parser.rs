@@ -0,0 +1,316 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{
+    ParolError, ParseTree, TokenStream,
+    parser::{
+        LLKParser, LookaheadDFA, ParseType, Production, Trans, parse_tree_type::TreeConstruct,
+    },
+};
+use scnr2::scanner;
+use std::path::Path;
+
+use crate::contextual_keywords_grammar::ContextualKeywordsGrammar;
+use crate::contextual_keywords_grammar_trait::ContextualKeywordsGrammarAuto;
+
+pub const TERMINAL_NAMES: &[&str; 14] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Property",
+    /*  6 */ "LBrace",
+    /*  7 */ "RBrace",
+    /*  8 */ "Semicolon",
+    /*  9 */ "Equ",
+    /* 10 */ "Get",
+    /* 11 */ "Set",
+    /* 12 */ "Name",
+    /* 13 */ "Error",
+];
+
+scanner! {
+    ContextualKeywordsGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"property" => 5; // "Property"
+            token r"\{" => 6; // "LBrace"
+            token r"\}" => 7; // "RBrace"
+            token r";" => 8; // "Semicolon"
+            token r"=" => 9; // "Equ"
+            token r"get" => 10; // "Get"
+            token r"set" => 11; // "Set"
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 12; // "Name"
+            token r"." => 13; // "Error"
+        }
+        mode __TERMINAL_5 {
+            token r"property" => 5; // "Property"
+        }
+        mode __TERMINAL_6 {
+            token r"\{" => 6; // "LBrace"
+        }
+        mode __TERMINAL_7 {
+            token r"\}" => 7; // "RBrace"
+        }
+        mode __TERMINAL_8 {
+            token r";" => 8; // "Semicolon"
+        }
+        mode __TERMINAL_9 {
+            token r"=" => 9; // "Equ"
+        }
+        mode __TERMINAL_10 {
+            token r"get" => 10; // "Get"
+        }
+        mode __TERMINAL_11 {
+            token r"set" => 11; // "Set"
+        }
+        mode __TERMINAL_12 {
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 12; // "Name"
+        }
+        mode __TERMINAL_13 {
+            token r"." => 13; // "Error"
+        }
+    }
+}
+
+const MAX_K: usize = 1;
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+
+pub const NON_TERMINALS: &[&str; 10] = &[
+    /*  0 */ "Accessor",
+    /*  1 */ "Assignment",
+    /*  2 */ "Declaration",
+    /*  3 */ "Declarations",
+    /*  4 */ "DeclarationsList",
+    /*  5 */ "Get",
+    /*  6 */ "Name",
+    /*  7 */ "Property",
+    /*  8 */ "PropertyList",
+    /*  9 */ "Set",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 10] = &[
+    /* 0 - "Accessor" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 10, 1, 8), Trans(0, 11, 2, 9)],
+        k: 1,
+    },
+    /* 1 - "Assignment" */
+    LookaheadDFA {
+        prod0: 10,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "Declaration" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 1, 3), Trans(0, 12, 2, 4)],
+        k: 1,
+    },
+    /* 3 - "Declarations" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "DeclarationsList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 5, 1, 1), Trans(0, 12, 1, 1)],
+        k: 1,
+    },
+    /* 5 - "Get" */
+    LookaheadDFA {
+        prod0: 11,
+        transitions: &[],
+        k: 0,
+    },
+    /* 6 - "Name" */
+    LookaheadDFA {
+        prod0: 13,
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "Property" */
+    LookaheadDFA {
+        prod0: 5,
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "PropertyList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 7, 2, 7), Trans(0, 10, 1, 6), Trans(0, 11, 1, 6)],
+        k: 1,
+    },
+    /* 9 - "Set" */
+    LookaheadDFA {
+        prod0: 12,
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 14] = &[
+    // 0 - Declarations: DeclarationsList /* Vec */;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(4)],
+        is_push_production: false,
+    },
+    // 1 - DeclarationsList: Declaration DeclarationsList;
+    Production {
+        lhs: 4,
+        production: &[ParseType::N(4), ParseType::N(2)],
+        is_push_production: true,
+    },
+    // 2 - DeclarationsList: ;
+    Production {
+        lhs: 4,
+        production: &[],
+        is_push_production: false,
+    },
+    // 3 - Declaration: Property;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(7)],
+        is_push_production: false,
+    },
+    // 4 - Declaration: Assignment;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(1)],
+        is_push_production: false,
+    },
+    // 5 - Property: 'property'^ /* Clipped */ Name '{'^ /* Clipped */ PropertyList /* Vec */ '}'^ /* Clipped */;
+    Production {
+        lhs: 7,
+        production: &[
+            ParseType::T(7),
+            ParseType::N(8),
+            ParseType::T(6),
+            ParseType::N(6),
+            ParseType::T(5),
+        ],
+        is_push_production: false,
+    },
+    // 6 - PropertyList: Accessor PropertyList;
+    Production {
+        lhs: 8,
+        production: &[ParseType::N(8), ParseType::N(0)],
+        is_push_production: true,
+    },
+    // 7 - PropertyList: ;
+    Production {
+        lhs: 8,
+        production: &[],
+        is_push_production: false,
+    },
+    // 8 - Accessor: Get ';'^ /* Clipped */;
+    Production {
+        lhs: 0,
+        production: &[ParseType::T(8), ParseType::N(5)],
+        is_push_production: false,
+    },
+    // 9 - Accessor: Set ';'^ /* Clipped */;
+    Production {
+        lhs: 0,
+        production: &[ParseType::T(8), ParseType::N(9)],
+        is_push_production: false,
+    },
+    // 10 - Assignment: Name '='^ /* Clipped */ Name ';'^ /* Clipped */;
+    Production {
+        lhs: 1,
+        production: &[
+            ParseType::T(8),
+            ParseType::N(6),
+            ParseType::T(9),
+            ParseType::N(6),
+        ],
+        is_push_production: false,
+    },
+    // 11 - Get: 'get';
+    Production {
+        lhs: 5,
+        production: &[ParseType::T(10)],
+        is_push_production: false,
+    },
+    // 12 - Set: 'set';
+    Production {
+        lhs: 9,
+        production: &[ParseType::T(11)],
+        is_push_production: false,
+    },
+    // 13 - Name: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    Production {
+        lhs: 6,
+        production: &[ParseType::T(12)],
+        is_push_production: false,
+    },
+];
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut ContextualKeywordsGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    parse_into(input, &mut builder, file_name, user_actions)?;
+    Ok(builder.build()?)
+}
+#[allow(dead_code)]
+pub fn parse_into<'t, T: TreeConstruct<'t>>(
+    input: &'t str,
+    tree_builder: &mut T,
+    file_name: impl AsRef<Path>,
+    user_actions: &mut ContextualKeywordsGrammar<'t>,
+) -> Result<(), ParolError>
+where
+    ParolError: From<T::Error>,
+{
+    use contextual_keywords_grammar_scanner::ContextualKeywordsGrammarScanner;
+    let mut llk_parser = LLKParser::new(
+        3,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let scanner = ContextualKeywordsGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = ContextualKeywordsGrammarAuto::new(user_actions);
+    llk_parser.parse_into(
+        tree_builder,
+        TokenStream::new_with_options(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &ContextualKeywordsGrammarScanner::match_function,
+            MAX_K,
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: &[],
+                contextual_lexing: true,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+        )?,
+        &mut user_actions,
+    )
+}
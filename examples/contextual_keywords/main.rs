@@ -0,0 +1,46 @@
+extern crate parol_runtime;
+
+mod contextual_keywords_grammar;
+mod contextual_keywords_grammar_trait;
+mod contextual_keywords_parser;
+
+use crate::contextual_keywords_grammar::ContextualKeywordsGrammar;
+use crate::contextual_keywords_parser::parse;
+use anyhow::{Context, Result, anyhow};
+use parol_runtime::Report;
+use parol_runtime::log::debug;
+use std::env;
+use std::fs;
+
+// To generate:
+// parol -f ./examples/contextual_keywords/contextual_keywords.par -e ./examples/contextual_keywords/contextual_keywords-exp.par -p ./examples/contextual_keywords/contextual_keywords_parser.rs -a ./examples/contextual_keywords/contextual_keywords_grammar_trait.rs -t ContextualKeywordsGrammar -m contextual_keywords_grammar -b --contextual-lexing
+
+// To run the example
+// cargo run --example contextual_keywords -- ./examples/contextual_keywords/contextual_keywords_test.txt
+struct ContextualKeywordsErrorReporter;
+impl Report for ContextualKeywordsErrorReporter {}
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let input = fs::read_to_string(file_name.clone())
+            .with_context(|| format!("Can't read file {}", file_name))?;
+        let mut contextual_keywords_grammar = ContextualKeywordsGrammar::new();
+        match parse(&input, &file_name, &mut contextual_keywords_grammar) {
+            Ok(_) => {
+                print!("{}", contextual_keywords_grammar);
+                Ok(())
+            }
+            Err(e) => {
+                ContextualKeywordsErrorReporter::report_error(&e, file_name).unwrap_or(());
+                Err(anyhow!("Parsing failed!"))
+            }
+        }
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
@@ -0,0 +1,218 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{LexerError, Token};
+use scnr2::scanner;
+use std::path::Path;
+
+#[allow(dead_code)]
+pub const TERMINAL_NAMES: &[&str; 14] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Fn",
+    /*  6 */ "Let",
+    /*  7 */ "Return",
+    /*  8 */ "Identifier",
+    /*  9 */ "Number",
+    /* 10 */ "Punctuation",
+    /* 11 */ "Quote",
+    /* 12 */ "Text",
+    /* 13 */ "Error",
+];
+
+scanner! {
+    HighlighterScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"fn" => 5; // "Fn"
+            token r"let" => 6; // "Let"
+            token r"return" => 7; // "Return"
+            token r"[a-zA-Z_]\w*" => 8; // "Identifier"
+            token r"[0-9]+" => 9; // "Number"
+            token r"[(){};=+*\/-]" => 10; // "Punctuation"
+            token r#"""# => 11; // "Quote"
+            token r"." => 13; // "Error"
+            on 11 enter STRING;
+        }
+        mode STRING {
+            token r#"""# => 11; // "Quote"
+            token r#"[^"]+"# => 12; // "Text"
+            token r"." => 13; // "Error"
+            on 11 enter INITIAL;
+        }
+    }
+}
+
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 2] = &[&[], &[]];
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TerminalKind {
+    NewLine,
+    Whitespace,
+    LineComment,
+    BlockComment,
+    Fn,
+    Let,
+    Return,
+    Identifier,
+    Number,
+    Punctuation,
+    Quote,
+    Text,
+}
+
+#[allow(dead_code)]
+impl TerminalKind {
+    pub fn from_terminal_index(index: u16) -> Self {
+        match index {
+            1 => Self::NewLine,
+            2 => Self::Whitespace,
+            3 => Self::LineComment,
+            4 => Self::BlockComment,
+            5 => Self::Fn,
+            6 => Self::Let,
+            7 => Self::Return,
+            8 => Self::Identifier,
+            9 => Self::Number,
+            10 => Self::Punctuation,
+            11 => Self::Quote,
+            12 => Self::Text,
+            _ => panic!("Invalid terminal index: {}", index),
+        }
+    }
+    pub fn is_builtin_terminal(&self) -> bool {
+        matches!(
+            self,
+            TerminalKind::NewLine
+                | TerminalKind::Whitespace
+                | TerminalKind::LineComment
+                | TerminalKind::BlockComment
+        )
+    }
+    pub fn is_builtin_new_line(&self) -> bool {
+        matches!(self, TerminalKind::NewLine)
+    }
+    pub fn is_builtin_whitespace(&self) -> bool {
+        matches!(self, TerminalKind::Whitespace)
+    }
+    pub fn is_builtin_line_comment(&self) -> bool {
+        matches!(self, TerminalKind::LineComment)
+    }
+    pub fn is_builtin_block_comment(&self) -> bool {
+        matches!(self, TerminalKind::BlockComment)
+    }
+}
+
+impl std::fmt::Display for TerminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NewLine => write!(f, stringify!(NewLine)),
+            Self::Whitespace => write!(f, stringify!(Whitespace)),
+            Self::LineComment => write!(f, stringify!(LineComment)),
+            Self::BlockComment => write!(f, stringify!(BlockComment)),
+            Self::Fn => write!(f, stringify!(Fn)),
+            Self::Let => write!(f, stringify!(Let)),
+            Self::Return => write!(f, stringify!(Return)),
+            Self::Identifier => write!(f, stringify!(Identifier)),
+            Self::Number => write!(f, stringify!(Number)),
+            Self::Punctuation => write!(f, stringify!(Punctuation)),
+            Self::Quote => write!(f, stringify!(Quote)),
+            Self::Text => write!(f, stringify!(Text)),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl TerminalKind {
+    /// Returns the kind of the given token or None for unmatched text and the error terminal
+    pub fn from_token(token: &Token<'_>) -> Option<Self> {
+        match token.token_type {
+            1 => Some(Self::NewLine),
+            2 => Some(Self::Whitespace),
+            3 => Some(Self::LineComment),
+            4 => Some(Self::BlockComment),
+            5 => Some(Self::Fn),
+            6 => Some(Self::Let),
+            7 => Some(Self::Return),
+            8 => Some(Self::Identifier),
+            9 => Some(Self::Number),
+            10 => Some(Self::Punctuation),
+            11 => Some(Self::Quote),
+            12 => Some(Self::Text),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Iterator over the tokens of an input, use [TerminalKind::from_token] to obtain their kinds.
+///
+/// By default it yields the tokens a parser would receive. With `with_skip_tokens(true)` also
+/// newlines, whitespace, comments and unmatched text are yielded, so that the tokens cover the
+/// whole input.
+///
+pub struct Tokenizer<'t> {
+    tokenizer: parol_runtime::lexer::Tokenizer<'t, fn(char) -> Option<usize>>,
+}
+
+#[allow(dead_code)]
+impl<'t> Tokenizer<'t> {
+    /// Creates a tokenizer for the given input
+    pub fn new<T>(input: &'t str, file_name: T) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {
+        use highlighter_scanner::HighlighterScanner;
+        let scanner = HighlighterScanner::new();
+        let tokenizer = parol_runtime::lexer::Tokenizer::new(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &(HighlighterScanner::match_function as fn(char) -> Option<usize>),
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: &[],
+                contextual_lexing: false,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+        )?;
+        Ok(Self { tokenizer })
+    }
+
+    /// Sets whether newlines, whitespace, comments and unmatched text are yielded, too
+    pub fn with_skip_tokens(self, skip_tokens: bool) -> Self {
+        Self {
+            tokenizer: self.tokenizer.with_skip_tokens(skip_tokens),
+        }
+    }
+
+    /// Returns the index of the scanner state in which the scanning continues
+    pub fn scanner_state(&self) -> usize {
+        self.tokenizer.scanner_state()
+    }
+
+    /// Returns the name of the scanner state in which the scanning continues
+    pub fn scanner_state_name(&self) -> &str {
+        self.tokenizer.scanner_state_name()
+    }
+}
+
+impl<'t> Iterator for Tokenizer<'t> {
+    type Item = Result<Token<'t>, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokenizer.next()
+    }
+}
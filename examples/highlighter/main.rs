@@ -0,0 +1,56 @@
+extern crate parol_runtime;
+
+mod highlighter_tokenizer;
+
+use crate::highlighter_tokenizer::{TerminalKind, Tokenizer};
+use anyhow::{Context, Result, anyhow};
+use parol_runtime::log::debug;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+
+// To generate:
+// parol -f ./examples/highlighter/highlighter.par -e ./examples/highlighter/highlighter-exp.par -p ./examples/highlighter/highlighter_tokenizer.rs -t Highlighter --lexer-only
+
+// To run the example
+// cargo run --example highlighter -- ./examples/highlighter/highlighter_test.txt
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let input = fs::read_to_string(file_name.clone())
+            .with_context(|| format!("Can't read file {}", file_name))?;
+        // Comments and whitespace are needed to reproduce the input
+        let mut tokenizer = Tokenizer::new(&input, &file_name)?.with_skip_tokens(true);
+        let mut counts = BTreeMap::<String, usize>::new();
+        while let Some(token) = tokenizer.next() {
+            let token = token?;
+            let kind = TerminalKind::from_token(&token)
+                .map_or_else(|| "Unknown".to_string(), |kind| kind.to_string());
+            if !matches!(
+                TerminalKind::from_token(&token),
+                Some(TerminalKind::NewLine | TerminalKind::Whitespace)
+            ) {
+                println!(
+                    "{}:{} {kind} {:?} -> {}",
+                    token.location.start_line,
+                    token.location.start_column,
+                    token.text(),
+                    tokenizer.scanner_state_name()
+                );
+            }
+            *counts.entry(kind).or_default() += 1;
+        }
+        println!("Token counts:");
+        counts
+            .iter()
+            .for_each(|(kind, count)| println!("{kind}: {count}"));
+        Ok(())
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::includes_grammar_trait::{Assignment, IncludesGrammarTrait};
+use parol_runtime::Result;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+///
+/// Data structure that implements the semantic actions for our includes grammar
+///
+#[derive(Debug, Default)]
+pub struct IncludesGrammar<'t> {
+    pub assignments: Vec<Assignment<'t>>,
+}
+
+impl IncludesGrammar<'_> {
+    pub fn new() -> Self {
+        IncludesGrammar::default()
+    }
+}
+
+impl Display for IncludesGrammar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        self.assignments.iter().try_for_each(|a| {
+            let location = &a.name.name.location;
+            writeln!(
+                f,
+                "{} = {} ({}:{})",
+                a.name.name.text(),
+                a.number.number.text(),
+                location.file_name.display(),
+                location.start_line
+            )
+        })
+    }
+}
+
+impl<'t> IncludesGrammarTrait<'t> for IncludesGrammar<'t> {
+    /// Semantic action for non-terminal 'Assignment'
+    fn assignment(&mut self, arg: &Assignment<'t>) -> Result<()> {
+        self.assignments.push(arg.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,470 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{ParserError, Result, Token};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait IncludesGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'Program'
+    fn program(&mut self, _arg: &Program<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Statement'
+    fn statement(&mut self, _arg: &Statement<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Include'
+    fn include(&mut self, _arg: &Include<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Assignment'
+    fn assignment(&mut self, _arg: &Assignment<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'FileName'
+    fn file_name(&mut self, _arg: &FileName<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Name'
+    fn name(&mut self, _arg: &Name<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Number'
+    fn number(&mut self, _arg: &Number<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// This method provides skipped language comments.
+    /// If you need comments please provide your own implementation of this method.
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 3
+///
+/// `Statement: Include;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StatementInclude<'t> {
+    pub include: Include<'t>,
+}
+
+///
+/// Type derived for production 4
+///
+/// `Statement: Assignment;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StatementAssignment<'t> {
+    pub assignment: Assignment<'t>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Assignment
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Assignment<'t> {
+    pub name: Name<'t>,
+    pub equ: Token<'t>, /* = */
+    pub number: Number<'t>,
+    pub semicolon: Token<'t>, /* ; */
+}
+
+///
+/// Type derived for non-terminal FileName
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct FileName<'t> {
+    pub file_name: Token<'t>, /* "[^"]*" */
+}
+
+///
+/// Type derived for non-terminal Include
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Include<'t> {
+    pub hash_include: Token<'t>, /* #include */
+    pub file_name: FileName<'t>,
+    pub semicolon: Token<'t>, /* ; */
+}
+
+///
+/// Type derived for non-terminal Name
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Name<'t> {
+    pub name: Token<'t>, /* [a-zA-Z_]\w* */
+}
+
+///
+/// Type derived for non-terminal Number
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Number<'t> {
+    pub number: Token<'t>, /* [0-9]+ */
+}
+
+///
+/// Type derived for non-terminal Program
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Program<'t> {
+    pub program_list: Vec<ProgramList<'t>>,
+}
+
+///
+/// Type derived for non-terminal ProgramList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ProgramList<'t> {
+    pub statement: Statement<'t>,
+}
+
+///
+/// Type derived for non-terminal Statement
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Statement<'t> {
+    Include(StatementInclude<'t>),
+    Assignment(StatementAssignment<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Assignment(Assignment<'t>),
+    FileName(FileName<'t>),
+    Include(Include<'t>),
+    Name(Name<'t>),
+    Number(Number<'t>),
+    Program(Program<'t>),
+    ProgramList(Vec<ProgramList<'t>>),
+    Statement(Statement<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct IncludesGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn IncludesGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `IncludesGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> IncludesGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn IncludesGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {context}: {item:?}");
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        let item = self.item_stack.pop();
+        if let Some(ref item) = item {
+            trace!("pop     {context}: {item:?}");
+        }
+        item
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {s:?}"))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// `Program: ProgramList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn program(&mut self, _program_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let program_list = pop_and_reverse_item!(self, program_list, ProgramList, context);
+        let program_built = Program { program_list };
+        // Calling user action here
+        self.user_grammar.program(&program_built)?;
+        self.push(ASTType::Program(program_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// `ProgramList /* Vec<T>::Push */: Statement ProgramList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn program_list_0(
+        &mut self,
+        _statement: &ParseTreeType<'t>,
+        _program_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut program_list = pop_item!(self, program_list, ProgramList, context);
+        let statement = pop_item!(self, statement, Statement, context);
+        let program_list_0_built = ProgramList { statement };
+        // Add an element to the vector
+        program_list.push(program_list_0_built);
+        self.push(ASTType::ProgramList(program_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// `ProgramList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn program_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let program_list_1_built = Vec::new();
+        self.push(ASTType::ProgramList(program_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Statement: Include;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_0(&mut self, _include: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let include = pop_item!(self, include, Include, context);
+        let statement_0_built = StatementInclude { include };
+        let statement_0_built = Statement::Include(statement_0_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_0_built)?;
+        self.push(ASTType::Statement(statement_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `Statement: Assignment;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_1(&mut self, _assignment: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assignment = pop_item!(self, assignment, Assignment, context);
+        let statement_1_built = StatementAssignment { assignment };
+        let statement_1_built = Statement::Assignment(statement_1_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_1_built)?;
+        self.push(ASTType::Statement(statement_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// `Include: '#include' FileName ';';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn include(
+        &mut self,
+        hash_include: &ParseTreeType<'t>,
+        _file_name: &ParseTreeType<'t>,
+        semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let hash_include = hash_include.token()?.clone();
+        let semicolon = semicolon.token()?.clone();
+        let file_name = pop_item!(self, file_name, FileName, context);
+        let include_built = Include {
+            hash_include,
+            file_name,
+            semicolon,
+        };
+        // Calling user action here
+        self.user_grammar.include(&include_built)?;
+        self.push(ASTType::Include(include_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// `Assignment: Name '=' Number ';';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assignment(
+        &mut self,
+        _name: &ParseTreeType<'t>,
+        equ: &ParseTreeType<'t>,
+        _number: &ParseTreeType<'t>,
+        semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let equ = equ.token()?.clone();
+        let semicolon = semicolon.token()?.clone();
+        let number = pop_item!(self, number, Number, context);
+        let name = pop_item!(self, name, Name, context);
+        let assignment_built = Assignment {
+            name,
+            equ,
+            number,
+            semicolon,
+        };
+        // Calling user action here
+        self.user_grammar.assignment(&assignment_built)?;
+        self.push(ASTType::Assignment(assignment_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// `FileName: /"[^"]*"/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn file_name(&mut self, file_name: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let file_name = file_name.token()?.clone();
+        let file_name_built = FileName { file_name };
+        // Calling user action here
+        self.user_grammar.file_name(&file_name_built)?;
+        self.push(ASTType::FileName(file_name_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// `Name: /[a-zA-Z_]\w*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn name(&mut self, name: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name = name.token()?.clone();
+        let name_built = Name { name };
+        // Calling user action here
+        self.user_grammar.name(&name_built)?;
+        self.push(ASTType::Name(name_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// `Number: /[0-9]+/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn number(&mut self, number: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let number = number.token()?.clone();
+        let number_built = Number { number };
+        // Calling user action here
+        self.user_grammar.number(&number_built)?;
+        self.push(ASTType::Number(number_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for IncludesGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item IncludesGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.program(&children[0]),
+            1 => self.program_list_0(&children[0], &children[1]),
+            2 => self.program_list_1(),
+            3 => self.statement_0(&children[0]),
+            4 => self.statement_1(&children[0]),
+            5 => self.include(&children[0], &children[1], &children[2]),
+            6 => self.assignment(&children[0], &children[1], &children[2], &children[3]),
+            7 => self.file_name(&children[0]),
+            8 => self.name(&children[0]),
+            9 => self.number(&children[0]),
+            _ => Err(ParserError::InternalError(format!(
+                "Unhandled production number: {prod_num}"
+            ))
+            .into()),
+        }
+    }
+
+    fn on_comment(&mut self, token: Token<'t>) {
+        self.user_grammar.on_comment(token)
+    }
+}
@@ -0,0 +1,261 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{
+    ParolError, ParseTree, TokenStream,
+    parser::{
+        LLKParser, LookaheadDFA, ParseType, Production, Trans, parse_tree_type::TreeConstruct,
+    },
+};
+use scnr2::scanner;
+use std::path::Path;
+
+use crate::includes_grammar::IncludesGrammar;
+use crate::includes_grammar_trait::IncludesGrammarAuto;
+
+pub const TERMINAL_NAMES: &[&str; 12] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "HashInclude",
+    /*  6 */ "Semicolon",
+    /*  7 */ "Equ",
+    /*  8 */ "FileName",
+    /*  9 */ "Name",
+    /* 10 */ "Number",
+    /* 11 */ "Error",
+];
+
+scanner! {
+    IncludesGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"\#include" => 5; // "HashInclude"
+            token r";" => 6; // "Semicolon"
+            token r"=" => 7; // "Equ"
+            token r#""[^"]*""# => 8; // "FileName"
+            token r"[a-zA-Z_]\w*" => 9; // "Name"
+            token r"[0-9]+" => 10; // "Number"
+            token r"." => 11; // "Error"
+        }
+    }
+}
+
+const MAX_K: usize = 1;
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+
+pub const NON_TERMINALS: &[&str; 8] = &[
+    /* 0 */ "Assignment",
+    /* 1 */ "FileName",
+    /* 2 */ "Include",
+    /* 3 */ "Name",
+    /* 4 */ "Number",
+    /* 5 */ "Program",
+    /* 6 */ "ProgramList",
+    /* 7 */ "Statement",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 8] = &[
+    /* 0 - "Assignment" */
+    LookaheadDFA {
+        prod0: 6,
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "FileName" */
+    LookaheadDFA {
+        prod0: 7,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "Include" */
+    LookaheadDFA {
+        prod0: 5,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "Name" */
+    LookaheadDFA {
+        prod0: 8,
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "Number" */
+    LookaheadDFA {
+        prod0: 9,
+        transitions: &[],
+        k: 0,
+    },
+    /* 5 - "Program" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 6 - "ProgramList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 5, 1, 1), Trans(0, 9, 1, 1)],
+        k: 1,
+    },
+    /* 7 - "Statement" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 1, 3), Trans(0, 9, 2, 4)],
+        k: 1,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 10] = &[
+    // 0 - Program: ProgramList /* Vec */;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(6)],
+        is_push_production: false,
+    },
+    // 1 - ProgramList: Statement ProgramList;
+    Production {
+        lhs: 6,
+        production: &[ParseType::N(6), ParseType::N(7)],
+        is_push_production: true,
+    },
+    // 2 - ProgramList: ;
+    Production {
+        lhs: 6,
+        production: &[],
+        is_push_production: false,
+    },
+    // 3 - Statement: Include;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(2)],
+        is_push_production: false,
+    },
+    // 4 - Statement: Assignment;
+    Production {
+        lhs: 7,
+        production: &[ParseType::N(0)],
+        is_push_production: false,
+    },
+    // 5 - Include: '#include' FileName ';';
+    Production {
+        lhs: 2,
+        production: &[ParseType::T(6), ParseType::N(1), ParseType::T(5)],
+        is_push_production: false,
+    },
+    // 6 - Assignment: Name '=' Number ';';
+    Production {
+        lhs: 0,
+        production: &[
+            ParseType::T(6),
+            ParseType::N(4),
+            ParseType::T(7),
+            ParseType::N(3),
+        ],
+        is_push_production: false,
+    },
+    // 7 - FileName: /"[^"]*"/;
+    Production {
+        lhs: 1,
+        production: &[ParseType::T(8)],
+        is_push_production: false,
+    },
+    // 8 - Name: /[a-zA-Z_]\w*/;
+    Production {
+        lhs: 3,
+        production: &[ParseType::T(9)],
+        is_push_production: false,
+    },
+    // 9 - Number: /[0-9]+/;
+    Production {
+        lhs: 4,
+        production: &[ParseType::T(10)],
+        is_push_production: false,
+    },
+];
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut IncludesGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    parse_into(input, &mut builder, file_name, user_actions)?;
+    Ok(builder.build()?)
+}
+#[allow(dead_code)]
+pub fn parse_into<'t, T: TreeConstruct<'t>>(
+    input: &'t str,
+    tree_builder: &mut T,
+    file_name: impl AsRef<Path>,
+    user_actions: &mut IncludesGrammar<'t>,
+) -> Result<(), ParolError>
+where
+    ParolError: From<T::Error>,
+{
+    use includes_grammar_scanner::IncludesGrammarScanner;
+    let mut llk_parser = LLKParser::new(
+        5,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let scanner = IncludesGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = IncludesGrammarAuto::new(user_actions);
+    llk_parser.parse_into(
+        tree_builder,
+        TokenStream::new_with_skip_tokens(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &IncludesGrammarScanner::match_function,
+            MAX_K,
+            SKIP_TOKENS_BY_SCANNER_STATE,
+        )
+        .unwrap(),
+        &mut user_actions,
+    )
+}
+
+///
+/// Parses the given tokens instead of scanning an input text, e.g. the output of a preprocessor.
+/// The token types are the indices into `TERMINAL_NAMES`. The locations of the tokens are kept,
+/// so they may point into different files.
+///
+#[allow(dead_code)]
+pub fn parse_tokens<'t, I>(
+    tokens: I,
+    user_actions: &mut IncludesGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    I: IntoIterator<Item = parol_runtime::Token<'t>>,
+    I::IntoIter: 't,
+{
+    let mut llk_parser = LLKParser::new(
+        5,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+
+    // Initialize wrapper
+    let mut user_actions = IncludesGrammarAuto::new(user_actions);
+    llk_parser.parse_tokens(tokens, &mut user_actions)
+}
@@ -0,0 +1,200 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{LexerError, Token};
+use scnr2::scanner;
+use std::path::Path;
+
+#[allow(dead_code)]
+pub const TERMINAL_NAMES: &[&str; 12] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "HashInclude",
+    /*  6 */ "Semicolon",
+    /*  7 */ "Equ",
+    /*  8 */ "FileName",
+    /*  9 */ "Name",
+    /* 10 */ "Number",
+    /* 11 */ "Error",
+];
+
+scanner! {
+    IncludesGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"\#include" => 5; // "HashInclude"
+            token r";" => 6; // "Semicolon"
+            token r"=" => 7; // "Equ"
+            token r#""[^"]*""# => 8; // "FileName"
+            token r"[a-zA-Z_]\w*" => 9; // "Name"
+            token r"[0-9]+" => 10; // "Number"
+            token r"." => 11; // "Error"
+        }
+    }
+}
+
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TerminalKind {
+    NewLine,
+    Whitespace,
+    LineComment,
+    BlockComment,
+    HashInclude,
+    Semicolon,
+    Equ,
+    FileName,
+    Name,
+    Number,
+}
+
+#[allow(dead_code)]
+impl TerminalKind {
+    pub fn from_terminal_index(index: u16) -> Self {
+        match index {
+            1 => Self::NewLine,
+            2 => Self::Whitespace,
+            3 => Self::LineComment,
+            4 => Self::BlockComment,
+            5 => Self::HashInclude,
+            6 => Self::Semicolon,
+            7 => Self::Equ,
+            8 => Self::FileName,
+            9 => Self::Name,
+            10 => Self::Number,
+            _ => panic!("Invalid terminal index: {}", index),
+        }
+    }
+    pub fn is_builtin_terminal(&self) -> bool {
+        matches!(
+            self,
+            TerminalKind::NewLine
+                | TerminalKind::Whitespace
+                | TerminalKind::LineComment
+                | TerminalKind::BlockComment
+        )
+    }
+    pub fn is_builtin_new_line(&self) -> bool {
+        matches!(self, TerminalKind::NewLine)
+    }
+    pub fn is_builtin_whitespace(&self) -> bool {
+        matches!(self, TerminalKind::Whitespace)
+    }
+    pub fn is_builtin_line_comment(&self) -> bool {
+        matches!(self, TerminalKind::LineComment)
+    }
+    pub fn is_builtin_block_comment(&self) -> bool {
+        matches!(self, TerminalKind::BlockComment)
+    }
+}
+
+impl std::fmt::Display for TerminalKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NewLine => write!(f, stringify!(NewLine)),
+            Self::Whitespace => write!(f, stringify!(Whitespace)),
+            Self::LineComment => write!(f, stringify!(LineComment)),
+            Self::BlockComment => write!(f, stringify!(BlockComment)),
+            Self::HashInclude => write!(f, stringify!(HashInclude)),
+            Self::Semicolon => write!(f, stringify!(Semicolon)),
+            Self::Equ => write!(f, stringify!(Equ)),
+            Self::FileName => write!(f, stringify!(FileName)),
+            Self::Name => write!(f, stringify!(Name)),
+            Self::Number => write!(f, stringify!(Number)),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl TerminalKind {
+    /// Returns the kind of the given token or None for unmatched text and the error terminal
+    pub fn from_token(token: &Token<'_>) -> Option<Self> {
+        match token.token_type {
+            1 => Some(Self::NewLine),
+            2 => Some(Self::Whitespace),
+            3 => Some(Self::LineComment),
+            4 => Some(Self::BlockComment),
+            5 => Some(Self::HashInclude),
+            6 => Some(Self::Semicolon),
+            7 => Some(Self::Equ),
+            8 => Some(Self::FileName),
+            9 => Some(Self::Name),
+            10 => Some(Self::Number),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Iterator over the tokens of an input, use [TerminalKind::from_token] to obtain their kinds.
+///
+/// By default it yields the tokens a parser would receive. With `with_skip_tokens(true)` also
+/// newlines, whitespace, comments and unmatched text are yielded, so that the tokens cover the
+/// whole input.
+///
+pub struct Tokenizer<'t> {
+    tokenizer: parol_runtime::lexer::Tokenizer<'t, fn(char) -> Option<usize>>,
+}
+
+#[allow(dead_code)]
+impl<'t> Tokenizer<'t> {
+    /// Creates a tokenizer for the given input
+    pub fn new<T>(input: &'t str, file_name: T) -> Result<Self, LexerError>
+    where
+        T: AsRef<Path>,
+    {
+        use includes_grammar_scanner::IncludesGrammarScanner;
+        let scanner = IncludesGrammarScanner::new();
+        let tokenizer = parol_runtime::lexer::Tokenizer::new(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &(IncludesGrammarScanner::match_function as fn(char) -> Option<usize>),
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: &[],
+                contextual_lexing: false,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+        )?;
+        Ok(Self { tokenizer })
+    }
+
+    /// Sets whether newlines, whitespace, comments and unmatched text are yielded, too
+    pub fn with_skip_tokens(self, skip_tokens: bool) -> Self {
+        Self {
+            tokenizer: self.tokenizer.with_skip_tokens(skip_tokens),
+        }
+    }
+
+    /// Returns the index of the scanner state in which the scanning continues
+    pub fn scanner_state(&self) -> usize {
+        self.tokenizer.scanner_state()
+    }
+
+    /// Returns the name of the scanner state in which the scanning continues
+    pub fn scanner_state_name(&self) -> &str {
+        self.tokenizer.scanner_state_name()
+    }
+}
+
+impl<'t> Iterator for Tokenizer<'t> {
+    type Item = Result<Token<'t>, LexerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokenizer.next()
+    }
+}
@@ -0,0 +1,83 @@
+extern crate parol_runtime;
+
+mod includes_grammar;
+mod includes_grammar_trait;
+mod includes_parser;
+mod includes_tokenizer;
+
+use crate::includes_grammar::IncludesGrammar;
+use crate::includes_parser::{parse, parse_tokens};
+use crate::includes_tokenizer::{TerminalKind, Tokenizer};
+use anyhow::{Context, Result, anyhow, bail};
+use parol_runtime::log::debug;
+use parol_runtime::{Report, Token};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// To generate:
+// parol -f ./examples/includes/includes.par -e ./examples/includes/includes-exp.par -p ./examples/includes/includes_parser.rs -a ./examples/includes/includes_grammar_trait.rs -t IncludesGrammar -m includes_grammar -b --token-input
+// parol -f ./examples/includes/includes.par -p ./examples/includes/includes_tokenizer.rs -t IncludesGrammar --lexer-only
+
+// To run the example
+// cargo run --example includes -- ./examples/includes/includes_test.txt
+
+struct IncludesErrorReporter;
+impl Report for IncludesErrorReporter {}
+
+/// Limits the nesting of includes, which also stops cyclic includes
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+///
+/// Scans the given file and replaces each `#include "file";` by the tokens of the included file.
+/// The file name is relative to the including file.
+///
+/// Each file is parsed on its own first, so that syntax errors are reported with the source of
+/// the file. The grammar accepts includes for this purpose.
+///
+fn expand_includes(file_name: &Path, depth: usize, tokens: &mut Vec<Token<'static>>) -> Result<()> {
+    if depth > MAX_INCLUDE_DEPTH {
+        bail!("Includes are nested too deeply in {}", file_name.display());
+    }
+    let input = fs::read_to_string(file_name)
+        .with_context(|| format!("Can't read file {}", file_name.display()))?;
+    if let Err(e) = parse(&input, file_name, &mut IncludesGrammar::new()) {
+        IncludesErrorReporter::report_error(&e, file_name).unwrap_or(());
+        bail!("Parsing of {} failed!", file_name.display());
+    }
+    let mut tokenizer = Tokenizer::new(&input, file_name)?;
+    while let Some(token) = tokenizer.next() {
+        let token = token?;
+        if TerminalKind::from_token(&token) != Some(TerminalKind::HashInclude) {
+            // The tokens have to outlive the input of their file
+            tokens.push(token.into_owned());
+            continue;
+        }
+        // The syntax was checked, so the file name and the semicolon follow
+        let included = tokenizer.next().context("Missing file name")??;
+        tokenizer.next();
+        let included = included.text().trim_matches('"');
+        let included = file_name.parent().unwrap_or(Path::new("")).join(included);
+        expand_includes(&included, depth + 1, tokens)?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let mut tokens = Vec::new();
+        expand_includes(Path::new(&file_name), 0, &mut tokens)?;
+        let mut includes_grammar = IncludesGrammar::new();
+        // The tokens stem from several files, so the errors are reported without source
+        parse_tokens(tokens, &mut includes_grammar).map_err(|e| anyhow!("Parsing failed: {e}"))?;
+        print!("{}", includes_grammar);
+        Ok(())
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
@@ -0,0 +1,81 @@
+extern crate parol_runtime;
+
+mod json_grammar;
+// The output is version controlled
+mod json_grammar_trait;
+mod json_parser;
+
+use crate::json_grammar::JsonGrammar;
+use crate::json_parser::parse;
+use parol_runtime::Report;
+use parol_runtime::arena::Bump;
+use parol_runtime::log::debug;
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+use std::time::Instant;
+
+// To generate:
+// parol -f ./json.par -e ./json-exp.par -p ./json_parser.rs -a ./json_grammar_trait.rs -t JsonGrammar -m json_grammar -x -b --arena
+
+struct JSONErrorReporter;
+impl Report for JSONErrorReporter {}
+
+fn main() -> ExitCode {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let input = match fs::read_to_string(file_name.clone()) {
+            Ok(input) => input,
+            Err(_) => {
+                println!("Can't read file {}", file_name);
+                return ExitCode::FAILURE;
+            }
+        };
+        // The nodes of the AST are allocated in the arena and released all at once
+        let arena = Bump::new();
+        let mut json_grammar = JsonGrammar::new();
+        let now = Instant::now();
+        match parse(&input, &file_name, &mut json_grammar, &arena) {
+            Ok(_) => {
+                let elapsed_time = now.elapsed();
+                if args.len() > 2 && args[2] == "-q" {
+                    println!("Parsing took {} milliseconds.", elapsed_time.as_millis());
+                    ExitCode::SUCCESS
+                } else {
+                    println!("Success!\n{}", json_grammar);
+                    println!("Parsing took {} milliseconds.", elapsed_time.as_millis());
+                    ExitCode::SUCCESS
+                }
+            }
+            Err(e) => {
+                let _ = JSONErrorReporter::report_error(&e, file_name);
+                ExitCode::FAILURE
+            }
+        }
+    } else {
+        println!("Please provide a file name as first parameter!");
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ast_is_allocated_in_the_arena() {
+        let input = r#"{"a": [1, true, {"b": null}], "c": "d"}"#;
+        let arena = Bump::new();
+        let mut json_grammar = JsonGrammar::new();
+        parse(input, "test.json", &mut json_grammar, &arena).unwrap();
+        assert_eq!(
+            r#"{"a": [1, true, {"b": null}], "c": "d"}"#,
+            json_grammar.to_string()
+        );
+        assert!(arena.allocated_bytes() > 0);
+    }
+}
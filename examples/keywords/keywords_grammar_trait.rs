@@ -101,7 +101,7 @@ pub struct ItemsBlock<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Begin<'t> {
-    pub begin: Token<'t>, /* (?i)begin */
+    pub begin: Token<'t>, /* begin */
 }
 
 ///
@@ -141,7 +141,7 @@ pub struct Declaration<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct End<'t> {
-    pub end: Token<'t>, /* (?i)end */
+    pub end: Token<'t>, /* end */
 }
 
 ///
@@ -187,7 +187,7 @@ pub enum Items<'t> {
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Var<'t> {
-    pub var: Token<'t>, /* (?i)var */
+    pub var: Token<'t>, /* var */
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -453,7 +453,7 @@ impl<'t, 'u> KeywordsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 9:
     ///
-    /// `Begin: "(?i)begin";`
+    /// `Begin: 'begin'i;`
     ///
     #[parol_runtime::function_name::named]
     fn begin(&mut self, begin: &ParseTreeType<'t>) -> Result<()> {
@@ -469,7 +469,7 @@ impl<'t, 'u> KeywordsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 10:
     ///
-    /// `End: "(?i)end";`
+    /// `End: 'end'i;`
     ///
     #[parol_runtime::function_name::named]
     fn end(&mut self, end: &ParseTreeType<'t>) -> Result<()> {
@@ -485,7 +485,7 @@ impl<'t, 'u> KeywordsGrammarAuto<'t, 'u> {
 
     /// Semantic action for production 11:
     ///
-    /// `Var: "(?i)var";`
+    /// `Var: 'var'i;`
     ///
     #[parol_runtime::function_name::named]
     fn var(&mut self, var: &ParseTreeType<'t>) -> Result<()> {
@@ -180,19 +180,19 @@ pub const PRODUCTIONS: &[Production; 13] = &[
         production: &[],
         is_push_production: false,
     },
-    // 9 - Begin: "(?i)begin";
+    // 9 - Begin: 'begin'i;
     Production {
         lhs: 0,
         production: &[ParseType::T(6)],
         is_push_production: false,
     },
-    // 10 - End: "(?i)end";
+    // 10 - End: 'end'i;
     Production {
         lhs: 4,
         production: &[ParseType::T(7)],
         is_push_production: false,
     },
-    // 11 - Var: "(?i)var";
+    // 11 - Var: 'var'i;
     Production {
         lhs: 9,
         production: &[ParseType::T(8)],
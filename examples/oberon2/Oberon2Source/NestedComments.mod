@@ -0,0 +1,13 @@
+MODULE NestedComments;
+
+(* Comments can be nested in Oberon-2.
+   (* This inner comment ends here: *)
+   and the outer comment is still active.
+*)
+
+IMPORT Out;
+
+BEGIN
+    (* (* Out.String("Never printed"); *) *)
+    Out.String("Hello, nested comments"); Out.Ln
+END NestedComments.
@@ -96,7 +96,7 @@ scanner! {
         mode INITIAL {
             token r"\r\n|\r|\n" => 1; // "Newline"
             token r"[\s--\r\n]+" => 2; // "Whitespace"
-            token r"\(\*[^*]*(\*+[^*)][^*]*)*\*+\)" => 4; // "BlockComment"
+            token r"\(\*" => 4; // "BlockComment"
             token r"BEGIN" => 5; // "KwBegin"
             token r"CASE" => 6; // "KwCase"
             token r"DO" => 7; // "KwDo"
@@ -170,6 +170,7 @@ scanner! {
 
 const MAX_K: usize = 2;
 pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+pub const NESTED_BLOCK_COMMENTS_BY_SCANNER_STATE: &[&[(&str, &str)]; 1] = &[&[("(*", "*)")]];
 
 pub const NON_TERMINALS: &[&str; 130] = &[
     /*   0 */ "AddOp",
@@ -3047,15 +3048,22 @@ where
     let mut user_actions = Oberon2GrammarAuto::new(user_actions);
     llk_parser.parse_into(
         tree_builder,
-        TokenStream::new_with_skip_tokens(
+        TokenStream::new_with_options(
             input,
             file_name,
             scanner.scanner_impl.clone(),
             &Oberon2GrammarScanner::match_function,
             MAX_K,
-            SKIP_TOKENS_BY_SCANNER_STATE,
-        )
-        .unwrap(),
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: NESTED_BLOCK_COMMENTS_BY_SCANNER_STATE,
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: &[],
+                contextual_lexing: false,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+        )?,
         &mut user_actions,
     )
 }
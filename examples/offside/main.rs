@@ -0,0 +1,46 @@
+extern crate parol_runtime;
+
+mod offside_grammar;
+mod offside_grammar_trait;
+mod offside_parser;
+
+use crate::offside_grammar::OffsideGrammar;
+use crate::offside_parser::parse;
+use anyhow::{Context, Result, anyhow};
+use parol_runtime::Report;
+use parol_runtime::log::debug;
+use std::env;
+use std::fs;
+
+// To generate:
+// parol -f ./examples/offside/offside.par -e ./examples/offside/offside-exp.par -p ./examples/offside/offside_parser.rs -a ./examples/offside/offside_grammar_trait.rs -t OffsideGrammar -m offside_grammar -b
+
+// To run the example
+// cargo run --example offside -- ./examples/offside/offside_test.txt
+struct OffsideErrorReporter;
+impl Report for OffsideErrorReporter {}
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let input = fs::read_to_string(file_name.clone())
+            .with_context(|| format!("Can't read file {}", file_name))?;
+        let mut offside_grammar = OffsideGrammar::new();
+        match parse(&input, &file_name, &mut offside_grammar) {
+            Ok(_) => {
+                print!("{}", offside_grammar);
+                Ok(())
+            }
+            Err(e) => {
+                OffsideErrorReporter::report_error(&e, file_name).unwrap_or(());
+                Err(anyhow!("Parsing failed!"))
+            }
+        }
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
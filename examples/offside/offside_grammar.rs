@@ -0,0 +1,60 @@
+use crate::offside_grammar_trait::{Entry, EntryBody, EntrySuffix, OffsideGrammarTrait, Outline};
+use parol_runtime::Result;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+///
+/// Data structure that implements the semantic actions for our outline grammar
+///
+#[derive(Debug, Default)]
+pub struct OffsideGrammar<'t> {
+    pub outline: Option<Outline<'t>>,
+}
+
+impl OffsideGrammar<'_> {
+    pub fn new() -> Self {
+        OffsideGrammar::default()
+    }
+}
+
+/// Writes one line per entry, prefixed with the keys of the enclosing entries
+fn write_entry(f: &mut Formatter<'_>, path: &str, entry: &Entry<'_>) -> std::fmt::Result {
+    let key = entry.key.key.text();
+    let path = if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    };
+    match &entry.entry_suffix {
+        EntrySuffix::Eol(_) => writeln!(f, "{path}"),
+        EntrySuffix::ColonEntryBody(suffix) => match suffix.entry_body.as_ref() {
+            EntryBody::ValueEol(value) => writeln!(f, "{path} = {}", value.value.value.text()),
+            EntryBody::EolIndentEntryEntryBodyListDedent(block) => {
+                write_entry(f, &path, &block.entry)?;
+                block
+                    .entry_body_list
+                    .iter()
+                    .try_for_each(|e| write_entry(f, &path, &e.entry))
+            }
+        },
+    }
+}
+
+impl Display for OffsideGrammar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match &self.outline {
+            Some(outline) => outline
+                .outline_list
+                .iter()
+                .try_for_each(|e| write_entry(f, "", &e.entry)),
+            None => write!(f, "No parse result"),
+        }
+    }
+}
+
+impl<'t> OffsideGrammarTrait<'t> for OffsideGrammar<'t> {
+    /// Semantic action for non-terminal 'Outline'
+    fn outline(&mut self, arg: &Outline<'t>) -> Result<()> {
+        self.outline = Some(arg.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,653 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{ParserError, Result, Token};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait OffsideGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'Outline'
+    fn outline(&mut self, _arg: &Outline<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Entry'
+    fn entry(&mut self, _arg: &Entry<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EntrySuffix'
+    fn entry_suffix(&mut self, _arg: &EntrySuffix<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'EntryBody'
+    fn entry_body(&mut self, _arg: &EntryBody<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Key'
+    fn key(&mut self, _arg: &Key<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Value'
+    fn value(&mut self, _arg: &Value<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Colon'
+    fn colon(&mut self, _arg: &Colon<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Indent'
+    fn indent(&mut self, _arg: &Indent<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Dedent'
+    fn dedent(&mut self, _arg: &Dedent<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Eol'
+    fn eol(&mut self, _arg: &Eol<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// This method provides skipped language comments.
+    /// If you need comments please provide your own implementation of this method.
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 4
+///
+/// `EntrySuffix: Colon^ /* Clipped */ EntryBody;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct EntrySuffixColonEntryBody<'t> {
+    pub entry_body: Box<EntryBody<'t>>,
+}
+
+///
+/// Type derived for production 5
+///
+/// `EntrySuffix: Eol^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct EntrySuffixEol {}
+
+///
+/// Type derived for production 6
+///
+/// `EntryBody: Value Eol^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct EntryBodyValueEol<'t> {
+    pub value: Value<'t>,
+}
+
+///
+/// Type derived for production 7
+///
+/// `EntryBody: Eol^ /* Clipped */ Indent^ /* Clipped */ Entry EntryBodyList /* Vec */ Dedent^ /* Clipped */;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct EntryBodyEolIndentEntryEntryBodyListDedent<'t> {
+    pub entry: Entry<'t>,
+    pub entry_body_list: Vec<EntryBodyList<'t>>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Colon
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Colon<'t> {
+    pub colon: Token<'t>, /* : */
+}
+
+///
+/// Type derived for non-terminal Dedent
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Dedent<'t> {
+    pub dedent: Token<'t>, /* <dedent> */
+}
+
+///
+/// Type derived for non-terminal Entry
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Entry<'t> {
+    pub key: Key<'t>,
+    pub entry_suffix: EntrySuffix<'t>,
+}
+
+///
+/// Type derived for non-terminal EntryBody
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EntryBody<'t> {
+    ValueEol(EntryBodyValueEol<'t>),
+    EolIndentEntryEntryBodyListDedent(EntryBodyEolIndentEntryEntryBodyListDedent<'t>),
+}
+
+///
+/// Type derived for non-terminal EntryBodyList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct EntryBodyList<'t> {
+    pub entry: Entry<'t>,
+}
+
+///
+/// Type derived for non-terminal EntrySuffix
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum EntrySuffix<'t> {
+    ColonEntryBody(EntrySuffixColonEntryBody<'t>),
+    Eol(EntrySuffixEol),
+}
+
+///
+/// Type derived for non-terminal Eol
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Eol<'t> {
+    pub eol: Token<'t>, /* <eol> */
+}
+
+///
+/// Type derived for non-terminal Indent
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Indent<'t> {
+    pub indent: Token<'t>, /* <indent> */
+}
+
+///
+/// Type derived for non-terminal Key
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Key<'t> {
+    pub key: Token<'t>, /* [a-zA-Z_][a-zA-Z0-9_]* */
+}
+
+///
+/// Type derived for non-terminal Outline
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Outline<'t> {
+    pub outline_list: Vec<OutlineList<'t>>,
+}
+
+///
+/// Type derived for non-terminal OutlineList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct OutlineList<'t> {
+    pub entry: Entry<'t>,
+}
+
+///
+/// Type derived for non-terminal Value
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Value<'t> {
+    pub value: Token<'t>, /* "(\\.|[^"\\])*"|-?[0-9]+(\.[0-9]+)? */
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Colon(Colon<'t>),
+    Dedent(Dedent<'t>),
+    Entry(Entry<'t>),
+    EntryBody(EntryBody<'t>),
+    EntryBodyList(Vec<EntryBodyList<'t>>),
+    EntrySuffix(EntrySuffix<'t>),
+    Eol(Eol<'t>),
+    Indent(Indent<'t>),
+    Key(Key<'t>),
+    Outline(Outline<'t>),
+    OutlineList(Vec<OutlineList<'t>>),
+    Value(Value<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct OffsideGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn OffsideGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `OffsideGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> OffsideGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn OffsideGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {context}: {item:?}");
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        let item = self.item_stack.pop();
+        if let Some(ref item) = item {
+            trace!("pop     {context}: {item:?}");
+        }
+        item
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {s:?}"))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// `Outline: OutlineList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn outline(&mut self, _outline_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let outline_list = pop_and_reverse_item!(self, outline_list, OutlineList, context);
+        let outline_built = Outline { outline_list };
+        // Calling user action here
+        self.user_grammar.outline(&outline_built)?;
+        self.push(ASTType::Outline(outline_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// `OutlineList /* Vec<T>::Push */: Entry OutlineList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn outline_list_0(
+        &mut self,
+        _entry: &ParseTreeType<'t>,
+        _outline_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut outline_list = pop_item!(self, outline_list, OutlineList, context);
+        let entry = pop_item!(self, entry, Entry, context);
+        let outline_list_0_built = OutlineList { entry };
+        // Add an element to the vector
+        outline_list.push(outline_list_0_built);
+        self.push(ASTType::OutlineList(outline_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// `OutlineList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn outline_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let outline_list_1_built = Vec::new();
+        self.push(ASTType::OutlineList(outline_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Entry: Key EntrySuffix;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry(&mut self, _key: &ParseTreeType<'t>, _entry_suffix: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let entry_suffix = pop_item!(self, entry_suffix, EntrySuffix, context);
+        let key = pop_item!(self, key, Key, context);
+        let entry_built = Entry { key, entry_suffix };
+        // Calling user action here
+        self.user_grammar.entry(&entry_built)?;
+        self.push(ASTType::Entry(entry_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `EntrySuffix: Colon^ /* Clipped */ EntryBody;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry_suffix_0(
+        &mut self,
+        _colon: &ParseTreeType<'t>,
+        _entry_body: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let entry_body = pop_item!(self, entry_body, EntryBody, context);
+        self.pop(context);
+        let entry_suffix_0_built = EntrySuffixColonEntryBody {
+            entry_body: Box::new(entry_body),
+        };
+        let entry_suffix_0_built = EntrySuffix::ColonEntryBody(entry_suffix_0_built);
+        // Calling user action here
+        self.user_grammar.entry_suffix(&entry_suffix_0_built)?;
+        self.push(ASTType::EntrySuffix(entry_suffix_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// `EntrySuffix: Eol^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry_suffix_1(&mut self, _eol: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.pop(context);
+        let entry_suffix_1_built = EntrySuffixEol {};
+        let entry_suffix_1_built = EntrySuffix::Eol(entry_suffix_1_built);
+        // Calling user action here
+        self.user_grammar.entry_suffix(&entry_suffix_1_built)?;
+        self.push(ASTType::EntrySuffix(entry_suffix_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// `EntryBody: Value Eol^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry_body_0(&mut self, _value: &ParseTreeType<'t>, _eol: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.pop(context);
+        let value = pop_item!(self, value, Value, context);
+        let entry_body_0_built = EntryBodyValueEol { value };
+        let entry_body_0_built = EntryBody::ValueEol(entry_body_0_built);
+        // Calling user action here
+        self.user_grammar.entry_body(&entry_body_0_built)?;
+        self.push(ASTType::EntryBody(entry_body_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// `EntryBody: Eol^ /* Clipped */ Indent^ /* Clipped */ Entry EntryBodyList /* Vec */ Dedent^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry_body_1(
+        &mut self,
+        _eol: &ParseTreeType<'t>,
+        _indent: &ParseTreeType<'t>,
+        _entry: &ParseTreeType<'t>,
+        _entry_body_list: &ParseTreeType<'t>,
+        _dedent: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        self.pop(context);
+        let entry_body_list = pop_and_reverse_item!(self, entry_body_list, EntryBodyList, context);
+        let entry = pop_item!(self, entry, Entry, context);
+        self.pop(context);
+        self.pop(context);
+        let entry_body_1_built = EntryBodyEolIndentEntryEntryBodyListDedent {
+            entry,
+            entry_body_list,
+        };
+        let entry_body_1_built = EntryBody::EolIndentEntryEntryBodyListDedent(entry_body_1_built);
+        // Calling user action here
+        self.user_grammar.entry_body(&entry_body_1_built)?;
+        self.push(ASTType::EntryBody(entry_body_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// `EntryBodyList /* Vec<T>::Push */: Entry EntryBodyList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry_body_list_0(
+        &mut self,
+        _entry: &ParseTreeType<'t>,
+        _entry_body_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut entry_body_list = pop_item!(self, entry_body_list, EntryBodyList, context);
+        let entry = pop_item!(self, entry, Entry, context);
+        let entry_body_list_0_built = EntryBodyList { entry };
+        // Add an element to the vector
+        entry_body_list.push(entry_body_list_0_built);
+        self.push(ASTType::EntryBodyList(entry_body_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// `EntryBodyList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn entry_body_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let entry_body_list_1_built = Vec::new();
+        self.push(ASTType::EntryBodyList(entry_body_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 10:
+    ///
+    /// `Key: /[a-zA-Z_][a-zA-Z0-9_]*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn key(&mut self, key: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let key = key.token()?.clone();
+        let key_built = Key { key };
+        // Calling user action here
+        self.user_grammar.key(&key_built)?;
+        self.push(ASTType::Key(key_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 11:
+    ///
+    /// `Value: /"(\\.|[^"\\])*"|-?[0-9]+(\.[0-9]+)?/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value(&mut self, value: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let value = value.token()?.clone();
+        let value_built = Value { value };
+        // Calling user action here
+        self.user_grammar.value(&value_built)?;
+        self.push(ASTType::Value(value_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 12:
+    ///
+    /// `Colon: ':';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn colon(&mut self, colon: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let colon = colon.token()?.clone();
+        let colon_built = Colon { colon };
+        // Calling user action here
+        self.user_grammar.colon(&colon_built)?;
+        self.push(ASTType::Colon(colon_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 13:
+    ///
+    /// `Indent: '<indent>';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn indent(&mut self, indent: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let indent = indent.token()?.clone();
+        let indent_built = Indent { indent };
+        // Calling user action here
+        self.user_grammar.indent(&indent_built)?;
+        self.push(ASTType::Indent(indent_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 14:
+    ///
+    /// `Dedent: '<dedent>';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn dedent(&mut self, dedent: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let dedent = dedent.token()?.clone();
+        let dedent_built = Dedent { dedent };
+        // Calling user action here
+        self.user_grammar.dedent(&dedent_built)?;
+        self.push(ASTType::Dedent(dedent_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 15:
+    ///
+    /// `Eol: '<eol>';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn eol(&mut self, eol: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let eol = eol.token()?.clone();
+        let eol_built = Eol { eol };
+        // Calling user action here
+        self.user_grammar.eol(&eol_built)?;
+        self.push(ASTType::Eol(eol_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for OffsideGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item OffsideGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.outline(&children[0]),
+            1 => self.outline_list_0(&children[0], &children[1]),
+            2 => self.outline_list_1(),
+            3 => self.entry(&children[0], &children[1]),
+            4 => self.entry_suffix_0(&children[0], &children[1]),
+            5 => self.entry_suffix_1(&children[0]),
+            6 => self.entry_body_0(&children[0], &children[1]),
+            7 => self.entry_body_1(
+                &children[0],
+                &children[1],
+                &children[2],
+                &children[3],
+                &children[4],
+            ),
+            8 => self.entry_body_list_0(&children[0], &children[1]),
+            9 => self.entry_body_list_1(),
+            10 => self.key(&children[0]),
+            11 => self.value(&children[0]),
+            12 => self.colon(&children[0]),
+            13 => self.indent(&children[0]),
+            14 => self.dedent(&children[0]),
+            15 => self.eol(&children[0]),
+            _ => Err(ParserError::InternalError(format!(
+                "Unhandled production number: {prod_num}"
+            ))
+            .into()),
+        }
+    }
+
+    fn on_comment(&mut self, token: Token<'t>) {
+        self.user_grammar.on_comment(token)
+    }
+}
@@ -0,0 +1,309 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{
+    ParolError, ParseTree, TokenStream,
+    parser::{
+        LLKParser, LookaheadDFA, ParseType, Production, Trans, parse_tree_type::TreeConstruct,
+    },
+};
+use scnr2::scanner;
+use std::path::Path;
+
+use crate::offside_grammar::OffsideGrammar;
+use crate::offside_grammar_trait::OffsideGrammarAuto;
+
+pub const TERMINAL_NAMES: &[&str; 12] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Key",
+    /*  6 */ "Value",
+    /*  7 */ "Colon",
+    /*  8 */ "Indent",
+    /*  9 */ "Dedent",
+    /* 10 */ "Eol",
+    /* 11 */ "Error",
+];
+
+scanner! {
+    OffsideGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"#.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 5; // "Key"
+            token r#""(\\.|[^"\\])*"|-?[0-9]+(\.[0-9]+)?"# => 6; // "Value"
+            token r":" => 7; // "Colon"
+            token r"." => 11; // "Error"
+        }
+    }
+}
+
+const MAX_K: usize = 1;
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+pub const OFFSIDE_TOKENS_BY_SCANNER_STATE: &[Option<parol_runtime::lexer::OffsideTokens>; 1] =
+    &[Some(parol_runtime::lexer::OffsideTokens {
+        indent: 8,
+        dedent: 9,
+        newline: 10,
+    })];
+
+pub const NON_TERMINALS: &[&str; 12] = &[
+    /*  0 */ "Colon",
+    /*  1 */ "Dedent",
+    /*  2 */ "Entry",
+    /*  3 */ "EntryBody",
+    /*  4 */ "EntryBodyList",
+    /*  5 */ "EntrySuffix",
+    /*  6 */ "Eol",
+    /*  7 */ "Indent",
+    /*  8 */ "Key",
+    /*  9 */ "Outline",
+    /* 10 */ "OutlineList",
+    /* 11 */ "Value",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 12] = &[
+    /* 0 - "Colon" */
+    LookaheadDFA {
+        prod0: 12,
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "Dedent" */
+    LookaheadDFA {
+        prod0: 14,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "Entry" */
+    LookaheadDFA {
+        prod0: 3,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "EntryBody" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 6, 1, 6), Trans(0, 10, 2, 7)],
+        k: 1,
+    },
+    /* 4 - "EntryBodyList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 1, 8), Trans(0, 9, 2, 9)],
+        k: 1,
+    },
+    /* 5 - "EntrySuffix" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 7, 1, 4), Trans(0, 10, 2, 5)],
+        k: 1,
+    },
+    /* 6 - "Eol" */
+    LookaheadDFA {
+        prod0: 15,
+        transitions: &[],
+        k: 0,
+    },
+    /* 7 - "Indent" */
+    LookaheadDFA {
+        prod0: 13,
+        transitions: &[],
+        k: 0,
+    },
+    /* 8 - "Key" */
+    LookaheadDFA {
+        prod0: 10,
+        transitions: &[],
+        k: 0,
+    },
+    /* 9 - "Outline" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 10 - "OutlineList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 5, 1, 1)],
+        k: 1,
+    },
+    /* 11 - "Value" */
+    LookaheadDFA {
+        prod0: 11,
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 16] = &[
+    // 0 - Outline: OutlineList /* Vec */;
+    Production {
+        lhs: 9,
+        production: &[ParseType::N(10)],
+        is_push_production: false,
+    },
+    // 1 - OutlineList: Entry OutlineList;
+    Production {
+        lhs: 10,
+        production: &[ParseType::N(10), ParseType::N(2)],
+        is_push_production: true,
+    },
+    // 2 - OutlineList: ;
+    Production {
+        lhs: 10,
+        production: &[],
+        is_push_production: false,
+    },
+    // 3 - Entry: Key EntrySuffix;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(5), ParseType::N(8)],
+        is_push_production: false,
+    },
+    // 4 - EntrySuffix: Colon^ /* Clipped */ EntryBody;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(3), ParseType::N(0)],
+        is_push_production: false,
+    },
+    // 5 - EntrySuffix: Eol^ /* Clipped */;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(6)],
+        is_push_production: false,
+    },
+    // 6 - EntryBody: Value Eol^ /* Clipped */;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(6), ParseType::N(11)],
+        is_push_production: false,
+    },
+    // 7 - EntryBody: Eol^ /* Clipped */ Indent^ /* Clipped */ Entry EntryBodyList /* Vec */ Dedent^ /* Clipped */;
+    Production {
+        lhs: 3,
+        production: &[
+            ParseType::N(1),
+            ParseType::N(4),
+            ParseType::N(2),
+            ParseType::N(7),
+            ParseType::N(6),
+        ],
+        is_push_production: false,
+    },
+    // 8 - EntryBodyList: Entry EntryBodyList;
+    Production {
+        lhs: 4,
+        production: &[ParseType::N(4), ParseType::N(2)],
+        is_push_production: true,
+    },
+    // 9 - EntryBodyList: ;
+    Production {
+        lhs: 4,
+        production: &[],
+        is_push_production: false,
+    },
+    // 10 - Key: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    Production {
+        lhs: 8,
+        production: &[ParseType::T(5)],
+        is_push_production: false,
+    },
+    // 11 - Value: /"(\\.|[^"\\])*"|-?[0-9]+(\.[0-9]+)?/;
+    Production {
+        lhs: 11,
+        production: &[ParseType::T(6)],
+        is_push_production: false,
+    },
+    // 12 - Colon: ':';
+    Production {
+        lhs: 0,
+        production: &[ParseType::T(7)],
+        is_push_production: false,
+    },
+    // 13 - Indent: '<indent>';
+    Production {
+        lhs: 7,
+        production: &[ParseType::T(8)],
+        is_push_production: false,
+    },
+    // 14 - Dedent: '<dedent>';
+    Production {
+        lhs: 1,
+        production: &[ParseType::T(9)],
+        is_push_production: false,
+    },
+    // 15 - Eol: '<eol>';
+    Production {
+        lhs: 6,
+        production: &[ParseType::T(10)],
+        is_push_production: false,
+    },
+];
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut OffsideGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    parse_into(input, &mut builder, file_name, user_actions)?;
+    Ok(builder.build()?)
+}
+#[allow(dead_code)]
+pub fn parse_into<'t, T: TreeConstruct<'t>>(
+    input: &'t str,
+    tree_builder: &mut T,
+    file_name: impl AsRef<Path>,
+    user_actions: &mut OffsideGrammar<'t>,
+) -> Result<(), ParolError>
+where
+    ParolError: From<T::Error>,
+{
+    use offside_grammar_scanner::OffsideGrammarScanner;
+    let mut llk_parser = LLKParser::new(
+        9,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let scanner = OffsideGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = OffsideGrammarAuto::new(user_actions);
+    llk_parser.parse_into(
+        tree_builder,
+        TokenStream::new_with_options(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &OffsideGrammarScanner::match_function,
+            MAX_K,
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: OFFSIDE_TOKENS_BY_SCANNER_STATE,
+                external_tokens_by_state: &[],
+                contextual_lexing: false,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+        )?,
+        &mut user_actions,
+    )
+}
@@ -0,0 +1,51 @@
+extern crate parol_runtime;
+
+mod raw_strings_grammar;
+mod raw_strings_grammar_trait;
+mod raw_strings_parser;
+
+use crate::raw_strings_grammar::{RawStringScanner, RawStringsGrammar};
+use crate::raw_strings_parser::parse;
+use anyhow::{Context, Result, anyhow};
+use parol_runtime::Report;
+use parol_runtime::log::debug;
+use std::env;
+use std::fs;
+
+// To generate:
+// parol -f ./examples/raw_strings/raw_strings.par -e ./examples/raw_strings/raw_strings-exp.par -p ./examples/raw_strings/raw_strings_parser.rs -a ./examples/raw_strings/raw_strings_grammar_trait.rs -t RawStringsGrammar -m raw_strings_grammar -b
+
+// To run the example
+// cargo run --example raw_strings -- ./examples/raw_strings/raw_strings_test.txt
+struct RawStringsErrorReporter;
+impl Report for RawStringsErrorReporter {}
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let input = fs::read_to_string(file_name.clone())
+            .with_context(|| format!("Can't read file {}", file_name))?;
+        let mut raw_strings_grammar = RawStringsGrammar::new();
+        match parse(
+            &input,
+            &file_name,
+            &mut raw_strings_grammar,
+            RawStringScanner,
+        ) {
+            Ok(_) => {
+                print!("{}", raw_strings_grammar);
+                Ok(())
+            }
+            Err(e) => {
+                RawStringsErrorReporter::report_error(&e, file_name).unwrap_or(());
+                Err(anyhow!("Parsing failed!"))
+            }
+        }
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::raw_strings_grammar_trait::{Assignments, RawStringsGrammarTrait, Value};
+use crate::raw_strings_parser::RawStringsGrammarExternalScanner;
+use parol_runtime::Result;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+///
+/// Scans Rust-like raw strings, e.g. `r#"a "quoted" text"#`.
+/// The closing delimiter has to repeat the number of hashes of the opening one.
+///
+#[derive(Debug, Default)]
+pub struct RawStringScanner;
+
+impl RawStringsGrammarExternalScanner for RawStringScanner {
+    fn raw_string(&mut self, input: &str, _scanner_state: usize) -> Option<usize> {
+        let rest = input.strip_prefix('r')?;
+        let hashes = rest.len() - rest.trim_start_matches('#').len();
+        let content = rest[hashes..].strip_prefix('"')?;
+        let closing = format!("\"{}", "#".repeat(hashes));
+        let end = content.find(&closing)?;
+        Some(1 + hashes + 1 + end + closing.len())
+    }
+}
+
+///
+/// Data structure that implements the semantic actions for our raw strings grammar
+///
+#[derive(Debug, Default)]
+pub struct RawStringsGrammar<'t> {
+    pub assignments: Option<Assignments<'t>>,
+}
+
+impl RawStringsGrammar<'_> {
+    pub fn new() -> Self {
+        RawStringsGrammar::default()
+    }
+}
+
+impl Display for RawStringsGrammar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        match &self.assignments {
+            Some(assignments) => assignments.assignments_list.iter().try_for_each(|a| {
+                let (kind, value) = match &a.assignment.value {
+                    Value::RawString(r) => ("raw", r.raw_string.raw_string.text()),
+                    Value::String(s) => ("string", s.string.string.text()),
+                };
+                writeln!(f, "{} = {kind} {value}", a.assignment.name.name.text())
+            }),
+            None => write!(f, "No parse result"),
+        }
+    }
+}
+
+impl<'t> RawStringsGrammarTrait<'t> for RawStringsGrammar<'t> {
+    /// Semantic action for non-terminal 'Assignments'
+    fn assignments(&mut self, arg: &Assignments<'t>) -> Result<()> {
+        self.assignments = Some(arg.clone());
+        Ok(())
+    }
+}
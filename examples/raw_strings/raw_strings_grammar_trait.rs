@@ -0,0 +1,417 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{ParserError, Result, Token};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait RawStringsGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'Assignments'
+    fn assignments(&mut self, _arg: &Assignments<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Assignment'
+    fn assignment(&mut self, _arg: &Assignment<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Value'
+    fn value(&mut self, _arg: &Value<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Name'
+    fn name(&mut self, _arg: &Name<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'String'
+    fn string(&mut self, _arg: &String<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'RawString'
+    fn raw_string(&mut self, _arg: &RawString<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// This method provides skipped language comments.
+    /// If you need comments please provide your own implementation of this method.
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 4
+///
+/// `Value: RawString;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ValueRawString<'t> {
+    pub raw_string: RawString<'t>,
+}
+
+///
+/// Type derived for production 5
+///
+/// `Value: String;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct ValueString<'t> {
+    pub string: String<'t>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Assignment
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Assignment<'t> {
+    pub name: Name<'t>,
+    pub value: Value<'t>,
+}
+
+///
+/// Type derived for non-terminal Assignments
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Assignments<'t> {
+    pub assignments_list: Vec<AssignmentsList<'t>>,
+}
+
+///
+/// Type derived for non-terminal AssignmentsList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct AssignmentsList<'t> {
+    pub assignment: Assignment<'t>,
+}
+
+///
+/// Type derived for non-terminal Name
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Name<'t> {
+    pub name: Token<'t>, /* [a-zA-Z_][a-zA-Z0-9_]* */
+}
+
+///
+/// Type derived for non-terminal RawString
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct RawString<'t> {
+    pub raw_string: Token<'t>, /* <raw string> */
+}
+
+///
+/// Type derived for non-terminal String
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct String<'t> {
+    pub string: Token<'t>, /* "(\\.|[^"\\])*" */
+}
+
+///
+/// Type derived for non-terminal Value
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Value<'t> {
+    RawString(ValueRawString<'t>),
+    String(ValueString<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Assignment(Assignment<'t>),
+    Assignments(Assignments<'t>),
+    AssignmentsList(Vec<AssignmentsList<'t>>),
+    Name(Name<'t>),
+    RawString(RawString<'t>),
+    String(String<'t>),
+    Value(Value<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct RawStringsGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn RawStringsGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `RawStringsGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> RawStringsGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn RawStringsGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {context}: {item:?}");
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        let item = self.item_stack.pop();
+        if let Some(ref item) = item {
+            trace!("pop     {context}: {item:?}");
+        }
+        item
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {s:?}"))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// `Assignments: AssignmentsList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assignments(&mut self, _assignments_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assignments_list =
+            pop_and_reverse_item!(self, assignments_list, AssignmentsList, context);
+        let assignments_built = Assignments { assignments_list };
+        // Calling user action here
+        self.user_grammar.assignments(&assignments_built)?;
+        self.push(ASTType::Assignments(assignments_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// `AssignmentsList /* Vec<T>::Push */: Assignment AssignmentsList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assignments_list_0(
+        &mut self,
+        _assignment: &ParseTreeType<'t>,
+        _assignments_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut assignments_list = pop_item!(self, assignments_list, AssignmentsList, context);
+        let assignment = pop_item!(self, assignment, Assignment, context);
+        let assignments_list_0_built = AssignmentsList { assignment };
+        // Add an element to the vector
+        assignments_list.push(assignments_list_0_built);
+        self.push(ASTType::AssignmentsList(assignments_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// `AssignmentsList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assignments_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let assignments_list_1_built = Vec::new();
+        self.push(ASTType::AssignmentsList(assignments_list_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Assignment: Name '='^ /* Clipped */ Value ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn assignment(
+        &mut self,
+        _name: &ParseTreeType<'t>,
+        _equ: &ParseTreeType<'t>,
+        _value: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let value = pop_item!(self, value, Value, context);
+        let name = pop_item!(self, name, Name, context);
+        let assignment_built = Assignment { name, value };
+        // Calling user action here
+        self.user_grammar.assignment(&assignment_built)?;
+        self.push(ASTType::Assignment(assignment_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `Value: RawString;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value_0(&mut self, _raw_string: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let raw_string = pop_item!(self, raw_string, RawString, context);
+        let value_0_built = ValueRawString { raw_string };
+        let value_0_built = Value::RawString(value_0_built);
+        // Calling user action here
+        self.user_grammar.value(&value_0_built)?;
+        self.push(ASTType::Value(value_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// `Value: String;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn value_1(&mut self, _string: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string = pop_item!(self, string, String, context);
+        let value_1_built = ValueString { string };
+        let value_1_built = Value::String(value_1_built);
+        // Calling user action here
+        self.user_grammar.value(&value_1_built)?;
+        self.push(ASTType::Value(value_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// `Name: /[a-zA-Z_][a-zA-Z0-9_]*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn name(&mut self, name: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name = name.token()?.clone();
+        let name_built = Name { name };
+        // Calling user action here
+        self.user_grammar.name(&name_built)?;
+        self.push(ASTType::Name(name_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// `String: /"(\\.|[^"\\])*"/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn string(&mut self, string: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let string = string.token()?.clone();
+        let string_built = String { string };
+        // Calling user action here
+        self.user_grammar.string(&string_built)?;
+        self.push(ASTType::String(string_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// `RawString: '<raw string>';`
+    ///
+    #[parol_runtime::function_name::named]
+    fn raw_string(&mut self, raw_string: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let raw_string = raw_string.token()?.clone();
+        let raw_string_built = RawString { raw_string };
+        // Calling user action here
+        self.user_grammar.raw_string(&raw_string_built)?;
+        self.push(ASTType::RawString(raw_string_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for RawStringsGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item RawStringsGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.assignments(&children[0]),
+            1 => self.assignments_list_0(&children[0], &children[1]),
+            2 => self.assignments_list_1(),
+            3 => self.assignment(&children[0], &children[1], &children[2], &children[3]),
+            4 => self.value_0(&children[0]),
+            5 => self.value_1(&children[0]),
+            6 => self.name(&children[0]),
+            7 => self.string(&children[0]),
+            8 => self.raw_string(&children[0]),
+            _ => Err(ParserError::InternalError(format!(
+                "Unhandled production number: {prod_num}"
+            ))
+            .into()),
+        }
+    }
+
+    fn on_comment(&mut self, token: Token<'t>) {
+        self.user_grammar.on_comment(token)
+    }
+}
@@ -0,0 +1,265 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::{
+    ParolError, ParseTree, TokenStream,
+    parser::{
+        LLKParser, LookaheadDFA, ParseType, Production, Trans, parse_tree_type::TreeConstruct,
+    },
+};
+use scnr2::scanner;
+use std::path::Path;
+
+use crate::raw_strings_grammar::RawStringsGrammar;
+use crate::raw_strings_grammar_trait::RawStringsGrammarAuto;
+
+pub const TERMINAL_NAMES: &[&str; 11] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Equ",
+    /*  6 */ "Semicolon",
+    /*  7 */ "Name",
+    /*  8 */ "String",
+    /*  9 */ "RawString",
+    /* 10 */ "Error",
+];
+
+scanner! {
+    RawStringsGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"=" => 5; // "Equ"
+            token r";" => 6; // "Semicolon"
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 7; // "Name"
+            token r#""(\\.|[^"\\])*""# => 8; // "String"
+            token r"." => 10; // "Error"
+        }
+    }
+}
+
+const MAX_K: usize = 1;
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+pub const EXTERNAL_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] =
+    &[&[9] /* INITIAL */];
+
+///
+/// The scanner of the terminals that are declared with `%external` in the grammar.
+/// It is asked for its terminals at every token start in the scanner states that declare them,
+/// before the generated scanner.
+///
+pub trait RawStringsGrammarExternalScanner {
+    /// Returns the length in bytes of the terminal RawString at the start of the
+    /// input or None if it doesn't start there.
+    fn raw_string(&mut self, input: &str, scanner_state: usize) -> Option<usize>;
+}
+
+struct ExternalScannerAdapter<S>(S);
+
+impl<S: RawStringsGrammarExternalScanner> parol_runtime::lexer::ExternalScanner
+    for ExternalScannerAdapter<S>
+{
+    fn scan(
+        &mut self,
+        terminal: parol_runtime::TerminalIndex,
+        input: &str,
+        scanner_state: usize,
+    ) -> Option<usize> {
+        match terminal {
+            9 => self.0.raw_string(input, scanner_state),
+            _ => None,
+        }
+    }
+}
+
+pub const NON_TERMINALS: &[&str; 7] = &[
+    /* 0 */ "Assignment",
+    /* 1 */ "Assignments",
+    /* 2 */ "AssignmentsList",
+    /* 3 */ "Name",
+    /* 4 */ "RawString",
+    /* 5 */ "String",
+    /* 6 */ "Value",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 7] = &[
+    /* 0 - "Assignment" */
+    LookaheadDFA {
+        prod0: 3,
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "Assignments" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "AssignmentsList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 7, 1, 1)],
+        k: 1,
+    },
+    /* 3 - "Name" */
+    LookaheadDFA {
+        prod0: 6,
+        transitions: &[],
+        k: 0,
+    },
+    /* 4 - "RawString" */
+    LookaheadDFA {
+        prod0: 8,
+        transitions: &[],
+        k: 0,
+    },
+    /* 5 - "String" */
+    LookaheadDFA {
+        prod0: 7,
+        transitions: &[],
+        k: 0,
+    },
+    /* 6 - "Value" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 8, 2, 5), Trans(0, 9, 1, 4)],
+        k: 1,
+    },
+];
+
+pub const PRODUCTIONS: &[Production; 9] = &[
+    // 0 - Assignments: AssignmentsList /* Vec */;
+    Production {
+        lhs: 1,
+        production: &[ParseType::N(2)],
+        is_push_production: false,
+    },
+    // 1 - AssignmentsList: Assignment AssignmentsList;
+    Production {
+        lhs: 2,
+        production: &[ParseType::N(2), ParseType::N(0)],
+        is_push_production: true,
+    },
+    // 2 - AssignmentsList: ;
+    Production {
+        lhs: 2,
+        production: &[],
+        is_push_production: false,
+    },
+    // 3 - Assignment: Name '='^ /* Clipped */ Value ';'^ /* Clipped */;
+    Production {
+        lhs: 0,
+        production: &[
+            ParseType::T(6),
+            ParseType::N(6),
+            ParseType::T(5),
+            ParseType::N(3),
+        ],
+        is_push_production: false,
+    },
+    // 4 - Value: RawString;
+    Production {
+        lhs: 6,
+        production: &[ParseType::N(4)],
+        is_push_production: false,
+    },
+    // 5 - Value: String;
+    Production {
+        lhs: 6,
+        production: &[ParseType::N(5)],
+        is_push_production: false,
+    },
+    // 6 - Name: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    Production {
+        lhs: 3,
+        production: &[ParseType::T(7)],
+        is_push_production: false,
+    },
+    // 7 - String: /"(\\.|[^"\\])*"/;
+    Production {
+        lhs: 5,
+        production: &[ParseType::T(8)],
+        is_push_production: false,
+    },
+    // 8 - RawString: '<raw string>';
+    Production {
+        lhs: 4,
+        production: &[ParseType::T(9)],
+        is_push_production: false,
+    },
+];
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut RawStringsGrammar<'t>,
+    external_scanner: impl RawStringsGrammarExternalScanner + 't,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    parse_into(
+        input,
+        &mut builder,
+        file_name,
+        user_actions,
+        external_scanner,
+    )?;
+    Ok(builder.build()?)
+}
+#[allow(dead_code)]
+pub fn parse_into<'t, T: TreeConstruct<'t>>(
+    input: &'t str,
+    tree_builder: &mut T,
+    file_name: impl AsRef<Path>,
+    user_actions: &mut RawStringsGrammar<'t>,
+    external_scanner: impl RawStringsGrammarExternalScanner + 't,
+) -> Result<(), ParolError>
+where
+    ParolError: From<T::Error>,
+{
+    use raw_strings_grammar_scanner::RawStringsGrammarScanner;
+    let mut llk_parser = LLKParser::new(
+        1,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    let scanner = RawStringsGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = RawStringsGrammarAuto::new(user_actions);
+    llk_parser.parse_into(
+        tree_builder,
+        TokenStream::new_with_external_scanner(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &RawStringsGrammarScanner::match_function,
+            MAX_K,
+            parol_runtime::lexer::TokenStreamOptions {
+                skip_tokens_by_state: SKIP_TOKENS_BY_SCANNER_STATE,
+                nested_block_comments_by_state: &[],
+                offside_tokens_by_state: &[],
+                external_tokens_by_state: EXTERNAL_TOKENS_BY_SCANNER_STATE,
+                contextual_lexing: false,
+                byte_input: false,
+                column_unit: parol_runtime::lexer::ColumnUnit::Chars,
+            },
+            Box::new(ExternalScannerAdapter(external_scanner)),
+        )?,
+        &mut user_actions,
+    )
+}
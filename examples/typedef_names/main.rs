@@ -0,0 +1,65 @@
+extern crate parol_runtime;
+
+mod typedef_names_grammar;
+mod typedef_names_grammar_trait;
+mod typedef_names_parser;
+
+use crate::typedef_names_grammar::TypedefNamesGrammar;
+use crate::typedef_names_parser::parse;
+use anyhow::{Context, Result, anyhow};
+use parol_runtime::Report;
+use parol_runtime::log::debug;
+use std::env;
+use std::fs;
+
+// To generate:
+// parol -f ./examples/typedef_names/typedef_names.par -e ./examples/typedef_names/typedef_names-exp.par -p ./examples/typedef_names/typedef_names_parser.rs -a ./examples/typedef_names/typedef_names_grammar_trait.rs -t TypedefNamesGrammar -m typedef_names_grammar -b
+
+// To run the example
+// cargo run --example typedef_names -- ./examples/typedef_names/typedef_names_test.txt
+struct TypedefNamesErrorReporter;
+impl Report for TypedefNamesErrorReporter {}
+
+fn main() -> Result<()> {
+    env_logger::init();
+    debug!("env logger started");
+
+    let args: Vec<String> = env::args().collect();
+    if args.len() >= 2 {
+        let file_name = args[1].clone();
+        let input = fs::read_to_string(file_name.clone())
+            .with_context(|| format!("Can't read file {}", file_name))?;
+        let mut typedef_names_grammar = TypedefNamesGrammar::new();
+        match parse(&input, &file_name, &mut typedef_names_grammar) {
+            Ok(_) => {
+                print!("{}", typedef_names_grammar);
+                Ok(())
+            }
+            Err(e) => {
+                TypedefNamesErrorReporter::report_error(&e, file_name).unwrap_or(());
+                Err(anyhow!("Parsing failed!"))
+            }
+        }
+    } else {
+        Err(anyhow!("Please provide a file name as single parameter!"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicate_decides_between_declaration_and_expression() {
+        let input = "a * b; typedef int a; a * b; c * d;";
+        let mut grammar = TypedefNamesGrammar::new();
+        parse(input, "test", &mut grammar).unwrap();
+        assert_eq!(
+            grammar.to_string(),
+            "multiplication of a and b\n\
+            typedef int a\n\
+            declaration of pointer b to a\n\
+            multiplication of c and d\n"
+        );
+    }
+}
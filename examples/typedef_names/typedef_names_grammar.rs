@@ -0,0 +1,65 @@
+use crate::typedef_names_grammar_trait::{Statement, Typedef, TypedefNamesGrammarTrait};
+use parol_runtime::{Result, Token};
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display, Error, Formatter};
+
+///
+/// Data structure that implements the semantic actions for our typedef names grammar
+///
+#[derive(Debug, Default)]
+pub struct TypedefNamesGrammar<'t> {
+    /// The typedef names declared so far
+    pub type_names: BTreeSet<String>,
+    /// The statements in the order of their occurrence
+    pub statements: Vec<Statement<'t>>,
+}
+
+impl TypedefNamesGrammar<'_> {
+    pub fn new() -> Self {
+        TypedefNamesGrammar::default()
+    }
+}
+
+impl Display for TypedefNamesGrammar<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), Error> {
+        self.statements.iter().try_for_each(|s| match s {
+            Statement::Typedef(t) => writeln!(
+                f,
+                "typedef {} {}",
+                t.typedef.name.name.text(),
+                t.typedef.name0.name.text()
+            ),
+            Statement::Declaration(d) => writeln!(
+                f,
+                "declaration of pointer {} to {}",
+                d.declaration.name0.name.text(),
+                d.declaration.name.name.text()
+            ),
+            Statement::Expression(e) => writeln!(
+                f,
+                "multiplication of {} and {}",
+                e.expression.name.name.text(),
+                e.expression.name0.name.text()
+            ),
+        })
+    }
+}
+
+impl<'t> TypedefNamesGrammarTrait<'t> for TypedefNamesGrammar<'t> {
+    /// Semantic action for non-terminal 'Statement'
+    fn statement(&mut self, arg: &Statement<'t>) -> Result<()> {
+        self.statements.push(arg.clone());
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Typedef'
+    fn typedef(&mut self, arg: &Typedef<'t>) -> Result<()> {
+        self.type_names.insert(arg.name0.name.text().to_string());
+        Ok(())
+    }
+
+    /// Semantic predicate '&{is_type_name}'
+    fn is_type_name(&mut self, lookahead: &Token<'t>) -> Result<bool> {
+        Ok(self.type_names.contains(lookahead.text()))
+    }
+}
@@ -0,0 +1,486 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+// Disable clippy warnings that can result in the way how parol generates code.
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::large_enum_variant)]
+#![allow(clippy::upper_case_acronyms)]
+
+use parol_runtime::log::trace;
+#[allow(unused_imports)]
+use parol_runtime::parol_macros::{pop_and_reverse_item, pop_item};
+use parol_runtime::parser::{ParseTreeType, UserActionsTrait};
+use parol_runtime::{ParserError, Result, Token};
+
+/// Semantic actions trait generated for the user grammar
+/// All functions have default implementations.
+pub trait TypedefNamesGrammarTrait<'t> {
+    /// Semantic action for non-terminal 'TranslationUnit'
+    fn translation_unit(&mut self, _arg: &TranslationUnit<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Statement'
+    fn statement(&mut self, _arg: &Statement<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Typedef'
+    fn typedef(&mut self, _arg: &Typedef<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Declaration'
+    fn declaration(&mut self, _arg: &Declaration<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Expression'
+    fn expression(&mut self, _arg: &Expression<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic action for non-terminal 'Name'
+    fn name(&mut self, _arg: &Name<'t>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Semantic predicate '&{is_type_name}'
+    /// Returns false if the guarded alternative must not be chosen for the lookahead token.
+    fn is_type_name(&mut self, _lookahead: &Token<'t>) -> Result<bool> {
+        Ok(true)
+    }
+
+    /// This method provides skipped language comments.
+    /// If you need comments please provide your own implementation of this method.
+    fn on_comment(&mut self, _token: Token<'t>) {}
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Output Types of productions deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for production 3
+///
+/// `Statement: Typedef;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StatementTypedef<'t> {
+    pub typedef: Typedef<'t>,
+}
+
+///
+/// Type derived for production 4
+///
+/// `Statement: &{is_type_name} Declaration;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StatementDeclaration<'t> {
+    pub declaration: Declaration<'t>,
+}
+
+///
+/// Type derived for production 5
+///
+/// `Statement: Expression;`
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct StatementExpression<'t> {
+    pub expression: Expression<'t>,
+}
+
+// -------------------------------------------------------------------------------------------------
+//
+// Types of non-terminals deduced from the structure of the transformed grammar
+//
+
+///
+/// Type derived for non-terminal Declaration
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Declaration<'t> {
+    pub name: Name<'t>,
+    pub name0: Name<'t>,
+}
+
+///
+/// Type derived for non-terminal Expression
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Expression<'t> {
+    pub name: Name<'t>,
+    pub name0: Name<'t>,
+}
+
+///
+/// Type derived for non-terminal Name
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Name<'t> {
+    pub name: Token<'t>, /* [a-zA-Z_][a-zA-Z0-9_]* */
+}
+
+///
+/// Type derived for non-terminal Statement
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum Statement<'t> {
+    Typedef(StatementTypedef<'t>),
+    Declaration(StatementDeclaration<'t>),
+    Expression(StatementExpression<'t>),
+}
+
+///
+/// Type derived for non-terminal TranslationUnit
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TranslationUnit<'t> {
+    pub translation_unit_list: Vec<TranslationUnitList<'t>>,
+}
+
+///
+/// Type derived for non-terminal TranslationUnitList
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct TranslationUnitList<'t> {
+    pub statement: Statement<'t>,
+}
+
+///
+/// Type derived for non-terminal Typedef
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Typedef<'t> {
+    pub name: Name<'t>,
+    pub name0: Name<'t>,
+}
+
+// -------------------------------------------------------------------------------------------------
+
+///
+/// Deduced ASTType of expanded grammar
+///
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub enum ASTType<'t> {
+    Declaration(Declaration<'t>),
+    Expression(Expression<'t>),
+    Name(Name<'t>),
+    Statement(Statement<'t>),
+    TranslationUnit(TranslationUnit<'t>),
+    TranslationUnitList(Vec<TranslationUnitList<'t>>),
+    Typedef(Typedef<'t>),
+}
+
+// -------------------------------------------------------------------------------------------------
+
+/// Auto-implemented adapter grammar
+///
+/// The lifetime parameter `'t` refers to the lifetime of the scanned text.
+/// The lifetime parameter `'u` refers to the lifetime of user grammar object.
+///
+#[allow(dead_code)]
+pub struct TypedefNamesGrammarAuto<'t, 'u>
+where
+    't: 'u,
+{
+    // Mutable reference of the actual user grammar to be able to call the semantic actions on it
+    user_grammar: &'u mut dyn TypedefNamesGrammarTrait<'t>,
+    // Stack to construct the AST on it
+    item_stack: Vec<ASTType<'t>>,
+}
+
+///
+/// The `TypedefNamesGrammarAuto` impl is automatically generated for the
+/// given grammar.
+///
+impl<'t, 'u> TypedefNamesGrammarAuto<'t, 'u> {
+    pub fn new(user_grammar: &'u mut dyn TypedefNamesGrammarTrait<'t>) -> Self {
+        Self {
+            user_grammar,
+            item_stack: Vec::new(),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn push(&mut self, item: ASTType<'t>, context: &str) {
+        trace!("push    {context}: {item:?}");
+        self.item_stack.push(item)
+    }
+
+    #[allow(dead_code)]
+    fn pop(&mut self, context: &str) -> Option<ASTType<'t>> {
+        let item = self.item_stack.pop();
+        if let Some(ref item) = item {
+            trace!("pop     {context}: {item:?}");
+        }
+        item
+    }
+
+    #[allow(dead_code)]
+    // Use this function for debugging purposes:
+    // trace!("{}", self.trace_item_stack(context));
+    fn trace_item_stack(&self, context: &str) -> std::string::String {
+        format!(
+            "Item stack at {}:\n{}",
+            context,
+            self.item_stack
+                .iter()
+                .rev()
+                .map(|s| format!("  {s:?}"))
+                .collect::<Vec<std::string::String>>()
+                .join("\n")
+        )
+    }
+
+    /// Semantic action for production 0:
+    ///
+    /// `TranslationUnit: TranslationUnitList /* Vec */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn translation_unit(&mut self, _translation_unit_list: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let translation_unit_list =
+            pop_and_reverse_item!(self, translation_unit_list, TranslationUnitList, context);
+        let translation_unit_built = TranslationUnit {
+            translation_unit_list,
+        };
+        // Calling user action here
+        self.user_grammar
+            .translation_unit(&translation_unit_built)?;
+        self.push(ASTType::TranslationUnit(translation_unit_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 1:
+    ///
+    /// `TranslationUnitList /* Vec<T>::Push */: Statement TranslationUnitList;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn translation_unit_list_0(
+        &mut self,
+        _statement: &ParseTreeType<'t>,
+        _translation_unit_list: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let mut translation_unit_list =
+            pop_item!(self, translation_unit_list, TranslationUnitList, context);
+        let statement = pop_item!(self, statement, Statement, context);
+        let translation_unit_list_0_built = TranslationUnitList { statement };
+        // Add an element to the vector
+        translation_unit_list.push(translation_unit_list_0_built);
+        self.push(ASTType::TranslationUnitList(translation_unit_list), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 2:
+    ///
+    /// `TranslationUnitList /* Vec<T>::New */: ;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn translation_unit_list_1(&mut self) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let translation_unit_list_1_built = Vec::new();
+        self.push(
+            ASTType::TranslationUnitList(translation_unit_list_1_built),
+            context,
+        );
+        Ok(())
+    }
+
+    /// Semantic action for production 3:
+    ///
+    /// `Statement: Typedef;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_0(&mut self, _typedef: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let typedef = pop_item!(self, typedef, Typedef, context);
+        let statement_0_built = StatementTypedef { typedef };
+        let statement_0_built = Statement::Typedef(statement_0_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_0_built)?;
+        self.push(ASTType::Statement(statement_0_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 4:
+    ///
+    /// `Statement: &{is_type_name} Declaration;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_1(&mut self, _declaration: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let declaration = pop_item!(self, declaration, Declaration, context);
+        let statement_1_built = StatementDeclaration { declaration };
+        let statement_1_built = Statement::Declaration(statement_1_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_1_built)?;
+        self.push(ASTType::Statement(statement_1_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 5:
+    ///
+    /// `Statement: Expression;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn statement_2(&mut self, _expression: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let expression = pop_item!(self, expression, Expression, context);
+        let statement_2_built = StatementExpression { expression };
+        let statement_2_built = Statement::Expression(statement_2_built);
+        // Calling user action here
+        self.user_grammar.statement(&statement_2_built)?;
+        self.push(ASTType::Statement(statement_2_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 6:
+    ///
+    /// `Typedef: 'typedef'^ /* Clipped */ Name Name ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn typedef(
+        &mut self,
+        _typedef: &ParseTreeType<'t>,
+        _name: &ParseTreeType<'t>,
+        _name0: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name0 = pop_item!(self, name0, Name, context);
+        let name = pop_item!(self, name, Name, context);
+        let typedef_built = Typedef { name, name0 };
+        // Calling user action here
+        self.user_grammar.typedef(&typedef_built)?;
+        self.push(ASTType::Typedef(typedef_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 7:
+    ///
+    /// `Declaration: Name '*'^ /* Clipped */ Name ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn declaration(
+        &mut self,
+        _name: &ParseTreeType<'t>,
+        _star: &ParseTreeType<'t>,
+        _name0: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name0 = pop_item!(self, name0, Name, context);
+        let name = pop_item!(self, name, Name, context);
+        let declaration_built = Declaration { name, name0 };
+        // Calling user action here
+        self.user_grammar.declaration(&declaration_built)?;
+        self.push(ASTType::Declaration(declaration_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 8:
+    ///
+    /// `Expression: Name '*'^ /* Clipped */ Name ';'^ /* Clipped */;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn expression(
+        &mut self,
+        _name: &ParseTreeType<'t>,
+        _star: &ParseTreeType<'t>,
+        _name0: &ParseTreeType<'t>,
+        _semicolon: &ParseTreeType<'t>,
+    ) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name0 = pop_item!(self, name0, Name, context);
+        let name = pop_item!(self, name, Name, context);
+        let expression_built = Expression { name, name0 };
+        // Calling user action here
+        self.user_grammar.expression(&expression_built)?;
+        self.push(ASTType::Expression(expression_built), context);
+        Ok(())
+    }
+
+    /// Semantic action for production 9:
+    ///
+    /// `Name: /[a-zA-Z_][a-zA-Z0-9_]*/;`
+    ///
+    #[parol_runtime::function_name::named]
+    fn name(&mut self, name: &ParseTreeType<'t>) -> Result<()> {
+        let context = function_name!();
+        trace!("{}", self.trace_item_stack(context));
+        let name = name.token()?.clone();
+        let name_built = Name { name };
+        // Calling user action here
+        self.user_grammar.name(&name_built)?;
+        self.push(ASTType::Name(name_built), context);
+        Ok(())
+    }
+}
+
+impl<'t> UserActionsTrait<'t> for TypedefNamesGrammarAuto<'t, '_> {
+    ///
+    /// This function is implemented automatically for the user's item TypedefNamesGrammar.
+    ///
+    fn call_semantic_action_for_production_number(
+        &mut self,
+        prod_num: usize,
+        children: &[ParseTreeType<'t>],
+    ) -> Result<()> {
+        match prod_num {
+            0 => self.translation_unit(&children[0]),
+            1 => self.translation_unit_list_0(&children[0], &children[1]),
+            2 => self.translation_unit_list_1(),
+            3 => self.statement_0(&children[0]),
+            4 => self.statement_1(&children[0]),
+            5 => self.statement_2(&children[0]),
+            6 => self.typedef(&children[0], &children[1], &children[2], &children[3]),
+            7 => self.declaration(&children[0], &children[1], &children[2], &children[3]),
+            8 => self.expression(&children[0], &children[1], &children[2], &children[3]),
+            9 => self.name(&children[0]),
+            _ => Err(ParserError::InternalError(format!(
+                "Unhandled production number: {prod_num}"
+            ))
+            .into()),
+        }
+    }
+
+    fn on_comment(&mut self, token: Token<'t>) {
+        self.user_grammar.on_comment(token)
+    }
+
+    fn evaluate_predicate(&mut self, predicate: usize, lookahead: &Token<'t>) -> Result<bool> {
+        match predicate {
+            0 => self.user_grammar.is_type_name(lookahead),
+            _ => Err(
+                ParserError::InternalError(format!("Unhandled predicate index: {predicate}"))
+                    .into(),
+            ),
+        }
+    }
+}
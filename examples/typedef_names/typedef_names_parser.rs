@@ -0,0 +1,246 @@
+// ---------------------------------------------------------
+// This file was generated by parol.
+// Do not edit this file manually.
+// Changes will be overwritten on the next build.
+// ---------------------------------------------------------
+
+use parol_runtime::parser::PredicatedDecision;
+use parol_runtime::{
+    ParolError, ParseTree, TokenStream,
+    parser::{
+        LLKParser, LookaheadDFA, ParseType, Production, Trans, parse_tree_type::TreeConstruct,
+    },
+};
+use scnr2::scanner;
+use std::path::Path;
+
+use crate::typedef_names_grammar::TypedefNamesGrammar;
+use crate::typedef_names_grammar_trait::TypedefNamesGrammarAuto;
+
+pub const TERMINAL_NAMES: &[&str; 10] = &[
+    /*  0 */ "EndOfInput",
+    /*  1 */ "Newline",
+    /*  2 */ "Whitespace",
+    /*  3 */ "LineComment",
+    /*  4 */ "BlockComment",
+    /*  5 */ "Typedef",
+    /*  6 */ "Semicolon",
+    /*  7 */ "Star",
+    /*  8 */ "Name",
+    /*  9 */ "Error",
+];
+
+scanner! {
+    TypedefNamesGrammarScanner {
+        mode INITIAL {
+            token r"\r\n|\r|\n" => 1; // "Newline"
+            token r"[\s--\r\n]+" => 2; // "Whitespace"
+            token r"//.*(\r\n|\r|\n)?" => 3; // "LineComment"
+            token r"typedef" => 5; // "Typedef"
+            token r";" => 6; // "Semicolon"
+            token r"\*" => 7; // "Star"
+            token r"[a-zA-Z_][a-zA-Z0-9_]*" => 8; // "Name"
+            token r"." => 9; // "Error"
+        }
+    }
+}
+
+const MAX_K: usize = 1;
+pub const SKIP_TOKENS_BY_SCANNER_STATE: &[&[parol_runtime::TerminalIndex]; 1] = &[&[]];
+
+pub const NON_TERMINALS: &[&str; 7] = &[
+    /* 0 */ "Declaration",
+    /* 1 */ "Expression",
+    /* 2 */ "Name",
+    /* 3 */ "Statement",
+    /* 4 */ "TranslationUnit",
+    /* 5 */ "TranslationUnitList",
+    /* 6 */ "Typedef",
+];
+
+pub const LOOKAHEAD_AUTOMATA: &[LookaheadDFA; 7] = &[
+    /* 0 - "Declaration" */
+    LookaheadDFA {
+        prod0: 7,
+        transitions: &[],
+        k: 0,
+    },
+    /* 1 - "Expression" */
+    LookaheadDFA {
+        prod0: 8,
+        transitions: &[],
+        k: 0,
+    },
+    /* 2 - "Name" */
+    LookaheadDFA {
+        prod0: 9,
+        transitions: &[],
+        k: 0,
+    },
+    /* 3 - "Statement" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 5, 1, 3), Trans(0, 8, 2, 4)],
+        k: 1,
+    },
+    /* 4 - "TranslationUnit" */
+    LookaheadDFA {
+        prod0: 0,
+        transitions: &[],
+        k: 0,
+    },
+    /* 5 - "TranslationUnitList" */
+    LookaheadDFA {
+        prod0: -1,
+        transitions: &[Trans(0, 0, 2, 2), Trans(0, 5, 1, 1), Trans(0, 8, 1, 1)],
+        k: 1,
+    },
+    /* 6 - "Typedef" */
+    LookaheadDFA {
+        prod0: 6,
+        transitions: &[],
+        k: 0,
+    },
+];
+
+pub const PREDICATED_DECISIONS: &[PredicatedDecision; 1] =
+    &[/* 3 - "Statement" */ PredicatedDecision {
+        non_terminal: 3,
+        predicates: &[(4, 0)],
+        fallback: LookaheadDFA {
+            prod0: -1,
+            transitions: &[Trans(0, 5, 1, 3), Trans(0, 8, 2, 5)],
+            k: 1,
+        },
+    }];
+
+pub const PRODUCTIONS: &[Production; 10] = &[
+    // 0 - TranslationUnit: TranslationUnitList /* Vec */;
+    Production {
+        lhs: 4,
+        production: &[ParseType::N(5)],
+        is_push_production: false,
+    },
+    // 1 - TranslationUnitList: Statement TranslationUnitList;
+    Production {
+        lhs: 5,
+        production: &[ParseType::N(5), ParseType::N(3)],
+        is_push_production: true,
+    },
+    // 2 - TranslationUnitList: ;
+    Production {
+        lhs: 5,
+        production: &[],
+        is_push_production: false,
+    },
+    // 3 - Statement: Typedef;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(6)],
+        is_push_production: false,
+    },
+    // 4 - Statement: Declaration;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(0)],
+        is_push_production: false,
+    },
+    // 5 - Statement: Expression;
+    Production {
+        lhs: 3,
+        production: &[ParseType::N(1)],
+        is_push_production: false,
+    },
+    // 6 - Typedef: 'typedef'^ /* Clipped */ Name Name ';'^ /* Clipped */;
+    Production {
+        lhs: 6,
+        production: &[
+            ParseType::T(6),
+            ParseType::N(2),
+            ParseType::N(2),
+            ParseType::T(5),
+        ],
+        is_push_production: false,
+    },
+    // 7 - Declaration: Name '*'^ /* Clipped */ Name ';'^ /* Clipped */;
+    Production {
+        lhs: 0,
+        production: &[
+            ParseType::T(6),
+            ParseType::N(2),
+            ParseType::T(7),
+            ParseType::N(2),
+        ],
+        is_push_production: false,
+    },
+    // 8 - Expression: Name '*'^ /* Clipped */ Name ';'^ /* Clipped */;
+    Production {
+        lhs: 1,
+        production: &[
+            ParseType::T(6),
+            ParseType::N(2),
+            ParseType::T(7),
+            ParseType::N(2),
+        ],
+        is_push_production: false,
+    },
+    // 9 - Name: /[a-zA-Z_][a-zA-Z0-9_]*/;
+    Production {
+        lhs: 2,
+        production: &[ParseType::T(8)],
+        is_push_production: false,
+    },
+];
+
+pub fn parse<'t, T>(
+    input: &'t str,
+    file_name: T,
+    user_actions: &mut TypedefNamesGrammar<'t>,
+) -> Result<ParseTree, ParolError>
+where
+    T: AsRef<Path>,
+{
+    use parol_runtime::{
+        parser::{parse_tree_type::SynTree, parser_types::SynTreeFlavor},
+        syntree::Builder,
+    };
+    let mut builder = Builder::<SynTree, SynTreeFlavor>::new_with();
+    parse_into(input, &mut builder, file_name, user_actions)?;
+    Ok(builder.build()?)
+}
+#[allow(dead_code)]
+pub fn parse_into<'t, T: TreeConstruct<'t>>(
+    input: &'t str,
+    tree_builder: &mut T,
+    file_name: impl AsRef<Path>,
+    user_actions: &mut TypedefNamesGrammar<'t>,
+) -> Result<(), ParolError>
+where
+    ParolError: From<T::Error>,
+{
+    use typedef_names_grammar_scanner::TypedefNamesGrammarScanner;
+    let mut llk_parser = LLKParser::new(
+        4,
+        LOOKAHEAD_AUTOMATA,
+        PRODUCTIONS,
+        TERMINAL_NAMES,
+        NON_TERMINALS,
+    );
+    llk_parser.set_predicated_decisions(PREDICATED_DECISIONS);
+    let scanner = TypedefNamesGrammarScanner::new();
+    // Initialize wrapper
+    let mut user_actions = TypedefNamesGrammarAuto::new(user_actions);
+    llk_parser.parse_into(
+        tree_builder,
+        TokenStream::new_with_skip_tokens(
+            input,
+            file_name,
+            scanner.scanner_impl.clone(),
+            &TypedefNamesGrammarScanner::match_function,
+            MAX_K,
+            SKIP_TOKENS_BY_SCANNER_STATE,
+        )
+        .unwrap(),
+        &mut user_actions,
+    )
+}
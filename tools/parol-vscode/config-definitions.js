@@ -17,6 +17,21 @@ const CONFIG_DEFINITIONS = Object.freeze([
     defaultValue: 100,
     valueType: "integer",
   },
+  {
+    name: "formatting.align_alternations",
+    defaultValue: false,
+    valueType: "boolean",
+  },
+  {
+    name: "formatting.align_comments",
+    defaultValue: false,
+    valueType: "boolean",
+  },
+  {
+    name: "formatting.sort_prolog_declarations",
+    defaultValue: false,
+    valueType: "boolean",
+  },
 ]);
 
 module.exports = {
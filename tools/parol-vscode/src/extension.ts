@@ -8,6 +8,7 @@ import * as lc from "vscode-languageclient/node";
 import { spawnSync } from "child_process";
 import { Config } from "./config";
 import { TransportKind } from "vscode-languageclient/node";
+import { TryInputPreview } from "./tryInput";
 
 let client: lc.LanguageClient;
 
@@ -134,6 +135,8 @@ async function tryActivate(
   // Start the client. This will also launch the server
   void client.start();
 
+  context.subscriptions.push(new TryInputPreview(client));
+
   return {
     client: client,
   };
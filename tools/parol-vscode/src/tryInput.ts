@@ -0,0 +1,187 @@
+import * as vscode from "vscode";
+import * as lc from "vscode-languageclient/node";
+
+// Types of the custom request `parol-ls/tryInput`
+interface ParseTreeNode {
+  label: string;
+  isTerminal: boolean;
+  range?: lc.Range;
+  children: number[];
+}
+
+interface TryInputResult {
+  grammarError?: string;
+  nodes: ParseTreeNode[];
+  roots: number[];
+  diagnostics: lc.Diagnostic[];
+}
+
+const TRY_INPUT_REQUEST = "parol-ls/tryInput";
+
+// Delay after the last edit before the sample is parsed again
+const DEBOUNCE_MS = 300;
+
+type TreeElement = number | string;
+
+// Shows the parse tree of a sample file in a side panel and highlights its syntax errors.
+// The sample is parsed again on every change of the grammar or of the sample itself.
+export class TryInputPreview
+  implements vscode.TreeDataProvider<TreeElement>, vscode.Disposable
+{
+  private grammarUri?: vscode.Uri;
+  private sampleUri?: vscode.Uri;
+  private result?: TryInputResult;
+  private timer?: NodeJS.Timeout;
+  private readonly diagnostics =
+    vscode.languages.createDiagnosticCollection("parol-try-input");
+  private readonly changeEmitter = new vscode.EventEmitter<
+    TreeElement | undefined
+  >();
+  private readonly disposables: vscode.Disposable[] = [];
+
+  readonly onDidChangeTreeData = this.changeEmitter.event;
+
+  constructor(private readonly client: lc.LanguageClient) {
+    this.disposables.push(
+      this.diagnostics,
+      this.changeEmitter,
+      vscode.window.registerTreeDataProvider("parolParseTree", this),
+      vscode.commands.registerCommand("parol-vscode.tryInput", () =>
+        this.start(),
+      ),
+      vscode.commands.registerCommand("parol-vscode.stopTryInput", () =>
+        this.stop(),
+      ),
+      vscode.workspace.onDidChangeTextDocument((e) => {
+        if (this.isWatched(e.document.uri)) {
+          this.schedule();
+        }
+      }),
+    );
+  }
+
+  dispose() {
+    clearTimeout(this.timer);
+    this.disposables.forEach((d) => d.dispose());
+  }
+
+  private async start() {
+    const editor = vscode.window.activeTextEditor;
+    if (!editor || editor.document.languageId !== "parol") {
+      void vscode.window.showWarningMessage(
+        "Open the grammar description (.par) you want to try first.",
+      );
+      return;
+    }
+    const samples = await vscode.window.showOpenDialog({
+      canSelectMany: false,
+      openLabel: "Try input",
+      title: "Select a sample file to parse with the grammar",
+    });
+    if (!samples || samples.length === 0) {
+      return;
+    }
+    this.grammarUri = editor.document.uri;
+    this.sampleUri = samples[0];
+    this.diagnostics.clear();
+    await vscode.window.showTextDocument(this.sampleUri, {
+      viewColumn: vscode.ViewColumn.Beside,
+      preserveFocus: true,
+    });
+    await vscode.commands.executeCommand("parolParseTree.focus");
+    await this.update();
+  }
+
+  private stop() {
+    clearTimeout(this.timer);
+    this.grammarUri = undefined;
+    this.sampleUri = undefined;
+    this.result = undefined;
+    this.diagnostics.clear();
+    this.changeEmitter.fire(undefined);
+  }
+
+  private isWatched(uri: vscode.Uri): boolean {
+    const uriString = uri.toString();
+    return (
+      uriString === this.grammarUri?.toString() ||
+      uriString === this.sampleUri?.toString()
+    );
+  }
+
+  private schedule() {
+    clearTimeout(this.timer);
+    this.timer = setTimeout(() => void this.update(), DEBOUNCE_MS);
+  }
+
+  private async update() {
+    const grammarUri = this.grammarUri;
+    const sampleUri = this.sampleUri;
+    if (!grammarUri || !sampleUri) {
+      return;
+    }
+    const sample = await vscode.workspace.openTextDocument(sampleUri);
+    const result = await this.client.sendRequest<TryInputResult>(
+      TRY_INPUT_REQUEST,
+      {
+        textDocument: { uri: grammarUri.toString() },
+        input: sample.getText(),
+      },
+    );
+    // The session could have been changed while the request was running
+    if (grammarUri !== this.grammarUri || sampleUri !== this.sampleUri) {
+      return;
+    }
+    this.result = result;
+    this.diagnostics.set(
+      sampleUri,
+      await this.client.protocol2CodeConverter.asDiagnostics(
+        result.diagnostics,
+      ),
+    );
+    this.changeEmitter.fire(undefined);
+  }
+
+  getChildren(element?: TreeElement): TreeElement[] {
+    if (!this.result) {
+      return [];
+    }
+    if (element === undefined) {
+      return this.result.grammarError
+        ? [this.result.grammarError]
+        : this.result.roots;
+    }
+    return typeof element === "number"
+      ? this.result.nodes[element].children
+      : [];
+  }
+
+  getTreeItem(element: TreeElement): vscode.TreeItem {
+    if (typeof element === "string") {
+      const item = new vscode.TreeItem(`Grammar error: ${element}`);
+      item.iconPath = new vscode.ThemeIcon("error");
+      item.tooltip = element;
+      return item;
+    }
+    const node = this.result!.nodes[element];
+    const item = new vscode.TreeItem(
+      node.label,
+      node.children.length > 0
+        ? vscode.TreeItemCollapsibleState.Expanded
+        : vscode.TreeItemCollapsibleState.None,
+    );
+    item.iconPath = new vscode.ThemeIcon(
+      node.isTerminal ? "symbol-string" : "symbol-class",
+    );
+    if (node.range && this.sampleUri) {
+      const range = this.client.protocol2CodeConverter.asRange(node.range);
+      item.description = `${range.start.line + 1}:${range.start.character + 1}`;
+      item.command = {
+        title: "Reveal in sample",
+        command: "vscode.open",
+        arguments: [this.sampleUri, { selection: range }],
+      };
+    }
+    return item;
+  }
+}